@@ -0,0 +1,276 @@
+/* Generated by cbindgen from santazk-ffi/src/lib.rs, do not edit. */
+
+#ifndef SANTAZK_H
+#define SANTAZK_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Size of a point, a secret key, a nullifier or a DH public key.
+#define SANTAZK_POINT_SIZE 64
+
+// Size of the compressed encoding of a point, as in version 1 transactions.
+#define SANTAZK_COMPRESSED_POINT_SIZE 32
+
+// Size of a chain id.
+#define SANTAZK_CHAIN_ID_SIZE 32
+
+// Depth of the Merkle tree of the participants of a zkretvm chain.
+#define SANTAZK_MERKLE_TREE_DEPTH 7
+
+typedef enum SantazkStatus {
+  SANTAZK_STATUS_OK = 0,
+  // A pointer is null, an input isn't a valid point or the prover is for
+  // another circuit.
+  SANTAZK_STATUS_INVALID_ARGUMENT = 1,
+  // The proof doesn't verify.
+  SANTAZK_STATUS_INVALID_PROOF = 2,
+  // santazk panicked, e.g. on a malformed Merkle path.
+  SANTAZK_STATUS_INTERNAL = 3,
+} SantazkStatus;
+
+// Circuit a [`SantazkProver`] proves.
+typedef enum SantazkCircuit {
+  SANTAZK_CIRCUIT_ENTER = 0,
+  SANTAZK_CIRCUIT_CHOICE = 1,
+  SANTAZK_CIRCUIT_REVEAL = 2,
+} SantazkCircuit;
+
+// A Groth16 prover of one circuit, which holds its proving key.
+typedef struct SantazkProver SantazkProver;
+
+// Bytes allocated by santazk, freed with [`santazk_buffer_free`].
+typedef struct SantazkBuffer {
+  uint8_t *data;
+  size_t len;
+} SantazkBuffer;
+
+// A participant's secrets and the public key derived from them.
+typedef struct SantazkKey {
+  uint8_t secret_key[SANTAZK_POINT_SIZE];
+  uint8_t nullifier[SANTAZK_POINT_SIZE];
+  uint8_t pub_key[SANTAZK_POINT_SIZE];
+  uint8_t dh_pub_key[SANTAZK_POINT_SIZE];
+} SantazkKey;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the description of the error of the last call on this thread, or null
+// if it succeeded. The string is valid until the next call on this thread.
+const char *santazk_last_error(void);
+
+// Frees the bytes of `buffer` and empties it. Freeing an empty buffer does nothing.
+//
+// # Safety
+// `buffer` must be null or point to a buffer filled by santazk.
+void santazk_buffer_free(struct SantazkBuffer *buffer);
+
+// Generates a key like `zkretctl keygen`, from the OS's secure randomness.
+//
+// # Safety
+// `out` must point to a writable [`SantazkKey`].
+enum SantazkStatus santazk_generate_key(struct SantazkKey *out);
+
+// Writes the public key of `secret_key` and `nullifier` to `out`.
+//
+// # Safety
+// `secret_key` and `nullifier` must point to 64 bytes, `out` to 64 writable bytes.
+enum SantazkStatus santazk_derive_pub_key(const uint8_t *secret_key,
+                                          const uint8_t *nullifier,
+                                          uint8_t *out);
+
+// Writes the hash of `input` to `out`, e.g. the ciphertext hash of a REVEAL.
+//
+// # Safety
+// `input` must point to `input_len` bytes, `out` to 64 writable bytes.
+enum SantazkStatus santazk_hash(const uint8_t *input, size_t input_len, uint8_t *out);
+
+// Writes the two-to-one hash of `left` and `right` to `out`.
+//
+// # Safety
+// `left` and `right` must point to `left_len` and `right_len` bytes, `out` to
+// 64 writable bytes.
+enum SantazkStatus santazk_hash2(const uint8_t *left,
+                                 size_t left_len,
+                                 const uint8_t *right,
+                                 size_t right_len,
+                                 uint8_t *out);
+
+// Writes the 32-byte compressed encoding of `point` to `out`.
+//
+// # Safety
+// `point` must point to 64 bytes, `out` to 32 writable bytes.
+enum SantazkStatus santazk_compress_point(const uint8_t *point, uint8_t *out);
+
+// Writes the 64-byte encoding of the point whose compressed encoding is
+// `compressed` to `out`.
+//
+// # Safety
+// `compressed` must point to 32 bytes, `out` to 64 writable bytes.
+enum SantazkStatus santazk_decompress_point(const uint8_t *compressed, uint8_t *out);
+
+// Writes the root of the Merkle tree of depth `depth` over `leaves` to `out`.
+//
+// # Safety
+// `leaves` must point to `leaf_count` concatenated 64-byte leaves, `out` to 64
+// writable bytes.
+enum SantazkStatus santazk_merkle_root(size_t depth,
+                                       const uint8_t *leaves,
+                                       size_t leaf_count,
+                                       uint8_t *out);
+
+// Fills `out` with the path from the leaf at `index` to the root of the Merkle
+// tree of depth `depth` over `leaves`, for [`santazk_prove_choice`].
+//
+// # Safety
+// `leaves` must point to `leaf_count` concatenated 64-byte leaves, `out` to a
+// writable [`SantazkBuffer`].
+enum SantazkStatus santazk_merkle_path(size_t depth,
+                                       const uint8_t *leaves,
+                                       size_t leaf_count,
+                                       size_t index,
+                                       struct SantazkBuffer *out);
+
+// Writes the signature of a CHOICE of the public key `choice` to `out`.
+//
+// # Safety
+// `chain_id` must point to 32 bytes, the other inputs to 64, and `out` to 64
+// writable bytes.
+enum SantazkStatus santazk_sign_choice(const uint8_t *secret_key,
+                                       const uint8_t *nullifier,
+                                       const uint8_t *chain_id,
+                                       const uint8_t *choice,
+                                       const uint8_t *dh_pub_key,
+                                       uint8_t *out);
+
+// Writes the signature of a REVEAL of the ciphertext hashed to
+// `ciphertext_hash` to `out`.
+//
+// # Safety
+// `chain_id` must point to 32 bytes, the other inputs to 64, and `out` to 64
+// writable bytes.
+enum SantazkStatus santazk_sign_reveal(const uint8_t *secret_key,
+                                       const uint8_t *nullifier,
+                                       const uint8_t *chain_id,
+                                       const uint8_t *ciphertext_hash,
+                                       const uint8_t *dh_pub_key,
+                                       uint8_t *out);
+
+// Makes a prover of `circuit` with the proving key compiled into santazk, which
+// takes seconds. Provers are freed with [`santazk_prover_free`].
+//
+// # Safety
+// `out` must point to a writable prover pointer.
+enum SantazkStatus santazk_prover_new(enum SantazkCircuit circuit, struct SantazkProver **out);
+
+// Makes a prover of `circuit` from the bytes of its `.groth16.pk` file. The key
+// isn't checked, so it must come from a trusted source.
+//
+// # Safety
+// `key` must point to `key_len` bytes and `out` to a writable prover pointer.
+enum SantazkStatus santazk_prover_from_key(enum SantazkCircuit circuit,
+                                           const uint8_t *key,
+                                           size_t key_len,
+                                           struct SantazkProver **out);
+
+// Frees a prover made by [`santazk_prover_new`] or [`santazk_prover_from_key`].
+//
+// # Safety
+// `prover` must be null or a prover that hasn't been freed.
+void santazk_prover_free(struct SantazkProver *prover);
+
+// Fills `out` with a proof that `pub_key` was derived from `secret_key` and
+// `nullifier`, for the ENTER of the chain `chain_id`.
+//
+// # Safety
+// `prover` must be a live ENTER prover, `chain_id` must point to 32 bytes, the
+// other inputs to 64, and `out` to a writable [`SantazkBuffer`].
+enum SantazkStatus santazk_prove_enter(const struct SantazkProver *prover,
+                                       const uint8_t *secret_key,
+                                       const uint8_t *nullifier,
+                                       const uint8_t *pub_key,
+                                       const uint8_t *chain_id,
+                                       struct SantazkBuffer *out);
+
+// Fills `out` with the proof of a CHOICE of `choice` by a participant whose
+// public key is in the Merkle tree with root `root`, at `merkle_path`.
+//
+// # Safety
+// `prover` must be a live CHOICE prover, `merkle_path` must point to
+// `merkle_path_len` bytes, `chain_id` to 32 bytes, the other inputs to 64, and
+// `out` to a writable [`SantazkBuffer`].
+enum SantazkStatus santazk_prove_choice(const struct SantazkProver *prover,
+                                        const uint8_t *secret_key,
+                                        const uint8_t *nullifier,
+                                        const uint8_t *root,
+                                        const uint8_t *merkle_path,
+                                        size_t merkle_path_len,
+                                        const uint8_t *choice,
+                                        const uint8_t *chain_id,
+                                        const uint8_t *dh_pub_key,
+                                        const uint8_t *signature,
+                                        struct SantazkBuffer *out);
+
+// Fills `out` with the proof of a REVEAL by the participant `pub_key` of the
+// ciphertext hashed to `ciphertext_hash`.
+//
+// # Safety
+// `prover` must be a live REVEAL prover, `chain_id` must point to 32 bytes, the
+// other inputs to 64, and `out` to a writable [`SantazkBuffer`].
+enum SantazkStatus santazk_prove_reveal(const struct SantazkProver *prover,
+                                        const uint8_t *secret_key,
+                                        const uint8_t *nullifier,
+                                        const uint8_t *pub_key,
+                                        const uint8_t *ciphertext_hash,
+                                        const uint8_t *chain_id,
+                                        const uint8_t *dh_pub_key,
+                                        const uint8_t *signature,
+                                        struct SantazkBuffer *out);
+
+// Verifies the proof of an ENTER of `pub_key` on the chain `chain_id`.
+//
+// # Safety
+// `proof` must point to `proof_len` bytes, `pub_key` to 64 and `chain_id` to 32.
+enum SantazkStatus santazk_verify_enter(const uint8_t *proof,
+                                        size_t proof_len,
+                                        const uint8_t *pub_key,
+                                        const uint8_t *chain_id);
+
+// Verifies the proof of a CHOICE against the Merkle root `root`, with the proof
+// system it is tagged with.
+//
+// # Safety
+// `proof` must point to `proof_len` bytes, `chain_id` to 32 and the other
+// inputs to 64.
+enum SantazkStatus santazk_verify_choice(const uint8_t *proof,
+                                         size_t proof_len,
+                                         const uint8_t *nullifier,
+                                         const uint8_t *root,
+                                         const uint8_t *choice,
+                                         const uint8_t *chain_id,
+                                         const uint8_t *dh_pub_key,
+                                         const uint8_t *signature);
+
+// Verifies the proof of a REVEAL, with the proof system it is tagged with.
+//
+// # Safety
+// `proof` must point to `proof_len` bytes, `chain_id` to 32 and the other
+// inputs to 64.
+enum SantazkStatus santazk_verify_reveal(const uint8_t *proof,
+                                         size_t proof_len,
+                                         const uint8_t *pub_key,
+                                         const uint8_t *ciphertext_hash,
+                                         const uint8_t *chain_id,
+                                         const uint8_t *dh_pub_key,
+                                         const uint8_t *signature);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SANTAZK_H */
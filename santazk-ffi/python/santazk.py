@@ -0,0 +1,424 @@
+"""Python bindings for santazk-ffi, the C ABI of the santazk participant operations.
+
+Build the library with ``cargo build -p santazk-ffi --release``. It is loaded
+from ``target/release`` of the checkout this file is in, or from the path in
+``SANTAZK_FFI_LIB``.
+
+Bytes are in the formats of zkretvm's ``TransactionData``: points, secret keys,
+nullifiers and DH public keys are 64 bytes and chain ids are 32. The
+``*_transaction`` functions build a ``Transaction`` in the JSON form zkretvm
+serializes it to, with its points compressed as ``Transaction::choice`` and the
+other constructors do.
+"""
+
+import ctypes
+import os
+import sys
+from pathlib import Path
+from typing import NamedTuple, Optional, Sequence
+
+POINT_SIZE = 64
+COMPRESSED_POINT_SIZE = 32
+CHAIN_ID_SIZE = 32
+MERKLE_TREE_DEPTH = 7
+
+TX_VERSION_COMPRESSED = 1
+
+STATUS_OK = 0
+STATUS_INVALID_ARGUMENT = 1
+STATUS_INVALID_PROOF = 2
+STATUS_INTERNAL = 3
+
+CIRCUIT_ENTER = 0
+CIRCUIT_CHOICE = 1
+CIRCUIT_REVEAL = 2
+
+
+class SantazkError(Exception):
+    """A santazk call failed, with the ``STATUS_*`` it returned."""
+
+    def __init__(self, status: int, message: str):
+        super().__init__(message)
+        self.status = status
+
+
+class _Buffer(ctypes.Structure):
+    _fields_ = [("data", ctypes.POINTER(ctypes.c_uint8)), ("len", ctypes.c_size_t)]
+
+
+class _Key(ctypes.Structure):
+    _fields_ = [
+        ("secret_key", ctypes.c_uint8 * POINT_SIZE),
+        ("nullifier", ctypes.c_uint8 * POINT_SIZE),
+        ("pub_key", ctypes.c_uint8 * POINT_SIZE),
+        ("dh_pub_key", ctypes.c_uint8 * POINT_SIZE),
+    ]
+
+
+class Key(NamedTuple):
+    """A participant's secrets and the public key derived from them."""
+
+    secret_key: bytes
+    nullifier: bytes
+    pub_key: bytes
+    dh_pub_key: bytes
+
+
+def _library_path() -> str:
+    if "SANTAZK_FFI_LIB" in os.environ:
+        return os.environ["SANTAZK_FFI_LIB"]
+    name = {"darwin": "libsantazk_ffi.dylib", "win32": "santazk_ffi.dll"}.get(
+        sys.platform, "libsantazk_ffi.so"
+    )
+    return str(Path(__file__).resolve().parents[2] / "target" / "release" / name)
+
+
+_lib = ctypes.CDLL(_library_path())
+
+_ptr = ctypes.c_char_p
+_out = ctypes.POINTER(ctypes.c_uint8)
+_size = ctypes.c_size_t
+_buffer = ctypes.POINTER(_Buffer)
+_prover = ctypes.c_void_p
+
+for _name, _args in {
+    "santazk_generate_key": [ctypes.POINTER(_Key)],
+    "santazk_derive_pub_key": [_ptr, _ptr, _out],
+    "santazk_hash": [_ptr, _size, _out],
+    "santazk_hash2": [_ptr, _size, _ptr, _size, _out],
+    "santazk_compress_point": [_ptr, _out],
+    "santazk_decompress_point": [_ptr, _out],
+    "santazk_merkle_root": [_size, _ptr, _size, _out],
+    "santazk_merkle_path": [_size, _ptr, _size, _size, _buffer],
+    "santazk_sign_choice": [_ptr, _ptr, _ptr, _ptr, _ptr, _out],
+    "santazk_sign_reveal": [_ptr, _ptr, _ptr, _ptr, _ptr, _out],
+    "santazk_prover_new": [ctypes.c_int, ctypes.POINTER(_prover)],
+    "santazk_prover_from_key": [ctypes.c_int, _ptr, _size, ctypes.POINTER(_prover)],
+    "santazk_prove_enter": [_prover, _ptr, _ptr, _ptr, _ptr, _buffer],
+    "santazk_prove_choice": [
+        _prover, _ptr, _ptr, _ptr, _ptr, _size, _ptr, _ptr, _ptr, _ptr, _buffer
+    ],
+    "santazk_prove_reveal": [_prover, _ptr, _ptr, _ptr, _ptr, _ptr, _ptr, _ptr, _buffer],
+    "santazk_verify_enter": [_ptr, _size, _ptr, _ptr],
+    "santazk_verify_choice": [_ptr, _size, _ptr, _ptr, _ptr, _ptr, _ptr, _ptr],
+    "santazk_verify_reveal": [_ptr, _size, _ptr, _ptr, _ptr, _ptr, _ptr],
+}.items():
+    getattr(_lib, _name).argtypes = _args
+    getattr(_lib, _name).restype = ctypes.c_int
+
+_lib.santazk_last_error.argtypes = []
+_lib.santazk_last_error.restype = ctypes.c_char_p
+_lib.santazk_buffer_free.argtypes = [_buffer]
+_lib.santazk_buffer_free.restype = None
+_lib.santazk_prover_free.argtypes = [_prover]
+_lib.santazk_prover_free.restype = None
+
+
+def _check(status: int) -> None:
+    if status != STATUS_OK:
+        message = _lib.santazk_last_error() or b"santazk call failed"
+        raise SantazkError(status, message.decode())
+
+
+def _sized(value: bytes, size: int, name: str) -> bytes:
+    if len(value) != size:
+        raise ValueError(f"{name} must be {size} bytes, not {len(value)}")
+    return bytes(value)
+
+
+def _point(value: bytes, name: str) -> bytes:
+    return _sized(value, POINT_SIZE, name)
+
+
+def _chain_id(value: bytes) -> bytes:
+    return _sized(value, CHAIN_ID_SIZE, "chain id")
+
+
+def _call_fixed(function, size: int, *args) -> bytes:
+    out = (ctypes.c_uint8 * size)()
+    _check(function(*args, out))
+    return bytes(out)
+
+
+def _call_buffer(function, *args) -> bytes:
+    buffer = _Buffer()
+    _check(function(*args, ctypes.byref(buffer)))
+    try:
+        return ctypes.string_at(buffer.data, buffer.len)
+    finally:
+        _lib.santazk_buffer_free(ctypes.byref(buffer))
+
+
+def generate_key() -> Key:
+    """Generates a key like ``zkretctl keygen``, from the OS's secure randomness."""
+    key = _Key()
+    _check(_lib.santazk_generate_key(ctypes.byref(key)))
+    return Key(
+        bytes(key.secret_key), bytes(key.nullifier), bytes(key.pub_key), bytes(key.dh_pub_key)
+    )
+
+
+def derive_pub_key(secret_key: bytes, nullifier: bytes) -> bytes:
+    return _call_fixed(
+        _lib.santazk_derive_pub_key,
+        POINT_SIZE,
+        _point(secret_key, "secret key"),
+        _point(nullifier, "nullifier"),
+    )
+
+
+def hash1(data: bytes) -> bytes:
+    """The hash of ``data``, e.g. the ciphertext hash of a REVEAL."""
+    return _call_fixed(_lib.santazk_hash, POINT_SIZE, bytes(data), len(data))
+
+
+def hash2(left: bytes, right: bytes) -> bytes:
+    """The two-to-one hash of ``left`` and ``right``."""
+    return _call_fixed(
+        _lib.santazk_hash2, POINT_SIZE, bytes(left), len(left), bytes(right), len(right)
+    )
+
+
+def compress_point(point: bytes) -> bytes:
+    return _call_fixed(
+        _lib.santazk_compress_point, COMPRESSED_POINT_SIZE, _point(point, "point")
+    )
+
+
+def decompress_point(compressed: bytes) -> bytes:
+    return _call_fixed(
+        _lib.santazk_decompress_point,
+        POINT_SIZE,
+        _sized(compressed, COMPRESSED_POINT_SIZE, "compressed point"),
+    )
+
+
+def _leaves(leaves: Sequence[bytes]) -> bytes:
+    return b"".join(_point(leaf, "leaf") for leaf in leaves)
+
+
+def merkle_root(leaves: Sequence[bytes], depth: int = MERKLE_TREE_DEPTH) -> bytes:
+    return _call_fixed(
+        _lib.santazk_merkle_root, POINT_SIZE, depth, _leaves(leaves), len(leaves)
+    )
+
+
+def merkle_path(leaves: Sequence[bytes], index: int, depth: int = MERKLE_TREE_DEPTH) -> bytes:
+    """The path of the leaf at ``index`` to the root, for ``Prover.prove_choice``."""
+    return _call_buffer(
+        _lib.santazk_merkle_path, depth, _leaves(leaves), len(leaves), index
+    )
+
+
+def sign_choice(key: Key, chain_id: bytes, choice: bytes) -> bytes:
+    return _call_fixed(
+        _lib.santazk_sign_choice,
+        POINT_SIZE,
+        _point(key.secret_key, "secret key"),
+        _point(key.nullifier, "nullifier"),
+        _chain_id(chain_id),
+        _point(choice, "choice"),
+        _point(key.dh_pub_key, "DH public key"),
+    )
+
+
+def sign_reveal(key: Key, chain_id: bytes, ciphertext_hash: bytes) -> bytes:
+    return _call_fixed(
+        _lib.santazk_sign_reveal,
+        POINT_SIZE,
+        _point(key.secret_key, "secret key"),
+        _point(key.nullifier, "nullifier"),
+        _chain_id(chain_id),
+        _point(ciphertext_hash, "ciphertext hash"),
+        _point(key.dh_pub_key, "DH public key"),
+    )
+
+
+class Prover:
+    """A Groth16 prover of one of the ``CIRCUIT_*``, which holds its proving key.
+
+    Without ``proving_key``, the key compiled into the library is used. Otherwise
+    it is the content of the circuit's ``.groth16.pk`` file, which isn't checked.
+    """
+
+    def __init__(self, circuit: int, proving_key: Optional[bytes] = None):
+        self._prover = _prover()
+        if proving_key is None:
+            _check(_lib.santazk_prover_new(circuit, ctypes.byref(self._prover)))
+        else:
+            _check(
+                _lib.santazk_prover_from_key(
+                    circuit, bytes(proving_key), len(proving_key), ctypes.byref(self._prover)
+                )
+            )
+
+    def __del__(self):
+        if getattr(self, "_prover", None):
+            _lib.santazk_prover_free(self._prover)
+            self._prover = _prover()
+
+    def prove_enter(self, key: Key, chain_id: bytes) -> bytes:
+        return _call_buffer(
+            _lib.santazk_prove_enter,
+            self._prover,
+            _point(key.secret_key, "secret key"),
+            _point(key.nullifier, "nullifier"),
+            _point(key.pub_key, "public key"),
+            _chain_id(chain_id),
+        )
+
+    def prove_choice(
+        self,
+        key: Key,
+        root: bytes,
+        path: bytes,
+        choice: bytes,
+        chain_id: bytes,
+        signature: bytes,
+    ) -> bytes:
+        return _call_buffer(
+            _lib.santazk_prove_choice,
+            self._prover,
+            _point(key.secret_key, "secret key"),
+            _point(key.nullifier, "nullifier"),
+            _point(root, "root"),
+            bytes(path),
+            len(path),
+            _point(choice, "choice"),
+            _chain_id(chain_id),
+            _point(key.dh_pub_key, "DH public key"),
+            _point(signature, "signature"),
+        )
+
+    def prove_reveal(
+        self, key: Key, ciphertext_hash: bytes, chain_id: bytes, signature: bytes
+    ) -> bytes:
+        return _call_buffer(
+            _lib.santazk_prove_reveal,
+            self._prover,
+            _point(key.secret_key, "secret key"),
+            _point(key.nullifier, "nullifier"),
+            _point(key.pub_key, "public key"),
+            _point(ciphertext_hash, "ciphertext hash"),
+            _chain_id(chain_id),
+            _point(key.dh_pub_key, "DH public key"),
+            _point(signature, "signature"),
+        )
+
+
+def _verified(status: int) -> bool:
+    if status == STATUS_INVALID_PROOF:
+        return False
+    _check(status)
+    return True
+
+
+def verify_enter(proof: bytes, pub_key: bytes, chain_id: bytes) -> bool:
+    return _verified(
+        _lib.santazk_verify_enter(
+            bytes(proof), len(proof), _point(pub_key, "public key"), _chain_id(chain_id)
+        )
+    )
+
+
+def verify_choice(
+    proof: bytes,
+    nullifier: bytes,
+    root: bytes,
+    choice: bytes,
+    chain_id: bytes,
+    dh_pub_key: bytes,
+    signature: bytes,
+) -> bool:
+    return _verified(
+        _lib.santazk_verify_choice(
+            bytes(proof),
+            len(proof),
+            _point(nullifier, "nullifier"),
+            _point(root, "root"),
+            _point(choice, "choice"),
+            _chain_id(chain_id),
+            _point(dh_pub_key, "DH public key"),
+            _point(signature, "signature"),
+        )
+    )
+
+
+def verify_reveal(
+    proof: bytes,
+    pub_key: bytes,
+    ciphertext_hash: bytes,
+    chain_id: bytes,
+    dh_pub_key: bytes,
+    signature: bytes,
+) -> bool:
+    return _verified(
+        _lib.santazk_verify_reveal(
+            bytes(proof),
+            len(proof),
+            _point(pub_key, "public key"),
+            _point(ciphertext_hash, "ciphertext hash"),
+            _chain_id(chain_id),
+            _point(dh_pub_key, "DH public key"),
+            _point(signature, "signature"),
+        )
+    )
+
+
+def _sbytes64(value: bytes) -> list:
+    """An ``SBytes64`` field as zkretvm serializes it."""
+    value = _point(value, "field")
+    return [list(value[:32]), list(value[32:])]
+
+
+def _compressed(point: bytes) -> list:
+    return _sbytes64(compress_point(point) + bytes(32))
+
+
+def _transaction(transaction_type: int, *data) -> dict:
+    return {
+        "transaction_type": transaction_type,
+        "version": TX_VERSION_COMPRESSED,
+        "data": list(data),
+    }
+
+
+def enter_transaction(pub_key: bytes, proof: bytes) -> dict:
+    """An ENTER of an open group, like ``Transaction::enter``."""
+    zero = _sbytes64(bytes(POINT_SIZE))
+    return _transaction(1, _compressed(pub_key), zero, zero, zero, list(proof), [])
+
+
+def choice_transaction(
+    choice: bytes, nullifier: bytes, dh_pub_key: bytes, signature: bytes, proof: bytes
+) -> dict:
+    """A CHOICE of ``choice``, like ``Transaction::choice``."""
+    return _transaction(
+        2,
+        _compressed(choice),
+        _sbytes64(nullifier),
+        _sbytes64(dh_pub_key),
+        _compressed(signature),
+        list(proof),
+        [],
+    )
+
+
+def reveal_transaction(
+    pub_key: bytes,
+    ciphertext_hash: bytes,
+    dh_pub_key: bytes,
+    signature: bytes,
+    ciphertext: bytes,
+    proof: bytes,
+) -> dict:
+    """A REVEAL of ``ciphertext``, like ``Transaction::reveal``."""
+    return _transaction(
+        3,
+        _compressed(pub_key),
+        _compressed(ciphertext_hash),
+        _sbytes64(dh_pub_key),
+        _compressed(signature),
+        list(ciphertext),
+        list(proof),
+    )
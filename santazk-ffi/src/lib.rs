@@ -0,0 +1,878 @@
+//! C ABI for what a participant does with santazk: making keys, hashing, Merkle
+//! paths, signing, and proving and verifying ENTER, CHOICE and REVEAL
+//! statements. `include/santazk.h` declares it and `python/santazk.py` wraps it.
+//!
+//! Bytes are in the formats of the fields of zkretvm's `TransactionData`: points,
+//! secret keys, nullifiers and DH public keys are 64 bytes, chain ids are 32, and
+//! CHOICE and REVEAL proofs start with the id of the proof system that made them.
+//!
+//! Functions return a [`SantazkStatus`], and [`santazk_last_error`] describes
+//! the last one that wasn't `SANTAZK_STATUS_OK`. Fixed-size results are written
+//! to caller-allocated arrays, variable-size ones to a [`SantazkBuffer`] that
+//! the caller frees with [`santazk_buffer_free`].
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+
+#[cfg(feature = "marlin")]
+use santazk::proofs::Marlin;
+use santazk::{
+    crypto,
+    hash::Hash,
+    merkle::MerkleTree,
+    participant::Participant,
+    proofs::{
+        proof_system_id, ChoiceAuthProver, ChoiceAuthVerifier, EnterAuthProver, EnterAuthVerifier,
+        Groth16, ProofSystem, RevealAuthProver, RevealAuthVerifier,
+    },
+    serialization::{compress_point, decompress_point, try_deserialize_jub_jub_affine_point},
+};
+
+/// Size of a point, a secret key, a nullifier or a DH public key.
+pub const SANTAZK_POINT_SIZE: usize = 64;
+/// Size of the compressed encoding of a point, as in version 1 transactions.
+pub const SANTAZK_COMPRESSED_POINT_SIZE: usize = 32;
+/// Size of a chain id.
+pub const SANTAZK_CHAIN_ID_SIZE: usize = 32;
+/// Depth of the Merkle tree of the participants of a zkretvm chain.
+pub const SANTAZK_MERKLE_TREE_DEPTH: usize = 7;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SantazkStatus {
+    Ok = 0,
+    /// A pointer is null, an input isn't a valid point or the prover is for
+    /// another circuit.
+    InvalidArgument = 1,
+    /// The proof doesn't verify.
+    InvalidProof = 2,
+    /// santazk panicked, e.g. on a malformed Merkle path.
+    Internal = 3,
+}
+
+/// Bytes allocated by santazk, freed with [`santazk_buffer_free`].
+#[repr(C)]
+pub struct SantazkBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl SantazkBuffer {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        let bytes = bytes.into_boxed_slice();
+        let len = bytes.len();
+        Self {
+            data: Box::into_raw(bytes).cast(),
+            len,
+        }
+    }
+}
+
+/// A participant's secrets and the public key derived from them.
+#[repr(C)]
+pub struct SantazkKey {
+    pub secret_key: [u8; SANTAZK_POINT_SIZE],
+    pub nullifier: [u8; SANTAZK_POINT_SIZE],
+    pub pub_key: [u8; SANTAZK_POINT_SIZE],
+    pub dh_pub_key: [u8; SANTAZK_POINT_SIZE],
+}
+
+/// Circuit a [`SantazkProver`] proves.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SantazkCircuit {
+    Enter = 0,
+    Choice = 1,
+    Reveal = 2,
+}
+
+/// A Groth16 prover of one circuit, which holds its proving key.
+pub enum SantazkProver {
+    Enter(EnterAuthProver),
+    Choice(ChoiceAuthProver),
+    Reveal(RevealAuthProver),
+}
+
+struct Error {
+    status: SantazkStatus,
+    message: String,
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error {
+        status: SantazkStatus::InvalidArgument,
+        message: message.into(),
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, turning its error or panic into a status and the last error.
+fn run(f: impl FnOnce() -> Result<()>) -> SantazkStatus {
+    let result = panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|panic| {
+        let message = panic
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "santazk panicked".to_string());
+        Err(Error {
+            status: SantazkStatus::Internal,
+            message,
+        })
+    });
+
+    let (status, message) = match result {
+        Ok(()) => (SantazkStatus::Ok, None),
+        Err(e) => (e.status, Some(e.message.replace('\0', " "))),
+    };
+    LAST_ERROR.with(|last| {
+        *last.borrow_mut() = message.map(|message| CString::new(message).unwrap());
+    });
+    status
+}
+
+/// Returns the description of the error of the last call on this thread, or null
+/// if it succeeded. The string is valid until the next call on this thread.
+#[no_mangle]
+pub extern "C" fn santazk_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |e| e.as_ptr()))
+}
+
+/// Frees the bytes of `buffer` and empties it. Freeing an empty buffer does nothing.
+///
+/// # Safety
+/// `buffer` must be null or point to a buffer filled by santazk.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_buffer_free(buffer: *mut SantazkBuffer) {
+    let Some(buffer) = buffer.as_mut() else {
+        return;
+    };
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+    buffer.data = ptr::null_mut();
+    buffer.len = 0;
+}
+
+unsafe fn bytes<'a>(data: *const u8, len: usize, name: &str) -> Result<&'a [u8]> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if data.is_null() {
+        return Err(invalid(format!("{name} is null")));
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+/// Reads the 64-byte point `name`, checking that it is on the curve and in the
+/// prime-order subgroup.
+unsafe fn point<'a>(data: *const u8, name: &str) -> Result<&'a [u8]> {
+    let point = bytes(data, SANTAZK_POINT_SIZE, name)?;
+    try_deserialize_jub_jub_affine_point(point)
+        .map(|_| point)
+        .map_err(|_| invalid(format!("invalid {name} point")))
+}
+
+unsafe fn write(out: *mut u8, bytes: &[u8]) -> Result<()> {
+    if out.is_null() {
+        return Err(invalid("output is null"));
+    }
+    ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len());
+    Ok(())
+}
+
+unsafe fn write_buffer(out: *mut SantazkBuffer, bytes: Vec<u8>) -> Result<()> {
+    let out = out.as_mut().ok_or_else(|| invalid("output is null"))?;
+    *out = SantazkBuffer::from_vec(bytes);
+    Ok(())
+}
+
+/// Generates a key like `zkretctl keygen`, from the OS's secure randomness.
+///
+/// # Safety
+/// `out` must point to a writable [`SantazkKey`].
+#[no_mangle]
+pub unsafe extern "C" fn santazk_generate_key(out: *mut SantazkKey) -> SantazkStatus {
+    run(|| {
+        let out = out.as_mut().ok_or_else(|| invalid("output is null"))?;
+        let participant = Participant::random();
+        out.secret_key.copy_from_slice(&participant.secret_key);
+        out.nullifier.copy_from_slice(&participant.nullifier);
+        out.pub_key.copy_from_slice(&participant.pub_key);
+        out.dh_pub_key.copy_from_slice(&participant.dh_pub_key);
+        Ok(())
+    })
+}
+
+/// Writes the public key of `secret_key` and `nullifier` to `out`.
+///
+/// # Safety
+/// `secret_key` and `nullifier` must point to 64 bytes, `out` to 64 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_derive_pub_key(
+    secret_key: *const u8,
+    nullifier: *const u8,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| {
+        let secret_key = bytes(secret_key, SANTAZK_POINT_SIZE, "secret key")?;
+        let nullifier = bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?;
+        write(
+            out,
+            &crypto::derive_participation_pubkey(&Hash::new(), secret_key, nullifier),
+        )
+    })
+}
+
+/// Writes the hash of `input` to `out`, e.g. the ciphertext hash of a REVEAL.
+///
+/// # Safety
+/// `input` must point to `input_len` bytes, `out` to 64 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_hash(
+    input: *const u8,
+    input_len: usize,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| write(out, &Hash::new().h1(bytes(input, input_len, "input")?)))
+}
+
+/// Writes the two-to-one hash of `left` and `right` to `out`.
+///
+/// # Safety
+/// `left` and `right` must point to `left_len` and `right_len` bytes, `out` to
+/// 64 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_hash2(
+    left: *const u8,
+    left_len: usize,
+    right: *const u8,
+    right_len: usize,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| {
+        let left = bytes(left, left_len, "left")?;
+        let right = bytes(right, right_len, "right")?;
+        write(out, &Hash::new().h2(left, right))
+    })
+}
+
+/// Writes the 32-byte compressed encoding of `point` to `out`.
+///
+/// # Safety
+/// `point` must point to 64 bytes, `out` to 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_compress_point(point: *const u8, out: *mut u8) -> SantazkStatus {
+    run(|| {
+        let point = bytes(point, SANTAZK_POINT_SIZE, "point")?;
+        let compressed = compress_point(point).map_err(|_| invalid("invalid point"))?;
+        write(out, &compressed)
+    })
+}
+
+/// Writes the 64-byte encoding of the point whose compressed encoding is
+/// `compressed` to `out`.
+///
+/// # Safety
+/// `compressed` must point to 32 bytes, `out` to 64 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_decompress_point(
+    compressed: *const u8,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| {
+        let compressed = bytes(
+            compressed,
+            SANTAZK_COMPRESSED_POINT_SIZE,
+            "compressed point",
+        )?;
+        let point = decompress_point(compressed).map_err(|_| invalid("invalid point"))?;
+        write(out, &point)
+    })
+}
+
+/// Builds the Merkle tree of depth `depth` over `leaf_count` leaves of 64 bytes.
+unsafe fn merkle_tree(depth: usize, leaves: *const u8, leaf_count: usize) -> Result<MerkleTree> {
+    if depth >= usize::BITS as usize || leaf_count > 1 << depth {
+        return Err(invalid(format!(
+            "a Merkle tree of depth {depth} can't hold {leaf_count} leaves"
+        )));
+    }
+    let leaves = bytes(leaves, leaf_count * SANTAZK_POINT_SIZE, "leaves")?
+        .chunks(SANTAZK_POINT_SIZE)
+        .map(<[u8]>::to_vec)
+        .collect::<Vec<_>>();
+    Ok(MerkleTree::new(depth, &leaves))
+}
+
+/// Writes the root of the Merkle tree of depth `depth` over `leaves` to `out`.
+///
+/// # Safety
+/// `leaves` must point to `leaf_count` concatenated 64-byte leaves, `out` to 64
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_merkle_root(
+    depth: usize,
+    leaves: *const u8,
+    leaf_count: usize,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| write(out, &merkle_tree(depth, leaves, leaf_count)?.root()))
+}
+
+/// Fills `out` with the path from the leaf at `index` to the root of the Merkle
+/// tree of depth `depth` over `leaves`, for [`santazk_prove_choice`].
+///
+/// # Safety
+/// `leaves` must point to `leaf_count` concatenated 64-byte leaves, `out` to a
+/// writable [`SantazkBuffer`].
+#[no_mangle]
+pub unsafe extern "C" fn santazk_merkle_path(
+    depth: usize,
+    leaves: *const u8,
+    leaf_count: usize,
+    index: usize,
+    out: *mut SantazkBuffer,
+) -> SantazkStatus {
+    run(|| {
+        if index >= leaf_count {
+            return Err(invalid(format!("no leaf at index {index}")));
+        }
+        let path = merkle_tree(depth, leaves, leaf_count)?
+            .generate_proof(index)
+            .ok_or_else(|| invalid(format!("no leaf at index {index}")))?;
+        write_buffer(out, path)
+    })
+}
+
+/// Writes the signature of a CHOICE of the public key `choice` to `out`.
+///
+/// # Safety
+/// `chain_id` must point to 32 bytes, the other inputs to 64, and `out` to 64
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_sign_choice(
+    secret_key: *const u8,
+    nullifier: *const u8,
+    chain_id: *const u8,
+    choice: *const u8,
+    dh_pub_key: *const u8,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| {
+        let signature = crypto::sign_choice_tx(
+            &Hash::new(),
+            bytes(secret_key, SANTAZK_POINT_SIZE, "secret key")?,
+            bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?,
+            bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?,
+            bytes(choice, SANTAZK_POINT_SIZE, "choice")?,
+            bytes(dh_pub_key, SANTAZK_POINT_SIZE, "DH public key")?,
+        );
+        write(out, &signature)
+    })
+}
+
+/// Writes the signature of a REVEAL of the ciphertext hashed to
+/// `ciphertext_hash` to `out`.
+///
+/// # Safety
+/// `chain_id` must point to 32 bytes, the other inputs to 64, and `out` to 64
+/// writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_sign_reveal(
+    secret_key: *const u8,
+    nullifier: *const u8,
+    chain_id: *const u8,
+    ciphertext_hash: *const u8,
+    dh_pub_key: *const u8,
+    out: *mut u8,
+) -> SantazkStatus {
+    run(|| {
+        let signature = crypto::sign_reveal_tx(
+            &Hash::new(),
+            bytes(secret_key, SANTAZK_POINT_SIZE, "secret key")?,
+            bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?,
+            bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?,
+            bytes(ciphertext_hash, SANTAZK_POINT_SIZE, "ciphertext hash")?,
+            bytes(dh_pub_key, SANTAZK_POINT_SIZE, "DH public key")?,
+        );
+        write(out, &signature)
+    })
+}
+
+unsafe fn write_prover(out: *mut *mut SantazkProver, prover: SantazkProver) -> Result<()> {
+    let out = out.as_mut().ok_or_else(|| invalid("output is null"))?;
+    *out = Box::into_raw(Box::new(prover));
+    Ok(())
+}
+
+/// Makes a prover of `circuit` with the proving key compiled into santazk, which
+/// takes seconds. Provers are freed with [`santazk_prover_free`].
+///
+/// # Safety
+/// `out` must point to a writable prover pointer.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_prover_new(
+    circuit: SantazkCircuit,
+    out: *mut *mut SantazkProver,
+) -> SantazkStatus {
+    run(|| {
+        let prover = match circuit {
+            SantazkCircuit::Enter => SantazkProver::Enter(EnterAuthProver::new()),
+            SantazkCircuit::Choice => SantazkProver::Choice(ChoiceAuthProver::new()),
+            SantazkCircuit::Reveal => SantazkProver::Reveal(RevealAuthProver::new()),
+        };
+        write_prover(out, prover)
+    })
+}
+
+/// Makes a prover of `circuit` from the bytes of its `.groth16.pk` file. The key
+/// isn't checked, so it must come from a trusted source.
+///
+/// # Safety
+/// `key` must point to `key_len` bytes and `out` to a writable prover pointer.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_prover_from_key(
+    circuit: SantazkCircuit,
+    key: *const u8,
+    key_len: usize,
+    out: *mut *mut SantazkProver,
+) -> SantazkStatus {
+    run(|| {
+        let pk = Groth16::deserialize_proving_key(bytes(key, key_len, "proving key")?)
+            .map_err(|e| invalid(format!("invalid proving key {e}")))?;
+        let prover = match circuit {
+            SantazkCircuit::Enter => SantazkProver::Enter(EnterAuthProver::from_key(pk)),
+            SantazkCircuit::Choice => SantazkProver::Choice(ChoiceAuthProver::from_key(pk)),
+            SantazkCircuit::Reveal => SantazkProver::Reveal(RevealAuthProver::from_key(pk)),
+        };
+        write_prover(out, prover)
+    })
+}
+
+/// Frees a prover made by [`santazk_prover_new`] or [`santazk_prover_from_key`].
+///
+/// # Safety
+/// `prover` must be null or a prover that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_prover_free(prover: *mut SantazkProver) {
+    if !prover.is_null() {
+        drop(Box::from_raw(prover));
+    }
+}
+
+/// Fills `out` with a proof that `pub_key` was derived from `secret_key` and
+/// `nullifier`, for the ENTER of the chain `chain_id`.
+///
+/// # Safety
+/// `prover` must be a live ENTER prover, `chain_id` must point to 32 bytes, the
+/// other inputs to 64, and `out` to a writable [`SantazkBuffer`].
+#[no_mangle]
+pub unsafe extern "C" fn santazk_prove_enter(
+    prover: *const SantazkProver,
+    secret_key: *const u8,
+    nullifier: *const u8,
+    pub_key: *const u8,
+    chain_id: *const u8,
+    out: *mut SantazkBuffer,
+) -> SantazkStatus {
+    run(|| {
+        let Some(SantazkProver::Enter(prover)) = prover.as_ref() else {
+            return Err(invalid("not an ENTER prover"));
+        };
+        let proof = prover.prove(
+            bytes(secret_key, SANTAZK_POINT_SIZE, "secret key")?,
+            bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?,
+            point(pub_key, "public key")?,
+            bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?,
+        );
+        write_buffer(out, proof)
+    })
+}
+
+/// Fills `out` with the proof of a CHOICE of `choice` by a participant whose
+/// public key is in the Merkle tree with root `root`, at `merkle_path`.
+///
+/// # Safety
+/// `prover` must be a live CHOICE prover, `merkle_path` must point to
+/// `merkle_path_len` bytes, `chain_id` to 32 bytes, the other inputs to 64, and
+/// `out` to a writable [`SantazkBuffer`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn santazk_prove_choice(
+    prover: *const SantazkProver,
+    secret_key: *const u8,
+    nullifier: *const u8,
+    root: *const u8,
+    merkle_path: *const u8,
+    merkle_path_len: usize,
+    choice: *const u8,
+    chain_id: *const u8,
+    dh_pub_key: *const u8,
+    signature: *const u8,
+    out: *mut SantazkBuffer,
+) -> SantazkStatus {
+    run(|| {
+        let Some(SantazkProver::Choice(prover)) = prover.as_ref() else {
+            return Err(invalid("not a CHOICE prover"));
+        };
+        let proof = prover
+            .prove(
+                bytes(secret_key, SANTAZK_POINT_SIZE, "secret key")?,
+                bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?,
+                point(root, "root")?,
+                bytes(merkle_path, merkle_path_len, "Merkle path")?,
+                point(choice, "choice")?,
+                bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?,
+                bytes(dh_pub_key, SANTAZK_POINT_SIZE, "DH public key")?,
+                point(signature, "signature")?,
+            )
+            .map_err(|e| invalid(e.to_string()))?;
+        write_buffer(out, proof)
+    })
+}
+
+/// Fills `out` with the proof of a REVEAL by the participant `pub_key` of the
+/// ciphertext hashed to `ciphertext_hash`.
+///
+/// # Safety
+/// `prover` must be a live REVEAL prover, `chain_id` must point to 32 bytes, the
+/// other inputs to 64, and `out` to a writable [`SantazkBuffer`].
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn santazk_prove_reveal(
+    prover: *const SantazkProver,
+    secret_key: *const u8,
+    nullifier: *const u8,
+    pub_key: *const u8,
+    ciphertext_hash: *const u8,
+    chain_id: *const u8,
+    dh_pub_key: *const u8,
+    signature: *const u8,
+    out: *mut SantazkBuffer,
+) -> SantazkStatus {
+    run(|| {
+        let Some(SantazkProver::Reveal(prover)) = prover.as_ref() else {
+            return Err(invalid("not a REVEAL prover"));
+        };
+        let proof = prover.prove(
+            bytes(secret_key, SANTAZK_POINT_SIZE, "secret key")?,
+            bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?,
+            point(pub_key, "public key")?,
+            point(ciphertext_hash, "ciphertext hash")?,
+            bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?,
+            bytes(dh_pub_key, SANTAZK_POINT_SIZE, "DH public key")?,
+            point(signature, "signature")?,
+        );
+        write_buffer(out, proof)
+    })
+}
+
+fn verified(verified: bool) -> Result<()> {
+    if verified {
+        Ok(())
+    } else {
+        Err(Error {
+            status: SantazkStatus::InvalidProof,
+            message: "the proof doesn't verify".to_string(),
+        })
+    }
+}
+
+/// Verifies the proof of an ENTER of `pub_key` on the chain `chain_id`.
+///
+/// # Safety
+/// `proof` must point to `proof_len` bytes, `pub_key` to 64 and `chain_id` to 32.
+#[no_mangle]
+pub unsafe extern "C" fn santazk_verify_enter(
+    proof: *const u8,
+    proof_len: usize,
+    pub_key: *const u8,
+    chain_id: *const u8,
+) -> SantazkStatus {
+    run(|| {
+        verified(EnterAuthVerifier::new().verify(
+            bytes(proof, proof_len, "proof")?,
+            point(pub_key, "public key")?,
+            bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?,
+        ))
+    })
+}
+
+/// Verifies the proof of a CHOICE against the Merkle root `root`, with the proof
+/// system it is tagged with.
+///
+/// # Safety
+/// `proof` must point to `proof_len` bytes, `chain_id` to 32 and the other
+/// inputs to 64.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn santazk_verify_choice(
+    proof: *const u8,
+    proof_len: usize,
+    nullifier: *const u8,
+    root: *const u8,
+    choice: *const u8,
+    chain_id: *const u8,
+    dh_pub_key: *const u8,
+    signature: *const u8,
+) -> SantazkStatus {
+    run(|| {
+        let proof = bytes(proof, proof_len, "proof")?;
+        let nullifier = bytes(nullifier, SANTAZK_POINT_SIZE, "nullifier")?;
+        let root = point(root, "root")?;
+        let choice = point(choice, "choice")?;
+        let chain_id = bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?;
+        let dh_pub_key = bytes(dh_pub_key, SANTAZK_POINT_SIZE, "DH public key")?;
+        let signature = point(signature, "signature")?;
+        verified(match proof_system_id(proof) {
+            Some(Groth16::ID) => ChoiceAuthVerifier::new().verify(
+                proof, nullifier, root, choice, chain_id, dh_pub_key, signature,
+            ),
+            #[cfg(feature = "marlin")]
+            Some(Marlin::ID) => ChoiceAuthVerifier::<Marlin>::embedded().verify(
+                proof, nullifier, root, choice, chain_id, dh_pub_key, signature,
+            ),
+            _ => false,
+        })
+    })
+}
+
+/// Verifies the proof of a REVEAL, with the proof system it is tagged with.
+///
+/// # Safety
+/// `proof` must point to `proof_len` bytes, `chain_id` to 32 and the other
+/// inputs to 64.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn santazk_verify_reveal(
+    proof: *const u8,
+    proof_len: usize,
+    pub_key: *const u8,
+    ciphertext_hash: *const u8,
+    chain_id: *const u8,
+    dh_pub_key: *const u8,
+    signature: *const u8,
+) -> SantazkStatus {
+    run(|| {
+        let proof = bytes(proof, proof_len, "proof")?;
+        let pub_key = point(pub_key, "public key")?;
+        let ct_hash = point(ciphertext_hash, "ciphertext hash")?;
+        let chain_id = bytes(chain_id, SANTAZK_CHAIN_ID_SIZE, "chain id")?;
+        let dh_pub_key = bytes(dh_pub_key, SANTAZK_POINT_SIZE, "DH public key")?;
+        let signature = point(signature, "signature")?;
+        verified(match proof_system_id(proof) {
+            Some(Groth16::ID) => RevealAuthVerifier::new()
+                .verify(proof, pub_key, ct_hash, chain_id, dh_pub_key, signature),
+            #[cfg(feature = "marlin")]
+            Some(Marlin::ID) => RevealAuthVerifier::<Marlin>::embedded()
+                .verify(proof, pub_key, ct_hash, chain_id, dh_pub_key, signature),
+            _ => false,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CStr;
+
+    use super::*;
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(santazk_last_error()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    fn new_key() -> SantazkKey {
+        let mut key = SantazkKey {
+            secret_key: [0; 64],
+            nullifier: [0; 64],
+            pub_key: [0; 64],
+            dh_pub_key: [0; 64],
+        };
+        assert_eq!(unsafe { santazk_generate_key(&mut key) }, SantazkStatus::Ok);
+        key
+    }
+
+    #[test]
+    fn keys_hashes_and_merkle_paths() {
+        let key = new_key();
+        let mut pub_key = [0u8; 64];
+        let status = unsafe {
+            santazk_derive_pub_key(
+                key.secret_key.as_ptr(),
+                key.nullifier.as_ptr(),
+                pub_key.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, SantazkStatus::Ok);
+        assert_eq!(pub_key, key.pub_key);
+        assert!(santazk_last_error().is_null());
+
+        let mut compressed = [0u8; 32];
+        let mut decompressed = [0u8; 64];
+        unsafe {
+            santazk_compress_point(pub_key.as_ptr(), compressed.as_mut_ptr());
+            santazk_decompress_point(compressed.as_ptr(), decompressed.as_mut_ptr());
+        }
+        assert_eq!(decompressed, pub_key);
+
+        let leaves = [key.pub_key, new_key().pub_key].concat();
+        let mut root = [0u8; 64];
+        let status = unsafe {
+            santazk_merkle_root(
+                SANTAZK_MERKLE_TREE_DEPTH,
+                leaves.as_ptr(),
+                2,
+                root.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, SantazkStatus::Ok);
+        assert_eq!(
+            root.to_vec(),
+            MerkleTree::new(7, &[leaves[..64].to_vec(), leaves[64..].to_vec()]).root()
+        );
+
+        let mut path = SantazkBuffer::from_vec(Vec::new());
+        let status = unsafe {
+            santazk_merkle_path(SANTAZK_MERKLE_TREE_DEPTH, leaves.as_ptr(), 2, 2, &mut path)
+        };
+        assert_eq!(status, SantazkStatus::InvalidArgument);
+        assert_eq!(last_error(), "no leaf at index 2");
+
+        let status = unsafe {
+            santazk_merkle_path(SANTAZK_MERKLE_TREE_DEPTH, leaves.as_ptr(), 2, 1, &mut path)
+        };
+        assert_eq!(status, SantazkStatus::Ok);
+        assert!(path.len > 0);
+        unsafe { santazk_buffer_free(&mut path) };
+        assert!(path.data.is_null());
+    }
+
+    #[test]
+    fn invalid_arguments_are_reported() {
+        let key = new_key();
+        let mut signature = [0u8; 64];
+        let status = unsafe {
+            santazk_sign_choice(
+                key.secret_key.as_ptr(),
+                ptr::null(),
+                [4u8; 32].as_ptr(),
+                key.pub_key.as_ptr(),
+                key.dh_pub_key.as_ptr(),
+                signature.as_mut_ptr(),
+            )
+        };
+        assert_eq!(status, SantazkStatus::InvalidArgument);
+        assert_eq!(last_error(), "nullifier is null");
+
+        // the DH public key is random bytes, not a point
+        let status = unsafe {
+            santazk_verify_reveal(
+                [1u8; 100].as_ptr(),
+                100,
+                key.pub_key.as_ptr(),
+                key.dh_pub_key.as_ptr(),
+                [4u8; 32].as_ptr(),
+                key.dh_pub_key.as_ptr(),
+                key.pub_key.as_ptr(),
+            )
+        };
+        assert_eq!(status, SantazkStatus::InvalidArgument);
+        assert_eq!(last_error(), "invalid ciphertext hash point");
+
+        let status = unsafe {
+            santazk_prove_enter(
+                ptr::null(),
+                key.secret_key.as_ptr(),
+                key.nullifier.as_ptr(),
+                key.pub_key.as_ptr(),
+                [4u8; 32].as_ptr(),
+                &mut SantazkBuffer::from_vec(Vec::new()),
+            )
+        };
+        assert_eq!(status, SantazkStatus::InvalidArgument);
+        assert_eq!(last_error(), "not an ENTER prover");
+    }
+
+    #[test]
+    #[ignore = "loads the REVEAL proving key, run with `cargo test --release -- --ignored`"]
+    fn reveal_proofs_verify() {
+        let key = new_key();
+        let chain_id = [4u8; 32];
+        let ct = b"ciphertext";
+        let (mut ct_hash, mut signature) = ([0u8; 64], [0u8; 64]);
+        let mut prover = ptr::null_mut();
+        let mut proof = SantazkBuffer::from_vec(Vec::new());
+        unsafe {
+            santazk_hash(ct.as_ptr(), ct.len(), ct_hash.as_mut_ptr());
+            santazk_sign_reveal(
+                key.secret_key.as_ptr(),
+                key.nullifier.as_ptr(),
+                chain_id.as_ptr(),
+                ct_hash.as_ptr(),
+                key.dh_pub_key.as_ptr(),
+                signature.as_mut_ptr(),
+            );
+            assert_eq!(
+                santazk_prover_new(SantazkCircuit::Reveal, &mut prover),
+                SantazkStatus::Ok
+            );
+            let status = santazk_prove_reveal(
+                prover,
+                key.secret_key.as_ptr(),
+                key.nullifier.as_ptr(),
+                key.pub_key.as_ptr(),
+                ct_hash.as_ptr(),
+                chain_id.as_ptr(),
+                key.dh_pub_key.as_ptr(),
+                signature.as_ptr(),
+                &mut proof,
+            );
+            assert_eq!(status, SantazkStatus::Ok);
+            santazk_prover_free(prover);
+        }
+
+        let verify = |chain_id: [u8; 32]| unsafe {
+            santazk_verify_reveal(
+                proof.data,
+                proof.len,
+                key.pub_key.as_ptr(),
+                ct_hash.as_ptr(),
+                chain_id.as_ptr(),
+                key.dh_pub_key.as_ptr(),
+                signature.as_ptr(),
+            )
+        };
+        assert_eq!(verify(chain_id), SantazkStatus::Ok);
+        assert_eq!(verify([5u8; 32]), SantazkStatus::InvalidProof);
+        unsafe { santazk_buffer_free(&mut proof) };
+    }
+
+    #[test]
+    fn header_is_up_to_date() {
+        let crate_dir = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml")).unwrap();
+        let mut header = Vec::new();
+        cbindgen::generate_with_config(crate_dir, config)
+            .unwrap()
+            .write(&mut header);
+        assert_eq!(
+            include_str!("../include/santazk.h"),
+            String::from_utf8(header).unwrap(),
+            "regenerate with `cbindgen --config santazk-ffi/cbindgen.toml --output santazk-ffi/include/santazk.h santazk-ffi`"
+        );
+    }
+}
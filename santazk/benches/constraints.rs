@@ -0,0 +1,138 @@
+//! Compares the constraint counts of the circuits with the Pedersen hash the
+//! chain uses and with the Poseidon one, and how long synthesizing them takes.
+//!
+//! Run with `cargo bench -p santazk --features poseidon --bench constraints`.
+
+use std::marker::PhantomData;
+use std::time::Instant;
+
+use ark_ed_on_bls12_381::Fq as Fr;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+
+use santazk::{
+    circuits::{ChoiceAuthCircuit, EnterAuthCircuit, InviteEnterAuthCircuit, RevealAuthCircuit},
+    crypto::{
+        derive_invite_commitment, derive_invite_nullifier, derive_participation_pubkey,
+        sign_choice_tx, sign_reveal_tx,
+    },
+    hash::{poseidon::Poseidon, HashConfig, NativeHash, Pedersen},
+    merkle::MerkleTreeOf,
+    participant::{INVITE_TREE_DEPTH, MERKLE_TREE_DEPTH},
+};
+
+/// Prints the constraints and witness variables of a satisfied `circuit`.
+fn measure(name: &str, hash: &str, circuit: impl ConstraintSynthesizer<Fr>) {
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    let start = Instant::now();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    let elapsed = start.elapsed();
+    assert!(cs.is_satisfied().unwrap());
+    println!(
+        "{:<18} {:<10} {:>12} {:>12} {:>10.2?}",
+        name,
+        hash,
+        cs.num_constraints(),
+        cs.num_witness_variables(),
+        elapsed
+    );
+}
+
+/// Measures the four circuits with the hash `H`, named `hash`, with inputs of the
+/// sizes the VM uses.
+fn measure_circuits<H: HashConfig>(hash: &str) {
+    let hasher = H::hash();
+    let (secret_key, nullifier) = (vec![1u8; 64], vec![2u8; 64]);
+    let (chain_id, dh_pub_key) = (vec![4u8; 32], vec![5u8; 64]);
+    let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+
+    measure(
+        "enter",
+        hash,
+        EnterAuthCircuit::<H> {
+            secret_key: secret_key.clone(),
+            nullifier: nullifier.clone(),
+            pub_key: pub_key.clone(),
+            chain_id: chain_id.clone(),
+            hash: PhantomData,
+        },
+    );
+
+    let (invite_secret, invite_salt) = (vec![6u8; 32], vec![7u8; 32]);
+    let invite = derive_invite_commitment(&hasher, &invite_secret, &invite_salt);
+    let invites = MerkleTreeOf::<H>::new(INVITE_TREE_DEPTH, &[invite]);
+    measure(
+        "invite enter",
+        hash,
+        InviteEnterAuthCircuit::<H> {
+            secret_key: secret_key.clone(),
+            nullifier: nullifier.clone(),
+            pub_key: pub_key.clone(),
+            chain_id: chain_id.clone(),
+            invite_nullifier: derive_invite_nullifier(&hasher, &invite_secret),
+            invite_secret,
+            invite_salt,
+            invite_root: H::deserialize_digest(&invites.root()).unwrap(),
+            invite_path: MerkleTreeOf::<H>::deserialize_path(&invites.generate_proof(0).unwrap())
+                .unwrap(),
+        },
+    );
+
+    let choice = vec![3u8; H::DIGEST_SIZE];
+    let participants = MerkleTreeOf::<H>::new(MERKLE_TREE_DEPTH, std::slice::from_ref(&pub_key));
+    measure(
+        "choice",
+        hash,
+        ChoiceAuthCircuit::<H> {
+            secret_key: secret_key.clone(),
+            nullifier: nullifier.clone(),
+            root: H::deserialize_digest(&participants.root()).unwrap(),
+            merkle_path: MerkleTreeOf::<H>::deserialize_path(
+                &participants.generate_proof(0).unwrap(),
+            )
+            .unwrap(),
+            signature: sign_choice_tx(
+                &hasher,
+                &secret_key,
+                &nullifier,
+                &chain_id,
+                &choice,
+                &dh_pub_key,
+            ),
+            choice,
+            chain_id: chain_id.clone(),
+            dh_pub_key: dh_pub_key.clone(),
+        },
+    );
+
+    let ciphertext_hash = hasher.h1(b"ciphertext");
+    measure(
+        "reveal",
+        hash,
+        RevealAuthCircuit::<H> {
+            signature: sign_reveal_tx(
+                &hasher,
+                &secret_key,
+                &nullifier,
+                &chain_id,
+                &ciphertext_hash,
+                &dh_pub_key,
+            ),
+            secret_key,
+            nullifier,
+            pub_key,
+            ciphertext_hash,
+            chain_id,
+            dh_pub_key,
+            hash: PhantomData,
+        },
+    );
+}
+
+fn main() {
+    println!(
+        "{:<18} {:<10} {:>12} {:>12} {:>10}",
+        "circuit", "hash", "constraints", "witnesses", "synthesis"
+    );
+    measure_circuits::<Pedersen>("pedersen");
+    measure_circuits::<Poseidon>("poseidon");
+}
@@ -0,0 +1,117 @@
+//! Times the stages of making CHOICE and REVEAL proofs with the embedded Groth16
+//! keys: loading the proving key, computing the witness and computing the proof.
+//!
+//! Run with `cargo bench -p santazk --bench proving`, and add
+//! `--no-default-features` to compare with single-threaded proving.
+
+use std::time::{Duration, Instant};
+
+use santazk::{
+    crypto::{derive_participation_pubkey, sign_choice_tx, sign_reveal_tx},
+    hash::Hash,
+    merkle::MerkleTree,
+    proofs::{ChoiceAuthProver, ProvingStage, RevealAuthProver},
+};
+
+const MERKLE_TREE_DEPTH: usize = 7;
+const RUNS: u32 = 3;
+
+/// Runs `prove` and returns how long its witness and proof stages took.
+fn time_stages(prove: impl FnOnce(&mut dyn FnMut(ProvingStage))) -> (Duration, Duration) {
+    let mut starts = Vec::new();
+    prove(&mut |stage| starts.push((stage, Instant::now())));
+    let end = Instant::now();
+
+    let start_of = |stage| starts.iter().find(|(s, _)| *s == stage).unwrap().1;
+    let witness = start_of(ProvingStage::Proof) - start_of(ProvingStage::Witness);
+    (witness, end - start_of(ProvingStage::Proof))
+}
+
+/// Times `load` once and the stages of `prove` over [`RUNS`] runs.
+fn bench<P>(
+    name: &str,
+    load: impl FnOnce() -> P,
+    prove: impl Fn(&P, &mut dyn FnMut(ProvingStage)),
+) {
+    let start = Instant::now();
+    let prover = load();
+    let load_time = start.elapsed();
+
+    let (mut witness_time, mut proof_time) = (Duration::ZERO, Duration::ZERO);
+    for _ in 0..RUNS {
+        let (witness, proof) = time_stages(|progress| prove(&prover, progress));
+        witness_time += witness;
+        proof_time += proof;
+    }
+
+    println!(
+        "{:<10} {:>10.2?} {:>10.2?} {:>10.2?}",
+        name,
+        load_time,
+        witness_time / RUNS,
+        proof_time / RUNS
+    );
+}
+
+fn main() {
+    let hasher = Hash::new();
+    let (secret_key, nullifier) = ([1u8; 64], [2u8; 64]);
+    let chain_id = [4u8; 32];
+    let dh_pub_key = [5u8; 64];
+    let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+    let choice = derive_participation_pubkey(&hasher, &[3u8; 64], &[4u8; 64]);
+
+    let mt = MerkleTree::new(MERKLE_TREE_DEPTH, &[pub_key.clone(), choice.clone()]);
+    let root = mt.root();
+    let merkle_path = mt.generate_proof(0).unwrap();
+    let choice_signature = sign_choice_tx(
+        &hasher,
+        &secret_key,
+        &nullifier,
+        &chain_id,
+        &choice,
+        &dh_pub_key,
+    );
+
+    let ct_hash = hasher.h1(b"ciphertext");
+    let reveal_signature = sign_reveal_tx(
+        &hasher,
+        &secret_key,
+        &nullifier,
+        &chain_id,
+        &ct_hash,
+        &dh_pub_key,
+    );
+
+    println!(
+        "{:<10} {:>10} {:>10} {:>10}",
+        "statement", "key load", "witness", "proof"
+    );
+    bench("choice", ChoiceAuthProver::new, |prover, progress| {
+        prover
+            .prove_with_progress(
+                &secret_key,
+                &nullifier,
+                &root,
+                &merkle_path,
+                &choice,
+                &chain_id,
+                &dh_pub_key,
+                &choice_signature,
+                progress,
+            )
+            .unwrap();
+    });
+    bench("reveal", RevealAuthProver::new, |prover, progress| {
+        prover.prove_with_progress(
+            &secret_key,
+            &nullifier,
+            &pub_key,
+            &ct_hash,
+            &chain_id,
+            &dh_pub_key,
+            &reveal_signature,
+            progress,
+        );
+    });
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: Apache-2.0
+// Generated by `cargo run -p santazk --bin santazkparams -- solidity`, do not edit.
+pragma solidity ^0.8.24;
+
+/// @notice Checks Groth16 proofs over BLS12-381 against a fixed verifying key,
+/// with the precompiles of EIP-2537. Proofs and inputs are encoded by
+/// `santazk::proofs::solidity`.
+contract ChoiceAuthVerifier {
+    /// Order of the BLS12-381 scalar field, which every public input is below.
+    uint256 internal constant R = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001;
+    address internal constant BLS12_G1MSM = address(0x0c);
+    address internal constant BLS12_PAIRING_CHECK = address(0x0f);
+
+    // The verifying key, with alpha, gamma and delta negated.
+    bytes internal constant ALPHA_NEG = hex"000000000000000000000000000000000c02d625af634f5e0d09e04b8d487de3916e42d1b1cc922cfbfc0df54addc5974a643898905612bd26531dabc5c4ea2c00000000000000000000000000000000058ed082f9cb5037de76491b0648aa61d0d4cd8bbee8a21220529078298d561188bb287fa05f6b8a93c7a9a8202197f5";
+    bytes internal constant BETA = hex"0000000000000000000000000000000012c5d29459eda0a4888922afc22adb4ac1c54ec9dcc14ad578cf7e0112e2bddda49f1f0d935086c34157997b4181a0c00000000000000000000000000000000012b3f4aea2495f1b68857f76f00cd287667ac8a2b269498ca9a6859dc5655f7fe37332d8eff84837b62dbc7f5fe30d95000000000000000000000000000000000301779daa31c61c07a34940ebf6334628270ef3f02afde82be941115fdeead5c6fb4dcda441e0d2fef505d788203bbe000000000000000000000000000000000fff40ca2f1f86d19406853a843830ae50070c8c12a1b5dc10bf1912d689254d53179e9d3720a539aa74d05a0c5b5d7b";
+    bytes internal constant GAMMA_NEG = hex"0000000000000000000000000000000013c80457214dafd7e82b3960d8dabb8d579329b1b1d4584d1f6f88ca8decfb203a3dd6747c53a707cf189d4e10e1098a000000000000000000000000000000000a3d02a1539ff2ae5d210f94560a9130e7732945fbe315dfbac595bfec193c2937f32edfd5dd252c7b0d9f69f59a24910000000000000000000000000000000007bed2ccebda9cf754a6fa57d50c6bd09433851b338765bd003155ed04236698969c2a3489e595a57b01d34200dbf997000000000000000000000000000000000602ba076b31cb3c00ed138fb0b4f2f304d21b2fd79d9f111549c55ed70c5046b8ec2d01e8e05a27926df5963491a0c6";
+    bytes internal constant DELTA_NEG = hex"0000000000000000000000000000000007ee7cea2a956333fb6ef6c210d5fd6cdb27e6414dd50616bd6e328715a1075a07f8ab7d255df991a07f1d0fb637ec0100000000000000000000000000000000021b7d038d0ac69736fc9a080fdb5d869257721da1ba4855cc81baa81127dcbb6264abb7a6cae6b36e61abcc648e64340000000000000000000000000000000019494a1a898f13f66bde76f2d5cbbec89f8c67d8a2459f65f8b58464b719755bd4c0d7f1376976cdd1192dbaf52e8315000000000000000000000000000000000e650a8b0610a1fe6d3629ab33b513e73542666f031190038e5180f1403e1cc7c1223575c831948519c9c05824f83b78";
+    // The G1 points the public inputs are multiplied with, the first one by 1.
+    bytes internal constant IC =
+        hex"000000000000000000000000000000001700ff97620fffdd7e8e9b1b5c7c702b76c7bcaee6be392cdad21da3a9e10b58e51667cce93ba1b067b771d30f37a47b0000000000000000000000000000000017d891ccfa031657da9991ccf7d5030055a659fdd3a225a657f000d23d5475878c4afd561dd84c84e7292ae26ea8d1cf"
+        hex"000000000000000000000000000000000c03028f6a076fa19f6790de91ba2d4b82b415ca12e31a2fbcac77bfca84b93bc559d26f57943e574ef13eb845deed7400000000000000000000000000000000130da0bb8425f38a90a155e936deaf1ff039b59580ac2f24db073b36fa7dd7578ae6d2805b895c6d99ea686d16fece85"
+        hex"0000000000000000000000000000000001b8e9d2f75a7ccf880ee96d79e396e48b11f3022f3d215f72c1db879df7bb04cb6f60d6d53545bad18ba3279fb2f95c00000000000000000000000000000000077c3c9b41c7bd648a5221e6a4811ee808807c7f5eb12eebad2a43f62888c6730262ade206fe626d3996e25933f55245"
+        hex"0000000000000000000000000000000010d7b344c09f39230db81bebf55d7cef470c1cb4ce310424653a66239cc261ef637be281368cebaa999f28d9d45292a40000000000000000000000000000000019eb4138b79b45aa9d0b79b3bc10364c8bf8bd682b40176795b99670e765e334e3387a9420bc9bf5243cf56aade5974d"
+        hex"0000000000000000000000000000000009a68424a949bdcf8e7afc97f011e86b115a13f3862351148120ddcbb0cfb91c64625bf438e70b70a3efdcdef43fc5fd000000000000000000000000000000000f01060851761963cebd6ba23d2ec6ec41916f7d113ef07083462629019a17599b8a62299ac5e14fa4275ae2f164e483"
+        hex"000000000000000000000000000000000a9cc1ba5d2c6163c52ff87baa94c1b0fd9b0ebaea8f6d29f5f2232dccc660eae6eae63bb4354b9c0b3e641c561979550000000000000000000000000000000014fa68f7caf35711b92f53d1e6fb1925606debaf4523c9d72a415fdb447d3a8a5c80823e1411aa31613b567a925ec510"
+        hex"000000000000000000000000000000000397480a0f573b0af503471f19e0e7024f3e3ebbe15f77b96b3f4d5f8b085b9b907af67d327f78c175607da6b740808c000000000000000000000000000000000ddcfffa0562850d3bf23582f31f69f5b8e7caaa821162ce315353e6aadcf7f70e311633b3d37df7f2cf1ebca2e2d000"
+        hex"000000000000000000000000000000001756300e44f8bba03d4ec5355aa1eeb16ab5528b9ead0b38c0a93b31fa69534f14cffefcbe74c94e16908c3ce8bdec2b000000000000000000000000000000000f043af3be623a622f2b38d218f7096f2e73a28ca7700f86c4d324dfa147b2a5412bbac16da42aed3f84038bd030c5d4"
+        hex"0000000000000000000000000000000003e647da19c62ed9a332c3059fa17f67d0a7b35367290c120784b0ccd993a1445031a2b1077dd6f5af7bc2799a9574df000000000000000000000000000000001460704a6920ee47a837fcd239140ed1224b48ff58ec0c291172423e77b30b2fa6bab934b29322bd3cf37cdd59767c3c"
+        hex"000000000000000000000000000000000d6ace0ac8a099662f614c69052a35df78c3ef48e62c5a9f24ff44271415332a6eb89273841b10bddf36d4f17251773b000000000000000000000000000000000714939e90feffecb6dccf65dc2b649c733b4bd65b9a55b3ae14a523b5014bafc7f866d03bc01e7728022e03b3208a1c"
+        hex"0000000000000000000000000000000009d760bec3e944739bd30cc7be3aef9597d1e4bf8cadccec6391fd6a71387b2f05352c82f45071812bccc57d7cdf22ec00000000000000000000000000000000097dd93b6d3aa403e336a6237f94d32352d2e71dae74f83389af285f3ed9802318be5a1a7f9ff38bf0adbabcf3e87ef1"
+        hex"000000000000000000000000000000000f99712fce762d1edbc3cdaf7217dd6c9653b527f211965eadf596f5179e77e10011f2415ae47224514965e2ef019ca500000000000000000000000000000000068c118045ef61b3049ae3a7c3c1806881163c00012a948f198955c81171ce43d486df90c930a4db10ea6d2336e8e707"
+        hex"000000000000000000000000000000000071ca1fd3d466602b12d535c7768e9dc3fbcdb280229c36e7206a9861056e05385e824b3646990cc9a01d82e8f4c8780000000000000000000000000000000012cafdada214184a2a52d61ff81f4de2466ac8c52f12e0771468204fbe5cb1706b4ad7d21426ecc949ad162748a1d339"
+        hex"000000000000000000000000000000000f7aab0142af40d3f5817dc67ebff63e5296e3588c29541d5c73626cdfe5e817d3420f1e145bd42d9090798fde88842d00000000000000000000000000000000045ae1f023ea25c94ca26dd5c1e45172e4fbf92fa03edd84e664bbca1a010c168ea44e365692c856caa4401ff6907100"
+        hex"0000000000000000000000000000000001c9a588990a13e1d7d62b0c360db9fa40080fea820be7762255833d3efffb955fc4b7dcbc14473c7480adf285e18e0e0000000000000000000000000000000011a4284b4116ea284283183ecf30bffff61a888475e66428577778d4ab8cce3b09fe0b83e91c3a2dd15b73d60ac02cbd"
+        hex"00000000000000000000000000000000091f8f6fa1fa60b2bd9d23646527ec2bb5172774ed45c50fefe8ade52cda78aa62341d38e7a7925a5cee5f7edc593ecf00000000000000000000000000000000144c338980aa3684cce0c25fdae30a18fd9eb499f8fc9073e82f6bb6d07ef9a1697112a9fe51da6305ce64caf5582e50"
+        hex"000000000000000000000000000000000c5510be61c70423c2db9798cd37b1f4590fd488d2fb0d8ecf448721fd8b2202a3b664f25e64305073eae6b51ab6774a000000000000000000000000000000000f97d6f1bb8b0fcee1552264e883c9c13816e651a240bf95e38daececf227180e741c567350ecc5c5651490aaebc2c75";
+
+    /// @param proof The points A, B and C of the proof.
+    /// @param input The public inputs.
+    /// @return Whether the proof is valid for the inputs.
+    function verifyProof(bytes calldata proof, uint256[16] calldata input) external view returns (bool) {
+        if (proof.length != 512) {
+            return false;
+        }
+
+        // vk_x = IC[0] + input[0] IC[1] + ... + input[15] IC[16]
+        bytes memory ic = IC;
+        bytes memory msm = new bytes(160 * 17);
+        for (uint256 i = 0; i < 17; i++) {
+            uint256 scalar = i == 0 ? 1 : input[i - 1];
+            if (scalar >= R) {
+                return false;
+            }
+            assembly ("memory-safe") {
+                let src := add(add(ic, 32), mul(i, 128))
+                let dst := add(add(msm, 32), mul(i, 160))
+                mstore(dst, mload(src))
+                mstore(add(dst, 32), mload(add(src, 32)))
+                mstore(add(dst, 64), mload(add(src, 64)))
+                mstore(add(dst, 96), mload(add(src, 96)))
+                mstore(add(dst, 128), scalar)
+            }
+        }
+        (bool ok, bytes memory vkX) = BLS12_G1MSM.staticcall(msm);
+        if (!ok || vkX.length != 128) {
+            return false;
+        }
+
+        // e(A, B) e(-alpha, beta) e(vk_x, -gamma) e(C, -delta) == 1
+        bytes memory pairs = abi.encodePacked(
+            proof[0:384], ALPHA_NEG, BETA, vkX, GAMMA_NEG, proof[384:512], DELTA_NEG
+        );
+        bytes memory result;
+        (ok, result) = BLS12_PAIRING_CHECK.staticcall(pairs);
+        return ok && result.length == 32 && abi.decode(result, (uint256)) == 1;
+    }
+}
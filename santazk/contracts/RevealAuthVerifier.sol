@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+// Generated by `cargo run -p santazk --bin santazkparams -- solidity`, do not edit.
+pragma solidity ^0.8.24;
+
+/// @notice Checks Groth16 proofs over BLS12-381 against a fixed verifying key,
+/// with the precompiles of EIP-2537. Proofs and inputs are encoded by
+/// `santazk::proofs::solidity`.
+contract RevealAuthVerifier {
+    /// Order of the BLS12-381 scalar field, which every public input is below.
+    uint256 internal constant R = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001;
+    address internal constant BLS12_G1MSM = address(0x0c);
+    address internal constant BLS12_PAIRING_CHECK = address(0x0f);
+
+    // The verifying key, with alpha, gamma and delta negated.
+    bytes internal constant ALPHA_NEG = hex"000000000000000000000000000000000e42387d1a25cac59b8aa2910de462cc3a470a2e7fd462a367f575b3e371eca6bcd591c6fa5c78750992aac78bf763b00000000000000000000000000000000013b5aa8c007a7742e1fdd45a1128be2338963afcda38b29bf33445ec102111365881dd085d46ff8b82bb36836a9606e6";
+    bytes internal constant BETA = hex"0000000000000000000000000000000012c7613eb9eed03f1155c92870f43cd845f07d388fbfb8c82eb8c7c617fbccb9b1d462cabd1f20ecf773b1e30e3ffd97000000000000000000000000000000000b67c859eaa5054bc51bb520147487cf57c05ecab2a8c0cc53003dcefc848c5a37567520eafae2af6887b8ef33a2db200000000000000000000000000000000006673f86d390c271e7a1d4463bd54273ca17622fb654d46b72924e3c6fe8546d6de2d3b2998dc9f1f3bd3efb0563b7c30000000000000000000000000000000014bb7bd60a2744aa3ae58bfeb331145b41841f7f23608296db1dae324cf04785242d809fbf5b1f067c6e9b9b98fe336d";
+    bytes internal constant GAMMA_NEG = hex"00000000000000000000000000000000130e2e41d5dc0f787a1196a1cef0167ea8ed7d3b7d5821f3c78fcbd2db4c05e77d81a96f4cdd960450be5004efd563860000000000000000000000000000000014eec9bff539fefbf59beb7f044d75a168573877b35de6117051458a1c96413b85c1174fb3550432cd6936099cd25f0f0000000000000000000000000000000003644b64df6e85449c8662ef5a13318e318190d46552c0d7f6def682e7ad337f96407adee24927c45060f9e2f123f5c10000000000000000000000000000000008c3322ab29229ae6a36e8ae739cd643b2608206668eb6957d6e169b0f60b65778f55cfda2dac19be44a1b02b0241d00";
+    bytes internal constant DELTA_NEG = hex"0000000000000000000000000000000008e6f8bad2197bd3c5fee509acdd0454371d00641ee92f8439cd5aeea73df5548a722aada468323b88084e5b471118ef0000000000000000000000000000000008a7c7de0eebe858a71d27edbfb62eaf2143a057bb7d338621e41beb1633036da50e44319f676ee1df555533f6cf6ba200000000000000000000000000000000051cce3a837e4607dbf11b0ebd2fb39478fdc436023e37ace39ba077612f43e2eedea2944d2d1d1a13673f8b79ba58220000000000000000000000000000000000080f4ac909b1ee53939d620603eb37088da4cd55203854c4cff0b50bfe63546593de95cf850ebd05e77b64ee2000c9";
+    // The G1 points the public inputs are multiplied with, the first one by 1.
+    bytes internal constant IC =
+        hex"00000000000000000000000000000000051f0ec9314296c96e1eceb8b4b81f7e28ef89200eb53ae3736a6964178c6cfec0d0b858a1d114b38742844e0c6c9e29000000000000000000000000000000000a4a6c9fd73e638aa26ce1e6ce603b5f81e251a5e65e6b2f121cfcdd224235967a6abee4de85dd23e9ae505c50620f14"
+        hex"000000000000000000000000000000000ac72a90805e06ffb94948361470ffa901156d50df6960073595cc8d9cbf7eb30094929767d26ea7613222f0c13cb025000000000000000000000000000000000cc63f3f2245c3403aa37c641351e1be37753df20a16e59a03c1bd10212bb75a145e2af2b90b54674fa744a3633e9021"
+        hex"000000000000000000000000000000000c96a8887c056a9e59e2b7f464514e2dfd24e5d0ea2bb110793252e80125efb20a05852915d7409ed6beaf131cd1581a0000000000000000000000000000000001e8849669f4c26122e2784bbed55d6ef0369a6fb78699a025a0f6b1b6ccd319173c033fdf2767c9a3e7e0a1796cfe11"
+        hex"000000000000000000000000000000000ce597138a3387f6e01efec0cc160552b177813dafe65cc047f49f66e9ffd32a37f26a8c370b6ad2864727575278a4b80000000000000000000000000000000002a78f2b608f911ed980ded21aeedfbfcee1a92e10e984f3a0ea42754a98e25dd897abe8e1d9228274d6887bf92cb54d"
+        hex"0000000000000000000000000000000013291c38f916beae108ffc7dbacb9f9640114ccf3d5419c125f94719491ed9adfcde360f20e1fded45fcf3bb78a100a20000000000000000000000000000000018108e1fac774b9283cedce4d913a3e72d126585d30fcc985e4c5bb9b886f901187496b310625f794f67bf6889cac5be"
+        hex"000000000000000000000000000000000f3570664329305c81430c9846d387489cb92fa9769d76dc6a1a3adc961dde3bb355e62b22f12f8827a8e03da0b2b9c00000000000000000000000000000000006e0216f8ca83f341499036cf73a1c9d27c82102f1f96df8d1cedea0f284c6800d54878e99508ca042b54cc74efa38d7"
+        hex"000000000000000000000000000000000968888c3849624f0a1ac81ec40567824ff747a0cc741b4df1fd4a84e28d96b927fb466a0517ed456a41047d207d08a500000000000000000000000000000000095377c0adaa9fa5641f909d049473c87b128eee6994ca3f9bc5f108e973c62a62a019ae463db9478e0e6a0888f55ea1"
+        hex"000000000000000000000000000000000a8ecd609a7cf62a6016d751fe583f3b712dd41cb3e053859d06f54998f6aeb343adf94ebee46aff9550adb0f0f95c4300000000000000000000000000000000014a9c357e05b3fb405fe32d9ea7bb5c617159540c506a8fb1cab142f042624f953ab52fc8429a0140e47309b09e8ca2"
+        hex"0000000000000000000000000000000017512b3454dceeae8f5bb298fb36ddb2d7ad49600c5ae679664ae7d48ea90fded415338fe89d80b4fbe9ff5599ab075c0000000000000000000000000000000007f410031b64a86082f53102b4fc5c38e2992d6ee43bbe795c003d6b4c763a5d21241731f3a93d25e7b4ee12623968dd"
+        hex"00000000000000000000000000000000140025c32910706d73075b5994d39410baf6605db35f9192b53a753e9f0f1033e5c44f586f741bb6478a2e17044b56b10000000000000000000000000000000003bfb213298cf0afb50dfebdd47acc9c68b062fa360ee6e24ab202262b894dbd38c5936ae41263492f3da0fbaeb2914d"
+        hex"000000000000000000000000000000000f6de8d8fc4943a9b63382d98262028cc75baad38d080554b649a78014a5c6a0e63eedbda57744185c2f7f7c64b913d9000000000000000000000000000000000aad65267a0dada94e2104709528e674574c207ca936f9dd606db50ec1ce1125a6535c16b469dc2a75d18ac3b86c5c32"
+        hex"000000000000000000000000000000000a0affe33f8ac0c6cb595816a396d509b45eed2fad7247ab6ad9146d9a0993597d4662b62453aeabfa3107ef3f0fc54b00000000000000000000000000000000023337472143dcb43455e9c3137e0ae98ccd4598ea0aaa06d4390e6e22ac9903919682315a9c9cc71d57ab1bf179c93a"
+        hex"00000000000000000000000000000000061aef05382bf4e2b9b8f5f44fbff74c08d26b4ea34f4838b1e8a18e7d2b5df0bf4a07bd1eb62b566a308a4cb1fb92de0000000000000000000000000000000011cf66807ede1ec1e75a52dfcf5197bf652f596232c26ae56feddc04e39dd0f20b209c419c37bbcda84fd1c233f682e9"
+        hex"0000000000000000000000000000000019833859432d5bd6f99f03fc0814d1c64c53d0ee814a427c37119a8679a439c22030b670e3d21420de9a77715f866c52000000000000000000000000000000001158f96a670a561b95f0b2209a82fd3e36c85a042913e87cf4ae54b7c6fe4c2f0428d3ff2b73fcbaf08ab6cb0656c65a"
+        hex"0000000000000000000000000000000014adc1c94b53877f86ac99819bc4bc420b57f650073e52b0a91b779ef89d596be38ff3245d66448674f7c671295352f10000000000000000000000000000000005c4e6f1a31ac90a67fbebaf44fa66b79e1f253026f6d7c16e1ef2e7ad92d45bce53845439933c92e6e7227f405351f3";
+
+    /// @param proof The points A, B and C of the proof.
+    /// @param input The public inputs.
+    /// @return Whether the proof is valid for the inputs.
+    function verifyProof(bytes calldata proof, uint256[14] calldata input) external view returns (bool) {
+        if (proof.length != 512) {
+            return false;
+        }
+
+        // vk_x = IC[0] + input[0] IC[1] + ... + input[13] IC[14]
+        bytes memory ic = IC;
+        bytes memory msm = new bytes(160 * 15);
+        for (uint256 i = 0; i < 15; i++) {
+            uint256 scalar = i == 0 ? 1 : input[i - 1];
+            if (scalar >= R) {
+                return false;
+            }
+            assembly ("memory-safe") {
+                let src := add(add(ic, 32), mul(i, 128))
+                let dst := add(add(msm, 32), mul(i, 160))
+                mstore(dst, mload(src))
+                mstore(add(dst, 32), mload(add(src, 32)))
+                mstore(add(dst, 64), mload(add(src, 64)))
+                mstore(add(dst, 96), mload(add(src, 96)))
+                mstore(add(dst, 128), scalar)
+            }
+        }
+        (bool ok, bytes memory vkX) = BLS12_G1MSM.staticcall(msm);
+        if (!ok || vkX.length != 128) {
+            return false;
+        }
+
+        // e(A, B) e(-alpha, beta) e(vk_x, -gamma) e(C, -delta) == 1
+        bytes memory pairs = abi.encodePacked(
+            proof[0:384], ALPHA_NEG, BETA, vkX, GAMMA_NEG, proof[384:512], DELTA_NEG
+        );
+        bytes memory result;
+        (ok, result) = BLS12_PAIRING_CHECK.staticcall(pairs);
+        return ok && result.length == 32 && abi.decode(result, (uint256)) == 1;
+    }
+}
@@ -0,0 +1,196 @@
+//! Regenerates or checks the Pedersen generators embedded in
+//! `santazk/src/hash/pedersen_params.rs` from their public seeds, and generates
+//! the Marlin universal SRS and the verifying keys indexed from it, the Groth16
+//! keys of the Poseidon circuits, and the Solidity verifiers of the Groth16 keys
+//! in `santazk/contracts/`.
+//!
+//! ```sh
+//! cargo run -p santazk --bin santazkparams -- generate > santazk/src/hash/pedersen_params.rs
+//! cargo run -p santazk --bin santazkparams -- check
+//! cargo run -p santazk --release --features marlin --bin santazkparams -- marlin-setup
+//! cargo run -p santazk --release --features marlin --bin santazkparams -- marlin-index
+//! cargo run -p santazk --release --no-default-features --features parallel,poseidon --bin santazkparams -- poseidon-setup
+//! cargo run -p santazk --bin santazkparams -- solidity
+//! ```
+
+use std::{fs, path::Path, process::ExitCode};
+
+#[cfg(feature = "marlin")]
+use santazk::proofs::marlin::{Marlin, MARLIN_SRS_PATH, SRS_MAX_CONSTRAINTS, SRS_MAX_NON_ZERO};
+#[cfg(any(feature = "marlin", feature = "poseidon"))]
+use santazk::proofs::{ChoiceAuthProver, RevealAuthProver};
+#[cfg(feature = "poseidon")]
+use santazk::{
+    hash::poseidon::Poseidon,
+    proofs::{EnterAuthProver, Groth16, InviteEnterAuthProver, ProofSystem},
+};
+use santazk::{
+    hash::{
+        common::{H1_PEDERSEN_SEED, H2_PEDERSEN_SEED},
+        pedersen_params::{H1_PEDERSEN_PARAMS_BYTES, H2_PEDERSEN_PARAMS_BYTES},
+        serialization::{check_pedersen_params, generate_pedersen_params},
+    },
+    proofs::solidity::{choice_verifier_contract, reveal_verifier_contract},
+};
+
+/// The depth the VM's Merkle tree of participants has.
+#[cfg(feature = "marlin")]
+const MERKLE_TREE_DEPTH: usize = 7;
+
+fn main() -> ExitCode {
+    match std::env::args().nth(1).as_deref() {
+        Some("generate") => {
+            println!("// Generated by `cargo run -p santazk --bin santazkparams -- generate`, do not edit.");
+            println!("// The generators are derived from H1_PEDERSEN_SEED and H2_PEDERSEN_SEED.\n");
+            println!("{}", generate_pedersen_params("H1", H1_PEDERSEN_SEED));
+            print!("{}", generate_pedersen_params("H2", H2_PEDERSEN_SEED));
+            ExitCode::SUCCESS
+        }
+        Some("check") => {
+            let mut ok = true;
+            for (name, matches) in [
+                (
+                    "H1",
+                    check_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES, H1_PEDERSEN_SEED),
+                ),
+                (
+                    "H2",
+                    check_pedersen_params(&H2_PEDERSEN_PARAMS_BYTES, H2_PEDERSEN_SEED),
+                ),
+            ] {
+                if matches {
+                    println!("{name} generators match their seed");
+                } else {
+                    println!("{name} generators DON'T match their seed");
+                    ok = false;
+                }
+            }
+            if ok {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        #[cfg(feature = "marlin")]
+        Some("marlin-setup") => {
+            println!("Generating the universal SRS...");
+            let srs = Marlin::universal_setup(SRS_MAX_CONSTRAINTS, SRS_MAX_NON_ZERO);
+            match Marlin::save_srs(&srs, MARLIN_SRS_PATH) {
+                Ok(digest) => {
+                    println!("Wrote {MARLIN_SRS_PATH}");
+                    println!("Pin it as MARLIN_SRS_SHA256 before indexing: {digest}");
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("failed to write {MARLIN_SRS_PATH}: {err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        #[cfg(feature = "marlin")]
+        Some("marlin-index") => match marlin_index() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        #[cfg(feature = "poseidon")]
+        Some("poseidon-setup") => match poseidon_setup() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Some("solidity") => match solidity() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        _ => {
+            eprintln!("usage: santazkparams <generate|check|marlin-setup|marlin-index|poseidon-setup|solidity>");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Indexes the CHOICE and REVEAL circuits from the universal SRS and writes their
+/// verifying keys next to it.
+#[cfg(feature = "marlin")]
+fn marlin_index() -> std::io::Result<()> {
+    let srs = Marlin::load_srs(MARLIN_SRS_PATH)?;
+    let params_dir = Path::new(MARLIN_SRS_PATH).parent().unwrap();
+
+    println!("Indexing CHOICE...");
+    let (_, verifier) = ChoiceAuthProver::<Marlin>::index(&srs, MERKLE_TREE_DEPTH)?;
+    let path = params_dir.join("choice_auth.marlin.vk");
+    fs::write(&path, Marlin::serialize_verifying_key(verifier.key()))?;
+    println!("Wrote {}", path.display());
+
+    println!("Indexing REVEAL...");
+    let (_, verifier) = RevealAuthProver::<Marlin>::index(&srs)?;
+    let path = params_dir.join("reveal_auth.marlin.vk");
+    fs::write(&path, Marlin::serialize_verifying_key(verifier.key()))?;
+    println!("Wrote {}", path.display());
+
+    Ok(())
+}
+
+/// Samples Groth16 keys for the Poseidon circuits and writes them to `params/`.
+/// Like the keys of the Pedersen circuits, they are only as trustworthy as whoever
+/// ran this.
+#[cfg(feature = "poseidon")]
+fn poseidon_setup() -> std::io::Result<()> {
+    println!("Setting up ENTER...");
+    let (prover, verifier) = EnterAuthProver::<Poseidon>::setup();
+    write_poseidon_keys("enter_auth", prover.key(), verifier.key())?;
+
+    println!("Setting up invite-gated ENTER...");
+    let (prover, verifier) = InviteEnterAuthProver::<Poseidon>::setup();
+    write_poseidon_keys("invite_enter_auth", prover.key(), verifier.key())?;
+
+    println!("Setting up CHOICE...");
+    let (prover, verifier) = ChoiceAuthProver::<Groth16, Poseidon>::setup();
+    write_poseidon_keys("choice_auth", prover.key(), verifier.key())?;
+
+    println!("Setting up REVEAL...");
+    let (prover, verifier) = RevealAuthProver::<Groth16, Poseidon>::setup();
+    write_poseidon_keys("reveal_auth", prover.key(), verifier.key())
+}
+
+#[cfg(feature = "poseidon")]
+fn write_poseidon_keys(
+    name: &str,
+    pk: &<Groth16 as ProofSystem>::ProvingKey,
+    vk: &<Groth16 as ProofSystem>::VerifyingKey,
+) -> std::io::Result<()> {
+    let params_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("params");
+    for (extension, bytes) in [
+        ("pk", Groth16::serialize_proving_key(pk)),
+        ("vk", Groth16::serialize_verifying_key(vk)),
+    ] {
+        let path = params_dir.join(format!("{name}.poseidon.groth16.{extension}"));
+        fs::write(&path, bytes)?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Writes the Solidity verifiers of the CHOICE and REVEAL Groth16 keys.
+fn solidity() -> std::io::Result<()> {
+    let contracts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("contracts");
+    fs::create_dir_all(&contracts_dir)?;
+
+    for (name, contract) in [
+        ("ChoiceAuthVerifier.sol", choice_verifier_contract()),
+        ("RevealAuthVerifier.sol", reveal_verifier_contract()),
+    ] {
+        let path = contracts_dir.join(name);
+        fs::write(&path, contract)?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
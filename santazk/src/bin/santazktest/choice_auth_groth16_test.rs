@@ -9,10 +9,18 @@ pub fn test_proof_and_verification() {
     let nullifier = vec![5u8; 64];
     let choice = vec![3u8; 64];
     let dh_pub_key = vec![9u8; 64];
+    let chain_id = vec![7u8; 32];
 
     let hasher = Hash::new();
     let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
-    let signature = sign_choice_tx(&hasher, &secret_key, &nullifier, &choice, &dh_pub_key);
+    let signature = sign_choice_tx(
+        &hasher,
+        &secret_key,
+        &nullifier,
+        &chain_id,
+        &choice,
+        &dh_pub_key,
+    );
 
     println!("Building merkle tree...");
     let mt = MerkleTree::new(MERKLE_TREE_DEPTH, &[pub_key]);
@@ -21,22 +29,46 @@ pub fn test_proof_and_verification() {
 
     println!("Proving...");
     let prover = ChoiceAuthProver::new();
-    let proof = prover.prove(
-        &secret_key,
+    let proof = prover
+        .prove(
+            &secret_key,
+            &nullifier,
+            &root,
+            &merkle_path,
+            &choice,
+            &chain_id,
+            &dh_pub_key,
+            &signature,
+        )
+        .unwrap();
+    println!("Proof len {}...", proof.len());
+
+    println!("Verifying...");
+    let verifier = ChoiceAuthVerifier::new();
+    let check = verifier.verify(
+        &proof,
         &nullifier,
         &root,
-        &merkle_path,
         &choice,
+        &chain_id,
         &dh_pub_key,
         &signature,
     );
-    println!("Proof len {}...", proof.len());
-
-    println!("Verifying...");
-    let verifier = ChoiceAuthVerifier::new();
-    let check = verifier.verify(&proof, &nullifier, &root, &choice, &dh_pub_key, &signature);
 
     assert!(check);
 
+    let other_chain_id = vec![8u8; 32];
+    let replayed = verifier.verify(
+        &proof,
+        &nullifier,
+        &root,
+        &choice,
+        &other_chain_id,
+        &dh_pub_key,
+        &signature,
+    );
+
+    assert!(!replayed);
+
     println!("Verification successful!");
 }
@@ -1,70 +1,36 @@
-use ark_crypto_primitives::crh::{
-    pedersen::{self, constraints::CRHParametersVar},
-    TwoToOneCRHScheme, TwoToOneCRHSchemeGadget,
-};
-use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
-
-use ark_crypto_primitives::crh::{CRHScheme, CRHSchemeGadget};
-use ark_crypto_primitives::merkle_tree::constraints::{BytesVarDigestConverter, ConfigGadget};
-use ark_crypto_primitives::merkle_tree::{
-    constraints::PathVar, ByteDigestConverter, Config, MerkleTree as ArkMerkleTree, Path,
-};
-use ark_ed_on_bls12_381::{
-    constraints::EdwardsVar, EdwardsAffine as JubJubAffine, EdwardsProjective as JubJub, Fq as Fr,
-};
+use std::marker::PhantomData;
 
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_crypto_primitives::merkle_tree::Path;
+use ark_ed_on_bls12_381::Fq as Fr;
 
-#[allow(unused)]
 use ark_r1cs_std::prelude::*;
-#[allow(unused)]
-use ark_relations::r1cs::{
-    ConstraintSynthesizer, ConstraintSystem, ConstraintSystemRef, SynthesisError,
-};
-
-use ark_bls12_381::Bls12_381;
-
-use ark_std::rand::{Rng, RngCore, SeedableRng};
-use ark_std::test_rng;
-use rand_core::OsRng;
-
-use ark_ff::ToConstraintField;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 
 use crate::{
-    hash::{common::*, pedersen_params::*, serialization::load_pedersen_params},
-    merkle::{JubJubMerkleTreeParams, JubJubMerkleTreeParamsVar, MerkleTree},
-    serialization::*,
+    crypto::{CHOICE_TAG, REVEAL_TAG},
+    hash::{DigestVar, HashConfig, MerklePathVar, Pedersen},
 };
 
 type ConstraintF = Fr;
 
 #[derive(Clone)]
-pub struct ChoiceAuthCircuit {
+pub struct ChoiceAuthCircuit<H: HashConfig = Pedersen> {
     pub secret_key: Vec<u8>,
     pub nullifier: Vec<u8>,
-    pub root: JubJubAffine,
-    pub merkle_path: Path<JubJubMerkleTreeParams>,
+    pub root: H::Digest,
+    pub merkle_path: Path<H::MerkleConfig>,
     pub choice: Vec<u8>,
+    pub chain_id: Vec<u8>,
     pub dh_pub_key: Vec<u8>,
     pub signature: Vec<u8>,
 }
 
-impl ConstraintSynthesizer<ConstraintF> for ChoiceAuthCircuit {
+impl<H: HashConfig> ConstraintSynthesizer<ConstraintF> for ChoiceAuthCircuit<H> {
     fn generate_constraints(
         self,
         cs: ConstraintSystemRef<ConstraintF>,
     ) -> Result<(), SynthesisError> {
-        let h1_crh_params_value = load_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES);
-        let h2_crh_params_value = load_pedersen_params(&H2_PEDERSEN_PARAMS_BYTES);
-
-        let h1_crh_params_var = CRHParametersVar::<JubJub, EdwardsVar>::new_constant(
-            ark_relations::ns!(cs, "h1_crh_params"),
-            h1_crh_params_value,
-        )?;
-        let h2_crh_params_var = CRHParametersVar::<JubJub, EdwardsVar>::new_constant(
-            ark_relations::ns!(cs, "h2_crh_params"),
-            h2_crh_params_value,
-        )?;
+        let params_var = H::params_var(cs.clone())?;
 
         let secret_key_var =
             UInt8::new_witness_vec(ark_relations::ns!(cs, "secret_key"), &self.secret_key)?; // cs.clone vs ns?
@@ -72,84 +38,64 @@ impl ConstraintSynthesizer<ConstraintF> for ChoiceAuthCircuit {
         let nullifier_var =
             UInt8::new_input_vec(ark_relations::ns!(cs, "nullifier"), &self.nullifier)?;
 
-        let aux_secret_key_var = hash_two_to_one_constrained(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
-            &secret_key_var,
-            &nullifier_var,
-        )?;
+        let aux_secret_key_var = H::h2_var(&params_var, &secret_key_var, &nullifier_var)?;
 
-        let pub_key_var = hash_one_constrained(&h1_crh_params_var, &aux_secret_key_var)?;
+        let pub_key_var = H::h1_var(&params_var, &aux_secret_key_var)?;
 
-        let root_var = EdwardsVar::new_input(ark_relations::ns!(cs, "root"), || Ok(self.root))?;
+        let root_var = DigestVar::<H>::new_input(ark_relations::ns!(cs, "root"), || Ok(self.root))?;
 
-        let merkle_path_var: PathVar<
-            JubJubMerkleTreeParams,
-            ConstraintF,
-            JubJubMerkleTreeParamsVar,
-        > = PathVar::new_witness(ark_relations::ns!(cs, "path"), || Ok(self.merkle_path))?;
+        let merkle_path_var =
+            MerklePathVar::<H>::new_witness(ark_relations::ns!(cs, "path"), || {
+                Ok(self.merkle_path)
+            })?;
 
-        let _ = merkle_path_var.verify_membership(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
-            &root_var,
-            &pub_key_var,
-        )?;
+        H::verify_membership(&params_var, &merkle_path_var, &root_var, &pub_key_var)?
+            .enforce_equal(&Boolean::TRUE)?;
 
         let choice_var = UInt8::new_input_vec(ark_relations::ns!(cs, "choice"), &self.choice)?;
 
-        let penultimate_signature_var = hash_two_to_one_constrained(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
+        let chain_id_var =
+            UInt8::new_input_vec(ark_relations::ns!(cs, "chain_id"), &self.chain_id)?;
+
+        let penultimate_signature_var = H::h2_var(
+            &params_var,
             &aux_secret_key_var,
-            &choice_var,
+            &tagged_message_var(CHOICE_TAG, &chain_id_var, &choice_var),
         )?;
 
         let dh_pub_key_var =
             UInt8::new_input_vec(ark_relations::ns!(cs, "dh_pub_key"), &self.dh_pub_key)?;
 
-        let expected_signature_var = hash_two_to_one_constrained(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
-            &penultimate_signature_var,
-            &dh_pub_key_var,
-        )?;
+        let expected_signature_var =
+            H::h2_var(&params_var, &penultimate_signature_var, &dh_pub_key_var)?;
 
         let signature_var =
             UInt8::new_input_vec(ark_relations::ns!(cs, "signature"), &self.signature)?;
 
-        let _ = expected_signature_var.is_eq(&signature_var)?;
+        expected_signature_var.enforce_equal(&signature_var)?;
 
         Ok(())
     }
 }
 
 #[derive(Clone)]
-pub struct RevealAuthCircuit {
+pub struct RevealAuthCircuit<H: HashConfig = Pedersen> {
     pub secret_key: Vec<u8>,
     pub nullifier: Vec<u8>,
     pub pub_key: Vec<u8>,
     pub ciphertext_hash: Vec<u8>,
+    pub chain_id: Vec<u8>,
     pub dh_pub_key: Vec<u8>,
     pub signature: Vec<u8>,
+    pub hash: PhantomData<H>,
 }
 
-impl ConstraintSynthesizer<ConstraintF> for RevealAuthCircuit {
+impl<H: HashConfig> ConstraintSynthesizer<ConstraintF> for RevealAuthCircuit<H> {
     fn generate_constraints(
         self,
         cs: ConstraintSystemRef<ConstraintF>,
     ) -> Result<(), SynthesisError> {
-        let h1_crh_params_value = load_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES);
-        let h2_crh_params_value = load_pedersen_params(&H2_PEDERSEN_PARAMS_BYTES);
-
-        let h1_crh_params_var = CRHParametersVar::<JubJub, EdwardsVar>::new_constant(
-            ark_relations::ns!(cs, "h1_crh_params"),
-            h1_crh_params_value,
-        )?;
-        let h2_crh_params_var = CRHParametersVar::<JubJub, EdwardsVar>::new_constant(
-            ark_relations::ns!(cs, "h2_crh_params"),
-            h2_crh_params_value,
-        )?;
+        let params_var = H::params_var(cs.clone())?;
 
         let secret_key_var =
             UInt8::new_witness_vec(ark_relations::ns!(cs, "secret_key"), &self.secret_key)?; // cs.clone vs ns?
@@ -157,147 +103,437 @@ impl ConstraintSynthesizer<ConstraintF> for RevealAuthCircuit {
         let nullifier_var =
             UInt8::new_witness_vec(ark_relations::ns!(cs, "nullifier"), &self.nullifier)?;
 
-        let aux_secret_key_var = hash_two_to_one_constrained(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
-            &secret_key_var,
-            &nullifier_var,
-        )?;
+        let aux_secret_key_var = H::h2_var(&params_var, &secret_key_var, &nullifier_var)?;
 
-        let expected_pub_key_var = hash_one_constrained(&h1_crh_params_var, &aux_secret_key_var)?;
+        let expected_pub_key_var = H::h1_var(&params_var, &aux_secret_key_var)?;
 
         let pub_key_var = UInt8::new_input_vec(ark_relations::ns!(cs, "pub_key"), &self.pub_key)?;
 
-        let _ = expected_pub_key_var.is_eq(&pub_key_var)?;
+        expected_pub_key_var.enforce_equal(&pub_key_var)?;
 
         let ciphertext_hash_var = UInt8::new_input_vec(
             ark_relations::ns!(cs, "ciphertext_hash"),
             &self.ciphertext_hash,
         )?;
 
-        let penultimate_signature_var = hash_two_to_one_constrained(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
+        let chain_id_var =
+            UInt8::new_input_vec(ark_relations::ns!(cs, "chain_id"), &self.chain_id)?;
+
+        let penultimate_signature_var = H::h2_var(
+            &params_var,
             &aux_secret_key_var,
-            &ciphertext_hash_var,
+            &tagged_message_var(REVEAL_TAG, &chain_id_var, &ciphertext_hash_var),
         )?;
 
         let dh_pub_key_var =
             UInt8::new_input_vec(ark_relations::ns!(cs, "dh_pub_key"), &self.dh_pub_key)?;
-        
-        let expected_signature_var = hash_two_to_one_constrained(
-            &h1_crh_params_var,
-            &h2_crh_params_var,
-            &penultimate_signature_var,
-            &dh_pub_key_var,
-        )?;
+
+        let expected_signature_var =
+            H::h2_var(&params_var, &penultimate_signature_var, &dh_pub_key_var)?;
 
         let signature_var =
             UInt8::new_input_vec(ark_relations::ns!(cs, "signature"), &self.signature)?;
 
-        let _ = expected_signature_var.is_eq(&signature_var)?;
+        expected_signature_var.enforce_equal(&signature_var)?;
 
         Ok(())
     }
 }
 
-fn hash_one_constrained(
-    h1_crh_params_var: &CRHParametersVar<JubJub, EdwardsVar>,
-    input: &[UInt8<ConstraintF>],
-) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError> {
-    let h = CRHGadget::evaluate(h1_crh_params_var, input)?;
-    convert_edwards_var_to_uint8_vec(h)
+/// Proves knowledge of the `secret_key` and `nullifier` behind `pub_key`, i.e. that
+/// `pub_key = H1(H2(secret_key, nullifier))`, for the chain `chain_id`.
+/// The chain id is a public input, so a proof can't be replayed on another chain.
+#[derive(Clone)]
+pub struct EnterAuthCircuit<H: HashConfig = Pedersen> {
+    pub secret_key: Vec<u8>,
+    pub nullifier: Vec<u8>,
+    pub pub_key: Vec<u8>,
+    pub chain_id: Vec<u8>,
+    pub hash: PhantomData<H>,
+}
+
+impl<H: HashConfig> ConstraintSynthesizer<ConstraintF> for EnterAuthCircuit<H> {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        let params_var = H::params_var(cs.clone())?;
+
+        let secret_key_var =
+            UInt8::new_witness_vec(ark_relations::ns!(cs, "secret_key"), &self.secret_key)?;
+
+        let nullifier_var =
+            UInt8::new_witness_vec(ark_relations::ns!(cs, "nullifier"), &self.nullifier)?;
+
+        let aux_secret_key_var = H::h2_var(&params_var, &secret_key_var, &nullifier_var)?;
+
+        let expected_pub_key_var = H::h1_var(&params_var, &aux_secret_key_var)?;
+
+        let pub_key_var = UInt8::new_input_vec(ark_relations::ns!(cs, "pub_key"), &self.pub_key)?;
+
+        expected_pub_key_var.enforce_equal(&pub_key_var)?;
+
+        // bound to the proof through the input consistency constraints of the proof system
+        let _chain_id_var =
+            UInt8::new_input_vec(ark_relations::ns!(cs, "chain_id"), &self.chain_id)?;
+
+        Ok(())
+    }
 }
 
-fn hash_two_to_one_constrained(
-    h1_crh_params_var: &CRHParametersVar<JubJub, EdwardsVar>,
-    h2_crh_params_var: &CRHParametersVar<JubJub, EdwardsVar>,
-    input1: &[UInt8<ConstraintF>],
-    input2: &[UInt8<ConstraintF>],
-) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError> {
-    let l = CRHGadget::evaluate(h1_crh_params_var, input1)?;
-    let r = CRHGadget::evaluate(h1_crh_params_var, input2)?;
-
-    let h = TwoToOneCRHGadget::compress(h2_crh_params_var, &l, &r)?;
-    convert_edwards_var_to_uint8_vec(h)
+/// Proves what [`EnterAuthCircuit`] proves, and that the sender holds an invite of an
+/// invite-only group: its commitment `H2(invite_secret, invite_salt)` is a leaf of the
+/// allowlist Merkle tree with root `invite_root`. Only the invite's nullifier
+/// `H1(invite_secret)` is public, so an invite can be redeemed once without telling
+/// which leaf it is.
+#[derive(Clone)]
+pub struct InviteEnterAuthCircuit<H: HashConfig = Pedersen> {
+    pub secret_key: Vec<u8>,
+    pub nullifier: Vec<u8>,
+    pub pub_key: Vec<u8>,
+    pub chain_id: Vec<u8>,
+    pub invite_secret: Vec<u8>,
+    pub invite_salt: Vec<u8>,
+    pub invite_root: H::Digest,
+    pub invite_path: Path<H::MerkleConfig>,
+    pub invite_nullifier: Vec<u8>,
 }
 
-fn convert_edwards_var_to_uint8_vec(
-    edwards_var: EdwardsVar,
-) -> Result<Vec<UInt8<ConstraintF>>, SynthesisError> {
-    let mut bits: Vec<Boolean<ConstraintF>> = edwards_var.to_bits_le()?;
-    // Size of bits is currently 510, pad by 2 bits to be a multiple of 8.
-    for _ in 0..2 {
-        bits.push(Boolean::<ConstraintF>::Constant(false));
+impl<H: HashConfig> ConstraintSynthesizer<ConstraintF> for InviteEnterAuthCircuit<H> {
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        let params_var = H::params_var(cs.clone())?;
+
+        let secret_key_var =
+            UInt8::new_witness_vec(ark_relations::ns!(cs, "secret_key"), &self.secret_key)?;
+
+        let nullifier_var =
+            UInt8::new_witness_vec(ark_relations::ns!(cs, "nullifier"), &self.nullifier)?;
+
+        let aux_secret_key_var = H::h2_var(&params_var, &secret_key_var, &nullifier_var)?;
+
+        let expected_pub_key_var = H::h1_var(&params_var, &aux_secret_key_var)?;
+
+        let pub_key_var = UInt8::new_input_vec(ark_relations::ns!(cs, "pub_key"), &self.pub_key)?;
+
+        expected_pub_key_var.enforce_equal(&pub_key_var)?;
+
+        // bound to the proof through the input consistency constraints of the proof system
+        let _chain_id_var =
+            UInt8::new_input_vec(ark_relations::ns!(cs, "chain_id"), &self.chain_id)?;
+
+        let invite_secret_var =
+            UInt8::new_witness_vec(ark_relations::ns!(cs, "invite_secret"), &self.invite_secret)?;
+
+        let invite_salt_var =
+            UInt8::new_witness_vec(ark_relations::ns!(cs, "invite_salt"), &self.invite_salt)?;
+
+        let invite_commitment_var = H::h2_var(&params_var, &invite_secret_var, &invite_salt_var)?;
+
+        let invite_root_var =
+            DigestVar::<H>::new_input(ark_relations::ns!(cs, "invite_root"), || {
+                Ok(self.invite_root)
+            })?;
+
+        let invite_path_var =
+            MerklePathVar::<H>::new_witness(ark_relations::ns!(cs, "invite_path"), || {
+                Ok(self.invite_path)
+            })?;
+
+        H::verify_membership(
+            &params_var,
+            &invite_path_var,
+            &invite_root_var,
+            &invite_commitment_var,
+        )?
+        .enforce_equal(&Boolean::TRUE)?;
+
+        let expected_invite_nullifier_var = H::h1_var(&params_var, &invite_secret_var)?;
+
+        let invite_nullifier_var = UInt8::new_input_vec(
+            ark_relations::ns!(cs, "invite_nullifier"),
+            &self.invite_nullifier,
+        )?;
+
+        expected_invite_nullifier_var.enforce_equal(&invite_nullifier_var)?;
+
+        Ok(())
     }
+}
 
-    Ok(bits.chunks(8).map(UInt8::from_bits_le).collect())
+/// Constrained counterpart of [`tagged_message`](crate::crypto::tagged_message).
+fn tagged_message_var(
+    tag: &[u8; 16],
+    chain_id: &[UInt8<ConstraintF>],
+    payload: &[UInt8<ConstraintF>],
+) -> Vec<UInt8<ConstraintF>> {
+    [UInt8::constant_vec(tag).as_slice(), chain_id, payload].concat()
 }
 
 #[cfg(test)]
 mod tests {
+    use ark_relations::r1cs::ConstraintSystem;
+
     use super::*;
     use crate::crypto::*;
-    use crate::hash::Hash;
+    use crate::hash::NativeHash;
+    use crate::merkle::MerkleTreeOf;
 
     #[test]
     fn choice_auth_circuit_test() {
+        choice_auth_circuit::<Pedersen>();
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn poseidon_choice_auth_circuit_test() {
+        choice_auth_circuit::<crate::hash::poseidon::Poseidon>();
+    }
+
+    fn choice_auth_circuit<H: HashConfig>() {
         let secret_key = vec![1u8; 32];
         let nullifier = vec![2u8; 32];
         let choice = vec![3u8; 32];
         let dh_pub_key = vec![4u8; 32];
+        let chain_id = vec![5u8; 32];
 
-        let hasher = Hash::new();
+        let hasher = H::hash();
         let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
-        let signature = sign_choice_tx(&hasher, &secret_key, &nullifier, &choice, &dh_pub_key);
+        let signature = sign_choice_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &choice,
+            &dh_pub_key,
+        );
 
-        let mt = MerkleTree::new(2,&[pub_key, vec![0u8; 64]]);
-        let merkle_path = MerkleTree::deserialize_path(&mt.generate_proof(0).unwrap());
+        let mt = MerkleTreeOf::<H>::new(2, &[pub_key, vec![0u8; H::DIGEST_SIZE]]);
+        let merkle_path =
+            MerkleTreeOf::<H>::deserialize_path(&mt.generate_proof(0).unwrap()).unwrap();
 
-        let ckt = ChoiceAuthCircuit {
+        let ckt = ChoiceAuthCircuit::<H> {
             secret_key: vec![1u8; 32],
             nullifier: vec![2u8; 32],
-            root: deserialize_jub_jub_affine_point(&mt.root()),
+            root: H::deserialize_digest(&mt.root()).unwrap(),
             merkle_path,
-            choice,
-            dh_pub_key,
+            choice: choice.clone(),
+            chain_id: chain_id.clone(),
+            dh_pub_key: dh_pub_key.clone(),
             signature,
         };
         let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        ckt.clone().generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+
+        // replayed on another chain
+        let replayed = ChoiceAuthCircuit {
+            chain_id: vec![6u8; 32],
+            ..ckt.clone()
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        replayed.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+
+        // a REVEAL signature over the same bytes
+        let reveal_signature = sign_reveal_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &choice,
+            &dh_pub_key,
+        );
+        let cross_type = ChoiceAuthCircuit {
+            signature: reveal_signature,
+            ..ckt.clone()
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        cross_type.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+
+        // a public key that isn't in the Merkle tree
+        let mt = MerkleTreeOf::<H>::new(2, &[vec![0u8; H::DIGEST_SIZE]]);
+        let outsider = ChoiceAuthCircuit {
+            root: H::deserialize_digest(&mt.root()).unwrap(),
+            ..ckt
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        outsider.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn enter_auth_circuit_test() {
+        enter_auth_circuit::<Pedersen>();
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn poseidon_enter_auth_circuit_test() {
+        enter_auth_circuit::<crate::hash::poseidon::Poseidon>();
+    }
+
+    fn enter_auth_circuit<H: HashConfig>() {
+        let secret_key = vec![1u8; 32];
+        let nullifier = vec![2u8; 32];
+        let chain_id = vec![3u8; 32];
+
+        let hasher = H::hash();
+        let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+
+        let ckt = EnterAuthCircuit::<H> {
+            secret_key: secret_key.clone(),
+            nullifier: nullifier.clone(),
+            pub_key: pub_key.clone(),
+            chain_id: chain_id.clone(),
+            hash: PhantomData,
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
         ckt.generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
 
+        // a public key nobody knows the secret key of
+        let ckt = EnterAuthCircuit::<H> {
+            secret_key,
+            nullifier,
+            pub_key: vec![4u8; H::DIGEST_SIZE],
+            chain_id,
+            hash: PhantomData,
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        ckt.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn invite_enter_auth_circuit_test() {
+        invite_enter_auth_circuit::<Pedersen>();
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn poseidon_invite_enter_auth_circuit_test() {
+        invite_enter_auth_circuit::<crate::hash::poseidon::Poseidon>();
+    }
+
+    fn invite_enter_auth_circuit<H: HashConfig>() {
+        let secret_key = vec![1u8; 32];
+        let nullifier = vec![2u8; 32];
+        let chain_id = vec![3u8; 32];
+        let invite_secret = vec![4u8; 32];
+        let invite_salt = vec![5u8; 32];
+
+        let hasher = H::hash();
+        let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+        let invite = derive_invite_commitment(&hasher, &invite_secret, &invite_salt);
+        let invite_nullifier = derive_invite_nullifier(&hasher, &invite_secret);
+
+        let mt = MerkleTreeOf::<H>::new(2, &[vec![6u8; H::DIGEST_SIZE], invite]);
+        let invite_path =
+            MerkleTreeOf::<H>::deserialize_path(&mt.generate_proof(1).unwrap()).unwrap();
+        let ckt = InviteEnterAuthCircuit::<H> {
+            secret_key,
+            nullifier,
+            pub_key,
+            chain_id,
+            invite_secret,
+            invite_salt,
+            invite_root: H::deserialize_digest(&mt.root()).unwrap(),
+            invite_path,
+            invite_nullifier: invite_nullifier.clone(),
+        };
+
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        ckt.clone().generate_constraints(cs.clone()).unwrap();
         assert!(cs.is_satisfied().unwrap());
+
+        // an invite that isn't on the allowlist
+        let mt = MerkleTreeOf::<H>::new(2, &[vec![6u8; H::DIGEST_SIZE]]);
+        let invalid = InviteEnterAuthCircuit {
+            invite_root: H::deserialize_digest(&mt.root()).unwrap(),
+            invite_path: MerkleTreeOf::<H>::deserialize_path(&mt.generate_proof(1).unwrap())
+                .unwrap(),
+            ..ckt.clone()
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        invalid.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+
+        // a nullifier that doesn't belong to the invite
+        let invalid = InviteEnterAuthCircuit {
+            invite_nullifier: derive_invite_nullifier(&hasher, &[7u8; 32]),
+            ..ckt
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        invalid.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
     }
 
     #[test]
     fn reveal_auth_circuit_test() {
+        reveal_auth_circuit::<Pedersen>();
+    }
+
+    #[cfg(feature = "poseidon")]
+    #[test]
+    fn poseidon_reveal_auth_circuit_test() {
+        reveal_auth_circuit::<crate::hash::poseidon::Poseidon>();
+    }
+
+    fn reveal_auth_circuit<H: HashConfig>() {
         let secret_key = vec![1u8; 32];
         let nullifier = vec![2u8; 32];
         let ciphertext = vec![3u8; 32];
         let dh_pub_key = vec![4u8; 32];
+        let chain_id = vec![5u8; 32];
 
-        let hasher = Hash::new();
+        let hasher = H::hash();
         let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
         let ciphertext_hash = hasher.h1(&ciphertext);
         let signature = sign_reveal_tx(
             &hasher,
             &secret_key,
             &nullifier,
+            &chain_id,
             &ciphertext_hash,
             &dh_pub_key,
         );
 
-        let ckt = RevealAuthCircuit {
+        let ckt = RevealAuthCircuit::<H> {
             secret_key,
             nullifier,
             pub_key,
             ciphertext_hash,
+            chain_id,
             dh_pub_key,
             signature,
+            hash: PhantomData,
         };
         let cs = ConstraintSystem::<ConstraintF>::new_ref();
-        ckt.generate_constraints(cs.clone()).unwrap();
+        ckt.clone().generate_constraints(cs.clone()).unwrap();
 
         assert!(cs.is_satisfied().unwrap());
+
+        // replayed on another chain where the same key entered
+        let replayed = RevealAuthCircuit {
+            chain_id: vec![6u8; 32],
+            ..ckt.clone()
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        replayed.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+
+        // a public key nobody knows the secret key of
+        let forged = RevealAuthCircuit {
+            pub_key: vec![7u8; H::DIGEST_SIZE],
+            ..ckt
+        };
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        forged.generate_constraints(cs.clone()).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
     }
 }
@@ -1,30 +1,64 @@
-use crate::hash::Hash;
+use crate::hash::NativeHash;
 
-pub fn derive_participation_pubkey(hash: &Hash, secret_key: &[u8], nullifier: &[u8]) -> Vec<u8> {
+/// Domain-separation tag of CHOICE signatures.
+pub const CHOICE_TAG: &[u8; 16] = b"ZKRET_CHOICE_V1_";
+/// Domain-separation tag of REVEAL signatures.
+pub const REVEAL_TAG: &[u8; 16] = b"ZKRET_REVEAL_V1_";
+
+pub fn derive_participation_pubkey(
+    hash: &impl NativeHash,
+    secret_key: &[u8],
+    nullifier: &[u8],
+) -> Vec<u8> {
     let aux_sk = hash.h2(&secret_key, &nullifier);
     hash.h1(&aux_sk)
 }
 
+/// Returns the message a signature of type `tag` commits to on the chain `chain_id`.
+/// Tags have the same length, so messages of different types never collide.
+pub fn tagged_message(tag: &[u8; 16], chain_id: &[u8], payload: &[u8]) -> Vec<u8> {
+    [tag.as_slice(), chain_id, payload].concat()
+}
+
 pub fn sign_choice_tx(
-    hash: &Hash,
+    hash: &impl NativeHash,
     secret_key: &[u8],
     nullifier: &[u8],
+    chain_id: &[u8],
     choice: &[u8],
     dh_pub_key: &[u8],
 ) -> Vec<u8> {
     let aux_sk = hash.h2(&secret_key, &nullifier);
-    let penultimate_signature = hash.h2(&aux_sk, &choice);
+    let penultimate_signature = hash.h2(&aux_sk, &tagged_message(CHOICE_TAG, chain_id, choice));
     hash.h2(&penultimate_signature, &dh_pub_key)
 }
 
 pub fn sign_reveal_tx(
-    hash: &Hash,
+    hash: &impl NativeHash,
     secret_key: &[u8],
     nullifier: &[u8],
+    chain_id: &[u8],
     ciphertext_hash: &[u8],
     dh_pub_key: &[u8],
 ) -> Vec<u8> {
     let aux_sk = hash.h2(secret_key, nullifier);
-    let penultimate_signature = hash.h2(&aux_sk, ciphertext_hash);
+    let penultimate_signature =
+        hash.h2(&aux_sk, &tagged_message(REVEAL_TAG, chain_id, ciphertext_hash));
     hash.h2(&penultimate_signature, dh_pub_key)
 }
+
+/// Commitment to an invite token, published in the allowlist of an invite-only group.
+pub fn derive_invite_commitment(
+    hash: &impl NativeHash,
+    invite_secret: &[u8],
+    invite_salt: &[u8],
+) -> Vec<u8> {
+    hash.h2(invite_secret, invite_salt)
+}
+
+/// Nullifier spent when an invite is redeemed. It can't be linked to the invite's
+/// commitment without the invite secret, which only the holder knows: the holder
+/// makes the token and only sends the commitment to the organiser.
+pub fn derive_invite_nullifier(hash: &impl NativeHash, invite_secret: &[u8]) -> Vec<u8> {
+    hash.h1(invite_secret)
+}
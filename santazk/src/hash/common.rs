@@ -3,6 +3,11 @@
 use ark_crypto_primitives::crh::pedersen;
 use ark_ed_on_bls12_381::{constraints::EdwardsVar, EdwardsProjective as JubJub};
 
+/// Public seeds the H1 and H2 generators are derived from, see
+/// [`derive_pedersen_params`](super::serialization::derive_pedersen_params).
+pub const H1_PEDERSEN_SEED: &[u8] = b"ZKretSanta Pedersen H1 generators";
+pub const H2_PEDERSEN_SEED: &[u8] = b"ZKretSanta Pedersen H2 generators";
+
 #[derive(Clone)]
 pub struct Window4x256;
 impl pedersen::Window for Window4x256 {
@@ -1,8 +1,23 @@
-use ark_crypto_primitives::crh::{CRHScheme, TwoToOneCRHScheme};
-use ark_ff::BigInteger;
+//! The hashes of the circuits and the Merkle tree, native and constrained.
+//!
+//! The chain uses the Pedersen [`Hash`]. The circuits and the Merkle tree are
+//! generic over a [`HashConfig`], so that they can also be instantiated with the
+//! Poseidon hash of the `poseidon` feature.
+
+use std::borrow::Borrow;
+
+use ark_crypto_primitives::crh::{
+    CRHScheme, CRHSchemeGadget, TwoToOneCRHScheme, TwoToOneCRHSchemeGadget,
+};
+use ark_crypto_primitives::merkle_tree::{
+    constraints::{ConfigGadget, PathVar},
+    Config, LeafParam, TwoToOneParam,
+};
 use ark_ff::PrimeField;
 
-use ark_ed_on_bls12_381::{EdwardsAffine as JubJubAffine, Fq as Fr};
+use ark_ed_on_bls12_381::{constraints::EdwardsVar, EdwardsAffine as JubJubAffine, Fq as Fr};
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
 
 pub mod common;
 use common::*;
@@ -13,8 +28,178 @@ use pedersen_params::*;
 pub mod serialization;
 use serialization::load_pedersen_params;
 
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
+
+use crate::merkle::{JubJubMerkleTreeParams, JubJubMerkleTreeParamsVar};
 use crate::serialization::*;
 
+/// A hash of bytes, as the protocol uses it: `h1` of one input, `h2` of two, and
+/// `h2c` of two digests, like an inner node of the Merkle tree.
+pub trait NativeHash {
+    fn h1(&self, input: &[u8]) -> Vec<u8>;
+    fn h2(&self, left: &[u8], right: &[u8]) -> Vec<u8>;
+    fn h2c(&self, left: &[u8], right: &[u8]) -> Vec<u8>;
+}
+
+/// A hash the circuits and [`MerkleTreeOf`](crate::merkle::MerkleTreeOf) are
+/// instantiated with, with its native and constrained implementations, which
+/// must match.
+pub trait HashConfig: Clone {
+    /// Size of a serialized digest.
+    const DIGEST_SIZE: usize;
+
+    type Hash: NativeHash;
+    /// Digest of the leaves and inner nodes of the Merkle tree.
+    type Digest: Clone;
+    type MerkleConfig: Config<LeafDigest = Self::Digest, InnerDigest = Self::Digest> + Clone;
+    type MerkleConfigVar: ConfigGadget<Self::MerkleConfig, Fr>;
+    /// A leaf of the Merkle tree, made of the leaf bytes.
+    type MerkleLeaf: Borrow<<Self::MerkleConfig as Config>::Leaf>;
+    /// Parameters of the constrained hash.
+    type ParamsVar;
+
+    fn hash() -> Self::Hash;
+    fn merkle_leaf(bytes: &[u8]) -> Self::MerkleLeaf;
+    fn merkle_params() -> (
+        LeafParam<Self::MerkleConfig>,
+        TwoToOneParam<Self::MerkleConfig>,
+    );
+    fn serialize_digest(digest: &Self::Digest) -> Vec<u8>;
+    /// Returns none if `bytes` isn't the encoding of a digest.
+    fn deserialize_digest(bytes: &[u8]) -> Option<Self::Digest>;
+    /// The public inputs a digest input of a circuit is made of.
+    fn digest_to_field_elements(digest: &Self::Digest) -> Vec<Fr>;
+
+    fn params_var(cs: ConstraintSystemRef<Fr>) -> Result<Self::ParamsVar, SynthesisError>;
+    /// Constrained counterpart of [`NativeHash::h1`].
+    fn h1_var(
+        params: &Self::ParamsVar,
+        input: &[UInt8<Fr>],
+    ) -> Result<Vec<UInt8<Fr>>, SynthesisError>;
+    /// Constrained counterpart of [`NativeHash::h2`].
+    fn h2_var(
+        params: &Self::ParamsVar,
+        left: &[UInt8<Fr>],
+        right: &[UInt8<Fr>],
+    ) -> Result<Vec<UInt8<Fr>>, SynthesisError>;
+    /// Returns whether `path` leads from `leaf` to `root`.
+    fn verify_membership(
+        params: &Self::ParamsVar,
+        path: &MerklePathVar<Self>,
+        root: &DigestVar<Self>,
+        leaf: &[UInt8<Fr>],
+    ) -> Result<Boolean<Fr>, SynthesisError>;
+}
+
+/// Constrained counterpart of a [`HashConfig::Digest`].
+pub type DigestVar<H> = <<H as HashConfig>::MerkleConfigVar as ConfigGadget<
+    <H as HashConfig>::MerkleConfig,
+    Fr,
+>>::InnerDigest;
+
+/// Constrained Merkle tree path of a [`HashConfig`].
+pub type MerklePathVar<H> =
+    PathVar<<H as HashConfig>::MerkleConfig, Fr, <H as HashConfig>::MerkleConfigVar>;
+
+/// The Pedersen [`Hash`], whose digests are JubJub points.
+#[derive(Clone, Copy, Debug)]
+pub struct Pedersen;
+
+impl HashConfig for Pedersen {
+    const DIGEST_SIZE: usize = JUBJUB_AFFINE_POINT_SIZE;
+
+    type Hash = Hash;
+    type Digest = JubJubAffine;
+    type MerkleConfig = JubJubMerkleTreeParams;
+    type MerkleConfigVar = JubJubMerkleTreeParamsVar;
+    type MerkleLeaf = Vec<u8>;
+    /// The H1 and H2 generators.
+    type ParamsVar = (PedersenParametersVar, PedersenParametersVar);
+
+    fn hash() -> Hash {
+        Hash::new()
+    }
+
+    fn merkle_leaf(bytes: &[u8]) -> Vec<u8> {
+        bytes.to_vec()
+    }
+
+    fn merkle_params() -> (PedersenParameters, PedersenParameters) {
+        (
+            load_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES),
+            load_pedersen_params(&H2_PEDERSEN_PARAMS_BYTES),
+        )
+    }
+
+    fn serialize_digest(digest: &JubJubAffine) -> Vec<u8> {
+        serialize_jub_jub_affine_point(digest)
+    }
+
+    fn deserialize_digest(bytes: &[u8]) -> Option<JubJubAffine> {
+        try_deserialize_jub_jub_affine_point(bytes).ok()
+    }
+
+    fn digest_to_field_elements(digest: &JubJubAffine) -> Vec<Fr> {
+        vec![digest.x, digest.y]
+    }
+
+    fn params_var(cs: ConstraintSystemRef<Fr>) -> Result<Self::ParamsVar, SynthesisError> {
+        let (h1_crh_params_value, h2_crh_params_value) = Self::merkle_params();
+        let h1_crh_params_var = PedersenParametersVar::new_constant(
+            ark_relations::ns!(cs, "h1_crh_params"),
+            h1_crh_params_value,
+        )?;
+        let h2_crh_params_var = PedersenParametersVar::new_constant(
+            ark_relations::ns!(cs, "h2_crh_params"),
+            h2_crh_params_value,
+        )?;
+        Ok((h1_crh_params_var, h2_crh_params_var))
+    }
+
+    fn h1_var(
+        (h1_crh_params_var, _): &Self::ParamsVar,
+        input: &[UInt8<Fr>],
+    ) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+        let h = CRHGadget::evaluate(h1_crh_params_var, input)?;
+        convert_edwards_var_to_uint8_vec(h)
+    }
+
+    fn h2_var(
+        (h1_crh_params_var, h2_crh_params_var): &Self::ParamsVar,
+        left: &[UInt8<Fr>],
+        right: &[UInt8<Fr>],
+    ) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+        let l = CRHGadget::evaluate(h1_crh_params_var, left)?;
+        let r = CRHGadget::evaluate(h1_crh_params_var, right)?;
+
+        let h = TwoToOneCRHGadget::compress(h2_crh_params_var, &l, &r)?;
+        convert_edwards_var_to_uint8_vec(h)
+    }
+
+    fn verify_membership(
+        (h1_crh_params_var, h2_crh_params_var): &Self::ParamsVar,
+        path: &MerklePathVar<Self>,
+        root: &EdwardsVar,
+        leaf: &[UInt8<Fr>],
+    ) -> Result<Boolean<Fr>, SynthesisError> {
+        path.verify_membership(h1_crh_params_var, h2_crh_params_var, root, leaf)
+    }
+}
+
+/// The x‖y bits of a point, like [`serialize_jub_jub_affine_point`].
+fn convert_edwards_var_to_uint8_vec(
+    edwards_var: EdwardsVar,
+) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    let mut bits: Vec<Boolean<Fr>> = edwards_var.to_bits_le()?;
+    // Size of bits is currently 510, pad by 2 bits to be a multiple of 8.
+    for _ in 0..2 {
+        bits.push(Boolean::<Fr>::Constant(false));
+    }
+
+    Ok(bits.chunks(8).map(UInt8::from_bits_le).collect())
+}
+
 pub struct Hash {
     h1_crh_params: PedersenParameters,
     h2_crh_params: PedersenParameters,
@@ -50,6 +235,26 @@ impl Hash {
     }
 }
 
+impl Default for Hash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NativeHash for Hash {
+    fn h1(&self, input: &[u8]) -> Vec<u8> {
+        Hash::h1(self, input)
+    }
+
+    fn h2(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        Hash::h2(self, left, right)
+    }
+
+    fn h2c(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        Hash::h2c(self, left, right)
+    }
+}
+
 mod tests {
     use super::*;
 
@@ -1,3079 +1,3082 @@
-pub const H1_PEDERSEN_PARAMS_BYTES: [[[u8; 32]; 4]; 256] = 
+// Generated by `cargo run -p santazk --bin santazkparams -- generate`, do not edit.
+// The generators are derived from H1_PEDERSEN_SEED and H2_PEDERSEN_SEED.
+
+pub static H1_PEDERSEN_PARAMS_BYTES: [[[u8; 32]; 4]; 256] =
 [
 	[
-		[17, 162, 84, 160, 148, 234, 200, 161, 12, 103, 157, 35, 234, 190, 198, 245, 125, 43, 225, 251, 177, 114, 246, 168, 165, 220, 56, 189, 101, 153, 97, 49],
-		[59, 96, 133, 156, 4, 99, 13, 219, 244, 122, 223, 218, 75, 16, 255, 22, 215, 195, 143, 115, 101, 32, 111, 84, 111, 154, 208, 129, 149, 84, 152, 90],
-		[98, 160, 249, 65, 206, 88, 183, 108, 160, 183, 234, 234, 196, 217, 184, 37, 56, 72, 238, 100, 202, 56, 218, 218, 95, 116, 156, 220, 221, 158, 63, 52],
-		[216, 66, 39, 235, 50, 174, 109, 252, 57, 72, 212, 69, 209, 199, 112, 63, 163, 141, 69, 119, 80, 13, 56, 27, 145, 68, 89, 30, 6, 124, 76, 149],
+		[90, 68, 64, 117, 18, 213, 92, 152, 180, 123, 113, 126, 39, 4, 60, 17, 124, 183, 139, 69, 188, 140, 250, 68, 144, 60, 100, 228, 161, 161, 14, 103],
+		[73, 94, 205, 98, 205, 5, 219, 215, 91, 67, 221, 243, 129, 120, 250, 44, 74, 232, 55, 142, 168, 5, 225, 123, 168, 214, 122, 197, 99, 147, 86, 90],
+		[193, 222, 255, 30, 34, 199, 235, 164, 18, 226, 126, 61, 96, 135, 101, 136, 114, 101, 198, 3, 211, 201, 12, 121, 37, 183, 0, 108, 81, 189, 164, 151],
+		[90, 246, 10, 19, 251, 72, 30, 244, 47, 102, 240, 89, 248, 249, 152, 69, 23, 98, 230, 164, 176, 174, 140, 165, 211, 113, 11, 195, 58, 134, 138, 233],
 	],
 	[
-		[95, 7, 24, 187, 161, 88, 107, 243, 12, 80, 199, 43, 217, 144, 184, 111, 172, 203, 29, 238, 164, 243, 150, 58, 91, 247, 252, 159, 95, 41, 195, 185],
-		[198, 252, 171, 13, 237, 62, 63, 4, 246, 148, 153, 125, 126, 57, 211, 219, 80, 31, 30, 138, 77, 187, 220, 246, 16, 153, 151, 58, 248, 56, 74, 30],
-		[126, 25, 10, 74, 118, 147, 62, 66, 5, 249, 223, 251, 207, 224, 235, 189, 116, 139, 48, 225, 60, 131, 142, 211, 134, 27, 213, 111, 230, 110, 38, 15],
-		[173, 20, 16, 223, 62, 182, 29, 69, 109, 57, 150, 112, 106, 102, 196, 64, 222, 175, 234, 25, 211, 149, 149, 157, 116, 25, 224, 14, 165, 52, 11, 67],
+		[194, 244, 38, 158, 245, 53, 209, 116, 196, 157, 243, 246, 161, 125, 120, 151, 184, 164, 197, 91, 244, 154, 16, 39, 11, 250, 218, 52, 111, 57, 234, 160],
+		[86, 33, 75, 189, 41, 135, 197, 239, 109, 69, 238, 103, 175, 213, 17, 252, 245, 250, 2, 15, 29, 236, 8, 234, 234, 96, 92, 40, 122, 240, 25, 138],
+		[9, 236, 99, 47, 91, 128, 9, 70, 177, 241, 223, 251, 158, 226, 12, 35, 238, 129, 75, 171, 173, 248, 192, 193, 154, 220, 228, 228, 243, 80, 21, 85],
+		[183, 83, 53, 0, 244, 143, 143, 217, 154, 17, 143, 30, 70, 163, 47, 189, 135, 106, 156, 119, 237, 106, 214, 84, 165, 210, 227, 84, 15, 154, 25, 113],
 	],
 	[
-		[149, 99, 204, 207, 97, 51, 52, 66, 180, 126, 121, 174, 199, 65, 188, 103, 183, 11, 180, 136, 8, 198, 94, 241, 15, 71, 146, 163, 23, 25, 135, 132],
-		[98, 87, 97, 140, 94, 181, 221, 141, 68, 219, 1, 114, 104, 96, 90, 16, 217, 221, 247, 5, 164, 120, 117, 4, 201, 140, 206, 245, 216, 221, 241, 196],
-		[134, 201, 252, 149, 148, 77, 101, 19, 30, 194, 116, 129, 33, 62, 200, 244, 152, 159, 163, 211, 28, 54, 1, 29, 185, 130, 146, 75, 41, 32, 24, 0],
-		[65, 116, 229, 128, 36, 119, 217, 25, 1, 60, 108, 72, 126, 159, 50, 141, 96, 0, 173, 240, 64, 74, 114, 47, 97, 68, 194, 212, 140, 182, 182, 217],
+		[249, 3, 7, 26, 14, 90, 62, 255, 146, 193, 150, 123, 57, 33, 101, 34, 165, 163, 237, 83, 252, 162, 97, 204, 109, 103, 61, 49, 133, 59, 138, 0],
+		[149, 194, 182, 211, 176, 14, 182, 153, 217, 162, 15, 159, 225, 82, 107, 180, 164, 24, 236, 0, 255, 16, 27, 149, 26, 205, 185, 75, 20, 162, 95, 55],
+		[89, 244, 158, 228, 205, 93, 187, 229, 204, 227, 39, 226, 153, 18, 49, 178, 233, 232, 39, 152, 136, 118, 163, 63, 60, 243, 237, 53, 220, 246, 111, 8],
+		[249, 198, 82, 185, 53, 37, 121, 99, 234, 233, 107, 210, 15, 125, 28, 37, 70, 115, 147, 206, 248, 138, 17, 41, 215, 35, 194, 55, 200, 212, 178, 200],
 	],
 	[
-		[156, 160, 65, 1, 177, 212, 172, 209, 85, 155, 126, 93, 140, 151, 37, 42, 117, 181, 241, 59, 64, 212, 55, 52, 171, 59, 86, 244, 100, 113, 231, 241],
-		[21, 86, 185, 252, 165, 147, 206, 106, 216, 240, 90, 87, 250, 231, 57, 178, 109, 75, 248, 235, 44, 195, 20, 21, 219, 46, 98, 161, 238, 215, 73, 162],
-		[173, 108, 62, 65, 33, 111, 10, 71, 238, 80, 95, 2, 127, 229, 174, 130, 119, 153, 191, 225, 12, 7, 216, 36, 143, 218, 84, 39, 202, 97, 34, 88],
-		[37, 79, 218, 102, 146, 234, 176, 26, 145, 123, 87, 99, 96, 9, 78, 96, 193, 239, 183, 160, 126, 139, 116, 182, 67, 55, 189, 250, 183, 73, 56, 194],
+		[147, 218, 111, 113, 148, 230, 165, 110, 49, 231, 148, 106, 158, 65, 110, 146, 62, 36, 53, 119, 14, 136, 175, 102, 90, 27, 72, 182, 136, 90, 253, 75],
+		[12, 17, 243, 84, 56, 205, 42, 197, 108, 255, 191, 18, 178, 129, 150, 212, 238, 2, 103, 153, 143, 130, 115, 146, 31, 209, 242, 238, 211, 102, 154, 183],
+		[202, 38, 169, 31, 225, 220, 120, 134, 71, 173, 9, 160, 40, 2, 144, 249, 39, 243, 85, 7, 246, 255, 5, 12, 151, 64, 73, 7, 182, 29, 132, 135],
+		[233, 201, 59, 59, 138, 82, 214, 43, 101, 192, 86, 5, 1, 182, 162, 178, 18, 235, 225, 254, 42, 171, 47, 244, 156, 191, 113, 209, 231, 176, 57, 40],
 	],
 	[
-		[110, 150, 150, 238, 206, 178, 12, 111, 229, 90, 191, 5, 192, 190, 62, 250, 11, 188, 159, 232, 246, 154, 202, 116, 221, 176, 233, 166, 73, 125, 123, 91],
-		[242, 248, 231, 18, 134, 52, 58, 195, 215, 132, 125, 237, 60, 77, 200, 23, 42, 224, 92, 222, 240, 48, 84, 242, 73, 48, 6, 41, 78, 26, 248, 216],
-		[80, 250, 206, 93, 134, 104, 192, 1, 104, 35, 120, 230, 54, 123, 100, 24, 22, 161, 225, 25, 63, 20, 24, 231, 103, 56, 29, 28, 148, 37, 73, 163],
-		[227, 122, 40, 24, 174, 105, 162, 98, 3, 17, 162, 18, 36, 37, 244, 253, 40, 10, 196, 24, 224, 255, 208, 78, 36, 233, 123, 107, 234, 1, 239, 67],
+		[69, 246, 139, 153, 94, 27, 107, 196, 47, 15, 166, 144, 172, 125, 221, 240, 20, 34, 240, 128, 182, 161, 224, 118, 7, 108, 12, 80, 4, 72, 207, 1],
+		[206, 84, 139, 54, 47, 66, 184, 94, 3, 57, 183, 105, 164, 190, 220, 94, 236, 202, 183, 123, 93, 188, 152, 186, 32, 47, 43, 2, 62, 224, 58, 46],
+		[40, 145, 126, 229, 89, 61, 148, 91, 58, 73, 53, 169, 75, 121, 224, 192, 11, 72, 240, 198, 79, 135, 111, 5, 9, 210, 246, 50, 222, 118, 106, 78],
+		[91, 44, 15, 178, 115, 49, 63, 58, 46, 81, 91, 66, 90, 40, 232, 87, 5, 80, 120, 191, 70, 18, 12, 215, 204, 206, 190, 57, 146, 90, 54, 177],
 	],
 	[
-		[1, 251, 154, 244, 242, 24, 53, 76, 179, 201, 51, 53, 162, 67, 172, 222, 159, 97, 133, 70, 110, 136, 84, 60, 18, 171, 68, 145, 146, 160, 216, 192],
-		[57, 30, 32, 218, 15, 254, 117, 206, 33, 63, 10, 162, 178, 125, 30, 174, 247, 51, 175, 72, 182, 142, 123, 156, 152, 83, 251, 50, 30, 138, 156, 188],
-		[154, 38, 99, 10, 29, 28, 169, 14, 230, 184, 14, 197, 181, 98, 95, 10, 170, 243, 215, 215, 165, 45, 233, 159, 225, 179, 124, 87, 93, 179, 140, 23],
-		[6, 175, 53, 78, 187, 27, 85, 225, 87, 121, 249, 24, 181, 192, 123, 150, 49, 247, 206, 238, 94, 166, 35, 60, 198, 5, 116, 58, 210, 134, 115, 219],
+		[117, 159, 63, 72, 183, 252, 102, 13, 102, 37, 28, 11, 90, 65, 239, 78, 65, 170, 17, 98, 106, 2, 88, 220, 98, 23, 29, 85, 239, 234, 38, 192],
+		[139, 108, 11, 200, 111, 217, 81, 228, 12, 177, 139, 49, 195, 14, 101, 70, 181, 185, 156, 177, 175, 172, 50, 98, 13, 195, 255, 52, 75, 46, 238, 221],
+		[239, 231, 97, 65, 133, 91, 126, 77, 24, 18, 38, 209, 227, 174, 138, 120, 123, 238, 201, 175, 221, 134, 201, 117, 176, 28, 209, 164, 202, 207, 91, 82],
+		[114, 44, 124, 104, 253, 32, 59, 114, 135, 18, 204, 207, 223, 254, 55, 66, 35, 211, 225, 121, 37, 84, 230, 43, 85, 103, 99, 45, 230, 13, 6, 135],
 	],
 	[
-		[6, 41, 192, 52, 88, 10, 78, 245, 103, 154, 174, 191, 45, 172, 11, 126, 86, 191, 8, 252, 32, 58, 220, 144, 13, 53, 92, 160, 144, 235, 103, 132],
-		[78, 236, 4, 74, 160, 110, 181, 134, 170, 149, 157, 40, 246, 164, 79, 46, 235, 104, 191, 185, 65, 66, 114, 89, 85, 247, 188, 30, 246, 197, 114, 155],
-		[145, 69, 26, 223, 145, 197, 82, 181, 242, 161, 123, 1, 252, 208, 50, 45, 200, 130, 182, 193, 49, 153, 88, 116, 110, 187, 227, 145, 52, 244, 104, 50],
-		[135, 240, 150, 69, 145, 18, 162, 58, 33, 154, 40, 62, 144, 192, 169, 203, 180, 58, 0, 248, 100, 88, 27, 80, 150, 137, 161, 183, 109, 165, 93, 91],
+		[139, 188, 23, 159, 188, 165, 115, 171, 118, 16, 196, 131, 209, 232, 170, 197, 10, 74, 217, 252, 89, 112, 13, 81, 25, 187, 42, 237, 48, 101, 193, 211],
+		[25, 139, 107, 178, 38, 44, 255, 184, 113, 153, 55, 100, 125, 9, 10, 81, 171, 240, 174, 32, 183, 223, 17, 72, 243, 241, 149, 241, 119, 37, 241, 235],
+		[74, 114, 141, 90, 86, 188, 48, 15, 36, 236, 95, 28, 176, 120, 46, 223, 101, 85, 138, 10, 155, 250, 214, 179, 122, 216, 73, 248, 61, 190, 56, 62],
+		[211, 160, 15, 28, 188, 82, 18, 98, 189, 118, 214, 161, 92, 125, 27, 57, 55, 85, 20, 7, 3, 8, 134, 117, 157, 55, 159, 220, 235, 80, 46, 94],
 	],
 	[
-		[29, 50, 91, 69, 170, 186, 174, 55, 96, 202, 126, 19, 185, 8, 161, 14, 187, 171, 216, 199, 155, 35, 116, 115, 238, 175, 254, 109, 68, 32, 182, 168],
-		[185, 43, 137, 72, 100, 191, 15, 107, 22, 226, 232, 104, 150, 162, 20, 124, 55, 198, 89, 139, 26, 54, 70, 36, 182, 252, 9, 249, 151, 110, 163, 172],
-		[154, 58, 231, 229, 237, 207, 237, 222, 226, 95, 190, 238, 237, 63, 67, 170, 31, 181, 162, 249, 88, 69, 24, 93, 105, 163, 23, 245, 253, 45, 71, 85],
-		[174, 7, 53, 246, 27, 115, 160, 72, 242, 123, 77, 77, 255, 209, 54, 25, 211, 47, 72, 235, 153, 215, 81, 222, 237, 229, 201, 32, 132, 30, 236, 170],
+		[233, 52, 92, 186, 119, 84, 74, 20, 236, 108, 88, 162, 112, 24, 31, 28, 70, 71, 18, 182, 148, 61, 91, 24, 201, 157, 156, 102, 29, 202, 51, 233],
+		[211, 77, 236, 42, 186, 231, 108, 165, 68, 40, 56, 123, 140, 14, 220, 160, 255, 144, 157, 150, 202, 93, 203, 66, 69, 163, 169, 70, 201, 67, 62, 237],
+		[181, 6, 238, 90, 157, 117, 29, 203, 234, 227, 11, 119, 61, 248, 69, 173, 83, 135, 120, 170, 130, 225, 249, 127, 3, 22, 255, 69, 117, 178, 238, 47],
+		[165, 204, 222, 171, 4, 175, 63, 11, 246, 205, 207, 125, 177, 134, 97, 201, 10, 166, 44, 232, 15, 45, 68, 30, 88, 248, 8, 232, 131, 89, 138, 31],
 	],
 	[
-		[249, 209, 245, 250, 8, 85, 43, 72, 10, 125, 90, 79, 225, 92, 39, 145, 172, 72, 91, 198, 116, 233, 103, 4, 186, 249, 139, 174, 206, 203, 31, 101],
-		[151, 43, 105, 225, 20, 8, 26, 217, 75, 211, 121, 112, 210, 222, 47, 49, 159, 156, 19, 58, 218, 5, 120, 54, 195, 202, 136, 212, 120, 131, 195, 231],
-		[99, 47, 12, 141, 139, 141, 99, 146, 168, 227, 36, 198, 91, 82, 255, 138, 201, 139, 173, 6, 145, 124, 83, 135, 120, 32, 170, 16, 86, 40, 59, 222],
-		[157, 208, 81, 212, 65, 58, 245, 131, 78, 110, 3, 85, 101, 41, 19, 70, 149, 9, 102, 144, 51, 159, 130, 191, 44, 59, 32, 73, 36, 167, 88, 18],
+		[184, 241, 217, 140, 96, 7, 194, 243, 204, 126, 90, 28, 242, 137, 170, 153, 128, 115, 190, 194, 121, 85, 86, 229, 36, 21, 9, 132, 61, 188, 41, 107],
+		[35, 233, 106, 180, 96, 227, 157, 173, 11, 162, 250, 128, 31, 14, 181, 16, 139, 185, 193, 208, 32, 222, 112, 147, 108, 51, 235, 74, 21, 204, 101, 47],
+		[186, 236, 184, 99, 93, 187, 214, 62, 25, 22, 149, 109, 28, 234, 71, 237, 172, 13, 83, 237, 6, 223, 14, 0, 179, 84, 94, 28, 228, 213, 186, 88],
+		[234, 226, 216, 249, 236, 40, 84, 163, 162, 28, 48, 111, 194, 221, 32, 137, 231, 246, 91, 15, 117, 143, 94, 206, 31, 62, 154, 240, 67, 227, 184, 9],
 	],
 	[
-		[125, 117, 118, 188, 246, 52, 162, 58, 0, 47, 2, 151, 111, 55, 34, 76, 21, 131, 93, 13, 71, 236, 252, 55, 68, 122, 154, 165, 59, 219, 68, 218],
-		[6, 77, 35, 123, 227, 3, 90, 177, 30, 75, 116, 98, 240, 147, 144, 221, 99, 92, 174, 243, 228, 139, 14, 238, 147, 251, 23, 23, 239, 98, 149, 14],
-		[249, 219, 20, 110, 118, 222, 83, 124, 136, 122, 70, 156, 152, 0, 62, 148, 23, 5, 114, 30, 55, 175, 233, 225, 206, 66, 173, 34, 44, 180, 3, 155],
-		[69, 229, 42, 112, 103, 172, 4, 248, 180, 226, 243, 191, 70, 106, 43, 204, 65, 60, 231, 153, 45, 239, 100, 7, 196, 4, 237, 189, 67, 87, 213, 75],
+		[148, 168, 176, 164, 110, 13, 245, 152, 55, 34, 250, 38, 56, 96, 229, 240, 144, 44, 245, 55, 216, 218, 133, 181, 113, 168, 86, 205, 155, 112, 170, 132],
+		[243, 74, 178, 79, 181, 23, 93, 73, 192, 76, 209, 152, 75, 46, 16, 244, 254, 43, 99, 205, 52, 158, 19, 31, 122, 186, 160, 36, 191, 229, 230, 106],
+		[225, 236, 94, 125, 21, 175, 160, 255, 109, 248, 28, 250, 164, 230, 20, 32, 70, 139, 181, 12, 197, 235, 139, 39, 228, 71, 72, 63, 210, 239, 166, 54],
+		[160, 80, 172, 15, 104, 232, 155, 113, 219, 17, 23, 236, 211, 241, 26, 161, 91, 5, 48, 244, 174, 29, 237, 125, 79, 167, 187, 240, 174, 100, 9, 95],
 	],
 	[
-		[6, 238, 198, 216, 98, 79, 230, 153, 165, 73, 56, 252, 153, 162, 54, 153, 204, 139, 116, 121, 65, 139, 20, 193, 206, 96, 125, 32, 179, 220, 39, 154],
-		[168, 39, 108, 164, 198, 151, 150, 2, 214, 40, 71, 96, 93, 32, 4, 252, 151, 251, 203, 159, 198, 153, 40, 205, 252, 156, 130, 224, 203, 136, 138, 181],
-		[187, 39, 182, 82, 119, 90, 181, 16, 198, 56, 127, 10, 225, 125, 38, 202, 185, 55, 175, 184, 45, 97, 89, 95, 165, 125, 37, 147, 158, 184, 49, 242],
-		[185, 9, 2, 98, 228, 80, 136, 103, 151, 68, 175, 226, 120, 233, 31, 199, 200, 174, 39, 143, 250, 7, 114, 247, 152, 210, 40, 20, 241, 109, 246, 93],
+		[4, 195, 41, 189, 0, 179, 219, 24, 240, 163, 40, 225, 173, 225, 227, 212, 172, 48, 179, 116, 147, 34, 88, 86, 46, 179, 226, 81, 92, 137, 193, 221],
+		[141, 27, 132, 121, 79, 4, 195, 183, 104, 221, 243, 213, 187, 164, 38, 106, 213, 149, 119, 43, 19, 164, 90, 231, 41, 151, 180, 101, 159, 131, 135, 71],
+		[64, 190, 161, 2, 109, 114, 213, 204, 239, 102, 197, 208, 110, 58, 0, 208, 202, 239, 69, 137, 187, 95, 122, 18, 80, 203, 71, 187, 140, 86, 38, 131],
+		[211, 126, 211, 150, 97, 171, 20, 8, 97, 179, 197, 178, 182, 34, 226, 96, 201, 192, 137, 195, 55, 124, 110, 49, 198, 136, 41, 74, 3, 99, 243, 145],
 	],
 	[
-		[155, 28, 109, 64, 192, 47, 81, 4, 179, 237, 138, 210, 169, 48, 106, 173, 180, 0, 114, 215, 221, 7, 134, 110, 31, 189, 135, 149, 129, 100, 206, 157],
-		[31, 222, 128, 55, 2, 51, 175, 117, 206, 51, 122, 242, 166, 244, 83, 118, 214, 206, 209, 211, 170, 16, 86, 31, 200, 201, 199, 176, 73, 187, 75, 210],
-		[30, 11, 219, 48, 144, 32, 60, 77, 5, 156, 147, 143, 221, 155, 243, 24, 91, 146, 209, 179, 178, 125, 43, 246, 142, 7, 56, 108, 86, 185, 221, 36],
-		[80, 255, 214, 136, 255, 189, 86, 175, 99, 7, 153, 182, 98, 155, 66, 16, 211, 140, 185, 135, 112, 163, 189, 58, 33, 188, 172, 141, 178, 209, 45, 194],
+		[182, 166, 192, 216, 164, 238, 148, 20, 213, 101, 69, 59, 199, 86, 68, 157, 190, 147, 176, 5, 228, 32, 34, 243, 150, 149, 255, 192, 172, 56, 117, 231],
+		[249, 140, 121, 44, 77, 155, 65, 175, 150, 190, 220, 205, 63, 79, 54, 162, 95, 52, 185, 144, 167, 246, 223, 224, 54, 153, 86, 251, 170, 44, 23, 157],
+		[66, 66, 162, 164, 209, 252, 19, 191, 8, 238, 71, 115, 111, 18, 205, 141, 40, 115, 73, 97, 132, 227, 20, 228, 240, 143, 156, 199, 49, 253, 176, 61],
+		[241, 57, 143, 191, 62, 110, 149, 169, 204, 29, 243, 162, 104, 248, 46, 184, 186, 204, 240, 147, 65, 214, 70, 67, 152, 160, 24, 9, 243, 88, 190, 182],
 	],
 	[
-		[0, 241, 201, 235, 4, 117, 208, 193, 11, 251, 157, 3, 102, 191, 180, 66, 237, 235, 33, 88, 12, 203, 55, 93, 114, 150, 234, 61, 209, 163, 155, 199],
-		[163, 210, 134, 243, 98, 212, 140, 74, 34, 76, 46, 183, 107, 201, 84, 169, 184, 136, 233, 8, 71, 131, 56, 179, 4, 93, 154, 76, 87, 161, 99, 200],
-		[168, 80, 68, 202, 67, 83, 22, 122, 169, 192, 128, 245, 69, 30, 222, 208, 75, 182, 126, 85, 228, 13, 83, 168, 128, 8, 113, 173, 111, 132, 107, 215],
-		[55, 185, 12, 57, 223, 50, 106, 141, 103, 54, 179, 42, 94, 4, 179, 159, 64, 151, 21, 164, 207, 166, 6, 66, 110, 155, 81, 183, 179, 23, 78, 103],
+		[68, 33, 233, 96, 130, 38, 182, 92, 39, 174, 185, 131, 57, 243, 55, 67, 129, 144, 92, 112, 175, 90, 254, 202, 53, 238, 51, 19, 89, 79, 13, 174],
+		[142, 140, 144, 190, 22, 88, 11, 90, 249, 75, 26, 103, 167, 68, 253, 113, 37, 141, 133, 38, 0, 97, 13, 31, 153, 10, 20, 193, 245, 23, 5, 10],
+		[118, 46, 184, 226, 213, 138, 166, 208, 17, 120, 52, 180, 174, 210, 220, 161, 145, 228, 201, 105, 111, 20, 152, 245, 77, 112, 57, 157, 209, 204, 141, 61],
+		[207, 250, 22, 142, 245, 37, 70, 196, 111, 24, 67, 34, 238, 215, 73, 177, 9, 13, 130, 57, 192, 73, 0, 140, 11, 145, 123, 154, 160, 142, 177, 206],
 	],
 	[
-		[216, 82, 3, 247, 202, 75, 21, 207, 172, 97, 69, 158, 6, 120, 252, 28, 43, 49, 220, 196, 94, 9, 178, 113, 209, 10, 237, 136, 59, 88, 233, 182],
-		[88, 201, 177, 8, 137, 231, 64, 51, 37, 20, 89, 90, 154, 230, 168, 86, 132, 77, 233, 128, 155, 133, 188, 50, 124, 144, 109, 248, 210, 16, 43, 243],
-		[244, 129, 59, 169, 65, 123, 222, 207, 208, 125, 25, 126, 142, 150, 173, 112, 129, 203, 185, 28, 71, 35, 19, 131, 207, 104, 48, 148, 96, 117, 216, 157],
-		[83, 224, 6, 2, 27, 204, 101, 245, 138, 207, 235, 32, 151, 194, 171, 124, 207, 147, 235, 64, 220, 155, 39, 164, 151, 234, 87, 211, 36, 152, 168, 68],
+		[90, 57, 21, 69, 192, 94, 123, 54, 225, 34, 47, 83, 199, 131, 44, 68, 55, 194, 47, 32, 74, 6, 41, 139, 152, 145, 200, 169, 155, 243, 105, 191],
+		[240, 2, 93, 57, 66, 217, 12, 46, 241, 39, 116, 229, 79, 234, 128, 109, 90, 248, 46, 93, 235, 67, 209, 31, 47, 6, 172, 207, 25, 236, 157, 215],
+		[62, 70, 193, 77, 76, 160, 177, 47, 96, 25, 157, 94, 203, 204, 21, 62, 86, 43, 153, 35, 91, 61, 174, 31, 213, 26, 102, 44, 62, 243, 196, 213],
+		[2, 221, 169, 194, 72, 213, 27, 172, 158, 252, 177, 2, 241, 31, 117, 221, 173, 106, 63, 130, 200, 72, 153, 247, 16, 69, 152, 228, 176, 151, 136, 73],
 	],
 	[
-		[196, 151, 241, 108, 191, 146, 126, 227, 71, 118, 229, 162, 88, 32, 121, 155, 216, 141, 166, 149, 227, 80, 210, 0, 131, 252, 29, 184, 161, 112, 173, 110],
-		[91, 109, 179, 151, 245, 207, 213, 105, 36, 108, 248, 231, 231, 216, 186, 9, 177, 139, 152, 3, 61, 118, 101, 170, 20, 74, 226, 188, 135, 51, 98, 233],
-		[79, 71, 134, 221, 189, 120, 73, 129, 96, 180, 167, 17, 109, 255, 37, 226, 132, 61, 214, 24, 80, 113, 230, 55, 13, 238, 99, 25, 58, 25, 205, 17],
-		[100, 179, 72, 169, 223, 67, 143, 110, 206, 69, 58, 100, 43, 85, 161, 235, 51, 28, 11, 174, 106, 105, 197, 105, 43, 66, 111, 23, 78, 104, 244, 216],
+		[22, 161, 231, 36, 153, 18, 195, 84, 70, 54, 209, 36, 149, 10, 238, 4, 124, 107, 24, 110, 89, 186, 83, 99, 247, 75, 48, 72, 196, 222, 21, 2],
+		[208, 136, 245, 61, 188, 211, 157, 53, 202, 132, 213, 225, 94, 217, 131, 146, 25, 145, 171, 80, 54, 28, 230, 40, 244, 182, 105, 204, 121, 212, 219, 199],
+		[61, 156, 210, 198, 231, 218, 157, 164, 150, 182, 231, 119, 11, 139, 249, 112, 174, 210, 196, 47, 188, 15, 128, 67, 10, 251, 223, 242, 50, 117, 184, 156],
+		[139, 161, 85, 246, 23, 169, 183, 101, 248, 86, 178, 87, 95, 62, 125, 48, 70, 161, 165, 217, 114, 137, 118, 240, 121, 31, 197, 163, 8, 234, 105, 82],
 	],
 	[
-		[111, 212, 37, 249, 235, 92, 93, 54, 150, 218, 23, 28, 225, 4, 230, 225, 68, 69, 170, 125, 229, 99, 124, 42, 117, 43, 186, 186, 87, 14, 175, 2],
-		[191, 36, 142, 205, 96, 24, 98, 27, 211, 31, 131, 44, 69, 76, 42, 87, 245, 95, 107, 221, 40, 212, 167, 111, 166, 243, 77, 160, 138, 52, 164, 48],
-		[22, 16, 189, 140, 223, 216, 131, 198, 103, 145, 196, 17, 74, 58, 184, 24, 116, 218, 61, 81, 104, 34, 36, 255, 149, 236, 104, 37, 253, 249, 146, 185],
-		[123, 108, 236, 107, 54, 46, 137, 88, 35, 55, 13, 148, 238, 237, 230, 121, 16, 46, 66, 14, 240, 201, 214, 86, 39, 144, 49, 96, 254, 72, 84, 110],
+		[47, 45, 89, 118, 202, 76, 232, 245, 171, 39, 206, 238, 101, 239, 176, 228, 175, 122, 229, 169, 149, 2, 38, 167, 130, 83, 110, 204, 231, 18, 89, 68],
+		[187, 224, 245, 252, 189, 136, 201, 59, 253, 233, 191, 92, 206, 126, 69, 119, 182, 54, 51, 184, 13, 169, 248, 48, 165, 95, 90, 84, 205, 22, 144, 78],
+		[25, 51, 55, 130, 80, 196, 75, 183, 69, 29, 238, 163, 20, 94, 195, 17, 140, 136, 5, 124, 7, 80, 85, 47, 222, 209, 165, 150, 204, 207, 176, 61],
+		[207, 133, 154, 220, 171, 61, 10, 0, 73, 69, 94, 190, 108, 130, 38, 200, 202, 17, 219, 10, 62, 232, 233, 132, 29, 140, 225, 175, 52, 25, 61, 243],
 	],
 	[
-		[219, 200, 215, 82, 95, 40, 120, 134, 24, 160, 62, 59, 88, 8, 87, 0, 203, 87, 166, 83, 220, 208, 236, 84, 86, 119, 139, 174, 37, 61, 57, 112],
-		[66, 55, 80, 55, 59, 184, 232, 50, 115, 212, 3, 39, 187, 109, 236, 247, 175, 56, 197, 211, 204, 238, 33, 184, 78, 158, 233, 79, 21, 219, 182, 175],
-		[97, 158, 121, 185, 40, 47, 183, 228, 199, 243, 152, 6, 58, 101, 158, 80, 16, 152, 20, 105, 76, 100, 236, 129, 159, 13, 184, 50, 195, 3, 156, 132],
-		[151, 67, 95, 98, 243, 226, 241, 150, 145, 249, 83, 205, 184, 52, 200, 217, 1, 31, 137, 56, 40, 50, 27, 17, 90, 134, 154, 175, 35, 163, 170, 184],
+		[188, 75, 173, 22, 74, 79, 41, 69, 58, 196, 11, 59, 105, 14, 221, 88, 71, 87, 234, 240, 73, 73, 159, 35, 250, 41, 56, 91, 18, 131, 17, 2],
+		[172, 49, 18, 46, 194, 23, 215, 238, 23, 116, 58, 170, 26, 76, 181, 39, 166, 83, 248, 191, 61, 211, 70, 244, 215, 70, 25, 227, 212, 191, 244, 0],
+		[64, 190, 255, 223, 159, 246, 28, 59, 100, 11, 254, 58, 243, 99, 18, 226, 35, 203, 219, 89, 50, 153, 81, 159, 208, 94, 124, 220, 96, 251, 101, 202],
+		[41, 54, 151, 28, 197, 89, 78, 14, 232, 186, 213, 67, 139, 193, 229, 26, 254, 3, 65, 169, 185, 65, 0, 161, 219, 20, 226, 6, 222, 108, 244, 192],
 	],
 	[
-		[221, 137, 183, 126, 27, 125, 183, 159, 6, 157, 50, 166, 55, 250, 205, 60, 132, 65, 244, 24, 194, 168, 230, 100, 137, 40, 148, 4, 248, 45, 126, 187],
-		[17, 80, 43, 200, 185, 15, 201, 247, 121, 123, 51, 251, 3, 39, 90, 18, 146, 118, 249, 172, 162, 214, 67, 79, 193, 9, 80, 113, 89, 128, 126, 35],
-		[187, 106, 242, 124, 92, 246, 201, 139, 44, 177, 6, 59, 181, 139, 119, 132, 214, 42, 218, 243, 208, 129, 89, 81, 95, 92, 85, 130, 128, 96, 121, 184],
-		[242, 97, 95, 150, 220, 56, 130, 225, 93, 141, 77, 102, 11, 73, 101, 128, 202, 205, 243, 160, 49, 204, 185, 20, 85, 110, 50, 212, 173, 171, 66, 154],
+		[163, 223, 75, 61, 10, 212, 228, 230, 192, 113, 44, 62, 115, 132, 177, 218, 22, 19, 166, 81, 116, 189, 72, 62, 62, 73, 200, 125, 243, 4, 51, 39],
+		[228, 234, 11, 132, 50, 7, 140, 165, 211, 230, 241, 183, 19, 78, 23, 22, 63, 241, 112, 229, 25, 22, 107, 76, 121, 196, 155, 194, 50, 174, 208, 31],
+		[103, 108, 107, 150, 144, 44, 32, 80, 80, 100, 187, 70, 183, 143, 168, 25, 104, 152, 75, 170, 70, 136, 121, 205, 239, 187, 252, 192, 123, 35, 48, 81],
+		[85, 23, 4, 3, 86, 233, 116, 144, 46, 135, 211, 168, 92, 247, 104, 31, 140, 99, 118, 141, 88, 234, 151, 192, 158, 134, 22, 12, 157, 3, 135, 73],
 	],
 	[
-		[205, 209, 208, 115, 56, 241, 55, 233, 133, 9, 11, 23, 249, 63, 155, 134, 252, 111, 54, 181, 127, 253, 133, 242, 96, 147, 20, 147, 153, 97, 180, 81],
-		[29, 107, 2, 234, 205, 187, 117, 179, 182, 92, 130, 165, 49, 146, 74, 16, 222, 225, 137, 180, 142, 87, 13, 195, 35, 121, 13, 152, 189, 159, 10, 242],
-		[120, 247, 139, 79, 145, 159, 196, 26, 32, 139, 207, 202, 202, 202, 89, 4, 4, 202, 87, 124, 28, 245, 62, 181, 187, 227, 174, 135, 247, 90, 150, 57],
-		[185, 97, 35, 127, 6, 239, 61, 115, 218, 245, 33, 245, 235, 105, 177, 135, 74, 197, 246, 58, 173, 247, 83, 72, 213, 234, 110, 25, 242, 225, 224, 236],
+		[139, 206, 22, 27, 59, 152, 175, 165, 157, 154, 149, 158, 82, 100, 160, 232, 223, 156, 188, 62, 82, 131, 139, 8, 221, 191, 68, 138, 51, 149, 198, 139],
+		[180, 186, 84, 182, 144, 198, 131, 126, 75, 186, 86, 217, 13, 7, 151, 85, 248, 200, 5, 168, 94, 141, 92, 73, 151, 174, 11, 9, 1, 28, 234, 156],
+		[73, 83, 60, 120, 80, 107, 81, 215, 115, 6, 117, 226, 40, 85, 65, 129, 176, 221, 174, 204, 53, 73, 181, 242, 164, 72, 37, 44, 159, 55, 117, 163],
+		[33, 92, 167, 55, 133, 12, 102, 109, 203, 102, 219, 100, 29, 127, 127, 141, 121, 79, 209, 205, 54, 202, 225, 224, 222, 26, 19, 176, 196, 229, 200, 24],
 	],
 	[
-		[197, 255, 11, 111, 143, 104, 231, 131, 195, 3, 149, 87, 184, 87, 189, 70, 105, 185, 131, 217, 114, 31, 66, 155, 139, 210, 57, 190, 29, 115, 67, 161],
-		[132, 117, 253, 51, 78, 88, 216, 140, 129, 105, 45, 136, 54, 113, 218, 16, 231, 199, 90, 178, 28, 7, 181, 196, 110, 31, 232, 83, 21, 2, 195, 33],
-		[190, 125, 222, 248, 158, 108, 30, 129, 103, 72, 92, 4, 40, 58, 230, 222, 37, 152, 249, 126, 227, 163, 126, 105, 191, 74, 81, 56, 85, 162, 78, 60],
-		[143, 149, 167, 207, 222, 31, 144, 113, 216, 176, 135, 150, 82, 28, 146, 201, 171, 106, 220, 201, 118, 10, 219, 156, 46, 110, 97, 185, 7, 182, 28, 93],
+		[85, 7, 146, 229, 168, 71, 72, 26, 31, 126, 39, 142, 73, 225, 198, 4, 118, 213, 124, 114, 246, 87, 252, 166, 248, 176, 114, 215, 89, 79, 113, 173],
+		[147, 67, 131, 186, 113, 90, 36, 106, 236, 31, 67, 96, 254, 205, 153, 185, 159, 249, 122, 121, 174, 62, 115, 245, 156, 205, 144, 39, 154, 165, 210, 103],
+		[69, 172, 96, 29, 36, 129, 188, 95, 131, 228, 4, 78, 255, 191, 116, 213, 67, 56, 87, 191, 4, 151, 63, 97, 191, 56, 95, 90, 206, 122, 33, 178],
+		[222, 1, 182, 65, 22, 87, 1, 169, 189, 32, 128, 226, 137, 195, 29, 95, 176, 48, 221, 240, 121, 222, 113, 82, 67, 98, 125, 191, 29, 3, 1, 161],
 	],
 	[
-		[116, 187, 255, 211, 154, 14, 227, 156, 93, 132, 16, 229, 229, 173, 118, 160, 128, 34, 36, 1, 174, 181, 157, 66, 225, 72, 172, 135, 66, 143, 117, 35],
-		[0, 81, 62, 223, 218, 87, 16, 51, 245, 132, 197, 148, 211, 135, 213, 60, 104, 250, 173, 94, 210, 179, 100, 232, 82, 245, 233, 16, 205, 192, 97, 220],
-		[141, 158, 232, 56, 83, 25, 104, 43, 186, 33, 77, 207, 169, 238, 219, 185, 26, 2, 54, 128, 61, 152, 54, 124, 91, 202, 99, 255, 63, 52, 101, 30],
-		[103, 166, 167, 79, 128, 111, 23, 221, 131, 216, 235, 197, 120, 162, 188, 116, 70, 142, 176, 187, 25, 139, 112, 222, 141, 199, 176, 235, 187, 221, 252, 70],
+		[24, 230, 216, 60, 119, 56, 120, 89, 49, 13, 128, 221, 212, 227, 144, 250, 114, 71, 147, 151, 190, 152, 114, 21, 177, 27, 103, 47, 49, 116, 39, 184],
+		[145, 98, 239, 255, 247, 135, 178, 238, 236, 169, 119, 21, 200, 120, 27, 90, 58, 80, 154, 213, 149, 16, 112, 2, 98, 84, 123, 142, 1, 177, 247, 39],
+		[83, 128, 231, 144, 27, 249, 88, 169, 245, 37, 233, 1, 196, 219, 237, 205, 189, 196, 179, 126, 62, 165, 251, 27, 131, 201, 206, 189, 30, 62, 182, 182],
+		[222, 181, 73, 74, 21, 51, 55, 230, 179, 177, 137, 106, 210, 32, 15, 81, 46, 136, 39, 158, 90, 210, 43, 88, 105, 29, 7, 211, 61, 80, 188, 68],
 	],
 	[
-		[243, 233, 34, 94, 194, 252, 117, 151, 109, 228, 120, 219, 158, 236, 24, 22, 101, 89, 129, 118, 90, 117, 186, 51, 148, 70, 254, 50, 245, 243, 95, 199],
-		[226, 222, 53, 82, 121, 13, 19, 78, 33, 92, 50, 167, 144, 10, 232, 150, 14, 177, 18, 41, 193, 92, 109, 133, 172, 183, 237, 151, 87, 215, 74, 234],
-		[71, 69, 182, 4, 137, 133, 51, 48, 236, 147, 164, 254, 48, 97, 66, 68, 171, 16, 139, 182, 113, 220, 113, 161, 78, 31, 196, 26, 137, 89, 12, 237],
-		[93, 75, 84, 196, 229, 152, 181, 106, 7, 246, 106, 154, 39, 226, 239, 196, 12, 252, 146, 64, 110, 141, 31, 142, 214, 242, 15, 21, 68, 245, 254, 99],
+		[129, 39, 31, 219, 175, 16, 231, 186, 72, 211, 252, 45, 113, 180, 26, 82, 148, 29, 125, 77, 159, 126, 63, 58, 155, 184, 237, 97, 121, 185, 149, 207],
+		[253, 185, 254, 113, 205, 66, 39, 172, 81, 166, 216, 109, 210, 12, 84, 39, 180, 13, 69, 168, 222, 243, 144, 205, 160, 80, 57, 248, 130, 181, 162, 221],
+		[91, 57, 131, 9, 187, 11, 67, 2, 222, 107, 227, 24, 23, 188, 63, 41, 158, 132, 247, 120, 255, 3, 153, 194, 136, 202, 37, 238, 49, 203, 1, 52],
+		[153, 221, 139, 78, 68, 108, 151, 187, 148, 177, 242, 71, 11, 232, 181, 183, 18, 147, 77, 47, 40, 206, 77, 251, 30, 28, 120, 48, 89, 196, 38, 85],
 	],
 	[
-		[133, 215, 197, 12, 203, 10, 56, 57, 74, 49, 10, 195, 183, 42, 44, 87, 198, 29, 215, 65, 206, 126, 218, 51, 57, 34, 61, 124, 4, 87, 158, 230],
-		[89, 91, 13, 149, 194, 145, 134, 240, 17, 14, 74, 82, 12, 91, 126, 81, 135, 28, 49, 54, 234, 1, 98, 79, 190, 203, 5, 135, 29, 112, 253, 11],
-		[65, 154, 16, 150, 54, 194, 110, 60, 97, 34, 126, 211, 92, 102, 112, 106, 44, 233, 0, 126, 253, 119, 58, 18, 176, 46, 42, 28, 125, 117, 71, 54],
-		[22, 186, 94, 24, 168, 66, 58, 167, 149, 27, 48, 46, 188, 221, 25, 66, 6, 147, 198, 118, 26, 213, 80, 159, 228, 190, 4, 117, 20, 63, 197, 98],
+		[210, 65, 36, 80, 93, 113, 177, 147, 22, 58, 180, 66, 8, 182, 71, 87, 10, 27, 201, 166, 218, 143, 219, 124, 198, 142, 94, 24, 163, 53, 152, 16],
+		[196, 134, 213, 79, 39, 6, 243, 41, 37, 92, 239, 218, 36, 193, 52, 243, 170, 132, 210, 108, 130, 26, 213, 123, 117, 191, 207, 240, 45, 137, 131, 184],
+		[34, 72, 153, 214, 36, 215, 36, 148, 100, 141, 10, 6, 129, 17, 20, 96, 185, 31, 55, 255, 235, 247, 238, 248, 203, 117, 199, 142, 83, 147, 227, 113],
+		[244, 110, 86, 84, 151, 100, 51, 49, 249, 65, 125, 147, 225, 157, 7, 62, 89, 153, 91, 231, 219, 241, 198, 125, 86, 35, 72, 30, 117, 89, 40, 172],
 	],
 	[
-		[216, 204, 17, 250, 142, 216, 32, 207, 160, 92, 177, 145, 89, 180, 218, 101, 217, 208, 37, 182, 61, 42, 225, 60, 182, 6, 236, 218, 222, 200, 3, 195],
-		[237, 103, 222, 26, 75, 129, 138, 4, 207, 151, 64, 42, 89, 192, 108, 191, 241, 93, 76, 119, 201, 244, 131, 201, 212, 252, 249, 96, 132, 129, 92, 55],
-		[34, 29, 74, 28, 31, 199, 151, 63, 251, 81, 214, 59, 45, 7, 66, 239, 142, 119, 231, 181, 183, 90, 64, 34, 217, 45, 243, 92, 9, 31, 127, 142],
-		[89, 163, 17, 57, 12, 229, 233, 73, 179, 230, 166, 116, 64, 142, 118, 210, 120, 184, 234, 102, 60, 60, 182, 34, 6, 75, 64, 133, 188, 17, 214, 25],
+		[109, 26, 118, 169, 254, 209, 23, 211, 7, 82, 147, 23, 102, 182, 26, 112, 164, 243, 32, 246, 103, 163, 123, 186, 111, 147, 12, 39, 149, 183, 196, 198],
+		[69, 73, 172, 143, 183, 211, 205, 118, 227, 90, 38, 219, 25, 53, 101, 228, 180, 175, 118, 221, 19, 145, 85, 188, 120, 160, 107, 126, 103, 102, 121, 168],
+		[180, 72, 140, 119, 79, 234, 251, 210, 216, 28, 170, 233, 86, 65, 126, 214, 7, 136, 180, 78, 163, 47, 219, 73, 66, 150, 63, 148, 166, 14, 101, 166],
+		[209, 48, 129, 27, 121, 48, 50, 166, 36, 169, 85, 191, 70, 0, 81, 56, 149, 251, 13, 67, 236, 89, 52, 93, 118, 216, 128, 184, 115, 43, 171, 214],
 	],
 	[
-		[195, 39, 245, 249, 205, 83, 230, 111, 71, 8, 76, 196, 52, 146, 179, 165, 233, 209, 194, 68, 41, 155, 212, 245, 66, 107, 68, 244, 219, 116, 24, 149],
-		[179, 202, 54, 29, 31, 121, 110, 92, 249, 4, 143, 18, 150, 228, 51, 42, 237, 188, 67, 48, 61, 119, 185, 236, 44, 153, 112, 88, 1, 191, 242, 81],
-		[77, 155, 31, 151, 131, 251, 62, 62, 45, 178, 114, 50, 131, 130, 123, 47, 188, 235, 187, 246, 223, 86, 201, 57, 3, 35, 44, 42, 3, 0, 137, 115],
-		[138, 114, 49, 135, 174, 2, 167, 134, 249, 32, 17, 34, 107, 239, 16, 207, 201, 92, 109, 206, 119, 225, 132, 5, 139, 87, 229, 110, 152, 82, 230, 222],
+		[248, 50, 142, 195, 49, 140, 122, 215, 212, 191, 23, 69, 6, 126, 94, 81, 55, 205, 126, 76, 197, 201, 198, 174, 135, 134, 242, 17, 65, 199, 103, 26],
+		[110, 113, 226, 159, 156, 73, 109, 181, 246, 3, 67, 201, 74, 10, 165, 232, 23, 12, 123, 190, 133, 230, 13, 22, 143, 31, 159, 113, 113, 247, 83, 69],
+		[129, 169, 155, 196, 148, 171, 213, 128, 220, 153, 79, 211, 54, 202, 220, 195, 230, 230, 72, 84, 11, 181, 230, 36, 65, 48, 97, 186, 80, 100, 27, 160],
+		[171, 9, 90, 220, 236, 243, 105, 50, 170, 30, 204, 223, 152, 207, 107, 137, 52, 93, 167, 220, 60, 50, 100, 54, 202, 154, 116, 213, 173, 190, 164, 106],
 	],
 	[
-		[86, 134, 220, 165, 0, 105, 230, 134, 250, 94, 226, 111, 183, 158, 7, 248, 52, 25, 18, 103, 84, 96, 89, 67, 208, 69, 87, 112, 134, 120, 13, 137],
-		[48, 126, 67, 32, 166, 67, 84, 156, 107, 131, 49, 47, 106, 7, 128, 190, 80, 146, 55, 12, 160, 46, 111, 126, 185, 206, 44, 249, 99, 230, 116, 26],
-		[130, 46, 55, 143, 211, 200, 26, 27, 33, 163, 83, 93, 241, 214, 144, 12, 14, 31, 124, 236, 241, 103, 207, 23, 71, 116, 112, 13, 220, 73, 212, 182],
-		[180, 204, 36, 68, 139, 26, 196, 151, 200, 184, 238, 144, 242, 88, 150, 134, 238, 130, 93, 91, 205, 138, 215, 189, 153, 168, 223, 100, 160, 106, 121, 5],
+		[83, 107, 59, 213, 93, 171, 74, 46, 209, 198, 41, 137, 202, 48, 254, 193, 43, 136, 196, 85, 194, 142, 166, 181, 46, 106, 180, 103, 35, 14, 242, 17],
+		[125, 21, 82, 103, 247, 158, 185, 188, 83, 64, 5, 111, 130, 178, 62, 201, 72, 102, 216, 63, 95, 115, 116, 173, 8, 238, 5, 75, 27, 211, 99, 95],
+		[140, 253, 27, 213, 228, 179, 139, 211, 78, 231, 214, 132, 254, 55, 157, 62, 236, 72, 26, 49, 18, 156, 171, 211, 119, 50, 167, 189, 202, 70, 6, 12],
+		[100, 182, 252, 39, 96, 215, 254, 84, 174, 105, 120, 106, 21, 132, 109, 109, 138, 172, 98, 110, 255, 180, 108, 203, 229, 138, 25, 34, 127, 154, 142, 35],
 	],
 	[
-		[82, 25, 61, 31, 57, 18, 69, 14, 217, 237, 246, 34, 77, 159, 147, 55, 23, 13, 240, 1, 134, 243, 81, 83, 219, 157, 201, 236, 187, 209, 104, 58],
-		[125, 193, 91, 109, 85, 255, 201, 146, 63, 123, 222, 120, 220, 149, 45, 43, 227, 134, 223, 171, 105, 63, 200, 45, 25, 74, 75, 82, 204, 22, 43, 217],
-		[36, 56, 7, 170, 25, 157, 136, 128, 86, 201, 15, 61, 167, 140, 209, 74, 67, 105, 119, 69, 138, 180, 226, 180, 22, 216, 11, 77, 150, 113, 106, 104],
-		[128, 85, 234, 225, 5, 232, 93, 156, 9, 91, 254, 146, 227, 186, 130, 117, 125, 142, 121, 152, 89, 108, 239, 127, 147, 19, 218, 5, 119, 0, 85, 24],
+		[92, 151, 235, 150, 112, 3, 79, 106, 61, 185, 47, 80, 242, 59, 242, 109, 48, 179, 254, 149, 216, 212, 101, 121, 95, 101, 18, 18, 5, 139, 26, 52],
+		[205, 131, 121, 0, 163, 239, 95, 158, 182, 229, 196, 141, 19, 123, 30, 15, 193, 67, 10, 88, 36, 23, 92, 200, 33, 140, 178, 139, 240, 67, 203, 184],
+		[86, 14, 171, 227, 182, 189, 31, 137, 153, 142, 88, 2, 190, 27, 82, 221, 226, 221, 61, 54, 72, 206, 188, 244, 227, 91, 104, 46, 219, 200, 174, 184],
+		[85, 51, 49, 32, 122, 42, 156, 246, 197, 136, 57, 200, 130, 35, 16, 11, 183, 90, 170, 4, 235, 184, 94, 0, 128, 3, 176, 87, 52, 44, 98, 101],
 	],
 	[
-		[243, 185, 145, 246, 182, 148, 118, 146, 246, 175, 87, 180, 216, 88, 252, 11, 181, 203, 143, 109, 2, 17, 138, 131, 171, 135, 212, 72, 46, 68, 57, 165],
-		[214, 134, 157, 63, 175, 160, 77, 116, 196, 10, 101, 204, 2, 179, 90, 63, 8, 120, 63, 20, 33, 67, 155, 170, 148, 148, 252, 221, 219, 152, 68, 96],
-		[95, 134, 186, 131, 243, 182, 181, 38, 203, 249, 48, 18, 168, 204, 152, 200, 33, 216, 64, 109, 61, 144, 157, 163, 30, 43, 203, 93, 70, 117, 134, 12],
-		[139, 211, 249, 46, 65, 80, 118, 190, 44, 98, 121, 163, 25, 155, 24, 14, 198, 195, 4, 73, 251, 112, 37, 225, 23, 2, 184, 100, 133, 59, 83, 178],
+		[86, 215, 70, 160, 103, 147, 176, 165, 213, 179, 223, 1, 79, 232, 112, 159, 13, 18, 129, 109, 157, 6, 154, 214, 238, 113, 118, 28, 180, 81, 74, 197],
+		[29, 231, 203, 230, 229, 33, 210, 82, 246, 199, 73, 227, 158, 253, 233, 86, 106, 125, 184, 164, 255, 184, 75, 26, 45, 213, 201, 145, 39, 26, 26, 136],
+		[36, 180, 145, 17, 224, 31, 81, 223, 101, 195, 138, 41, 80, 50, 146, 51, 101, 145, 120, 162, 187, 186, 148, 0, 221, 11, 64, 177, 178, 167, 228, 70],
+		[53, 101, 222, 7, 175, 157, 189, 162, 241, 158, 27, 149, 162, 157, 175, 108, 9, 200, 190, 52, 91, 51, 95, 228, 223, 215, 17, 122, 26, 167, 129, 28],
 	],
 	[
-		[218, 118, 161, 91, 254, 106, 47, 77, 39, 63, 109, 112, 75, 196, 168, 79, 166, 125, 160, 254, 24, 115, 192, 66, 106, 69, 148, 254, 195, 25, 106, 163],
-		[141, 82, 84, 191, 12, 159, 41, 58, 132, 205, 103, 142, 86, 149, 50, 214, 28, 174, 126, 156, 14, 8, 238, 114, 49, 110, 165, 8, 108, 227, 76, 231],
-		[243, 165, 22, 66, 235, 44, 88, 179, 154, 204, 163, 47, 202, 243, 220, 128, 172, 254, 78, 102, 230, 199, 255, 175, 111, 137, 55, 157, 25, 69, 159, 42],
-		[206, 135, 106, 253, 107, 210, 203, 251, 202, 83, 34, 197, 127, 233, 228, 200, 179, 145, 197, 222, 232, 145, 85, 116, 188, 81, 103, 37, 176, 44, 70, 47],
+		[121, 2, 254, 38, 220, 164, 68, 187, 208, 65, 239, 203, 62, 54, 89, 114, 48, 0, 253, 223, 205, 177, 177, 52, 194, 213, 219, 241, 148, 56, 39, 14],
+		[43, 4, 116, 53, 155, 84, 128, 88, 149, 9, 143, 181, 150, 207, 111, 92, 164, 31, 242, 50, 155, 134, 77, 107, 250, 223, 123, 116, 66, 213, 49, 141],
+		[42, 193, 184, 34, 166, 34, 108, 57, 245, 51, 25, 194, 76, 41, 198, 112, 73, 49, 220, 148, 103, 51, 45, 88, 141, 222, 207, 175, 103, 70, 98, 197],
+		[131, 17, 134, 59, 56, 109, 84, 97, 133, 104, 63, 206, 178, 162, 89, 171, 222, 43, 164, 54, 22, 37, 230, 32, 97, 251, 149, 181, 35, 90, 213, 187],
 	],
 	[
-		[88, 150, 72, 1, 245, 209, 59, 60, 1, 25, 159, 33, 63, 65, 176, 150, 86, 92, 218, 255, 145, 175, 217, 109, 203, 55, 98, 197, 205, 40, 220, 13],
-		[40, 89, 48, 57, 181, 162, 255, 91, 47, 225, 241, 159, 12, 12, 133, 78, 114, 76, 76, 149, 197, 124, 35, 227, 141, 212, 164, 194, 1, 222, 59, 175],
-		[146, 125, 211, 205, 216, 65, 116, 79, 127, 114, 229, 176, 87, 14, 55, 74, 1, 215, 49, 122, 154, 241, 36, 76, 78, 205, 7, 197, 2, 61, 57, 151],
-		[248, 1, 85, 213, 107, 220, 78, 165, 231, 216, 141, 80, 111, 59, 10, 228, 221, 76, 185, 175, 118, 78, 206, 237, 40, 176, 140, 163, 137, 141, 94, 70],
+		[203, 115, 102, 3, 245, 92, 57, 104, 31, 128, 62, 11, 223, 12, 229, 127, 15, 217, 215, 75, 182, 178, 236, 232, 205, 227, 148, 168, 219, 245, 220, 148],
+		[76, 154, 172, 219, 4, 57, 113, 234, 162, 113, 230, 80, 243, 114, 213, 33, 64, 91, 206, 155, 139, 161, 127, 7, 177, 222, 241, 155, 91, 73, 211, 97],
+		[120, 120, 251, 186, 220, 5, 226, 168, 14, 169, 22, 174, 39, 255, 213, 27, 44, 112, 105, 79, 129, 245, 110, 219, 22, 4, 149, 221, 164, 131, 149, 156],
+		[60, 98, 18, 200, 144, 125, 222, 132, 61, 123, 154, 207, 62, 184, 4, 136, 217, 222, 247, 194, 154, 234, 84, 216, 146, 59, 59, 118, 8, 166, 177, 72],
 	],
 	[
-		[201, 37, 233, 194, 77, 183, 115, 233, 13, 250, 173, 78, 16, 104, 102, 156, 22, 185, 162, 11, 78, 212, 62, 217, 198, 125, 7, 97, 187, 169, 134, 201],
-		[86, 112, 237, 87, 91, 45, 176, 67, 230, 94, 13, 26, 182, 242, 18, 179, 185, 220, 202, 80, 125, 1, 146, 83, 144, 232, 86, 54, 150, 194, 214, 71],
-		[99, 166, 58, 251, 55, 9, 6, 224, 225, 107, 8, 246, 222, 68, 103, 90, 213, 168, 197, 32, 140, 48, 95, 12, 221, 203, 12, 232, 124, 204, 128, 5],
-		[240, 203, 124, 60, 196, 186, 161, 130, 123, 57, 49, 199, 130, 32, 150, 32, 24, 234, 55, 242, 167, 90, 7, 195, 127, 36, 187, 202, 158, 162, 241, 106],
+		[25, 212, 229, 106, 95, 131, 114, 195, 126, 210, 128, 10, 138, 93, 104, 201, 84, 200, 243, 194, 41, 0, 198, 15, 108, 242, 84, 142, 58, 250, 64, 225],
+		[14, 201, 209, 61, 221, 247, 200, 34, 223, 150, 250, 163, 123, 97, 240, 126, 233, 233, 27, 32, 159, 182, 253, 110, 193, 165, 64, 49, 239, 177, 167, 241],
+		[50, 56, 0, 13, 220, 6, 41, 108, 91, 32, 7, 63, 206, 31, 157, 214, 106, 217, 213, 111, 27, 247, 224, 253, 186, 130, 77, 47, 190, 28, 216, 105],
+		[145, 195, 242, 226, 32, 176, 28, 91, 227, 131, 143, 114, 15, 112, 3, 251, 91, 40, 170, 120, 253, 79, 71, 39, 90, 114, 24, 226, 103, 105, 156, 165],
 	],
 	[
-		[48, 97, 228, 238, 90, 107, 139, 135, 143, 156, 13, 234, 188, 62, 82, 186, 245, 197, 203, 154, 24, 210, 22, 128, 228, 67, 68, 120, 157, 63, 104, 60],
-		[237, 181, 160, 35, 208, 26, 53, 68, 106, 57, 15, 48, 93, 76, 200, 84, 195, 5, 14, 27, 222, 215, 179, 89, 153, 78, 38, 233, 29, 0, 225, 235],
-		[125, 128, 21, 250, 231, 82, 226, 60, 214, 240, 84, 174, 76, 173, 149, 14, 48, 226, 157, 130, 167, 33, 27, 175, 190, 239, 228, 50, 20, 236, 105, 196],
-		[94, 131, 133, 93, 23, 57, 48, 15, 217, 113, 33, 44, 230, 171, 241, 39, 161, 11, 240, 146, 137, 66, 227, 124, 101, 232, 226, 201, 197, 118, 217, 7],
+		[138, 58, 22, 72, 115, 29, 191, 103, 140, 99, 112, 34, 206, 97, 132, 66, 66, 231, 144, 61, 35, 193, 190, 42, 216, 186, 80, 14, 39, 174, 91, 195],
+		[175, 130, 94, 213, 48, 191, 234, 79, 69, 16, 242, 8, 32, 157, 203, 40, 10, 46, 243, 73, 196, 98, 71, 153, 20, 111, 220, 7, 175, 88, 161, 7],
+		[247, 221, 236, 245, 94, 215, 191, 169, 168, 217, 94, 50, 152, 87, 4, 236, 90, 255, 249, 196, 180, 9, 127, 90, 30, 191, 41, 163, 129, 201, 215, 26],
+		[219, 153, 219, 107, 173, 231, 163, 244, 185, 98, 179, 67, 100, 18, 22, 108, 125, 31, 233, 156, 40, 221, 33, 47, 206, 105, 222, 187, 9, 45, 2, 163],
 	],
 	[
-		[123, 230, 130, 131, 67, 66, 152, 241, 5, 23, 112, 219, 247, 19, 51, 160, 33, 69, 232, 162, 249, 144, 234, 168, 46, 60, 112, 63, 76, 209, 55, 172],
-		[200, 131, 126, 195, 114, 187, 43, 188, 21, 81, 8, 209, 43, 3, 80, 234, 33, 51, 149, 94, 218, 129, 253, 83, 201, 200, 6, 79, 52, 230, 214, 66],
-		[177, 12, 79, 235, 108, 34, 166, 253, 12, 30, 98, 81, 206, 178, 85, 151, 231, 159, 174, 193, 60, 70, 110, 227, 69, 106, 25, 244, 154, 134, 248, 18],
-		[5, 78, 205, 170, 242, 115, 249, 232, 52, 182, 42, 36, 194, 199, 1, 219, 92, 247, 139, 130, 6, 19, 254, 188, 92, 94, 111, 62, 208, 115, 69, 111],
+		[194, 159, 125, 120, 23, 5, 21, 60, 107, 128, 7, 52, 19, 22, 58, 240, 48, 241, 102, 172, 205, 168, 219, 238, 5, 210, 27, 61, 48, 175, 72, 237],
+		[214, 5, 11, 196, 8, 139, 218, 226, 157, 254, 212, 221, 16, 204, 47, 238, 183, 100, 17, 112, 179, 253, 177, 129, 176, 65, 131, 186, 222, 178, 75, 58],
+		[210, 57, 140, 85, 75, 151, 109, 237, 253, 80, 108, 216, 183, 67, 35, 82, 42, 62, 161, 219, 64, 135, 108, 152, 184, 208, 2, 93, 165, 139, 247, 148],
+		[57, 17, 17, 111, 43, 53, 212, 72, 122, 131, 221, 182, 105, 214, 122, 105, 193, 178, 180, 52, 65, 201, 169, 44, 114, 122, 233, 124, 120, 213, 50, 219],
 	],
 	[
-		[191, 27, 6, 58, 46, 114, 145, 101, 169, 192, 238, 119, 177, 17, 112, 42, 52, 75, 224, 164, 188, 206, 16, 22, 186, 145, 233, 215, 59, 241, 79, 4],
-		[46, 255, 72, 50, 85, 27, 120, 108, 45, 216, 193, 204, 65, 176, 158, 161, 139, 167, 228, 47, 13, 170, 215, 211, 189, 26, 212, 241, 90, 212, 200, 57],
-		[16, 132, 78, 146, 87, 66, 222, 14, 153, 142, 72, 38, 227, 166, 172, 41, 27, 88, 160, 184, 201, 83, 142, 166, 120, 39, 86, 68, 45, 102, 199, 16],
-		[57, 121, 10, 187, 251, 138, 75, 34, 32, 172, 116, 238, 124, 168, 151, 233, 28, 137, 180, 219, 155, 83, 41, 235, 35, 129, 155, 169, 85, 132, 46, 56],
+		[253, 54, 112, 236, 161, 9, 13, 9, 184, 237, 199, 90, 143, 144, 92, 31, 51, 182, 188, 238, 31, 126, 188, 205, 21, 31, 1, 22, 171, 35, 139, 210],
+		[217, 160, 104, 5, 161, 50, 182, 91, 173, 228, 61, 46, 7, 225, 87, 173, 194, 207, 159, 88, 44, 169, 107, 44, 194, 9, 160, 142, 213, 223, 135, 85],
+		[229, 134, 241, 57, 18, 202, 41, 69, 172, 75, 222, 49, 130, 72, 80, 20, 180, 165, 69, 73, 55, 6, 170, 35, 63, 165, 225, 36, 209, 93, 220, 159],
+		[56, 13, 62, 9, 28, 118, 236, 236, 249, 162, 215, 206, 132, 62, 251, 72, 137, 221, 32, 203, 37, 185, 233, 192, 193, 149, 140, 228, 32, 234, 6, 172],
 	],
 	[
-		[14, 115, 182, 203, 203, 32, 144, 24, 92, 254, 83, 202, 217, 156, 246, 114, 105, 93, 38, 77, 241, 48, 87, 210, 186, 68, 35, 31, 113, 190, 127, 222],
-		[86, 34, 247, 10, 236, 62, 161, 145, 162, 13, 41, 226, 36, 15, 39, 67, 42, 186, 228, 23, 219, 9, 181, 131, 105, 102, 175, 207, 246, 252, 11, 28],
-		[134, 245, 44, 57, 1, 230, 92, 184, 203, 120, 161, 10, 171, 36, 121, 78, 120, 163, 109, 57, 207, 57, 246, 118, 23, 191, 37, 234, 218, 244, 243, 75],
-		[45, 171, 11, 14, 21, 68, 84, 172, 45, 160, 83, 35, 158, 140, 28, 78, 250, 71, 202, 11, 235, 95, 200, 255, 139, 79, 37, 157, 212, 45, 203, 29],
+		[216, 225, 203, 42, 27, 134, 24, 9, 96, 122, 236, 140, 125, 167, 221, 155, 115, 29, 169, 30, 52, 120, 42, 184, 130, 65, 198, 2, 22, 62, 206, 212],
+		[161, 112, 120, 227, 74, 248, 243, 40, 194, 211, 247, 154, 236, 183, 155, 85, 188, 247, 67, 241, 200, 233, 118, 10, 166, 202, 64, 35, 29, 184, 118, 137],
+		[40, 8, 115, 254, 160, 121, 182, 33, 21, 119, 234, 198, 220, 113, 149, 241, 127, 225, 77, 24, 76, 38, 52, 39, 195, 72, 244, 210, 101, 3, 153, 47],
+		[87, 50, 33, 244, 93, 83, 7, 192, 208, 109, 29, 164, 216, 121, 208, 51, 132, 89, 222, 85, 116, 83, 240, 60, 69, 78, 218, 82, 46, 24, 10, 24],
 	],
 	[
-		[79, 243, 31, 165, 28, 174, 218, 228, 204, 206, 231, 167, 16, 11, 102, 171, 88, 13, 245, 169, 36, 69, 160, 164, 69, 180, 193, 125, 166, 96, 248, 21],
-		[205, 171, 183, 239, 74, 0, 173, 198, 201, 59, 247, 71, 116, 9, 56, 176, 121, 175, 74, 223, 143, 159, 65, 190, 34, 2, 219, 212, 208, 188, 28, 129],
-		[134, 98, 199, 20, 39, 84, 1, 21, 0, 172, 237, 25, 242, 4, 41, 19, 179, 35, 103, 100, 82, 63, 94, 245, 7, 166, 64, 45, 10, 133, 79, 182],
-		[71, 1, 88, 243, 195, 164, 202, 25, 107, 19, 229, 235, 39, 123, 183, 70, 81, 167, 1, 142, 145, 195, 72, 55, 52, 131, 84, 13, 57, 79, 58, 1],
+		[63, 213, 36, 85, 226, 73, 199, 87, 239, 84, 175, 63, 18, 245, 46, 116, 9, 101, 60, 15, 90, 124, 71, 35, 118, 147, 15, 46, 171, 43, 57, 72],
+		[67, 230, 183, 189, 139, 235, 98, 39, 169, 232, 197, 217, 132, 52, 209, 130, 156, 160, 26, 213, 71, 182, 217, 13, 17, 204, 125, 101, 207, 209, 26, 94],
+		[74, 168, 124, 1, 105, 254, 168, 126, 178, 213, 163, 141, 107, 104, 100, 240, 223, 206, 49, 84, 7, 232, 36, 159, 119, 143, 68, 158, 253, 137, 233, 99],
+		[90, 185, 58, 131, 147, 107, 254, 48, 73, 185, 211, 157, 48, 68, 63, 179, 190, 254, 141, 56, 5, 129, 69, 209, 155, 115, 166, 56, 141, 169, 74, 128],
 	],
 	[
-		[164, 139, 137, 249, 0, 54, 71, 84, 178, 183, 239, 81, 133, 202, 180, 185, 185, 221, 66, 158, 54, 88, 107, 52, 213, 189, 255, 53, 40, 33, 202, 230],
-		[215, 81, 83, 46, 197, 91, 196, 117, 104, 97, 244, 61, 241, 20, 48, 206, 2, 60, 208, 231, 164, 124, 40, 30, 53, 245, 212, 250, 23, 225, 41, 65],
-		[93, 237, 52, 30, 248, 169, 52, 242, 101, 172, 182, 61, 41, 164, 223, 71, 183, 88, 182, 156, 233, 234, 164, 222, 136, 181, 164, 248, 214, 212, 128, 188],
-		[42, 102, 142, 244, 94, 102, 83, 224, 22, 234, 95, 82, 143, 130, 184, 226, 180, 234, 114, 190, 144, 170, 148, 121, 223, 153, 105, 10, 166, 23, 0, 225],
+		[98, 166, 129, 132, 53, 153, 232, 235, 115, 61, 241, 95, 131, 244, 66, 51, 215, 177, 108, 1, 130, 245, 128, 44, 12, 34, 163, 203, 99, 89, 200, 181],
+		[251, 109, 112, 21, 60, 188, 144, 147, 105, 175, 220, 129, 14, 122, 113, 255, 84, 241, 98, 149, 151, 55, 2, 57, 7, 15, 131, 116, 174, 196, 196, 63],
+		[117, 155, 144, 127, 105, 81, 23, 116, 74, 228, 213, 40, 148, 16, 209, 224, 230, 123, 129, 172, 234, 60, 224, 203, 214, 163, 148, 236, 147, 145, 1, 213],
+		[133, 127, 230, 152, 200, 30, 231, 73, 143, 240, 217, 172, 25, 89, 34, 100, 102, 231, 191, 89, 6, 95, 96, 143, 183, 190, 169, 239, 184, 178, 102, 13],
 	],
 	[
-		[6, 234, 189, 94, 228, 82, 119, 118, 173, 110, 81, 69, 149, 163, 235, 69, 10, 237, 148, 1, 204, 125, 159, 50, 73, 33, 11, 15, 67, 12, 208, 237],
-		[74, 138, 146, 85, 239, 159, 6, 126, 132, 163, 0, 122, 8, 206, 119, 3, 227, 150, 34, 15, 218, 84, 110, 129, 168, 50, 110, 82, 168, 72, 242, 137],
-		[7, 30, 233, 46, 26, 72, 218, 86, 47, 231, 209, 154, 201, 142, 9, 222, 152, 74, 226, 253, 113, 32, 34, 169, 243, 54, 18, 51, 177, 161, 70, 135],
-		[32, 42, 21, 236, 47, 225, 184, 14, 248, 170, 92, 104, 136, 125, 163, 200, 7, 160, 52, 236, 144, 25, 205, 129, 172, 37, 224, 195, 203, 232, 114, 68],
+		[125, 97, 232, 79, 215, 120, 142, 110, 2, 172, 146, 52, 96, 121, 111, 31, 89, 70, 69, 46, 223, 210, 87, 75, 87, 211, 58, 239, 63, 221, 231, 146],
+		[227, 4, 52, 199, 54, 173, 43, 31, 69, 198, 148, 67, 173, 161, 19, 235, 174, 37, 17, 11, 63, 227, 130, 16, 228, 5, 83, 30, 72, 16, 140, 60],
+		[238, 248, 41, 162, 112, 7, 72, 74, 236, 1, 211, 195, 207, 121, 58, 29, 15, 232, 72, 231, 200, 218, 164, 97, 34, 149, 130, 44, 0, 156, 187, 205],
+		[190, 92, 246, 9, 73, 110, 216, 157, 242, 61, 251, 180, 169, 174, 242, 66, 3, 136, 137, 37, 103, 23, 30, 155, 128, 179, 85, 76, 175, 12, 66, 112],
 	],
 	[
-		[33, 43, 117, 235, 125, 39, 12, 72, 79, 32, 1, 115, 148, 241, 126, 65, 66, 91, 181, 107, 153, 157, 239, 154, 44, 26, 198, 4, 233, 69, 4, 197],
-		[23, 43, 74, 211, 29, 251, 37, 55, 169, 31, 92, 10, 158, 164, 32, 81, 148, 108, 32, 35, 44, 40, 38, 9, 1, 134, 201, 16, 134, 210, 165, 27],
-		[42, 185, 214, 55, 239, 170, 104, 77, 27, 165, 226, 157, 238, 47, 150, 227, 232, 211, 159, 11, 29, 175, 240, 69, 202, 61, 47, 215, 102, 63, 91, 33],
-		[165, 20, 173, 253, 70, 237, 92, 138, 250, 198, 136, 18, 68, 23, 209, 203, 211, 55, 238, 206, 135, 201, 93, 237, 5, 124, 112, 63, 179, 188, 83, 148],
+		[77, 176, 11, 6, 16, 53, 106, 72, 114, 8, 206, 20, 104, 62, 87, 175, 158, 105, 12, 40, 1, 49, 143, 77, 65, 30, 221, 98, 132, 96, 1, 213],
+		[63, 130, 8, 131, 64, 169, 65, 245, 44, 183, 227, 121, 253, 136, 138, 209, 90, 157, 8, 193, 169, 26, 60, 168, 76, 65, 91, 196, 29, 232, 37, 21],
+		[25, 228, 97, 34, 141, 255, 16, 53, 131, 109, 44, 149, 7, 45, 169, 206, 172, 56, 122, 64, 85, 125, 241, 174, 235, 126, 92, 107, 28, 20, 11, 142],
+		[100, 238, 114, 146, 31, 197, 14, 194, 37, 42, 188, 131, 148, 145, 225, 246, 214, 185, 99, 99, 198, 80, 221, 98, 178, 128, 152, 155, 220, 72, 130, 133],
 	],
 	[
-		[210, 255, 7, 38, 228, 102, 55, 94, 172, 2, 128, 206, 229, 214, 244, 159, 154, 122, 152, 229, 234, 13, 85, 73, 91, 40, 54, 125, 48, 181, 157, 50],
-		[87, 68, 58, 130, 22, 46, 23, 209, 226, 102, 74, 236, 170, 102, 152, 241, 32, 69, 169, 38, 148, 83, 111, 8, 36, 88, 96, 195, 199, 199, 55, 62],
-		[18, 80, 160, 180, 211, 52, 228, 196, 181, 92, 138, 45, 210, 199, 70, 111, 243, 221, 145, 110, 123, 178, 93, 212, 160, 193, 195, 24, 128, 29, 105, 47],
-		[23, 234, 77, 36, 229, 107, 187, 111, 67, 23, 82, 169, 81, 40, 214, 190, 66, 251, 213, 203, 129, 109, 80, 235, 175, 116, 146, 158, 239, 132, 231, 62],
+		[171, 218, 75, 98, 66, 96, 93, 237, 247, 180, 80, 72, 197, 91, 13, 83, 144, 104, 231, 255, 196, 158, 102, 53, 36, 117, 178, 209, 88, 216, 221, 206],
+		[189, 250, 10, 77, 89, 62, 55, 234, 140, 8, 4, 58, 14, 12, 47, 185, 65, 76, 241, 177, 75, 22, 40, 196, 24, 253, 219, 148, 65, 41, 20, 14],
+		[139, 115, 218, 115, 143, 107, 32, 165, 62, 163, 195, 186, 210, 135, 114, 67, 129, 117, 125, 96, 117, 13, 152, 93, 242, 231, 1, 53, 251, 129, 94, 4],
+		[28, 10, 0, 235, 116, 197, 160, 20, 141, 89, 84, 26, 29, 71, 62, 120, 108, 226, 98, 139, 13, 13, 183, 250, 139, 206, 181, 151, 199, 249, 190, 101],
 	],
 	[
-		[20, 201, 146, 107, 182, 51, 30, 83, 231, 59, 21, 145, 33, 239, 81, 138, 8, 20, 121, 90, 65, 57, 76, 200, 202, 48, 90, 61, 164, 100, 40, 63],
-		[95, 204, 134, 39, 161, 54, 119, 35, 60, 225, 144, 130, 194, 24, 109, 153, 30, 31, 126, 29, 206, 108, 245, 176, 220, 157, 184, 240, 103, 229, 146, 7],
-		[74, 54, 222, 6, 196, 140, 167, 16, 117, 220, 180, 208, 106, 185, 109, 222, 105, 107, 8, 103, 220, 189, 203, 130, 96, 48, 52, 51, 56, 238, 141, 220],
-		[73, 16, 208, 205, 63, 113, 14, 164, 61, 94, 195, 7, 137, 233, 68, 190, 237, 127, 32, 159, 39, 140, 137, 228, 151, 197, 36, 176, 206, 10, 166, 157],
+		[23, 231, 212, 210, 196, 139, 196, 30, 242, 35, 172, 186, 158, 247, 32, 126, 27, 241, 101, 245, 126, 207, 47, 24, 56, 190, 62, 136, 62, 239, 115, 148],
+		[84, 207, 18, 180, 68, 249, 22, 135, 138, 248, 35, 67, 134, 8, 164, 247, 53, 122, 169, 223, 102, 19, 137, 247, 103, 245, 177, 58, 58, 64, 253, 85],
+		[232, 249, 162, 49, 240, 13, 227, 128, 155, 173, 104, 206, 8, 11, 35, 245, 133, 154, 202, 238, 48, 123, 124, 238, 51, 32, 119, 158, 253, 128, 56, 189],
+		[21, 170, 87, 2, 138, 80, 89, 155, 65, 241, 76, 67, 55, 123, 25, 77, 45, 151, 161, 255, 57, 154, 139, 26, 55, 165, 75, 201, 244, 132, 83, 91],
 	],
 	[
-		[164, 220, 219, 155, 247, 15, 112, 160, 157, 126, 150, 50, 43, 25, 225, 189, 58, 4, 24, 208, 89, 241, 189, 46, 34, 205, 35, 195, 238, 180, 216, 99],
-		[229, 246, 206, 105, 116, 239, 116, 94, 49, 137, 220, 77, 56, 169, 207, 247, 252, 216, 81, 31, 163, 59, 183, 67, 97, 156, 49, 215, 124, 104, 176, 85],
-		[237, 205, 184, 173, 94, 135, 137, 107, 118, 165, 107, 12, 190, 103, 40, 46, 228, 128, 165, 24, 118, 80, 172, 194, 22, 188, 252, 129, 81, 187, 8, 69],
-		[228, 221, 128, 202, 229, 142, 174, 159, 58, 149, 18, 252, 128, 235, 106, 82, 88, 24, 69, 55, 155, 49, 17, 176, 157, 40, 213, 200, 176, 106, 21, 13],
+		[175, 195, 164, 50, 161, 161, 240, 128, 81, 73, 207, 43, 136, 105, 2, 172, 50, 193, 97, 242, 98, 102, 50, 154, 195, 65, 198, 121, 149, 122, 94, 17],
+		[89, 131, 96, 37, 163, 18, 246, 63, 149, 232, 29, 254, 127, 215, 187, 95, 20, 233, 35, 94, 170, 141, 84, 211, 67, 65, 146, 133, 211, 8, 99, 177],
+		[170, 180, 247, 42, 41, 179, 130, 185, 220, 89, 151, 207, 100, 86, 255, 213, 143, 153, 216, 97, 174, 62, 184, 145, 49, 153, 143, 120, 62, 234, 68, 240],
+		[200, 226, 57, 208, 29, 30, 117, 179, 96, 132, 176, 125, 229, 137, 84, 202, 88, 11, 35, 12, 86, 126, 155, 137, 171, 47, 184, 179, 71, 124, 172, 12],
 	],
 	[
-		[77, 153, 208, 174, 160, 150, 159, 233, 51, 140, 118, 170, 241, 127, 171, 18, 29, 154, 98, 73, 250, 22, 44, 106, 56, 19, 31, 95, 59, 132, 254, 203],
-		[192, 120, 250, 231, 112, 224, 206, 81, 155, 101, 125, 6, 30, 82, 231, 59, 61, 31, 2, 149, 141, 192, 244, 26, 99, 217, 30, 200, 8, 20, 166, 145],
-		[110, 68, 44, 118, 232, 15, 247, 230, 225, 166, 238, 83, 11, 177, 232, 126, 195, 190, 182, 130, 182, 226, 13, 26, 11, 193, 66, 62, 210, 117, 244, 12],
-		[100, 60, 210, 40, 248, 169, 68, 89, 107, 201, 243, 149, 47, 179, 9, 161, 238, 56, 196, 110, 56, 175, 68, 8, 81, 191, 151, 117, 160, 53, 209, 24],
+		[154, 223, 5, 227, 215, 156, 22, 15, 212, 20, 167, 238, 227, 98, 101, 9, 49, 112, 107, 1, 108, 76, 6, 210, 249, 55, 13, 200, 125, 177, 236, 229],
+		[151, 143, 11, 69, 28, 38, 163, 174, 246, 133, 19, 11, 245, 159, 37, 0, 49, 225, 146, 53, 11, 153, 143, 141, 50, 159, 62, 45, 85, 244, 197, 55],
+		[214, 127, 153, 24, 115, 60, 164, 185, 252, 107, 97, 200, 62, 8, 19, 55, 52, 252, 88, 15, 11, 202, 139, 165, 204, 199, 167, 133, 81, 118, 68, 237],
+		[84, 34, 128, 212, 63, 172, 18, 190, 160, 133, 20, 48, 42, 183, 34, 233, 70, 75, 198, 143, 224, 111, 190, 213, 90, 142, 191, 254, 15, 235, 40, 128],
 	],
 	[
-		[39, 102, 173, 95, 171, 156, 1, 137, 227, 229, 255, 150, 44, 66, 118, 147, 95, 157, 7, 151, 76, 113, 125, 88, 30, 116, 180, 173, 189, 163, 32, 106],
-		[32, 183, 77, 253, 126, 214, 193, 155, 50, 235, 121, 73, 27, 190, 218, 226, 252, 223, 98, 102, 114, 195, 239, 46, 16, 235, 149, 63, 108, 157, 155, 217],
-		[73, 224, 100, 197, 228, 145, 24, 74, 86, 104, 2, 118, 217, 223, 2, 190, 100, 8, 90, 163, 41, 195, 50, 236, 45, 101, 248, 205, 18, 90, 14, 176],
-		[109, 27, 155, 12, 110, 113, 53, 138, 109, 199, 168, 110, 97, 168, 15, 105, 71, 217, 145, 124, 85, 203, 15, 140, 171, 78, 144, 27, 104, 25, 155, 217],
+		[182, 20, 55, 210, 196, 44, 43, 97, 187, 255, 68, 156, 151, 17, 42, 74, 20, 219, 94, 80, 75, 134, 214, 50, 65, 214, 80, 193, 72, 191, 167, 139],
+		[143, 120, 2, 158, 245, 212, 160, 157, 238, 199, 73, 1, 118, 23, 239, 101, 187, 0, 242, 194, 244, 91, 42, 88, 145, 222, 104, 38, 91, 90, 249, 25],
+		[98, 36, 124, 132, 43, 145, 27, 212, 71, 167, 154, 12, 55, 241, 60, 253, 151, 195, 22, 11, 97, 149, 234, 182, 32, 21, 173, 49, 104, 140, 208, 91],
+		[126, 238, 198, 53, 188, 198, 195, 92, 117, 109, 173, 40, 159, 58, 150, 121, 81, 227, 82, 59, 176, 148, 248, 177, 175, 26, 215, 92, 30, 26, 27, 175],
 	],
 	[
-		[197, 230, 200, 52, 74, 179, 81, 152, 48, 158, 47, 210, 160, 191, 76, 208, 12, 224, 215, 186, 24, 223, 61, 93, 141, 198, 226, 79, 68, 152, 146, 96],
-		[59, 128, 17, 131, 219, 29, 241, 85, 222, 210, 105, 235, 77, 132, 151, 167, 40, 246, 177, 99, 249, 233, 48, 193, 229, 3, 217, 49, 217, 233, 27, 87],
-		[227, 191, 251, 156, 54, 8, 20, 68, 7, 33, 120, 50, 237, 36, 71, 91, 206, 100, 239, 251, 213, 96, 222, 254, 106, 132, 165, 185, 244, 18, 54, 69],
-		[149, 186, 103, 56, 69, 198, 187, 174, 168, 174, 238, 42, 230, 112, 160, 125, 89, 63, 115, 183, 77, 230, 187, 152, 75, 102, 165, 59, 138, 65, 128, 228],
+		[29, 16, 201, 150, 149, 215, 170, 112, 46, 159, 254, 170, 198, 85, 19, 131, 93, 248, 110, 147, 153, 85, 71, 192, 62, 82, 51, 188, 67, 82, 118, 188],
+		[130, 78, 219, 111, 130, 133, 162, 56, 62, 44, 133, 206, 19, 244, 98, 87, 74, 166, 38, 147, 205, 107, 150, 213, 47, 13, 141, 128, 192, 19, 4, 37],
+		[9, 41, 86, 78, 218, 167, 40, 38, 248, 57, 131, 251, 10, 88, 120, 180, 149, 139, 48, 222, 143, 42, 100, 82, 37, 198, 110, 145, 201, 84, 140, 128],
+		[151, 101, 239, 92, 101, 244, 82, 254, 89, 248, 70, 15, 240, 186, 14, 166, 58, 201, 27, 191, 88, 191, 46, 241, 95, 1, 243, 137, 235, 144, 15, 240],
 	],
 	[
-		[243, 159, 133, 228, 158, 230, 185, 10, 107, 248, 50, 242, 27, 117, 158, 71, 244, 68, 142, 123, 19, 103, 53, 237, 211, 34, 109, 109, 5, 188, 79, 102],
-		[133, 18, 3, 102, 149, 27, 103, 177, 123, 73, 86, 125, 18, 115, 78, 82, 64, 178, 184, 239, 255, 81, 88, 147, 189, 228, 156, 8, 56, 48, 148, 79],
-		[56, 229, 33, 189, 33, 128, 91, 33, 102, 151, 151, 173, 180, 39, 84, 225, 76, 209, 92, 249, 195, 16, 71, 8, 140, 68, 77, 163, 24, 247, 154, 231],
-		[110, 87, 118, 202, 140, 86, 199, 253, 140, 3, 35, 157, 159, 140, 31, 214, 230, 137, 88, 131, 19, 143, 37, 23, 77, 230, 203, 133, 246, 159, 94, 20],
+		[249, 102, 39, 240, 53, 31, 157, 108, 107, 252, 28, 235, 154, 10, 182, 80, 183, 254, 178, 217, 234, 40, 55, 221, 54, 204, 197, 5, 175, 79, 136, 174],
+		[130, 197, 190, 143, 179, 142, 66, 24, 83, 23, 121, 169, 80, 241, 248, 100, 221, 158, 34, 129, 194, 187, 25, 58, 34, 188, 157, 133, 189, 218, 128, 196],
+		[29, 9, 113, 72, 138, 147, 100, 3, 225, 11, 204, 206, 93, 30, 76, 162, 250, 12, 93, 150, 148, 41, 166, 47, 219, 243, 105, 41, 127, 254, 241, 10],
+		[204, 244, 227, 70, 50, 152, 112, 5, 97, 240, 228, 221, 98, 78, 247, 179, 64, 135, 83, 5, 106, 116, 180, 226, 106, 2, 199, 151, 243, 161, 241, 197],
 	],
 	[
-		[31, 208, 186, 194, 169, 220, 69, 82, 47, 76, 190, 174, 82, 254, 166, 216, 111, 165, 65, 194, 206, 4, 106, 139, 190, 52, 204, 205, 60, 168, 238, 175],
-		[206, 250, 32, 16, 101, 6, 27, 221, 210, 214, 22, 105, 132, 239, 36, 148, 109, 128, 15, 42, 189, 35, 94, 47, 162, 122, 89, 100, 188, 227, 194, 2],
-		[72, 30, 221, 232, 84, 43, 230, 55, 201, 140, 237, 131, 40, 187, 109, 237, 124, 26, 154, 168, 39, 12, 132, 78, 29, 24, 182, 146, 225, 72, 167, 14],
-		[138, 62, 37, 170, 215, 21, 227, 4, 74, 12, 195, 219, 65, 82, 211, 173, 17, 81, 66, 48, 156, 148, 111, 36, 21, 37, 55, 115, 137, 171, 23, 74],
+		[218, 221, 192, 158, 83, 191, 20, 215, 226, 173, 151, 94, 66, 227, 106, 202, 100, 227, 33, 58, 101, 123, 88, 19, 84, 151, 220, 201, 162, 254, 185, 34],
+		[108, 138, 208, 181, 149, 175, 72, 74, 77, 31, 31, 56, 232, 151, 7, 229, 104, 160, 35, 19, 135, 149, 148, 66, 0, 250, 0, 212, 175, 240, 88, 236],
+		[135, 5, 245, 215, 134, 177, 76, 97, 236, 220, 247, 103, 216, 173, 65, 30, 40, 234, 218, 222, 115, 24, 205, 141, 175, 20, 133, 0, 39, 187, 104, 223],
+		[242, 110, 135, 51, 50, 243, 254, 234, 214, 187, 117, 237, 143, 200, 105, 112, 168, 18, 204, 3, 98, 3, 19, 70, 17, 122, 40, 28, 21, 108, 0, 92],
 	],
 	[
-		[12, 188, 10, 140, 177, 198, 184, 212, 124, 234, 148, 118, 115, 88, 243, 213, 201, 91, 57, 8, 93, 24, 121, 112, 178, 252, 181, 194, 124, 39, 140, 179],
-		[183, 130, 58, 2, 95, 166, 191, 61, 170, 38, 64, 242, 17, 145, 179, 174, 22, 141, 73, 120, 69, 79, 64, 1, 18, 251, 3, 202, 221, 198, 188, 77],
-		[102, 178, 213, 224, 209, 130, 26, 57, 120, 248, 98, 243, 108, 86, 223, 137, 159, 35, 63, 203, 139, 55, 148, 171, 115, 89, 59, 17, 114, 36, 5, 8],
-		[99, 60, 102, 158, 81, 170, 93, 137, 19, 87, 250, 75, 248, 71, 20, 21, 15, 250, 60, 127, 122, 60, 254, 201, 100, 8, 140, 152, 181, 157, 44, 43],
+		[16, 9, 18, 53, 219, 158, 176, 248, 230, 1, 124, 36, 172, 219, 178, 191, 90, 168, 14, 6, 200, 243, 84, 138, 194, 62, 99, 171, 121, 194, 202, 173],
+		[170, 108, 39, 192, 55, 61, 108, 75, 214, 67, 146, 149, 146, 38, 206, 126, 6, 127, 45, 128, 179, 6, 123, 156, 32, 31, 18, 47, 28, 4, 34, 105],
+		[18, 89, 231, 57, 239, 112, 244, 165, 141, 146, 127, 69, 157, 173, 45, 44, 254, 179, 20, 85, 56, 251, 106, 244, 39, 148, 217, 158, 215, 44, 147, 106],
+		[204, 249, 125, 12, 76, 116, 48, 178, 132, 152, 57, 28, 11, 143, 190, 255, 39, 109, 93, 107, 152, 155, 43, 108, 209, 23, 225, 231, 201, 127, 12, 54],
 	],
 	[
-		[213, 147, 100, 221, 196, 242, 68, 41, 53, 183, 224, 107, 98, 121, 229, 206, 4, 190, 14, 145, 201, 18, 85, 91, 127, 27, 108, 40, 213, 27, 1, 220],
-		[159, 103, 106, 10, 78, 70, 142, 9, 246, 101, 102, 183, 111, 130, 50, 1, 196, 25, 43, 247, 172, 161, 90, 231, 244, 31, 249, 237, 90, 211, 7, 149],
-		[117, 180, 179, 161, 231, 41, 70, 83, 164, 108, 65, 43, 11, 60, 141, 132, 30, 34, 213, 242, 168, 245, 226, 96, 111, 119, 214, 254, 132, 47, 39, 238],
-		[240, 117, 140, 139, 151, 132, 20, 246, 226, 102, 109, 39, 103, 106, 199, 40, 201, 63, 147, 130, 77, 223, 107, 170, 128, 193, 68, 153, 39, 165, 50, 173],
+		[64, 62, 246, 111, 198, 155, 228, 130, 232, 40, 145, 28, 193, 132, 19, 162, 225, 48, 71, 191, 109, 214, 236, 173, 114, 232, 106, 187, 156, 239, 218, 23],
+		[83, 246, 198, 198, 65, 253, 242, 170, 3, 137, 22, 13, 16, 157, 108, 46, 73, 92, 104, 17, 141, 109, 58, 208, 141, 142, 157, 229, 219, 92, 245, 218],
+		[162, 244, 9, 244, 27, 135, 165, 133, 250, 125, 189, 76, 220, 242, 3, 186, 163, 134, 160, 34, 14, 160, 49, 248, 211, 42, 18, 46, 160, 232, 221, 207],
+		[97, 254, 240, 108, 179, 159, 67, 153, 122, 104, 179, 67, 120, 18, 198, 79, 19, 252, 71, 217, 69, 175, 248, 240, 141, 128, 215, 16, 195, 75, 61, 115],
 	],
 	[
-		[152, 84, 145, 26, 122, 73, 58, 233, 140, 154, 87, 193, 149, 91, 211, 159, 232, 153, 185, 115, 210, 147, 30, 100, 51, 158, 148, 108, 157, 5, 230, 54],
-		[220, 54, 20, 240, 60, 117, 18, 34, 20, 145, 56, 198, 142, 147, 36, 236, 126, 148, 154, 214, 66, 25, 215, 148, 9, 207, 203, 5, 156, 4, 131, 192],
-		[35, 110, 22, 192, 15, 73, 48, 51, 97, 80, 255, 56, 113, 16, 86, 146, 70, 38, 203, 101, 202, 114, 215, 148, 149, 129, 69, 107, 220, 94, 26, 24],
-		[168, 218, 121, 122, 243, 213, 31, 231, 231, 229, 231, 121, 215, 161, 236, 9, 119, 183, 250, 68, 180, 199, 128, 90, 152, 245, 91, 33, 57, 232, 46, 88],
+		[201, 125, 223, 185, 102, 211, 44, 126, 178, 93, 14, 190, 124, 32, 172, 48, 201, 198, 232, 203, 3, 123, 56, 121, 86, 16, 215, 250, 223, 32, 131, 173],
+		[121, 137, 70, 70, 162, 27, 49, 208, 201, 167, 67, 73, 214, 83, 80, 123, 199, 223, 154, 89, 60, 61, 245, 185, 10, 90, 160, 186, 123, 193, 114, 83],
+		[135, 125, 83, 147, 188, 104, 25, 32, 5, 199, 197, 230, 69, 181, 158, 82, 104, 123, 56, 31, 121, 24, 205, 99, 152, 127, 131, 164, 215, 143, 30, 63],
+		[115, 215, 214, 235, 184, 64, 97, 109, 241, 178, 192, 197, 84, 28, 109, 224, 59, 158, 214, 150, 136, 73, 66, 157, 29, 59, 137, 225, 59, 16, 124, 47],
 	],
 	[
-		[167, 107, 230, 139, 125, 134, 179, 226, 98, 236, 187, 40, 132, 204, 55, 237, 194, 210, 150, 87, 124, 6, 129, 47, 16, 29, 88, 67, 243, 50, 200, 86],
-		[98, 228, 110, 107, 68, 218, 170, 121, 255, 110, 122, 112, 189, 70, 43, 195, 164, 152, 90, 11, 230, 3, 245, 152, 41, 128, 85, 120, 185, 94, 145, 22],
-		[241, 5, 196, 80, 248, 210, 241, 244, 198, 244, 78, 131, 82, 177, 75, 10, 238, 69, 234, 132, 182, 83, 83, 37, 113, 210, 23, 67, 194, 183, 42, 148],
-		[50, 0, 40, 139, 208, 167, 238, 219, 165, 235, 31, 152, 99, 92, 32, 60, 199, 202, 100, 200, 229, 98, 69, 0, 112, 86, 148, 22, 213, 114, 54, 6],
+		[148, 42, 41, 136, 255, 26, 47, 243, 253, 241, 158, 29, 65, 211, 57, 152, 168, 151, 42, 112, 100, 104, 123, 28, 135, 26, 20, 208, 95, 144, 193, 39],
+		[202, 217, 172, 230, 159, 88, 113, 243, 117, 84, 142, 60, 61, 211, 109, 155, 18, 0, 128, 196, 189, 104, 185, 237, 236, 145, 129, 81, 211, 231, 12, 102],
+		[211, 115, 196, 163, 64, 86, 2, 16, 244, 150, 208, 125, 132, 116, 192, 12, 221, 75, 72, 46, 37, 181, 243, 50, 66, 216, 218, 103, 229, 84, 241, 223],
+		[112, 169, 37, 198, 159, 198, 170, 222, 12, 183, 76, 216, 25, 191, 94, 138, 109, 80, 53, 66, 156, 198, 58, 196, 135, 247, 190, 90, 102, 58, 44, 179],
 	],
 	[
-		[68, 97, 93, 225, 112, 47, 145, 47, 197, 29, 92, 64, 211, 47, 147, 58, 255, 134, 138, 124, 187, 24, 142, 70, 183, 201, 2, 124, 26, 169, 95, 52],
-		[182, 112, 50, 40, 98, 217, 130, 15, 90, 248, 55, 118, 231, 106, 110, 206, 227, 151, 169, 80, 226, 78, 29, 204, 66, 5, 134, 17, 4, 214, 203, 88],
-		[241, 185, 184, 111, 90, 157, 29, 71, 32, 90, 194, 254, 109, 73, 212, 150, 249, 222, 134, 43, 86, 148, 85, 139, 118, 79, 204, 150, 3, 229, 218, 243],
-		[28, 163, 241, 242, 38, 188, 8, 226, 113, 84, 113, 156, 60, 252, 103, 179, 103, 91, 219, 104, 64, 102, 7, 62, 107, 32, 214, 137, 116, 159, 46, 37],
+		[65, 72, 102, 153, 59, 199, 187, 172, 123, 231, 225, 229, 200, 148, 31, 250, 69, 179, 17, 211, 3, 59, 74, 113, 250, 173, 39, 57, 255, 15, 233, 18],
+		[168, 194, 124, 116, 172, 31, 156, 144, 91, 49, 180, 28, 207, 223, 177, 42, 130, 41, 164, 243, 80, 52, 252, 75, 22, 117, 77, 26, 166, 255, 116, 132],
+		[218, 232, 84, 116, 101, 34, 0, 67, 157, 185, 195, 240, 241, 82, 192, 220, 37, 225, 54, 196, 170, 189, 240, 212, 105, 133, 80, 100, 50, 185, 248, 207],
+		[133, 159, 198, 46, 239, 192, 217, 121, 156, 18, 85, 160, 164, 28, 240, 115, 60, 69, 0, 152, 216, 85, 242, 217, 131, 43, 159, 139, 229, 198, 99, 154],
 	],
 	[
-		[150, 255, 156, 104, 141, 239, 41, 212, 1, 160, 94, 162, 12, 248, 190, 65, 158, 63, 13, 198, 207, 169, 31, 153, 218, 186, 159, 134, 244, 21, 97, 150],
-		[115, 0, 42, 250, 138, 4, 203, 241, 81, 190, 136, 120, 69, 165, 159, 161, 178, 126, 126, 191, 34, 136, 27, 57, 11, 156, 66, 75, 245, 201, 160, 205],
-		[234, 123, 30, 98, 160, 126, 9, 43, 43, 2, 22, 247, 63, 117, 185, 239, 47, 29, 72, 2, 34, 50, 65, 123, 214, 84, 22, 137, 0, 211, 145, 55],
-		[6, 234, 77, 86, 179, 192, 148, 72, 154, 48, 30, 93, 38, 66, 195, 64, 137, 1, 187, 136, 117, 69, 103, 53, 11, 187, 11, 176, 23, 173, 227, 180],
+		[7, 17, 9, 187, 17, 241, 7, 32, 78, 236, 58, 7, 34, 146, 12, 36, 244, 37, 226, 98, 201, 88, 114, 22, 28, 57, 171, 5, 21, 75, 60, 91],
+		[24, 24, 104, 25, 4, 161, 108, 71, 173, 11, 168, 65, 103, 90, 159, 156, 189, 81, 6, 15, 10, 219, 109, 118, 140, 70, 66, 85, 142, 211, 211, 193],
+		[100, 189, 183, 77, 63, 104, 136, 160, 12, 172, 224, 112, 242, 126, 218, 41, 160, 165, 95, 20, 80, 15, 242, 213, 34, 15, 27, 140, 29, 80, 219, 31],
+		[34, 224, 162, 153, 243, 110, 101, 84, 56, 252, 69, 219, 172, 42, 44, 96, 1, 247, 188, 105, 3, 20, 241, 216, 217, 236, 228, 85, 171, 34, 29, 61],
 	],
 	[
-		[58, 21, 179, 212, 147, 234, 215, 33, 5, 116, 134, 91, 1, 234, 184, 117, 135, 58, 216, 155, 217, 132, 16, 144, 178, 198, 100, 98, 255, 105, 6, 86],
-		[64, 124, 82, 10, 36, 206, 219, 153, 252, 173, 105, 219, 86, 89, 69, 62, 72, 222, 70, 67, 215, 54, 83, 78, 250, 65, 201, 250, 132, 221, 33, 222],
-		[72, 167, 203, 20, 236, 242, 17, 42, 151, 16, 69, 187, 160, 76, 206, 231, 174, 114, 97, 149, 171, 146, 116, 243, 142, 37, 83, 201, 60, 83, 73, 188],
-		[249, 123, 201, 194, 230, 70, 126, 25, 138, 113, 175, 33, 210, 5, 158, 129, 36, 104, 71, 128, 164, 209, 142, 196, 224, 255, 33, 193, 187, 124, 152, 58],
+		[199, 10, 116, 77, 122, 158, 128, 180, 43, 24, 157, 12, 186, 53, 247, 87, 4, 191, 209, 166, 214, 153, 112, 88, 118, 160, 93, 244, 213, 229, 90, 45],
+		[66, 36, 122, 81, 103, 27, 79, 190, 114, 173, 169, 188, 112, 139, 130, 101, 79, 85, 181, 227, 23, 74, 216, 251, 165, 165, 66, 92, 49, 3, 109, 79],
+		[201, 230, 250, 232, 220, 113, 78, 191, 7, 55, 51, 97, 221, 134, 25, 188, 188, 78, 68, 197, 103, 182, 55, 197, 29, 114, 74, 164, 134, 179, 252, 11],
+		[160, 190, 14, 149, 236, 188, 220, 48, 104, 66, 51, 19, 250, 233, 194, 172, 157, 209, 11, 93, 181, 214, 156, 178, 98, 166, 74, 165, 244, 137, 156, 7],
 	],
 	[
-		[11, 50, 105, 59, 244, 112, 55, 177, 139, 52, 135, 24, 52, 70, 90, 227, 47, 185, 225, 184, 54, 67, 220, 241, 143, 235, 88, 183, 115, 17, 119, 194],
-		[19, 122, 174, 186, 98, 27, 25, 244, 31, 170, 165, 50, 5, 176, 243, 2, 214, 111, 173, 26, 38, 17, 141, 67, 124, 248, 154, 104, 159, 90, 173, 30],
-		[162, 95, 13, 110, 218, 75, 122, 125, 214, 15, 163, 186, 150, 34, 89, 42, 184, 25, 65, 253, 120, 127, 217, 21, 65, 80, 207, 233, 163, 51, 50, 240],
-		[154, 178, 223, 222, 165, 157, 20, 94, 44, 20, 212, 104, 46, 186, 90, 20, 18, 36, 37, 183, 205, 84, 225, 197, 51, 99, 123, 209, 21, 179, 29, 61],
+		[113, 237, 255, 140, 8, 168, 224, 208, 90, 61, 157, 179, 11, 14, 9, 152, 186, 163, 78, 240, 124, 88, 138, 177, 6, 186, 181, 109, 224, 24, 30, 217],
+		[249, 16, 162, 198, 146, 225, 199, 88, 192, 242, 136, 151, 66, 37, 17, 32, 176, 171, 227, 52, 195, 49, 218, 183, 139, 148, 112, 106, 111, 8, 97, 78],
+		[195, 169, 199, 252, 243, 130, 44, 191, 106, 177, 80, 67, 130, 58, 97, 110, 73, 167, 111, 72, 161, 32, 215, 240, 129, 24, 119, 253, 12, 16, 170, 222],
+		[3, 202, 147, 136, 152, 194, 161, 206, 32, 44, 70, 207, 23, 37, 75, 90, 145, 174, 90, 49, 139, 137, 44, 76, 247, 63, 129, 233, 139, 66, 68, 188],
 	],
 	[
-		[251, 41, 203, 80, 141, 188, 175, 175, 214, 168, 128, 63, 165, 140, 240, 230, 207, 156, 215, 205, 222, 173, 251, 1, 196, 9, 155, 46, 30, 25, 76, 17],
-		[16, 254, 88, 68, 130, 159, 215, 14, 252, 116, 196, 185, 226, 55, 67, 158, 5, 190, 195, 66, 68, 7, 161, 11, 185, 181, 87, 247, 212, 44, 144, 243],
-		[16, 161, 68, 118, 51, 82, 122, 18, 213, 79, 95, 33, 145, 45, 11, 247, 175, 110, 13, 89, 67, 101, 100, 163, 94, 90, 191, 115, 225, 220, 127, 194],
-		[237, 140, 11, 117, 26, 48, 86, 168, 152, 192, 219, 42, 123, 207, 150, 178, 123, 153, 115, 157, 155, 234, 120, 89, 217, 26, 247, 155, 202, 74, 94, 165],
+		[173, 219, 32, 176, 212, 129, 93, 238, 5, 84, 190, 120, 138, 125, 11, 13, 107, 39, 241, 240, 58, 195, 66, 7, 230, 211, 7, 230, 15, 239, 226, 18],
+		[42, 50, 42, 64, 210, 151, 153, 51, 9, 151, 190, 175, 213, 37, 148, 90, 196, 238, 215, 46, 95, 133, 122, 155, 183, 6, 122, 152, 34, 39, 107, 94],
+		[71, 171, 229, 183, 240, 186, 182, 101, 223, 20, 243, 134, 31, 53, 61, 142, 30, 77, 194, 221, 177, 149, 6, 167, 147, 177, 117, 225, 131, 122, 203, 73],
+		[96, 52, 242, 6, 69, 34, 226, 100, 86, 176, 38, 145, 144, 158, 129, 249, 173, 9, 201, 39, 4, 154, 174, 24, 22, 128, 230, 3, 223, 174, 199, 155],
 	],
 	[
-		[151, 215, 38, 106, 88, 3, 37, 170, 61, 92, 157, 166, 91, 82, 113, 164, 236, 33, 98, 10, 4, 156, 148, 59, 168, 128, 153, 80, 195, 251, 148, 102],
-		[215, 84, 246, 170, 171, 120, 127, 158, 66, 177, 188, 27, 71, 136, 201, 150, 174, 173, 153, 95, 243, 31, 74, 115, 149, 103, 157, 47, 172, 89, 17, 4],
-		[101, 164, 59, 56, 106, 240, 33, 118, 85, 143, 18, 208, 88, 188, 108, 35, 104, 150, 172, 80, 161, 179, 41, 210, 163, 227, 96, 253, 77, 193, 14, 101],
-		[10, 90, 195, 104, 195, 110, 132, 141, 235, 226, 88, 194, 2, 55, 48, 148, 1, 209, 99, 133, 89, 43, 47, 17, 127, 52, 207, 136, 193, 77, 215, 91],
+		[82, 221, 58, 119, 49, 39, 1, 136, 103, 117, 240, 244, 154, 230, 199, 87, 234, 57, 82, 155, 216, 51, 193, 62, 135, 122, 198, 200, 129, 207, 142, 61],
+		[58, 211, 9, 210, 97, 204, 100, 153, 80, 229, 41, 123, 100, 246, 141, 118, 81, 103, 30, 212, 214, 14, 252, 87, 152, 177, 68, 221, 170, 247, 94, 13],
+		[41, 180, 181, 64, 205, 208, 59, 176, 225, 233, 38, 161, 240, 29, 80, 195, 172, 130, 50, 127, 157, 196, 18, 34, 206, 200, 186, 67, 167, 232, 186, 150],
+		[40, 101, 250, 140, 59, 111, 209, 233, 24, 254, 182, 190, 212, 169, 132, 116, 22, 114, 181, 106, 86, 138, 55, 209, 30, 27, 12, 101, 92, 166, 146, 207],
 	],
 	[
-		[29, 167, 241, 180, 58, 80, 222, 181, 254, 149, 41, 142, 123, 27, 9, 242, 87, 101, 83, 185, 185, 251, 188, 117, 22, 122, 94, 110, 15, 124, 178, 236],
-		[25, 130, 208, 242, 144, 87, 171, 247, 98, 201, 67, 99, 20, 100, 184, 213, 83, 111, 183, 249, 101, 114, 166, 72, 80, 45, 121, 71, 166, 247, 5, 5],
-		[160, 171, 2, 115, 115, 62, 29, 58, 29, 162, 42, 23, 166, 70, 75, 49, 124, 202, 127, 172, 102, 192, 114, 20, 234, 217, 238, 53, 158, 122, 31, 186],
-		[255, 209, 83, 219, 248, 47, 241, 250, 148, 40, 145, 51, 104, 11, 123, 2, 248, 170, 59, 70, 10, 170, 120, 147, 71, 131, 114, 42, 115, 217, 137, 4],
+		[104, 85, 41, 4, 247, 202, 133, 194, 252, 178, 221, 178, 179, 107, 189, 166, 55, 92, 240, 94, 170, 158, 80, 147, 18, 171, 79, 107, 118, 27, 87, 155],
+		[88, 130, 237, 198, 90, 29, 131, 103, 244, 11, 131, 251, 104, 228, 119, 34, 203, 101, 35, 174, 136, 74, 131, 133, 230, 223, 246, 248, 132, 236, 196, 54],
+		[124, 35, 165, 76, 148, 157, 13, 119, 129, 196, 2, 169, 178, 29, 191, 227, 59, 138, 96, 25, 150, 50, 67, 216, 156, 228, 145, 8, 84, 191, 150, 16],
+		[238, 165, 53, 142, 217, 141, 131, 91, 104, 103, 67, 11, 75, 67, 33, 18, 86, 168, 125, 174, 59, 62, 98, 222, 147, 212, 154, 109, 235, 250, 134, 107],
 	],
 	[
-		[133, 191, 228, 235, 66, 92, 57, 44, 103, 241, 81, 62, 54, 235, 60, 168, 201, 172, 31, 82, 195, 44, 123, 220, 180, 180, 13, 91, 213, 200, 108, 75],
-		[141, 98, 107, 147, 214, 68, 225, 236, 25, 117, 227, 49, 39, 95, 151, 175, 39, 27, 237, 58, 49, 12, 120, 235, 34, 63, 12, 165, 144, 135, 14, 168],
-		[229, 232, 187, 20, 4, 121, 62, 183, 85, 69, 180, 109, 227, 208, 160, 30, 223, 201, 245, 254, 105, 245, 220, 59, 111, 151, 193, 17, 115, 181, 151, 166],
-		[5, 130, 36, 95, 207, 41, 48, 11, 75, 8, 199, 199, 241, 48, 171, 21, 20, 74, 38, 247, 53, 176, 63, 202, 222, 157, 101, 107, 16, 153, 14, 19],
+		[30, 47, 31, 244, 245, 80, 158, 157, 36, 46, 131, 213, 47, 47, 160, 23, 234, 10, 161, 40, 149, 234, 95, 0, 76, 214, 188, 128, 170, 16, 126, 146],
+		[182, 43, 216, 74, 61, 213, 78, 70, 5, 93, 240, 57, 47, 157, 252, 241, 205, 77, 175, 39, 106, 79, 230, 40, 187, 145, 118, 109, 148, 227, 5, 156],
+		[191, 112, 98, 79, 82, 78, 17, 104, 91, 14, 45, 1, 96, 70, 231, 237, 144, 32, 218, 202, 71, 4, 36, 36, 148, 198, 39, 7, 154, 180, 73, 229],
+		[254, 217, 193, 92, 93, 99, 176, 75, 39, 153, 242, 175, 135, 32, 233, 20, 24, 81, 251, 177, 141, 154, 54, 148, 198, 85, 71, 252, 211, 127, 98, 137],
 	],
 	[
-		[49, 83, 1, 101, 135, 236, 222, 124, 34, 162, 218, 212, 222, 36, 80, 194, 1, 121, 54, 176, 127, 243, 201, 101, 92, 98, 68, 178, 133, 77, 9, 61],
-		[69, 175, 155, 23, 215, 89, 57, 119, 115, 34, 130, 127, 118, 16, 55, 152, 145, 152, 140, 163, 59, 165, 32, 39, 143, 212, 202, 189, 129, 26, 31, 4],
-		[167, 153, 184, 251, 117, 201, 14, 26, 193, 170, 102, 251, 169, 185, 150, 118, 137, 33, 10, 243, 150, 228, 198, 154, 179, 89, 238, 7, 191, 242, 110, 138],
-		[122, 99, 97, 227, 58, 187, 20, 233, 15, 100, 184, 38, 29, 225, 163, 158, 232, 21, 106, 216, 241, 229, 129, 252, 26, 103, 135, 169, 15, 62, 207, 128],
+		[108, 149, 37, 104, 166, 178, 41, 162, 98, 155, 149, 237, 249, 33, 161, 43, 198, 180, 219, 241, 254, 252, 128, 96, 152, 46, 143, 8, 245, 12, 132, 80],
+		[177, 168, 8, 140, 187, 129, 15, 230, 39, 188, 164, 61, 133, 108, 113, 48, 11, 156, 30, 47, 71, 114, 211, 251, 92, 206, 215, 249, 186, 152, 53, 164],
+		[37, 102, 47, 144, 165, 54, 62, 220, 73, 190, 98, 243, 200, 5, 157, 121, 182, 121, 195, 230, 252, 16, 238, 194, 183, 28, 69, 41, 136, 149, 189, 158],
+		[194, 209, 154, 87, 0, 119, 230, 135, 186, 90, 232, 219, 160, 122, 63, 201, 45, 10, 219, 47, 35, 200, 68, 0, 110, 113, 124, 39, 167, 169, 108, 178],
 	],
 	[
-		[6, 22, 253, 231, 227, 180, 111, 186, 76, 57, 169, 18, 52, 214, 76, 39, 3, 190, 10, 169, 74, 228, 210, 145, 195, 76, 91, 107, 255, 152, 109, 20],
-		[147, 18, 27, 94, 88, 118, 166, 106, 76, 73, 72, 234, 159, 7, 218, 179, 239, 129, 123, 1, 215, 145, 140, 107, 6, 199, 92, 78, 47, 105, 190, 145],
-		[172, 72, 26, 18, 244, 10, 202, 72, 240, 102, 107, 245, 196, 86, 47, 129, 162, 52, 170, 85, 28, 208, 116, 68, 248, 155, 1, 252, 254, 252, 219, 189],
-		[188, 84, 62, 20, 200, 152, 204, 214, 213, 15, 185, 143, 175, 189, 135, 171, 177, 206, 38, 170, 165, 16, 76, 25, 125, 116, 237, 11, 70, 88, 232, 207],
+		[182, 143, 134, 238, 52, 185, 80, 225, 43, 188, 219, 169, 197, 225, 76, 150, 151, 101, 56, 101, 13, 201, 177, 248, 232, 104, 177, 255, 39, 143, 186, 225],
+		[109, 162, 112, 0, 103, 237, 102, 93, 182, 184, 252, 33, 150, 172, 252, 154, 38, 36, 47, 34, 138, 45, 246, 88, 153, 251, 249, 136, 182, 177, 132, 189],
+		[247, 157, 166, 6, 94, 125, 176, 232, 40, 144, 96, 127, 236, 19, 104, 188, 29, 203, 0, 204, 31, 190, 165, 105, 119, 213, 199, 239, 238, 13, 15, 78],
+		[3, 205, 83, 160, 167, 137, 234, 241, 87, 220, 203, 15, 114, 71, 32, 40, 18, 240, 28, 35, 169, 143, 77, 242, 138, 56, 32, 73, 147, 222, 217, 93],
 	],
 	[
-		[65, 166, 48, 120, 90, 114, 248, 205, 2, 147, 110, 27, 50, 205, 142, 67, 205, 233, 201, 10, 156, 185, 218, 237, 186, 248, 202, 161, 163, 111, 181, 28],
-		[140, 28, 119, 239, 146, 207, 22, 40, 82, 239, 177, 98, 86, 184, 232, 53, 162, 104, 200, 143, 213, 170, 243, 242, 127, 71, 101, 111, 214, 237, 77, 242],
-		[22, 30, 133, 97, 1, 215, 21, 64, 229, 188, 13, 171, 222, 39, 195, 40, 254, 112, 72, 119, 203, 149, 24, 254, 253, 32, 98, 84, 247, 140, 91, 83],
-		[26, 205, 80, 193, 245, 247, 139, 184, 108, 96, 136, 26, 105, 54, 222, 159, 71, 71, 243, 106, 41, 142, 171, 29, 249, 154, 121, 183, 228, 230, 129, 75],
+		[135, 251, 140, 250, 115, 228, 213, 234, 22, 90, 5, 173, 51, 210, 101, 121, 46, 95, 40, 36, 155, 226, 62, 245, 16, 117, 33, 71, 188, 26, 44, 233],
+		[27, 198, 37, 73, 19, 197, 75, 58, 156, 181, 243, 109, 53, 106, 70, 221, 14, 89, 105, 228, 188, 148, 54, 77, 246, 123, 144, 193, 223, 229, 123, 8],
+		[59, 117, 101, 47, 113, 127, 141, 192, 186, 54, 20, 111, 179, 19, 36, 105, 147, 1, 90, 102, 116, 114, 114, 166, 15, 179, 102, 138, 125, 171, 118, 36],
+		[234, 160, 78, 22, 253, 200, 184, 163, 111, 113, 154, 197, 106, 31, 219, 165, 57, 151, 214, 1, 168, 15, 165, 242, 157, 111, 163, 35, 168, 203, 240, 220],
 	],
 	[
-		[34, 178, 117, 215, 218, 82, 93, 156, 65, 155, 166, 206, 255, 93, 166, 71, 70, 86, 68, 104, 162, 128, 21, 183, 74, 137, 252, 92, 183, 218, 79, 74],
-		[199, 53, 104, 120, 165, 248, 227, 15, 28, 7, 245, 19, 54, 68, 158, 127, 55, 88, 136, 221, 142, 223, 208, 170, 213, 106, 124, 186, 109, 51, 245, 44],
-		[97, 222, 153, 45, 241, 11, 109, 159, 105, 157, 20, 162, 174, 213, 167, 234, 24, 251, 93, 90, 197, 48, 122, 161, 79, 83, 112, 161, 141, 81, 72, 155],
-		[243, 146, 252, 24, 117, 13, 245, 193, 204, 87, 23, 46, 185, 240, 41, 130, 202, 249, 72, 20, 194, 163, 107, 136, 48, 75, 214, 25, 191, 87, 172, 40],
+		[20, 171, 89, 27, 178, 86, 230, 120, 190, 65, 200, 190, 105, 89, 167, 101, 112, 51, 173, 117, 203, 125, 18, 156, 69, 158, 84, 216, 194, 25, 197, 146],
+		[127, 155, 78, 91, 42, 96, 49, 71, 21, 17, 105, 173, 29, 115, 142, 193, 170, 82, 88, 186, 66, 141, 197, 177, 243, 160, 197, 13, 184, 109, 69, 229],
+		[166, 7, 200, 86, 173, 202, 175, 47, 83, 192, 64, 53, 96, 245, 19, 182, 5, 177, 76, 66, 204, 71, 175, 135, 99, 129, 247, 70, 171, 7, 180, 210],
+		[174, 163, 67, 109, 37, 145, 158, 143, 216, 176, 175, 44, 71, 127, 76, 150, 30, 201, 184, 144, 228, 61, 82, 84, 171, 4, 80, 29, 92, 190, 199, 217],
 	],
 	[
-		[56, 144, 157, 133, 254, 229, 133, 235, 242, 44, 9, 0, 225, 83, 216, 230, 158, 200, 104, 169, 77, 208, 27, 241, 213, 223, 92, 247, 125, 109, 71, 159],
-		[108, 190, 184, 139, 18, 111, 18, 207, 218, 195, 48, 249, 12, 75, 250, 123, 142, 243, 109, 50, 232, 198, 99, 130, 197, 248, 10, 99, 141, 108, 130, 170],
-		[7, 208, 175, 77, 110, 188, 131, 24, 109, 110, 145, 110, 173, 190, 31, 13, 186, 176, 76, 226, 81, 136, 240, 71, 50, 88, 74, 206, 30, 235, 102, 110],
-		[153, 40, 24, 161, 129, 129, 51, 178, 79, 41, 101, 78, 50, 167, 29, 19, 156, 84, 29, 120, 121, 136, 139, 159, 213, 7, 62, 176, 177, 157, 115, 11],
+		[164, 192, 165, 183, 35, 159, 104, 12, 172, 130, 175, 164, 59, 9, 13, 148, 244, 159, 141, 173, 130, 116, 18, 8, 31, 253, 78, 192, 186, 215, 67, 162],
+		[55, 222, 247, 239, 1, 189, 131, 220, 251, 29, 33, 231, 211, 248, 221, 40, 4, 110, 49, 114, 123, 48, 26, 140, 239, 127, 208, 234, 184, 211, 219, 166],
+		[189, 7, 95, 35, 42, 114, 234, 140, 66, 63, 173, 135, 40, 81, 220, 147, 85, 0, 0, 57, 221, 214, 81, 93, 212, 63, 71, 30, 103, 196, 191, 111],
+		[143, 240, 114, 245, 121, 11, 109, 151, 147, 124, 237, 38, 145, 67, 218, 136, 128, 233, 226, 17, 8, 88, 128, 131, 48, 144, 3, 16, 89, 150, 159, 133],
 	],
 	[
-		[230, 148, 165, 245, 119, 236, 135, 43, 220, 65, 97, 248, 1, 75, 118, 75, 225, 101, 199, 55, 147, 186, 221, 88, 59, 206, 156, 8, 147, 5, 47, 193],
-		[61, 223, 254, 144, 159, 150, 134, 240, 144, 11, 212, 107, 220, 54, 44, 47, 182, 195, 132, 234, 155, 27, 131, 65, 81, 231, 213, 40, 22, 227, 93, 164],
-		[231, 30, 231, 74, 5, 163, 52, 164, 130, 125, 223, 25, 170, 240, 189, 19, 184, 186, 39, 26, 189, 136, 135, 236, 224, 126, 254, 164, 95, 232, 173, 214],
-		[184, 68, 150, 76, 156, 60, 249, 12, 180, 240, 117, 197, 129, 227, 70, 103, 76, 126, 61, 27, 159, 202, 60, 13, 178, 120, 203, 10, 175, 139, 54, 158],
+		[15, 34, 182, 149, 21, 152, 146, 23, 48, 114, 41, 203, 123, 131, 167, 60, 138, 84, 59, 120, 227, 133, 244, 152, 94, 115, 140, 226, 96, 184, 148, 16],
+		[146, 161, 252, 166, 238, 96, 6, 202, 216, 122, 245, 215, 139, 135, 245, 57, 189, 48, 229, 106, 129, 146, 144, 124, 35, 127, 103, 94, 145, 52, 238, 24],
+		[51, 98, 246, 91, 131, 216, 127, 213, 173, 112, 227, 42, 237, 221, 29, 179, 183, 69, 35, 67, 61, 199, 44, 175, 155, 234, 228, 209, 139, 175, 148, 88],
+		[238, 119, 54, 12, 174, 117, 201, 217, 140, 212, 198, 155, 51, 248, 102, 228, 17, 122, 206, 186, 177, 213, 157, 176, 112, 247, 4, 214, 85, 12, 19, 7],
 	],
 	[
-		[175, 119, 101, 65, 219, 36, 132, 186, 132, 114, 184, 184, 83, 251, 30, 206, 53, 24, 147, 9, 150, 171, 233, 132, 61, 22, 173, 37, 249, 86, 127, 176],
-		[159, 168, 4, 154, 228, 15, 179, 94, 164, 108, 220, 114, 70, 143, 4, 108, 68, 23, 235, 214, 217, 119, 142, 201, 75, 223, 63, 2, 95, 213, 11, 234],
-		[111, 115, 208, 140, 49, 57, 67, 69, 165, 60, 123, 142, 60, 58, 135, 16, 38, 230, 121, 136, 197, 200, 27, 101, 37, 106, 110, 110, 120, 250, 231, 2],
-		[231, 58, 62, 190, 118, 75, 113, 64, 123, 84, 24, 223, 100, 91, 224, 41, 88, 66, 151, 23, 111, 182, 17, 142, 48, 76, 163, 157, 66, 17, 49, 29],
+		[121, 234, 221, 250, 62, 14, 224, 219, 175, 161, 105, 170, 195, 108, 6, 97, 161, 214, 244, 181, 214, 72, 28, 248, 26, 57, 158, 247, 28, 84, 217, 164],
+		[92, 73, 133, 87, 74, 98, 202, 168, 33, 184, 62, 160, 47, 195, 54, 218, 71, 121, 192, 78, 34, 238, 140, 218, 185, 148, 50, 147, 91, 202, 90, 132],
+		[34, 212, 5, 116, 113, 84, 126, 205, 12, 3, 212, 59, 227, 207, 210, 251, 94, 167, 5, 211, 148, 101, 92, 154, 142, 53, 85, 185, 50, 189, 175, 5],
+		[117, 118, 239, 223, 63, 149, 245, 112, 162, 188, 60, 0, 67, 194, 193, 42, 31, 245, 65, 77, 13, 80, 67, 70, 195, 13, 193, 132, 96, 41, 43, 129],
 	],
 	[
-		[84, 166, 229, 150, 6, 157, 99, 93, 202, 244, 45, 114, 29, 2, 64, 251, 151, 52, 239, 153, 99, 196, 178, 0, 108, 226, 92, 9, 250, 34, 98, 208],
-		[181, 189, 156, 101, 213, 184, 106, 80, 243, 173, 164, 81, 130, 72, 69, 14, 172, 74, 119, 111, 168, 68, 11, 160, 13, 74, 169, 156, 154, 64, 32, 30],
-		[134, 84, 95, 72, 251, 18, 166, 35, 192, 175, 55, 34, 92, 81, 134, 3, 80, 157, 172, 164, 148, 109, 64, 13, 92, 195, 250, 97, 16, 29, 120, 136],
-		[71, 46, 254, 213, 65, 241, 119, 130, 224, 112, 46, 194, 91, 211, 87, 184, 198, 43, 129, 253, 155, 2, 205, 44, 64, 213, 160, 166, 233, 206, 230, 227],
+		[117, 136, 254, 249, 6, 169, 110, 123, 54, 144, 105, 218, 40, 104, 68, 138, 122, 51, 216, 188, 230, 34, 241, 120, 54, 101, 109, 59, 108, 200, 46, 94],
+		[14, 214, 169, 73, 2, 0, 133, 81, 5, 244, 83, 188, 52, 128, 104, 9, 210, 41, 98, 197, 53, 92, 141, 111, 116, 166, 149, 107, 219, 51, 111, 162],
+		[110, 4, 174, 110, 51, 145, 182, 15, 195, 118, 60, 251, 152, 210, 99, 151, 207, 145, 24, 200, 110, 184, 64, 81, 193, 171, 220, 181, 58, 210, 112, 128],
+		[161, 193, 26, 169, 197, 169, 132, 126, 212, 249, 220, 48, 237, 200, 43, 178, 66, 154, 253, 26, 3, 61, 107, 166, 52, 112, 163, 143, 8, 60, 233, 197],
 	],
 	[
-		[120, 75, 112, 28, 155, 201, 86, 188, 226, 166, 224, 234, 118, 36, 180, 116, 247, 242, 156, 247, 83, 220, 52, 238, 20, 31, 42, 10, 177, 173, 63, 206],
-		[125, 74, 209, 102, 165, 0, 167, 14, 116, 216, 76, 24, 25, 209, 136, 65, 210, 88, 239, 105, 230, 225, 126, 67, 197, 158, 196, 93, 39, 87, 158, 221],
-		[148, 207, 71, 81, 16, 138, 59, 66, 64, 158, 28, 161, 254, 85, 198, 117, 141, 184, 172, 28, 233, 58, 124, 131, 8, 92, 79, 182, 205, 111, 135, 80],
-		[161, 55, 37, 106, 224, 114, 150, 254, 215, 5, 173, 0, 215, 100, 145, 16, 41, 107, 42, 199, 19, 97, 221, 79, 200, 41, 24, 142, 148, 213, 55, 224],
+		[137, 144, 160, 179, 175, 187, 189, 117, 45, 156, 8, 84, 102, 32, 20, 246, 21, 136, 34, 224, 231, 196, 185, 235, 147, 92, 202, 185, 165, 139, 12, 50],
+		[6, 212, 106, 217, 66, 116, 55, 60, 8, 122, 127, 64, 111, 95, 59, 122, 96, 228, 20, 118, 134, 177, 231, 154, 41, 6, 66, 30, 158, 184, 166, 23],
+		[185, 65, 224, 112, 167, 50, 152, 49, 33, 113, 113, 37, 107, 27, 24, 248, 116, 99, 99, 201, 91, 168, 165, 197, 93, 11, 65, 136, 160, 71, 248, 142],
+		[165, 58, 82, 34, 253, 14, 221, 118, 186, 98, 37, 136, 144, 212, 65, 132, 120, 178, 85, 49, 199, 94, 67, 134, 155, 81, 212, 71, 7, 49, 148, 140],
 	],
 	[
-		[49, 215, 129, 64, 142, 122, 152, 241, 65, 97, 7, 132, 80, 110, 196, 193, 5, 88, 187, 215, 127, 249, 75, 115, 50, 137, 116, 134, 137, 143, 56, 106],
-		[133, 13, 143, 190, 94, 100, 67, 73, 28, 70, 140, 121, 133, 227, 189, 195, 38, 39, 225, 97, 191, 176, 52, 179, 123, 173, 229, 121, 224, 33, 251, 208],
-		[59, 10, 47, 118, 170, 12, 42, 244, 232, 44, 134, 159, 222, 176, 244, 23, 214, 217, 26, 174, 101, 81, 242, 48, 214, 254, 244, 88, 92, 217, 36, 202],
-		[133, 186, 41, 63, 192, 238, 151, 58, 241, 63, 220, 186, 100, 136, 166, 165, 179, 168, 42, 156, 61, 137, 24, 64, 172, 123, 29, 4, 151, 153, 85, 30],
+		[188, 253, 162, 214, 157, 31, 177, 43, 160, 212, 213, 190, 170, 74, 22, 2, 104, 64, 100, 11, 241, 216, 85, 167, 49, 161, 78, 81, 49, 236, 110, 152],
+		[192, 185, 31, 176, 203, 80, 31, 231, 158, 53, 26, 227, 21, 99, 254, 55, 212, 209, 239, 237, 213, 190, 147, 248, 139, 16, 95, 5, 103, 166, 52, 38],
+		[110, 176, 238, 73, 95, 48, 200, 253, 178, 20, 249, 93, 209, 235, 118, 116, 182, 122, 89, 158, 158, 253, 116, 122, 77, 147, 106, 255, 150, 249, 90, 142],
+		[95, 153, 175, 54, 98, 220, 149, 67, 46, 19, 47, 232, 49, 193, 203, 238, 43, 139, 244, 104, 133, 46, 233, 141, 78, 101, 208, 83, 179, 1, 217, 1],
 	],
 	[
-		[74, 106, 242, 27, 93, 5, 247, 30, 125, 31, 19, 22, 158, 165, 156, 131, 175, 63, 185, 66, 212, 65, 83, 98, 204, 97, 122, 180, 242, 134, 155, 227],
-		[107, 146, 170, 14, 178, 18, 210, 24, 95, 21, 153, 128, 160, 101, 204, 74, 45, 120, 187, 115, 203, 26, 31, 69, 241, 61, 210, 244, 133, 113, 170, 195],
-		[89, 228, 159, 94, 44, 9, 94, 151, 93, 123, 117, 134, 98, 196, 191, 99, 69, 255, 249, 216, 179, 232, 105, 55, 18, 174, 15, 47, 43, 139, 88, 78],
-		[61, 178, 188, 6, 187, 171, 122, 63, 76, 203, 21, 26, 213, 197, 164, 81, 154, 58, 200, 229, 190, 202, 182, 143, 130, 156, 12, 20, 76, 133, 164, 59],
+		[237, 3, 43, 235, 155, 206, 26, 200, 19, 187, 5, 52, 185, 99, 80, 94, 249, 248, 30, 8, 205, 175, 35, 242, 146, 252, 50, 49, 2, 58, 189, 71],
+		[123, 201, 189, 62, 238, 140, 122, 207, 97, 176, 181, 231, 222, 51, 178, 10, 176, 23, 55, 146, 79, 103, 179, 121, 40, 168, 122, 221, 234, 68, 33, 184],
+		[152, 233, 173, 242, 220, 72, 204, 209, 87, 226, 247, 190, 124, 231, 35, 162, 10, 192, 194, 19, 55, 43, 41, 213, 150, 25, 28, 86, 157, 101, 220, 227],
+		[9, 20, 127, 176, 178, 240, 146, 17, 13, 174, 158, 239, 112, 64, 107, 10, 114, 169, 205, 17, 254, 145, 6, 194, 139, 101, 30, 25, 181, 158, 183, 150],
 	],
 	[
-		[121, 59, 21, 203, 150, 117, 76, 234, 164, 201, 98, 64, 27, 195, 43, 210, 93, 146, 99, 122, 4, 17, 123, 124, 60, 165, 161, 19, 127, 98, 222, 145],
-		[233, 242, 33, 148, 123, 152, 85, 151, 176, 196, 125, 228, 181, 33, 124, 242, 142, 209, 43, 85, 179, 80, 13, 133, 255, 228, 232, 225, 55, 165, 208, 111],
-		[136, 92, 244, 95, 241, 140, 162, 46, 71, 42, 129, 162, 148, 77, 125, 50, 94, 64, 182, 34, 215, 177, 82, 76, 63, 93, 113, 152, 63, 110, 85, 192],
-		[232, 146, 39, 253, 136, 152, 107, 20, 9, 249, 48, 37, 203, 142, 229, 24, 233, 64, 55, 118, 255, 104, 94, 150, 144, 18, 44, 61, 185, 235, 150, 128],
+		[23, 105, 164, 160, 63, 9, 109, 138, 158, 71, 241, 146, 183, 71, 109, 159, 224, 115, 65, 193, 125, 179, 10, 149, 254, 140, 85, 242, 37, 118, 27, 169],
+		[181, 202, 174, 155, 78, 8, 57, 27, 115, 122, 89, 98, 49, 212, 243, 109, 230, 129, 197, 200, 172, 148, 19, 201, 227, 180, 33, 113, 174, 155, 25, 188],
+		[203, 198, 5, 159, 118, 234, 90, 36, 112, 122, 140, 131, 14, 240, 78, 107, 34, 220, 56, 54, 26, 47, 149, 2, 247, 133, 214, 98, 167, 173, 179, 159],
+		[186, 204, 93, 96, 134, 156, 73, 248, 31, 203, 156, 190, 129, 119, 48, 37, 98, 200, 231, 66, 132, 67, 154, 218, 20, 131, 162, 201, 19, 112, 193, 9],
 	],
 	[
-		[225, 11, 155, 219, 118, 51, 103, 43, 69, 210, 192, 149, 117, 146, 18, 112, 170, 184, 220, 161, 101, 10, 254, 253, 143, 102, 41, 24, 58, 226, 24, 166],
-		[195, 52, 54, 69, 50, 201, 131, 96, 208, 206, 117, 67, 176, 154, 179, 217, 174, 78, 187, 90, 34, 30, 241, 129, 200, 157, 114, 209, 118, 230, 169, 147],
-		[21, 146, 50, 14, 255, 176, 47, 107, 170, 73, 89, 178, 218, 153, 86, 183, 3, 211, 44, 178, 122, 54, 222, 39, 50, 62, 20, 163, 26, 247, 149, 96],
-		[8, 113, 123, 57, 216, 65, 177, 193, 170, 137, 152, 104, 29, 174, 237, 208, 55, 5, 4, 48, 240, 182, 221, 34, 96, 128, 21, 200, 239, 217, 44, 22],
+		[192, 218, 195, 5, 2, 215, 55, 141, 31, 21, 59, 152, 114, 198, 178, 81, 79, 15, 159, 75, 101, 239, 91, 188, 180, 222, 124, 225, 53, 119, 90, 13],
+		[191, 201, 109, 80, 158, 77, 215, 1, 66, 3, 141, 150, 241, 195, 41, 183, 167, 78, 76, 248, 82, 27, 148, 10, 164, 51, 150, 0, 90, 111, 26, 231],
+		[164, 49, 110, 14, 254, 54, 150, 27, 166, 38, 92, 79, 0, 234, 180, 238, 71, 240, 20, 112, 81, 211, 60, 227, 210, 142, 63, 136, 76, 65, 220, 223],
+		[161, 136, 241, 168, 11, 15, 8, 241, 90, 164, 156, 92, 231, 225, 183, 82, 234, 134, 98, 35, 223, 174, 9, 136, 33, 58, 61, 146, 40, 226, 89, 243],
 	],
 	[
-		[195, 185, 217, 225, 29, 71, 41, 79, 234, 36, 171, 11, 5, 187, 56, 146, 51, 131, 167, 222, 65, 79, 145, 226, 158, 233, 234, 11, 179, 220, 202, 143],
-		[140, 119, 60, 135, 156, 84, 208, 11, 187, 2, 173, 64, 174, 2, 146, 21, 50, 87, 216, 248, 134, 135, 197, 102, 2, 36, 59, 61, 44, 226, 12, 146],
-		[121, 96, 165, 107, 70, 74, 48, 215, 224, 42, 5, 76, 66, 42, 44, 235, 87, 162, 49, 82, 76, 34, 118, 38, 251, 179, 213, 112, 160, 67, 225, 12],
-		[105, 85, 47, 251, 237, 239, 6, 111, 44, 179, 61, 184, 213, 61, 214, 137, 255, 12, 25, 132, 150, 97, 154, 39, 135, 80, 22, 16, 77, 174, 170, 64],
+		[9, 0, 13, 154, 42, 91, 15, 109, 0, 132, 25, 15, 236, 30, 185, 31, 221, 235, 95, 164, 231, 221, 112, 205, 231, 189, 25, 160, 121, 146, 253, 180],
+		[103, 154, 120, 29, 155, 16, 168, 162, 127, 54, 183, 199, 229, 94, 133, 253, 109, 117, 247, 23, 132, 115, 157, 144, 5, 191, 72, 169, 198, 189, 128, 213],
+		[82, 150, 65, 64, 244, 117, 174, 109, 130, 183, 132, 151, 146, 158, 29, 109, 188, 89, 119, 138, 228, 125, 59, 21, 250, 123, 217, 26, 103, 81, 75, 38],
+		[205, 233, 73, 184, 116, 123, 146, 162, 205, 146, 144, 109, 198, 8, 201, 205, 169, 202, 244, 116, 79, 108, 192, 95, 194, 86, 193, 226, 238, 230, 2, 193],
 	],
 	[
-		[250, 58, 66, 219, 111, 6, 123, 54, 170, 133, 27, 154, 154, 115, 237, 163, 214, 228, 250, 32, 232, 84, 22, 203, 161, 200, 26, 81, 192, 35, 128, 229],
-		[32, 185, 163, 114, 73, 41, 160, 184, 36, 230, 74, 17, 237, 163, 170, 254, 60, 69, 105, 61, 140, 183, 59, 208, 104, 205, 249, 184, 207, 20, 226, 186],
-		[17, 172, 216, 104, 108, 41, 12, 145, 147, 219, 108, 3, 20, 149, 214, 183, 107, 69, 161, 205, 189, 239, 31, 191, 131, 73, 168, 75, 162, 219, 238, 151],
-		[188, 88, 143, 211, 46, 104, 122, 172, 116, 66, 57, 168, 187, 234, 80, 33, 25, 233, 150, 222, 106, 7, 22, 46, 54, 94, 25, 166, 100, 218, 53, 164],
+		[198, 50, 135, 39, 125, 185, 103, 45, 149, 50, 153, 181, 62, 162, 240, 240, 65, 101, 119, 17, 146, 24, 38, 254, 215, 4, 70, 166, 69, 134, 56, 84],
+		[126, 217, 85, 213, 121, 122, 152, 41, 71, 221, 96, 238, 120, 207, 43, 122, 145, 233, 87, 49, 131, 161, 117, 81, 59, 253, 2, 143, 229, 39, 84, 40],
+		[226, 100, 88, 206, 110, 185, 190, 154, 85, 60, 132, 183, 169, 215, 96, 209, 73, 86, 115, 233, 99, 199, 199, 203, 229, 175, 197, 221, 57, 22, 104, 224],
+		[99, 160, 221, 73, 120, 198, 42, 29, 140, 80, 18, 19, 67, 205, 245, 239, 198, 46, 137, 168, 96, 8, 38, 181, 255, 213, 29, 128, 228, 135, 165, 53],
 	],
 	[
-		[237, 44, 94, 215, 11, 56, 170, 166, 89, 16, 214, 88, 87, 74, 70, 199, 63, 72, 163, 37, 155, 18, 238, 64, 120, 253, 190, 57, 72, 252, 75, 96],
-		[103, 7, 92, 93, 35, 232, 64, 61, 187, 109, 111, 98, 151, 222, 246, 219, 75, 13, 109, 115, 17, 120, 215, 97, 23, 20, 244, 112, 166, 167, 0, 227],
-		[117, 97, 130, 58, 211, 253, 221, 92, 145, 122, 74, 74, 136, 253, 252, 122, 27, 189, 61, 102, 152, 120, 242, 100, 39, 141, 80, 183, 249, 76, 208, 218],
-		[106, 128, 86, 171, 0, 205, 35, 167, 180, 110, 239, 140, 160, 59, 147, 139, 6, 177, 78, 108, 100, 130, 2, 125, 236, 104, 58, 154, 35, 239, 3, 67],
+		[123, 133, 92, 216, 126, 168, 21, 65, 159, 1, 9, 57, 212, 124, 125, 124, 27, 104, 131, 149, 112, 36, 136, 139, 33, 167, 8, 128, 85, 72, 134, 29],
+		[201, 238, 27, 9, 61, 75, 247, 251, 128, 48, 190, 9, 54, 55, 109, 183, 61, 196, 46, 20, 254, 111, 170, 90, 153, 217, 181, 166, 119, 124, 53, 108],
+		[28, 51, 6, 92, 122, 86, 134, 155, 219, 151, 250, 60, 39, 0, 117, 88, 225, 199, 215, 187, 73, 178, 240, 17, 87, 16, 176, 216, 61, 71, 4, 63],
+		[235, 226, 71, 235, 88, 145, 70, 49, 217, 16, 152, 100, 230, 204, 240, 92, 77, 181, 221, 137, 35, 186, 159, 221, 126, 175, 246, 120, 10, 59, 101, 102],
 	],
 	[
-		[140, 31, 148, 28, 240, 81, 161, 131, 243, 106, 217, 96, 188, 173, 222, 86, 216, 27, 229, 154, 141, 32, 7, 224, 204, 52, 232, 100, 208, 151, 57, 220],
-		[251, 111, 11, 166, 102, 201, 210, 136, 235, 10, 6, 98, 227, 172, 91, 210, 217, 186, 95, 95, 146, 160, 168, 143, 19, 149, 97, 1, 123, 58, 223, 237],
-		[154, 227, 167, 99, 231, 36, 217, 43, 66, 94, 102, 152, 215, 122, 158, 71, 128, 119, 71, 27, 233, 21, 219, 116, 86, 67, 73, 50, 241, 8, 130, 183],
-		[240, 255, 2, 93, 147, 22, 119, 65, 78, 243, 157, 1, 217, 109, 29, 120, 173, 127, 11, 61, 54, 71, 41, 64, 22, 110, 126, 212, 169, 18, 168, 163],
+		[138, 96, 79, 112, 36, 225, 132, 102, 45, 189, 142, 236, 16, 118, 31, 247, 90, 247, 6, 141, 54, 91, 63, 212, 7, 193, 71, 193, 63, 136, 37, 87],
+		[254, 34, 121, 228, 240, 17, 121, 41, 45, 180, 135, 227, 244, 51, 216, 116, 175, 222, 106, 193, 65, 96, 229, 229, 99, 115, 168, 149, 135, 63, 166, 163],
+		[128, 192, 247, 44, 190, 133, 214, 37, 190, 50, 117, 218, 21, 124, 63, 124, 240, 146, 155, 26, 23, 182, 93, 96, 236, 236, 75, 162, 131, 147, 84, 108],
+		[197, 29, 228, 255, 158, 68, 189, 136, 40, 220, 8, 198, 212, 61, 43, 133, 155, 141, 249, 12, 3, 92, 41, 62, 179, 16, 6, 130, 178, 0, 50, 88],
 	],
 	[
-		[30, 125, 220, 27, 25, 57, 210, 209, 26, 228, 32, 196, 169, 64, 161, 17, 127, 213, 46, 230, 140, 95, 221, 54, 73, 169, 201, 214, 167, 177, 202, 164],
-		[11, 170, 15, 19, 144, 103, 158, 146, 207, 28, 168, 165, 140, 132, 83, 27, 35, 146, 234, 167, 226, 83, 37, 85, 147, 119, 175, 103, 84, 49, 6, 90],
-		[164, 218, 3, 6, 11, 102, 243, 246, 249, 221, 73, 192, 108, 56, 215, 43, 156, 27, 245, 96, 199, 85, 189, 133, 67, 185, 129, 6, 20, 140, 98, 66],
-		[234, 238, 238, 1, 153, 124, 6, 66, 43, 15, 140, 40, 169, 201, 131, 226, 15, 21, 89, 178, 32, 51, 61, 20, 236, 225, 209, 143, 191, 148, 181, 166],
+		[46, 1, 150, 183, 216, 44, 110, 223, 52, 244, 219, 228, 132, 252, 62, 98, 240, 31, 233, 160, 22, 25, 158, 130, 107, 158, 111, 106, 212, 6, 188, 148],
+		[182, 79, 232, 3, 142, 128, 11, 194, 105, 148, 162, 52, 48, 236, 42, 194, 91, 247, 70, 65, 141, 208, 90, 64, 5, 193, 87, 118, 59, 151, 208, 137],
+		[114, 188, 82, 183, 151, 188, 198, 0, 238, 31, 190, 80, 252, 190, 204, 225, 61, 74, 109, 185, 86, 172, 207, 254, 28, 170, 248, 117, 251, 46, 26, 240],
+		[155, 168, 120, 124, 117, 210, 2, 135, 129, 250, 194, 177, 110, 217, 241, 216, 7, 103, 246, 4, 191, 217, 59, 16, 209, 40, 0, 85, 61, 171, 221, 217],
 	],
 	[
-		[131, 106, 61, 30, 21, 78, 138, 117, 42, 29, 143, 233, 184, 215, 228, 65, 73, 53, 100, 215, 178, 217, 200, 116, 115, 199, 88, 140, 103, 211, 148, 64],
-		[39, 35, 101, 213, 151, 171, 26, 177, 27, 26, 189, 108, 83, 117, 189, 191, 219, 143, 114, 157, 60, 110, 28, 218, 183, 87, 253, 26, 43, 80, 12, 13],
-		[94, 197, 125, 114, 186, 45, 214, 7, 211, 179, 179, 150, 195, 183, 180, 23, 105, 183, 129, 163, 53, 100, 36, 94, 21, 244, 92, 53, 162, 184, 95, 139],
-		[229, 42, 167, 8, 199, 42, 251, 201, 163, 142, 119, 254, 40, 209, 124, 4, 152, 53, 95, 175, 154, 230, 69, 255, 252, 170, 147, 59, 110, 171, 251, 234],
+		[32, 231, 93, 244, 108, 225, 235, 117, 177, 14, 110, 60, 158, 167, 84, 43, 0, 205, 106, 67, 143, 174, 229, 0, 31, 62, 113, 182, 61, 155, 177, 223],
+		[24, 52, 108, 82, 215, 107, 1, 1, 74, 253, 213, 136, 103, 87, 54, 186, 111, 246, 204, 205, 5, 176, 190, 54, 36, 225, 11, 46, 190, 144, 177, 87],
+		[226, 121, 170, 125, 95, 43, 110, 127, 176, 196, 88, 43, 240, 162, 116, 154, 140, 24, 2, 30, 250, 185, 203, 188, 202, 100, 5, 61, 175, 92, 238, 18],
+		[200, 142, 130, 53, 63, 214, 120, 80, 184, 111, 123, 111, 9, 131, 160, 93, 52, 29, 21, 79, 145, 91, 231, 255, 186, 154, 200, 73, 69, 163, 56, 97],
 	],
 	[
-		[167, 229, 194, 50, 247, 65, 151, 246, 145, 232, 86, 177, 185, 222, 246, 205, 214, 64, 37, 46, 108, 23, 245, 54, 40, 83, 116, 118, 31, 15, 205, 58],
-		[20, 11, 145, 102, 41, 168, 169, 12, 7, 75, 1, 238, 177, 31, 167, 20, 248, 101, 92, 239, 180, 17, 183, 6, 164, 233, 130, 245, 33, 208, 130, 228],
-		[34, 168, 94, 113, 114, 96, 134, 254, 225, 72, 53, 97, 122, 8, 21, 227, 216, 22, 96, 93, 59, 232, 32, 156, 252, 98, 8, 0, 115, 108, 95, 196],
-		[224, 178, 61, 71, 176, 154, 65, 145, 187, 95, 0, 98, 247, 228, 55, 50, 130, 119, 243, 161, 147, 100, 66, 4, 200, 13, 122, 34, 19, 139, 73, 87],
+		[61, 120, 125, 62, 176, 220, 71, 28, 130, 89, 120, 13, 61, 222, 117, 55, 114, 18, 172, 92, 65, 19, 101, 54, 161, 170, 219, 109, 171, 123, 1, 204],
+		[153, 120, 96, 64, 232, 131, 233, 16, 229, 33, 223, 190, 194, 110, 182, 36, 23, 51, 40, 247, 221, 184, 252, 139, 225, 23, 91, 211, 188, 102, 129, 133],
+		[118, 173, 100, 115, 159, 241, 148, 158, 94, 44, 99, 144, 24, 95, 166, 194, 172, 50, 152, 158, 104, 141, 42, 24, 134, 161, 237, 68, 72, 40, 199, 220],
+		[75, 99, 94, 127, 177, 194, 133, 218, 132, 4, 137, 105, 36, 100, 237, 17, 210, 253, 55, 114, 252, 175, 122, 111, 72, 133, 56, 182, 236, 189, 174, 0],
 	],
 	[
-		[24, 245, 221, 79, 17, 212, 97, 22, 166, 188, 245, 173, 29, 73, 57, 9, 57, 72, 120, 49, 94, 43, 142, 201, 112, 63, 158, 240, 97, 100, 207, 75],
-		[229, 119, 38, 80, 160, 219, 240, 62, 28, 222, 231, 107, 215, 247, 217, 217, 205, 232, 3, 176, 171, 201, 8, 116, 183, 117, 181, 238, 87, 144, 207, 4],
-		[125, 199, 15, 18, 105, 159, 199, 77, 251, 216, 127, 188, 225, 190, 113, 64, 10, 186, 24, 239, 211, 114, 198, 0, 156, 52, 114, 34, 129, 179, 153, 34],
-		[21, 62, 52, 249, 151, 91, 180, 30, 29, 201, 247, 0, 107, 22, 129, 24, 126, 229, 84, 21, 175, 114, 21, 185, 131, 234, 163, 228, 180, 9, 152, 23],
+		[212, 179, 155, 191, 111, 114, 183, 145, 160, 101, 203, 238, 72, 168, 7, 121, 36, 179, 222, 47, 16, 94, 124, 78, 149, 129, 10, 18, 191, 64, 33, 54],
+		[6, 64, 86, 24, 34, 226, 52, 81, 134, 67, 31, 122, 71, 6, 222, 218, 63, 125, 180, 22, 90, 48, 15, 105, 61, 15, 100, 200, 184, 226, 63, 165],
+		[234, 120, 178, 149, 112, 174, 40, 106, 188, 253, 200, 220, 23, 87, 84, 55, 180, 80, 40, 37, 19, 170, 189, 217, 100, 147, 180, 161, 99, 158, 136, 236],
+		[134, 125, 119, 22, 247, 195, 129, 129, 171, 119, 39, 237, 81, 78, 176, 209, 20, 52, 212, 179, 120, 1, 231, 136, 169, 177, 44, 62, 41, 5, 203, 98],
 	],
 	[
-		[123, 34, 235, 125, 177, 128, 155, 141, 120, 174, 245, 98, 255, 234, 40, 38, 240, 187, 92, 100, 7, 234, 219, 57, 176, 208, 151, 12, 133, 152, 29, 130],
-		[155, 99, 43, 50, 121, 43, 51, 35, 40, 133, 32, 204, 92, 143, 43, 233, 38, 150, 127, 78, 250, 178, 127, 39, 28, 2, 208, 180, 132, 74, 64, 93],
-		[141, 69, 206, 204, 107, 197, 10, 23, 169, 108, 103, 213, 107, 180, 95, 208, 24, 213, 55, 197, 54, 18, 31, 180, 149, 108, 88, 132, 143, 102, 177, 91],
-		[231, 45, 108, 23, 78, 237, 160, 154, 54, 36, 192, 253, 107, 12, 114, 135, 49, 203, 64, 242, 102, 185, 23, 76, 105, 92, 126, 174, 18, 160, 109, 18],
+		[193, 44, 163, 135, 15, 252, 120, 163, 66, 125, 120, 15, 210, 186, 9, 83, 113, 117, 219, 255, 54, 142, 194, 228, 211, 199, 30, 68, 183, 223, 221, 182],
+		[216, 41, 229, 10, 183, 83, 40, 194, 98, 33, 230, 102, 135, 133, 246, 144, 170, 199, 114, 162, 165, 163, 178, 145, 179, 239, 25, 147, 84, 99, 157, 28],
+		[14, 146, 107, 34, 164, 142, 184, 69, 76, 52, 232, 236, 54, 83, 81, 179, 82, 87, 32, 35, 18, 197, 161, 228, 94, 186, 127, 170, 216, 32, 128, 90],
+		[163, 50, 98, 16, 146, 27, 82, 214, 195, 56, 70, 176, 54, 186, 221, 29, 44, 129, 239, 94, 131, 117, 150, 52, 253, 40, 50, 158, 8, 210, 216, 110],
 	],
 	[
-		[108, 213, 10, 187, 200, 122, 141, 120, 7, 146, 35, 89, 131, 218, 34, 83, 144, 151, 115, 76, 62, 246, 72, 135, 6, 201, 180, 226, 171, 28, 159, 23],
-		[45, 135, 11, 217, 112, 15, 181, 112, 215, 66, 149, 215, 117, 155, 40, 188, 71, 21, 145, 1, 9, 158, 40, 1, 92, 232, 233, 114, 206, 34, 76, 54],
-		[230, 254, 158, 156, 8, 38, 166, 84, 70, 234, 43, 73, 73, 178, 167, 243, 125, 205, 213, 234, 95, 191, 66, 46, 77, 0, 153, 19, 114, 32, 149, 152],
-		[138, 79, 106, 236, 219, 200, 120, 157, 180, 43, 220, 140, 97, 216, 51, 164, 183, 228, 59, 119, 153, 161, 38, 53, 5, 86, 153, 29, 161, 197, 221, 52],
+		[38, 140, 48, 221, 240, 84, 16, 97, 127, 19, 171, 206, 138, 245, 243, 32, 92, 91, 252, 198, 0, 170, 204, 236, 149, 179, 245, 85, 46, 135, 39, 164],
+		[7, 50, 15, 179, 56, 39, 94, 92, 186, 100, 177, 229, 160, 210, 235, 71, 208, 63, 79, 214, 32, 8, 110, 54, 154, 218, 218, 236, 78, 235, 154, 111],
+		[92, 197, 14, 92, 194, 255, 64, 119, 139, 214, 6, 75, 185, 5, 98, 213, 14, 155, 128, 50, 102, 255, 230, 217, 87, 231, 217, 109, 38, 66, 129, 91],
+		[35, 165, 155, 172, 43, 150, 250, 253, 100, 65, 57, 6, 192, 144, 180, 14, 228, 24, 96, 54, 80, 149, 30, 60, 143, 145, 178, 170, 255, 120, 238, 112],
 	],
 	[
-		[157, 226, 237, 58, 152, 54, 113, 226, 28, 72, 233, 98, 173, 30, 157, 1, 214, 147, 190, 224, 119, 206, 163, 168, 64, 102, 228, 36, 219, 149, 13, 5],
-		[238, 234, 36, 43, 145, 206, 167, 182, 178, 57, 141, 232, 205, 101, 20, 34, 153, 243, 111, 71, 189, 118, 59, 16, 6, 171, 241, 77, 146, 88, 132, 107],
-		[123, 42, 249, 225, 244, 53, 151, 211, 196, 81, 188, 204, 147, 208, 248, 31, 100, 82, 144, 72, 106, 119, 40, 182, 50, 163, 83, 45, 132, 215, 61, 218],
-		[15, 187, 94, 253, 235, 26, 94, 86, 55, 255, 187, 1, 62, 49, 138, 222, 170, 134, 145, 209, 0, 10, 212, 102, 69, 155, 171, 24, 89, 156, 91, 92],
+		[138, 57, 40, 230, 75, 197, 229, 171, 30, 251, 14, 176, 98, 91, 103, 219, 39, 132, 0, 143, 117, 244, 81, 174, 189, 104, 205, 31, 68, 166, 40, 10],
+		[103, 147, 144, 223, 167, 226, 242, 9, 234, 248, 249, 197, 97, 244, 89, 151, 199, 210, 40, 195, 82, 78, 110, 189, 3, 62, 246, 185, 135, 236, 87, 217],
+		[135, 143, 185, 31, 248, 125, 202, 101, 24, 6, 120, 71, 98, 26, 28, 145, 138, 168, 35, 177, 104, 106, 133, 140, 51, 219, 172, 78, 169, 108, 249, 29],
+		[68, 221, 202, 146, 152, 88, 186, 30, 151, 55, 8, 2, 34, 39, 223, 180, 162, 93, 109, 3, 1, 110, 160, 48, 23, 111, 49, 160, 160, 13, 189, 186],
 	],
 	[
-		[182, 85, 25, 72, 184, 175, 138, 107, 246, 16, 9, 243, 118, 177, 151, 122, 159, 52, 51, 31, 73, 159, 138, 70, 18, 118, 25, 203, 250, 16, 41, 44],
-		[141, 180, 39, 205, 194, 59, 62, 118, 9, 43, 229, 49, 155, 241, 47, 198, 141, 7, 35, 131, 211, 24, 203, 57, 30, 130, 196, 41, 49, 174, 59, 142],
-		[116, 135, 56, 193, 98, 1, 215, 239, 59, 3, 252, 28, 161, 2, 18, 202, 27, 127, 171, 195, 214, 19, 4, 220, 155, 19, 67, 182, 87, 60, 0, 97],
-		[104, 148, 17, 192, 116, 91, 198, 5, 106, 42, 61, 88, 162, 142, 146, 108, 184, 162, 112, 249, 170, 196, 189, 115, 78, 61, 106, 69, 50, 38, 118, 167],
+		[77, 18, 147, 44, 195, 9, 51, 6, 110, 250, 83, 116, 76, 165, 37, 119, 162, 49, 143, 31, 32, 92, 196, 196, 20, 198, 71, 194, 151, 245, 45, 191],
+		[51, 189, 41, 192, 142, 148, 192, 103, 66, 253, 129, 119, 197, 119, 89, 158, 175, 84, 38, 176, 241, 112, 82, 0, 42, 65, 164, 114, 132, 32, 142, 241],
+		[123, 162, 208, 24, 169, 30, 93, 242, 43, 5, 226, 109, 228, 116, 165, 236, 223, 237, 14, 122, 235, 195, 104, 48, 131, 200, 123, 41, 179, 27, 27, 11],
+		[52, 50, 114, 103, 131, 78, 121, 117, 95, 100, 255, 135, 193, 17, 126, 240, 130, 163, 11, 86, 67, 202, 71, 75, 137, 74, 166, 21, 179, 117, 203, 183],
 	],
 	[
-		[70, 53, 134, 92, 86, 146, 232, 211, 173, 177, 193, 162, 6, 141, 216, 208, 78, 192, 34, 190, 103, 29, 60, 154, 139, 5, 216, 172, 56, 133, 138, 139],
-		[212, 90, 127, 24, 244, 135, 110, 232, 68, 84, 130, 75, 52, 135, 231, 88, 247, 98, 145, 228, 45, 109, 44, 30, 43, 44, 212, 252, 35, 52, 22, 46],
-		[103, 230, 94, 20, 210, 250, 249, 148, 95, 163, 125, 107, 232, 217, 6, 89, 68, 18, 0, 86, 25, 134, 20, 248, 44, 101, 82, 96, 91, 107, 150, 236],
-		[245, 77, 142, 67, 166, 144, 220, 151, 167, 54, 126, 45, 169, 84, 139, 106, 159, 249, 122, 20, 128, 19, 107, 70, 189, 188, 25, 126, 88, 0, 252, 69],
+		[121, 254, 89, 19, 190, 194, 100, 49, 107, 195, 237, 104, 242, 149, 101, 56, 158, 89, 130, 35, 129, 222, 156, 17, 139, 157, 17, 250, 66, 128, 201, 35],
+		[158, 180, 180, 208, 157, 190, 34, 181, 97, 223, 247, 98, 91, 108, 153, 200, 7, 215, 142, 160, 160, 40, 210, 43, 120, 191, 0, 2, 142, 255, 59, 111],
+		[190, 104, 222, 175, 165, 4, 86, 116, 56, 22, 19, 55, 60, 234, 169, 129, 207, 156, 0, 160, 74, 33, 145, 178, 236, 54, 65, 226, 8, 74, 8, 86],
+		[82, 94, 48, 11, 102, 154, 149, 171, 94, 8, 17, 55, 56, 136, 20, 148, 82, 73, 94, 58, 237, 121, 83, 94, 52, 172, 4, 167, 187, 245, 1, 155],
 	],
 	[
-		[100, 188, 228, 18, 105, 248, 6, 250, 239, 202, 12, 12, 63, 239, 82, 143, 209, 53, 15, 193, 219, 173, 242, 9, 211, 11, 247, 200, 149, 81, 178, 204],
-		[14, 13, 171, 169, 36, 19, 3, 61, 145, 111, 245, 233, 120, 171, 99, 81, 25, 22, 195, 92, 111, 216, 110, 107, 3, 114, 132, 116, 248, 53, 205, 151],
-		[8, 197, 22, 76, 97, 227, 118, 25, 44, 54, 72, 57, 225, 168, 180, 133, 46, 55, 23, 198, 107, 148, 134, 159, 117, 195, 190, 244, 196, 109, 62, 216],
-		[191, 86, 174, 103, 19, 123, 19, 1, 131, 208, 90, 199, 161, 18, 70, 75, 173, 92, 95, 29, 138, 244, 135, 151, 195, 116, 235, 129, 155, 188, 35, 145],
+		[182, 133, 123, 15, 22, 110, 77, 42, 240, 234, 33, 214, 160, 240, 176, 151, 197, 197, 22, 34, 66, 209, 90, 36, 30, 66, 85, 25, 33, 174, 190, 139],
+		[117, 104, 102, 193, 85, 216, 93, 143, 206, 179, 138, 101, 134, 218, 161, 84, 231, 74, 136, 62, 41, 241, 134, 34, 150, 134, 229, 253, 245, 193, 151, 233],
+		[78, 104, 198, 23, 185, 181, 147, 211, 121, 104, 79, 86, 12, 181, 51, 93, 64, 152, 65, 95, 253, 215, 250, 20, 60, 111, 212, 2, 54, 180, 120, 129],
+		[115, 32, 250, 216, 23, 238, 129, 80, 209, 19, 49, 65, 150, 91, 64, 43, 59, 188, 165, 201, 187, 191, 207, 14, 211, 95, 6, 85, 205, 32, 109, 148],
 	],
 	[
-		[196, 186, 21, 124, 72, 13, 15, 211, 208, 94, 96, 48, 66, 122, 1, 153, 208, 90, 84, 118, 86, 140, 87, 46, 3, 139, 222, 219, 135, 82, 88, 65],
-		[215, 235, 231, 168, 134, 237, 31, 13, 168, 31, 102, 5, 161, 168, 254, 202, 62, 250, 77, 139, 231, 214, 25, 188, 86, 229, 196, 186, 161, 247, 132, 178],
-		[178, 19, 161, 161, 119, 189, 204, 80, 238, 247, 18, 216, 77, 63, 45, 86, 214, 227, 237, 3, 87, 121, 154, 109, 56, 183, 21, 44, 12, 232, 74, 35],
-		[10, 144, 119, 46, 101, 25, 129, 117, 150, 81, 206, 218, 172, 53, 233, 226, 216, 9, 28, 73, 51, 160, 41, 74, 42, 35, 60, 255, 57, 128, 214, 226],
+		[184, 126, 2, 176, 40, 138, 165, 151, 231, 232, 222, 250, 187, 1, 110, 7, 23, 176, 0, 252, 13, 197, 88, 107, 87, 45, 181, 110, 89, 81, 126, 184],
+		[220, 144, 252, 152, 123, 161, 251, 111, 57, 80, 177, 10, 251, 79, 105, 13, 200, 38, 208, 146, 173, 10, 248, 151, 111, 151, 140, 140, 63, 47, 218, 197],
+		[63, 157, 97, 183, 145, 253, 217, 151, 186, 37, 135, 202, 25, 131, 51, 6, 127, 44, 89, 215, 37, 26, 212, 183, 108, 30, 113, 126, 202, 124, 226, 140],
+		[211, 79, 16, 140, 229, 214, 212, 140, 187, 5, 219, 237, 52, 232, 78, 164, 157, 140, 222, 157, 217, 95, 98, 135, 52, 79, 139, 9, 222, 226, 235, 131],
 	],
 	[
-		[157, 200, 161, 115, 192, 70, 28, 71, 84, 110, 200, 159, 64, 220, 103, 160, 24, 159, 100, 223, 244, 141, 84, 115, 69, 32, 195, 224, 115, 190, 118, 97],
-		[36, 123, 31, 100, 192, 11, 176, 110, 26, 49, 135, 236, 205, 193, 31, 31, 67, 187, 198, 151, 2, 58, 232, 42, 219, 228, 194, 71, 134, 149, 23, 231],
-		[244, 26, 2, 56, 137, 47, 241, 111, 126, 149, 52, 57, 85, 24, 242, 152, 209, 82, 86, 105, 103, 152, 145, 213, 186, 25, 62, 166, 204, 180, 122, 230],
-		[252, 64, 25, 141, 199, 181, 36, 20, 113, 89, 169, 22, 80, 251, 141, 43, 70, 113, 21, 245, 177, 75, 120, 96, 218, 66, 148, 12, 122, 220, 36, 199],
+		[83, 27, 5, 37, 204, 210, 23, 96, 51, 159, 62, 176, 52, 181, 37, 120, 223, 255, 252, 105, 114, 204, 232, 67, 202, 14, 63, 7, 113, 245, 38, 63],
+		[104, 22, 60, 198, 39, 224, 88, 127, 130, 203, 54, 146, 5, 230, 19, 182, 132, 37, 177, 97, 23, 95, 130, 25, 9, 91, 80, 79, 203, 199, 36, 148],
+		[249, 124, 109, 34, 121, 54, 251, 36, 121, 122, 235, 129, 11, 4, 97, 197, 167, 208, 5, 43, 220, 32, 63, 190, 120, 24, 220, 212, 96, 123, 56, 52],
+		[222, 105, 135, 100, 55, 17, 231, 112, 161, 66, 156, 13, 92, 186, 94, 200, 156, 120, 43, 243, 221, 84, 29, 46, 110, 28, 63, 39, 76, 233, 210, 193],
 	],
 	[
-		[120, 112, 236, 129, 33, 103, 136, 10, 31, 61, 215, 66, 209, 40, 145, 190, 139, 0, 57, 56, 208, 197, 37, 70, 5, 60, 138, 196, 125, 3, 235, 141],
-		[38, 191, 185, 52, 41, 21, 38, 222, 38, 125, 202, 193, 240, 55, 107, 96, 136, 136, 180, 199, 219, 164, 177, 45, 35, 240, 69, 159, 144, 32, 226, 190],
-		[129, 159, 238, 83, 235, 30, 225, 195, 46, 24, 183, 222, 51, 42, 140, 76, 225, 82, 133, 233, 201, 250, 92, 55, 14, 123, 240, 195, 46, 159, 160, 57],
-		[2, 45, 153, 158, 142, 56, 4, 101, 107, 166, 68, 124, 54, 190, 215, 213, 223, 208, 56, 197, 9, 130, 210, 148, 148, 22, 241, 137, 13, 36, 22, 183],
+		[21, 139, 152, 52, 112, 204, 173, 186, 130, 239, 173, 9, 229, 197, 131, 81, 231, 184, 167, 247, 183, 41, 85, 130, 141, 224, 235, 73, 37, 53, 213, 142],
+		[29, 123, 165, 246, 49, 39, 50, 221, 165, 79, 129, 172, 0, 70, 235, 216, 204, 44, 118, 34, 80, 93, 5, 163, 72, 5, 179, 85, 213, 234, 81, 52],
+		[31, 224, 5, 193, 104, 115, 8, 119, 41, 35, 0, 186, 128, 57, 57, 203, 138, 239, 83, 189, 241, 235, 7, 234, 117, 139, 16, 16, 206, 7, 111, 33],
+		[65, 197, 147, 181, 108, 57, 22, 5, 21, 219, 177, 137, 91, 180, 55, 67, 80, 115, 252, 108, 34, 14, 196, 139, 111, 201, 237, 66, 62, 2, 76, 180],
 	],
 	[
-		[119, 114, 53, 221, 54, 53, 190, 130, 129, 86, 232, 228, 181, 71, 8, 165, 12, 230, 162, 117, 130, 150, 214, 191, 133, 95, 160, 95, 159, 218, 128, 200],
-		[50, 126, 81, 39, 51, 46, 95, 243, 73, 166, 4, 61, 252, 242, 248, 29, 54, 158, 15, 128, 15, 111, 38, 232, 188, 191, 48, 148, 244, 200, 81, 187],
-		[46, 37, 21, 224, 110, 40, 214, 182, 106, 210, 46, 56, 127, 65, 232, 111, 205, 113, 178, 52, 148, 229, 86, 83, 76, 190, 33, 183, 190, 189, 178, 194],
-		[61, 127, 218, 230, 72, 255, 250, 155, 237, 228, 164, 140, 38, 90, 250, 130, 1, 194, 51, 216, 82, 110, 141, 33, 255, 129, 15, 227, 203, 40, 38, 99],
+		[25, 150, 193, 37, 60, 211, 181, 208, 231, 244, 241, 248, 207, 46, 15, 211, 244, 159, 143, 140, 6, 150, 215, 60, 94, 233, 159, 86, 83, 127, 168, 49],
+		[6, 232, 144, 77, 228, 82, 181, 54, 246, 113, 146, 105, 74, 148, 72, 167, 157, 163, 196, 81, 212, 9, 5, 73, 32, 141, 199, 189, 172, 20, 74, 221],
+		[33, 206, 207, 29, 135, 42, 11, 191, 33, 174, 90, 174, 167, 174, 171, 46, 228, 248, 192, 97, 22, 62, 83, 216, 41, 82, 57, 228, 79, 215, 202, 12],
+		[231, 76, 239, 170, 217, 180, 82, 14, 100, 45, 123, 12, 139, 156, 8, 156, 244, 229, 234, 200, 141, 164, 142, 114, 173, 70, 128, 113, 118, 29, 230, 40],
 	],
 	[
-		[97, 114, 42, 188, 144, 178, 247, 218, 193, 134, 196, 240, 226, 51, 196, 229, 169, 106, 54, 249, 118, 253, 217, 145, 141, 4, 6, 113, 214, 216, 220, 105],
-		[219, 46, 218, 145, 183, 58, 127, 59, 205, 20, 29, 107, 92, 62, 232, 178, 90, 179, 180, 247, 44, 151, 26, 13, 30, 183, 9, 154, 236, 146, 160, 3],
-		[253, 6, 190, 142, 15, 152, 245, 15, 3, 168, 146, 184, 178, 57, 197, 48, 199, 233, 40, 96, 123, 236, 147, 152, 31, 251, 17, 83, 231, 35, 185, 33],
-		[163, 184, 27, 204, 243, 6, 169, 143, 9, 78, 234, 6, 88, 194, 241, 44, 70, 102, 18, 117, 99, 95, 228, 222, 12, 14, 160, 176, 68, 107, 101, 109],
+		[20, 79, 234, 153, 56, 186, 90, 44, 179, 225, 126, 6, 132, 199, 85, 151, 163, 14, 55, 29, 234, 156, 99, 139, 86, 134, 249, 46, 204, 164, 71, 143],
+		[250, 115, 28, 0, 157, 116, 240, 130, 144, 203, 170, 190, 213, 22, 90, 18, 17, 182, 120, 75, 20, 152, 116, 217, 217, 161, 169, 220, 60, 9, 42, 37],
+		[33, 101, 181, 180, 62, 187, 204, 143, 16, 122, 80, 157, 15, 202, 160, 63, 151, 233, 13, 86, 202, 41, 1, 252, 197, 63, 123, 50, 103, 83, 193, 109],
+		[12, 247, 39, 197, 78, 182, 13, 133, 104, 199, 202, 253, 191, 131, 156, 31, 43, 114, 10, 118, 254, 209, 248, 149, 51, 45, 145, 6, 171, 152, 54, 239],
 	],
 	[
-		[33, 245, 186, 249, 115, 33, 188, 137, 249, 32, 66, 30, 168, 211, 56, 23, 128, 146, 234, 140, 251, 137, 127, 107, 24, 84, 230, 208, 199, 210, 6, 217],
-		[138, 230, 122, 121, 91, 91, 107, 118, 219, 136, 147, 145, 146, 81, 143, 5, 162, 232, 2, 208, 99, 131, 233, 46, 80, 54, 216, 118, 35, 239, 235, 186],
-		[214, 112, 130, 147, 30, 125, 235, 40, 63, 61, 255, 59, 188, 108, 57, 33, 160, 167, 191, 144, 137, 221, 155, 227, 54, 20, 197, 137, 85, 187, 0, 27],
-		[202, 199, 205, 203, 208, 102, 192, 254, 232, 231, 171, 218, 104, 22, 175, 146, 138, 205, 83, 158, 9, 197, 154, 175, 138, 221, 124, 109, 182, 232, 208, 232],
+		[60, 108, 234, 98, 205, 34, 31, 221, 53, 229, 89, 174, 31, 254, 31, 255, 168, 194, 85, 131, 232, 112, 217, 14, 208, 81, 94, 20, 17, 252, 41, 136],
+		[92, 178, 210, 170, 236, 144, 86, 151, 88, 229, 131, 76, 81, 177, 189, 114, 77, 253, 102, 55, 223, 60, 248, 47, 211, 146, 142, 126, 246, 202, 185, 78],
+		[3, 75, 177, 12, 79, 215, 55, 159, 206, 213, 31, 89, 164, 193, 182, 158, 24, 38, 67, 245, 104, 207, 202, 134, 209, 204, 176, 241, 253, 125, 24, 188],
+		[55, 216, 178, 26, 190, 120, 217, 3, 15, 42, 30, 13, 107, 219, 230, 188, 83, 124, 127, 138, 190, 185, 171, 198, 51, 44, 180, 63, 12, 54, 226, 0],
 	],
 	[
-		[220, 81, 28, 122, 142, 162, 86, 173, 88, 20, 25, 118, 25, 5, 58, 99, 101, 32, 62, 255, 34, 137, 171, 192, 31, 162, 199, 177, 121, 230, 233, 84],
-		[229, 155, 2, 131, 228, 184, 3, 184, 134, 187, 176, 94, 131, 60, 165, 16, 190, 250, 40, 99, 102, 3, 179, 153, 18, 82, 52, 74, 168, 65, 62, 167],
-		[155, 154, 30, 198, 88, 126, 157, 183, 185, 252, 129, 129, 46, 218, 160, 67, 132, 251, 225, 55, 207, 212, 155, 156, 8, 191, 153, 190, 233, 160, 74, 60],
-		[17, 118, 193, 157, 30, 87, 75, 191, 132, 195, 33, 53, 140, 148, 241, 101, 163, 38, 5, 248, 79, 36, 17, 186, 144, 196, 211, 93, 4, 212, 96, 39],
+		[188, 248, 47, 253, 60, 248, 81, 234, 53, 111, 69, 51, 125, 181, 66, 118, 236, 244, 158, 1, 90, 190, 32, 157, 30, 62, 68, 228, 168, 195, 142, 74],
+		[7, 122, 81, 145, 70, 89, 93, 167, 9, 139, 229, 212, 55, 155, 202, 253, 75, 151, 89, 96, 23, 83, 0, 198, 20, 48, 231, 170, 202, 80, 14, 83],
+		[99, 67, 231, 16, 171, 193, 145, 132, 74, 222, 248, 228, 224, 133, 233, 205, 183, 245, 227, 201, 6, 166, 209, 248, 205, 182, 89, 45, 26, 8, 108, 14],
+		[91, 154, 57, 238, 15, 15, 239, 7, 101, 175, 187, 46, 81, 65, 72, 174, 0, 102, 183, 224, 171, 236, 36, 173, 177, 139, 42, 216, 239, 59, 191, 205],
 	],
 	[
-		[188, 57, 119, 236, 192, 141, 230, 255, 226, 77, 184, 208, 38, 103, 150, 5, 215, 72, 156, 5, 149, 122, 203, 22, 167, 105, 60, 51, 193, 23, 85, 34],
-		[7, 115, 2, 109, 161, 133, 174, 109, 9, 5, 64, 150, 195, 150, 230, 243, 45, 123, 166, 107, 185, 89, 207, 12, 137, 14, 21, 109, 193, 217, 251, 147],
-		[122, 17, 225, 130, 113, 44, 42, 160, 212, 167, 193, 77, 165, 231, 41, 173, 162, 62, 68, 135, 2, 226, 154, 107, 56, 189, 247, 18, 180, 44, 183, 166],
-		[48, 237, 192, 166, 40, 1, 174, 84, 22, 5, 229, 194, 187, 113, 57, 153, 249, 128, 234, 143, 123, 254, 243, 225, 188, 182, 66, 170, 14, 145, 21, 153],
+		[137, 5, 175, 23, 103, 106, 250, 123, 88, 253, 69, 41, 67, 131, 123, 84, 146, 3, 27, 6, 66, 70, 83, 66, 238, 159, 70, 183, 218, 209, 245, 191],
+		[192, 174, 241, 185, 129, 34, 154, 95, 42, 77, 59, 73, 38, 20, 151, 212, 196, 114, 130, 47, 6, 79, 139, 49, 139, 191, 166, 114, 161, 246, 50, 232],
+		[239, 60, 10, 220, 166, 133, 185, 24, 63, 33, 137, 156, 187, 174, 127, 110, 80, 107, 189, 35, 248, 195, 137, 67, 38, 226, 104, 104, 168, 183, 73, 223],
+		[187, 66, 161, 51, 213, 67, 205, 191, 8, 181, 122, 15, 117, 141, 155, 182, 254, 5, 64, 74, 25, 10, 73, 124, 205, 247, 56, 73, 163, 112, 235, 30],
 	],
 	[
-		[189, 183, 180, 198, 50, 96, 79, 252, 175, 27, 167, 211, 252, 50, 139, 98, 124, 1, 137, 38, 79, 250, 212, 213, 154, 29, 52, 231, 148, 174, 35, 93],
-		[4, 85, 210, 186, 29, 114, 213, 213, 203, 181, 242, 60, 96, 236, 214, 67, 126, 54, 97, 75, 196, 101, 133, 198, 201, 196, 236, 46, 180, 184, 97, 66],
-		[234, 132, 247, 69, 58, 67, 17, 38, 114, 232, 158, 188, 0, 133, 105, 177, 103, 181, 148, 93, 4, 141, 9, 88, 241, 213, 127, 142, 182, 123, 131, 82],
-		[93, 46, 222, 245, 61, 111, 53, 49, 214, 117, 209, 233, 165, 244, 116, 155, 60, 249, 176, 249, 59, 216, 154, 141, 85, 188, 245, 116, 61, 203, 102, 29],
+		[194, 142, 181, 8, 156, 220, 0, 115, 226, 202, 182, 63, 35, 199, 82, 84, 255, 216, 172, 45, 163, 174, 131, 19, 189, 38, 135, 183, 103, 107, 38, 141],
+		[234, 182, 200, 60, 241, 126, 76, 186, 10, 10, 241, 164, 15, 103, 68, 93, 240, 158, 130, 68, 148, 201, 206, 143, 50, 39, 188, 220, 41, 114, 5, 139],
+		[195, 236, 18, 70, 80, 213, 63, 28, 249, 132, 25, 127, 16, 199, 154, 28, 163, 29, 200, 125, 238, 171, 231, 30, 122, 129, 49, 162, 123, 73, 57, 107],
+		[176, 196, 50, 126, 238, 111, 172, 71, 182, 239, 171, 59, 54, 189, 76, 116, 29, 101, 33, 69, 246, 177, 167, 55, 221, 116, 144, 244, 245, 1, 179, 69],
 	],
 	[
-		[221, 159, 41, 13, 171, 225, 207, 53, 194, 23, 227, 3, 30, 88, 155, 105, 253, 146, 49, 54, 151, 81, 178, 205, 153, 79, 130, 58, 234, 42, 220, 197],
-		[12, 252, 36, 130, 126, 242, 183, 134, 138, 209, 182, 40, 206, 154, 28, 222, 216, 119, 0, 252, 38, 158, 55, 71, 64, 9, 84, 217, 148, 233, 135, 72],
-		[181, 160, 3, 199, 60, 163, 129, 141, 210, 161, 187, 227, 141, 167, 27, 50, 29, 200, 139, 177, 113, 192, 77, 246, 71, 35, 201, 217, 187, 107, 14, 54],
-		[26, 16, 158, 188, 8, 151, 126, 213, 155, 55, 1, 142, 48, 38, 255, 160, 192, 238, 115, 28, 48, 185, 249, 195, 175, 43, 56, 58, 92, 134, 192, 17],
+		[135, 84, 2, 103, 136, 119, 120, 65, 131, 180, 57, 251, 49, 230, 171, 212, 130, 42, 141, 165, 243, 26, 231, 154, 10, 94, 125, 73, 41, 182, 12, 109],
+		[7, 162, 227, 140, 119, 164, 248, 67, 176, 18, 189, 241, 114, 92, 96, 152, 22, 200, 165, 175, 85, 115, 164, 4, 48, 11, 37, 110, 206, 142, 146, 0],
+		[139, 65, 150, 214, 138, 27, 85, 149, 210, 62, 48, 142, 31, 229, 52, 16, 88, 164, 244, 163, 200, 241, 52, 95, 244, 6, 239, 44, 108, 161, 15, 150],
+		[54, 206, 64, 245, 216, 137, 83, 85, 242, 87, 39, 1, 89, 20, 124, 154, 172, 213, 183, 209, 162, 114, 226, 147, 220, 72, 51, 196, 55, 130, 172, 143],
 	],
 	[
-		[240, 21, 32, 132, 73, 29, 14, 169, 60, 1, 205, 120, 143, 99, 215, 240, 166, 202, 82, 194, 226, 68, 38, 192, 117, 7, 189, 111, 54, 174, 105, 99],
-		[157, 155, 110, 177, 185, 54, 219, 8, 23, 7, 230, 105, 229, 20, 195, 201, 170, 6, 251, 55, 172, 196, 14, 249, 35, 220, 75, 15, 188, 88, 211, 93],
-		[233, 142, 186, 185, 223, 176, 137, 221, 56, 39, 157, 226, 111, 252, 201, 156, 237, 130, 126, 136, 62, 96, 109, 83, 224, 233, 210, 230, 139, 109, 207, 94],
-		[129, 28, 37, 235, 75, 192, 21, 37, 233, 157, 239, 62, 61, 55, 253, 102, 199, 193, 45, 70, 138, 169, 214, 219, 82, 222, 238, 27, 135, 56, 250, 204],
+		[55, 255, 143, 153, 160, 169, 90, 3, 80, 110, 225, 135, 66, 204, 183, 230, 170, 132, 38, 147, 72, 253, 1, 58, 64, 158, 73, 42, 253, 248, 125, 112],
+		[121, 162, 54, 188, 235, 249, 150, 10, 37, 128, 81, 89, 177, 130, 122, 23, 37, 222, 126, 92, 159, 198, 174, 68, 1, 215, 193, 189, 230, 126, 150, 97],
+		[42, 179, 160, 46, 26, 163, 212, 186, 69, 240, 5, 254, 108, 120, 182, 168, 233, 252, 161, 107, 93, 195, 36, 81, 125, 37, 114, 237, 4, 220, 220, 189],
+		[211, 122, 127, 189, 44, 243, 218, 121, 188, 130, 14, 146, 15, 123, 147, 118, 54, 219, 177, 110, 189, 145, 8, 191, 197, 165, 33, 152, 55, 7, 94, 167],
 	],
 	[
-		[244, 159, 224, 111, 17, 170, 226, 85, 46, 153, 246, 119, 59, 120, 23, 136, 69, 192, 36, 87, 64, 44, 36, 45, 25, 94, 32, 119, 242, 110, 77, 160],
-		[198, 149, 6, 106, 48, 0, 136, 167, 14, 147, 72, 151, 11, 77, 85, 224, 88, 145, 215, 187, 181, 135, 151, 77, 161, 57, 45, 80, 76, 152, 31, 89],
-		[201, 155, 77, 29, 248, 33, 86, 214, 210, 64, 86, 75, 63, 36, 151, 12, 108, 194, 31, 225, 236, 98, 193, 175, 134, 246, 51, 241, 102, 53, 23, 235],
-		[224, 57, 54, 205, 147, 238, 209, 27, 133, 37, 15, 5, 235, 105, 251, 187, 189, 198, 178, 18, 84, 191, 95, 193, 68, 215, 179, 158, 65, 19, 96, 215],
+		[15, 171, 109, 152, 193, 156, 138, 173, 32, 81, 71, 204, 131, 28, 27, 249, 137, 50, 157, 17, 67, 181, 134, 241, 102, 217, 54, 60, 127, 206, 158, 60],
+		[223, 247, 95, 175, 190, 154, 54, 209, 52, 199, 124, 224, 66, 203, 71, 17, 4, 223, 37, 196, 170, 135, 185, 31, 211, 94, 243, 152, 56, 53, 112, 31],
+		[172, 241, 185, 123, 64, 84, 151, 64, 123, 3, 169, 70, 134, 102, 130, 174, 214, 47, 203, 235, 69, 1, 110, 106, 246, 30, 155, 56, 94, 99, 195, 42],
+		[244, 234, 103, 151, 173, 93, 41, 2, 47, 116, 86, 185, 86, 83, 158, 223, 201, 89, 232, 100, 161, 126, 110, 60, 38, 107, 49, 212, 62, 27, 93, 111],
 	],
 	[
-		[121, 86, 108, 215, 118, 80, 63, 7, 149, 86, 92, 11, 138, 224, 200, 141, 180, 10, 6, 39, 136, 167, 67, 23, 11, 189, 202, 124, 134, 126, 141, 29],
-		[11, 169, 244, 198, 143, 62, 240, 51, 68, 62, 77, 109, 132, 228, 173, 82, 148, 58, 135, 177, 2, 45, 98, 201, 160, 172, 38, 144, 116, 46, 117, 186],
-		[92, 29, 122, 235, 249, 25, 9, 109, 185, 45, 246, 163, 221, 241, 187, 192, 199, 145, 147, 47, 115, 177, 188, 167, 166, 21, 85, 153, 196, 2, 173, 208],
-		[21, 33, 15, 234, 92, 189, 201, 140, 131, 179, 142, 248, 119, 254, 39, 162, 189, 169, 193, 12, 158, 250, 136, 72, 145, 128, 90, 113, 152, 182, 139, 166],
+		[241, 79, 41, 52, 250, 245, 142, 123, 251, 185, 104, 97, 153, 17, 16, 163, 177, 12, 227, 230, 254, 5, 217, 32, 96, 174, 181, 139, 231, 58, 82, 101],
+		[110, 148, 246, 64, 191, 132, 53, 206, 105, 75, 138, 116, 27, 246, 124, 84, 4, 58, 37, 106, 141, 178, 14, 222, 64, 162, 159, 55, 36, 223, 12, 81],
+		[141, 145, 86, 211, 82, 182, 108, 58, 196, 220, 176, 246, 243, 218, 79, 231, 154, 72, 18, 145, 56, 196, 157, 53, 160, 124, 31, 70, 101, 49, 12, 30],
+		[95, 16, 241, 26, 87, 154, 71, 191, 219, 42, 56, 226, 9, 27, 171, 10, 92, 85, 150, 168, 116, 199, 222, 169, 106, 132, 78, 162, 222, 235, 70, 102],
 	],
 	[
-		[111, 21, 26, 255, 14, 117, 71, 4, 2, 194, 247, 233, 97, 114, 178, 215, 43, 129, 231, 159, 28, 93, 70, 31, 160, 233, 244, 98, 48, 166, 121, 57],
-		[7, 95, 64, 15, 173, 248, 4, 244, 91, 200, 98, 252, 105, 221, 223, 17, 179, 125, 3, 69, 224, 21, 222, 142, 169, 56, 17, 63, 118, 60, 226, 211],
-		[146, 111, 23, 225, 185, 3, 111, 151, 205, 210, 32, 226, 100, 226, 142, 102, 164, 79, 132, 241, 89, 194, 32, 25, 75, 213, 195, 164, 254, 26, 233, 180],
-		[150, 0, 229, 176, 18, 61, 44, 33, 111, 234, 190, 233, 146, 104, 167, 34, 64, 24, 139, 88, 162, 122, 19, 54, 187, 188, 217, 143, 68, 48, 253, 164],
+		[212, 37, 20, 79, 207, 181, 148, 221, 72, 66, 203, 189, 246, 41, 88, 104, 156, 119, 237, 4, 234, 128, 216, 7, 255, 42, 178, 193, 156, 146, 57, 48],
+		[237, 111, 15, 38, 59, 184, 140, 68, 103, 249, 206, 4, 87, 18, 93, 95, 134, 31, 220, 20, 113, 221, 214, 225, 64, 11, 88, 76, 73, 47, 139, 109],
+		[35, 172, 36, 40, 84, 53, 93, 10, 217, 142, 60, 50, 149, 151, 86, 79, 130, 7, 83, 159, 226, 8, 0, 151, 13, 251, 215, 168, 112, 24, 120, 44],
+		[51, 224, 162, 92, 225, 74, 87, 121, 77, 164, 191, 17, 209, 72, 131, 192, 92, 54, 244, 53, 15, 184, 124, 124, 86, 220, 158, 144, 45, 12, 169, 99],
 	],
 	[
-		[239, 66, 125, 60, 131, 200, 44, 51, 58, 123, 241, 141, 118, 26, 169, 11, 92, 88, 87, 21, 194, 46, 11, 202, 24, 50, 119, 244, 250, 113, 152, 22],
-		[159, 102, 32, 166, 33, 128, 82, 0, 48, 121, 0, 124, 105, 158, 109, 157, 128, 90, 180, 47, 70, 79, 147, 64, 82, 16, 227, 229, 81, 119, 44, 226],
-		[160, 45, 242, 246, 124, 83, 144, 13, 61, 116, 133, 83, 91, 21, 102, 212, 61, 245, 12, 141, 139, 55, 129, 144, 0, 217, 193, 174, 14, 228, 223, 77],
-		[118, 105, 85, 227, 213, 150, 6, 151, 87, 213, 163, 45, 251, 37, 6, 215, 3, 121, 9, 61, 226, 144, 147, 157, 87, 214, 22, 69, 24, 115, 226, 162],
+		[1, 193, 1, 102, 31, 25, 20, 151, 34, 90, 11, 1, 27, 122, 193, 241, 234, 142, 67, 238, 154, 165, 225, 159, 255, 52, 223, 211, 35, 10, 118, 224],
+		[232, 93, 54, 51, 233, 191, 239, 93, 210, 113, 215, 26, 94, 10, 197, 211, 178, 43, 190, 92, 74, 225, 116, 251, 53, 34, 209, 1, 4, 22, 30, 160],
+		[152, 46, 54, 20, 42, 91, 214, 110, 239, 140, 81, 35, 60, 239, 15, 200, 249, 199, 133, 135, 241, 116, 39, 229, 118, 192, 75, 165, 101, 230, 97, 76],
+		[181, 140, 212, 170, 32, 148, 175, 0, 238, 162, 187, 90, 186, 223, 99, 121, 155, 20, 64, 162, 14, 77, 211, 190, 184, 183, 207, 21, 74, 211, 31, 174],
 	],
 	[
-		[120, 102, 147, 43, 155, 103, 149, 26, 122, 175, 239, 56, 181, 92, 209, 255, 123, 130, 63, 224, 123, 54, 24, 58, 220, 212, 225, 193, 82, 18, 7, 222],
-		[223, 87, 114, 209, 11, 106, 16, 26, 145, 153, 16, 114, 249, 132, 8, 152, 161, 179, 137, 177, 93, 18, 76, 114, 107, 108, 178, 167, 210, 227, 145, 230],
-		[186, 211, 6, 2, 35, 22, 105, 180, 224, 206, 35, 68, 66, 80, 14, 230, 160, 70, 128, 44, 182, 251, 5, 229, 157, 122, 255, 254, 83, 108, 207, 192],
-		[252, 76, 13, 253, 144, 23, 19, 29, 216, 254, 250, 55, 189, 179, 182, 101, 201, 238, 88, 39, 173, 106, 30, 53, 36, 181, 195, 134, 228, 161, 137, 39],
+		[52, 97, 22, 187, 176, 237, 57, 67, 66, 29, 67, 200, 198, 32, 224, 234, 86, 13, 133, 23, 80, 232, 189, 72, 133, 13, 169, 241, 230, 89, 79, 160],
+		[13, 107, 69, 148, 107, 104, 241, 255, 206, 251, 220, 103, 145, 129, 147, 108, 0, 179, 165, 150, 90, 176, 97, 133, 20, 170, 245, 55, 56, 129, 16, 149],
+		[58, 138, 142, 94, 179, 101, 101, 8, 178, 255, 2, 110, 157, 181, 87, 94, 226, 232, 136, 17, 95, 79, 92, 143, 183, 203, 194, 136, 220, 61, 65, 33],
+		[188, 68, 98, 248, 27, 203, 44, 42, 141, 184, 135, 26, 167, 113, 35, 83, 143, 168, 13, 179, 172, 143, 226, 141, 231, 31, 149, 131, 141, 7, 154, 89],
 	],
 	[
-		[234, 27, 74, 30, 131, 119, 221, 44, 93, 152, 20, 107, 42, 159, 66, 204, 119, 175, 43, 22, 174, 1, 33, 213, 246, 185, 89, 173, 247, 160, 245, 69],
-		[61, 154, 92, 184, 97, 53, 206, 215, 35, 22, 235, 37, 161, 58, 212, 60, 205, 175, 73, 233, 48, 30, 106, 28, 218, 136, 153, 114, 64, 183, 110, 82],
-		[66, 160, 118, 38, 234, 140, 53, 136, 54, 162, 106, 99, 24, 119, 83, 195, 225, 144, 28, 152, 209, 213, 8, 160, 142, 36, 124, 81, 191, 181, 93, 242],
-		[235, 125, 7, 158, 12, 179, 119, 125, 26, 77, 72, 232, 128, 4, 148, 32, 39, 219, 225, 158, 163, 77, 23, 118, 50, 60, 212, 193, 137, 161, 241, 25],
+		[62, 192, 133, 127, 213, 46, 101, 209, 185, 215, 118, 67, 138, 154, 123, 120, 166, 37, 35, 210, 171, 133, 79, 24, 130, 74, 248, 15, 250, 155, 246, 41],
+		[176, 230, 81, 205, 107, 33, 73, 34, 240, 61, 65, 63, 178, 109, 179, 108, 247, 231, 120, 176, 184, 5, 28, 20, 44, 220, 86, 219, 100, 227, 133, 162],
+		[144, 197, 213, 18, 47, 99, 136, 2, 121, 175, 188, 187, 60, 199, 229, 209, 170, 161, 131, 193, 33, 178, 84, 42, 32, 107, 171, 220, 230, 79, 252, 110],
+		[156, 76, 137, 180, 155, 135, 51, 38, 166, 44, 165, 115, 122, 97, 145, 103, 155, 74, 87, 65, 183, 149, 88, 40, 147, 77, 224, 61, 183, 44, 63, 26],
 	],
 	[
-		[162, 155, 25, 136, 127, 252, 194, 174, 226, 120, 168, 140, 107, 80, 246, 233, 135, 185, 191, 207, 240, 100, 51, 162, 143, 70, 0, 216, 26, 204, 31, 71],
-		[32, 54, 88, 4, 243, 102, 254, 248, 124, 72, 182, 17, 113, 134, 57, 83, 159, 153, 203, 219, 229, 198, 229, 252, 145, 255, 53, 10, 241, 233, 42, 188],
-		[251, 152, 207, 150, 103, 78, 72, 100, 227, 240, 129, 15, 172, 95, 40, 53, 155, 50, 43, 253, 81, 123, 231, 9, 130, 35, 148, 162, 58, 177, 75, 166],
-		[99, 26, 175, 187, 41, 10, 58, 104, 223, 227, 115, 57, 50, 253, 189, 255, 32, 138, 148, 95, 105, 28, 238, 148, 211, 57, 112, 38, 158, 174, 5, 158],
+		[190, 26, 103, 83, 193, 69, 202, 246, 56, 46, 194, 38, 222, 183, 31, 57, 75, 83, 93, 197, 255, 230, 226, 189, 50, 71, 65, 177, 185, 49, 194, 134],
+		[25, 110, 20, 159, 101, 212, 39, 201, 255, 150, 114, 226, 230, 63, 146, 96, 144, 235, 42, 12, 169, 111, 152, 207, 68, 57, 246, 245, 251, 243, 22, 85],
+		[202, 99, 38, 217, 194, 185, 188, 34, 254, 44, 187, 7, 120, 160, 137, 255, 186, 126, 66, 150, 129, 24, 123, 129, 152, 190, 127, 29, 137, 161, 223, 169],
+		[36, 200, 107, 150, 132, 190, 184, 228, 118, 187, 168, 8, 100, 127, 139, 161, 74, 84, 101, 84, 214, 74, 140, 15, 101, 146, 254, 32, 73, 24, 44, 15],
 	],
 	[
-		[2, 220, 56, 93, 98, 114, 85, 174, 209, 6, 170, 128, 201, 208, 189, 53, 180, 158, 161, 253, 168, 101, 119, 36, 109, 36, 74, 145, 135, 154, 249, 206],
-		[7, 185, 245, 44, 200, 128, 13, 165, 93, 239, 12, 171, 6, 79, 141, 144, 148, 71, 77, 41, 23, 122, 235, 223, 9, 0, 150, 11, 194, 155, 227, 228],
-		[192, 234, 239, 195, 250, 122, 115, 232, 1, 95, 253, 51, 217, 162, 151, 72, 13, 27, 208, 250, 154, 53, 152, 108, 101, 42, 178, 196, 70, 188, 84, 72],
-		[231, 19, 118, 224, 245, 245, 17, 42, 107, 192, 236, 80, 17, 143, 86, 185, 6, 80, 86, 217, 231, 240, 204, 246, 146, 163, 178, 33, 40, 120, 118, 149],
+		[221, 188, 40, 39, 72, 224, 25, 50, 237, 158, 95, 9, 39, 50, 47, 143, 234, 168, 97, 157, 246, 60, 55, 108, 87, 22, 253, 188, 195, 186, 170, 115],
+		[217, 135, 206, 103, 235, 68, 134, 93, 110, 254, 172, 35, 203, 109, 100, 42, 54, 66, 103, 50, 58, 169, 37, 244, 204, 203, 53, 87, 142, 224, 201, 91],
+		[48, 130, 228, 228, 248, 236, 210, 57, 158, 123, 16, 45, 253, 237, 221, 122, 6, 46, 32, 197, 35, 81, 183, 165, 141, 87, 14, 94, 249, 230, 72, 55],
+		[29, 48, 18, 70, 198, 153, 166, 142, 170, 147, 218, 130, 62, 48, 245, 39, 189, 225, 65, 81, 230, 137, 185, 95, 132, 24, 217, 60, 246, 99, 242, 210],
 	],
 	[
-		[66, 102, 90, 182, 13, 25, 19, 101, 245, 136, 99, 47, 12, 115, 242, 34, 25, 75, 117, 212, 90, 127, 42, 52, 147, 48, 50, 41, 192, 205, 193, 109],
-		[87, 135, 225, 147, 162, 157, 26, 203, 83, 249, 70, 49, 21, 186, 184, 141, 222, 60, 219, 88, 130, 226, 103, 176, 105, 244, 9, 193, 42, 153, 73, 73],
-		[127, 23, 186, 31, 246, 33, 143, 213, 231, 206, 6, 66, 204, 135, 239, 32, 48, 34, 156, 98, 129, 231, 156, 26, 62, 140, 37, 113, 88, 199, 137, 2],
-		[15, 17, 94, 19, 82, 45, 108, 27, 92, 32, 232, 65, 157, 234, 50, 157, 192, 183, 210, 125, 55, 161, 16, 233, 182, 153, 140, 31, 145, 101, 189, 176],
+		[81, 123, 115, 80, 42, 113, 93, 35, 123, 243, 207, 213, 161, 246, 27, 35, 58, 77, 181, 177, 140, 99, 207, 94, 194, 114, 72, 149, 85, 181, 23, 138],
+		[91, 95, 241, 223, 190, 169, 182, 198, 194, 160, 24, 0, 187, 169, 255, 38, 53, 103, 156, 7, 170, 242, 152, 112, 236, 7, 63, 175, 51, 163, 35, 128],
+		[144, 118, 136, 128, 47, 18, 60, 138, 67, 32, 198, 168, 246, 157, 134, 241, 119, 157, 6, 26, 15, 193, 132, 16, 249, 126, 242, 29, 84, 75, 150, 148],
+		[233, 245, 40, 201, 199, 28, 120, 32, 139, 109, 165, 106, 10, 153, 47, 208, 45, 97, 144, 46, 189, 175, 170, 8, 253, 241, 49, 229, 123, 152, 210, 101],
 	],
 	[
-		[48, 29, 118, 101, 162, 165, 162, 15, 157, 226, 144, 76, 44, 39, 39, 173, 146, 91, 175, 25, 194, 233, 125, 69, 219, 158, 107, 31, 180, 239, 123, 155],
-		[113, 9, 73, 149, 106, 98, 250, 210, 185, 164, 30, 103, 218, 218, 187, 77, 5, 146, 90, 234, 187, 162, 99, 236, 203, 170, 16, 47, 103, 200, 108, 215],
-		[151, 124, 36, 92, 133, 93, 1, 14, 246, 251, 251, 104, 79, 224, 252, 135, 218, 125, 80, 145, 234, 98, 25, 102, 241, 167, 10, 3, 193, 5, 206, 97],
-		[143, 67, 52, 14, 119, 152, 174, 94, 244, 126, 68, 187, 162, 141, 165, 64, 96, 69, 232, 7, 243, 156, 221, 77, 82, 37, 26, 51, 24, 230, 49, 100],
+		[230, 255, 62, 15, 186, 82, 4, 119, 58, 16, 39, 7, 236, 18, 79, 102, 78, 165, 250, 29, 116, 41, 18, 94, 177, 82, 137, 63, 160, 18, 180, 167],
+		[16, 84, 139, 192, 118, 100, 220, 10, 54, 234, 54, 54, 247, 25, 89, 219, 47, 7, 80, 90, 195, 130, 148, 243, 67, 139, 91, 88, 29, 56, 171, 173],
+		[196, 166, 204, 40, 122, 209, 100, 221, 177, 225, 187, 149, 208, 161, 35, 143, 74, 58, 24, 70, 41, 14, 66, 28, 77, 67, 121, 135, 162, 136, 84, 223],
+		[204, 205, 138, 13, 131, 79, 240, 195, 161, 237, 230, 53, 192, 57, 166, 69, 9, 59, 25, 208, 155, 154, 219, 2, 55, 173, 102, 92, 232, 229, 83, 3],
 	],
 	[
-		[9, 113, 233, 245, 24, 22, 79, 70, 145, 34, 38, 11, 124, 37, 182, 49, 232, 33, 90, 125, 235, 106, 66, 70, 114, 59, 216, 46, 187, 142, 61, 154],
-		[67, 4, 25, 38, 77, 71, 150, 210, 121, 56, 226, 102, 67, 13, 105, 170, 159, 129, 193, 173, 51, 252, 80, 150, 202, 218, 13, 202, 154, 43, 129, 46],
-		[214, 131, 210, 80, 136, 32, 152, 128, 166, 91, 255, 207, 54, 7, 161, 193, 215, 64, 165, 200, 183, 227, 78, 61, 166, 224, 177, 51, 119, 106, 174, 29],
-		[72, 192, 41, 224, 93, 109, 139, 2, 76, 47, 17, 120, 16, 185, 15, 164, 31, 66, 242, 161, 229, 132, 131, 109, 171, 12, 65, 189, 112, 82, 89, 70],
+		[120, 138, 152, 240, 118, 93, 110, 172, 217, 192, 138, 197, 215, 171, 112, 105, 38, 191, 255, 71, 93, 53, 5, 80, 158, 141, 75, 208, 35, 38, 200, 65],
+		[62, 123, 118, 131, 109, 229, 166, 22, 82, 229, 103, 116, 63, 219, 143, 225, 23, 21, 64, 191, 75, 147, 205, 125, 46, 148, 102, 240, 253, 157, 8, 221],
+		[56, 98, 72, 144, 82, 129, 198, 105, 52, 115, 4, 230, 0, 250, 196, 116, 114, 16, 190, 162, 132, 137, 47, 178, 218, 169, 109, 147, 162, 104, 140, 17],
+		[246, 102, 99, 89, 78, 207, 139, 40, 118, 55, 45, 178, 105, 146, 85, 32, 171, 154, 144, 12, 192, 181, 219, 219, 152, 139, 40, 120, 221, 148, 216, 82],
 	],
 	[
-		[115, 246, 57, 24, 175, 223, 22, 234, 37, 43, 73, 50, 77, 142, 246, 52, 112, 116, 2, 250, 186, 225, 123, 81, 136, 87, 116, 157, 177, 205, 176, 94],
-		[171, 203, 232, 152, 236, 194, 174, 62, 137, 154, 227, 115, 42, 70, 40, 169, 37, 69, 5, 231, 16, 179, 167, 243, 67, 205, 146, 119, 146, 203, 245, 103],
-		[217, 88, 232, 33, 96, 99, 190, 85, 43, 49, 45, 93, 47, 240, 195, 124, 145, 110, 133, 82, 201, 147, 242, 229, 181, 132, 36, 199, 136, 17, 107, 57],
-		[228, 79, 229, 252, 51, 172, 176, 84, 106, 190, 137, 240, 94, 245, 7, 47, 6, 190, 14, 189, 18, 57, 220, 72, 206, 141, 71, 178, 14, 211, 159, 88],
+		[219, 113, 116, 211, 254, 47, 189, 4, 200, 66, 195, 87, 246, 30, 17, 192, 186, 106, 217, 48, 120, 98, 50, 49, 140, 246, 102, 17, 128, 71, 80, 53],
+		[238, 201, 28, 201, 243, 16, 99, 215, 205, 220, 70, 223, 10, 249, 7, 57, 48, 111, 70, 34, 91, 16, 247, 38, 118, 89, 39, 136, 234, 169, 11, 170],
+		[142, 19, 254, 180, 248, 169, 25, 40, 177, 198, 235, 25, 239, 139, 190, 176, 158, 235, 219, 162, 159, 249, 21, 219, 237, 130, 82, 145, 107, 3, 137, 213],
+		[221, 213, 32, 105, 85, 92, 210, 118, 202, 82, 195, 149, 107, 10, 136, 242, 57, 34, 125, 196, 22, 196, 36, 149, 125, 6, 141, 93, 213, 222, 166, 220],
 	],
 	[
-		[206, 242, 121, 38, 72, 177, 86, 43, 92, 163, 132, 197, 117, 199, 5, 72, 222, 8, 149, 231, 189, 94, 236, 126, 5, 128, 141, 135, 179, 45, 237, 92],
-		[44, 160, 207, 51, 252, 10, 119, 233, 98, 251, 163, 3, 145, 133, 39, 54, 134, 163, 119, 135, 187, 40, 227, 19, 46, 99, 29, 25, 119, 66, 113, 99],
-		[248, 73, 169, 148, 223, 200, 79, 244, 173, 140, 142, 173, 107, 155, 200, 107, 181, 121, 186, 111, 12, 79, 182, 231, 70, 167, 229, 67, 77, 59, 175, 92],
-		[78, 254, 248, 89, 10, 187, 50, 158, 170, 151, 188, 107, 123, 237, 220, 223, 131, 188, 164, 39, 250, 90, 143, 22, 241, 58, 116, 207, 227, 132, 251, 84],
+		[218, 195, 49, 253, 130, 131, 79, 198, 241, 131, 188, 3, 72, 128, 6, 225, 24, 181, 74, 113, 245, 13, 41, 129, 67, 233, 45, 38, 227, 229, 173, 183],
+		[147, 120, 1, 227, 201, 133, 10, 69, 152, 170, 99, 90, 203, 27, 48, 174, 252, 118, 13, 244, 108, 114, 218, 149, 64, 125, 104, 172, 195, 151, 70, 176],
+		[97, 215, 109, 176, 26, 92, 142, 176, 144, 111, 251, 80, 253, 127, 209, 166, 117, 76, 162, 130, 4, 97, 187, 1, 27, 21, 43, 175, 42, 119, 48, 163],
+		[162, 127, 136, 123, 228, 54, 28, 228, 151, 121, 124, 116, 164, 67, 0, 14, 93, 24, 188, 49, 101, 49, 184, 40, 231, 171, 30, 187, 48, 114, 251, 109],
 	],
 	[
-		[26, 229, 176, 186, 11, 248, 96, 222, 62, 82, 104, 105, 69, 56, 144, 179, 3, 220, 34, 247, 19, 143, 200, 14, 92, 205, 172, 98, 215, 1, 224, 26],
-		[131, 53, 110, 104, 60, 150, 20, 254, 161, 192, 5, 202, 191, 202, 64, 161, 94, 46, 117, 3, 174, 167, 176, 134, 253, 127, 166, 206, 166, 159, 23, 86],
-		[230, 174, 246, 45, 37, 18, 234, 56, 170, 100, 192, 36, 163, 40, 114, 95, 2, 69, 208, 176, 1, 37, 84, 177, 248, 72, 208, 189, 236, 252, 211, 229],
-		[95, 36, 97, 27, 122, 114, 105, 153, 149, 28, 153, 80, 130, 75, 186, 90, 173, 74, 218, 115, 167, 9, 153, 72, 71, 174, 4, 225, 235, 37, 171, 238],
+		[43, 163, 24, 247, 2, 74, 215, 129, 81, 157, 213, 197, 225, 130, 139, 156, 170, 53, 39, 236, 78, 184, 128, 233, 83, 211, 172, 138, 92, 207, 238, 226],
+		[75, 176, 59, 67, 204, 232, 61, 168, 137, 105, 74, 189, 206, 150, 253, 250, 109, 189, 82, 140, 65, 197, 253, 179, 243, 39, 80, 35, 162, 191, 239, 79],
+		[170, 192, 12, 157, 22, 177, 163, 154, 183, 130, 214, 79, 138, 188, 106, 171, 242, 203, 205, 17, 0, 189, 196, 233, 143, 13, 147, 124, 246, 107, 133, 153],
+		[1, 223, 190, 193, 86, 215, 24, 21, 232, 83, 52, 91, 157, 211, 115, 82, 3, 143, 241, 23, 244, 111, 115, 215, 14, 120, 40, 173, 113, 201, 28, 152],
 	],
 	[
-		[27, 216, 88, 44, 210, 102, 135, 220, 47, 114, 92, 75, 154, 170, 95, 145, 118, 122, 112, 45, 198, 91, 74, 161, 81, 63, 169, 20, 2, 192, 137, 91],
-		[31, 51, 239, 124, 175, 108, 147, 95, 242, 51, 164, 62, 100, 128, 146, 211, 6, 151, 146, 175, 190, 231, 49, 223, 136, 75, 232, 174, 164, 19, 163, 208],
-		[16, 14, 194, 6, 82, 244, 27, 83, 206, 142, 189, 235, 234, 135, 161, 94, 200, 53, 138, 198, 15, 149, 164, 15, 162, 72, 42, 77, 167, 190, 27, 102],
-		[221, 0, 174, 40, 60, 191, 145, 179, 35, 240, 13, 16, 195, 205, 49, 21, 202, 221, 89, 1, 91, 240, 236, 34, 138, 75, 12, 192, 49, 181, 58, 171],
+		[225, 201, 77, 212, 149, 45, 196, 80, 230, 131, 9, 10, 45, 219, 199, 5, 63, 147, 197, 26, 85, 178, 113, 76, 236, 209, 34, 188, 218, 60, 254, 146],
+		[10, 16, 218, 13, 240, 27, 186, 83, 63, 217, 161, 120, 218, 193, 94, 149, 62, 90, 254, 81, 141, 19, 253, 40, 169, 147, 29, 120, 146, 7, 100, 192],
+		[85, 57, 205, 119, 191, 103, 143, 51, 249, 82, 208, 128, 92, 62, 77, 0, 110, 178, 197, 203, 108, 114, 81, 58, 57, 20, 231, 57, 249, 28, 164, 205],
+		[77, 151, 172, 126, 98, 106, 123, 27, 157, 95, 56, 24, 243, 129, 14, 221, 42, 92, 108, 191, 117, 179, 61, 251, 205, 62, 205, 73, 42, 211, 130, 42],
 	],
 	[
-		[254, 24, 5, 190, 7, 165, 228, 190, 155, 229, 141, 155, 220, 185, 253, 154, 119, 48, 244, 115, 247, 229, 0, 205, 148, 194, 254, 113, 252, 153, 41, 111],
-		[194, 84, 43, 234, 25, 34, 196, 24, 134, 140, 85, 71, 82, 126, 167, 147, 215, 187, 212, 201, 150, 192, 123, 183, 229, 91, 128, 112, 113, 212, 89, 57],
-		[142, 168, 219, 127, 130, 11, 151, 205, 108, 94, 94, 123, 12, 137, 50, 212, 163, 79, 32, 102, 101, 89, 147, 85, 149, 109, 38, 217, 113, 175, 146, 160],
-		[36, 67, 83, 233, 175, 31, 143, 215, 27, 229, 124, 147, 32, 236, 154, 5, 202, 104, 192, 89, 241, 26, 203, 27, 215, 228, 123, 250, 209, 53, 219, 67],
+		[158, 237, 131, 237, 232, 10, 211, 22, 68, 9, 5, 27, 27, 219, 40, 77, 128, 207, 223, 158, 138, 105, 188, 112, 11, 231, 191, 182, 254, 136, 176, 41],
+		[193, 73, 178, 1, 18, 197, 72, 213, 84, 116, 44, 113, 231, 13, 204, 31, 102, 233, 13, 231, 81, 17, 65, 139, 222, 76, 174, 107, 73, 49, 198, 177],
+		[177, 0, 237, 120, 215, 222, 55, 158, 132, 60, 210, 165, 227, 237, 84, 152, 148, 16, 216, 125, 193, 222, 111, 82, 214, 133, 212, 209, 250, 78, 123, 34],
+		[224, 139, 133, 91, 80, 225, 112, 42, 175, 232, 237, 180, 28, 65, 245, 196, 190, 54, 52, 122, 168, 129, 27, 86, 5, 118, 10, 19, 183, 248, 69, 112],
 	],
 	[
-		[162, 234, 127, 243, 252, 17, 185, 109, 230, 242, 231, 234, 39, 251, 157, 152, 202, 106, 11, 13, 192, 244, 71, 46, 149, 4, 131, 244, 110, 190, 168, 73],
-		[128, 135, 221, 41, 153, 94, 229, 215, 116, 125, 181, 213, 144, 145, 56, 112, 168, 55, 39, 55, 164, 14, 126, 201, 9, 160, 46, 45, 1, 53, 115, 222],
-		[163, 46, 139, 150, 174, 222, 80, 75, 248, 243, 186, 133, 49, 144, 31, 166, 37, 202, 229, 254, 154, 193, 105, 124, 93, 105, 7, 225, 222, 199, 117, 195],
-		[60, 194, 85, 114, 92, 169, 82, 41, 163, 196, 189, 163, 141, 97, 145, 66, 216, 4, 114, 48, 207, 152, 154, 39, 207, 164, 123, 25, 109, 5, 81, 195],
+		[210, 9, 51, 104, 29, 93, 252, 96, 235, 65, 196, 65, 123, 142, 57, 145, 31, 3, 139, 58, 163, 238, 103, 154, 100, 248, 156, 79, 60, 115, 133, 46],
+		[93, 163, 34, 88, 143, 237, 42, 105, 123, 107, 222, 70, 78, 75, 42, 120, 87, 239, 63, 222, 88, 251, 80, 37, 36, 108, 113, 142, 78, 213, 159, 107],
+		[110, 207, 175, 130, 95, 37, 186, 182, 76, 84, 75, 192, 58, 55, 204, 113, 183, 93, 170, 197, 145, 166, 192, 125, 60, 55, 182, 42, 69, 241, 156, 188],
+		[222, 116, 180, 80, 165, 134, 127, 120, 116, 249, 254, 137, 28, 70, 48, 130, 141, 199, 12, 244, 68, 28, 182, 239, 254, 202, 78, 247, 236, 8, 78, 32],
 	],
 	[
-		[204, 212, 176, 105, 23, 5, 202, 149, 176, 83, 164, 208, 192, 163, 198, 102, 213, 133, 173, 115, 42, 83, 170, 229, 76, 199, 80, 49, 248, 188, 151, 212],
-		[180, 34, 240, 124, 159, 252, 45, 59, 72, 184, 99, 223, 112, 98, 140, 195, 230, 25, 202, 71, 154, 9, 183, 2, 62, 157, 236, 190, 174, 153, 97, 61],
-		[149, 174, 27, 161, 14, 189, 153, 8, 192, 116, 5, 162, 87, 197, 210, 229, 243, 29, 15, 189, 60, 49, 255, 126, 157, 177, 189, 51, 40, 168, 242, 48],
-		[192, 234, 8, 215, 72, 254, 180, 2, 99, 247, 48, 236, 191, 163, 28, 243, 86, 81, 251, 2, 235, 73, 173, 209, 227, 229, 31, 101, 124, 240, 251, 162],
+		[58, 87, 244, 6, 114, 220, 87, 113, 169, 125, 161, 140, 216, 124, 53, 245, 220, 217, 110, 207, 250, 247, 214, 106, 91, 129, 74, 222, 233, 73, 160, 147],
+		[70, 110, 55, 183, 92, 204, 102, 230, 225, 235, 189, 118, 210, 204, 136, 127, 149, 131, 105, 52, 110, 142, 144, 16, 94, 195, 14, 37, 153, 48, 32, 235],
+		[18, 172, 20, 73, 154, 13, 16, 169, 127, 235, 121, 58, 197, 14, 18, 50, 100, 53, 91, 128, 172, 195, 66, 52, 200, 125, 87, 127, 24, 234, 251, 89],
+		[52, 21, 131, 45, 166, 250, 230, 9, 23, 248, 230, 115, 187, 148, 128, 120, 126, 181, 3, 225, 117, 203, 68, 41, 174, 185, 218, 36, 59, 75, 88, 46],
 	],
 	[
-		[98, 138, 76, 149, 45, 243, 156, 64, 102, 125, 42, 104, 93, 183, 147, 49, 227, 186, 217, 48, 11, 218, 14, 109, 207, 225, 243, 170, 51, 189, 60, 84],
-		[44, 220, 65, 93, 19, 178, 76, 27, 4, 234, 197, 25, 220, 116, 164, 138, 53, 114, 218, 26, 175, 230, 69, 236, 138, 244, 169, 114, 6, 124, 250, 59],
-		[187, 11, 136, 65, 73, 219, 132, 64, 214, 156, 15, 50, 144, 45, 106, 166, 57, 9, 109, 199, 101, 89, 218, 132, 123, 131, 245, 67, 203, 105, 217, 35],
-		[38, 67, 7, 57, 90, 54, 56, 102, 247, 221, 135, 80, 78, 221, 164, 5, 171, 151, 243, 193, 171, 199, 8, 121, 127, 248, 67, 8, 236, 237, 64, 67],
+		[52, 233, 159, 106, 85, 34, 66, 195, 191, 209, 178, 46, 26, 100, 229, 23, 232, 61, 84, 23, 39, 84, 180, 117, 102, 222, 39, 23, 145, 12, 171, 56],
+		[135, 68, 241, 28, 58, 17, 34, 178, 68, 126, 7, 18, 199, 84, 197, 150, 83, 61, 92, 111, 78, 95, 192, 105, 8, 39, 207, 156, 168, 196, 55, 57],
+		[81, 246, 241, 70, 246, 10, 172, 192, 210, 240, 181, 169, 210, 26, 99, 54, 253, 254, 72, 115, 45, 10, 137, 110, 165, 210, 166, 65, 208, 36, 178, 201],
+		[125, 207, 190, 10, 50, 101, 154, 22, 147, 166, 134, 105, 21, 135, 2, 252, 164, 122, 54, 122, 49, 210, 228, 28, 145, 10, 237, 81, 102, 241, 52, 81],
 	],
 	[
-		[188, 20, 81, 147, 222, 94, 248, 56, 19, 207, 4, 234, 30, 44, 178, 167, 215, 116, 210, 147, 244, 44, 89, 5, 202, 22, 237, 76, 113, 7, 215, 231],
-		[138, 135, 53, 98, 68, 241, 249, 248, 232, 152, 136, 251, 180, 203, 102, 135, 35, 121, 201, 68, 91, 54, 224, 75, 106, 117, 249, 200, 197, 53, 219, 193],
-		[14, 74, 187, 3, 139, 177, 170, 207, 199, 69, 6, 174, 105, 137, 66, 242, 180, 223, 130, 194, 165, 244, 216, 111, 31, 122, 174, 11, 64, 145, 42, 149],
-		[121, 121, 49, 231, 44, 155, 90, 120, 229, 146, 158, 33, 58, 208, 171, 53, 254, 41, 130, 214, 231, 241, 146, 124, 160, 142, 117, 44, 163, 156, 122, 89],
+		[194, 125, 221, 203, 214, 204, 24, 135, 102, 169, 249, 77, 212, 66, 30, 193, 232, 208, 141, 53, 148, 130, 109, 51, 185, 129, 30, 54, 98, 18, 213, 164],
+		[127, 218, 162, 192, 175, 12, 12, 193, 199, 111, 84, 50, 42, 204, 174, 149, 118, 36, 227, 151, 184, 193, 245, 114, 190, 188, 178, 32, 241, 238, 41, 16],
+		[36, 220, 43, 1, 203, 68, 18, 98, 114, 162, 73, 133, 90, 173, 238, 118, 226, 57, 82, 194, 228, 46, 242, 43, 186, 16, 212, 213, 1, 55, 239, 227],
+		[150, 185, 106, 1, 187, 197, 235, 169, 87, 151, 128, 81, 3, 69, 23, 173, 209, 52, 192, 108, 88, 70, 112, 223, 30, 232, 88, 241, 207, 246, 160, 227],
 	],
 	[
-		[135, 66, 155, 206, 63, 214, 95, 148, 145, 34, 4, 47, 42, 81, 85, 195, 191, 241, 81, 89, 185, 211, 69, 192, 127, 83, 88, 179, 251, 144, 201, 225],
-		[216, 141, 212, 157, 11, 203, 120, 127, 95, 250, 195, 173, 198, 172, 176, 240, 229, 3, 128, 60, 115, 107, 107, 130, 120, 26, 63, 183, 146, 166, 150, 39],
-		[188, 208, 95, 163, 103, 253, 27, 205, 208, 29, 30, 64, 7, 205, 120, 10, 207, 107, 109, 175, 114, 168, 116, 51, 137, 167, 84, 174, 198, 132, 223, 3],
-		[169, 84, 47, 33, 41, 103, 108, 51, 157, 233, 102, 56, 26, 215, 144, 150, 39, 19, 99, 149, 158, 202, 71, 67, 209, 194, 54, 166, 233, 126, 80, 32],
+		[79, 11, 138, 99, 22, 126, 66, 132, 29, 122, 56, 248, 76, 182, 144, 247, 13, 175, 100, 162, 103, 217, 107, 34, 150, 81, 137, 136, 171, 167, 25, 64],
+		[145, 16, 168, 108, 19, 201, 92, 181, 192, 183, 46, 157, 86, 246, 139, 245, 95, 254, 225, 156, 106, 185, 63, 94, 28, 111, 101, 47, 203, 154, 100, 27],
+		[5, 219, 165, 126, 240, 249, 241, 180, 210, 5, 234, 70, 18, 170, 156, 52, 224, 79, 194, 28, 140, 119, 89, 146, 26, 173, 72, 45, 221, 69, 123, 23],
+		[176, 81, 166, 41, 166, 207, 183, 71, 180, 61, 145, 122, 70, 79, 107, 14, 243, 169, 96, 19, 250, 145, 110, 147, 109, 153, 241, 44, 147, 25, 170, 77],
 	],
 	[
-		[221, 2, 137, 178, 159, 167, 94, 123, 99, 124, 211, 48, 184, 201, 241, 26, 202, 199, 136, 35, 173, 24, 249, 57, 175, 252, 216, 52, 34, 141, 5, 230],
-		[205, 150, 248, 175, 30, 98, 247, 214, 182, 41, 250, 141, 67, 253, 230, 223, 189, 13, 222, 132, 52, 155, 245, 187, 29, 131, 151, 13, 3, 250, 36, 40],
-		[240, 50, 76, 96, 255, 60, 99, 112, 232, 165, 113, 90, 140, 133, 225, 83, 121, 67, 86, 107, 159, 4, 9, 38, 57, 8, 159, 250, 183, 188, 101, 113],
-		[40, 140, 95, 205, 24, 147, 100, 135, 115, 38, 102, 124, 181, 74, 36, 14, 201, 4, 223, 174, 185, 246, 10, 146, 0, 98, 219, 224, 51, 246, 35, 69],
+		[210, 146, 140, 61, 116, 193, 178, 15, 254, 8, 232, 5, 122, 30, 119, 107, 5, 45, 20, 31, 56, 76, 28, 185, 183, 194, 235, 246, 10, 221, 76, 196],
+		[140, 192, 33, 22, 3, 237, 121, 200, 150, 247, 146, 138, 196, 178, 75, 93, 122, 119, 187, 189, 88, 28, 30, 185, 63, 3, 11, 242, 193, 10, 67, 170],
+		[167, 42, 147, 71, 104, 9, 212, 160, 212, 165, 101, 219, 44, 50, 102, 163, 125, 217, 141, 216, 59, 48, 158, 34, 18, 45, 39, 133, 136, 198, 241, 230],
+		[22, 117, 113, 119, 249, 138, 30, 245, 197, 117, 71, 225, 224, 70, 252, 10, 44, 104, 30, 208, 98, 85, 158, 54, 108, 110, 231, 37, 55, 20, 34, 57],
 	],
 	[
-		[147, 191, 69, 199, 4, 146, 56, 49, 21, 105, 211, 50, 243, 241, 244, 241, 205, 94, 14, 245, 87, 124, 125, 69, 164, 150, 11, 178, 1, 224, 88, 129],
-		[123, 249, 8, 94, 23, 109, 116, 72, 152, 163, 128, 193, 121, 186, 207, 114, 6, 77, 229, 226, 149, 108, 15, 154, 241, 7, 235, 150, 224, 155, 172, 61],
-		[17, 92, 209, 128, 196, 39, 38, 221, 168, 35, 114, 9, 169, 7, 153, 250, 144, 106, 43, 144, 47, 207, 154, 193, 65, 149, 122, 251, 220, 8, 185, 136],
-		[29, 59, 133, 191, 229, 245, 119, 180, 244, 226, 234, 54, 95, 24, 213, 7, 9, 206, 80, 2, 113, 163, 6, 102, 247, 41, 2, 253, 217, 153, 33, 213],
+		[160, 51, 33, 61, 247, 88, 231, 232, 32, 46, 4, 73, 38, 65, 132, 154, 237, 193, 126, 146, 117, 48, 97, 151, 87, 134, 113, 119, 58, 238, 201, 27],
+		[138, 136, 53, 52, 117, 27, 118, 89, 124, 203, 113, 59, 70, 164, 66, 44, 31, 203, 10, 72, 187, 41, 148, 158, 237, 219, 234, 246, 200, 153, 160, 80],
+		[221, 170, 167, 204, 236, 244, 66, 37, 224, 57, 62, 138, 162, 46, 185, 185, 131, 224, 143, 159, 118, 218, 204, 37, 142, 105, 108, 18, 11, 104, 92, 81],
+		[75, 226, 49, 64, 232, 117, 91, 162, 209, 71, 52, 222, 74, 177, 47, 175, 67, 45, 64, 95, 83, 230, 192, 79, 188, 129, 30, 140, 22, 34, 114, 202],
 	],
 	[
-		[130, 156, 16, 79, 222, 78, 200, 200, 38, 154, 198, 135, 11, 227, 121, 33, 243, 191, 101, 65, 3, 107, 157, 68, 28, 139, 185, 179, 101, 144, 36, 216],
-		[35, 108, 84, 155, 56, 216, 185, 133, 139, 112, 10, 227, 228, 222, 226, 187, 105, 12, 88, 34, 116, 91, 4, 61, 77, 31, 85, 110, 152, 161, 169, 228],
-		[216, 157, 150, 11, 178, 33, 97, 30, 43, 148, 169, 189, 138, 51, 76, 246, 144, 253, 137, 131, 190, 176, 0, 74, 89, 8, 224, 35, 58, 122, 243, 140],
-		[170, 37, 103, 166, 211, 83, 179, 177, 235, 32, 186, 50, 206, 104, 82, 164, 238, 143, 37, 99, 215, 220, 133, 130, 106, 200, 213, 124, 238, 73, 112, 88],
+		[47, 128, 238, 47, 240, 128, 75, 30, 130, 184, 97, 180, 21, 226, 120, 111, 206, 90, 85, 189, 38, 63, 13, 136, 239, 198, 206, 169, 148, 186, 11, 69],
+		[44, 254, 215, 151, 71, 62, 186, 26, 78, 161, 223, 251, 62, 62, 144, 12, 47, 104, 99, 29, 34, 96, 163, 100, 71, 107, 225, 67, 106, 186, 247, 16],
+		[21, 230, 249, 163, 147, 217, 210, 175, 81, 159, 81, 135, 79, 63, 230, 138, 76, 240, 73, 235, 69, 65, 146, 36, 227, 120, 134, 41, 71, 46, 71, 59],
+		[216, 163, 191, 108, 203, 101, 171, 147, 113, 190, 136, 79, 124, 228, 231, 60, 191, 229, 115, 209, 52, 191, 248, 198, 65, 158, 57, 244, 162, 10, 45, 27],
 	],
 	[
-		[10, 8, 247, 188, 44, 130, 192, 68, 19, 236, 241, 203, 127, 192, 238, 199, 246, 9, 7, 35, 1, 254, 67, 69, 121, 160, 192, 215, 159, 123, 248, 26],
-		[59, 68, 6, 134, 137, 6, 143, 140, 160, 135, 203, 53, 139, 104, 206, 81, 131, 228, 220, 149, 33, 150, 139, 221, 186, 199, 45, 91, 194, 177, 59, 155],
-		[226, 155, 119, 5, 237, 147, 239, 205, 135, 43, 163, 75, 158, 95, 13, 14, 232, 25, 89, 200, 156, 25, 45, 237, 163, 49, 209, 112, 103, 233, 87, 194],
-		[173, 198, 57, 249, 63, 114, 145, 54, 24, 188, 8, 149, 59, 119, 197, 97, 211, 49, 26, 22, 87, 21, 39, 12, 136, 37, 221, 211, 53, 31, 212, 99],
+		[10, 78, 94, 119, 38, 64, 21, 95, 191, 175, 98, 5, 97, 113, 233, 150, 6, 58, 163, 230, 39, 69, 248, 137, 242, 233, 194, 22, 13, 144, 203, 115],
+		[128, 87, 101, 0, 31, 15, 211, 101, 25, 241, 195, 47, 154, 207, 0, 164, 58, 216, 105, 173, 160, 249, 170, 164, 135, 215, 15, 114, 132, 144, 209, 232],
+		[14, 239, 248, 166, 35, 35, 48, 42, 47, 175, 77, 122, 44, 185, 198, 214, 151, 137, 187, 97, 80, 35, 64, 134, 91, 77, 166, 219, 125, 153, 165, 185],
+		[246, 142, 177, 212, 50, 119, 156, 9, 133, 123, 191, 252, 220, 218, 232, 71, 11, 31, 171, 104, 65, 245, 170, 5, 74, 164, 39, 190, 35, 130, 101, 101],
 	],
 	[
-		[254, 146, 18, 24, 175, 132, 164, 119, 26, 134, 110, 44, 209, 132, 241, 93, 134, 19, 24, 188, 128, 76, 173, 236, 218, 162, 112, 7, 101, 136, 14, 189],
-		[216, 138, 220, 161, 33, 45, 3, 175, 31, 88, 124, 66, 95, 148, 171, 102, 185, 60, 143, 246, 232, 54, 49, 118, 242, 15, 159, 86, 55, 185, 83, 217],
-		[36, 207, 41, 129, 65, 118, 101, 6, 230, 10, 131, 238, 160, 238, 131, 102, 81, 59, 157, 229, 226, 109, 233, 220, 132, 214, 147, 240, 105, 78, 140, 94],
-		[65, 32, 85, 6, 173, 147, 251, 80, 253, 247, 167, 30, 38, 187, 126, 29, 175, 92, 212, 194, 34, 128, 143, 153, 219, 87, 130, 58, 214, 130, 179, 139],
+		[187, 90, 197, 64, 75, 129, 166, 93, 181, 81, 169, 164, 254, 230, 168, 57, 31, 149, 69, 48, 80, 52, 132, 251, 94, 237, 204, 181, 125, 235, 72, 164],
+		[60, 6, 196, 4, 105, 229, 15, 27, 131, 177, 34, 183, 88, 48, 138, 157, 174, 162, 12, 151, 125, 141, 4, 112, 248, 8, 200, 175, 169, 60, 72, 181],
+		[111, 24, 111, 230, 33, 241, 186, 232, 29, 233, 19, 125, 226, 202, 6, 6, 20, 10, 136, 19, 129, 117, 248, 44, 20, 83, 60, 160, 15, 203, 38, 53],
+		[210, 60, 14, 131, 117, 54, 136, 38, 15, 54, 146, 177, 177, 100, 106, 225, 159, 153, 160, 5, 67, 38, 119, 39, 250, 75, 138, 135, 138, 131, 255, 97],
 	],
 	[
-		[123, 45, 184, 209, 10, 151, 129, 156, 232, 131, 78, 123, 96, 151, 158, 186, 216, 112, 252, 156, 124, 228, 65, 26, 121, 4, 132, 209, 129, 50, 59, 39],
-		[171, 13, 254, 54, 154, 118, 94, 202, 91, 211, 67, 200, 33, 174, 171, 224, 145, 150, 23, 220, 0, 205, 145, 159, 0, 163, 91, 44, 47, 200, 27, 149],
-		[81, 45, 80, 89, 130, 195, 204, 107, 185, 250, 244, 209, 103, 10, 35, 98, 85, 3, 118, 5, 217, 183, 121, 212, 150, 166, 102, 216, 177, 67, 244, 15],
-		[252, 122, 118, 81, 75, 128, 160, 236, 171, 157, 207, 71, 150, 25, 90, 215, 149, 97, 188, 150, 135, 152, 106, 184, 194, 215, 98, 190, 99, 20, 53, 151],
+		[205, 43, 138, 233, 187, 131, 225, 51, 59, 49, 7, 68, 37, 159, 18, 122, 77, 184, 23, 94, 15, 254, 92, 151, 252, 29, 26, 251, 40, 55, 130, 32],
+		[114, 217, 47, 11, 7, 214, 76, 39, 82, 100, 98, 238, 158, 210, 36, 202, 128, 176, 174, 186, 135, 222, 9, 128, 237, 121, 7, 199, 176, 248, 60, 35],
+		[41, 125, 114, 248, 173, 4, 24, 193, 55, 9, 251, 59, 172, 151, 206, 53, 164, 75, 93, 155, 138, 193, 215, 14, 180, 207, 160, 69, 4, 28, 124, 50],
+		[223, 193, 166, 157, 168, 227, 164, 17, 184, 22, 15, 68, 26, 147, 34, 101, 102, 9, 222, 202, 146, 105, 157, 57, 224, 130, 159, 97, 174, 127, 37, 53],
 	],
 	[
-		[218, 49, 42, 23, 92, 134, 200, 104, 181, 91, 44, 175, 247, 49, 42, 95, 124, 206, 62, 73, 153, 34, 216, 171, 49, 130, 38, 174, 100, 214, 159, 192],
-		[139, 197, 37, 94, 50, 235, 14, 246, 77, 223, 146, 42, 208, 155, 160, 62, 79, 151, 254, 92, 226, 33, 25, 55, 221, 71, 233, 167, 163, 240, 81, 103],
-		[113, 229, 214, 66, 20, 205, 176, 141, 254, 126, 173, 230, 232, 242, 185, 76, 80, 84, 184, 35, 120, 75, 155, 137, 224, 72, 17, 118, 230, 243, 29, 155],
-		[254, 104, 14, 28, 35, 242, 37, 248, 179, 216, 38, 25, 174, 65, 191, 46, 71, 177, 216, 82, 245, 244, 30, 63, 30, 75, 178, 62, 137, 198, 159, 1],
+		[134, 167, 185, 48, 30, 213, 100, 240, 247, 210, 92, 213, 147, 96, 152, 4, 18, 156, 159, 106, 224, 61, 73, 135, 54, 231, 39, 67, 40, 155, 33, 57],
+		[185, 172, 194, 199, 175, 171, 247, 20, 40, 99, 39, 175, 46, 237, 192, 112, 166, 150, 87, 74, 185, 176, 8, 164, 93, 88, 172, 234, 203, 208, 81, 134],
+		[118, 57, 52, 203, 5, 129, 248, 181, 80, 235, 151, 34, 157, 168, 183, 244, 207, 6, 234, 52, 162, 241, 124, 226, 204, 156, 184, 86, 33, 14, 109, 85],
+		[150, 228, 223, 178, 229, 187, 51, 198, 25, 116, 107, 136, 132, 179, 161, 129, 58, 24, 114, 66, 60, 94, 156, 10, 160, 170, 44, 34, 50, 204, 208, 161],
 	],
 	[
-		[3, 245, 84, 190, 12, 166, 214, 166, 61, 176, 204, 82, 139, 129, 84, 230, 18, 57, 226, 252, 208, 41, 182, 140, 138, 103, 93, 198, 91, 70, 215, 55],
-		[55, 1, 56, 150, 64, 203, 7, 137, 152, 40, 141, 125, 143, 28, 250, 143, 104, 68, 180, 233, 21, 155, 64, 53, 131, 204, 118, 85, 105, 203, 156, 223],
-		[251, 43, 102, 30, 74, 248, 249, 28, 228, 203, 53, 99, 109, 186, 176, 218, 169, 204, 124, 77, 12, 25, 160, 85, 254, 219, 96, 98, 77, 130, 30, 233],
-		[88, 247, 222, 92, 51, 211, 182, 177, 89, 93, 213, 104, 132, 234, 0, 46, 84, 236, 12, 118, 121, 75, 197, 133, 12, 215, 10, 251, 189, 202, 95, 13],
+		[183, 14, 4, 47, 218, 219, 71, 4, 169, 148, 141, 95, 144, 102, 153, 183, 0, 206, 89, 130, 0, 244, 204, 136, 242, 234, 230, 253, 140, 224, 157, 108],
+		[197, 247, 106, 40, 106, 235, 209, 98, 62, 231, 125, 79, 30, 194, 11, 114, 95, 166, 193, 168, 106, 172, 53, 176, 158, 190, 83, 17, 254, 62, 47, 165],
+		[159, 46, 177, 46, 146, 145, 245, 76, 74, 48, 143, 64, 48, 110, 124, 42, 11, 236, 36, 178, 137, 80, 189, 14, 78, 237, 207, 170, 27, 57, 114, 96],
+		[4, 93, 31, 232, 153, 19, 45, 0, 135, 51, 104, 3, 88, 160, 68, 157, 66, 77, 145, 158, 195, 48, 190, 102, 245, 51, 49, 52, 221, 156, 150, 82],
 	],
 	[
-		[76, 153, 16, 46, 51, 66, 18, 177, 160, 231, 231, 185, 188, 210, 106, 104, 33, 183, 135, 152, 4, 128, 204, 74, 32, 128, 140, 19, 152, 99, 222, 204],
-		[31, 245, 2, 147, 168, 134, 9, 7, 81, 189, 191, 27, 203, 223, 177, 2, 13, 36, 93, 74, 189, 79, 251, 183, 242, 33, 160, 100, 149, 59, 126, 225],
-		[213, 10, 165, 231, 236, 135, 193, 179, 65, 70, 195, 151, 29, 100, 2, 192, 51, 95, 128, 230, 176, 54, 123, 162, 232, 125, 185, 7, 161, 208, 199, 171],
-		[114, 141, 123, 131, 45, 173, 119, 67, 94, 23, 176, 6, 75, 239, 242, 251, 226, 145, 132, 33, 200, 54, 140, 106, 213, 188, 106, 128, 219, 8, 116, 194],
+		[69, 169, 136, 14, 143, 197, 48, 104, 171, 77, 128, 32, 255, 58, 182, 216, 188, 89, 115, 106, 40, 129, 155, 86, 111, 126, 114, 191, 166, 120, 14, 59],
+		[201, 101, 159, 230, 209, 161, 36, 19, 124, 115, 81, 233, 227, 82, 96, 161, 115, 169, 154, 146, 118, 243, 98, 73, 82, 41, 164, 190, 165, 250, 89, 227],
+		[172, 231, 103, 45, 48, 186, 192, 5, 111, 213, 217, 119, 118, 91, 157, 134, 223, 1, 89, 29, 78, 150, 95, 162, 92, 42, 166, 210, 74, 215, 45, 75],
+		[183, 149, 194, 241, 189, 13, 67, 45, 71, 198, 31, 19, 6, 35, 126, 111, 32, 227, 252, 111, 192, 45, 109, 196, 74, 218, 32, 66, 131, 232, 192, 34],
 	],
 	[
-		[71, 123, 12, 194, 17, 155, 220, 250, 149, 197, 53, 2, 12, 250, 213, 245, 144, 25, 242, 63, 246, 200, 3, 28, 244, 198, 235, 90, 21, 99, 105, 67],
-		[90, 209, 98, 185, 187, 60, 229, 213, 136, 30, 30, 127, 104, 132, 188, 67, 135, 214, 188, 43, 246, 76, 244, 22, 165, 50, 70, 36, 240, 253, 186, 158],
-		[116, 99, 255, 104, 36, 153, 58, 94, 13, 155, 23, 84, 44, 210, 22, 155, 78, 207, 68, 169, 224, 44, 224, 147, 158, 251, 229, 204, 6, 47, 110, 182],
-		[96, 156, 90, 198, 218, 13, 70, 132, 177, 51, 63, 227, 86, 79, 230, 38, 102, 8, 215, 73, 248, 14, 252, 40, 79, 245, 145, 60, 254, 200, 139, 75],
+		[131, 227, 155, 123, 123, 193, 101, 69, 117, 23, 179, 3, 168, 157, 83, 185, 146, 61, 114, 180, 60, 100, 172, 250, 152, 253, 23, 205, 202, 9, 222, 2],
+		[175, 21, 5, 90, 101, 138, 123, 118, 27, 174, 61, 169, 242, 53, 133, 192, 9, 218, 236, 96, 150, 193, 179, 224, 201, 86, 204, 87, 73, 150, 135, 184],
+		[59, 151, 91, 115, 13, 79, 64, 197, 36, 126, 40, 224, 156, 111, 15, 31, 3, 138, 75, 75, 164, 184, 213, 97, 142, 169, 238, 250, 111, 6, 233, 166],
+		[54, 108, 248, 169, 203, 248, 245, 241, 219, 173, 8, 248, 253, 39, 207, 0, 31, 192, 166, 176, 74, 191, 98, 110, 180, 217, 218, 0, 30, 82, 183, 141],
 	],
 	[
-		[49, 187, 26, 137, 114, 178, 20, 110, 114, 55, 249, 178, 2, 38, 107, 122, 77, 127, 237, 24, 43, 161, 242, 125, 160, 151, 176, 1, 191, 113, 222, 168],
-		[40, 109, 209, 140, 211, 31, 4, 111, 158, 63, 186, 100, 112, 212, 111, 147, 240, 89, 221, 109, 98, 115, 55, 132, 83, 209, 232, 198, 120, 158, 205, 135],
-		[24, 14, 232, 92, 112, 110, 17, 121, 226, 79, 202, 226, 40, 181, 213, 130, 129, 87, 110, 156, 75, 142, 61, 42, 46, 122, 84, 45, 36, 0, 112, 81],
-		[165, 130, 81, 96, 2, 239, 84, 252, 34, 155, 215, 70, 231, 214, 130, 156, 142, 43, 178, 154, 220, 87, 191, 43, 68, 123, 113, 48, 106, 223, 99, 38],
+		[154, 59, 152, 147, 69, 46, 159, 82, 175, 90, 192, 110, 74, 78, 109, 243, 120, 94, 247, 219, 121, 48, 1, 58, 237, 220, 16, 7, 205, 3, 28, 218],
+		[241, 129, 13, 184, 48, 196, 136, 205, 187, 63, 109, 245, 32, 48, 166, 254, 104, 233, 177, 74, 66, 221, 214, 103, 66, 41, 91, 93, 71, 220, 106, 43],
+		[245, 205, 28, 59, 127, 10, 240, 83, 197, 251, 114, 18, 4, 252, 133, 45, 100, 216, 40, 163, 140, 5, 245, 93, 78, 147, 224, 251, 17, 160, 96, 32],
+		[133, 182, 205, 65, 212, 4, 185, 117, 79, 78, 60, 9, 69, 210, 218, 78, 157, 245, 250, 171, 150, 250, 88, 44, 33, 70, 12, 116, 51, 177, 48, 112],
 	],
 	[
-		[227, 120, 155, 91, 169, 79, 154, 238, 175, 64, 54, 65, 69, 105, 233, 89, 240, 81, 220, 233, 120, 217, 227, 31, 97, 102, 240, 208, 78, 226, 14, 177],
-		[221, 217, 229, 49, 94, 209, 26, 175, 242, 53, 182, 174, 99, 145, 159, 13, 96, 9, 220, 109, 46, 127, 219, 35, 216, 50, 49, 144, 80, 30, 180, 210],
-		[165, 146, 38, 188, 200, 39, 247, 77, 70, 231, 71, 52, 254, 203, 72, 146, 20, 56, 230, 144, 5, 107, 85, 182, 114, 35, 158, 193, 24, 225, 129, 43],
-		[113, 43, 216, 147, 35, 112, 169, 230, 150, 208, 111, 40, 54, 165, 148, 223, 4, 181, 87, 163, 58, 195, 89, 11, 57, 171, 66, 187, 202, 156, 125, 133],
+		[62, 95, 91, 152, 2, 175, 101, 254, 118, 61, 167, 189, 73, 185, 3, 39, 52, 236, 179, 127, 86, 125, 126, 128, 99, 236, 170, 237, 7, 128, 67, 43],
+		[89, 121, 86, 194, 3, 165, 134, 189, 182, 40, 89, 78, 41, 237, 225, 219, 120, 184, 122, 199, 80, 126, 121, 52, 207, 63, 17, 150, 20, 184, 101, 143],
+		[48, 234, 130, 178, 236, 48, 51, 138, 200, 198, 18, 238, 107, 215, 51, 102, 230, 219, 246, 93, 245, 79, 7, 6, 141, 237, 6, 252, 242, 230, 213, 65],
+		[142, 135, 70, 220, 83, 141, 228, 34, 110, 35, 183, 129, 105, 255, 213, 228, 162, 131, 214, 49, 26, 247, 187, 145, 23, 36, 207, 0, 135, 81, 121, 49],
 	],
 	[
-		[23, 116, 223, 53, 105, 173, 19, 237, 212, 194, 67, 65, 100, 197, 216, 189, 120, 122, 47, 224, 104, 63, 220, 229, 245, 22, 103, 113, 131, 135, 171, 56],
-		[204, 135, 34, 222, 231, 13, 23, 183, 41, 130, 85, 187, 100, 168, 34, 220, 18, 213, 97, 79, 220, 68, 242, 178, 53, 249, 122, 99, 28, 235, 170, 105],
-		[147, 168, 19, 106, 6, 198, 211, 209, 147, 177, 118, 215, 144, 128, 38, 152, 164, 10, 131, 242, 45, 241, 242, 201, 105, 139, 121, 227, 173, 20, 85, 16],
-		[96, 90, 116, 86, 209, 25, 246, 228, 48, 70, 60, 24, 120, 28, 5, 187, 202, 196, 178, 155, 139, 219, 11, 159, 216, 173, 34, 202, 168, 47, 120, 41],
+		[225, 7, 206, 79, 141, 160, 230, 17, 231, 111, 213, 103, 126, 238, 24, 239, 159, 221, 146, 85, 12, 104, 50, 248, 157, 89, 76, 182, 216, 193, 52, 67],
+		[209, 253, 241, 36, 140, 222, 212, 130, 108, 198, 45, 114, 28, 248, 172, 175, 207, 142, 215, 190, 16, 165, 172, 173, 89, 202, 45, 143, 159, 255, 180, 216],
+		[242, 9, 225, 205, 33, 75, 191, 118, 137, 35, 130, 234, 119, 172, 52, 222, 129, 206, 26, 142, 128, 4, 161, 120, 169, 173, 88, 28, 129, 113, 138, 50],
+		[40, 15, 103, 138, 40, 176, 80, 50, 196, 230, 49, 85, 208, 42, 206, 54, 184, 8, 243, 110, 109, 150, 119, 230, 174, 254, 252, 11, 210, 189, 234, 29],
 	],
 	[
-		[1, 198, 133, 165, 68, 58, 136, 59, 197, 106, 16, 144, 42, 155, 63, 255, 34, 58, 202, 221, 79, 140, 89, 123, 167, 30, 145, 252, 135, 133, 59, 21],
-		[160, 112, 92, 253, 29, 239, 224, 155, 6, 168, 124, 62, 4, 142, 199, 188, 136, 99, 122, 179, 167, 197, 94, 236, 111, 84, 133, 9, 152, 213, 102, 20],
-		[219, 163, 107, 137, 53, 100, 114, 88, 248, 176, 186, 130, 82, 140, 100, 34, 75, 185, 114, 223, 77, 44, 221, 117, 85, 176, 62, 242, 240, 47, 43, 146],
-		[231, 33, 129, 101, 115, 167, 252, 100, 184, 103, 34, 160, 199, 81, 3, 175, 223, 54, 205, 241, 32, 136, 183, 87, 45, 103, 39, 172, 53, 62, 16, 131],
+		[181, 184, 198, 127, 70, 242, 117, 70, 252, 212, 127, 249, 96, 7, 247, 204, 45, 160, 170, 21, 61, 59, 114, 64, 231, 25, 1, 40, 84, 166, 173, 0],
+		[235, 91, 195, 14, 228, 43, 84, 100, 15, 127, 79, 57, 179, 183, 213, 246, 58, 208, 186, 226, 62, 232, 21, 177, 59, 79, 168, 134, 185, 29, 232, 165],
+		[220, 49, 174, 121, 178, 245, 94, 253, 255, 119, 123, 196, 169, 177, 173, 100, 102, 3, 122, 208, 133, 87, 83, 113, 151, 199, 202, 247, 95, 5, 75, 197],
+		[128, 206, 88, 216, 196, 255, 3, 71, 100, 124, 205, 198, 204, 25, 115, 239, 152, 230, 220, 167, 196, 101, 63, 44, 41, 175, 47, 7, 135, 60, 142, 185],
 	],
 	[
-		[43, 82, 151, 89, 246, 168, 6, 230, 59, 114, 71, 119, 65, 251, 219, 123, 71, 118, 204, 181, 7, 139, 207, 75, 167, 84, 29, 212, 158, 43, 247, 235],
-		[201, 237, 13, 84, 195, 158, 134, 204, 135, 233, 73, 156, 243, 158, 113, 219, 46, 32, 43, 18, 74, 174, 14, 252, 14, 143, 203, 81, 224, 142, 114, 183],
-		[89, 65, 42, 212, 247, 224, 173, 134, 85, 104, 225, 27, 106, 96, 201, 132, 254, 21, 47, 141, 31, 64, 246, 102, 203, 179, 156, 228, 149, 33, 103, 107],
-		[39, 56, 235, 4, 152, 114, 49, 110, 45, 41, 2, 119, 241, 52, 130, 235, 10, 231, 186, 182, 225, 187, 107, 103, 175, 0, 217, 166, 53, 42, 170, 94],
+		[203, 23, 148, 1, 21, 74, 207, 91, 128, 20, 218, 82, 46, 253, 152, 174, 120, 76, 207, 38, 250, 113, 254, 26, 214, 108, 23, 149, 72, 96, 255, 192],
+		[5, 138, 122, 105, 89, 75, 197, 221, 97, 41, 21, 78, 85, 41, 22, 202, 146, 120, 0, 221, 210, 198, 224, 154, 109, 249, 77, 207, 9, 162, 17, 154],
+		[133, 52, 148, 62, 235, 35, 223, 15, 10, 241, 194, 55, 211, 214, 65, 62, 196, 79, 60, 84, 183, 141, 65, 153, 60, 66, 196, 133, 145, 237, 225, 43],
+		[234, 70, 57, 130, 66, 164, 32, 102, 18, 62, 96, 177, 43, 41, 109, 50, 66, 125, 139, 77, 50, 15, 3, 24, 69, 253, 27, 106, 84, 214, 251, 134],
 	],
 	[
-		[248, 166, 201, 240, 203, 207, 4, 159, 3, 198, 52, 134, 218, 159, 95, 69, 75, 54, 115, 122, 176, 178, 151, 132, 192, 80, 98, 59, 95, 7, 94, 95],
-		[218, 162, 231, 15, 144, 32, 61, 115, 16, 224, 131, 184, 242, 200, 209, 19, 87, 113, 51, 35, 65, 252, 164, 173, 218, 237, 13, 74, 187, 126, 1, 100],
-		[213, 77, 80, 13, 211, 74, 249, 87, 191, 74, 250, 146, 251, 71, 108, 131, 90, 113, 190, 31, 254, 104, 174, 166, 241, 115, 206, 46, 239, 39, 198, 78],
-		[98, 220, 96, 43, 20, 118, 217, 112, 73, 239, 6, 144, 85, 248, 136, 86, 83, 6, 176, 225, 65, 250, 190, 7, 133, 241, 65, 84, 42, 223, 39, 236],
+		[241, 35, 210, 252, 134, 227, 197, 94, 51, 28, 128, 239, 178, 129, 47, 216, 96, 60, 162, 173, 200, 8, 32, 192, 107, 227, 95, 159, 89, 154, 168, 3],
+		[38, 30, 51, 48, 176, 217, 200, 122, 3, 179, 30, 207, 3, 132, 185, 184, 37, 68, 59, 61, 62, 73, 30, 50, 13, 0, 7, 72, 219, 37, 64, 12],
+		[25, 87, 40, 255, 109, 112, 164, 216, 198, 83, 130, 19, 18, 31, 156, 187, 100, 143, 93, 114, 137, 115, 165, 40, 213, 8, 191, 83, 73, 114, 95, 134],
+		[97, 158, 84, 194, 72, 122, 184, 164, 129, 148, 36, 130, 61, 106, 152, 237, 176, 58, 235, 186, 119, 217, 138, 71, 222, 152, 135, 110, 219, 56, 59, 238],
 	],
 	[
-		[152, 215, 158, 192, 194, 67, 178, 179, 39, 36, 243, 198, 217, 162, 121, 195, 203, 45, 208, 149, 30, 16, 1, 210, 23, 158, 231, 210, 158, 164, 62, 31],
-		[137, 131, 25, 147, 217, 8, 61, 28, 81, 88, 6, 203, 68, 61, 142, 72, 68, 121, 35, 110, 41, 232, 95, 145, 95, 141, 166, 165, 254, 151, 226, 41],
-		[252, 115, 176, 103, 21, 10, 202, 240, 97, 150, 185, 136, 32, 185, 162, 157, 93, 246, 138, 32, 159, 210, 143, 220, 176, 72, 208, 229, 107, 175, 180, 78],
-		[94, 7, 146, 74, 107, 157, 123, 250, 157, 203, 200, 231, 85, 29, 216, 218, 24, 178, 204, 26, 67, 114, 14, 141, 121, 139, 235, 61, 201, 113, 220, 190],
+		[40, 146, 34, 129, 153, 102, 35, 168, 166, 150, 143, 18, 154, 10, 139, 251, 45, 35, 35, 101, 135, 34, 142, 58, 2, 196, 105, 244, 57, 189, 195, 80],
+		[6, 200, 210, 164, 48, 50, 37, 191, 99, 42, 2, 138, 158, 0, 63, 251, 100, 16, 208, 66, 178, 120, 35, 166, 119, 170, 52, 83, 41, 53, 234, 109],
+		[247, 169, 239, 103, 63, 200, 62, 175, 208, 246, 188, 195, 30, 199, 63, 88, 158, 135, 13, 22, 156, 15, 12, 52, 227, 209, 224, 201, 25, 148, 118, 36],
+		[106, 187, 152, 222, 172, 87, 111, 226, 239, 210, 102, 175, 90, 32, 86, 147, 71, 190, 34, 183, 113, 51, 167, 128, 166, 156, 120, 140, 231, 136, 210, 183],
 	],
 	[
-		[13, 15, 121, 197, 57, 192, 244, 129, 236, 207, 39, 143, 251, 20, 221, 52, 152, 234, 33, 188, 130, 111, 126, 113, 8, 246, 0, 218, 169, 129, 22, 27],
-		[88, 39, 40, 241, 56, 80, 195, 240, 61, 98, 137, 228, 116, 124, 105, 161, 37, 30, 130, 253, 111, 57, 171, 181, 193, 167, 35, 135, 217, 143, 159, 52],
-		[35, 34, 232, 171, 111, 14, 121, 153, 235, 117, 10, 98, 4, 84, 200, 154, 103, 98, 141, 145, 0, 16, 47, 212, 253, 237, 109, 75, 122, 97, 160, 109],
-		[56, 136, 33, 228, 196, 48, 14, 9, 143, 10, 234, 76, 197, 240, 228, 175, 73, 162, 16, 106, 184, 98, 12, 67, 57, 232, 88, 1, 20, 184, 219, 81],
+		[58, 69, 249, 69, 64, 52, 109, 22, 16, 110, 50, 47, 164, 214, 31, 39, 80, 151, 71, 189, 52, 199, 236, 233, 134, 134, 86, 255, 0, 206, 54, 195],
+		[49, 79, 87, 22, 237, 96, 243, 154, 129, 134, 38, 224, 19, 42, 143, 252, 219, 214, 190, 165, 51, 105, 42, 74, 145, 198, 230, 111, 47, 145, 88, 114],
+		[116, 127, 202, 244, 230, 24, 30, 127, 23, 135, 50, 216, 35, 202, 135, 54, 110, 82, 103, 113, 89, 64, 100, 47, 176, 209, 203, 252, 164, 95, 43, 128],
+		[53, 146, 199, 143, 2, 138, 215, 80, 191, 209, 246, 219, 45, 32, 144, 185, 250, 123, 216, 50, 201, 53, 21, 127, 41, 27, 86, 76, 118, 49, 96, 31],
 	],
 	[
-		[38, 80, 103, 124, 228, 104, 61, 10, 202, 225, 180, 191, 21, 237, 227, 121, 173, 28, 114, 91, 162, 28, 107, 37, 164, 2, 241, 169, 149, 161, 37, 40],
-		[24, 96, 181, 234, 86, 128, 68, 85, 98, 77, 144, 47, 145, 116, 221, 111, 64, 31, 57, 235, 225, 187, 222, 179, 247, 9, 216, 75, 55, 159, 12, 174],
-		[189, 233, 71, 18, 162, 37, 102, 103, 135, 229, 137, 68, 10, 119, 75, 157, 181, 175, 78, 37, 109, 223, 132, 241, 208, 133, 129, 81, 206, 35, 145, 43],
-		[8, 44, 124, 236, 130, 56, 67, 168, 185, 120, 45, 235, 122, 68, 180, 24, 5, 225, 78, 208, 242, 213, 136, 30, 85, 86, 215, 13, 170, 222, 230, 236],
+		[0, 88, 77, 243, 4, 177, 168, 229, 230, 37, 253, 190, 199, 162, 91, 245, 101, 67, 16, 182, 104, 181, 91, 245, 85, 158, 14, 236, 146, 85, 34, 96],
+		[11, 2, 100, 11, 38, 185, 155, 195, 252, 174, 182, 119, 72, 215, 118, 56, 68, 112, 216, 46, 58, 54, 167, 107, 19, 35, 20, 110, 41, 150, 76, 113],
+		[34, 233, 244, 78, 69, 29, 32, 36, 212, 214, 45, 2, 237, 96, 114, 115, 31, 178, 227, 10, 77, 197, 240, 36, 157, 191, 113, 118, 226, 171, 69, 25],
+		[160, 123, 143, 60, 19, 228, 205, 242, 182, 40, 189, 67, 111, 10, 194, 28, 4, 43, 233, 127, 85, 26, 25, 237, 99, 215, 251, 129, 140, 90, 131, 218],
 	],
 	[
-		[41, 151, 226, 218, 5, 213, 219, 50, 141, 255, 66, 112, 232, 207, 206, 178, 121, 114, 231, 27, 132, 105, 241, 104, 69, 73, 33, 95, 111, 116, 229, 111],
-		[22, 34, 62, 47, 21, 175, 151, 15, 188, 63, 54, 23, 79, 223, 73, 172, 97, 207, 145, 180, 9, 93, 72, 68, 187, 195, 144, 116, 26, 151, 196, 200],
-		[112, 14, 255, 116, 50, 217, 80, 87, 220, 25, 193, 33, 94, 253, 62, 29, 251, 133, 213, 192, 65, 253, 227, 89, 203, 37, 122, 33, 145, 198, 238, 20],
-		[248, 41, 204, 234, 152, 47, 175, 111, 126, 178, 205, 158, 246, 19, 15, 140, 162, 127, 106, 55, 114, 6, 43, 215, 118, 23, 120, 141, 213, 225, 183, 222],
+		[142, 197, 194, 117, 152, 246, 254, 184, 169, 218, 34, 27, 30, 50, 233, 141, 140, 27, 195, 50, 154, 174, 221, 129, 150, 102, 36, 185, 124, 160, 254, 85],
+		[115, 81, 41, 143, 26, 57, 52, 175, 245, 129, 223, 75, 113, 245, 24, 127, 179, 175, 251, 157, 6, 156, 36, 246, 204, 40, 133, 138, 53, 163, 136, 153],
+		[12, 208, 107, 7, 141, 71, 87, 26, 95, 54, 35, 133, 108, 218, 0, 13, 246, 82, 168, 45, 116, 231, 65, 29, 89, 12, 142, 0, 211, 116, 240, 45],
+		[101, 126, 174, 237, 97, 112, 136, 172, 179, 168, 209, 160, 89, 32, 221, 189, 171, 185, 47, 249, 228, 101, 204, 99, 83, 19, 100, 130, 201, 207, 246, 176],
 	],
 	[
-		[182, 93, 232, 135, 116, 25, 195, 126, 168, 238, 226, 31, 20, 91, 82, 83, 202, 44, 46, 206, 39, 110, 254, 109, 41, 92, 151, 41, 125, 1, 12, 182],
-		[35, 196, 96, 149, 63, 86, 156, 45, 191, 17, 37, 111, 80, 190, 60, 252, 110, 244, 52, 236, 207, 233, 159, 126, 79, 70, 206, 194, 175, 63, 248, 50],
-		[167, 65, 206, 234, 234, 68, 135, 75, 197, 35, 16, 77, 213, 119, 178, 145, 169, 18, 234, 2, 126, 198, 105, 210, 1, 215, 226, 7, 26, 228, 170, 208],
-		[82, 90, 235, 142, 12, 223, 244, 77, 177, 23, 136, 63, 27, 165, 75, 180, 221, 117, 236, 157, 40, 81, 33, 153, 130, 249, 105, 95, 138, 160, 141, 235],
+		[205, 185, 100, 17, 169, 160, 108, 204, 3, 48, 6, 68, 13, 125, 179, 14, 82, 239, 112, 86, 6, 123, 18, 164, 27, 75, 17, 6, 84, 239, 175, 78],
+		[20, 225, 22, 62, 15, 61, 25, 30, 157, 231, 254, 185, 149, 140, 86, 10, 255, 53, 94, 186, 112, 85, 246, 124, 47, 95, 87, 105, 107, 96, 93, 175],
+		[218, 71, 185, 170, 215, 134, 249, 6, 39, 75, 27, 74, 170, 29, 32, 20, 90, 163, 175, 47, 122, 212, 171, 184, 27, 190, 64, 89, 237, 208, 165, 160],
+		[159, 79, 128, 39, 186, 12, 114, 150, 48, 156, 129, 66, 248, 128, 134, 100, 21, 4, 200, 208, 25, 38, 238, 112, 148, 73, 222, 169, 47, 252, 218, 44],
 	],
 	[
-		[167, 52, 56, 226, 51, 154, 243, 99, 51, 148, 221, 169, 177, 76, 137, 199, 146, 139, 166, 136, 230, 64, 255, 254, 183, 144, 222, 86, 216, 111, 198, 44],
-		[152, 141, 118, 205, 29, 192, 85, 18, 92, 70, 153, 41, 180, 19, 196, 185, 0, 122, 49, 225, 128, 89, 219, 221, 192, 35, 245, 97, 197, 233, 21, 71],
-		[106, 69, 58, 183, 8, 60, 75, 252, 69, 202, 217, 193, 154, 183, 104, 216, 80, 102, 189, 140, 54, 234, 114, 29, 229, 198, 133, 147, 67, 131, 18, 211],
-		[255, 171, 125, 203, 59, 19, 45, 199, 25, 129, 69, 63, 88, 133, 37, 148, 173, 121, 89, 155, 12, 249, 148, 210, 153, 195, 4, 246, 244, 176, 239, 216],
+		[61, 109, 21, 115, 164, 183, 137, 155, 136, 178, 73, 167, 61, 2, 110, 89, 52, 253, 252, 56, 148, 70, 129, 177, 67, 199, 193, 245, 72, 64, 116, 7],
+		[255, 143, 34, 149, 81, 177, 72, 100, 155, 68, 26, 105, 153, 145, 248, 156, 179, 218, 29, 84, 138, 184, 111, 255, 23, 140, 42, 210, 213, 110, 212, 30],
+		[192, 133, 35, 141, 170, 93, 24, 19, 119, 9, 68, 208, 241, 44, 43, 230, 239, 225, 167, 119, 189, 45, 72, 96, 188, 92, 246, 21, 103, 28, 58, 104],
+		[150, 159, 60, 87, 91, 183, 213, 90, 100, 76, 38, 117, 25, 102, 38, 198, 16, 167, 246, 23, 242, 229, 92, 65, 233, 157, 8, 219, 120, 13, 149, 92],
 	],
 	[
-		[111, 105, 72, 20, 253, 68, 25, 176, 48, 97, 184, 175, 107, 196, 216, 186, 61, 116, 140, 229, 235, 56, 239, 124, 112, 246, 102, 93, 6, 115, 54, 209],
-		[33, 178, 57, 169, 185, 83, 113, 80, 201, 50, 15, 220, 226, 165, 32, 93, 26, 171, 170, 147, 104, 241, 36, 162, 137, 70, 18, 135, 171, 253, 233, 218],
-		[120, 100, 123, 148, 184, 44, 93, 41, 232, 109, 11, 36, 197, 100, 141, 40, 213, 54, 55, 70, 7, 34, 61, 70, 41, 202, 121, 209, 106, 227, 50, 88],
-		[108, 140, 37, 140, 32, 115, 152, 36, 96, 67, 133, 154, 80, 26, 220, 113, 158, 153, 33, 162, 98, 162, 69, 9, 225, 234, 159, 235, 163, 152, 175, 145],
+		[173, 197, 164, 22, 204, 230, 143, 197, 63, 105, 216, 108, 56, 208, 120, 53, 47, 188, 196, 139, 170, 158, 116, 103, 26, 206, 239, 119, 42, 150, 26, 146],
+		[195, 131, 142, 250, 50, 230, 113, 199, 215, 76, 248, 45, 98, 120, 194, 118, 241, 158, 131, 63, 8, 30, 227, 169, 138, 138, 141, 253, 174, 204, 192, 60],
+		[8, 10, 76, 221, 5, 8, 151, 251, 29, 98, 18, 116, 47, 151, 32, 192, 41, 228, 118, 196, 175, 203, 215, 201, 64, 173, 74, 112, 190, 229, 65, 92],
+		[220, 92, 62, 7, 102, 49, 239, 178, 220, 159, 39, 131, 2, 45, 78, 11, 249, 133, 111, 251, 129, 235, 96, 192, 177, 220, 76, 198, 108, 33, 198, 202],
 	],
 	[
-		[89, 144, 64, 125, 196, 150, 77, 10, 10, 196, 190, 35, 173, 9, 113, 35, 249, 23, 160, 165, 123, 68, 245, 165, 43, 170, 49, 91, 27, 25, 192, 128],
-		[130, 239, 39, 106, 114, 195, 71, 185, 51, 23, 180, 206, 88, 128, 98, 59, 114, 139, 169, 108, 218, 228, 231, 65, 2, 132, 166, 30, 242, 9, 9, 161],
-		[8, 187, 218, 85, 247, 202, 62, 46, 153, 37, 164, 17, 240, 29, 16, 19, 149, 16, 170, 50, 111, 227, 159, 253, 141, 65, 5, 230, 201, 196, 113, 168],
-		[37, 216, 45, 221, 23, 106, 35, 61, 185, 33, 97, 39, 208, 6, 69, 36, 208, 97, 233, 211, 235, 188, 117, 166, 161, 186, 110, 113, 140, 218, 46, 213],
+		[222, 97, 43, 141, 225, 65, 4, 120, 230, 137, 107, 113, 92, 186, 217, 12, 56, 0, 53, 236, 180, 214, 70, 47, 189, 10, 133, 140, 217, 131, 148, 132],
+		[27, 157, 98, 80, 12, 161, 64, 201, 224, 147, 79, 43, 156, 35, 23, 16, 37, 19, 203, 127, 117, 177, 168, 62, 122, 30, 222, 49, 125, 192, 61, 239],
+		[156, 31, 216, 109, 1, 149, 22, 169, 84, 156, 95, 194, 229, 143, 18, 134, 153, 253, 37, 232, 30, 229, 201, 85, 65, 103, 71, 235, 240, 96, 76, 115],
+		[95, 65, 24, 121, 63, 41, 73, 224, 162, 70, 163, 73, 97, 177, 214, 169, 156, 170, 21, 170, 248, 135, 192, 16, 88, 194, 196, 187, 15, 164, 199, 135],
 	],
 	[
-		[180, 58, 210, 153, 94, 123, 35, 118, 150, 199, 186, 190, 193, 10, 165, 130, 201, 143, 94, 72, 34, 154, 80, 45, 200, 140, 172, 210, 188, 182, 88, 111],
-		[224, 161, 146, 157, 221, 106, 124, 82, 250, 63, 146, 212, 145, 31, 37, 63, 180, 121, 79, 169, 252, 106, 115, 121, 79, 223, 189, 244, 120, 199, 122, 239],
-		[253, 195, 198, 69, 40, 225, 110, 72, 118, 207, 110, 120, 186, 123, 0, 83, 233, 255, 217, 189, 68, 82, 2, 189, 26, 67, 18, 70, 0, 192, 106, 24],
-		[217, 136, 109, 210, 112, 251, 9, 92, 75, 69, 177, 32, 183, 14, 193, 1, 65, 238, 216, 194, 85, 210, 186, 75, 223, 157, 173, 36, 20, 104, 5, 153],
+		[246, 185, 248, 42, 187, 187, 38, 127, 174, 122, 115, 91, 183, 214, 95, 223, 19, 207, 187, 19, 12, 77, 51, 196, 216, 57, 166, 62, 21, 39, 8, 7],
+		[93, 174, 167, 161, 213, 3, 185, 16, 207, 202, 22, 105, 180, 169, 15, 173, 150, 222, 30, 42, 112, 12, 111, 147, 121, 207, 226, 241, 238, 254, 215, 70],
+		[179, 62, 236, 245, 55, 157, 75, 121, 22, 61, 21, 53, 66, 194, 198, 153, 18, 93, 139, 116, 142, 65, 115, 88, 161, 166, 212, 112, 109, 62, 12, 87],
+		[123, 136, 45, 104, 48, 246, 106, 42, 54, 8, 85, 137, 193, 126, 116, 252, 43, 90, 49, 120, 78, 142, 156, 209, 96, 41, 61, 161, 32, 241, 223, 214],
 	],
 	[
-		[211, 56, 64, 225, 112, 136, 176, 12, 169, 10, 109, 181, 127, 255, 78, 249, 175, 126, 14, 19, 197, 107, 241, 182, 108, 84, 226, 206, 63, 45, 74, 221],
-		[195, 17, 241, 38, 148, 41, 166, 78, 8, 96, 135, 124, 123, 23, 101, 101, 80, 247, 252, 243, 160, 98, 37, 225, 113, 205, 224, 236, 246, 12, 184, 207],
-		[161, 206, 161, 222, 155, 177, 248, 38, 210, 36, 15, 105, 132, 147, 192, 38, 136, 0, 29, 207, 80, 237, 156, 93, 180, 151, 230, 196, 242, 126, 186, 219],
-		[93, 119, 113, 230, 170, 183, 199, 177, 115, 41, 143, 219, 22, 175, 32, 245, 241, 183, 131, 120, 227, 35, 247, 196, 34, 35, 125, 8, 98, 87, 7, 193],
+		[121, 87, 2, 49, 23, 105, 45, 220, 192, 119, 13, 39, 218, 24, 123, 252, 253, 213, 223, 178, 174, 172, 252, 94, 160, 19, 216, 195, 23, 254, 239, 163],
+		[167, 188, 208, 48, 19, 254, 58, 10, 142, 250, 205, 210, 130, 115, 46, 245, 22, 159, 121, 92, 82, 161, 253, 145, 193, 128, 232, 7, 80, 191, 107, 32],
+		[198, 22, 57, 167, 203, 195, 116, 83, 55, 42, 110, 31, 249, 55, 4, 117, 200, 149, 249, 11, 81, 194, 186, 37, 179, 21, 208, 249, 12, 79, 39, 35],
+		[84, 103, 208, 235, 184, 1, 244, 126, 178, 107, 70, 109, 141, 50, 98, 19, 68, 199, 174, 119, 154, 75, 130, 192, 6, 85, 215, 150, 242, 147, 168, 28],
 	],
 	[
-		[17, 42, 164, 9, 79, 219, 74, 27, 136, 219, 74, 22, 82, 12, 208, 194, 138, 134, 168, 113, 158, 179, 105, 19, 96, 127, 159, 175, 9, 43, 106, 1],
-		[115, 130, 160, 94, 7, 157, 166, 165, 91, 154, 43, 52, 74, 176, 6, 242, 75, 234, 40, 201, 69, 189, 26, 255, 174, 67, 152, 184, 199, 179, 54, 229],
-		[246, 177, 70, 243, 200, 34, 228, 190, 223, 217, 156, 182, 185, 198, 97, 163, 102, 96, 147, 194, 94, 82, 201, 103, 90, 72, 196, 142, 208, 105, 126, 213],
-		[215, 45, 227, 185, 46, 14, 107, 78, 124, 224, 254, 136, 111, 148, 71, 198, 206, 36, 16, 36, 175, 229, 229, 39, 87, 214, 176, 102, 93, 11, 77, 235],
+		[170, 208, 89, 144, 111, 92, 79, 84, 42, 111, 81, 203, 204, 237, 67, 41, 226, 243, 222, 194, 242, 185, 238, 195, 36, 85, 120, 106, 46, 83, 170, 32],
+		[221, 70, 21, 95, 234, 234, 42, 233, 61, 169, 230, 227, 127, 2, 245, 2, 255, 244, 206, 167, 235, 178, 19, 98, 142, 204, 20, 147, 174, 206, 253, 62],
+		[253, 176, 28, 189, 133, 74, 31, 52, 148, 154, 235, 8, 58, 158, 253, 162, 115, 51, 2, 14, 83, 35, 235, 144, 227, 37, 214, 216, 137, 241, 241, 194],
+		[69, 248, 28, 6, 119, 119, 223, 209, 8, 162, 133, 77, 65, 190, 177, 160, 16, 224, 142, 253, 4, 26, 179, 193, 23, 7, 205, 25, 21, 74, 254, 42],
 	],
 	[
-		[58, 151, 193, 72, 152, 64, 77, 213, 177, 34, 93, 56, 235, 205, 110, 255, 59, 21, 109, 127, 195, 42, 98, 250, 122, 252, 110, 236, 89, 75, 49, 156],
-		[221, 104, 30, 58, 76, 235, 76, 158, 239, 46, 92, 194, 211, 158, 26, 93, 58, 229, 27, 70, 130, 69, 49, 79, 146, 27, 195, 125, 91, 31, 229, 73],
-		[237, 106, 136, 62, 98, 34, 52, 22, 185, 113, 40, 119, 165, 237, 24, 157, 117, 67, 207, 246, 81, 200, 22, 70, 208, 38, 209, 100, 57, 214, 160, 135],
-		[75, 226, 163, 15, 64, 137, 58, 153, 253, 177, 36, 12, 211, 231, 107, 142, 214, 117, 78, 79, 115, 252, 202, 150, 249, 50, 53, 141, 180, 148, 102, 26],
+		[151, 141, 35, 107, 4, 190, 44, 238, 127, 232, 246, 74, 202, 31, 141, 96, 198, 150, 67, 33, 220, 153, 99, 93, 197, 124, 220, 158, 148, 70, 81, 37],
+		[16, 76, 34, 228, 189, 1, 75, 144, 69, 116, 125, 145, 63, 182, 99, 12, 159, 226, 87, 60, 109, 113, 59, 103, 209, 209, 210, 35, 233, 254, 197, 231],
+		[210, 98, 208, 178, 179, 156, 207, 28, 129, 69, 107, 131, 179, 146, 112, 198, 234, 95, 84, 255, 163, 143, 214, 214, 16, 153, 238, 171, 195, 231, 239, 8],
+		[78, 212, 21, 174, 50, 67, 9, 142, 116, 172, 209, 230, 219, 85, 76, 146, 125, 11, 137, 193, 200, 74, 134, 163, 162, 67, 111, 131, 171, 50, 251, 61],
 	],
 	[
-		[24, 21, 171, 96, 61, 93, 254, 225, 8, 210, 212, 17, 203, 152, 230, 228, 91, 34, 91, 95, 74, 188, 81, 97, 240, 94, 44, 81, 186, 102, 184, 15],
-		[79, 2, 235, 143, 247, 186, 226, 254, 121, 55, 175, 144, 15, 16, 165, 241, 171, 21, 89, 246, 131, 147, 99, 1, 205, 112, 49, 230, 230, 211, 178, 147],
-		[190, 159, 91, 95, 128, 207, 113, 105, 108, 222, 80, 24, 84, 118, 30, 44, 91, 208, 137, 29, 128, 11, 28, 5, 37, 127, 81, 31, 240, 173, 234, 161],
-		[253, 42, 67, 106, 155, 135, 26, 229, 63, 217, 198, 4, 7, 89, 182, 228, 181, 77, 43, 217, 242, 87, 219, 153, 42, 32, 106, 51, 161, 191, 123, 3],
+		[236, 79, 36, 55, 40, 238, 95, 88, 6, 158, 66, 9, 147, 28, 39, 92, 243, 134, 185, 36, 139, 139, 121, 61, 242, 202, 112, 150, 210, 234, 70, 23],
+		[153, 31, 159, 153, 23, 101, 193, 173, 242, 72, 84, 30, 198, 31, 147, 152, 218, 4, 52, 187, 185, 233, 56, 79, 143, 62, 138, 255, 191, 118, 221, 44],
+		[223, 57, 252, 123, 158, 6, 97, 226, 97, 19, 149, 1, 242, 199, 30, 163, 177, 9, 165, 132, 54, 50, 56, 80, 156, 241, 245, 173, 20, 148, 82, 46],
+		[14, 37, 140, 185, 136, 69, 62, 100, 239, 197, 134, 242, 151, 187, 20, 106, 118, 56, 35, 36, 38, 37, 93, 138, 185, 239, 218, 237, 0, 58, 141, 194],
 	],
 	[
-		[91, 159, 41, 255, 38, 198, 168, 170, 235, 115, 99, 58, 121, 255, 122, 9, 70, 11, 69, 8, 5, 19, 159, 140, 194, 73, 133, 113, 254, 131, 57, 216],
-		[220, 184, 150, 198, 127, 0, 176, 254, 55, 103, 114, 11, 122, 64, 69, 34, 241, 109, 234, 191, 208, 189, 93, 163, 69, 170, 252, 65, 109, 73, 242, 160],
-		[42, 34, 90, 174, 72, 67, 207, 20, 139, 173, 246, 254, 200, 163, 57, 142, 3, 231, 169, 22, 162, 74, 229, 208, 229, 31, 22, 15, 171, 128, 2, 131],
-		[107, 98, 194, 166, 210, 181, 47, 220, 185, 208, 70, 36, 244, 126, 77, 197, 71, 176, 189, 247, 174, 178, 233, 163, 18, 244, 35, 133, 180, 99, 35, 241],
+		[166, 255, 33, 122, 1, 125, 245, 59, 241, 141, 50, 39, 208, 100, 239, 250, 87, 244, 124, 155, 121, 99, 77, 166, 240, 129, 55, 148, 170, 121, 83, 1],
+		[127, 86, 114, 72, 162, 5, 21, 62, 168, 205, 130, 76, 186, 178, 48, 7, 200, 222, 193, 49, 11, 131, 40, 11, 9, 174, 44, 83, 1, 33, 202, 68],
+		[221, 145, 70, 9, 57, 139, 165, 98, 191, 59, 159, 244, 186, 237, 59, 210, 216, 201, 230, 71, 3, 71, 172, 213, 217, 253, 108, 127, 103, 216, 185, 151],
+		[220, 180, 23, 233, 129, 145, 99, 3, 140, 179, 101, 63, 139, 13, 161, 131, 88, 130, 17, 244, 152, 14, 141, 66, 12, 115, 48, 219, 194, 206, 47, 155],
 	],
 	[
-		[9, 250, 208, 160, 227, 111, 132, 124, 120, 145, 36, 17, 12, 109, 20, 154, 237, 34, 112, 215, 64, 200, 12, 18, 243, 153, 254, 61, 204, 187, 206, 216],
-		[130, 9, 222, 67, 65, 81, 177, 106, 116, 234, 217, 89, 24, 87, 148, 225, 132, 140, 211, 20, 79, 69, 139, 187, 214, 122, 212, 131, 57, 200, 167, 190],
-		[127, 79, 211, 160, 173, 46, 143, 56, 86, 241, 65, 154, 175, 5, 4, 83, 17, 134, 246, 154, 43, 14, 132, 134, 36, 70, 15, 196, 219, 144, 45, 30],
-		[114, 13, 125, 165, 202, 84, 76, 91, 180, 3, 141, 100, 109, 177, 223, 36, 199, 130, 154, 105, 180, 209, 5, 88, 195, 55, 91, 140, 117, 73, 155, 93],
+		[233, 135, 104, 36, 180, 61, 50, 118, 129, 49, 129, 242, 18, 53, 237, 19, 162, 126, 22, 81, 58, 39, 120, 143, 244, 221, 144, 194, 217, 10, 105, 18],
+		[103, 199, 251, 147, 63, 173, 71, 103, 185, 64, 157, 172, 143, 71, 139, 69, 46, 156, 87, 119, 243, 100, 93, 92, 235, 123, 95, 150, 193, 51, 1, 207],
+		[126, 190, 66, 37, 185, 83, 152, 67, 228, 206, 54, 101, 200, 131, 23, 50, 247, 97, 19, 182, 230, 113, 88, 131, 18, 250, 217, 103, 233, 11, 88, 12],
+		[145, 143, 112, 43, 217, 144, 237, 127, 208, 196, 135, 22, 15, 30, 110, 229, 84, 139, 122, 236, 160, 122, 86, 236, 134, 175, 2, 119, 249, 67, 218, 9],
 	],
 	[
-		[75, 224, 23, 222, 221, 83, 113, 209, 76, 91, 43, 113, 122, 51, 250, 22, 81, 89, 160, 153, 120, 72, 45, 4, 188, 103, 230, 4, 73, 56, 14, 28],
-		[150, 211, 178, 199, 49, 162, 117, 22, 119, 145, 180, 164, 177, 238, 167, 98, 209, 26, 169, 205, 126, 112, 54, 53, 36, 255, 45, 134, 155, 167, 105, 25],
-		[111, 249, 80, 92, 93, 211, 160, 50, 212, 27, 214, 218, 73, 133, 60, 108, 62, 80, 88, 92, 188, 59, 153, 15, 223, 25, 24, 147, 149, 108, 242, 104],
-		[206, 223, 79, 205, 132, 55, 56, 251, 120, 83, 73, 20, 222, 21, 181, 246, 156, 124, 230, 211, 106, 95, 228, 150, 228, 151, 205, 130, 4, 59, 217, 93],
+		[247, 86, 150, 99, 80, 71, 136, 249, 80, 115, 251, 212, 53, 21, 76, 201, 27, 176, 248, 186, 71, 181, 14, 103, 136, 113, 167, 4, 241, 160, 91, 201],
+		[30, 37, 42, 229, 208, 157, 253, 150, 207, 227, 47, 12, 115, 222, 56, 8, 198, 143, 183, 244, 243, 58, 126, 26, 192, 18, 226, 237, 212, 204, 229, 19],
+		[210, 130, 247, 213, 73, 72, 103, 9, 3, 192, 118, 69, 83, 3, 213, 65, 218, 231, 172, 61, 6, 209, 186, 51, 215, 53, 126, 66, 245, 124, 218, 128],
+		[118, 184, 225, 133, 152, 16, 113, 230, 79, 124, 156, 77, 104, 110, 193, 254, 136, 147, 235, 35, 6, 5, 141, 76, 145, 143, 179, 237, 184, 206, 140, 179],
 	],
 	[
-		[130, 89, 13, 34, 85, 209, 200, 141, 164, 175, 171, 130, 254, 0, 250, 252, 138, 14, 206, 226, 135, 128, 33, 148, 155, 9, 161, 204, 84, 61, 87, 37],
-		[250, 146, 118, 149, 182, 156, 194, 120, 54, 200, 155, 40, 46, 30, 115, 127, 179, 18, 55, 69, 183, 3, 234, 118, 169, 75, 237, 110, 115, 172, 199, 91],
-		[68, 48, 121, 212, 143, 73, 35, 191, 234, 245, 18, 151, 230, 35, 224, 194, 172, 156, 250, 81, 216, 113, 7, 246, 31, 92, 216, 160, 8, 22, 47, 11],
-		[228, 59, 167, 5, 232, 75, 39, 222, 222, 39, 11, 69, 27, 149, 163, 153, 188, 242, 147, 154, 40, 25, 203, 1, 63, 178, 196, 185, 114, 63, 243, 88],
+		[204, 241, 98, 123, 168, 213, 251, 99, 229, 122, 25, 130, 227, 31, 156, 42, 239, 164, 103, 171, 174, 143, 63, 77, 176, 27, 243, 34, 39, 232, 202, 221],
+		[177, 145, 84, 239, 98, 73, 17, 214, 80, 81, 85, 66, 240, 3, 129, 189, 34, 79, 61, 216, 4, 231, 124, 108, 38, 83, 249, 166, 154, 83, 130, 89],
+		[94, 126, 143, 37, 57, 250, 217, 138, 72, 63, 152, 65, 144, 129, 25, 212, 60, 67, 193, 168, 2, 217, 84, 170, 136, 206, 136, 233, 229, 209, 156, 204],
+		[237, 93, 26, 28, 220, 52, 212, 38, 43, 199, 106, 185, 138, 144, 77, 202, 61, 101, 112, 118, 173, 85, 8, 38, 207, 128, 249, 145, 181, 132, 0, 243],
 	],
 	[
-		[247, 114, 43, 50, 145, 146, 88, 213, 77, 216, 238, 140, 209, 181, 191, 151, 102, 106, 11, 188, 6, 108, 35, 225, 160, 129, 46, 130, 193, 118, 14, 169],
-		[87, 207, 52, 246, 171, 79, 222, 237, 94, 252, 141, 74, 102, 189, 128, 220, 233, 44, 117, 128, 188, 0, 111, 56, 88, 186, 135, 110, 58, 46, 235, 197],
-		[114, 69, 26, 121, 36, 226, 36, 125, 9, 176, 155, 10, 59, 62, 135, 139, 16, 112, 75, 213, 134, 241, 0, 113, 72, 113, 167, 26, 0, 220, 232, 193],
-		[119, 123, 184, 79, 143, 180, 1, 200, 172, 202, 132, 229, 118, 238, 138, 202, 92, 61, 229, 182, 45, 84, 20, 101, 141, 48, 118, 134, 198, 23, 186, 67],
+		[21, 56, 247, 117, 40, 234, 48, 39, 86, 163, 106, 182, 191, 241, 237, 17, 129, 16, 116, 248, 59, 110, 165, 42, 245, 12, 81, 118, 205, 158, 179, 129],
+		[219, 24, 64, 144, 76, 179, 197, 202, 201, 87, 44, 36, 88, 134, 133, 172, 171, 251, 49, 114, 153, 117, 236, 203, 71, 231, 201, 193, 228, 198, 71, 70],
+		[86, 74, 85, 61, 67, 19, 31, 255, 11, 164, 123, 208, 59, 212, 255, 112, 100, 127, 175, 84, 196, 48, 21, 99, 237, 69, 21, 118, 250, 61, 72, 162],
+		[185, 254, 59, 151, 200, 93, 199, 117, 57, 191, 31, 110, 124, 106, 44, 14, 124, 187, 17, 55, 250, 184, 75, 5, 111, 188, 197, 0, 239, 71, 135, 140],
 	],
 	[
-		[201, 242, 224, 220, 209, 209, 217, 32, 97, 150, 253, 179, 31, 212, 250, 241, 185, 118, 137, 79, 61, 176, 140, 51, 121, 92, 150, 16, 238, 175, 94, 171],
-		[210, 85, 10, 194, 39, 177, 61, 174, 0, 138, 10, 154, 64, 79, 173, 244, 113, 107, 229, 244, 134, 87, 30, 31, 200, 4, 217, 121, 105, 229, 65, 98],
-		[230, 61, 199, 39, 134, 166, 178, 112, 226, 26, 83, 20, 248, 115, 81, 77, 28, 132, 149, 144, 192, 252, 184, 47, 186, 137, 143, 101, 146, 138, 138, 101],
-		[15, 240, 135, 179, 137, 2, 186, 240, 82, 40, 15, 233, 19, 203, 169, 53, 10, 235, 162, 12, 25, 7, 246, 197, 84, 21, 19, 133, 204, 5, 221, 140],
+		[99, 59, 62, 134, 44, 201, 236, 107, 171, 187, 216, 196, 202, 101, 198, 109, 168, 226, 149, 124, 126, 255, 44, 113, 155, 233, 235, 149, 217, 92, 6, 40],
+		[39, 16, 186, 8, 151, 189, 150, 1, 104, 246, 184, 211, 94, 168, 19, 41, 144, 21, 114, 13, 248, 218, 105, 46, 255, 57, 242, 242, 88, 53, 63, 39],
+		[49, 74, 159, 172, 168, 223, 56, 5, 169, 74, 43, 11, 212, 19, 75, 160, 76, 254, 83, 169, 34, 141, 224, 75, 96, 167, 12, 115, 101, 35, 120, 243],
+		[166, 13, 155, 63, 221, 237, 66, 140, 17, 152, 76, 119, 10, 101, 132, 214, 61, 222, 192, 11, 30, 174, 192, 132, 142, 179, 46, 219, 36, 213, 193, 134],
 	],
 	[
-		[67, 168, 42, 93, 145, 239, 46, 173, 152, 51, 6, 231, 66, 3, 165, 220, 210, 90, 38, 164, 251, 80, 196, 19, 51, 104, 79, 208, 23, 154, 12, 192],
-		[58, 120, 165, 166, 223, 146, 252, 26, 100, 76, 241, 17, 208, 29, 5, 155, 202, 213, 142, 162, 207, 157, 27, 240, 145, 87, 108, 57, 254, 176, 191, 15],
-		[139, 60, 130, 27, 66, 246, 189, 221, 226, 134, 119, 31, 171, 76, 86, 34, 180, 142, 148, 89, 216, 213, 1, 235, 147, 185, 157, 209, 228, 101, 192, 181],
-		[127, 245, 78, 182, 223, 202, 148, 128, 81, 23, 72, 39, 57, 4, 171, 101, 252, 36, 168, 198, 95, 208, 178, 19, 127, 248, 250, 153, 74, 64, 180, 96],
+		[221, 167, 67, 171, 52, 49, 44, 24, 83, 20, 94, 66, 49, 60, 172, 219, 136, 248, 37, 216, 42, 28, 199, 223, 179, 229, 76, 231, 85, 57, 171, 189],
+		[172, 132, 122, 169, 147, 54, 154, 252, 189, 229, 208, 233, 20, 222, 212, 148, 164, 202, 70, 39, 112, 255, 177, 230, 230, 189, 170, 51, 248, 165, 67, 11],
+		[1, 202, 143, 119, 149, 110, 8, 134, 70, 169, 169, 168, 120, 197, 131, 241, 143, 94, 40, 178, 101, 189, 239, 85, 13, 77, 114, 182, 183, 124, 239, 27],
+		[7, 99, 148, 190, 23, 36, 129, 201, 243, 65, 114, 178, 92, 64, 196, 243, 117, 18, 242, 156, 118, 80, 1, 12, 193, 30, 58, 128, 51, 110, 228, 228],
 	],
 	[
-		[79, 36, 171, 95, 112, 82, 51, 106, 154, 157, 170, 82, 196, 169, 59, 78, 101, 47, 209, 153, 12, 155, 207, 105, 143, 252, 90, 163, 29, 84, 163, 63],
-		[164, 20, 18, 84, 112, 238, 58, 176, 113, 106, 182, 181, 9, 25, 190, 68, 224, 12, 31, 125, 163, 19, 237, 252, 118, 41, 51, 249, 173, 74, 105, 38],
-		[246, 223, 98, 140, 67, 220, 150, 253, 8, 228, 184, 246, 31, 36, 42, 80, 204, 14, 170, 183, 121, 255, 145, 162, 216, 29, 246, 1, 129, 237, 45, 93],
-		[91, 38, 153, 211, 129, 218, 202, 246, 62, 53, 26, 57, 108, 48, 211, 156, 148, 131, 101, 225, 125, 64, 115, 29, 7, 63, 136, 72, 5, 47, 195, 65],
+		[122, 36, 159, 117, 51, 135, 127, 117, 215, 177, 65, 203, 243, 130, 61, 194, 118, 157, 56, 145, 173, 180, 47, 194, 139, 94, 144, 69, 119, 1, 195, 143],
+		[73, 200, 89, 236, 226, 224, 50, 136, 37, 57, 62, 183, 142, 134, 239, 187, 42, 8, 6, 64, 99, 125, 205, 228, 129, 36, 13, 166, 106, 162, 108, 90],
+		[164, 221, 246, 33, 156, 69, 10, 175, 141, 31, 61, 130, 67, 241, 196, 250, 170, 243, 187, 216, 18, 238, 212, 204, 198, 181, 115, 43, 170, 99, 146, 23],
+		[161, 65, 135, 17, 90, 204, 35, 146, 219, 233, 65, 211, 78, 178, 48, 206, 241, 180, 121, 217, 30, 191, 187, 163, 47, 121, 34, 245, 19, 119, 126, 141],
 	],
 	[
-		[80, 237, 21, 160, 235, 74, 79, 60, 154, 70, 101, 8, 186, 245, 68, 251, 92, 186, 220, 77, 26, 224, 116, 233, 188, 221, 202, 18, 202, 75, 107, 169],
-		[86, 6, 13, 218, 13, 83, 219, 55, 175, 98, 214, 45, 199, 203, 83, 210, 96, 116, 47, 51, 49, 226, 119, 203, 119, 190, 197, 112, 217, 103, 26, 165],
-		[184, 14, 170, 244, 159, 66, 249, 145, 21, 242, 204, 154, 98, 143, 251, 135, 4, 8, 71, 70, 188, 4, 170, 139, 50, 49, 146, 140, 86, 165, 170, 109],
-		[250, 98, 108, 190, 241, 174, 184, 201, 41, 142, 180, 255, 34, 156, 143, 126, 213, 113, 121, 221, 150, 162, 168, 63, 101, 177, 82, 168, 124, 220, 30, 198],
+		[50, 13, 145, 172, 76, 80, 138, 24, 252, 143, 192, 81, 88, 31, 240, 10, 26, 69, 74, 30, 240, 83, 177, 172, 15, 138, 121, 0, 26, 140, 152, 4],
+		[113, 15, 131, 102, 47, 120, 190, 160, 94, 175, 100, 1, 31, 20, 0, 248, 98, 125, 118, 35, 212, 153, 91, 42, 186, 196, 91, 185, 87, 21, 67, 21],
+		[138, 49, 200, 111, 155, 236, 93, 128, 182, 34, 97, 17, 5, 211, 19, 12, 59, 200, 31, 207, 75, 131, 19, 171, 187, 25, 212, 108, 96, 206, 62, 182],
+		[138, 8, 175, 37, 223, 137, 234, 143, 94, 131, 151, 152, 13, 44, 233, 187, 149, 245, 247, 107, 157, 120, 175, 41, 14, 143, 217, 231, 27, 215, 157, 173],
 	],
 	[
-		[76, 49, 10, 125, 18, 103, 129, 249, 248, 195, 35, 206, 180, 75, 18, 12, 97, 159, 94, 175, 31, 204, 83, 244, 202, 111, 243, 109, 19, 151, 212, 187],
-		[66, 64, 121, 10, 10, 165, 188, 72, 174, 114, 214, 131, 31, 54, 78, 243, 137, 158, 147, 180, 110, 80, 253, 98, 9, 221, 130, 76, 18, 115, 173, 170],
-		[208, 111, 81, 10, 215, 171, 17, 14, 221, 74, 168, 123, 250, 80, 8, 169, 22, 14, 52, 100, 234, 128, 241, 166, 13, 66, 217, 60, 65, 74, 148, 200],
-		[121, 8, 77, 167, 44, 75, 19, 46, 143, 86, 202, 116, 161, 172, 240, 70, 89, 153, 195, 78, 33, 47, 76, 210, 123, 217, 117, 141, 104, 226, 32, 134],
+		[32, 250, 203, 183, 143, 107, 166, 41, 234, 50, 240, 164, 191, 21, 224, 183, 35, 193, 131, 24, 198, 2, 96, 47, 138, 211, 249, 178, 182, 46, 8, 98],
+		[252, 163, 78, 141, 39, 22, 220, 21, 198, 73, 15, 85, 219, 8, 210, 79, 127, 219, 34, 193, 247, 222, 72, 27, 41, 94, 83, 15, 35, 119, 203, 206],
+		[228, 241, 32, 22, 172, 109, 227, 219, 121, 238, 51, 15, 33, 63, 120, 60, 155, 141, 192, 58, 51, 121, 116, 99, 187, 14, 27, 99, 173, 207, 141, 135],
+		[92, 191, 106, 251, 127, 179, 251, 36, 218, 197, 191, 116, 185, 188, 173, 167, 37, 198, 160, 247, 19, 92, 71, 21, 120, 121, 218, 13, 57, 196, 150, 64],
 	],
 	[
-		[217, 178, 124, 213, 78, 230, 245, 98, 250, 61, 45, 236, 158, 156, 102, 253, 146, 123, 16, 161, 77, 46, 114, 85, 25, 35, 86, 53, 97, 231, 236, 145],
-		[207, 196, 161, 106, 92, 203, 49, 49, 63, 87, 198, 142, 53, 168, 64, 195, 54, 253, 128, 227, 208, 189, 45, 132, 101, 254, 201, 195, 139, 49, 56, 112],
-		[32, 201, 95, 198, 118, 126, 128, 199, 111, 240, 61, 109, 197, 223, 76, 255, 39, 41, 172, 94, 173, 50, 42, 77, 131, 0, 112, 229, 69, 112, 55, 17],
-		[70, 130, 140, 25, 244, 10, 170, 178, 198, 235, 196, 61, 195, 131, 198, 35, 60, 228, 98, 125, 208, 240, 23, 240, 247, 158, 161, 33, 181, 92, 23, 169],
+		[54, 172, 157, 4, 161, 240, 43, 81, 194, 30, 140, 172, 111, 161, 224, 192, 130, 203, 174, 114, 83, 251, 6, 65, 71, 194, 39, 103, 156, 62, 186, 99],
+		[40, 116, 209, 100, 94, 32, 155, 30, 44, 24, 145, 43, 148, 224, 64, 125, 174, 92, 7, 7, 131, 225, 181, 224, 26, 226, 132, 75, 5, 255, 169, 74],
+		[124, 113, 179, 67, 197, 7, 158, 200, 91, 44, 232, 28, 162, 33, 47, 21, 91, 196, 50, 108, 97, 56, 228, 127, 32, 8, 236, 96, 8, 141, 206, 71],
+		[28, 90, 150, 211, 9, 59, 176, 113, 211, 80, 137, 39, 255, 50, 203, 244, 204, 155, 248, 25, 64, 226, 97, 16, 113, 110, 56, 206, 19, 255, 149, 200],
 	],
 	[
-		[6, 174, 7, 210, 151, 235, 142, 121, 143, 94, 75, 4, 191, 188, 254, 74, 141, 37, 88, 213, 67, 252, 19, 183, 66, 224, 104, 118, 56, 121, 209, 101],
-		[27, 214, 235, 170, 244, 104, 154, 93, 58, 216, 17, 206, 50, 195, 80, 125, 136, 156, 179, 48, 107, 90, 227, 15, 96, 198, 17, 23, 144, 195, 40, 70],
-		[118, 209, 170, 75, 113, 252, 8, 93, 42, 132, 123, 101, 12, 152, 81, 225, 122, 240, 251, 237, 179, 170, 74, 27, 5, 98, 48, 130, 91, 0, 53, 103],
-		[222, 154, 169, 105, 230, 140, 225, 184, 166, 19, 2, 246, 240, 223, 179, 38, 98, 199, 129, 167, 56, 217, 135, 134, 209, 111, 129, 215, 246, 35, 173, 109],
+		[73, 147, 218, 161, 211, 98, 120, 17, 239, 104, 241, 107, 27, 183, 197, 39, 152, 32, 159, 135, 8, 176, 175, 141, 110, 107, 35, 237, 80, 121, 92, 179],
+		[102, 50, 120, 32, 152, 23, 240, 52, 118, 66, 214, 203, 124, 148, 7, 241, 127, 255, 81, 34, 83, 117, 83, 252, 255, 117, 47, 11, 41, 162, 255, 88],
+		[5, 187, 20, 66, 164, 149, 220, 200, 129, 225, 184, 34, 127, 173, 208, 77, 117, 12, 251, 90, 173, 140, 61, 116, 22, 208, 41, 4, 24, 141, 102, 104],
+		[174, 34, 181, 118, 157, 179, 200, 69, 24, 83, 73, 128, 78, 250, 111, 83, 151, 29, 140, 156, 237, 43, 143, 221, 77, 165, 48, 112, 89, 216, 67, 230],
 	],
 	[
-		[248, 7, 182, 9, 248, 29, 57, 107, 220, 117, 248, 81, 149, 110, 119, 64, 94, 196, 89, 74, 38, 14, 61, 34, 154, 139, 47, 222, 2, 135, 170, 203],
-		[141, 109, 248, 234, 227, 218, 181, 112, 145, 110, 53, 181, 7, 126, 15, 134, 214, 243, 89, 229, 111, 55, 188, 199, 185, 222, 160, 187, 130, 103, 91, 71],
-		[61, 214, 155, 9, 236, 57, 193, 53, 115, 97, 181, 115, 142, 133, 127, 42, 173, 185, 231, 231, 189, 22, 126, 50, 129, 61, 155, 230, 53, 78, 148, 107],
-		[41, 17, 48, 178, 224, 148, 108, 22, 54, 122, 161, 121, 73, 205, 103, 81, 17, 39, 126, 68, 160, 226, 249, 51, 127, 175, 63, 146, 185, 38, 76, 174],
+		[254, 194, 80, 195, 40, 90, 133, 0, 240, 198, 27, 93, 176, 89, 18, 106, 55, 236, 96, 53, 123, 80, 85, 32, 122, 154, 102, 238, 201, 116, 137, 173],
+		[243, 254, 213, 200, 155, 188, 166, 103, 205, 255, 63, 148, 132, 117, 235, 238, 4, 160, 235, 201, 111, 40, 78, 194, 41, 124, 80, 55, 10, 45, 69, 137],
+		[233, 169, 176, 151, 18, 41, 170, 175, 87, 104, 150, 212, 28, 47, 143, 181, 36, 212, 83, 231, 62, 92, 119, 223, 243, 158, 94, 178, 12, 66, 235, 2],
+		[11, 102, 88, 9, 170, 76, 250, 221, 56, 133, 140, 63, 85, 249, 123, 157, 63, 121, 7, 208, 38, 195, 95, 10, 227, 139, 187, 5, 62, 55, 175, 239],
 	],
 	[
-		[75, 243, 137, 209, 195, 190, 102, 33, 101, 109, 51, 247, 72, 90, 49, 239, 196, 27, 137, 32, 15, 112, 44, 164, 14, 215, 37, 127, 59, 89, 157, 45],
-		[227, 170, 9, 165, 15, 37, 251, 221, 198, 9, 5, 11, 253, 181, 92, 131, 213, 141, 145, 183, 27, 159, 252, 223, 152, 109, 8, 102, 170, 250, 232, 185],
-		[172, 110, 146, 23, 233, 178, 195, 77, 250, 124, 168, 181, 5, 216, 63, 55, 236, 69, 23, 111, 42, 102, 103, 206, 8, 143, 183, 72, 147, 165, 221, 29],
-		[176, 132, 163, 14, 54, 79, 102, 110, 239, 140, 243, 153, 142, 71, 160, 103, 144, 245, 29, 95, 64, 196, 59, 99, 52, 248, 89, 196, 41, 66, 27, 155],
+		[31, 7, 111, 210, 91, 187, 112, 71, 117, 251, 69, 206, 1, 35, 85, 58, 212, 249, 227, 97, 24, 213, 153, 229, 78, 106, 55, 60, 159, 200, 172, 13],
+		[179, 228, 76, 239, 39, 47, 150, 30, 11, 203, 1, 163, 223, 159, 56, 26, 68, 105, 36, 168, 70, 147, 42, 198, 64, 138, 163, 74, 130, 99, 77, 55],
+		[13, 224, 184, 119, 243, 50, 21, 127, 67, 34, 254, 199, 206, 34, 140, 133, 226, 69, 72, 83, 114, 171, 184, 187, 78, 55, 220, 13, 88, 14, 176, 168],
+		[54, 138, 134, 187, 153, 210, 114, 176, 69, 180, 170, 37, 232, 55, 165, 22, 180, 230, 86, 18, 235, 229, 68, 9, 217, 251, 113, 100, 153, 34, 145, 169],
 	],
 	[
-		[138, 195, 170, 169, 180, 115, 25, 196, 43, 127, 185, 236, 123, 99, 63, 185, 175, 33, 6, 173, 147, 113, 116, 121, 130, 43, 92, 183, 70, 149, 191, 204],
-		[217, 222, 26, 121, 66, 102, 226, 25, 166, 35, 137, 179, 142, 83, 46, 244, 7, 187, 181, 46, 178, 104, 199, 204, 138, 248, 158, 41, 148, 216, 73, 49],
-		[45, 241, 181, 255, 184, 142, 2, 1, 27, 75, 192, 16, 34, 108, 135, 99, 205, 102, 98, 222, 5, 219, 114, 187, 97, 158, 18, 252, 22, 174, 233, 93],
-		[221, 66, 75, 201, 255, 137, 81, 39, 89, 176, 29, 210, 167, 15, 209, 94, 124, 58, 51, 177, 89, 44, 168, 175, 142, 132, 197, 62, 91, 206, 250, 39],
+		[255, 197, 148, 204, 84, 95, 165, 227, 241, 251, 131, 69, 108, 127, 201, 14, 85, 243, 223, 197, 1, 33, 150, 42, 208, 140, 254, 208, 205, 204, 204, 73],
+		[75, 99, 79, 207, 137, 64, 2, 117, 34, 101, 29, 45, 11, 97, 222, 214, 166, 163, 74, 200, 46, 89, 167, 135, 118, 181, 17, 9, 112, 149, 72, 65],
+		[141, 238, 5, 250, 225, 145, 138, 55, 112, 95, 113, 44, 233, 11, 34, 23, 46, 25, 90, 80, 59, 109, 184, 126, 252, 58, 252, 244, 111, 231, 210, 41],
+		[120, 220, 18, 37, 220, 230, 102, 53, 200, 171, 234, 191, 118, 174, 65, 140, 222, 39, 142, 254, 83, 246, 106, 208, 243, 0, 237, 104, 84, 243, 230, 4],
 	],
 	[
-		[69, 69, 128, 243, 55, 17, 81, 55, 77, 75, 216, 223, 85, 132, 105, 53, 205, 92, 232, 104, 18, 148, 133, 124, 26, 179, 118, 60, 34, 240, 251, 139],
-		[175, 192, 119, 164, 88, 141, 15, 80, 137, 91, 233, 66, 51, 92, 12, 74, 50, 223, 17, 163, 221, 238, 85, 119, 74, 135, 232, 60, 58, 247, 0, 73],
-		[85, 171, 14, 33, 9, 152, 170, 140, 200, 254, 202, 144, 90, 111, 68, 232, 196, 74, 152, 246, 121, 66, 186, 18, 182, 19, 180, 22, 101, 206, 170, 47],
-		[145, 97, 226, 51, 10, 178, 158, 43, 42, 250, 74, 157, 203, 11, 113, 197, 8, 196, 209, 104, 161, 32, 38, 52, 244, 202, 100, 192, 112, 158, 148, 158],
+		[222, 148, 155, 236, 154, 69, 123, 217, 77, 102, 133, 162, 98, 177, 147, 67, 247, 146, 226, 201, 103, 233, 17, 250, 220, 1, 148, 131, 211, 147, 203, 174],
+		[148, 231, 87, 159, 132, 149, 32, 250, 4, 210, 123, 70, 203, 165, 159, 25, 230, 158, 137, 159, 195, 57, 4, 156, 41, 46, 183, 141, 144, 133, 252, 195],
+		[50, 69, 123, 8, 167, 108, 3, 129, 189, 214, 150, 216, 179, 118, 114, 7, 247, 70, 181, 116, 88, 155, 113, 228, 253, 140, 249, 184, 181, 135, 9, 69],
+		[243, 165, 25, 8, 8, 209, 212, 255, 78, 50, 180, 137, 240, 41, 251, 207, 117, 21, 69, 200, 188, 21, 148, 31, 39, 128, 36, 222, 41, 178, 169, 112],
 	],
 	[
-		[54, 224, 146, 32, 56, 181, 151, 194, 53, 41, 16, 225, 176, 176, 128, 141, 2, 180, 217, 180, 98, 104, 219, 89, 36, 179, 249, 221, 114, 20, 238, 178],
-		[226, 167, 154, 26, 69, 109, 169, 183, 28, 146, 191, 191, 233, 65, 35, 11, 109, 37, 42, 64, 68, 6, 116, 78, 95, 235, 223, 18, 114, 191, 203, 55],
-		[159, 127, 139, 68, 138, 208, 128, 149, 23, 18, 96, 194, 71, 195, 117, 224, 54, 153, 54, 127, 125, 43, 180, 77, 209, 23, 120, 86, 65, 29, 53, 135],
-		[145, 223, 245, 68, 249, 118, 23, 3, 67, 110, 201, 135, 239, 184, 113, 246, 42, 132, 212, 101, 87, 92, 239, 94, 133, 80, 50, 44, 255, 233, 9, 154],
+		[188, 251, 154, 99, 130, 211, 172, 115, 42, 255, 135, 248, 103, 161, 214, 243, 90, 208, 73, 248, 87, 39, 142, 233, 208, 142, 90, 66, 95, 191, 160, 154],
+		[120, 187, 90, 201, 206, 245, 42, 234, 83, 243, 86, 10, 87, 185, 244, 2, 30, 129, 158, 113, 246, 82, 161, 227, 237, 148, 4, 153, 224, 217, 24, 113],
+		[183, 100, 153, 253, 143, 148, 72, 81, 24, 89, 126, 166, 89, 233, 243, 190, 88, 235, 148, 121, 82, 110, 238, 153, 166, 47, 196, 21, 239, 96, 26, 25],
+		[186, 103, 77, 139, 42, 173, 0, 25, 12, 203, 158, 133, 115, 241, 99, 94, 150, 28, 251, 197, 33, 99, 62, 203, 208, 123, 139, 76, 21, 195, 57, 214],
 	],
 	[
-		[213, 89, 200, 243, 60, 173, 220, 255, 239, 62, 163, 224, 244, 226, 104, 59, 178, 102, 183, 133, 56, 31, 241, 96, 72, 247, 25, 87, 159, 138, 83, 13],
-		[47, 150, 217, 160, 162, 58, 206, 84, 230, 102, 175, 76, 40, 16, 233, 174, 107, 11, 57, 26, 71, 40, 147, 93, 24, 16, 31, 64, 16, 121, 107, 233],
-		[24, 76, 164, 2, 139, 237, 220, 99, 48, 115, 164, 122, 85, 117, 89, 154, 155, 223, 134, 71, 77, 207, 123, 100, 25, 6, 116, 140, 97, 225, 119, 111],
-		[16, 126, 180, 235, 223, 15, 140, 43, 29, 135, 160, 166, 200, 189, 117, 40, 97, 132, 126, 190, 94, 181, 226, 75, 130, 17, 221, 205, 156, 100, 171, 196],
+		[22, 68, 218, 130, 156, 74, 254, 106, 251, 111, 85, 210, 33, 162, 66, 246, 27, 218, 208, 62, 66, 216, 60, 133, 121, 188, 52, 12, 249, 132, 175, 200],
+		[35, 61, 57, 185, 205, 186, 142, 58, 130, 169, 86, 190, 64, 132, 159, 155, 59, 202, 42, 32, 250, 2, 213, 137, 107, 7, 17, 176, 65, 24, 148, 131],
+		[224, 153, 144, 7, 163, 191, 134, 146, 40, 194, 110, 12, 85, 22, 105, 134, 152, 109, 74, 112, 71, 157, 159, 54, 98, 90, 117, 210, 225, 230, 32, 228],
+		[228, 88, 228, 90, 203, 152, 119, 30, 16, 128, 218, 71, 198, 179, 32, 91, 230, 85, 207, 122, 132, 97, 202, 49, 184, 53, 204, 157, 64, 227, 189, 195],
 	],
 	[
-		[80, 112, 174, 167, 30, 241, 173, 20, 104, 141, 154, 204, 57, 76, 157, 123, 91, 0, 176, 139, 142, 24, 222, 182, 61, 217, 179, 30, 26, 39, 84, 215],
-		[6, 221, 81, 16, 78, 185, 105, 16, 241, 55, 195, 184, 159, 134, 36, 230, 39, 28, 132, 93, 116, 112, 214, 139, 50, 117, 123, 181, 155, 108, 156, 235],
-		[160, 234, 37, 113, 57, 58, 255, 174, 6, 102, 67, 74, 50, 243, 70, 145, 190, 208, 163, 114, 105, 105, 48, 3, 108, 244, 88, 229, 252, 86, 210, 214],
-		[226, 252, 18, 146, 250, 53, 34, 208, 17, 164, 175, 56, 151, 187, 161, 23, 159, 187, 14, 134, 183, 65, 131, 140, 4, 24, 209, 122, 132, 83, 41, 165],
+		[104, 155, 95, 189, 77, 173, 183, 219, 99, 242, 232, 217, 230, 194, 23, 226, 191, 242, 203, 74, 127, 141, 213, 130, 129, 240, 74, 106, 203, 180, 193, 64],
+		[9, 8, 191, 206, 174, 218, 241, 95, 225, 8, 49, 177, 66, 12, 178, 133, 80, 202, 41, 22, 60, 237, 199, 165, 72, 72, 110, 238, 165, 159, 32, 218],
+		[101, 118, 181, 174, 120, 75, 239, 76, 18, 2, 46, 8, 149, 59, 20, 183, 221, 29, 0, 244, 136, 227, 77, 40, 73, 124, 171, 231, 93, 222, 89, 40],
+		[130, 97, 79, 133, 22, 134, 66, 224, 79, 18, 2, 156, 22, 178, 26, 37, 148, 218, 157, 31, 94, 73, 54, 65, 83, 33, 26, 22, 242, 234, 113, 221],
 	],
 	[
-		[240, 164, 24, 131, 67, 140, 185, 246, 185, 30, 231, 223, 99, 248, 103, 213, 240, 170, 110, 51, 189, 199, 125, 112, 167, 124, 245, 34, 204, 212, 1, 159],
-		[109, 41, 157, 116, 215, 86, 105, 221, 179, 13, 47, 145, 18, 149, 66, 242, 184, 54, 134, 218, 141, 50, 15, 188, 183, 239, 149, 208, 72, 172, 41, 148],
-		[68, 255, 179, 205, 158, 19, 20, 150, 26, 154, 196, 83, 153, 1, 26, 81, 221, 64, 122, 61, 213, 205, 139, 32, 102, 94, 94, 51, 19, 112, 24, 4],
-		[225, 229, 84, 16, 11, 192, 143, 81, 96, 196, 192, 143, 191, 36, 38, 6, 239, 37, 23, 78, 46, 19, 242, 107, 57, 43, 238, 88, 50, 247, 209, 88],
+		[34, 61, 148, 217, 114, 138, 0, 208, 38, 56, 69, 189, 191, 200, 48, 194, 71, 136, 131, 132, 192, 143, 203, 111, 245, 109, 181, 212, 209, 7, 183, 200],
+		[142, 204, 26, 36, 79, 82, 106, 179, 83, 96, 244, 24, 18, 128, 142, 80, 161, 28, 240, 40, 150, 199, 221, 87, 209, 62, 35, 6, 19, 6, 144, 198],
+		[188, 32, 87, 224, 1, 33, 111, 244, 112, 151, 181, 127, 230, 113, 226, 166, 206, 9, 112, 187, 236, 230, 53, 87, 117, 15, 122, 188, 149, 145, 94, 227],
+		[124, 61, 107, 236, 47, 155, 100, 138, 185, 23, 161, 206, 15, 80, 117, 100, 185, 112, 108, 245, 36, 239, 197, 143, 82, 103, 147, 15, 37, 25, 215, 102],
 	],
 	[
-		[150, 120, 203, 187, 171, 58, 178, 101, 174, 73, 111, 127, 178, 181, 9, 12, 6, 20, 223, 71, 225, 61, 187, 65, 144, 170, 104, 190, 210, 44, 236, 164],
-		[78, 94, 132, 130, 139, 94, 60, 124, 254, 117, 131, 197, 150, 173, 187, 166, 41, 226, 214, 203, 189, 80, 149, 79, 206, 223, 57, 146, 64, 97, 80, 184],
-		[117, 50, 3, 246, 99, 180, 222, 57, 17, 21, 109, 15, 99, 254, 79, 254, 52, 192, 213, 175, 64, 248, 233, 57, 68, 167, 8, 86, 193, 230, 238, 168],
-		[230, 216, 250, 172, 184, 76, 150, 113, 248, 161, 216, 97, 211, 116, 54, 18, 26, 237, 22, 87, 194, 53, 75, 39, 189, 205, 113, 213, 101, 92, 130, 243],
+		[48, 92, 103, 247, 74, 15, 211, 91, 237, 61, 237, 161, 73, 9, 174, 44, 66, 128, 251, 197, 127, 49, 142, 133, 62, 33, 252, 59, 248, 62, 28, 157],
+		[130, 202, 245, 69, 157, 89, 246, 10, 107, 175, 219, 111, 58, 190, 192, 37, 137, 177, 153, 242, 170, 38, 160, 121, 201, 209, 47, 133, 22, 233, 79, 16],
+		[255, 97, 51, 23, 17, 127, 98, 214, 56, 159, 127, 243, 125, 60, 219, 65, 53, 33, 7, 249, 14, 6, 83, 202, 161, 116, 92, 205, 223, 136, 167, 96],
+		[183, 114, 92, 127, 248, 115, 85, 13, 12, 147, 192, 106, 138, 11, 144, 216, 72, 198, 183, 158, 205, 192, 10, 42, 57, 45, 66, 162, 208, 69, 174, 53],
 	],
 	[
-		[6, 94, 147, 6, 139, 27, 210, 121, 29, 15, 28, 193, 245, 158, 188, 171, 239, 50, 75, 246, 246, 39, 110, 50, 0, 179, 178, 47, 54, 129, 180, 208],
-		[68, 156, 147, 25, 13, 60, 200, 56, 123, 189, 38, 73, 92, 118, 205, 187, 20, 241, 91, 103, 224, 230, 113, 88, 226, 62, 131, 173, 154, 135, 199, 228],
-		[230, 108, 64, 4, 127, 242, 16, 178, 236, 29, 229, 130, 118, 248, 121, 100, 23, 90, 28, 30, 202, 17, 212, 134, 142, 70, 109, 173, 177, 60, 67, 108],
-		[99, 248, 200, 98, 169, 165, 98, 15, 65, 220, 91, 104, 131, 74, 200, 94, 228, 117, 197, 177, 186, 40, 61, 60, 186, 28, 87, 101, 75, 159, 186, 219],
+		[217, 1, 243, 191, 130, 147, 215, 225, 155, 111, 46, 216, 83, 19, 209, 183, 123, 138, 167, 190, 86, 195, 208, 105, 237, 51, 246, 222, 220, 87, 123, 134],
+		[191, 202, 207, 111, 242, 40, 246, 159, 178, 227, 252, 138, 153, 10, 142, 15, 153, 155, 132, 49, 65, 39, 21, 171, 173, 206, 96, 183, 252, 239, 209, 34],
+		[68, 249, 248, 24, 227, 179, 225, 218, 142, 35, 55, 240, 158, 244, 8, 218, 17, 202, 118, 224, 64, 122, 142, 132, 53, 15, 198, 71, 169, 165, 2, 101],
+		[230, 68, 218, 211, 232, 213, 73, 234, 222, 14, 210, 174, 168, 40, 38, 241, 107, 13, 252, 138, 15, 39, 223, 1, 118, 214, 88, 23, 150, 254, 212, 221],
 	],
 	[
-		[149, 69, 15, 52, 138, 219, 0, 234, 132, 208, 207, 170, 40, 133, 46, 99, 105, 210, 64, 179, 3, 82, 166, 214, 30, 238, 236, 223, 148, 85, 148, 217],
-		[57, 70, 170, 3, 26, 175, 172, 178, 237, 155, 188, 125, 225, 35, 155, 126, 189, 61, 167, 69, 196, 253, 169, 143, 65, 243, 207, 152, 77, 163, 6, 77],
-		[124, 71, 152, 213, 241, 41, 201, 247, 190, 83, 27, 241, 113, 2, 188, 77, 63, 1, 198, 91, 255, 219, 106, 191, 190, 27, 228, 169, 188, 157, 74, 195],
-		[162, 53, 78, 195, 72, 0, 43, 126, 156, 103, 251, 174, 197, 20, 2, 123, 81, 248, 200, 14, 144, 231, 247, 150, 159, 246, 253, 247, 30, 192, 244, 32],
+		[54, 89, 119, 79, 172, 10, 153, 220, 172, 131, 185, 218, 108, 209, 145, 110, 239, 12, 98, 168, 82, 229, 140, 73, 29, 67, 226, 97, 206, 220, 142, 231],
+		[63, 255, 215, 255, 213, 10, 111, 126, 155, 230, 214, 222, 195, 123, 206, 6, 6, 198, 67, 183, 161, 95, 168, 111, 68, 88, 16, 224, 63, 105, 241, 149],
+		[54, 104, 42, 106, 5, 61, 29, 62, 238, 159, 37, 25, 159, 7, 190, 86, 196, 131, 87, 27, 249, 220, 116, 158, 16, 15, 27, 57, 215, 231, 197, 237],
+		[6, 82, 170, 38, 222, 1, 215, 2, 76, 86, 252, 43, 122, 110, 60, 201, 184, 66, 214, 74, 76, 165, 140, 173, 69, 145, 153, 213, 125, 43, 56, 82],
 	],
 	[
-		[31, 249, 142, 133, 36, 130, 46, 78, 196, 211, 53, 223, 67, 225, 135, 74, 155, 66, 38, 158, 112, 128, 220, 138, 36, 116, 142, 166, 55, 170, 143, 40],
-		[113, 143, 95, 145, 235, 95, 87, 45, 228, 161, 187, 84, 93, 59, 169, 83, 226, 73, 108, 230, 5, 242, 109, 65, 79, 149, 95, 217, 128, 47, 181, 40],
-		[73, 213, 164, 66, 74, 210, 86, 122, 241, 85, 193, 35, 132, 20, 89, 134, 134, 204, 243, 203, 143, 134, 131, 3, 25, 15, 8, 219, 149, 252, 118, 234],
-		[177, 216, 120, 177, 153, 33, 164, 82, 249, 106, 192, 42, 101, 247, 4, 164, 79, 48, 172, 47, 106, 158, 190, 165, 108, 108, 247, 33, 107, 203, 172, 181],
+		[4, 229, 40, 191, 247, 224, 148, 59, 252, 240, 245, 101, 136, 100, 103, 29, 11, 36, 21, 94, 214, 63, 105, 178, 145, 99, 248, 214, 233, 8, 104, 11],
+		[235, 229, 17, 146, 73, 252, 50, 199, 6, 24, 195, 39, 201, 103, 19, 224, 242, 64, 32, 253, 18, 166, 221, 71, 39, 35, 41, 89, 179, 14, 130, 198],
+		[146, 226, 172, 213, 94, 242, 217, 38, 39, 44, 100, 111, 117, 62, 228, 88, 219, 148, 211, 248, 6, 75, 8, 128, 9, 173, 185, 82, 135, 17, 90, 4],
+		[179, 242, 108, 130, 136, 233, 222, 41, 161, 189, 232, 182, 60, 198, 70, 94, 42, 167, 123, 122, 37, 234, 127, 104, 151, 61, 216, 208, 121, 192, 120, 99],
 	],
 	[
-		[8, 89, 112, 86, 117, 88, 100, 107, 193, 67, 6, 107, 19, 193, 166, 9, 171, 56, 45, 118, 150, 251, 44, 198, 20, 252, 71, 41, 6, 123, 186, 233],
-		[135, 123, 227, 223, 98, 255, 79, 75, 65, 216, 144, 197, 197, 209, 224, 75, 53, 39, 60, 84, 229, 244, 229, 212, 204, 20, 155, 164, 85, 51, 48, 28],
-		[242, 60, 50, 99, 211, 244, 1, 90, 179, 159, 111, 239, 240, 243, 63, 154, 148, 142, 244, 220, 22, 106, 173, 39, 136, 169, 149, 220, 52, 71, 223, 140],
-		[187, 66, 140, 208, 5, 86, 140, 77, 205, 78, 247, 45, 77, 184, 86, 158, 205, 175, 143, 190, 245, 127, 87, 53, 183, 156, 226, 143, 218, 102, 127, 193],
+		[50, 6, 255, 21, 47, 211, 166, 11, 69, 23, 202, 248, 211, 13, 84, 179, 231, 31, 211, 253, 5, 47, 211, 23, 236, 169, 145, 57, 186, 31, 241, 199],
+		[101, 170, 97, 80, 156, 63, 211, 106, 184, 160, 208, 145, 68, 73, 231, 63, 243, 208, 161, 187, 216, 18, 108, 229, 75, 75, 151, 189, 19, 44, 38, 33],
+		[231, 100, 80, 78, 116, 92, 1, 14, 101, 142, 166, 181, 237, 73, 181, 205, 43, 60, 157, 134, 168, 109, 200, 140, 3, 211, 183, 140, 76, 211, 193, 68],
+		[44, 115, 36, 201, 175, 95, 227, 191, 50, 92, 96, 222, 157, 244, 105, 177, 109, 139, 244, 112, 221, 89, 167, 229, 44, 88, 231, 255, 190, 239, 60, 102],
 	],
 	[
-		[191, 247, 237, 180, 136, 222, 85, 217, 77, 143, 177, 105, 80, 85, 226, 230, 88, 248, 166, 55, 32, 48, 152, 26, 85, 159, 107, 27, 127, 93, 70, 89],
-		[251, 173, 2, 34, 255, 92, 230, 100, 64, 46, 144, 154, 164, 213, 135, 53, 3, 223, 248, 115, 138, 175, 54, 25, 14, 194, 155, 8, 102, 98, 24, 86],
-		[37, 64, 124, 126, 75, 80, 132, 21, 93, 106, 207, 121, 122, 52, 164, 67, 40, 221, 47, 86, 55, 58, 3, 206, 30, 42, 133, 207, 224, 59, 114, 95],
-		[155, 61, 182, 169, 125, 159, 145, 49, 197, 14, 50, 225, 165, 43, 229, 81, 163, 111, 118, 213, 13, 220, 191, 22, 225, 44, 120, 237, 179, 5, 125, 61],
+		[36, 88, 79, 112, 247, 187, 34, 207, 231, 153, 245, 136, 230, 215, 197, 154, 243, 251, 28, 251, 99, 2, 57, 29, 161, 118, 224, 107, 12, 253, 163, 31],
+		[223, 77, 162, 248, 247, 110, 74, 168, 155, 152, 26, 242, 106, 79, 212, 55, 132, 28, 55, 232, 134, 244, 154, 160, 149, 118, 116, 34, 11, 29, 217, 219],
+		[157, 45, 233, 157, 185, 96, 143, 255, 56, 15, 195, 175, 87, 15, 5, 218, 233, 140, 40, 233, 10, 221, 153, 19, 212, 66, 32, 243, 124, 103, 231, 22],
+		[98, 208, 220, 43, 189, 254, 193, 24, 202, 128, 75, 208, 121, 229, 217, 69, 10, 234, 2, 195, 93, 226, 152, 153, 230, 223, 80, 26, 151, 201, 102, 241],
 	],
 	[
-		[7, 193, 53, 226, 184, 35, 68, 228, 167, 218, 45, 55, 228, 133, 90, 70, 39, 119, 61, 192, 68, 212, 253, 98, 25, 195, 43, 144, 88, 11, 29, 131],
-		[94, 152, 96, 234, 104, 222, 227, 158, 168, 169, 163, 94, 25, 243, 159, 167, 11, 28, 198, 207, 3, 226, 67, 11, 186, 98, 226, 210, 198, 139, 76, 65],
-		[34, 144, 49, 95, 154, 150, 143, 52, 24, 234, 183, 149, 240, 26, 250, 86, 171, 134, 45, 253, 23, 6, 176, 95, 248, 161, 171, 139, 114, 10, 99, 105],
-		[188, 10, 63, 209, 159, 237, 5, 80, 5, 192, 157, 94, 46, 185, 53, 132, 160, 55, 192, 50, 122, 139, 93, 217, 128, 1, 83, 100, 223, 130, 19, 172],
+		[124, 134, 207, 70, 254, 146, 110, 255, 10, 60, 231, 56, 230, 34, 83, 10, 177, 132, 204, 25, 10, 26, 109, 245, 76, 231, 202, 90, 2, 74, 142, 142],
+		[189, 13, 0, 219, 7, 124, 165, 244, 210, 201, 88, 241, 148, 84, 248, 231, 186, 236, 105, 192, 29, 232, 11, 210, 143, 66, 15, 30, 155, 134, 132, 17],
+		[91, 233, 120, 146, 21, 40, 222, 227, 161, 1, 61, 7, 231, 133, 217, 115, 130, 41, 243, 187, 107, 104, 32, 222, 71, 249, 79, 59, 37, 153, 3, 17],
+		[145, 231, 149, 140, 133, 3, 155, 113, 172, 37, 226, 138, 151, 123, 237, 191, 192, 201, 96, 63, 197, 235, 23, 163, 110, 178, 40, 235, 6, 101, 170, 87],
 	],
 	[
-		[200, 183, 161, 87, 68, 204, 58, 154, 148, 232, 13, 114, 211, 15, 39, 241, 20, 243, 249, 223, 3, 90, 210, 40, 184, 212, 113, 248, 122, 143, 75, 189],
-		[173, 18, 77, 35, 176, 87, 180, 126, 188, 78, 16, 172, 161, 53, 144, 169, 116, 152, 178, 148, 240, 76, 252, 52, 106, 121, 15, 172, 198, 137, 251, 29],
-		[117, 50, 28, 171, 23, 251, 57, 116, 16, 239, 149, 238, 246, 27, 122, 67, 143, 97, 118, 145, 247, 216, 210, 183, 155, 119, 185, 202, 173, 73, 6, 235],
-		[145, 81, 65, 115, 48, 41, 191, 208, 223, 229, 226, 110, 52, 33, 79, 182, 135, 52, 181, 138, 138, 234, 101, 147, 42, 2, 118, 212, 172, 185, 9, 53],
+		[80, 131, 166, 89, 33, 126, 141, 136, 0, 37, 57, 8, 93, 51, 206, 54, 85, 17, 190, 191, 248, 29, 154, 217, 111, 89, 14, 174, 43, 24, 215, 114],
+		[212, 39, 169, 159, 16, 214, 31, 19, 136, 17, 37, 254, 11, 81, 3, 210, 147, 163, 196, 202, 48, 198, 111, 227, 211, 170, 192, 100, 249, 89, 36, 238],
+		[222, 5, 134, 224, 228, 38, 129, 81, 94, 153, 44, 10, 88, 28, 119, 198, 208, 192, 183, 185, 43, 201, 207, 241, 220, 246, 192, 132, 72, 6, 100, 88],
+		[203, 133, 195, 89, 34, 10, 119, 53, 59, 9, 217, 78, 15, 203, 221, 1, 18, 83, 222, 39, 215, 43, 4, 100, 116, 201, 83, 71, 67, 184, 146, 111],
 	],
 	[
-		[211, 88, 10, 12, 183, 219, 174, 161, 215, 170, 40, 116, 22, 210, 249, 229, 174, 232, 94, 111, 77, 103, 41, 17, 249, 190, 192, 107, 193, 215, 18, 210],
-		[176, 69, 237, 237, 194, 54, 7, 180, 180, 129, 21, 45, 144, 174, 15, 50, 171, 1, 42, 39, 138, 197, 50, 54, 151, 82, 6, 205, 183, 129, 177, 109],
-		[195, 107, 183, 180, 22, 3, 66, 176, 49, 150, 164, 18, 97, 22, 72, 5, 128, 116, 243, 164, 78, 90, 244, 187, 231, 113, 189, 120, 201, 121, 247, 179],
-		[11, 215, 139, 42, 73, 29, 158, 175, 78, 220, 179, 206, 201, 90, 250, 214, 230, 22, 49, 202, 81, 119, 150, 16, 166, 137, 222, 34, 32, 100, 113, 218],
+		[48, 198, 47, 244, 4, 158, 66, 174, 189, 97, 176, 10, 30, 194, 47, 248, 137, 8, 116, 113, 249, 83, 253, 240, 114, 128, 241, 71, 42, 5, 71, 86],
+		[180, 15, 48, 157, 175, 214, 178, 239, 176, 181, 174, 14, 20, 68, 133, 191, 63, 83, 59, 252, 110, 53, 64, 144, 222, 167, 229, 219, 143, 5, 135, 143],
+		[128, 90, 164, 209, 209, 126, 46, 250, 53, 52, 91, 247, 245, 168, 167, 2, 138, 103, 26, 13, 169, 144, 32, 28, 183, 77, 51, 14, 220, 96, 188, 3],
+		[63, 233, 2, 73, 87, 252, 121, 47, 159, 172, 67, 39, 153, 208, 239, 146, 90, 151, 115, 210, 119, 84, 51, 148, 249, 126, 136, 152, 199, 152, 34, 115],
 	],
 	[
-		[164, 29, 31, 65, 119, 161, 0, 29, 159, 35, 152, 156, 112, 6, 13, 215, 197, 27, 107, 126, 228, 146, 75, 226, 37, 237, 254, 146, 14, 23, 99, 210],
-		[210, 40, 71, 157, 252, 60, 0, 92, 223, 74, 246, 10, 36, 126, 243, 143, 128, 181, 131, 33, 28, 15, 7, 120, 51, 70, 167, 144, 66, 229, 185, 198],
-		[32, 87, 189, 108, 98, 236, 163, 10, 153, 4, 167, 75, 248, 73, 195, 124, 82, 235, 129, 133, 10, 55, 230, 251, 226, 158, 31, 40, 58, 173, 121, 157],
-		[217, 180, 203, 30, 191, 37, 26, 226, 65, 79, 148, 137, 3, 96, 154, 48, 30, 0, 253, 158, 12, 157, 188, 15, 10, 215, 118, 240, 231, 201, 196, 132],
+		[152, 38, 143, 244, 86, 150, 108, 43, 37, 182, 16, 120, 211, 37, 128, 34, 119, 110, 102, 86, 218, 115, 48, 64, 248, 243, 226, 192, 124, 229, 67, 236],
+		[110, 59, 83, 243, 14, 77, 150, 104, 154, 251, 6, 29, 129, 140, 20, 104, 143, 247, 98, 16, 250, 181, 125, 246, 207, 94, 58, 82, 97, 165, 157, 146],
+		[13, 16, 100, 151, 239, 66, 145, 163, 130, 53, 224, 23, 61, 201, 171, 218, 44, 254, 98, 29, 140, 6, 167, 7, 255, 45, 99, 203, 164, 111, 162, 7],
+		[252, 208, 187, 192, 38, 168, 155, 78, 182, 34, 89, 154, 89, 179, 155, 251, 2, 6, 135, 106, 57, 23, 124, 212, 15, 123, 154, 46, 17, 86, 172, 66],
 	],
 	[
-		[175, 216, 56, 124, 201, 4, 82, 200, 194, 115, 4, 115, 90, 248, 161, 240, 230, 19, 252, 75, 250, 12, 179, 120, 90, 118, 117, 178, 166, 235, 148, 151],
-		[4, 91, 205, 216, 26, 45, 101, 22, 15, 116, 250, 76, 161, 92, 12, 59, 128, 147, 166, 227, 206, 65, 25, 232, 127, 142, 163, 253, 141, 165, 145, 54],
-		[7, 11, 111, 218, 216, 92, 155, 114, 113, 234, 145, 80, 253, 14, 55, 224, 27, 5, 216, 12, 60, 17, 159, 160, 105, 115, 154, 158, 86, 130, 72, 5],
-		[13, 150, 154, 63, 39, 205, 220, 81, 52, 188, 158, 153, 137, 96, 84, 53, 178, 226, 129, 3, 68, 231, 248, 218, 86, 43, 184, 64, 11, 216, 255, 106],
+		[152, 17, 213, 221, 210, 222, 62, 94, 6, 134, 186, 57, 240, 206, 14, 236, 4, 125, 53, 31, 101, 152, 35, 3, 132, 89, 209, 181, 84, 178, 46, 128],
+		[79, 6, 195, 146, 80, 238, 170, 149, 44, 249, 129, 205, 121, 61, 247, 137, 37, 246, 150, 97, 36, 86, 103, 218, 136, 231, 180, 122, 253, 119, 24, 195],
+		[128, 133, 85, 137, 9, 6, 217, 74, 174, 106, 112, 83, 39, 237, 97, 106, 210, 7, 29, 142, 106, 184, 57, 255, 101, 106, 69, 76, 171, 253, 164, 33],
+		[73, 39, 2, 27, 218, 86, 152, 60, 44, 65, 226, 168, 14, 92, 141, 175, 159, 170, 158, 114, 190, 139, 182, 119, 5, 163, 128, 6, 169, 142, 92, 221],
 	],
 	[
-		[36, 151, 218, 79, 181, 214, 218, 217, 114, 75, 49, 193, 138, 196, 66, 40, 47, 3, 198, 12, 93, 217, 181, 213, 1, 64, 253, 60, 73, 242, 32, 3],
-		[102, 30, 108, 201, 44, 242, 12, 18, 145, 110, 50, 135, 23, 231, 235, 244, 214, 251, 26, 25, 3, 2, 97, 171, 212, 110, 249, 109, 96, 173, 53, 59],
-		[61, 111, 120, 222, 198, 0, 245, 248, 44, 76, 245, 124, 24, 6, 133, 153, 86, 23, 93, 76, 31, 223, 228, 147, 109, 53, 202, 24, 232, 86, 21, 232],
-		[78, 208, 185, 124, 151, 49, 0, 108, 130, 117, 69, 167, 170, 75, 100, 175, 227, 230, 210, 152, 107, 22, 46, 14, 124, 45, 89, 17, 94, 100, 209, 206],
+		[159, 10, 143, 61, 241, 214, 103, 232, 248, 141, 172, 63, 111, 53, 26, 71, 213, 66, 204, 93, 213, 125, 90, 171, 167, 91, 214, 213, 222, 76, 112, 226],
+		[65, 31, 39, 89, 82, 62, 87, 220, 164, 186, 118, 47, 209, 1, 248, 199, 157, 226, 217, 229, 5, 88, 226, 68, 128, 116, 78, 141, 244, 217, 111, 199],
+		[121, 74, 98, 154, 180, 70, 109, 100, 207, 149, 246, 217, 18, 52, 109, 37, 132, 58, 164, 180, 106, 114, 78, 56, 152, 123, 237, 161, 60, 186, 5, 9],
+		[125, 105, 16, 64, 194, 59, 50, 44, 65, 118, 182, 76, 242, 230, 149, 83, 233, 24, 170, 8, 167, 61, 86, 101, 42, 31, 238, 1, 141, 28, 45, 98],
 	],
 	[
-		[238, 172, 231, 227, 22, 45, 192, 44, 130, 245, 104, 213, 95, 134, 92, 115, 25, 188, 21, 111, 104, 94, 31, 77, 96, 232, 254, 36, 67, 67, 0, 6],
-		[210, 254, 100, 36, 109, 244, 19, 15, 46, 184, 61, 36, 106, 69, 170, 248, 100, 75, 235, 218, 179, 218, 55, 128, 252, 103, 139, 241, 148, 20, 2, 240],
-		[166, 86, 131, 176, 189, 136, 144, 45, 144, 34, 139, 3, 59, 255, 240, 94, 122, 235, 110, 230, 36, 150, 106, 190, 25, 6, 178, 177, 1, 39, 228, 160],
-		[169, 5, 82, 5, 150, 177, 108, 46, 249, 55, 177, 198, 60, 231, 196, 5, 199, 182, 84, 93, 132, 56, 133, 90, 145, 237, 164, 155, 175, 178, 70, 23],
+		[4, 120, 161, 140, 163, 173, 84, 124, 7, 27, 151, 201, 36, 61, 148, 180, 38, 76, 59, 147, 111, 112, 175, 93, 202, 95, 19, 75, 250, 105, 51, 164],
+		[165, 212, 96, 82, 81, 47, 71, 219, 179, 31, 55, 1, 82, 37, 64, 1, 31, 113, 52, 62, 71, 44, 170, 225, 5, 188, 193, 54, 234, 143, 169, 133],
+		[74, 150, 12, 12, 254, 184, 147, 222, 60, 140, 239, 166, 27, 6, 219, 176, 193, 90, 88, 97, 249, 84, 176, 8, 198, 44, 37, 137, 140, 196, 111, 56],
+		[164, 3, 28, 240, 51, 85, 162, 239, 179, 33, 94, 17, 6, 157, 168, 183, 89, 49, 40, 0, 160, 210, 120, 221, 136, 170, 246, 215, 29, 232, 147, 241],
 	],
 	[
-		[65, 137, 196, 59, 49, 153, 36, 195, 88, 164, 83, 40, 121, 101, 28, 58, 0, 135, 107, 56, 21, 20, 181, 168, 128, 94, 3, 71, 107, 37, 138, 66],
-		[70, 136, 3, 59, 126, 106, 60, 15, 212, 177, 240, 53, 2, 235, 178, 34, 30, 60, 250, 225, 247, 88, 147, 92, 142, 65, 91, 47, 23, 99, 99, 59],
-		[208, 9, 72, 11, 152, 68, 120, 17, 184, 205, 43, 47, 95, 96, 22, 150, 90, 168, 206, 1, 196, 19, 9, 69, 86, 220, 174, 233, 46, 165, 109, 182],
-		[116, 178, 79, 160, 232, 46, 121, 214, 227, 49, 83, 154, 38, 87, 94, 8, 102, 115, 67, 148, 243, 185, 206, 47, 167, 132, 7, 220, 126, 119, 67, 136],
+		[84, 138, 255, 229, 70, 155, 189, 129, 37, 109, 167, 252, 9, 198, 109, 132, 88, 132, 220, 241, 179, 186, 119, 202, 182, 188, 24, 68, 207, 115, 96, 187],
+		[165, 72, 219, 158, 85, 90, 8, 13, 163, 250, 194, 6, 46, 124, 26, 22, 139, 194, 218, 67, 129, 146, 200, 197, 125, 163, 120, 159, 78, 199, 184, 43],
+		[132, 24, 230, 63, 231, 18, 119, 251, 174, 105, 183, 215, 16, 55, 32, 18, 119, 184, 37, 58, 228, 115, 151, 22, 215, 43, 213, 173, 4, 85, 219, 96],
+		[3, 159, 25, 180, 245, 106, 72, 78, 166, 54, 68, 11, 133, 83, 220, 61, 18, 145, 31, 9, 134, 84, 213, 103, 249, 103, 212, 153, 92, 233, 15, 241],
 	],
 	[
-		[182, 15, 185, 252, 97, 43, 104, 198, 60, 57, 171, 118, 148, 87, 27, 68, 15, 26, 123, 182, 113, 86, 201, 122, 161, 147, 230, 142, 18, 63, 78, 153],
-		[80, 24, 180, 80, 252, 178, 143, 37, 38, 143, 177, 70, 199, 19, 192, 217, 192, 39, 202, 245, 59, 198, 250, 60, 195, 233, 152, 0, 199, 85, 177, 76],
-		[237, 17, 132, 232, 252, 61, 38, 151, 241, 163, 7, 156, 68, 70, 231, 202, 87, 200, 166, 56, 60, 191, 54, 9, 158, 224, 28, 34, 127, 190, 119, 107],
-		[150, 88, 90, 65, 52, 192, 149, 81, 80, 118, 20, 26, 221, 129, 94, 102, 32, 221, 49, 97, 206, 2, 49, 57, 62, 2, 89, 103, 4, 107, 138, 44],
+		[176, 116, 157, 233, 237, 69, 73, 117, 53, 87, 11, 70, 218, 191, 151, 30, 223, 167, 215, 8, 89, 102, 0, 248, 216, 53, 188, 40, 151, 206, 249, 139],
+		[185, 81, 245, 199, 159, 106, 232, 183, 151, 177, 112, 102, 236, 91, 41, 13, 176, 136, 216, 236, 84, 242, 245, 210, 192, 153, 230, 168, 191, 16, 244, 23],
+		[6, 101, 238, 193, 12, 111, 76, 77, 3, 186, 136, 19, 36, 45, 51, 81, 4, 123, 193, 156, 74, 208, 158, 170, 26, 245, 132, 212, 103, 209, 92, 78],
+		[201, 229, 57, 7, 150, 96, 184, 219, 75, 114, 176, 60, 16, 90, 113, 135, 183, 164, 78, 188, 113, 22, 78, 163, 250, 31, 225, 88, 40, 11, 161, 239],
 	],
 	[
-		[253, 13, 96, 189, 185, 179, 4, 198, 207, 243, 218, 44, 121, 127, 51, 25, 21, 223, 130, 230, 63, 202, 29, 243, 98, 168, 205, 119, 182, 253, 230, 110],
-		[179, 202, 200, 135, 30, 130, 158, 148, 140, 138, 155, 144, 75, 31, 201, 145, 229, 224, 141, 244, 93, 179, 214, 188, 209, 40, 124, 72, 110, 195, 99, 10],
-		[48, 8, 170, 119, 206, 77, 179, 156, 243, 169, 231, 95, 232, 158, 200, 215, 30, 200, 2, 91, 173, 30, 219, 152, 157, 69, 155, 57, 220, 27, 112, 231],
-		[138, 85, 85, 221, 188, 213, 225, 52, 148, 134, 201, 155, 138, 39, 72, 48, 157, 247, 143, 127, 127, 86, 55, 207, 1, 59, 134, 156, 112, 164, 107, 214],
+		[8, 231, 19, 234, 82, 189, 214, 174, 230, 33, 92, 167, 86, 151, 29, 189, 83, 12, 158, 209, 203, 82, 118, 82, 20, 124, 60, 157, 105, 96, 125, 59],
+		[91, 118, 222, 89, 64, 52, 24, 199, 5, 86, 195, 164, 10, 94, 2, 236, 74, 236, 170, 168, 55, 234, 164, 113, 156, 243, 111, 155, 166, 205, 154, 59],
+		[112, 26, 248, 202, 29, 47, 94, 175, 32, 36, 19, 64, 156, 206, 112, 94, 249, 55, 55, 127, 137, 51, 60, 220, 51, 197, 207, 255, 65, 51, 222, 210],
+		[198, 161, 67, 62, 218, 99, 101, 207, 54, 16, 113, 240, 135, 60, 224, 129, 4, 139, 234, 103, 219, 99, 182, 166, 104, 136, 125, 162, 78, 185, 44, 100],
 	],
 	[
-		[141, 193, 54, 48, 234, 239, 126, 230, 241, 192, 228, 177, 252, 216, 117, 155, 33, 192, 137, 44, 65, 71, 99, 170, 43, 137, 43, 107, 183, 0, 125, 200],
-		[248, 218, 216, 90, 77, 251, 45, 171, 129, 219, 162, 37, 140, 60, 100, 226, 201, 154, 101, 99, 183, 122, 110, 54, 155, 243, 178, 61, 60, 148, 92, 95],
-		[68, 143, 199, 219, 212, 25, 176, 10, 28, 249, 27, 219, 40, 90, 152, 4, 57, 148, 88, 18, 143, 17, 227, 162, 79, 107, 7, 254, 246, 14, 15, 199],
-		[33, 52, 202, 252, 151, 223, 97, 140, 253, 78, 64, 52, 124, 255, 206, 250, 85, 206, 223, 132, 148, 142, 112, 73, 212, 103, 189, 228, 16, 17, 112, 35],
+		[197, 74, 48, 56, 241, 81, 23, 245, 250, 149, 193, 126, 15, 230, 207, 127, 80, 113, 193, 229, 203, 115, 242, 88, 144, 245, 128, 88, 58, 198, 204, 110],
+		[82, 155, 28, 137, 176, 103, 144, 213, 249, 22, 200, 140, 133, 100, 220, 241, 84, 140, 214, 210, 161, 106, 238, 185, 18, 107, 77, 250, 246, 80, 82, 99],
+		[245, 36, 109, 112, 82, 67, 201, 212, 79, 92, 186, 252, 21, 87, 65, 91, 193, 198, 249, 108, 72, 168, 228, 111, 121, 172, 102, 56, 132, 207, 255, 114],
+		[69, 103, 75, 38, 193, 230, 153, 57, 203, 243, 99, 69, 125, 201, 123, 73, 77, 218, 84, 3, 173, 237, 194, 163, 27, 32, 237, 34, 58, 209, 242, 1],
 	],
 	[
-		[11, 105, 214, 97, 202, 27, 2, 233, 93, 77, 236, 244, 135, 142, 75, 241, 129, 209, 191, 209, 241, 17, 53, 81, 70, 29, 33, 159, 63, 112, 46, 28],
-		[162, 190, 148, 125, 63, 62, 170, 250, 160, 33, 41, 201, 177, 74, 242, 164, 52, 47, 42, 47, 176, 150, 62, 45, 98, 152, 11, 37, 82, 146, 203, 24],
-		[148, 235, 103, 138, 17, 184, 84, 106, 209, 10, 54, 156, 47, 125, 201, 254, 32, 146, 159, 27, 32, 209, 60, 95, 5, 3, 76, 228, 77, 37, 91, 101],
-		[28, 174, 6, 81, 159, 21, 157, 167, 39, 62, 97, 144, 140, 91, 250, 172, 247, 132, 254, 145, 164, 212, 0, 205, 248, 99, 203, 159, 176, 121, 89, 34],
+		[246, 254, 77, 236, 67, 61, 125, 23, 166, 196, 166, 51, 138, 187, 252, 209, 62, 78, 199, 229, 131, 206, 154, 151, 136, 46, 35, 21, 191, 211, 131, 73],
+		[79, 82, 139, 204, 144, 9, 100, 160, 2, 61, 12, 145, 15, 33, 105, 29, 238, 12, 109, 60, 214, 173, 61, 170, 102, 123, 46, 2, 92, 31, 59, 172],
+		[90, 195, 36, 72, 227, 78, 104, 222, 78, 232, 176, 89, 3, 187, 233, 201, 118, 143, 101, 166, 131, 56, 132, 204, 69, 4, 78, 177, 160, 17, 238, 33],
+		[250, 63, 249, 172, 213, 1, 43, 135, 105, 204, 70, 68, 236, 73, 111, 111, 168, 82, 107, 220, 104, 199, 42, 231, 171, 100, 216, 91, 100, 102, 253, 16],
 	],
 	[
-		[100, 152, 238, 174, 220, 145, 64, 31, 74, 156, 74, 11, 63, 118, 86, 178, 85, 200, 77, 38, 30, 65, 1, 33, 254, 218, 29, 96, 13, 103, 68, 2],
-		[189, 140, 110, 202, 119, 63, 25, 163, 72, 37, 95, 189, 43, 29, 79, 26, 118, 231, 165, 150, 158, 32, 252, 229, 152, 156, 102, 74, 137, 66, 0, 183],
-		[179, 230, 143, 83, 164, 87, 7, 50, 210, 18, 73, 135, 61, 42, 33, 202, 0, 24, 124, 181, 152, 214, 180, 125, 246, 180, 173, 24, 56, 38, 221, 45],
-		[166, 164, 2, 190, 118, 45, 84, 158, 198, 243, 20, 118, 230, 229, 167, 147, 233, 182, 11, 94, 201, 21, 108, 178, 153, 177, 57, 131, 32, 75, 7, 77],
+		[110, 118, 22, 137, 201, 172, 60, 47, 64, 114, 176, 41, 89, 69, 25, 64, 125, 55, 128, 155, 192, 102, 145, 76, 136, 203, 91, 198, 14, 179, 143, 46],
+		[193, 158, 92, 239, 143, 155, 12, 74, 63, 147, 139, 88, 59, 9, 100, 0, 43, 11, 94, 209, 224, 180, 195, 201, 40, 151, 217, 47, 234, 54, 238, 134],
+		[191, 197, 241, 37, 207, 247, 246, 254, 141, 41, 106, 149, 215, 85, 76, 102, 148, 150, 16, 148, 144, 50, 44, 17, 202, 181, 109, 75, 25, 200, 160, 55],
+		[143, 59, 225, 205, 43, 184, 127, 156, 129, 96, 249, 115, 209, 64, 109, 14, 7, 24, 121, 11, 203, 60, 253, 2, 225, 84, 126, 215, 165, 208, 237, 146],
 	],
 	[
-		[114, 72, 203, 158, 229, 7, 130, 118, 156, 155, 61, 14, 190, 167, 223, 31, 18, 66, 108, 125, 51, 196, 128, 197, 64, 167, 43, 159, 211, 178, 51, 191],
-		[231, 249, 136, 135, 151, 125, 128, 220, 35, 203, 204, 32, 100, 160, 32, 92, 194, 168, 228, 204, 60, 83, 227, 132, 2, 200, 61, 22, 110, 118, 13, 27],
-		[101, 38, 204, 182, 177, 60, 227, 245, 171, 102, 188, 135, 212, 69, 45, 86, 163, 221, 247, 69, 45, 183, 12, 114, 221, 80, 88, 182, 220, 69, 170, 184],
-		[152, 203, 137, 152, 41, 226, 72, 136, 195, 204, 198, 6, 58, 68, 134, 154, 17, 60, 137, 99, 132, 230, 103, 111, 186, 2, 190, 242, 72, 45, 69, 71],
+		[38, 122, 247, 4, 193, 223, 85, 210, 111, 157, 51, 111, 149, 159, 200, 127, 90, 91, 186, 146, 253, 197, 230, 199, 223, 233, 78, 130, 138, 114, 152, 40],
+		[190, 169, 132, 44, 118, 245, 166, 160, 202, 28, 135, 143, 219, 235, 73, 178, 53, 4, 119, 107, 123, 239, 219, 165, 6, 244, 220, 72, 184, 47, 175, 112],
+		[207, 129, 246, 194, 238, 38, 70, 92, 40, 91, 188, 85, 249, 119, 102, 38, 162, 24, 49, 75, 139, 37, 180, 3, 113, 217, 93, 23, 24, 8, 220, 38],
+		[48, 236, 207, 178, 116, 55, 233, 112, 1, 43, 123, 189, 82, 100, 87, 172, 187, 17, 234, 173, 128, 80, 105, 27, 30, 167, 31, 22, 121, 84, 173, 71],
 	],
 	[
-		[210, 176, 137, 206, 234, 206, 7, 77, 186, 208, 65, 167, 184, 143, 108, 225, 120, 87, 143, 250, 211, 181, 57, 130, 37, 139, 86, 91, 56, 56, 137, 61],
-		[242, 90, 196, 186, 217, 58, 158, 250, 219, 55, 194, 32, 9, 142, 43, 216, 164, 204, 41, 238, 240, 216, 21, 20, 87, 180, 254, 131, 246, 181, 98, 27],
-		[36, 59, 6, 89, 195, 14, 179, 111, 170, 1, 32, 162, 29, 174, 91, 249, 241, 197, 108, 239, 22, 179, 103, 178, 207, 170, 197, 219, 204, 182, 214, 15],
-		[119, 252, 199, 103, 220, 127, 29, 27, 223, 75, 164, 177, 38, 151, 235, 200, 66, 100, 16, 127, 183, 133, 238, 218, 153, 33, 96, 152, 219, 26, 92, 79],
+		[159, 156, 170, 58, 204, 70, 149, 1, 234, 176, 156, 165, 7, 186, 69, 11, 70, 78, 58, 249, 8, 176, 110, 137, 67, 166, 14, 46, 225, 5, 11, 178],
+		[167, 125, 144, 41, 12, 184, 49, 122, 22, 121, 109, 23, 169, 251, 95, 42, 7, 158, 81, 78, 109, 188, 246, 214, 61, 216, 82, 198, 39, 109, 48, 240],
+		[189, 234, 251, 142, 205, 222, 139, 7, 254, 224, 86, 239, 108, 180, 14, 32, 150, 84, 48, 57, 193, 195, 31, 107, 121, 127, 193, 230, 197, 100, 35, 204],
+		[206, 224, 68, 77, 91, 153, 43, 162, 76, 236, 100, 156, 36, 34, 10, 236, 64, 128, 135, 185, 159, 197, 43, 89, 159, 207, 146, 171, 155, 119, 179, 60],
 	],
 	[
-		[247, 3, 22, 142, 184, 57, 198, 153, 42, 155, 83, 128, 224, 164, 92, 89, 114, 236, 219, 251, 84, 40, 150, 17, 140, 117, 215, 50, 60, 52, 69, 229],
-		[231, 237, 104, 6, 133, 170, 56, 164, 124, 34, 69, 41, 30, 119, 148, 8, 75, 143, 182, 69, 49, 86, 232, 213, 189, 43, 67, 184, 20, 87, 215, 5],
-		[139, 22, 50, 122, 169, 138, 206, 237, 101, 102, 196, 168, 110, 108, 173, 9, 164, 42, 210, 252, 242, 135, 237, 252, 112, 35, 47, 32, 169, 175, 143, 230],
-		[4, 142, 24, 198, 140, 86, 166, 45, 4, 154, 194, 232, 218, 252, 181, 236, 108, 213, 183, 135, 117, 239, 122, 49, 106, 39, 203, 34, 34, 201, 169, 92],
+		[250, 133, 160, 160, 155, 136, 248, 27, 126, 27, 106, 233, 4, 170, 119, 225, 76, 115, 16, 0, 38, 56, 51, 100, 152, 222, 202, 35, 190, 46, 131, 64],
+		[192, 93, 122, 9, 58, 181, 208, 127, 72, 156, 155, 85, 30, 234, 237, 197, 198, 9, 14, 150, 119, 134, 224, 117, 111, 26, 101, 167, 251, 216, 59, 76],
+		[23, 121, 222, 218, 90, 47, 134, 193, 165, 117, 27, 203, 38, 69, 82, 115, 200, 120, 123, 223, 146, 78, 155, 199, 198, 205, 212, 82, 223, 131, 89, 88],
+		[164, 117, 132, 115, 55, 123, 80, 196, 20, 165, 112, 73, 253, 143, 44, 132, 31, 168, 27, 250, 73, 48, 73, 51, 245, 39, 101, 203, 83, 0, 110, 235],
 	],
 	[
-		[253, 254, 108, 5, 139, 173, 212, 248, 254, 170, 172, 149, 98, 212, 146, 60, 191, 155, 224, 202, 34, 15, 65, 104, 217, 23, 99, 4, 2, 210, 32, 66],
-		[119, 153, 41, 92, 21, 71, 3, 201, 125, 83, 63, 230, 170, 224, 122, 84, 147, 196, 109, 127, 33, 12, 88, 185, 238, 202, 170, 247, 49, 222, 42, 27],
-		[223, 250, 123, 48, 54, 237, 206, 243, 102, 89, 234, 169, 127, 151, 237, 171, 168, 248, 242, 149, 1, 5, 99, 121, 255, 162, 53, 38, 16, 185, 106, 232],
-		[136, 171, 143, 51, 169, 118, 138, 229, 175, 21, 50, 159, 17, 122, 115, 107, 93, 113, 222, 111, 42, 159, 63, 6, 230, 241, 32, 216, 3, 211, 115, 19],
+		[149, 125, 200, 20, 136, 193, 214, 52, 250, 98, 50, 190, 87, 226, 96, 41, 136, 70, 86, 64, 219, 105, 159, 176, 63, 182, 107, 226, 189, 144, 61, 90],
+		[212, 78, 161, 148, 73, 90, 96, 103, 224, 211, 179, 252, 50, 60, 215, 223, 64, 228, 9, 15, 186, 121, 0, 231, 128, 219, 58, 163, 130, 39, 240, 197],
+		[239, 52, 99, 31, 36, 151, 194, 147, 7, 213, 94, 228, 1, 23, 189, 255, 216, 180, 22, 239, 37, 204, 51, 77, 196, 254, 121, 4, 49, 88, 193, 164],
+		[156, 184, 113, 255, 120, 53, 17, 149, 105, 63, 227, 0, 211, 128, 98, 172, 70, 210, 130, 163, 9, 4, 180, 66, 35, 117, 100, 250, 186, 24, 238, 197],
 	],
 	[
-		[120, 46, 102, 164, 80, 117, 221, 165, 121, 138, 235, 238, 178, 94, 204, 120, 232, 125, 213, 217, 58, 186, 145, 189, 116, 89, 90, 38, 255, 53, 179, 133],
-		[96, 70, 155, 48, 145, 174, 124, 125, 183, 34, 101, 212, 144, 139, 236, 186, 32, 110, 21, 175, 188, 161, 162, 111, 228, 38, 204, 113, 249, 179, 101, 88],
-		[42, 20, 55, 240, 183, 220, 183, 202, 109, 69, 118, 98, 211, 98, 62, 233, 240, 213, 188, 236, 232, 146, 69, 189, 173, 171, 219, 254, 105, 10, 64, 70],
-		[59, 253, 231, 180, 214, 198, 36, 253, 136, 59, 157, 84, 153, 192, 207, 54, 35, 160, 210, 118, 83, 230, 240, 241, 150, 246, 85, 86, 225, 74, 58, 147],
+		[167, 87, 79, 75, 124, 161, 148, 59, 28, 108, 198, 62, 121, 41, 147, 81, 109, 51, 39, 40, 119, 153, 165, 237, 2, 210, 189, 157, 100, 160, 67, 76],
+		[21, 177, 236, 53, 47, 7, 27, 115, 135, 88, 224, 215, 177, 151, 19, 48, 156, 46, 206, 171, 98, 182, 12, 129, 28, 196, 163, 226, 201, 195, 253, 95],
+		[8, 229, 32, 3, 81, 144, 110, 102, 158, 221, 154, 134, 142, 94, 216, 177, 204, 2, 6, 48, 136, 0, 201, 214, 129, 169, 254, 155, 149, 134, 164, 146],
+		[75, 150, 237, 130, 70, 189, 209, 141, 203, 97, 19, 24, 46, 227, 217, 202, 48, 232, 134, 55, 177, 187, 23, 48, 176, 44, 122, 226, 217, 219, 212, 80],
 	],
 	[
-		[240, 215, 185, 12, 77, 20, 111, 232, 24, 250, 239, 93, 22, 23, 139, 135, 85, 143, 245, 218, 140, 217, 161, 130, 33, 64, 161, 203, 239, 238, 177, 35],
-		[221, 26, 102, 18, 213, 24, 156, 252, 191, 204, 25, 186, 244, 9, 97, 124, 168, 33, 32, 4, 182, 63, 77, 239, 39, 251, 255, 184, 253, 213, 171, 25],
-		[51, 118, 232, 97, 142, 192, 67, 160, 242, 55, 80, 112, 135, 11, 194, 35, 125, 133, 93, 19, 159, 14, 42, 95, 141, 7, 243, 203, 211, 98, 187, 61],
-		[53, 82, 83, 191, 184, 249, 146, 121, 49, 25, 131, 26, 130, 159, 183, 94, 38, 65, 47, 47, 29, 172, 168, 55, 224, 10, 231, 153, 13, 105, 136, 17],
+		[8, 132, 119, 173, 240, 110, 161, 98, 212, 178, 143, 25, 163, 115, 177, 90, 228, 49, 251, 79, 212, 197, 51, 98, 121, 32, 110, 255, 230, 249, 97, 176],
+		[190, 36, 70, 59, 141, 201, 31, 184, 158, 235, 50, 161, 107, 125, 63, 150, 223, 153, 225, 77, 3, 208, 129, 232, 175, 82, 186, 12, 204, 224, 218, 243],
+		[193, 5, 85, 75, 75, 40, 127, 212, 192, 92, 198, 242, 13, 228, 179, 223, 180, 209, 21, 161, 202, 251, 88, 110, 107, 35, 43, 91, 32, 154, 18, 145],
+		[96, 193, 4, 246, 214, 245, 139, 196, 103, 116, 239, 88, 255, 103, 135, 182, 48, 20, 2, 172, 197, 17, 237, 229, 9, 195, 78, 73, 216, 230, 68, 26],
 	],
 	[
-		[152, 84, 27, 101, 5, 165, 133, 127, 172, 230, 56, 247, 69, 56, 140, 45, 154, 80, 198, 112, 254, 98, 32, 191, 175, 208, 168, 116, 71, 36, 223, 149],
-		[120, 62, 56, 138, 200, 80, 63, 247, 28, 140, 91, 85, 196, 19, 122, 2, 44, 228, 156, 203, 17, 38, 126, 101, 204, 144, 109, 23, 119, 121, 103, 59],
-		[29, 122, 45, 247, 24, 102, 2, 195, 64, 190, 254, 232, 231, 45, 138, 203, 87, 134, 22, 40, 223, 32, 58, 104, 94, 209, 143, 52, 76, 59, 77, 47],
-		[99, 234, 169, 37, 169, 211, 71, 183, 29, 84, 8, 82, 15, 202, 94, 245, 252, 251, 67, 163, 229, 131, 87, 87, 199, 64, 253, 11, 6, 72, 255, 228],
+		[79, 111, 0, 225, 52, 132, 20, 227, 207, 236, 50, 120, 243, 224, 251, 98, 47, 172, 80, 222, 224, 45, 12, 105, 132, 55, 162, 4, 183, 142, 182, 136],
+		[117, 77, 59, 184, 58, 59, 229, 221, 254, 55, 106, 71, 15, 38, 104, 64, 24, 89, 10, 149, 32, 41, 32, 190, 248, 154, 204, 108, 130, 173, 4, 223],
+		[43, 74, 210, 229, 140, 15, 21, 39, 240, 23, 131, 86, 175, 69, 81, 234, 220, 195, 151, 245, 60, 227, 62, 14, 136, 191, 181, 128, 203, 178, 131, 17],
+		[219, 93, 244, 175, 173, 16, 234, 153, 93, 169, 118, 86, 244, 106, 245, 45, 168, 127, 144, 141, 43, 215, 204, 69, 120, 172, 218, 17, 0, 87, 87, 50],
 	],
 	[
-		[50, 81, 194, 105, 249, 37, 58, 220, 1, 219, 109, 1, 218, 67, 164, 215, 47, 62, 127, 75, 97, 232, 112, 221, 225, 199, 48, 254, 213, 210, 68, 90],
-		[224, 100, 97, 243, 118, 128, 32, 212, 80, 56, 240, 33, 60, 51, 109, 255, 16, 243, 32, 198, 194, 115, 38, 167, 107, 136, 201, 77, 211, 136, 144, 186],
-		[240, 241, 16, 49, 187, 99, 160, 238, 241, 50, 142, 121, 242, 143, 171, 234, 113, 202, 231, 232, 123, 84, 158, 176, 142, 8, 148, 148, 219, 189, 38, 20],
-		[216, 246, 209, 227, 21, 172, 35, 197, 217, 12, 224, 149, 59, 94, 54, 79, 79, 140, 174, 168, 62, 50, 19, 35, 104, 105, 121, 153, 102, 1, 66, 13],
+		[3, 47, 47, 254, 86, 240, 88, 15, 10, 3, 245, 7, 252, 154, 57, 60, 181, 31, 86, 227, 244, 162, 168, 1, 157, 0, 239, 69, 254, 121, 10, 132],
+		[125, 4, 35, 80, 18, 170, 108, 13, 217, 93, 44, 30, 27, 245, 60, 149, 99, 110, 126, 141, 106, 72, 130, 127, 49, 61, 184, 150, 109, 90, 173, 241],
+		[206, 13, 110, 33, 199, 33, 70, 188, 191, 127, 205, 53, 67, 123, 193, 56, 126, 180, 204, 23, 14, 150, 170, 37, 227, 45, 75, 95, 215, 89, 170, 142],
+		[249, 190, 11, 109, 119, 115, 160, 19, 206, 47, 83, 134, 145, 255, 58, 71, 57, 66, 106, 255, 106, 17, 229, 137, 72, 94, 56, 186, 107, 128, 226, 235],
 	],
 	[
-		[188, 12, 44, 129, 6, 169, 48, 169, 14, 56, 26, 156, 108, 173, 74, 49, 52, 118, 49, 246, 202, 188, 153, 87, 60, 165, 48, 31, 248, 115, 201, 213],
-		[37, 198, 243, 25, 215, 151, 226, 129, 15, 244, 207, 87, 141, 59, 131, 164, 219, 35, 129, 9, 127, 106, 95, 204, 3, 162, 220, 219, 210, 232, 247, 74],
-		[79, 136, 226, 13, 95, 247, 240, 138, 194, 6, 169, 226, 1, 184, 128, 181, 158, 202, 111, 54, 99, 75, 43, 196, 195, 21, 193, 72, 170, 46, 26, 95],
-		[180, 11, 0, 25, 227, 103, 204, 224, 216, 112, 157, 54, 21, 237, 77, 221, 131, 72, 123, 47, 71, 230, 70, 101, 234, 98, 101, 163, 101, 113, 129, 145],
+		[61, 106, 77, 56, 108, 235, 9, 206, 203, 74, 2, 10, 179, 112, 160, 236, 237, 224, 2, 243, 0, 112, 54, 255, 118, 41, 96, 192, 24, 137, 83, 101],
+		[129, 78, 145, 1, 7, 16, 5, 89, 184, 228, 209, 32, 0, 24, 150, 70, 70, 169, 11, 36, 242, 74, 27, 34, 216, 84, 137, 209, 138, 109, 165, 158],
+		[21, 116, 13, 138, 46, 93, 125, 68, 47, 200, 191, 81, 25, 107, 198, 68, 33, 203, 167, 201, 152, 113, 89, 36, 55, 154, 166, 141, 2, 30, 92, 205],
+		[4, 183, 219, 167, 189, 17, 39, 169, 39, 47, 216, 164, 225, 255, 172, 94, 181, 72, 13, 213, 105, 1, 244, 97, 181, 81, 179, 86, 72, 240, 246, 140],
 	],
 	[
-		[163, 203, 42, 23, 41, 83, 196, 211, 19, 77, 62, 65, 78, 59, 139, 9, 78, 76, 207, 48, 95, 233, 214, 219, 192, 3, 8, 12, 88, 42, 11, 226],
-		[248, 150, 159, 143, 219, 164, 201, 237, 111, 134, 34, 7, 214, 167, 234, 17, 66, 207, 238, 75, 115, 140, 73, 100, 244, 86, 177, 189, 120, 26, 54, 152],
-		[34, 230, 123, 17, 6, 168, 55, 204, 40, 165, 137, 59, 140, 219, 104, 147, 27, 124, 234, 54, 183, 240, 243, 92, 158, 116, 2, 124, 214, 233, 115, 92],
-		[128, 252, 160, 177, 152, 102, 16, 217, 232, 157, 66, 226, 201, 48, 241, 236, 243, 36, 166, 247, 249, 102, 97, 209, 173, 190, 189, 158, 63, 229, 62, 38],
+		[82, 255, 64, 151, 187, 150, 212, 15, 11, 33, 231, 175, 13, 34, 47, 241, 16, 160, 154, 79, 124, 66, 108, 70, 209, 25, 183, 14, 95, 89, 190, 55],
+		[158, 173, 134, 162, 213, 125, 106, 169, 207, 192, 88, 172, 220, 35, 167, 75, 132, 166, 35, 117, 252, 238, 244, 222, 160, 113, 216, 67, 164, 220, 220, 216],
+		[71, 114, 96, 181, 226, 148, 53, 43, 123, 188, 225, 75, 134, 67, 234, 176, 247, 174, 177, 155, 194, 144, 34, 41, 8, 175, 235, 0, 216, 72, 12, 62],
+		[157, 131, 128, 243, 231, 78, 221, 162, 222, 51, 122, 54, 146, 185, 20, 112, 220, 252, 39, 60, 197, 130, 42, 212, 99, 248, 118, 115, 34, 230, 100, 76],
 	],
 	[
-		[72, 37, 163, 246, 11, 236, 159, 182, 27, 108, 129, 182, 74, 118, 8, 112, 43, 67, 191, 73, 193, 32, 48, 142, 45, 233, 89, 96, 121, 173, 44, 190],
-		[210, 8, 18, 185, 71, 244, 248, 94, 99, 126, 149, 243, 124, 34, 126, 250, 133, 1, 40, 129, 63, 185, 147, 47, 99, 102, 193, 146, 56, 229, 141, 234],
-		[178, 125, 253, 176, 41, 72, 130, 78, 225, 244, 71, 250, 103, 217, 47, 79, 179, 107, 209, 239, 218, 54, 227, 132, 222, 68, 245, 145, 179, 163, 143, 37],
-		[244, 182, 111, 147, 135, 30, 192, 165, 157, 6, 149, 105, 78, 200, 155, 101, 190, 213, 6, 197, 143, 93, 188, 129, 160, 69, 228, 171, 130, 225, 137, 39],
+		[234, 38, 184, 170, 15, 77, 143, 121, 21, 91, 208, 151, 252, 54, 157, 28, 225, 140, 138, 125, 54, 16, 120, 238, 57, 189, 213, 93, 172, 139, 124, 4],
+		[236, 189, 103, 18, 235, 8, 230, 65, 207, 173, 42, 138, 171, 140, 16, 175, 19, 26, 183, 7, 0, 51, 120, 110, 29, 37, 95, 62, 196, 224, 51, 9],
+		[32, 35, 115, 22, 64, 53, 100, 213, 80, 1, 189, 85, 251, 177, 242, 78, 213, 85, 85, 238, 56, 9, 70, 150, 138, 102, 178, 174, 175, 59, 33, 130],
+		[168, 210, 103, 81, 145, 4, 8, 85, 114, 187, 149, 50, 81, 124, 243, 182, 50, 11, 233, 140, 193, 100, 89, 164, 97, 30, 163, 244, 49, 229, 195, 172],
 	],
 	[
-		[67, 211, 85, 177, 105, 133, 147, 249, 170, 248, 174, 99, 76, 121, 40, 17, 211, 185, 188, 128, 189, 174, 32, 79, 250, 158, 81, 234, 1, 203, 23, 195],
-		[98, 204, 73, 131, 160, 220, 132, 58, 135, 214, 131, 44, 70, 160, 254, 113, 195, 107, 159, 23, 240, 185, 135, 94, 61, 78, 11, 101, 22, 31, 223, 197],
-		[105, 22, 86, 39, 104, 165, 233, 148, 177, 194, 64, 74, 67, 192, 138, 241, 59, 194, 201, 92, 214, 226, 224, 193, 86, 109, 63, 251, 32, 157, 19, 8],
-		[33, 130, 141, 248, 135, 138, 16, 132, 105, 162, 216, 215, 30, 63, 137, 39, 66, 108, 219, 37, 82, 50, 30, 171, 189, 181, 72, 107, 127, 153, 225, 5],
+		[124, 172, 89, 82, 172, 101, 133, 231, 170, 9, 127, 66, 81, 200, 82, 226, 196, 145, 166, 255, 217, 216, 232, 87, 56, 17, 208, 26, 185, 192, 144, 144],
+		[31, 212, 12, 157, 153, 150, 160, 189, 162, 237, 1, 96, 212, 131, 7, 100, 34, 65, 174, 222, 201, 80, 45, 154, 140, 137, 89, 89, 236, 19, 211, 83],
+		[43, 16, 212, 139, 227, 234, 236, 17, 163, 79, 162, 85, 5, 14, 178, 155, 80, 148, 251, 238, 124, 62, 226, 240, 226, 162, 139, 113, 11, 3, 179, 213],
+		[230, 126, 0, 44, 127, 173, 109, 111, 19, 186, 21, 254, 243, 129, 21, 161, 184, 15, 127, 210, 56, 141, 128, 140, 177, 101, 28, 131, 78, 154, 108, 105],
 	],
 	[
-		[228, 49, 224, 2, 32, 252, 169, 147, 106, 61, 173, 96, 117, 108, 223, 249, 162, 195, 199, 169, 200, 51, 59, 202, 13, 112, 222, 61, 161, 200, 153, 227],
-		[106, 110, 107, 30, 121, 50, 51, 32, 203, 177, 213, 194, 235, 179, 171, 212, 21, 219, 21, 252, 221, 207, 125, 184, 137, 52, 142, 182, 32, 123, 161, 53],
-		[217, 212, 29, 209, 151, 182, 198, 123, 230, 191, 164, 202, 252, 64, 17, 155, 150, 143, 128, 60, 16, 83, 181, 208, 80, 58, 79, 108, 68, 51, 86, 197],
-		[48, 25, 163, 148, 192, 66, 88, 41, 202, 0, 91, 236, 52, 172, 33, 85, 26, 241, 70, 180, 213, 0, 64, 95, 246, 147, 40, 146, 157, 247, 172, 218],
+		[68, 130, 163, 20, 88, 36, 169, 250, 225, 22, 67, 185, 1, 134, 61, 148, 93, 208, 95, 192, 151, 214, 26, 199, 255, 192, 8, 72, 161, 247, 81, 208],
+		[16, 35, 183, 212, 218, 94, 183, 192, 52, 10, 62, 227, 82, 210, 139, 248, 61, 150, 23, 11, 235, 140, 16, 140, 144, 83, 148, 243, 206, 41, 186, 196],
+		[183, 254, 141, 85, 187, 113, 18, 204, 6, 162, 81, 178, 244, 54, 188, 241, 253, 71, 135, 122, 198, 43, 107, 211, 240, 25, 189, 36, 104, 48, 48, 99],
+		[248, 210, 196, 59, 67, 198, 221, 22, 70, 46, 53, 178, 229, 254, 30, 217, 167, 33, 252, 68, 61, 156, 209, 10, 192, 48, 169, 9, 173, 183, 254, 62],
 	],
 	[
-		[47, 50, 254, 44, 3, 127, 168, 235, 139, 246, 114, 56, 96, 117, 70, 61, 150, 161, 238, 130, 44, 165, 105, 128, 116, 241, 46, 96, 123, 23, 249, 139],
-		[13, 129, 233, 216, 50, 24, 207, 0, 10, 30, 146, 99, 246, 87, 112, 247, 210, 99, 91, 232, 15, 193, 126, 42, 211, 148, 166, 96, 160, 187, 253, 42],
-		[195, 135, 230, 9, 242, 162, 117, 207, 39, 98, 208, 201, 196, 68, 252, 188, 192, 82, 151, 58, 53, 85, 196, 17, 254, 208, 241, 149, 13, 126, 182, 85],
-		[205, 0, 208, 103, 125, 152, 139, 5, 109, 171, 77, 173, 49, 50, 27, 67, 99, 39, 228, 88, 249, 253, 75, 60, 136, 167, 214, 93, 184, 90, 54, 43],
+		[40, 113, 44, 213, 157, 14, 114, 196, 114, 222, 123, 82, 65, 12, 66, 89, 83, 249, 171, 146, 180, 59, 222, 198, 143, 112, 237, 117, 23, 191, 154, 139],
+		[89, 86, 125, 103, 178, 231, 4, 135, 120, 254, 18, 53, 66, 243, 216, 29, 166, 181, 153, 136, 37, 92, 77, 157, 49, 197, 75, 228, 198, 215, 240, 25],
+		[153, 88, 126, 155, 214, 135, 84, 196, 235, 13, 29, 86, 140, 125, 240, 8, 120, 31, 25, 79, 243, 181, 21, 205, 236, 211, 249, 160, 54, 185, 92, 165],
+		[86, 249, 172, 159, 186, 42, 25, 84, 127, 180, 25, 231, 30, 54, 190, 207, 225, 95, 231, 4, 138, 91, 29, 2, 108, 169, 149, 184, 59, 174, 202, 86],
 	],
 	[
-		[94, 67, 183, 175, 100, 13, 153, 30, 156, 192, 119, 29, 246, 104, 60, 30, 192, 136, 105, 189, 118, 78, 110, 50, 134, 168, 57, 242, 135, 53, 251, 147],
-		[74, 254, 46, 69, 182, 61, 69, 249, 6, 120, 35, 70, 255, 155, 90, 209, 4, 173, 78, 167, 2, 47, 141, 98, 39, 26, 205, 166, 207, 108, 18, 25],
-		[106, 195, 121, 171, 144, 233, 151, 145, 215, 39, 61, 113, 1, 231, 125, 244, 195, 175, 119, 69, 47, 113, 174, 110, 157, 117, 109, 147, 127, 206, 214, 151],
-		[23, 168, 53, 57, 149, 200, 151, 85, 70, 75, 120, 206, 250, 195, 251, 189, 207, 77, 243, 109, 221, 175, 146, 36, 228, 40, 180, 147, 198, 103, 64, 232],
+		[144, 233, 177, 71, 188, 7, 206, 146, 47, 228, 38, 116, 246, 50, 240, 72, 217, 230, 12, 107, 233, 73, 56, 244, 20, 249, 139, 250, 136, 81, 173, 239],
+		[16, 112, 38, 212, 71, 71, 224, 114, 95, 72, 143, 85, 103, 208, 124, 224, 21, 137, 207, 121, 212, 76, 163, 52, 103, 214, 25, 6, 190, 204, 203, 94],
+		[207, 193, 236, 53, 137, 255, 0, 85, 244, 195, 6, 132, 171, 2, 233, 39, 9, 204, 195, 23, 113, 16, 98, 171, 168, 165, 114, 165, 55, 97, 133, 84],
+		[140, 125, 68, 30, 208, 160, 224, 126, 184, 58, 121, 135, 165, 255, 124, 73, 4, 231, 107, 86, 206, 27, 242, 147, 111, 37, 142, 247, 87, 70, 214, 77],
 	],
 	[
-		[109, 125, 83, 81, 204, 5, 196, 100, 103, 235, 3, 22, 144, 252, 96, 84, 245, 148, 125, 3, 49, 160, 0, 105, 181, 153, 129, 71, 88, 25, 31, 240],
-		[53, 5, 235, 239, 28, 68, 224, 150, 250, 140, 213, 255, 11, 122, 236, 91, 7, 161, 210, 250, 100, 202, 60, 114, 247, 214, 59, 1, 58, 185, 106, 173],
-		[210, 194, 165, 207, 243, 130, 11, 230, 234, 100, 21, 229, 104, 193, 126, 26, 241, 218, 185, 233, 178, 204, 28, 11, 134, 216, 233, 19, 222, 143, 90, 151],
-		[2, 197, 146, 7, 140, 6, 44, 126, 250, 172, 97, 125, 181, 192, 83, 169, 169, 122, 244, 44, 71, 133, 45, 122, 35, 54, 135, 148, 228, 142, 3, 65],
+		[153, 130, 135, 151, 67, 114, 196, 196, 233, 249, 211, 155, 156, 184, 212, 248, 50, 187, 121, 79, 250, 217, 219, 113, 222, 36, 114, 73, 4, 244, 72, 63],
+		[84, 187, 112, 127, 232, 176, 243, 190, 117, 244, 196, 0, 2, 253, 24, 225, 146, 76, 136, 139, 167, 66, 133, 14, 53, 138, 88, 212, 69, 194, 117, 105],
+		[185, 119, 130, 36, 212, 115, 219, 10, 189, 128, 137, 73, 251, 152, 153, 79, 179, 59, 22, 9, 119, 97, 180, 52, 237, 57, 94, 147, 228, 51, 13, 196],
+		[130, 188, 226, 88, 250, 141, 159, 119, 86, 136, 49, 139, 181, 224, 37, 40, 76, 24, 153, 193, 58, 237, 15, 133, 143, 3, 34, 255, 215, 7, 135, 12],
 	],
 	[
-		[134, 119, 243, 170, 92, 212, 33, 204, 153, 205, 86, 225, 205, 174, 157, 77, 24, 158, 139, 225, 48, 125, 143, 184, 71, 41, 159, 43, 242, 219, 160, 88],
-		[136, 7, 147, 171, 44, 121, 182, 12, 216, 191, 79, 214, 0, 34, 190, 220, 188, 121, 232, 111, 158, 88, 23, 117, 108, 202, 93, 55, 239, 254, 81, 14],
-		[117, 100, 7, 135, 154, 151, 102, 197, 95, 126, 109, 239, 212, 9, 124, 217, 143, 69, 204, 98, 65, 133, 119, 206, 12, 216, 186, 143, 28, 115, 207, 150],
-		[3, 62, 134, 227, 173, 16, 158, 91, 197, 144, 134, 252, 110, 235, 251, 244, 201, 53, 34, 149, 68, 201, 78, 172, 64, 84, 82, 139, 131, 90, 163, 8],
+		[75, 190, 77, 251, 227, 59, 89, 91, 32, 41, 203, 113, 46, 68, 209, 86, 15, 164, 83, 4, 187, 208, 206, 179, 30, 129, 185, 104, 35, 142, 116, 222],
+		[36, 113, 84, 223, 96, 201, 249, 226, 190, 81, 152, 204, 78, 198, 86, 98, 107, 115, 232, 57, 22, 98, 149, 249, 151, 174, 47, 96, 33, 135, 101, 42],
+		[251, 20, 187, 45, 112, 82, 110, 79, 215, 71, 172, 177, 159, 231, 13, 51, 172, 80, 13, 184, 77, 27, 149, 183, 206, 120, 195, 77, 54, 155, 177, 242],
+		[132, 37, 206, 220, 145, 200, 234, 232, 214, 68, 71, 213, 78, 139, 47, 122, 74, 235, 97, 116, 242, 30, 180, 25, 243, 250, 10, 123, 75, 240, 116, 150],
 	],
 	[
-		[95, 110, 122, 226, 177, 222, 17, 94, 60, 6, 168, 118, 70, 138, 105, 51, 186, 109, 203, 156, 159, 60, 73, 243, 154, 182, 211, 109, 140, 111, 217, 104],
-		[88, 216, 41, 33, 226, 188, 128, 20, 183, 147, 229, 33, 146, 0, 202, 171, 128, 201, 85, 100, 55, 159, 79, 126, 149, 137, 242, 43, 148, 200, 39, 104],
-		[29, 110, 48, 205, 214, 227, 67, 122, 15, 220, 32, 250, 75, 240, 201, 244, 196, 62, 188, 127, 246, 25, 42, 109, 188, 111, 142, 241, 133, 53, 89, 196],
-		[105, 50, 243, 123, 222, 152, 171, 125, 153, 40, 205, 85, 11, 172, 185, 86, 145, 153, 26, 146, 71, 94, 17, 172, 205, 48, 39, 174, 112, 105, 51, 13],
+		[18, 222, 101, 172, 162, 4, 100, 117, 75, 12, 46, 159, 238, 28, 92, 198, 171, 183, 66, 16, 94, 105, 46, 154, 241, 183, 237, 219, 229, 51, 140, 237],
+		[27, 171, 232, 160, 122, 59, 161, 185, 21, 93, 104, 91, 1, 217, 14, 50, 90, 249, 229, 226, 128, 150, 23, 152, 149, 181, 212, 102, 139, 113, 112, 54],
+		[3, 155, 106, 80, 157, 147, 98, 126, 188, 107, 33, 173, 180, 230, 133, 9, 186, 171, 189, 203, 147, 18, 57, 131, 151, 211, 28, 33, 250, 67, 96, 238],
+		[220, 207, 159, 6, 159, 22, 42, 187, 27, 17, 226, 58, 11, 100, 134, 190, 135, 7, 4, 177, 10, 171, 174, 210, 68, 97, 128, 63, 228, 191, 78, 70],
 	],
 	[
-		[51, 59, 44, 25, 229, 85, 1, 209, 183, 203, 48, 237, 4, 30, 148, 244, 109, 255, 42, 108, 76, 59, 86, 164, 6, 242, 9, 113, 117, 119, 180, 68],
-		[201, 183, 1, 232, 150, 253, 0, 136, 147, 5, 186, 209, 30, 34, 3, 120, 211, 209, 91, 220, 211, 136, 25, 130, 167, 182, 119, 22, 191, 231, 240, 155],
-		[254, 167, 174, 178, 147, 107, 155, 113, 74, 70, 235, 251, 215, 55, 134, 192, 251, 94, 164, 126, 233, 208, 188, 108, 167, 25, 56, 182, 94, 148, 251, 170],
-		[203, 60, 23, 30, 104, 175, 196, 21, 166, 62, 205, 213, 239, 106, 8, 220, 182, 141, 104, 3, 109, 157, 139, 61, 221, 247, 98, 167, 75, 30, 231, 187],
+		[221, 229, 219, 232, 127, 14, 156, 101, 110, 44, 13, 177, 215, 216, 197, 55, 151, 210, 216, 230, 11, 193, 248, 122, 57, 62, 31, 6, 109, 116, 139, 215],
+		[150, 23, 147, 212, 222, 13, 113, 191, 219, 228, 62, 100, 113, 137, 137, 149, 53, 145, 216, 117, 223, 67, 191, 67, 131, 155, 22, 106, 19, 60, 103, 69],
+		[224, 93, 142, 158, 100, 83, 44, 191, 81, 65, 38, 8, 72, 70, 117, 48, 235, 166, 222, 30, 70, 208, 40, 23, 182, 231, 31, 50, 227, 5, 0, 152],
+		[253, 216, 162, 94, 165, 122, 218, 233, 141, 195, 12, 46, 22, 77, 60, 29, 79, 149, 27, 169, 224, 200, 241, 21, 123, 113, 194, 154, 47, 17, 178, 213],
 	],
 	[
-		[146, 218, 109, 12, 217, 214, 131, 226, 137, 122, 224, 149, 234, 141, 206, 192, 116, 27, 128, 185, 233, 88, 34, 108, 247, 143, 193, 116, 15, 251, 246, 107],
-		[105, 104, 29, 19, 83, 236, 137, 196, 224, 6, 136, 194, 148, 199, 232, 31, 190, 132, 251, 172, 48, 84, 54, 40, 1, 2, 5, 101, 189, 201, 42, 71],
-		[218, 63, 47, 193, 34, 255, 134, 187, 72, 27, 44, 224, 16, 28, 51, 90, 197, 97, 51, 76, 139, 146, 196, 150, 246, 39, 17, 229, 127, 213, 144, 36],
-		[199, 0, 117, 244, 155, 224, 251, 202, 25, 90, 37, 143, 91, 166, 120, 87, 237, 252, 193, 86, 243, 48, 234, 142, 66, 159, 39, 109, 234, 30, 185, 0],
+		[49, 230, 227, 21, 131, 186, 232, 253, 122, 27, 118, 226, 211, 96, 159, 133, 49, 131, 163, 120, 86, 96, 223, 127, 16, 192, 239, 192, 237, 25, 198, 75],
+		[77, 155, 28, 153, 8, 112, 74, 224, 231, 184, 206, 182, 200, 243, 189, 247, 130, 234, 171, 132, 86, 118, 232, 44, 191, 240, 239, 15, 214, 160, 193, 67],
+		[81, 219, 226, 232, 148, 26, 237, 229, 154, 226, 69, 177, 176, 173, 110, 72, 15, 29, 191, 11, 146, 55, 93, 50, 48, 231, 94, 213, 134, 187, 71, 38],
+		[32, 120, 205, 16, 217, 144, 237, 36, 11, 7, 105, 98, 194, 158, 67, 144, 45, 8, 141, 2, 239, 198, 80, 152, 238, 31, 187, 164, 196, 212, 169, 216],
 	],
 	[
-		[69, 182, 193, 140, 41, 96, 125, 180, 202, 54, 122, 154, 208, 168, 220, 3, 74, 37, 195, 243, 242, 166, 47, 153, 225, 83, 168, 24, 105, 224, 245, 129],
-		[247, 71, 139, 170, 16, 87, 89, 80, 202, 237, 25, 60, 176, 202, 177, 194, 168, 92, 61, 147, 109, 109, 40, 121, 198, 173, 204, 237, 34, 225, 240, 130],
-		[204, 123, 112, 172, 157, 27, 99, 80, 130, 16, 126, 157, 115, 206, 227, 8, 198, 180, 216, 56, 168, 43, 211, 20, 33, 100, 74, 190, 167, 110, 24, 21],
-		[155, 2, 142, 52, 193, 248, 207, 254, 191, 169, 211, 149, 191, 98, 64, 29, 18, 147, 235, 210, 177, 2, 26, 133, 207, 202, 44, 60, 17, 186, 209, 193],
+		[137, 56, 46, 32, 75, 207, 34, 19, 227, 72, 119, 235, 25, 154, 25, 255, 125, 55, 4, 13, 243, 139, 193, 40, 250, 205, 167, 48, 17, 182, 180, 79],
+		[215, 36, 181, 83, 228, 225, 200, 162, 2, 206, 210, 248, 58, 5, 157, 7, 46, 186, 70, 219, 8, 60, 157, 67, 150, 129, 78, 93, 220, 68, 52, 155],
+		[218, 124, 195, 140, 253, 15, 60, 139, 35, 44, 223, 124, 189, 254, 8, 221, 71, 238, 97, 223, 125, 135, 197, 208, 186, 163, 129, 61, 71, 81, 247, 38],
+		[67, 241, 174, 159, 124, 28, 24, 68, 150, 219, 135, 73, 145, 2, 57, 227, 169, 130, 19, 77, 215, 58, 102, 234, 37, 54, 235, 74, 193, 152, 144, 10],
 	],
 	[
-		[69, 111, 43, 200, 157, 123, 31, 128, 162, 156, 157, 116, 246, 52, 11, 96, 43, 96, 10, 26, 97, 23, 17, 148, 169, 113, 142, 206, 49, 42, 3, 39],
-		[149, 149, 214, 154, 109, 181, 158, 115, 244, 100, 215, 77, 43, 89, 214, 53, 62, 24, 119, 183, 161, 30, 76, 116, 245, 54, 119, 52, 129, 82, 121, 46],
-		[232, 6, 99, 181, 118, 11, 227, 120, 173, 129, 220, 221, 198, 220, 160, 186, 169, 216, 111, 182, 55, 161, 28, 51, 12, 69, 3, 55, 247, 49, 226, 189],
-		[27, 13, 84, 32, 211, 207, 113, 6, 142, 209, 214, 246, 54, 168, 53, 84, 58, 235, 28, 17, 80, 179, 12, 134, 62, 26, 218, 138, 116, 206, 229, 171],
+		[157, 42, 141, 140, 234, 194, 192, 51, 241, 197, 82, 243, 8, 176, 51, 189, 79, 7, 161, 227, 27, 230, 158, 11, 203, 177, 77, 196, 56, 121, 181, 13],
+		[249, 213, 161, 30, 240, 122, 63, 143, 205, 20, 159, 188, 29, 234, 251, 50, 14, 182, 232, 41, 75, 202, 58, 241, 124, 107, 166, 53, 190, 134, 176, 68],
+		[55, 110, 64, 35, 86, 125, 163, 119, 104, 48, 29, 137, 175, 14, 152, 116, 93, 23, 216, 147, 111, 201, 80, 4, 28, 171, 6, 185, 251, 15, 55, 50],
+		[19, 209, 111, 144, 137, 88, 52, 144, 115, 254, 82, 226, 103, 49, 159, 175, 141, 103, 198, 55, 30, 241, 229, 246, 0, 43, 227, 27, 50, 230, 29, 39],
 	],
 	[
-		[254, 27, 95, 242, 22, 235, 102, 50, 17, 70, 88, 158, 233, 207, 128, 76, 60, 90, 246, 58, 215, 74, 209, 104, 231, 237, 242, 126, 193, 151, 118, 180],
-		[83, 5, 248, 14, 56, 153, 213, 144, 139, 250, 57, 174, 6, 118, 125, 66, 189, 222, 65, 253, 50, 64, 109, 60, 197, 91, 8, 79, 8, 83, 48, 147],
-		[120, 89, 117, 88, 224, 52, 138, 75, 48, 131, 221, 192, 161, 34, 238, 203, 93, 13, 39, 198, 224, 131, 28, 108, 11, 95, 32, 153, 14, 189, 189, 228],
-		[85, 250, 127, 29, 4, 99, 25, 215, 112, 250, 16, 172, 222, 64, 96, 72, 23, 185, 237, 40, 239, 200, 13, 40, 51, 237, 244, 65, 129, 182, 205, 60],
+		[205, 162, 19, 248, 160, 129, 186, 205, 243, 82, 67, 53, 135, 107, 26, 164, 253, 59, 226, 110, 38, 115, 199, 12, 219, 227, 176, 233, 59, 27, 60, 139],
+		[204, 241, 171, 97, 20, 67, 152, 20, 154, 79, 249, 71, 25, 97, 211, 14, 155, 108, 254, 20, 151, 133, 228, 17, 210, 74, 132, 204, 39, 51, 28, 206],
+		[223, 171, 199, 181, 114, 108, 221, 92, 6, 123, 61, 244, 126, 93, 236, 149, 87, 20, 59, 178, 155, 70, 181, 134, 191, 181, 209, 2, 155, 200, 176, 42],
+		[53, 85, 203, 61, 64, 232, 110, 207, 111, 103, 207, 246, 72, 28, 78, 161, 190, 255, 44, 4, 176, 76, 253, 81, 157, 113, 156, 32, 220, 152, 109, 85],
 	],
 	[
-		[87, 106, 216, 46, 110, 91, 186, 65, 72, 132, 4, 198, 44, 199, 76, 68, 120, 166, 198, 15, 74, 116, 216, 221, 65, 132, 0, 97, 114, 153, 152, 163],
-		[225, 246, 252, 114, 198, 221, 226, 243, 10, 46, 58, 60, 64, 22, 205, 3, 129, 77, 158, 83, 217, 210, 240, 9, 227, 27, 22, 109, 101, 59, 95, 41],
-		[182, 16, 43, 70, 73, 175, 219, 198, 149, 48, 99, 237, 55, 234, 197, 247, 72, 225, 193, 146, 1, 41, 168, 163, 237, 141, 86, 2, 198, 180, 247, 184],
-		[68, 42, 58, 160, 82, 96, 171, 218, 238, 47, 175, 170, 165, 143, 171, 56, 68, 218, 231, 0, 8, 169, 213, 173, 107, 81, 122, 4, 245, 7, 179, 136],
+		[6, 107, 100, 141, 54, 73, 229, 200, 208, 102, 119, 248, 13, 27, 178, 65, 245, 243, 109, 98, 188, 223, 59, 131, 0, 30, 111, 165, 0, 17, 207, 0],
+		[201, 154, 246, 155, 30, 213, 188, 125, 86, 132, 91, 197, 45, 48, 92, 134, 77, 241, 167, 62, 52, 149, 104, 186, 7, 99, 200, 10, 80, 209, 79, 74],
+		[194, 131, 14, 42, 99, 189, 0, 236, 97, 194, 134, 198, 21, 240, 239, 30, 54, 35, 197, 123, 97, 195, 25, 243, 91, 240, 145, 225, 95, 250, 167, 216],
+		[3, 20, 104, 108, 46, 162, 90, 107, 93, 151, 34, 223, 201, 169, 69, 244, 228, 4, 41, 83, 210, 223, 232, 44, 134, 19, 45, 49, 98, 248, 1, 78],
 	],
 	[
-		[127, 100, 74, 231, 4, 65, 246, 139, 163, 157, 5, 236, 87, 210, 75, 23, 59, 46, 139, 196, 204, 134, 11, 53, 155, 42, 109, 108, 177, 63, 31, 240],
-		[16, 128, 231, 200, 235, 103, 125, 208, 161, 110, 122, 229, 39, 172, 124, 119, 174, 165, 110, 136, 2, 122, 32, 143, 241, 172, 55, 176, 250, 11, 16, 9],
-		[93, 99, 158, 111, 31, 220, 186, 18, 21, 164, 182, 232, 163, 154, 8, 73, 136, 96, 50, 247, 238, 192, 247, 8, 12, 19, 142, 213, 52, 64, 141, 129],
-		[124, 71, 77, 183, 176, 76, 125, 176, 89, 198, 34, 59, 4, 124, 116, 152, 180, 43, 75, 197, 191, 189, 4, 81, 95, 55, 48, 171, 55, 154, 42, 209],
+		[240, 6, 190, 76, 194, 205, 136, 113, 213, 26, 232, 108, 215, 202, 102, 80, 162, 18, 235, 71, 244, 91, 56, 89, 141, 20, 150, 154, 187, 231, 44, 88],
+		[132, 250, 2, 8, 88, 240, 158, 8, 70, 83, 81, 142, 255, 214, 145, 228, 133, 255, 163, 146, 25, 212, 53, 53, 190, 125, 12, 87, 181, 110, 31, 38],
+		[221, 195, 184, 188, 198, 124, 167, 130, 251, 45, 64, 26, 29, 144, 203, 222, 18, 38, 4, 136, 47, 37, 183, 27, 216, 191, 34, 21, 10, 208, 22, 158],
+		[139, 237, 71, 249, 25, 0, 106, 186, 98, 128, 233, 24, 103, 174, 188, 235, 187, 48, 92, 151, 254, 143, 85, 240, 232, 104, 143, 26, 212, 151, 172, 176],
 	],
 	[
-		[47, 230, 210, 202, 66, 224, 158, 30, 37, 196, 5, 79, 205, 167, 27, 177, 198, 69, 185, 128, 10, 98, 183, 90, 247, 219, 39, 195, 221, 56, 208, 27],
-		[152, 21, 127, 224, 3, 202, 0, 249, 97, 65, 88, 209, 104, 8, 65, 152, 78, 34, 20, 99, 192, 162, 126, 164, 69, 186, 26, 106, 196, 241, 238, 134],
-		[16, 52, 73, 214, 113, 108, 193, 151, 63, 36, 21, 236, 151, 66, 97, 253, 34, 178, 41, 163, 250, 52, 221, 191, 8, 174, 105, 62, 234, 153, 50, 164],
-		[181, 3, 148, 201, 228, 67, 215, 148, 60, 30, 191, 80, 2, 110, 59, 30, 10, 11, 109, 104, 212, 28, 167, 210, 170, 59, 47, 142, 34, 29, 212, 18],
+		[191, 179, 232, 57, 103, 64, 166, 15, 171, 251, 81, 78, 134, 173, 34, 49, 254, 118, 239, 179, 1, 160, 141, 240, 52, 111, 15, 130, 47, 184, 187, 194],
+		[237, 179, 103, 88, 215, 101, 115, 115, 247, 40, 123, 8, 219, 1, 235, 175, 21, 31, 204, 184, 62, 113, 109, 46, 0, 144, 99, 16, 59, 89, 35, 161],
+		[82, 57, 55, 142, 49, 15, 87, 83, 155, 86, 87, 216, 128, 146, 112, 24, 241, 149, 146, 198, 160, 116, 23, 48, 203, 253, 170, 150, 144, 0, 50, 39],
+		[19, 180, 151, 191, 138, 162, 2, 18, 177, 118, 147, 113, 42, 252, 77, 177, 140, 40, 181, 23, 203, 74, 197, 36, 18, 1, 154, 74, 141, 25, 149, 171],
 	],
 	[
-		[139, 248, 221, 225, 255, 165, 151, 146, 243, 223, 29, 236, 22, 228, 204, 225, 69, 39, 222, 9, 0, 220, 156, 142, 113, 194, 231, 122, 6, 209, 2, 41],
-		[25, 168, 18, 178, 223, 121, 44, 82, 163, 11, 189, 234, 31, 170, 58, 234, 120, 77, 78, 229, 196, 51, 40, 188, 230, 223, 126, 15, 174, 233, 179, 135],
-		[197, 70, 85, 193, 10, 187, 7, 224, 76, 22, 81, 91, 66, 20, 36, 186, 60, 126, 226, 188, 86, 179, 25, 72, 130, 98, 203, 169, 195, 163, 203, 133],
-		[130, 1, 62, 102, 19, 97, 177, 91, 99, 73, 161, 105, 205, 141, 192, 50, 50, 143, 18, 140, 107, 206, 2, 77, 197, 23, 39, 19, 49, 5, 241, 178],
+		[159, 222, 252, 208, 39, 130, 132, 176, 14, 24, 21, 164, 9, 127, 226, 187, 32, 225, 95, 103, 185, 64, 161, 214, 63, 13, 82, 112, 233, 15, 243, 97],
+		[8, 116, 96, 4, 250, 21, 211, 194, 205, 7, 196, 221, 138, 244, 11, 249, 50, 104, 133, 61, 99, 33, 40, 30, 3, 234, 123, 184, 110, 132, 91, 85],
+		[191, 1, 52, 85, 212, 160, 225, 219, 254, 223, 52, 108, 215, 108, 3, 194, 175, 240, 128, 16, 208, 80, 212, 167, 165, 91, 142, 143, 126, 230, 136, 200],
+		[117, 51, 232, 51, 121, 163, 247, 2, 10, 135, 232, 26, 111, 108, 122, 168, 126, 133, 31, 39, 105, 144, 8, 169, 43, 167, 231, 53, 42, 138, 57, 81],
 	],
 	[
-		[70, 155, 12, 222, 183, 222, 255, 64, 233, 148, 193, 55, 73, 126, 197, 253, 131, 138, 188, 87, 27, 14, 147, 239, 141, 40, 150, 156, 29, 156, 16, 174],
-		[151, 73, 1, 97, 233, 67, 104, 128, 85, 252, 189, 15, 192, 170, 87, 78, 197, 2, 247, 89, 107, 164, 98, 121, 186, 203, 222, 32, 93, 85, 13, 94],
-		[231, 153, 63, 213, 163, 124, 134, 136, 115, 1, 146, 202, 160, 212, 156, 132, 128, 123, 214, 187, 87, 72, 168, 97, 192, 82, 244, 84, 39, 172, 43, 141],
-		[71, 83, 74, 246, 82, 43, 6, 240, 14, 38, 237, 88, 172, 47, 234, 157, 171, 16, 169, 247, 47, 120, 235, 214, 0, 71, 77, 44, 223, 19, 14, 196],
+		[89, 13, 235, 131, 63, 172, 67, 186, 237, 235, 191, 133, 121, 253, 230, 128, 147, 133, 178, 207, 136, 217, 229, 198, 166, 56, 147, 44, 178, 129, 243, 241],
+		[0, 251, 114, 216, 50, 24, 129, 221, 45, 45, 71, 201, 31, 141, 111, 194, 214, 255, 67, 156, 11, 172, 184, 222, 224, 110, 13, 155, 85, 233, 148, 193],
+		[220, 73, 218, 121, 211, 221, 248, 8, 222, 131, 224, 159, 141, 16, 104, 221, 20, 98, 32, 97, 11, 117, 36, 48, 87, 239, 199, 2, 230, 40, 46, 140],
+		[12, 86, 255, 239, 210, 94, 103, 111, 4, 103, 87, 215, 122, 118, 161, 19, 118, 233, 198, 66, 77, 192, 34, 194, 81, 38, 41, 228, 16, 101, 230, 75],
 	],
 	[
-		[88, 193, 61, 132, 232, 81, 158, 5, 193, 166, 99, 163, 219, 254, 103, 59, 72, 172, 83, 184, 15, 23, 131, 6, 110, 74, 126, 165, 190, 23, 141, 188],
-		[215, 225, 180, 217, 250, 195, 179, 6, 2, 254, 114, 66, 26, 115, 220, 69, 106, 213, 186, 207, 209, 120, 245, 1, 225, 221, 160, 225, 115, 148, 98, 102],
-		[211, 9, 229, 240, 210, 93, 122, 200, 27, 99, 190, 0, 166, 234, 168, 252, 164, 210, 5, 153, 86, 235, 136, 154, 206, 165, 86, 33, 207, 12, 80, 205],
-		[128, 216, 176, 34, 96, 185, 124, 138, 243, 139, 181, 34, 8, 148, 83, 22, 227, 191, 23, 189, 91, 210, 118, 129, 138, 176, 240, 76, 32, 124, 152, 165],
+		[239, 209, 58, 169, 197, 59, 117, 185, 137, 159, 136, 174, 181, 191, 132, 195, 241, 107, 186, 8, 158, 175, 79, 76, 194, 1, 212, 47, 18, 215, 175, 210],
+		[160, 114, 252, 115, 131, 1, 66, 82, 107, 184, 178, 35, 40, 130, 54, 244, 165, 59, 134, 138, 106, 193, 163, 53, 73, 219, 210, 118, 204, 143, 160, 181],
+		[96, 32, 175, 92, 104, 110, 142, 23, 167, 42, 74, 28, 132, 146, 177, 90, 218, 210, 78, 64, 213, 33, 94, 46, 229, 0, 60, 107, 29, 169, 111, 241],
+		[34, 31, 212, 229, 206, 237, 244, 34, 237, 157, 147, 216, 54, 18, 181, 104, 254, 72, 3, 87, 246, 173, 12, 86, 217, 197, 138, 148, 25, 16, 205, 87],
 	],
 	[
-		[160, 178, 169, 180, 166, 241, 65, 196, 36, 60, 164, 16, 2, 93, 101, 157, 122, 88, 101, 110, 126, 40, 211, 93, 97, 128, 84, 107, 203, 129, 3, 129],
-		[187, 80, 15, 243, 133, 231, 135, 130, 75, 236, 44, 114, 238, 7, 148, 6, 177, 116, 240, 90, 26, 135, 66, 219, 78, 0, 157, 249, 223, 184, 16, 176],
-		[147, 165, 78, 30, 126, 162, 225, 125, 66, 140, 22, 241, 231, 232, 28, 103, 210, 193, 148, 84, 39, 3, 36, 52, 113, 145, 213, 214, 239, 235, 217, 201],
-		[117, 199, 183, 212, 8, 220, 75, 234, 173, 190, 226, 193, 100, 180, 58, 19, 188, 103, 134, 180, 36, 153, 124, 135, 10, 129, 30, 251, 61, 252, 78, 182],
+		[119, 136, 35, 152, 84, 1, 154, 72, 37, 179, 219, 246, 101, 20, 177, 101, 222, 202, 193, 106, 136, 13, 230, 2, 234, 221, 63, 48, 124, 151, 15, 193],
+		[188, 115, 45, 119, 5, 255, 17, 104, 97, 85, 117, 28, 200, 232, 95, 179, 213, 30, 67, 176, 228, 190, 95, 27, 238, 110, 138, 77, 224, 37, 102, 26],
+		[177, 100, 161, 218, 55, 73, 182, 145, 103, 87, 242, 78, 246, 74, 161, 114, 155, 73, 203, 111, 217, 205, 41, 180, 139, 139, 71, 252, 189, 37, 133, 46],
+		[248, 153, 38, 65, 155, 169, 154, 234, 150, 56, 16, 70, 112, 198, 28, 142, 26, 156, 154, 180, 50, 180, 144, 196, 93, 8, 145, 160, 202, 39, 90, 209],
 	],
 	[
-		[159, 232, 238, 181, 65, 99, 218, 246, 192, 244, 79, 26, 53, 28, 150, 176, 222, 88, 84, 12, 61, 206, 165, 29, 239, 74, 170, 142, 124, 93, 200, 219],
-		[1, 176, 33, 197, 182, 218, 220, 64, 30, 170, 77, 94, 8, 174, 220, 114, 35, 248, 245, 127, 19, 39, 217, 182, 71, 128, 115, 22, 45, 108, 213, 57],
-		[98, 83, 3, 138, 206, 101, 154, 244, 121, 29, 108, 19, 114, 213, 242, 65, 7, 134, 230, 163, 175, 3, 204, 227, 164, 79, 241, 102, 78, 100, 77, 139],
-		[100, 135, 4, 238, 229, 24, 74, 222, 8, 167, 82, 58, 143, 56, 49, 142, 6, 28, 166, 23, 24, 46, 81, 117, 193, 34, 187, 24, 245, 63, 126, 36],
+		[229, 81, 188, 84, 250, 161, 16, 228, 161, 110, 119, 2, 240, 124, 230, 72, 153, 107, 179, 40, 224, 4, 37, 88, 89, 116, 184, 212, 156, 218, 161, 168],
+		[74, 69, 100, 80, 4, 176, 33, 29, 224, 99, 30, 90, 45, 215, 27, 0, 74, 17, 174, 88, 30, 72, 237, 62, 111, 162, 144, 237, 220, 145, 162, 198],
+		[215, 182, 234, 143, 138, 97, 85, 81, 48, 153, 89, 121, 103, 86, 88, 235, 3, 195, 139, 152, 16, 110, 49, 173, 49, 253, 43, 48, 93, 233, 170, 190],
+		[21, 27, 27, 120, 235, 76, 192, 73, 216, 122, 39, 171, 68, 145, 178, 199, 122, 246, 135, 225, 7, 31, 255, 114, 128, 136, 254, 124, 230, 255, 82, 142],
 	],
 	[
-		[193, 201, 128, 237, 27, 118, 156, 23, 175, 172, 94, 222, 171, 189, 251, 231, 173, 169, 158, 43, 60, 227, 165, 231, 229, 213, 125, 237, 113, 228, 9, 130],
-		[164, 157, 250, 21, 73, 251, 176, 21, 50, 213, 187, 5, 175, 249, 252, 222, 96, 44, 31, 189, 121, 52, 234, 70, 133, 134, 2, 164, 160, 18, 162, 198],
-		[81, 68, 251, 235, 216, 60, 111, 248, 121, 106, 234, 22, 133, 44, 212, 64, 66, 248, 41, 50, 105, 213, 99, 45, 24, 228, 43, 34, 14, 160, 191, 95],
-		[60, 177, 129, 83, 227, 222, 92, 44, 69, 113, 198, 47, 101, 185, 25, 143, 4, 141, 42, 206, 133, 38, 159, 150, 60, 226, 11, 218, 46, 74, 12, 82],
+		[5, 168, 27, 54, 129, 154, 110, 12, 113, 168, 152, 28, 104, 5, 141, 14, 136, 13, 29, 123, 3, 55, 4, 134, 128, 21, 199, 86, 102, 215, 192, 92],
+		[167, 196, 61, 14, 47, 13, 4, 40, 28, 145, 70, 112, 161, 68, 243, 88, 0, 235, 83, 233, 239, 93, 79, 82, 143, 71, 92, 248, 112, 144, 42, 214],
+		[22, 71, 7, 194, 192, 244, 145, 152, 54, 166, 8, 174, 102, 238, 155, 213, 51, 161, 123, 4, 52, 249, 229, 189, 66, 141, 246, 255, 147, 113, 115, 152],
+		[0, 115, 74, 255, 49, 22, 64, 139, 12, 114, 239, 48, 254, 28, 108, 251, 168, 41, 94, 65, 94, 93, 189, 99, 102, 132, 60, 130, 171, 104, 94, 13],
 	],
 	[
-		[215, 27, 162, 76, 126, 242, 150, 74, 151, 189, 82, 63, 152, 132, 239, 116, 99, 128, 87, 246, 45, 230, 203, 88, 11, 109, 231, 29, 166, 23, 26, 148],
-		[86, 134, 50, 69, 138, 212, 152, 132, 144, 77, 244, 50, 225, 178, 135, 9, 219, 159, 245, 20, 162, 80, 134, 222, 14, 218, 73, 178, 22, 5, 177, 153],
-		[132, 45, 3, 23, 22, 119, 186, 163, 197, 4, 78, 183, 251, 228, 155, 188, 236, 226, 54, 47, 3, 229, 31, 65, 104, 107, 166, 83, 155, 186, 171, 226],
-		[171, 131, 127, 143, 155, 209, 89, 125, 4, 17, 250, 114, 148, 192, 253, 240, 232, 90, 246, 218, 20, 223, 252, 62, 198, 146, 251, 166, 167, 159, 209, 238],
+		[97, 253, 212, 41, 145, 149, 195, 56, 52, 5, 42, 2, 209, 248, 155, 126, 165, 23, 200, 81, 104, 119, 125, 111, 87, 42, 146, 200, 25, 124, 61, 239],
+		[131, 71, 210, 216, 206, 11, 14, 237, 227, 133, 103, 109, 28, 195, 152, 117, 102, 246, 155, 119, 209, 90, 95, 177, 153, 108, 42, 36, 50, 185, 215, 106],
+		[105, 82, 254, 30, 168, 231, 154, 32, 170, 158, 50, 239, 44, 124, 126, 90, 243, 25, 188, 151, 234, 217, 253, 135, 129, 18, 199, 175, 57, 245, 72, 96],
+		[104, 22, 44, 105, 217, 73, 62, 7, 202, 210, 31, 158, 131, 127, 15, 9, 92, 223, 224, 124, 226, 241, 91, 158, 220, 228, 82, 33, 221, 199, 172, 26],
 	],
 	[
-		[202, 106, 99, 83, 170, 75, 2, 204, 144, 204, 111, 180, 23, 45, 245, 26, 110, 12, 85, 208, 185, 74, 22, 241, 10, 231, 216, 245, 159, 220, 144, 168],
-		[217, 152, 101, 242, 117, 95, 35, 188, 57, 68, 216, 51, 126, 81, 21, 111, 66, 104, 113, 66, 80, 126, 43, 35, 150, 113, 245, 169, 45, 133, 207, 78],
-		[101, 67, 222, 182, 78, 4, 149, 32, 127, 9, 197, 48, 10, 248, 123, 156, 96, 17, 6, 110, 4, 217, 6, 20, 88, 212, 203, 64, 99, 173, 28, 226],
-		[243, 199, 54, 21, 71, 61, 28, 120, 42, 105, 151, 73, 19, 97, 100, 57, 149, 82, 248, 115, 109, 12, 94, 236, 137, 169, 86, 104, 91, 43, 4, 63],
+		[32, 124, 75, 231, 146, 241, 142, 84, 105, 255, 170, 194, 107, 237, 183, 10, 58, 238, 74, 134, 79, 80, 187, 188, 81, 49, 153, 205, 21, 193, 50, 93],
+		[152, 32, 94, 254, 176, 53, 35, 255, 139, 213, 127, 129, 132, 104, 184, 208, 143, 211, 19, 233, 211, 61, 101, 27, 129, 73, 44, 243, 153, 25, 30, 229],
+		[67, 53, 116, 97, 20, 168, 170, 36, 70, 159, 85, 17, 28, 43, 247, 10, 49, 111, 116, 237, 11, 228, 47, 106, 228, 246, 127, 1, 159, 65, 211, 103],
+		[48, 174, 97, 176, 117, 195, 183, 90, 146, 130, 206, 32, 244, 193, 130, 109, 25, 38, 75, 86, 70, 194, 229, 166, 39, 22, 177, 71, 110, 137, 142, 62],
 	],
 	[
-		[53, 117, 211, 181, 151, 138, 86, 191, 206, 52, 158, 159, 65, 60, 38, 193, 180, 133, 243, 79, 224, 76, 183, 204, 96, 18, 137, 61, 186, 182, 199, 64],
-		[196, 187, 226, 202, 144, 200, 250, 172, 253, 112, 86, 28, 125, 202, 111, 102, 229, 209, 63, 182, 120, 41, 250, 250, 205, 134, 247, 120, 10, 124, 134, 93],
-		[191, 58, 110, 1, 112, 207, 13, 127, 126, 254, 242, 193, 210, 148, 170, 56, 141, 122, 251, 103, 10, 150, 128, 226, 30, 57, 53, 216, 14, 127, 218, 54],
-		[84, 117, 106, 16, 174, 124, 101, 6, 110, 182, 15, 240, 208, 79, 184, 227, 126, 242, 97, 53, 107, 241, 194, 165, 195, 135, 214, 173, 161, 132, 24, 136],
+		[96, 65, 242, 159, 48, 95, 228, 104, 166, 30, 154, 65, 105, 147, 199, 10, 246, 246, 246, 128, 84, 251, 179, 116, 117, 170, 14, 235, 36, 79, 215, 166],
+		[85, 8, 149, 31, 170, 16, 249, 121, 22, 190, 141, 123, 229, 203, 204, 179, 144, 242, 24, 213, 19, 121, 28, 244, 83, 228, 120, 18, 82, 219, 59, 156],
+		[164, 140, 29, 110, 120, 108, 12, 72, 62, 248, 1, 220, 97, 132, 212, 220, 176, 132, 177, 219, 134, 57, 25, 162, 206, 248, 81, 80, 101, 76, 177, 214],
+		[77, 38, 248, 28, 139, 174, 125, 34, 68, 240, 227, 86, 94, 82, 16, 160, 42, 34, 135, 164, 146, 85, 227, 234, 133, 99, 180, 38, 207, 120, 240, 173],
 	],
 	[
-		[45, 1, 253, 21, 34, 251, 172, 68, 6, 41, 142, 177, 112, 237, 40, 2, 35, 9, 42, 54, 61, 249, 213, 187, 109, 189, 183, 231, 109, 4, 120, 187],
-		[154, 168, 177, 255, 21, 208, 214, 175, 46, 58, 205, 174, 208, 10, 9, 242, 47, 42, 189, 223, 177, 54, 104, 70, 58, 138, 252, 167, 130, 128, 229, 206],
-		[228, 124, 122, 106, 87, 150, 211, 202, 172, 28, 181, 57, 238, 147, 205, 10, 158, 158, 64, 52, 200, 143, 251, 42, 236, 88, 125, 187, 132, 109, 74, 189],
-		[231, 33, 162, 6, 230, 198, 127, 253, 128, 47, 109, 12, 121, 185, 31, 9, 185, 87, 253, 169, 50, 194, 13, 118, 27, 198, 19, 92, 130, 134, 197, 180],
+		[241, 49, 43, 94, 64, 238, 135, 226, 7, 214, 158, 125, 5, 33, 158, 185, 112, 18, 251, 216, 119, 69, 35, 175, 13, 249, 47, 154, 153, 76, 57, 180],
+		[136, 225, 74, 108, 138, 233, 117, 212, 8, 21, 10, 62, 193, 174, 68, 65, 27, 234, 60, 65, 198, 50, 24, 142, 112, 178, 85, 205, 190, 34, 246, 222],
+		[246, 214, 205, 201, 234, 69, 178, 171, 79, 208, 162, 180, 211, 9, 130, 28, 76, 58, 225, 198, 218, 248, 85, 215, 216, 92, 204, 247, 124, 144, 143, 206],
+		[31, 81, 33, 32, 55, 114, 9, 60, 20, 213, 137, 4, 69, 142, 215, 231, 196, 240, 50, 82, 91, 28, 170, 210, 239, 221, 141, 163, 247, 160, 182, 165],
 	],
 	[
-		[57, 15, 91, 133, 235, 75, 177, 101, 213, 218, 213, 87, 218, 219, 225, 120, 140, 200, 230, 104, 172, 18, 238, 153, 188, 18, 84, 86, 248, 34, 45, 175],
-		[224, 175, 160, 59, 197, 171, 71, 121, 219, 71, 27, 60, 230, 178, 69, 22, 177, 173, 17, 41, 207, 98, 147, 13, 166, 150, 200, 175, 82, 139, 57, 114],
-		[60, 65, 36, 187, 223, 55, 252, 206, 66, 11, 232, 72, 202, 46, 121, 181, 40, 95, 143, 226, 174, 168, 69, 116, 19, 165, 10, 32, 123, 208, 76, 209],
-		[45, 45, 17, 158, 219, 147, 202, 17, 28, 94, 7, 105, 100, 105, 88, 242, 227, 103, 99, 197, 93, 78, 177, 60, 37, 94, 127, 27, 226, 176, 119, 171],
+		[44, 98, 220, 62, 126, 76, 122, 36, 201, 164, 190, 212, 164, 28, 192, 174, 247, 236, 135, 47, 37, 201, 105, 45, 245, 153, 228, 222, 54, 185, 125, 154],
+		[194, 151, 91, 229, 144, 161, 193, 189, 194, 116, 237, 55, 187, 104, 136, 119, 27, 221, 80, 111, 113, 13, 137, 92, 213, 98, 117, 88, 216, 124, 208, 110],
+		[187, 160, 205, 125, 233, 84, 94, 141, 213, 110, 89, 49, 101, 24, 181, 239, 84, 100, 170, 218, 87, 81, 20, 63, 82, 109, 92, 63, 233, 64, 181, 9],
+		[225, 106, 38, 155, 248, 42, 225, 83, 254, 170, 18, 180, 122, 171, 170, 218, 113, 183, 147, 35, 98, 250, 113, 17, 88, 92, 49, 19, 92, 116, 134, 161],
 	],
 	[
-		[242, 51, 3, 197, 87, 254, 29, 242, 103, 38, 236, 221, 45, 14, 146, 112, 34, 191, 17, 36, 162, 193, 138, 118, 211, 37, 231, 216, 33, 242, 142, 56],
-		[147, 138, 243, 123, 6, 198, 2, 215, 11, 212, 57, 38, 126, 165, 38, 177, 231, 39, 184, 164, 48, 24, 61, 123, 237, 131, 92, 108, 20, 42, 52, 190],
-		[255, 183, 32, 114, 155, 236, 162, 169, 55, 252, 2, 61, 98, 223, 78, 7, 34, 106, 201, 57, 89, 64, 133, 95, 147, 39, 142, 21, 140, 55, 98, 77],
-		[94, 134, 42, 114, 23, 247, 58, 214, 51, 38, 69, 247, 45, 240, 9, 250, 140, 86, 108, 33, 91, 125, 126, 223, 233, 86, 199, 16, 68, 24, 109, 57],
+		[231, 182, 68, 180, 15, 67, 210, 117, 3, 173, 55, 199, 252, 171, 161, 191, 1, 9, 42, 182, 65, 78, 90, 65, 220, 220, 206, 174, 105, 148, 253, 185],
+		[146, 76, 73, 92, 212, 107, 133, 214, 218, 194, 216, 199, 46, 65, 33, 208, 73, 140, 107, 36, 25, 248, 158, 248, 187, 184, 22, 216, 224, 27, 132, 194],
+		[73, 94, 123, 180, 201, 176, 63, 154, 18, 73, 235, 39, 247, 84, 91, 157, 152, 18, 180, 56, 186, 197, 32, 34, 89, 248, 123, 249, 105, 191, 78, 106],
+		[172, 207, 144, 36, 162, 31, 45, 106, 156, 33, 118, 190, 122, 193, 165, 122, 123, 124, 211, 31, 111, 71, 251, 228, 242, 158, 155, 38, 169, 53, 98, 199],
 	],
 	[
-		[10, 251, 187, 116, 103, 151, 211, 67, 142, 189, 254, 204, 165, 141, 154, 240, 167, 88, 64, 202, 21, 143, 64, 147, 165, 175, 176, 241, 118, 40, 6, 43],
-		[191, 141, 15, 80, 125, 238, 161, 18, 148, 203, 120, 243, 66, 173, 228, 47, 57, 38, 226, 204, 135, 210, 177, 83, 212, 210, 67, 158, 92, 25, 239, 5],
-		[102, 214, 211, 7, 50, 1, 111, 69, 62, 227, 254, 24, 132, 68, 29, 169, 226, 49, 128, 82, 66, 233, 251, 117, 163, 104, 70, 26, 211, 57, 70, 241],
-		[169, 151, 61, 100, 68, 166, 9, 147, 219, 8, 150, 117, 205, 76, 83, 77, 131, 13, 121, 6, 84, 128, 86, 91, 181, 11, 134, 217, 168, 47, 40, 173],
+		[56, 37, 128, 76, 88, 166, 136, 228, 135, 88, 117, 254, 120, 95, 92, 88, 125, 201, 74, 78, 254, 109, 65, 200, 23, 155, 85, 132, 38, 97, 144, 155],
+		[237, 48, 90, 31, 4, 14, 186, 187, 78, 70, 99, 126, 94, 235, 161, 141, 73, 142, 56, 103, 182, 51, 13, 202, 116, 126, 175, 68, 95, 228, 23, 7],
+		[129, 155, 6, 164, 0, 165, 38, 1, 152, 228, 93, 222, 245, 196, 170, 107, 100, 242, 252, 65, 74, 62, 54, 98, 4, 214, 36, 234, 91, 19, 64, 2],
+		[87, 105, 150, 141, 184, 203, 138, 9, 128, 181, 76, 216, 96, 160, 180, 59, 147, 227, 82, 86, 214, 64, 80, 117, 140, 224, 240, 106, 222, 129, 68, 44],
 	],
 	[
-		[235, 126, 128, 162, 194, 25, 75, 50, 252, 171, 22, 49, 181, 87, 88, 197, 125, 96, 166, 190, 114, 31, 195, 99, 232, 79, 227, 205, 249, 37, 72, 45],
-		[50, 238, 158, 136, 102, 203, 222, 0, 10, 142, 116, 99, 149, 94, 223, 170, 141, 19, 229, 66, 236, 128, 92, 1, 147, 105, 135, 74, 152, 200, 13, 240],
-		[86, 156, 153, 130, 117, 73, 139, 158, 158, 165, 50, 156, 233, 216, 17, 98, 196, 217, 80, 241, 2, 118, 7, 181, 117, 15, 117, 130, 171, 119, 34, 53],
-		[238, 253, 232, 167, 30, 59, 192, 107, 57, 144, 171, 145, 130, 22, 3, 78, 96, 156, 74, 76, 133, 144, 16, 26, 219, 173, 63, 204, 117, 20, 233, 114],
+		[219, 141, 28, 86, 141, 38, 168, 103, 1, 67, 92, 105, 8, 92, 104, 24, 126, 250, 243, 79, 42, 188, 240, 199, 229, 131, 39, 115, 132, 53, 210, 184],
+		[54, 13, 84, 113, 152, 109, 220, 231, 232, 53, 194, 146, 167, 182, 228, 161, 201, 249, 165, 14, 212, 33, 241, 98, 109, 46, 162, 110, 180, 201, 209, 166],
+		[179, 55, 203, 125, 229, 17, 99, 207, 40, 126, 193, 117, 82, 80, 133, 33, 138, 87, 40, 137, 183, 175, 45, 146, 87, 231, 183, 26, 169, 84, 19, 68],
+		[224, 83, 17, 11, 155, 72, 113, 105, 188, 21, 78, 27, 58, 52, 102, 42, 255, 193, 248, 79, 159, 46, 4, 141, 110, 180, 39, 70, 68, 86, 15, 7],
 	],
 	[
-		[172, 120, 238, 148, 152, 43, 133, 235, 243, 254, 228, 97, 157, 153, 130, 57, 59, 139, 172, 120, 243, 235, 226, 234, 38, 57, 82, 248, 240, 160, 111, 99],
-		[164, 77, 209, 209, 108, 1, 58, 246, 169, 151, 73, 9, 10, 202, 67, 29, 65, 114, 28, 253, 129, 186, 26, 86, 91, 168, 49, 198, 175, 148, 35, 9],
-		[225, 21, 230, 229, 124, 190, 250, 147, 107, 148, 54, 53, 190, 126, 15, 3, 182, 134, 108, 90, 18, 247, 51, 216, 194, 42, 80, 92, 180, 89, 36, 15],
-		[37, 249, 223, 159, 126, 44, 195, 195, 98, 196, 18, 192, 232, 12, 160, 51, 150, 228, 128, 100, 8, 169, 161, 1, 30, 161, 99, 59, 16, 182, 170, 194],
+		[111, 175, 108, 91, 245, 140, 56, 241, 193, 35, 42, 30, 95, 59, 39, 118, 5, 115, 250, 167, 226, 224, 138, 225, 195, 243, 243, 54, 85, 10, 176, 15],
+		[228, 179, 231, 14, 218, 9, 134, 193, 165, 246, 126, 115, 53, 241, 228, 174, 48, 112, 93, 251, 3, 69, 1, 33, 204, 162, 18, 143, 49, 246, 46, 6],
+		[200, 85, 138, 159, 153, 93, 104, 238, 141, 22, 159, 79, 196, 96, 193, 61, 74, 66, 92, 122, 95, 46, 38, 132, 183, 71, 150, 181, 138, 115, 129, 100],
+		[151, 112, 128, 39, 217, 36, 70, 21, 142, 239, 250, 85, 47, 65, 90, 94, 152, 17, 88, 233, 101, 220, 54, 98, 157, 9, 233, 244, 74, 142, 224, 109],
 	],
 	[
-		[224, 166, 31, 103, 82, 53, 32, 132, 5, 134, 89, 25, 113, 221, 167, 208, 41, 180, 94, 130, 85, 231, 103, 25, 75, 181, 126, 149, 104, 208, 219, 24],
-		[216, 57, 245, 171, 123, 186, 57, 93, 114, 204, 181, 86, 134, 74, 213, 197, 139, 247, 162, 52, 156, 10, 165, 109, 72, 219, 163, 19, 186, 54, 151, 11],
-		[28, 52, 77, 32, 197, 112, 122, 185, 97, 166, 178, 118, 117, 93, 194, 130, 227, 108, 181, 173, 56, 226, 72, 130, 81, 161, 177, 65, 10, 21, 210, 180],
-		[99, 80, 247, 66, 243, 70, 189, 219, 223, 7, 240, 63, 143, 14, 247, 198, 155, 10, 107, 29, 51, 221, 94, 87, 149, 22, 42, 10, 176, 1, 6, 202],
+		[60, 179, 1, 169, 253, 110, 183, 13, 128, 119, 217, 138, 13, 1, 136, 39, 62, 70, 196, 171, 70, 74, 54, 116, 208, 86, 27, 239, 214, 172, 6, 243],
+		[52, 189, 53, 127, 100, 85, 32, 199, 155, 146, 246, 125, 9, 221, 6, 69, 255, 169, 32, 3, 202, 229, 29, 201, 210, 52, 170, 32, 42, 97, 200, 91],
+		[50, 122, 21, 234, 241, 228, 166, 175, 237, 94, 53, 32, 101, 102, 11, 40, 129, 240, 140, 244, 53, 163, 32, 85, 61, 227, 158, 53, 17, 131, 38, 194],
+		[62, 115, 36, 87, 118, 47, 174, 145, 208, 9, 242, 21, 169, 31, 85, 46, 35, 45, 113, 166, 15, 197, 94, 194, 127, 221, 149, 154, 2, 95, 84, 47],
 	],
 	[
-		[74, 54, 22, 173, 227, 239, 201, 221, 188, 95, 197, 155, 27, 55, 84, 28, 16, 222, 194, 143, 198, 26, 235, 36, 115, 251, 238, 0, 2, 65, 52, 10],
-		[148, 251, 8, 85, 40, 246, 79, 103, 107, 246, 122, 87, 235, 90, 147, 8, 11, 20, 62, 224, 251, 185, 125, 202, 238, 149, 240, 240, 226, 63, 149, 56],
-		[20, 165, 169, 82, 174, 103, 171, 157, 13, 143, 73, 84, 126, 139, 98, 22, 139, 206, 126, 90, 240, 36, 232, 228, 254, 52, 181, 96, 159, 49, 198, 233],
-		[61, 222, 121, 197, 226, 198, 35, 33, 210, 60, 26, 216, 107, 2, 54, 24, 122, 126, 69, 10, 70, 71, 92, 97, 134, 196, 206, 230, 113, 146, 246, 2],
+		[251, 125, 59, 55, 162, 7, 123, 97, 233, 82, 37, 10, 241, 29, 184, 156, 200, 162, 16, 244, 178, 3, 56, 204, 81, 32, 21, 39, 93, 197, 2, 203],
+		[101, 3, 253, 58, 163, 64, 127, 65, 133, 224, 23, 191, 100, 201, 222, 59, 40, 113, 53, 218, 207, 90, 121, 192, 196, 148, 39, 91, 184, 175, 179, 155],
+		[234, 55, 43, 182, 194, 195, 195, 17, 94, 117, 106, 120, 102, 160, 121, 47, 113, 30, 214, 122, 51, 87, 30, 197, 9, 220, 57, 62, 151, 13, 209, 11],
+		[248, 149, 202, 203, 178, 206, 152, 5, 110, 151, 56, 115, 60, 220, 3, 101, 38, 6, 97, 53, 224, 26, 115, 32, 23, 146, 95, 241, 162, 133, 47, 25],
 	],
 	[
-		[110, 152, 156, 196, 19, 245, 195, 80, 214, 69, 254, 70, 171, 88, 187, 126, 17, 7, 178, 176, 140, 164, 101, 77, 220, 43, 173, 64, 37, 66, 211, 137],
-		[241, 134, 14, 55, 151, 244, 179, 204, 26, 61, 1, 103, 177, 1, 47, 68, 128, 18, 103, 30, 80, 240, 109, 8, 123, 101, 23, 6, 50, 210, 88, 105],
-		[154, 64, 18, 38, 22, 98, 176, 22, 52, 66, 125, 121, 33, 128, 59, 152, 48, 149, 121, 30, 110, 121, 75, 53, 174, 165, 149, 114, 186, 23, 255, 29],
-		[156, 68, 81, 0, 66, 146, 41, 104, 152, 87, 50, 38, 85, 121, 140, 16, 231, 122, 236, 17, 153, 115, 62, 2, 81, 161, 202, 39, 77, 26, 14, 104],
+		[170, 33, 135, 218, 197, 28, 19, 92, 183, 129, 66, 171, 147, 219, 30, 116, 207, 255, 46, 194, 46, 51, 239, 214, 165, 152, 223, 185, 13, 10, 32, 23],
+		[90, 69, 56, 77, 238, 96, 200, 208, 158, 25, 7, 139, 198, 37, 221, 199, 14, 5, 7, 241, 49, 142, 133, 30, 172, 180, 157, 200, 157, 29, 78, 9],
+		[153, 179, 52, 247, 170, 56, 107, 53, 48, 31, 116, 28, 40, 119, 78, 42, 60, 48, 84, 230, 63, 51, 212, 107, 148, 176, 81, 17, 254, 207, 78, 144],
+		[249, 247, 100, 19, 2, 87, 73, 22, 187, 215, 51, 163, 79, 130, 169, 12, 140, 191, 123, 198, 80, 37, 145, 74, 19, 30, 144, 54, 10, 36, 96, 63],
 	],
 	[
-		[142, 74, 145, 30, 240, 245, 246, 167, 231, 205, 49, 16, 117, 113, 4, 36, 138, 174, 41, 212, 200, 195, 168, 185, 119, 7, 235, 143, 201, 5, 192, 46],
-		[190, 241, 90, 150, 56, 114, 202, 6, 57, 145, 153, 85, 96, 191, 237, 243, 1, 175, 49, 115, 13, 155, 6, 124, 174, 114, 242, 124, 143, 5, 201, 28],
-		[104, 120, 133, 111, 249, 142, 242, 225, 33, 23, 58, 193, 40, 91, 138, 183, 0, 185, 126, 105, 46, 126, 27, 107, 13, 178, 174, 93, 87, 189, 232, 105],
-		[183, 116, 26, 224, 215, 147, 40, 85, 120, 5, 82, 198, 53, 201, 138, 29, 182, 249, 98, 185, 195, 185, 49, 203, 8, 218, 93, 179, 245, 205, 83, 179],
+		[112, 26, 42, 199, 74, 89, 230, 21, 8, 89, 71, 75, 68, 15, 171, 225, 17, 66, 22, 140, 142, 169, 246, 119, 37, 141, 70, 66, 139, 132, 63, 138],
+		[115, 99, 58, 52, 9, 104, 105, 131, 202, 255, 217, 36, 59, 8, 85, 242, 140, 225, 137, 145, 124, 113, 187, 233, 16, 120, 190, 75, 117, 5, 252, 17],
+		[105, 127, 241, 215, 227, 229, 207, 244, 62, 136, 94, 153, 127, 46, 62, 6, 38, 17, 255, 130, 161, 67, 194, 139, 64, 192, 208, 61, 172, 174, 202, 48],
+		[161, 202, 246, 36, 115, 78, 45, 12, 23, 40, 12, 161, 207, 213, 228, 84, 207, 104, 45, 117, 31, 215, 80, 43, 2, 193, 55, 200, 198, 1, 204, 96],
 	],
 	[
-		[194, 251, 253, 22, 189, 164, 88, 155, 214, 116, 245, 158, 159, 155, 202, 88, 169, 62, 117, 122, 193, 141, 116, 126, 249, 64, 100, 87, 140, 51, 173, 76],
-		[140, 156, 22, 206, 100, 173, 99, 216, 173, 195, 10, 132, 27, 253, 227, 44, 178, 14, 184, 209, 24, 130, 102, 225, 31, 55, 131, 106, 27, 99, 81, 153],
-		[197, 152, 202, 127, 195, 119, 36, 155, 218, 177, 118, 3, 199, 254, 246, 65, 139, 232, 203, 98, 30, 213, 146, 200, 254, 84, 139, 110, 173, 128, 164, 70],
-		[78, 55, 175, 138, 199, 45, 87, 166, 78, 133, 172, 7, 67, 75, 172, 27, 227, 100, 71, 194, 24, 224, 36, 191, 52, 166, 207, 18, 122, 74, 127, 241],
+		[89, 177, 41, 23, 242, 230, 240, 238, 220, 32, 3, 117, 74, 75, 99, 253, 140, 141, 222, 166, 72, 138, 121, 106, 35, 105, 218, 44, 0, 67, 242, 242],
+		[47, 62, 198, 60, 242, 44, 151, 89, 118, 201, 14, 214, 220, 84, 243, 209, 4, 99, 16, 15, 152, 62, 79, 106, 114, 148, 150, 98, 245, 141, 102, 239],
+		[203, 181, 60, 198, 224, 5, 40, 132, 252, 166, 34, 59, 229, 239, 62, 87, 2, 143, 33, 168, 251, 5, 250, 255, 128, 96, 250, 195, 122, 168, 126, 71],
+		[244, 100, 141, 224, 234, 95, 113, 185, 246, 25, 23, 161, 133, 80, 125, 53, 57, 4, 237, 234, 56, 206, 95, 200, 102, 59, 138, 146, 225, 231, 51, 83],
 	],
 	[
-		[209, 53, 101, 90, 198, 35, 86, 118, 181, 172, 91, 57, 192, 178, 136, 57, 133, 82, 121, 30, 42, 137, 29, 143, 13, 64, 226, 177, 122, 183, 63, 38],
-		[167, 231, 234, 67, 211, 248, 124, 129, 178, 133, 82, 117, 25, 192, 219, 198, 51, 157, 134, 25, 132, 126, 210, 226, 21, 203, 34, 111, 228, 156, 87, 234],
-		[205, 168, 165, 16, 155, 218, 69, 178, 191, 148, 212, 172, 180, 85, 159, 246, 198, 12, 62, 114, 5, 118, 245, 26, 224, 18, 119, 44, 181, 205, 151, 32],
-		[168, 255, 7, 153, 49, 28, 118, 97, 22, 74, 214, 244, 62, 252, 154, 196, 193, 2, 223, 165, 48, 216, 164, 178, 136, 39, 208, 211, 174, 238, 242, 110],
+		[7, 195, 81, 0, 9, 184, 56, 200, 103, 229, 60, 110, 86, 175, 88, 43, 195, 152, 131, 239, 52, 136, 189, 132, 105, 10, 243, 192, 149, 118, 45, 185],
+		[9, 187, 51, 193, 0, 250, 177, 37, 80, 38, 167, 203, 113, 47, 104, 162, 35, 209, 136, 138, 3, 65, 72, 253, 206, 7, 50, 29, 139, 111, 3, 156],
+		[235, 207, 124, 206, 234, 69, 146, 226, 142, 11, 209, 44, 111, 155, 45, 166, 170, 157, 152, 63, 131, 133, 60, 142, 77, 150, 204, 195, 171, 158, 109, 107],
+		[88, 150, 66, 171, 242, 135, 224, 29, 77, 6, 123, 92, 154, 238, 76, 23, 241, 69, 189, 182, 152, 195, 13, 64, 181, 198, 164, 159, 22, 69, 172, 109],
 	],
 	[
-		[89, 20, 119, 34, 206, 164, 203, 185, 61, 3, 205, 28, 210, 228, 111, 38, 78, 182, 206, 171, 36, 151, 101, 195, 72, 69, 215, 40, 224, 101, 89, 232],
-		[62, 182, 51, 164, 97, 208, 84, 83, 28, 182, 145, 98, 186, 218, 43, 158, 5, 15, 62, 227, 104, 147, 144, 101, 102, 57, 10, 26, 201, 5, 92, 17],
-		[187, 225, 251, 142, 243, 100, 69, 104, 129, 195, 32, 191, 67, 245, 63, 136, 15, 185, 86, 238, 120, 235, 147, 11, 135, 206, 80, 57, 179, 174, 192, 162],
-		[178, 112, 121, 0, 220, 44, 244, 144, 33, 180, 46, 136, 87, 35, 119, 20, 210, 179, 120, 216, 81, 74, 7, 160, 67, 99, 53, 47, 14, 222, 46, 187],
+		[153, 170, 204, 139, 224, 182, 56, 134, 194, 84, 103, 144, 97, 182, 230, 185, 150, 68, 126, 205, 248, 52, 236, 39, 153, 104, 255, 12, 130, 28, 108, 67],
+		[102, 209, 181, 10, 153, 176, 93, 124, 81, 223, 71, 201, 211, 77, 188, 158, 220, 47, 227, 246, 226, 72, 56, 54, 173, 129, 179, 124, 166, 197, 58, 181],
+		[18, 154, 50, 194, 181, 46, 255, 208, 156, 207, 44, 94, 113, 240, 165, 99, 246, 91, 191, 179, 222, 208, 72, 25, 202, 200, 179, 10, 192, 252, 41, 144],
+		[83, 214, 16, 30, 179, 210, 73, 212, 29, 235, 50, 208, 223, 29, 49, 220, 124, 174, 101, 98, 153, 200, 209, 154, 234, 129, 137, 110, 5, 183, 73, 78],
 	],
 	[
-		[57, 124, 14, 163, 19, 28, 169, 146, 208, 170, 4, 12, 180, 84, 133, 82, 81, 185, 117, 170, 156, 66, 210, 215, 196, 149, 69, 87, 172, 61, 148, 78],
-		[59, 100, 213, 220, 42, 125, 151, 67, 116, 129, 35, 145, 3, 13, 34, 217, 210, 65, 214, 23, 166, 145, 118, 144, 203, 0, 241, 30, 73, 75, 223, 51],
-		[239, 235, 156, 150, 15, 88, 171, 43, 118, 247, 185, 213, 226, 237, 58, 194, 8, 155, 250, 34, 254, 163, 229, 232, 184, 162, 141, 145, 33, 11, 104, 63],
-		[51, 129, 158, 143, 149, 10, 161, 48, 89, 136, 56, 223, 96, 136, 71, 218, 183, 113, 127, 179, 11, 16, 26, 16, 104, 217, 106, 84, 53, 197, 126, 84],
+		[247, 206, 109, 9, 239, 193, 114, 114, 140, 30, 48, 139, 28, 234, 87, 176, 186, 156, 53, 63, 76, 62, 81, 253, 97, 45, 106, 139, 242, 210, 18, 28],
+		[93, 21, 113, 225, 154, 74, 254, 148, 187, 35, 128, 28, 248, 14, 135, 49, 121, 211, 70, 202, 167, 58, 231, 206, 10, 169, 167, 172, 242, 156, 42, 18],
+		[52, 201, 114, 236, 251, 139, 17, 156, 242, 167, 50, 35, 213, 56, 28, 171, 103, 139, 155, 67, 250, 222, 211, 220, 38, 115, 131, 45, 198, 173, 244, 0],
+		[55, 39, 246, 195, 153, 194, 6, 109, 103, 167, 241, 171, 40, 18, 170, 206, 140, 6, 170, 190, 114, 52, 114, 68, 244, 85, 161, 116, 182, 165, 175, 94],
 	],
 	[
-		[55, 215, 76, 246, 95, 156, 234, 235, 140, 113, 92, 133, 246, 149, 121, 44, 191, 45, 88, 88, 204, 22, 105, 176, 195, 80, 47, 79, 72, 231, 61, 181],
-		[193, 249, 34, 71, 103, 146, 98, 150, 228, 128, 54, 255, 166, 68, 169, 51, 177, 202, 84, 182, 131, 211, 95, 148, 60, 54, 130, 147, 16, 143, 128, 223],
-		[76, 167, 65, 193, 146, 156, 24, 174, 211, 120, 188, 27, 107, 92, 80, 132, 153, 18, 47, 195, 209, 168, 225, 159, 241, 103, 173, 194, 154, 69, 193, 222],
-		[16, 204, 59, 160, 5, 151, 242, 36, 221, 178, 77, 239, 43, 228, 255, 243, 6, 165, 24, 78, 247, 234, 252, 124, 16, 90, 7, 201, 252, 132, 115, 51],
+		[187, 129, 248, 231, 176, 57, 199, 3, 41, 153, 187, 186, 218, 105, 57, 229, 162, 33, 192, 79, 224, 114, 24, 48, 12, 76, 36, 246, 23, 46, 249, 182],
+		[179, 96, 3, 225, 33, 1, 153, 214, 243, 85, 38, 211, 80, 154, 106, 61, 9, 210, 3, 133, 198, 178, 228, 5, 255, 63, 141, 177, 62, 126, 85, 141],
+		[140, 221, 39, 221, 108, 104, 95, 140, 159, 147, 250, 133, 227, 42, 90, 232, 154, 70, 185, 104, 79, 186, 153, 61, 185, 251, 229, 178, 3, 215, 8, 156],
+		[159, 167, 19, 169, 40, 85, 112, 244, 194, 187, 133, 244, 108, 21, 20, 189, 170, 211, 74, 66, 211, 187, 114, 123, 23, 215, 177, 108, 58, 22, 246, 92],
 	],
 	[
-		[139, 165, 197, 160, 5, 114, 2, 166, 62, 118, 172, 10, 53, 38, 160, 105, 159, 80, 110, 7, 219, 118, 241, 226, 183, 238, 247, 126, 182, 172, 204, 113],
-		[244, 70, 77, 15, 52, 190, 27, 245, 162, 61, 251, 253, 236, 171, 140, 72, 230, 233, 218, 25, 1, 151, 60, 251, 0, 66, 192, 242, 223, 214, 51, 48],
-		[36, 58, 206, 82, 245, 34, 104, 240, 191, 143, 71, 9, 177, 141, 192, 43, 229, 57, 243, 28, 52, 148, 215, 46, 159, 26, 149, 253, 240, 155, 213, 206],
-		[196, 60, 97, 136, 73, 227, 177, 82, 43, 216, 64, 254, 224, 129, 54, 218, 254, 202, 138, 89, 19, 227, 14, 152, 195, 241, 50, 163, 76, 157, 170, 209],
+		[64, 168, 175, 200, 40, 123, 207, 248, 174, 23, 121, 112, 249, 178, 100, 152, 54, 4, 99, 210, 33, 145, 194, 92, 166, 162, 159, 1, 89, 111, 124, 145],
+		[32, 221, 64, 159, 18, 45, 199, 206, 238, 236, 171, 222, 81, 155, 149, 59, 239, 13, 123, 60, 174, 165, 164, 192, 204, 221, 177, 199, 173, 72, 76, 217],
+		[205, 120, 191, 244, 139, 203, 186, 40, 172, 72, 65, 80, 118, 152, 56, 43, 89, 93, 156, 82, 254, 68, 31, 19, 189, 14, 117, 186, 19, 12, 188, 232],
+		[228, 65, 30, 90, 121, 219, 229, 82, 225, 203, 70, 44, 194, 129, 253, 89, 217, 85, 202, 193, 224, 110, 158, 169, 219, 250, 251, 255, 109, 122, 186, 203],
 	],
 	[
-		[1, 251, 74, 80, 144, 65, 237, 83, 112, 161, 0, 32, 253, 57, 239, 23, 136, 194, 242, 31, 85, 78, 247, 196, 173, 117, 199, 231, 61, 138, 236, 106],
-		[13, 24, 46, 114, 151, 132, 7, 201, 203, 49, 253, 171, 160, 226, 191, 205, 215, 120, 70, 26, 63, 88, 136, 69, 253, 232, 47, 36, 157, 49, 103, 62],
-		[244, 212, 137, 207, 97, 183, 157, 140, 179, 66, 119, 232, 84, 47, 245, 108, 144, 125, 242, 1, 24, 23, 65, 153, 60, 17, 113, 44, 163, 122, 33, 56],
-		[62, 22, 232, 90, 136, 184, 101, 49, 225, 152, 192, 60, 62, 131, 125, 165, 129, 114, 22, 203, 36, 21, 59, 160, 17, 184, 117, 215, 56, 59, 249, 181],
+		[102, 120, 47, 240, 90, 109, 224, 84, 8, 50, 53, 175, 135, 164, 37, 17, 15, 163, 143, 178, 32, 217, 128, 68, 93, 17, 133, 84, 126, 84, 195, 140],
+		[64, 3, 51, 119, 69, 189, 151, 80, 190, 68, 173, 30, 83, 78, 193, 37, 164, 140, 162, 21, 108, 198, 27, 77, 109, 84, 135, 204, 147, 243, 26, 167],
+		[95, 45, 181, 53, 222, 242, 206, 65, 47, 36, 210, 190, 232, 114, 27, 159, 209, 228, 243, 22, 18, 77, 27, 107, 166, 169, 151, 31, 50, 203, 72, 142],
+		[83, 74, 91, 233, 142, 177, 100, 101, 192, 59, 169, 20, 124, 171, 211, 74, 69, 127, 154, 50, 43, 129, 223, 228, 179, 252, 24, 34, 255, 66, 154, 148],
 	],
 	[
-		[220, 249, 4, 129, 43, 70, 42, 218, 27, 241, 24, 170, 56, 92, 30, 235, 150, 159, 40, 201, 244, 198, 235, 15, 223, 223, 39, 238, 134, 150, 33, 153],
-		[4, 140, 211, 11, 44, 10, 45, 27, 165, 105, 7, 25, 166, 204, 38, 171, 59, 164, 94, 127, 106, 20, 21, 45, 37, 251, 212, 39, 187, 9, 126, 179],
-		[198, 65, 87, 201, 55, 49, 222, 232, 51, 19, 200, 1, 175, 210, 166, 223, 229, 72, 55, 18, 99, 154, 200, 63, 73, 235, 59, 76, 30, 178, 222, 169],
-		[109, 55, 241, 120, 57, 209, 121, 232, 23, 110, 144, 101, 66, 137, 49, 227, 80, 12, 154, 227, 137, 16, 130, 70, 68, 88, 183, 97, 90, 46, 142, 231],
+		[78, 118, 120, 240, 171, 150, 189, 234, 222, 70, 122, 49, 8, 120, 64, 164, 41, 195, 73, 64, 91, 242, 208, 61, 155, 24, 127, 158, 140, 36, 52, 108],
+		[191, 199, 147, 123, 93, 197, 201, 155, 100, 39, 131, 141, 18, 53, 88, 29, 211, 128, 220, 185, 183, 211, 28, 169, 73, 130, 86, 4, 6, 204, 193, 66],
+		[212, 138, 196, 19, 161, 181, 154, 37, 108, 221, 199, 168, 105, 246, 191, 93, 218, 109, 123, 236, 1, 71, 183, 187, 219, 26, 15, 217, 249, 252, 74, 205],
+		[5, 91, 115, 76, 247, 151, 69, 19, 90, 63, 57, 83, 131, 159, 221, 134, 136, 105, 93, 232, 46, 161, 13, 205, 197, 137, 76, 44, 234, 98, 205, 144],
 	],
 	[
-		[212, 128, 54, 51, 233, 5, 239, 66, 199, 103, 77, 169, 201, 254, 99, 224, 209, 10, 248, 252, 129, 79, 117, 136, 52, 123, 51, 126, 157, 141, 37, 173],
-		[197, 188, 112, 251, 45, 89, 165, 212, 66, 42, 146, 209, 161, 229, 73, 189, 123, 232, 177, 190, 42, 198, 36, 105, 21, 28, 253, 133, 205, 129, 148, 131],
-		[17, 216, 16, 195, 109, 54, 24, 124, 73, 230, 209, 20, 81, 162, 215, 144, 128, 187, 131, 122, 154, 129, 104, 8, 237, 88, 247, 156, 15, 235, 234, 56],
-		[158, 217, 109, 220, 0, 213, 74, 183, 115, 49, 85, 35, 164, 177, 161, 205, 0, 239, 100, 128, 59, 168, 38, 147, 227, 228, 65, 33, 20, 24, 10, 84],
+		[85, 61, 97, 98, 48, 47, 114, 147, 199, 58, 92, 130, 228, 190, 248, 137, 180, 255, 121, 168, 99, 49, 6, 250, 213, 75, 163, 208, 9, 158, 169, 229],
+		[214, 30, 186, 167, 226, 100, 84, 167, 196, 83, 80, 90, 160, 224, 168, 149, 32, 168, 43, 103, 219, 117, 118, 169, 241, 37, 117, 150, 166, 217, 246, 49],
+		[22, 9, 179, 104, 109, 202, 244, 10, 135, 251, 125, 45, 130, 127, 148, 203, 19, 224, 250, 70, 105, 130, 220, 140, 185, 79, 226, 66, 66, 140, 101, 95],
+		[21, 5, 187, 184, 91, 69, 113, 182, 41, 138, 139, 124, 251, 98, 109, 237, 188, 52, 201, 152, 102, 1, 92, 81, 183, 6, 178, 117, 204, 196, 229, 128],
 	],
 	[
-		[24, 73, 154, 247, 0, 119, 195, 174, 231, 86, 37, 242, 184, 38, 91, 110, 78, 139, 240, 152, 146, 98, 59, 28, 157, 65, 175, 58, 154, 43, 185, 26],
-		[9, 88, 206, 140, 194, 224, 122, 144, 85, 101, 133, 136, 90, 233, 49, 71, 13, 98, 148, 230, 93, 72, 14, 202, 18, 63, 175, 89, 220, 211, 197, 130],
-		[46, 202, 187, 15, 130, 154, 68, 103, 103, 41, 16, 209, 145, 120, 70, 19, 84, 44, 26, 202, 242, 55, 229, 53, 195, 47, 197, 221, 174, 143, 194, 232],
-		[206, 139, 163, 244, 218, 242, 201, 109, 199, 6, 163, 180, 101, 180, 241, 155, 173, 241, 27, 66, 200, 224, 102, 252, 5, 89, 162, 71, 101, 1, 32, 25],
+		[82, 18, 102, 250, 73, 226, 71, 112, 11, 168, 123, 111, 62, 184, 59, 191, 106, 232, 72, 57, 178, 123, 6, 179, 139, 159, 30, 237, 146, 101, 14, 197],
+		[213, 113, 29, 80, 202, 64, 65, 186, 138, 76, 147, 204, 137, 138, 127, 152, 169, 31, 26, 191, 168, 52, 134, 157, 6, 162, 222, 198, 18, 18, 82, 172],
+		[248, 107, 101, 181, 126, 28, 198, 65, 212, 147, 237, 96, 164, 5, 39, 203, 244, 185, 62, 131, 250, 244, 119, 66, 235, 137, 197, 120, 154, 20, 177, 165],
+		[206, 212, 70, 198, 183, 202, 89, 255, 231, 148, 222, 47, 232, 110, 9, 68, 59, 119, 148, 118, 239, 149, 101, 231, 197, 255, 211, 235, 43, 86, 96, 147],
 	],
 	[
-		[54, 209, 155, 68, 117, 234, 30, 175, 119, 91, 10, 35, 28, 248, 24, 129, 34, 157, 151, 139, 240, 99, 210, 150, 89, 81, 136, 80, 147, 249, 44, 88],
-		[133, 129, 36, 34, 183, 64, 245, 169, 71, 62, 45, 7, 16, 194, 194, 37, 20, 214, 62, 246, 138, 239, 164, 67, 138, 184, 166, 125, 111, 148, 110, 159],
-		[121, 50, 213, 75, 51, 129, 132, 14, 68, 234, 54, 40, 230, 225, 11, 44, 207, 84, 67, 116, 178, 201, 103, 126, 176, 202, 150, 51, 198, 176, 125, 174],
-		[197, 68, 84, 74, 102, 92, 18, 22, 61, 245, 228, 131, 33, 241, 157, 255, 57, 69, 167, 113, 134, 49, 149, 242, 115, 195, 112, 37, 107, 150, 112, 202],
+		[146, 98, 85, 144, 108, 52, 18, 21, 94, 23, 176, 31, 245, 9, 236, 66, 34, 54, 113, 118, 244, 14, 193, 216, 93, 34, 86, 46, 254, 152, 139, 91],
+		[240, 64, 249, 152, 133, 161, 112, 175, 246, 106, 203, 179, 39, 95, 43, 7, 7, 188, 163, 27, 11, 39, 233, 101, 169, 208, 142, 192, 237, 65, 58, 130],
+		[191, 96, 25, 40, 123, 180, 20, 161, 244, 68, 1, 130, 75, 26, 98, 233, 189, 251, 114, 169, 148, 173, 222, 101, 251, 0, 199, 237, 140, 227, 185, 179],
+		[207, 43, 226, 252, 77, 70, 76, 254, 134, 76, 122, 69, 30, 165, 195, 176, 11, 139, 16, 145, 8, 182, 5, 109, 109, 151, 144, 99, 61, 79, 142, 58],
 	],
 	[
-		[90, 229, 201, 142, 220, 141, 195, 191, 35, 240, 28, 228, 105, 14, 99, 185, 132, 168, 95, 0, 10, 21, 116, 243, 80, 148, 84, 221, 48, 103, 219, 106],
-		[200, 93, 242, 62, 85, 45, 211, 135, 217, 158, 72, 126, 222, 171, 89, 147, 65, 83, 167, 150, 26, 7, 250, 172, 148, 248, 22, 159, 228, 115, 242, 198],
-		[185, 169, 204, 205, 7, 117, 142, 206, 86, 111, 70, 195, 30, 15, 182, 100, 215, 51, 130, 111, 165, 231, 120, 213, 106, 218, 58, 16, 96, 196, 77, 197],
-		[30, 107, 180, 183, 215, 235, 63, 246, 164, 72, 103, 70, 118, 128, 4, 44, 110, 120, 26, 253, 255, 55, 126, 197, 28, 11, 206, 237, 255, 206, 159, 243],
+		[247, 67, 60, 247, 147, 239, 23, 206, 87, 74, 5, 154, 149, 56, 0, 227, 57, 57, 166, 190, 50, 145, 136, 49, 66, 97, 127, 109, 178, 58, 38, 140],
+		[46, 167, 5, 23, 55, 221, 193, 136, 205, 129, 150, 53, 44, 251, 110, 143, 233, 125, 172, 204, 32, 57, 14, 98, 156, 40, 134, 165, 38, 132, 45, 69],
+		[8, 25, 117, 249, 176, 248, 224, 68, 131, 93, 191, 120, 7, 193, 91, 227, 210, 67, 239, 135, 240, 192, 167, 149, 158, 65, 201, 66, 105, 178, 76, 130],
+		[38, 120, 149, 223, 204, 49, 250, 23, 247, 220, 8, 135, 106, 75, 24, 175, 42, 176, 191, 116, 158, 179, 187, 86, 251, 92, 32, 253, 131, 79, 73, 84],
 	],
 	[
-		[51, 229, 180, 149, 211, 100, 121, 52, 42, 140, 232, 177, 129, 249, 177, 152, 193, 84, 107, 114, 25, 235, 57, 143, 88, 32, 36, 225, 124, 181, 10, 8],
-		[227, 65, 208, 26, 138, 51, 201, 142, 30, 185, 48, 54, 224, 220, 161, 9, 158, 205, 163, 215, 95, 164, 13, 0, 40, 33, 75, 138, 20, 93, 204, 177],
-		[246, 233, 121, 12, 147, 69, 158, 211, 129, 99, 38, 19, 205, 151, 106, 56, 93, 44, 112, 13, 100, 93, 1, 21, 75, 233, 254, 57, 122, 243, 218, 195],
-		[72, 18, 31, 147, 41, 28, 91, 64, 106, 3, 29, 177, 152, 168, 94, 18, 179, 219, 199, 188, 224, 49, 187, 145, 219, 166, 254, 105, 247, 176, 141, 112],
+		[233, 89, 150, 249, 94, 217, 168, 123, 51, 188, 2, 251, 199, 225, 169, 74, 26, 170, 249, 143, 47, 79, 46, 38, 66, 102, 24, 61, 172, 253, 177, 55],
+		[203, 180, 208, 94, 112, 88, 233, 180, 136, 91, 197, 137, 239, 12, 225, 245, 111, 142, 98, 158, 89, 227, 17, 46, 59, 35, 10, 101, 11, 152, 19, 203],
+		[187, 37, 203, 214, 69, 193, 30, 172, 141, 90, 52, 176, 109, 28, 87, 161, 93, 232, 247, 137, 175, 86, 240, 101, 160, 30, 163, 240, 107, 136, 54, 106],
+		[242, 79, 184, 59, 232, 15, 20, 101, 200, 153, 39, 1, 232, 26, 62, 32, 252, 184, 68, 25, 119, 230, 24, 188, 149, 182, 180, 10, 48, 97, 54, 155],
 	],
 	[
-		[170, 135, 194, 92, 3, 213, 190, 21, 215, 242, 56, 204, 199, 136, 137, 239, 110, 180, 148, 78, 98, 81, 64, 179, 152, 213, 130, 91, 125, 159, 206, 59],
-		[66, 243, 5, 160, 210, 14, 90, 138, 134, 175, 84, 110, 101, 20, 76, 204, 214, 78, 194, 218, 100, 24, 87, 119, 55, 251, 5, 76, 242, 35, 85, 87],
-		[91, 151, 229, 190, 60, 171, 232, 146, 155, 118, 20, 71, 229, 53, 54, 10, 196, 152, 126, 242, 179, 201, 126, 56, 26, 147, 243, 107, 94, 165, 20, 30],
-		[161, 20, 237, 33, 236, 207, 105, 119, 113, 155, 82, 199, 228, 39, 43, 219, 239, 22, 65, 11, 35, 105, 133, 129, 111, 170, 219, 81, 155, 51, 189, 91],
+		[212, 188, 147, 81, 60, 15, 122, 225, 73, 239, 158, 129, 197, 238, 14, 207, 4, 79, 249, 50, 166, 14, 136, 70, 240, 162, 13, 118, 16, 46, 151, 58],
+		[49, 135, 125, 70, 181, 47, 3, 181, 44, 81, 90, 112, 165, 58, 9, 6, 184, 164, 175, 250, 99, 129, 106, 71, 216, 25, 113, 89, 242, 185, 131, 240],
+		[208, 225, 239, 49, 200, 62, 73, 44, 182, 244, 98, 72, 70, 228, 196, 42, 8, 231, 225, 87, 243, 127, 60, 102, 190, 90, 198, 149, 223, 134, 97, 149],
+		[197, 195, 230, 67, 251, 177, 55, 124, 122, 63, 74, 230, 152, 108, 71, 138, 128, 54, 35, 71, 181, 91, 81, 71, 30, 210, 64, 110, 239, 175, 201, 158],
 	],
 	[
-		[202, 123, 3, 99, 151, 229, 152, 125, 48, 206, 184, 186, 203, 65, 37, 96, 243, 253, 102, 167, 25, 242, 122, 166, 95, 2, 27, 177, 45, 21, 106, 47],
-		[170, 20, 131, 217, 30, 81, 140, 230, 41, 13, 137, 168, 213, 99, 116, 139, 119, 195, 177, 124, 216, 183, 143, 110, 88, 168, 69, 14, 95, 237, 7, 19],
-		[122, 151, 238, 90, 188, 205, 26, 203, 33, 227, 85, 10, 249, 20, 14, 84, 81, 247, 145, 229, 133, 209, 119, 70, 213, 80, 30, 208, 114, 227, 187, 209],
-		[178, 97, 139, 41, 138, 69, 178, 161, 163, 231, 72, 104, 45, 141, 57, 160, 245, 94, 46, 185, 226, 242, 255, 38, 175, 175, 203, 247, 170, 169, 53, 188],
+		[245, 47, 221, 135, 69, 232, 155, 139, 158, 81, 99, 61, 253, 68, 153, 144, 83, 253, 95, 31, 7, 221, 25, 1, 44, 86, 207, 185, 87, 184, 87, 31],
+		[237, 141, 211, 37, 176, 7, 209, 154, 59, 174, 226, 108, 247, 103, 128, 203, 28, 37, 249, 154, 18, 246, 250, 172, 52, 66, 88, 63, 247, 163, 53, 36],
+		[54, 208, 92, 67, 24, 209, 179, 42, 0, 156, 4, 26, 85, 55, 134, 140, 223, 232, 57, 188, 130, 153, 123, 169, 128, 138, 36, 51, 94, 167, 81, 128],
+		[233, 32, 141, 251, 160, 69, 71, 94, 115, 101, 224, 99, 210, 148, 238, 245, 111, 24, 220, 168, 157, 187, 32, 62, 6, 207, 144, 169, 180, 87, 127, 84],
 	],
 	[
-		[46, 58, 58, 10, 81, 151, 30, 22, 104, 160, 253, 156, 119, 81, 191, 41, 233, 112, 24, 80, 215, 152, 49, 119, 247, 48, 100, 165, 179, 53, 23, 33],
-		[211, 196, 108, 111, 186, 102, 137, 2, 192, 100, 118, 83, 23, 50, 120, 5, 248, 72, 24, 58, 206, 63, 24, 72, 11, 41, 132, 223, 205, 132, 183, 172],
-		[78, 61, 170, 10, 118, 102, 212, 193, 180, 59, 191, 121, 190, 110, 74, 139, 50, 166, 8, 202, 158, 130, 49, 148, 66, 196, 62, 21, 58, 87, 109, 111],
-		[191, 16, 239, 126, 233, 186, 159, 33, 146, 8, 191, 132, 47, 84, 220, 148, 111, 73, 23, 229, 85, 150, 134, 242, 186, 220, 224, 40, 236, 149, 208, 11],
+		[62, 139, 137, 182, 142, 181, 68, 118, 50, 162, 144, 1, 104, 137, 75, 4, 91, 179, 94, 56, 100, 203, 5, 6, 179, 172, 144, 117, 209, 74, 150, 92],
+		[41, 204, 227, 43, 103, 163, 234, 206, 176, 49, 187, 231, 5, 155, 224, 35, 187, 198, 214, 241, 174, 142, 156, 221, 170, 177, 193, 142, 174, 209, 27, 83],
+		[38, 28, 116, 65, 23, 166, 43, 195, 202, 79, 133, 106, 193, 18, 230, 219, 193, 140, 9, 186, 150, 117, 140, 112, 184, 12, 246, 34, 121, 181, 39, 187],
+		[97, 130, 7, 138, 244, 42, 16, 28, 245, 254, 220, 216, 69, 166, 169, 50, 14, 242, 116, 210, 13, 181, 70, 190, 171, 212, 116, 102, 204, 193, 25, 8],
 	],
 	[
-		[2, 82, 61, 51, 131, 244, 184, 37, 32, 115, 254, 101, 5, 50, 242, 128, 98, 234, 197, 209, 160, 12, 227, 16, 237, 185, 191, 124, 92, 106, 136, 139],
-		[174, 254, 120, 70, 52, 99, 92, 87, 132, 93, 115, 154, 176, 187, 177, 129, 254, 149, 182, 171, 40, 115, 107, 8, 22, 20, 156, 30, 29, 145, 31, 92],
-		[152, 45, 164, 149, 70, 48, 204, 85, 193, 126, 184, 240, 172, 69, 61, 143, 166, 120, 60, 84, 58, 129, 209, 158, 189, 24, 189, 123, 224, 107, 21, 139],
-		[154, 79, 76, 76, 89, 187, 222, 247, 57, 113, 212, 155, 237, 115, 44, 239, 164, 239, 90, 245, 16, 51, 91, 87, 191, 137, 173, 239, 118, 191, 218, 217],
+		[152, 107, 233, 174, 17, 210, 253, 210, 9, 150, 142, 135, 100, 133, 186, 138, 92, 250, 194, 107, 131, 252, 79, 208, 193, 77, 169, 146, 166, 161, 238, 49],
+		[34, 13, 100, 148, 36, 180, 108, 72, 52, 160, 179, 210, 23, 123, 232, 8, 224, 235, 237, 254, 224, 211, 231, 146, 92, 89, 26, 24, 110, 240, 202, 89],
+		[39, 24, 194, 19, 255, 217, 106, 246, 66, 242, 112, 31, 159, 80, 160, 118, 46, 69, 238, 31, 254, 251, 5, 120, 94, 230, 140, 149, 13, 30, 225, 85],
+		[1, 20, 120, 55, 104, 202, 109, 136, 136, 152, 37, 197, 155, 15, 228, 207, 219, 168, 174, 181, 253, 100, 177, 36, 18, 83, 4, 236, 32, 166, 38, 212],
 	],
 	[
-		[62, 106, 44, 62, 199, 224, 158, 20, 166, 123, 168, 222, 160, 221, 238, 235, 210, 13, 212, 230, 250, 197, 17, 148, 151, 64, 195, 235, 125, 154, 118, 114],
-		[254, 234, 205, 14, 175, 98, 201, 191, 62, 230, 209, 180, 145, 124, 34, 181, 225, 217, 0, 173, 176, 179, 176, 110, 62, 66, 189, 57, 245, 157, 234, 0],
-		[122, 224, 77, 136, 36, 187, 48, 56, 233, 252, 75, 19, 231, 52, 169, 79, 74, 97, 251, 83, 36, 94, 120, 32, 142, 96, 164, 100, 56, 152, 162, 227],
-		[110, 204, 3, 85, 229, 78, 99, 66, 168, 163, 40, 222, 127, 225, 239, 41, 36, 148, 219, 170, 86, 51, 57, 143, 84, 114, 112, 196, 216, 144, 249, 214],
+		[157, 252, 112, 138, 53, 162, 230, 99, 99, 182, 14, 29, 203, 173, 133, 95, 42, 130, 167, 70, 23, 25, 158, 200, 138, 83, 163, 129, 52, 62, 114, 53],
+		[202, 101, 122, 35, 47, 64, 217, 142, 175, 67, 94, 104, 31, 138, 66, 42, 132, 76, 79, 78, 26, 227, 103, 28, 80, 18, 123, 5, 64, 46, 192, 209],
+		[26, 148, 126, 157, 88, 214, 38, 43, 221, 194, 13, 84, 100, 5, 92, 191, 219, 234, 103, 162, 179, 33, 86, 210, 190, 68, 68, 156, 177, 138, 180, 58],
+		[244, 212, 77, 221, 231, 243, 223, 50, 54, 118, 83, 245, 69, 122, 223, 56, 132, 173, 83, 40, 41, 150, 56, 100, 207, 11, 53, 39, 33, 64, 237, 59],
 	],
 	[
-		[119, 156, 152, 118, 9, 136, 132, 167, 116, 125, 7, 251, 227, 58, 153, 218, 148, 16, 155, 180, 23, 70, 20, 80, 210, 197, 15, 181, 167, 204, 232, 144],
-		[104, 117, 173, 209, 252, 81, 103, 87, 155, 0, 53, 226, 143, 90, 234, 19, 230, 105, 187, 128, 194, 242, 223, 239, 140, 176, 83, 10, 34, 249, 114, 177],
-		[217, 212, 142, 177, 116, 175, 58, 148, 237, 175, 32, 169, 202, 223, 151, 190, 110, 35, 229, 98, 183, 204, 20, 125, 0, 9, 193, 209, 141, 254, 11, 54],
-		[241, 149, 203, 180, 153, 151, 159, 134, 154, 74, 179, 12, 175, 225, 164, 60, 185, 248, 21, 235, 206, 113, 171, 119, 170, 186, 24, 206, 102, 151, 249, 242],
+		[156, 220, 162, 93, 161, 153, 223, 211, 163, 204, 173, 65, 105, 135, 149, 43, 3, 18, 113, 65, 193, 187, 176, 176, 79, 14, 237, 233, 48, 223, 87, 194],
+		[66, 176, 15, 30, 95, 77, 87, 180, 248, 117, 166, 149, 62, 249, 85, 201, 157, 196, 233, 234, 168, 58, 66, 3, 75, 110, 157, 80, 201, 58, 60, 4],
+		[90, 175, 225, 15, 83, 136, 124, 230, 7, 130, 227, 29, 15, 235, 102, 147, 13, 10, 41, 17, 88, 20, 119, 165, 71, 156, 69, 24, 150, 62, 98, 103],
+		[7, 121, 161, 37, 205, 54, 103, 241, 5, 158, 235, 2, 65, 184, 197, 91, 147, 188, 38, 26, 246, 174, 59, 173, 49, 134, 238, 14, 153, 114, 239, 23],
 	],
 	[
-		[11, 18, 54, 10, 80, 176, 241, 151, 91, 110, 172, 65, 87, 105, 70, 8, 171, 17, 83, 230, 88, 61, 191, 170, 209, 8, 218, 168, 113, 95, 154, 150],
-		[132, 196, 252, 121, 200, 149, 3, 246, 203, 1, 7, 157, 235, 193, 109, 83, 242, 214, 92, 222, 157, 182, 160, 197, 9, 4, 85, 161, 150, 147, 113, 20],
-		[190, 144, 133, 38, 227, 67, 247, 13, 59, 153, 181, 172, 145, 74, 233, 196, 190, 123, 177, 4, 19, 198, 211, 236, 210, 128, 99, 150, 195, 12, 108, 156],
-		[15, 120, 206, 84, 146, 89, 250, 225, 54, 175, 219, 147, 125, 21, 3, 6, 170, 71, 22, 116, 232, 205, 241, 54, 78, 13, 92, 51, 52, 13, 187, 45],
+		[93, 208, 206, 61, 142, 225, 176, 115, 144, 163, 217, 83, 113, 73, 185, 46, 176, 75, 132, 63, 249, 74, 238, 174, 119, 60, 241, 189, 248, 219, 57, 19],
+		[237, 21, 167, 51, 18, 171, 164, 254, 135, 193, 124, 133, 55, 252, 206, 122, 16, 89, 128, 104, 105, 128, 251, 51, 181, 35, 104, 121, 126, 173, 245, 42],
+		[0, 117, 250, 251, 48, 132, 6, 161, 0, 200, 90, 194, 241, 107, 22, 50, 68, 76, 77, 195, 162, 110, 179, 98, 89, 212, 128, 32, 63, 154, 185, 109],
+		[200, 48, 234, 222, 239, 177, 233, 86, 245, 160, 218, 42, 36, 15, 35, 173, 18, 120, 144, 81, 62, 163, 26, 20, 227, 10, 97, 245, 37, 12, 21, 182],
 	],
 	[
-		[206, 10, 158, 238, 124, 177, 166, 212, 200, 190, 194, 220, 244, 228, 128, 93, 44, 102, 226, 111, 55, 117, 64, 250, 19, 148, 162, 123, 76, 254, 92, 160],
-		[99, 242, 33, 12, 119, 211, 146, 222, 128, 98, 156, 128, 84, 83, 35, 221, 189, 4, 155, 17, 184, 137, 57, 171, 210, 175, 83, 17, 173, 81, 239, 82],
-		[80, 3, 26, 28, 15, 186, 41, 115, 179, 192, 27, 70, 13, 106, 78, 37, 186, 246, 38, 88, 167, 172, 20, 75, 67, 74, 191, 83, 25, 235, 15, 192],
-		[182, 208, 102, 104, 166, 139, 95, 147, 208, 131, 7, 39, 66, 145, 207, 201, 41, 47, 54, 254, 228, 217, 115, 78, 119, 0, 244, 35, 71, 224, 19, 148],
+		[241, 14, 66, 170, 117, 7, 72, 36, 83, 160, 89, 73, 87, 40, 33, 51, 82, 43, 128, 70, 241, 180, 234, 102, 21, 200, 37, 215, 110, 116, 160, 188],
+		[125, 207, 250, 116, 232, 22, 97, 132, 251, 6, 112, 133, 62, 176, 57, 32, 100, 227, 94, 27, 76, 211, 176, 179, 86, 203, 88, 164, 81, 112, 151, 78],
+		[186, 147, 123, 166, 143, 171, 11, 195, 68, 173, 76, 218, 233, 17, 23, 179, 82, 201, 98, 117, 67, 245, 231, 88, 82, 175, 224, 163, 148, 70, 142, 140],
+		[240, 242, 68, 95, 229, 3, 136, 177, 200, 186, 149, 37, 230, 89, 237, 81, 124, 160, 91, 134, 189, 144, 187, 253, 244, 49, 58, 188, 88, 39, 235, 6],
 	],
 	[
-		[0, 125, 6, 35, 237, 0, 131, 65, 170, 243, 18, 47, 108, 227, 22, 94, 93, 131, 57, 37, 37, 101, 38, 3, 141, 117, 49, 239, 125, 27, 55, 44],
-		[162, 1, 232, 47, 129, 27, 120, 95, 1, 154, 114, 42, 239, 9, 252, 56, 128, 67, 83, 72, 187, 155, 189, 159, 141, 31, 224, 36, 185, 38, 215, 43],
-		[186, 105, 159, 90, 124, 216, 49, 123, 13, 201, 41, 248, 134, 218, 162, 166, 74, 153, 64, 77, 22, 58, 159, 244, 150, 204, 198, 241, 21, 144, 143, 229],
-		[39, 118, 165, 166, 252, 193, 40, 241, 108, 159, 222, 144, 229, 215, 197, 45, 165, 248, 169, 179, 234, 129, 255, 220, 223, 207, 69, 26, 219, 34, 241, 130],
+		[91, 82, 58, 194, 21, 15, 110, 37, 173, 218, 117, 169, 177, 215, 143, 75, 123, 38, 17, 172, 175, 185, 88, 177, 92, 125, 44, 101, 22, 135, 253, 103],
+		[194, 5, 44, 154, 91, 153, 28, 235, 50, 132, 16, 209, 202, 36, 128, 214, 191, 173, 228, 147, 211, 142, 104, 41, 175, 45, 34, 245, 177, 174, 118, 206],
+		[127, 47, 90, 89, 239, 36, 128, 78, 220, 15, 6, 92, 121, 183, 48, 180, 111, 56, 64, 8, 28, 169, 137, 14, 239, 93, 156, 85, 178, 200, 156, 87],
+		[77, 193, 34, 83, 72, 63, 243, 126, 105, 4, 217, 168, 122, 59, 252, 171, 171, 120, 153, 86, 96, 239, 189, 61, 215, 186, 63, 21, 222, 157, 107, 134],
 	],
 	[
-		[39, 87, 209, 23, 76, 188, 79, 9, 233, 175, 50, 31, 102, 144, 221, 91, 163, 229, 194, 196, 113, 242, 144, 153, 85, 243, 180, 31, 169, 226, 82, 168],
-		[39, 146, 164, 10, 66, 98, 131, 224, 205, 0, 202, 81, 193, 244, 39, 6, 164, 1, 149, 1, 225, 51, 93, 12, 146, 187, 192, 44, 8, 219, 71, 42],
-		[238, 101, 1, 22, 92, 136, 100, 189, 61, 92, 120, 194, 81, 141, 79, 12, 255, 255, 15, 135, 178, 138, 178, 250, 0, 186, 240, 162, 23, 64, 168, 164],
-		[142, 181, 149, 152, 158, 72, 133, 15, 99, 216, 209, 5, 97, 246, 137, 108, 82, 123, 112, 83, 3, 70, 244, 64, 10, 182, 240, 70, 98, 3, 183, 159],
+		[113, 44, 22, 53, 35, 89, 251, 119, 0, 200, 214, 119, 74, 69, 116, 50, 124, 70, 120, 180, 178, 7, 236, 238, 202, 19, 226, 63, 51, 203, 15, 112],
+		[143, 75, 101, 200, 248, 134, 224, 224, 63, 192, 175, 26, 134, 175, 72, 63, 57, 68, 182, 253, 100, 81, 190, 104, 140, 174, 25, 163, 118, 225, 170, 166],
+		[175, 173, 88, 170, 110, 197, 15, 67, 175, 154, 13, 126, 98, 251, 176, 129, 143, 148, 243, 112, 139, 166, 39, 78, 204, 192, 129, 255, 2, 233, 119, 184],
+		[67, 86, 235, 154, 254, 217, 27, 123, 205, 54, 153, 33, 198, 23, 158, 175, 14, 41, 85, 194, 145, 119, 5, 249, 64, 240, 145, 27, 230, 54, 20, 189],
 	],
 	[
-		[75, 176, 117, 221, 217, 193, 77, 51, 27, 52, 85, 71, 116, 84, 206, 176, 123, 16, 119, 183, 107, 212, 249, 254, 236, 120, 19, 128, 52, 206, 170, 137],
-		[236, 189, 249, 190, 98, 95, 221, 254, 71, 1, 226, 67, 250, 22, 174, 110, 106, 146, 62, 218, 2, 5, 61, 187, 243, 213, 110, 224, 163, 70, 87, 174],
-		[252, 5, 47, 52, 211, 71, 180, 27, 204, 47, 82, 225, 97, 99, 30, 232, 156, 167, 213, 36, 199, 48, 142, 37, 173, 125, 158, 132, 103, 85, 223, 51],
-		[68, 80, 246, 126, 10, 178, 68, 173, 6, 249, 151, 61, 208, 40, 137, 35, 24, 178, 116, 222, 142, 141, 251, 9, 103, 135, 77, 97, 77, 209, 148, 177],
+		[109, 198, 29, 178, 19, 43, 174, 209, 174, 142, 38, 31, 173, 38, 8, 233, 81, 62, 27, 47, 133, 229, 110, 178, 146, 181, 194, 93, 195, 28, 145, 177],
+		[255, 47, 206, 113, 147, 84, 70, 156, 205, 65, 230, 229, 141, 244, 97, 93, 194, 37, 77, 7, 248, 99, 174, 71, 109, 67, 111, 68, 128, 170, 171, 69],
+		[167, 116, 97, 173, 9, 146, 171, 61, 71, 121, 74, 69, 86, 34, 79, 39, 220, 112, 123, 171, 235, 236, 198, 58, 130, 27, 253, 46, 236, 195, 17, 81],
+		[109, 36, 17, 95, 93, 211, 88, 92, 197, 121, 73, 242, 199, 58, 152, 157, 4, 151, 210, 57, 135, 10, 12, 35, 78, 182, 151, 162, 101, 8, 246, 74],
 	],
 	[
-		[70, 169, 137, 33, 149, 132, 16, 131, 202, 20, 169, 67, 23, 2, 108, 249, 177, 119, 161, 84, 11, 35, 88, 196, 188, 225, 6, 227, 230, 97, 250, 140],
-		[139, 136, 121, 97, 103, 82, 222, 156, 172, 161, 206, 19, 60, 100, 22, 15, 182, 253, 157, 210, 116, 226, 143, 69, 202, 147, 35, 18, 134, 98, 106, 111],
-		[83, 90, 232, 50, 101, 113, 49, 222, 95, 162, 164, 175, 121, 191, 115, 126, 250, 149, 64, 149, 74, 54, 67, 37, 217, 51, 145, 34, 75, 237, 63, 204],
-		[114, 65, 7, 188, 78, 136, 40, 196, 27, 162, 82, 62, 90, 11, 66, 73, 10, 200, 186, 94, 161, 85, 107, 102, 35, 61, 34, 119, 30, 243, 128, 55],
+		[19, 58, 224, 147, 199, 222, 143, 251, 95, 190, 48, 157, 108, 151, 233, 56, 249, 159, 126, 222, 33, 57, 159, 125, 231, 197, 181, 47, 53, 233, 132, 150],
+		[188, 77, 143, 192, 47, 27, 116, 139, 217, 74, 44, 37, 188, 70, 92, 241, 72, 15, 227, 211, 130, 141, 161, 250, 69, 9, 208, 156, 77, 76, 194, 46],
+		[34, 239, 194, 120, 177, 255, 196, 240, 47, 48, 167, 88, 15, 105, 46, 25, 3, 92, 47, 196, 231, 160, 222, 62, 199, 20, 129, 34, 55, 127, 55, 237],
+		[110, 250, 140, 58, 213, 255, 243, 238, 0, 249, 189, 148, 137, 163, 244, 32, 13, 58, 45, 128, 182, 245, 69, 222, 113, 83, 157, 150, 121, 222, 216, 227],
 	],
 ];
 
-pub const H2_PEDERSEN_PARAMS_BYTES: [[[u8; 32]; 4]; 256] =
+pub static H2_PEDERSEN_PARAMS_BYTES: [[[u8; 32]; 4]; 256] =
 [
 	[
-		[255, 96, 228, 29, 157, 221, 115, 178, 220, 186, 239, 17, 181, 26, 11, 121, 118, 0, 157, 13, 87, 55, 124, 201, 113, 228, 56, 232, 51, 164, 198, 167],
-		[70, 109, 113, 213, 108, 227, 251, 158, 150, 237, 231, 162, 50, 198, 116, 136, 51, 112, 186, 128, 33, 107, 207, 152, 13, 254, 119, 254, 106, 218, 245, 193],
-		[222, 109, 19, 127, 104, 1, 60, 197, 229, 251, 78, 86, 236, 222, 170, 162, 120, 100, 0, 96, 156, 20, 98, 171, 136, 186, 96, 53, 52, 203, 194, 174],
-		[195, 2, 83, 25, 179, 115, 237, 194, 250, 217, 231, 142, 119, 225, 88, 26, 24, 18, 108, 160, 160, 75, 205, 236, 71, 61, 184, 137, 139, 234, 171, 217],
+		[35, 225, 127, 126, 168, 16, 32, 57, 126, 163, 104, 134, 32, 149, 56, 14, 199, 35, 55, 52, 84, 18, 179, 13, 31, 204, 101, 192, 110, 55, 238, 215],
+		[191, 3, 223, 249, 183, 81, 174, 154, 243, 82, 177, 92, 191, 70, 207, 186, 94, 74, 36, 222, 184, 161, 112, 14, 112, 83, 95, 121, 211, 16, 184, 170],
+		[245, 185, 57, 24, 242, 104, 254, 124, 32, 89, 68, 178, 57, 243, 122, 117, 48, 75, 201, 134, 191, 214, 167, 128, 128, 36, 10, 182, 26, 90, 168, 48],
+		[129, 118, 254, 134, 207, 149, 149, 21, 35, 250, 15, 153, 217, 88, 149, 92, 167, 19, 238, 235, 191, 27, 71, 49, 11, 162, 48, 86, 216, 82, 195, 69],
 	],
 	[
-		[209, 74, 251, 38, 177, 224, 195, 110, 107, 89, 66, 62, 126, 193, 213, 236, 85, 163, 140, 246, 63, 173, 238, 91, 86, 177, 76, 40, 44, 170, 193, 144],
-		[110, 29, 196, 18, 227, 231, 78, 191, 98, 174, 160, 145, 110, 132, 183, 82, 7, 26, 133, 165, 11, 197, 236, 18, 227, 95, 81, 183, 211, 122, 50, 133],
-		[52, 21, 243, 245, 114, 187, 170, 14, 131, 33, 59, 44, 116, 19, 23, 221, 118, 237, 145, 66, 138, 214, 66, 171, 12, 187, 175, 23, 158, 92, 106, 11],
-		[75, 94, 153, 85, 63, 212, 48, 213, 65, 145, 201, 49, 90, 243, 205, 35, 137, 15, 84, 228, 243, 185, 231, 114, 54, 35, 123, 217, 87, 38, 143, 205],
+		[145, 202, 101, 104, 113, 189, 176, 136, 176, 126, 150, 0, 65, 190, 55, 97, 58, 205, 213, 223, 104, 134, 199, 227, 249, 111, 131, 19, 239, 81, 190, 198],
+		[96, 248, 30, 51, 227, 64, 91, 65, 136, 145, 18, 160, 249, 185, 16, 231, 212, 196, 49, 35, 156, 20, 82, 236, 86, 146, 44, 8, 53, 201, 53, 21],
+		[149, 241, 166, 134, 155, 228, 125, 80, 88, 43, 155, 210, 27, 43, 175, 0, 94, 161, 246, 136, 73, 183, 36, 255, 70, 167, 161, 70, 119, 180, 37, 160],
+		[105, 9, 80, 123, 218, 248, 207, 192, 153, 218, 36, 55, 95, 52, 112, 197, 67, 101, 240, 101, 255, 149, 142, 6, 72, 112, 168, 52, 215, 109, 193, 101],
 	],
 	[
-		[104, 156, 225, 41, 199, 41, 201, 156, 228, 196, 102, 89, 65, 34, 108, 251, 204, 228, 126, 16, 109, 135, 150, 212, 65, 27, 13, 73, 148, 67, 218, 183],
-		[65, 153, 2, 206, 93, 116, 26, 157, 40, 90, 133, 17, 189, 120, 190, 16, 118, 125, 120, 119, 192, 241, 87, 113, 20, 196, 162, 40, 135, 96, 167, 82],
-		[95, 114, 237, 159, 248, 70, 26, 245, 60, 226, 175, 113, 166, 27, 213, 132, 166, 201, 124, 239, 8, 24, 236, 25, 70, 160, 151, 63, 29, 203, 134, 59],
-		[104, 62, 74, 217, 176, 9, 46, 132, 167, 205, 5, 68, 182, 26, 106, 142, 105, 253, 182, 4, 2, 54, 41, 69, 64, 151, 237, 234, 208, 44, 225, 100],
+		[208, 64, 176, 115, 14, 191, 96, 87, 141, 234, 203, 145, 89, 209, 69, 122, 138, 106, 148, 112, 148, 204, 8, 76, 187, 112, 138, 193, 136, 164, 202, 64],
+		[253, 46, 236, 133, 199, 55, 173, 133, 235, 46, 10, 78, 130, 144, 40, 143, 98, 46, 185, 25, 212, 233, 139, 181, 78, 126, 200, 63, 37, 107, 132, 185],
+		[72, 166, 102, 169, 100, 160, 29, 241, 23, 109, 235, 150, 194, 101, 126, 174, 121, 51, 103, 23, 89, 110, 234, 157, 123, 216, 172, 214, 8, 147, 102, 138],
+		[234, 180, 140, 189, 109, 193, 137, 164, 192, 51, 253, 14, 72, 79, 198, 64, 155, 181, 90, 198, 183, 198, 40, 198, 175, 4, 128, 94, 164, 101, 231, 214],
 	],
 	[
-		[97, 182, 172, 58, 134, 69, 2, 244, 108, 253, 117, 206, 234, 105, 46, 193, 253, 71, 178, 114, 74, 143, 94, 152, 70, 103, 127, 73, 72, 50, 167, 4],
-		[189, 67, 85, 166, 162, 147, 154, 3, 175, 237, 106, 50, 124, 146, 15, 121, 234, 67, 129, 218, 212, 230, 146, 77, 4, 253, 110, 25, 115, 142, 206, 42],
-		[236, 114, 15, 78, 247, 54, 223, 144, 42, 158, 154, 221, 216, 221, 196, 52, 122, 130, 101, 229, 204, 192, 51, 131, 250, 197, 25, 251, 193, 136, 202, 226],
-		[41, 102, 214, 207, 189, 205, 167, 28, 129, 99, 136, 48, 223, 137, 52, 117, 238, 15, 158, 175, 23, 79, 214, 164, 169, 40, 14, 182, 255, 239, 9, 192],
+		[149, 181, 155, 27, 4, 174, 121, 222, 230, 52, 54, 51, 21, 51, 139, 5, 51, 126, 105, 155, 34, 143, 68, 18, 145, 51, 216, 173, 180, 218, 25, 235],
+		[37, 81, 223, 244, 193, 163, 86, 187, 236, 34, 220, 174, 245, 103, 20, 74, 79, 234, 149, 50, 31, 135, 87, 37, 161, 85, 138, 40, 74, 10, 96, 53],
+		[221, 7, 219, 136, 51, 242, 143, 221, 133, 47, 217, 111, 163, 122, 83, 219, 76, 253, 84, 81, 21, 120, 251, 97, 200, 84, 162, 79, 3, 141, 166, 52],
+		[131, 8, 254, 155, 16, 150, 195, 23, 85, 17, 51, 219, 140, 82, 5, 87, 164, 146, 94, 72, 81, 187, 193, 98, 22, 212, 80, 177, 72, 34, 166, 107],
 	],
 	[
-		[32, 235, 141, 164, 71, 95, 182, 233, 148, 35, 15, 166, 1, 136, 176, 100, 66, 97, 120, 35, 226, 43, 236, 191, 196, 218, 192, 106, 1, 109, 147, 205],
-		[232, 167, 111, 169, 62, 235, 226, 100, 90, 29, 119, 79, 153, 74, 24, 231, 196, 44, 118, 37, 7, 113, 72, 44, 164, 100, 166, 231, 169, 102, 18, 212],
-		[27, 107, 20, 102, 118, 214, 59, 108, 239, 122, 33, 45, 12, 238, 119, 172, 146, 159, 173, 65, 138, 116, 179, 121, 126, 16, 144, 177, 128, 8, 51, 237],
-		[32, 143, 3, 109, 114, 206, 228, 176, 6, 114, 200, 178, 163, 133, 237, 206, 12, 87, 91, 241, 178, 246, 207, 79, 89, 120, 209, 101, 105, 209, 90, 204],
+		[139, 83, 61, 173, 109, 102, 251, 215, 102, 217, 200, 25, 143, 194, 151, 151, 117, 138, 145, 231, 112, 163, 87, 122, 115, 29, 49, 168, 117, 117, 92, 218],
+		[229, 148, 36, 118, 253, 220, 155, 7, 165, 127, 248, 59, 160, 99, 108, 85, 135, 174, 249, 36, 29, 146, 78, 172, 8, 35, 56, 95, 234, 213, 46, 202],
+		[2, 148, 219, 191, 85, 203, 138, 1, 221, 253, 65, 236, 153, 249, 99, 193, 245, 204, 119, 172, 194, 216, 13, 202, 131, 237, 57, 252, 3, 125, 213, 47],
+		[153, 86, 208, 115, 193, 108, 83, 172, 231, 173, 3, 220, 173, 138, 198, 62, 157, 113, 227, 45, 39, 147, 220, 130, 109, 177, 215, 43, 141, 174, 48, 150],
 	],
 	[
-		[83, 252, 213, 105, 118, 216, 9, 139, 179, 148, 89, 250, 110, 95, 173, 182, 102, 137, 131, 175, 121, 200, 72, 141, 89, 79, 252, 163, 11, 198, 18, 15],
-		[238, 196, 32, 53, 254, 141, 79, 165, 239, 32, 215, 26, 238, 200, 159, 137, 8, 159, 19, 65, 45, 41, 187, 5, 8, 166, 150, 43, 83, 251, 40, 138],
-		[111, 196, 169, 221, 85, 228, 207, 220, 36, 29, 248, 56, 239, 62, 153, 5, 29, 125, 136, 191, 168, 250, 75, 239, 61, 235, 152, 70, 157, 31, 177, 174],
-		[70, 82, 129, 145, 30, 249, 6, 84, 135, 221, 205, 252, 140, 77, 100, 211, 45, 241, 177, 18, 60, 198, 173, 35, 226, 162, 110, 115, 77, 230, 209, 174],
+		[227, 76, 213, 58, 92, 91, 7, 65, 210, 202, 0, 55, 28, 153, 5, 59, 252, 193, 115, 44, 159, 130, 248, 106, 147, 204, 76, 48, 247, 231, 179, 44],
+		[108, 236, 45, 137, 252, 254, 235, 125, 109, 233, 224, 252, 228, 154, 217, 172, 229, 133, 5, 206, 198, 170, 137, 3, 167, 5, 51, 202, 104, 171, 201, 6],
+		[81, 171, 38, 154, 161, 72, 51, 118, 23, 127, 235, 50, 137, 221, 208, 149, 251, 179, 212, 81, 76, 54, 196, 0, 137, 180, 116, 231, 7, 102, 124, 227],
+		[207, 15, 31, 193, 37, 2, 70, 131, 111, 17, 186, 32, 151, 27, 241, 171, 48, 83, 225, 237, 58, 34, 180, 16, 77, 196, 249, 12, 106, 176, 202, 231],
 	],
 	[
-		[248, 131, 115, 206, 6, 198, 148, 99, 132, 15, 98, 219, 195, 20, 246, 223, 241, 252, 189, 226, 50, 3, 46, 248, 139, 89, 245, 13, 133, 243, 246, 5],
-		[221, 77, 174, 38, 15, 94, 232, 175, 202, 6, 127, 221, 72, 85, 144, 126, 16, 57, 120, 137, 101, 48, 162, 97, 15, 236, 20, 232, 38, 159, 161, 207],
-		[171, 95, 121, 235, 23, 101, 81, 80, 123, 115, 225, 166, 9, 201, 195, 113, 18, 86, 196, 80, 214, 37, 149, 25, 76, 63, 248, 10, 11, 200, 78, 201],
-		[48, 29, 218, 154, 113, 162, 203, 3, 113, 53, 124, 39, 95, 72, 203, 109, 184, 15, 40, 241, 142, 201, 151, 130, 239, 137, 219, 239, 182, 77, 129, 194],
+		[10, 219, 253, 19, 245, 27, 130, 166, 127, 164, 166, 210, 135, 201, 147, 42, 172, 168, 150, 58, 173, 238, 235, 151, 250, 149, 81, 241, 139, 141, 249, 242],
+		[217, 211, 144, 15, 140, 206, 225, 35, 119, 212, 199, 51, 133, 113, 219, 52, 207, 176, 68, 215, 110, 91, 99, 132, 26, 136, 158, 198, 39, 64, 154, 141],
+		[222, 231, 237, 66, 37, 133, 104, 79, 169, 92, 233, 33, 117, 217, 26, 159, 73, 69, 151, 110, 188, 214, 201, 51, 126, 69, 213, 99, 164, 125, 26, 146],
+		[198, 88, 210, 186, 54, 201, 143, 6, 174, 107, 13, 117, 88, 37, 172, 222, 206, 54, 116, 122, 207, 108, 230, 239, 194, 6, 67, 24, 69, 138, 160, 37],
 	],
 	[
-		[39, 232, 20, 132, 217, 25, 81, 242, 254, 6, 109, 106, 101, 166, 111, 60, 132, 190, 212, 234, 116, 215, 208, 163, 201, 93, 71, 133, 164, 245, 35, 62],
-		[25, 92, 148, 150, 45, 26, 89, 232, 124, 58, 158, 178, 133, 188, 111, 186, 127, 139, 32, 181, 254, 220, 6, 77, 131, 144, 91, 87, 93, 66, 224, 83],
-		[138, 4, 144, 100, 185, 35, 27, 250, 253, 179, 201, 182, 82, 162, 72, 6, 134, 114, 23, 255, 251, 208, 224, 56, 229, 255, 65, 179, 242, 103, 214, 242],
-		[238, 227, 35, 221, 167, 252, 157, 70, 111, 121, 70, 188, 182, 54, 34, 172, 198, 26, 98, 152, 38, 51, 182, 44, 60, 232, 193, 80, 166, 174, 32, 188],
+		[101, 129, 191, 141, 177, 99, 164, 120, 172, 198, 26, 229, 79, 179, 131, 39, 18, 247, 122, 6, 77, 81, 58, 190, 249, 194, 73, 252, 55, 57, 0, 146],
+		[125, 98, 66, 98, 89, 54, 236, 37, 188, 11, 29, 10, 52, 84, 202, 101, 77, 27, 186, 237, 242, 117, 178, 225, 229, 2, 109, 54, 2, 119, 75, 179],
+		[16, 205, 231, 144, 229, 54, 176, 109, 203, 181, 154, 212, 227, 45, 119, 169, 122, 72, 103, 72, 160, 43, 243, 57, 40, 147, 225, 161, 177, 247, 25, 113],
+		[249, 160, 164, 104, 248, 168, 27, 183, 133, 132, 121, 220, 173, 60, 230, 214, 217, 93, 250, 132, 214, 214, 92, 118, 41, 187, 33, 194, 93, 60, 37, 103],
 	],
 	[
-		[122, 181, 118, 188, 208, 188, 72, 226, 215, 42, 247, 82, 155, 5, 177, 46, 86, 61, 218, 47, 232, 3, 220, 214, 237, 9, 100, 123, 242, 212, 60, 156],
-		[215, 175, 226, 125, 54, 163, 238, 238, 57, 86, 44, 73, 56, 68, 190, 73, 42, 80, 83, 50, 246, 92, 153, 254, 181, 107, 6, 235, 244, 195, 229, 180],
-		[91, 72, 4, 6, 231, 163, 104, 37, 67, 126, 6, 45, 185, 62, 64, 131, 219, 77, 10, 213, 53, 205, 34, 231, 149, 131, 188, 167, 43, 65, 169, 21],
-		[145, 130, 178, 239, 153, 10, 180, 48, 58, 205, 226, 113, 186, 100, 74, 64, 207, 34, 37, 2, 254, 140, 153, 138, 223, 203, 157, 43, 63, 64, 60, 40],
+		[181, 1, 106, 83, 65, 171, 25, 118, 34, 223, 200, 4, 43, 130, 112, 178, 117, 198, 36, 50, 29, 111, 214, 233, 173, 191, 176, 128, 84, 24, 230, 155],
+		[183, 195, 233, 223, 24, 42, 176, 3, 167, 171, 68, 98, 219, 184, 248, 151, 24, 38, 90, 240, 50, 134, 118, 91, 179, 143, 132, 195, 37, 193, 171, 65],
+		[159, 212, 205, 154, 58, 165, 102, 90, 59, 213, 52, 176, 162, 156, 162, 56, 50, 137, 5, 17, 156, 61, 83, 23, 236, 236, 215, 112, 5, 63, 143, 47],
+		[121, 188, 188, 123, 22, 229, 16, 139, 18, 249, 154, 154, 100, 22, 82, 102, 86, 16, 170, 205, 43, 102, 128, 198, 132, 130, 26, 25, 77, 219, 231, 37],
 	],
 	[
-		[244, 73, 149, 99, 49, 110, 54, 188, 218, 144, 1, 207, 233, 75, 34, 130, 212, 23, 103, 46, 114, 204, 64, 77, 158, 246, 32, 163, 58, 33, 9, 70],
-		[54, 93, 87, 131, 65, 219, 189, 173, 105, 214, 230, 39, 183, 181, 247, 206, 63, 110, 39, 38, 96, 167, 68, 206, 152, 113, 178, 234, 37, 104, 4, 84],
-		[102, 41, 207, 61, 242, 205, 215, 51, 84, 255, 10, 231, 34, 127, 94, 63, 109, 130, 180, 16, 32, 83, 43, 228, 2, 69, 183, 189, 200, 67, 246, 67],
-		[140, 129, 111, 27, 113, 192, 82, 206, 129, 143, 225, 108, 169, 39, 51, 75, 89, 127, 176, 230, 220, 80, 114, 135, 163, 119, 158, 31, 20, 219, 137, 191],
+		[86, 165, 93, 0, 230, 178, 109, 13, 29, 155, 142, 56, 221, 180, 42, 100, 37, 145, 86, 20, 30, 153, 110, 14, 140, 249, 35, 212, 139, 55, 59, 87],
+		[99, 235, 211, 50, 221, 104, 232, 218, 8, 247, 139, 210, 145, 190, 84, 101, 200, 18, 3, 142, 112, 139, 20, 38, 123, 30, 179, 199, 64, 202, 184, 229],
+		[63, 249, 18, 122, 54, 76, 160, 26, 215, 68, 220, 215, 15, 123, 85, 96, 214, 232, 23, 72, 202, 235, 142, 75, 141, 22, 167, 252, 212, 208, 161, 2],
+		[108, 176, 204, 178, 169, 126, 25, 115, 39, 74, 20, 125, 227, 92, 238, 23, 211, 221, 194, 138, 174, 198, 213, 43, 100, 11, 22, 58, 62, 152, 55, 53],
 	],
 	[
-		[241, 89, 140, 201, 203, 28, 138, 250, 240, 204, 9, 75, 252, 111, 151, 0, 242, 39, 169, 165, 182, 1, 38, 223, 158, 21, 50, 21, 96, 237, 239, 141],
-		[94, 187, 212, 39, 60, 137, 232, 117, 114, 46, 169, 191, 158, 16, 33, 95, 39, 144, 150, 245, 220, 1, 46, 40, 122, 98, 126, 154, 12, 46, 101, 136],
-		[62, 186, 154, 141, 169, 228, 202, 25, 109, 57, 82, 218, 206, 211, 157, 37, 154, 71, 182, 154, 200, 215, 107, 99, 6, 1, 27, 146, 28, 120, 10, 234],
-		[211, 33, 155, 224, 161, 163, 168, 176, 209, 139, 35, 186, 101, 146, 238, 19, 26, 185, 172, 112, 180, 180, 85, 252, 96, 180, 143, 168, 66, 31, 145, 44],
+		[0, 197, 179, 74, 110, 142, 167, 231, 157, 255, 166, 208, 22, 206, 5, 127, 245, 89, 218, 48, 65, 22, 200, 20, 100, 73, 6, 109, 154, 81, 204, 228],
+		[209, 111, 57, 149, 32, 181, 136, 242, 100, 30, 216, 42, 159, 42, 83, 154, 99, 13, 111, 208, 183, 20, 77, 191, 176, 134, 122, 64, 30, 53, 164, 34],
+		[0, 49, 176, 174, 207, 252, 204, 249, 154, 81, 241, 147, 251, 237, 123, 206, 211, 155, 8, 188, 228, 225, 43, 247, 78, 105, 98, 151, 89, 249, 147, 145],
+		[240, 225, 191, 124, 206, 54, 9, 83, 87, 220, 159, 154, 43, 212, 117, 142, 63, 82, 201, 116, 29, 236, 242, 156, 151, 88, 93, 112, 169, 25, 160, 224],
 	],
 	[
-		[19, 97, 251, 58, 164, 149, 120, 34, 71, 169, 99, 93, 37, 45, 17, 237, 87, 92, 42, 212, 15, 239, 148, 53, 91, 209, 47, 3, 177, 145, 240, 1],
-		[123, 204, 110, 238, 223, 89, 29, 245, 84, 147, 139, 132, 199, 214, 218, 53, 125, 113, 126, 7, 188, 106, 84, 243, 21, 184, 169, 238, 148, 133, 85, 13],
-		[215, 85, 130, 119, 142, 98, 13, 206, 167, 208, 154, 187, 238, 82, 214, 186, 164, 141, 32, 6, 90, 45, 99, 176, 108, 241, 12, 185, 199, 229, 38, 18],
-		[205, 198, 181, 150, 85, 231, 212, 232, 36, 235, 213, 202, 65, 206, 250, 28, 88, 141, 91, 76, 47, 123, 228, 116, 235, 99, 13, 22, 49, 75, 147, 175],
+		[209, 5, 107, 59, 72, 236, 237, 69, 5, 72, 190, 199, 28, 18, 140, 93, 72, 18, 90, 14, 18, 103, 42, 36, 201, 158, 119, 151, 25, 118, 162, 220],
+		[52, 84, 231, 44, 82, 211, 207, 193, 150, 117, 6, 19, 80, 237, 38, 111, 213, 91, 228, 230, 62, 6, 154, 88, 187, 121, 25, 31, 7, 54, 187, 214],
+		[160, 230, 138, 150, 136, 27, 244, 83, 41, 47, 169, 177, 4, 128, 61, 251, 226, 107, 65, 111, 32, 64, 0, 90, 151, 130, 146, 65, 155, 43, 27, 206],
+		[40, 65, 34, 203, 84, 84, 155, 132, 221, 247, 27, 21, 245, 143, 120, 93, 116, 141, 153, 12, 235, 50, 220, 243, 66, 184, 55, 3, 255, 51, 123, 76],
 	],
 	[
-		[81, 190, 71, 197, 22, 11, 126, 134, 128, 241, 56, 150, 205, 26, 228, 73, 46, 15, 221, 172, 9, 240, 153, 100, 149, 130, 15, 203, 178, 139, 169, 57],
-		[128, 116, 16, 105, 83, 66, 86, 184, 149, 165, 42, 130, 129, 253, 156, 242, 228, 231, 119, 226, 157, 164, 109, 238, 137, 102, 233, 128, 27, 13, 18, 90],
-		[253, 227, 26, 202, 28, 169, 26, 159, 241, 18, 83, 230, 131, 150, 243, 215, 245, 199, 25, 197, 221, 2, 144, 253, 132, 158, 43, 75, 200, 13, 8, 187],
-		[183, 232, 93, 156, 181, 109, 138, 99, 250, 206, 23, 147, 73, 170, 179, 2, 195, 116, 202, 135, 25, 40, 13, 4, 34, 196, 209, 71, 228, 237, 143, 238],
+		[185, 236, 166, 162, 44, 247, 217, 113, 223, 190, 74, 254, 187, 235, 123, 108, 70, 17, 28, 107, 232, 166, 9, 49, 48, 236, 170, 106, 92, 122, 139, 144],
+		[38, 56, 1, 215, 67, 231, 225, 76, 200, 2, 93, 52, 123, 161, 95, 245, 14, 105, 101, 116, 120, 45, 120, 45, 212, 225, 117, 20, 207, 12, 107, 170],
+		[244, 220, 109, 34, 186, 141, 199, 153, 175, 26, 67, 114, 164, 247, 32, 198, 195, 65, 43, 208, 229, 162, 36, 121, 71, 238, 249, 249, 125, 28, 197, 190],
+		[10, 15, 175, 237, 113, 115, 243, 60, 15, 170, 140, 99, 79, 69, 183, 183, 203, 157, 240, 230, 246, 179, 0, 158, 2, 18, 35, 163, 26, 62, 100, 139],
 	],
 	[
-		[28, 205, 114, 246, 103, 64, 181, 172, 89, 119, 212, 117, 160, 10, 223, 187, 239, 146, 200, 197, 207, 224, 242, 174, 230, 154, 59, 205, 8, 151, 6, 150],
-		[130, 147, 190, 6, 193, 176, 35, 81, 68, 223, 85, 96, 170, 97, 140, 32, 22, 57, 58, 4, 131, 148, 182, 105, 254, 208, 94, 202, 139, 2, 246, 157],
-		[119, 246, 29, 83, 200, 18, 10, 164, 82, 14, 145, 174, 99, 125, 142, 174, 97, 122, 130, 176, 37, 84, 1, 55, 110, 215, 222, 5, 212, 24, 234, 109],
-		[245, 11, 100, 158, 113, 61, 22, 181, 77, 24, 198, 56, 167, 198, 204, 118, 65, 73, 9, 51, 57, 120, 196, 208, 140, 128, 146, 230, 249, 34, 133, 201],
+		[184, 120, 166, 247, 27, 7, 82, 74, 246, 145, 103, 34, 55, 201, 44, 26, 219, 146, 236, 251, 212, 116, 2, 158, 20, 193, 147, 93, 216, 248, 54, 191],
+		[105, 115, 255, 205, 42, 18, 250, 162, 86, 99, 167, 238, 235, 241, 111, 213, 209, 212, 4, 85, 182, 154, 164, 113, 184, 233, 76, 76, 239, 4, 77, 65],
+		[6, 1, 41, 100, 112, 1, 225, 194, 239, 45, 7, 21, 154, 8, 147, 101, 16, 52, 2, 29, 14, 79, 14, 39, 67, 249, 95, 172, 128, 33, 168, 227],
+		[58, 5, 204, 211, 235, 44, 234, 237, 11, 217, 191, 161, 90, 8, 249, 92, 169, 227, 58, 189, 127, 96, 114, 24, 43, 241, 20, 172, 70, 176, 106, 4],
 	],
 	[
-		[143, 158, 84, 49, 123, 251, 91, 168, 231, 211, 204, 156, 133, 43, 13, 5, 154, 20, 168, 114, 205, 29, 17, 237, 37, 26, 112, 36, 194, 201, 7, 225],
-		[75, 187, 160, 108, 181, 19, 5, 135, 40, 51, 181, 37, 139, 75, 57, 86, 252, 74, 184, 64, 246, 242, 164, 123, 195, 145, 238, 166, 73, 105, 72, 221],
-		[172, 205, 228, 76, 237, 175, 96, 83, 58, 50, 251, 56, 14, 219, 180, 105, 153, 196, 120, 248, 206, 175, 79, 19, 246, 163, 19, 19, 6, 103, 112, 111],
-		[167, 88, 207, 104, 14, 231, 179, 37, 188, 182, 65, 69, 83, 178, 47, 58, 151, 193, 5, 114, 222, 18, 233, 252, 105, 95, 83, 186, 131, 252, 50, 115],
+		[153, 86, 125, 138, 197, 154, 145, 248, 236, 125, 69, 105, 106, 48, 93, 97, 51, 90, 104, 245, 215, 141, 94, 22, 197, 77, 215, 17, 244, 33, 123, 16],
+		[95, 254, 27, 161, 54, 49, 92, 114, 205, 224, 82, 238, 87, 161, 78, 2, 58, 7, 211, 56, 43, 126, 9, 250, 165, 225, 24, 67, 40, 38, 207, 176],
+		[220, 221, 7, 51, 8, 58, 9, 229, 88, 158, 135, 52, 161, 80, 143, 227, 30, 125, 142, 123, 100, 96, 180, 23, 84, 111, 144, 90, 173, 115, 181, 197],
+		[213, 232, 148, 229, 95, 91, 142, 49, 249, 171, 179, 144, 155, 199, 19, 183, 246, 124, 145, 100, 116, 127, 97, 136, 21, 142, 74, 232, 24, 169, 31, 135],
 	],
 	[
-		[107, 37, 2, 136, 218, 15, 82, 16, 246, 196, 197, 105, 125, 215, 32, 204, 83, 142, 81, 12, 77, 11, 237, 154, 255, 112, 42, 229, 167, 225, 167, 172],
-		[183, 83, 97, 137, 128, 53, 148, 181, 93, 178, 60, 62, 68, 231, 184, 21, 120, 152, 22, 204, 253, 4, 14, 211, 226, 129, 172, 207, 90, 87, 95, 44],
-		[179, 65, 244, 177, 197, 38, 147, 71, 156, 106, 132, 136, 236, 119, 190, 117, 217, 226, 195, 125, 200, 0, 12, 97, 113, 197, 189, 117, 67, 86, 226, 99],
-		[153, 62, 150, 61, 96, 6, 186, 201, 156, 75, 85, 167, 200, 26, 187, 106, 6, 29, 25, 50, 228, 10, 41, 12, 90, 107, 112, 140, 197, 245, 33, 198],
+		[89, 253, 10, 251, 239, 132, 194, 133, 70, 155, 107, 68, 115, 120, 166, 239, 157, 81, 12, 148, 195, 248, 230, 4, 104, 188, 3, 102, 145, 95, 121, 5],
+		[135, 124, 31, 47, 33, 166, 41, 7, 14, 103, 230, 231, 122, 191, 183, 112, 101, 126, 242, 199, 8, 230, 47, 253, 245, 83, 89, 154, 246, 156, 131, 149],
+		[96, 22, 123, 164, 150, 108, 233, 174, 204, 254, 186, 155, 115, 5, 84, 174, 80, 194, 17, 86, 130, 235, 50, 208, 103, 194, 80, 243, 95, 36, 208, 143],
+		[34, 98, 32, 24, 232, 96, 92, 236, 36, 227, 43, 162, 40, 40, 140, 237, 73, 48, 102, 56, 95, 148, 63, 177, 123, 61, 23, 208, 63, 185, 115, 67],
 	],
 	[
-		[176, 32, 26, 21, 25, 219, 13, 4, 254, 198, 72, 232, 150, 96, 214, 204, 12, 100, 193, 7, 102, 31, 0, 19, 222, 180, 53, 145, 98, 220, 129, 154],
-		[254, 216, 117, 77, 228, 117, 64, 171, 49, 254, 46, 1, 123, 123, 102, 90, 23, 131, 143, 15, 78, 251, 166, 197, 251, 65, 217, 105, 186, 209, 236, 29],
-		[171, 224, 1, 51, 27, 231, 24, 123, 231, 133, 62, 162, 155, 228, 115, 138, 167, 152, 246, 161, 30, 198, 163, 114, 92, 126, 65, 56, 212, 245, 37, 191],
-		[139, 53, 248, 165, 78, 206, 202, 210, 72, 95, 253, 61, 152, 127, 29, 169, 145, 83, 126, 116, 194, 173, 84, 84, 64, 68, 224, 196, 123, 166, 241, 174],
+		[174, 214, 47, 34, 239, 213, 200, 88, 192, 47, 89, 74, 65, 235, 117, 130, 222, 74, 90, 219, 114, 207, 19, 115, 44, 243, 149, 184, 72, 137, 86, 88],
+		[170, 49, 66, 190, 244, 119, 227, 220, 32, 4, 46, 140, 54, 126, 120, 247, 28, 101, 224, 188, 86, 104, 133, 96, 194, 94, 118, 122, 206, 213, 170, 220],
+		[37, 144, 57, 104, 205, 219, 86, 133, 167, 250, 5, 182, 30, 43, 242, 253, 193, 227, 177, 66, 158, 137, 213, 233, 92, 25, 65, 233, 55, 194, 144, 8],
+		[71, 236, 47, 115, 91, 41, 91, 234, 154, 230, 157, 164, 137, 205, 237, 124, 163, 16, 75, 84, 24, 161, 96, 34, 11, 227, 8, 234, 105, 105, 102, 165],
 	],
 	[
-		[230, 80, 105, 227, 21, 110, 184, 55, 146, 31, 195, 89, 56, 196, 166, 98, 84, 49, 173, 117, 178, 237, 23, 183, 20, 115, 61, 60, 200, 10, 9, 227],
-		[42, 145, 15, 108, 110, 254, 41, 73, 101, 105, 142, 52, 195, 161, 251, 171, 99, 101, 140, 245, 147, 205, 221, 61, 93, 183, 248, 162, 225, 29, 224, 170],
-		[138, 162, 74, 154, 17, 225, 12, 36, 76, 66, 93, 98, 115, 159, 204, 115, 13, 144, 147, 234, 105, 210, 142, 133, 6, 193, 53, 117, 100, 146, 124, 15],
-		[71, 126, 126, 53, 243, 146, 96, 211, 163, 107, 159, 53, 159, 35, 134, 72, 67, 149, 176, 107, 127, 176, 31, 179, 97, 109, 55, 126, 157, 67, 155, 49],
+		[196, 83, 76, 183, 72, 140, 41, 249, 21, 210, 48, 164, 20, 88, 165, 87, 155, 138, 232, 14, 83, 59, 67, 3, 148, 209, 47, 173, 71, 22, 179, 82],
+		[254, 197, 157, 16, 58, 32, 176, 64, 32, 60, 225, 76, 63, 207, 118, 87, 246, 121, 78, 231, 69, 8, 79, 128, 175, 186, 137, 169, 174, 107, 17, 138],
+		[148, 105, 102, 225, 189, 85, 226, 31, 125, 250, 99, 212, 163, 237, 248, 236, 99, 187, 97, 5, 251, 138, 43, 147, 243, 77, 254, 108, 232, 186, 174, 53],
+		[216, 10, 4, 8, 169, 249, 237, 153, 38, 164, 81, 145, 20, 189, 2, 165, 182, 178, 207, 184, 43, 101, 27, 119, 220, 190, 1, 13, 76, 130, 79, 155],
 	],
 	[
-		[21, 235, 143, 31, 173, 3, 28, 89, 130, 253, 203, 221, 85, 82, 107, 178, 220, 54, 216, 59, 243, 40, 124, 137, 112, 14, 17, 223, 144, 29, 151, 78],
-		[121, 96, 51, 211, 81, 6, 83, 78, 162, 103, 79, 56, 231, 247, 58, 193, 92, 8, 4, 218, 22, 204, 160, 220, 161, 255, 40, 240, 132, 55, 101, 47],
-		[97, 14, 199, 19, 44, 25, 237, 16, 90, 205, 120, 249, 110, 89, 241, 53, 108, 128, 103, 180, 171, 105, 17, 188, 255, 117, 11, 203, 175, 208, 117, 202],
-		[250, 83, 12, 40, 115, 54, 206, 155, 152, 167, 199, 45, 30, 80, 147, 190, 103, 47, 49, 249, 107, 132, 70, 67, 197, 43, 119, 192, 120, 119, 193, 187],
+		[169, 162, 192, 82, 176, 146, 144, 132, 198, 255, 45, 212, 106, 228, 204, 140, 158, 114, 250, 203, 37, 57, 169, 68, 12, 107, 167, 74, 22, 82, 166, 211],
+		[140, 202, 56, 85, 56, 61, 1, 43, 29, 9, 123, 74, 57, 184, 167, 74, 160, 221, 183, 3, 33, 148, 5, 173, 142, 248, 76, 1, 236, 33, 32, 145],
+		[187, 73, 139, 152, 104, 50, 54, 142, 53, 216, 4, 135, 199, 188, 53, 251, 230, 15, 173, 46, 23, 204, 134, 71, 78, 2, 251, 196, 37, 186, 107, 35],
+		[235, 219, 111, 183, 96, 160, 227, 169, 124, 111, 104, 226, 60, 112, 62, 35, 230, 195, 4, 96, 146, 167, 14, 215, 199, 224, 205, 210, 77, 92, 223, 201],
 	],
 	[
-		[117, 229, 121, 74, 87, 207, 241, 195, 172, 255, 202, 238, 118, 244, 136, 212, 112, 220, 66, 153, 109, 11, 17, 98, 3, 119, 225, 31, 89, 231, 84, 180],
-		[196, 149, 67, 132, 6, 227, 203, 100, 219, 193, 140, 178, 247, 216, 191, 181, 80, 176, 148, 239, 3, 86, 239, 2, 63, 48, 136, 58, 237, 175, 193, 76],
-		[27, 155, 252, 108, 93, 104, 222, 189, 20, 231, 223, 27, 14, 88, 121, 58, 192, 143, 65, 41, 12, 111, 237, 82, 48, 204, 82, 77, 170, 40, 37, 174],
-		[183, 125, 103, 126, 112, 242, 104, 142, 57, 217, 203, 54, 211, 124, 215, 198, 183, 45, 11, 48, 82, 35, 219, 245, 136, 199, 97, 11, 21, 130, 110, 61],
+		[148, 89, 187, 162, 141, 241, 124, 158, 44, 92, 35, 15, 84, 185, 93, 222, 178, 126, 228, 29, 240, 188, 145, 236, 214, 117, 180, 253, 223, 229, 185, 60],
+		[80, 54, 120, 205, 72, 68, 243, 136, 197, 113, 97, 142, 49, 158, 217, 233, 142, 41, 172, 62, 105, 208, 87, 227, 103, 179, 20, 92, 7, 115, 110, 208],
+		[189, 216, 194, 29, 36, 146, 101, 1, 185, 148, 121, 169, 160, 14, 173, 40, 89, 201, 116, 30, 63, 67, 209, 249, 73, 235, 3, 135, 166, 8, 176, 58],
+		[81, 252, 65, 205, 143, 244, 231, 250, 237, 28, 24, 50, 39, 181, 248, 53, 68, 252, 44, 206, 213, 60, 131, 138, 123, 189, 233, 144, 137, 30, 196, 192],
 	],
 	[
-		[243, 39, 147, 84, 199, 77, 56, 156, 76, 90, 43, 61, 51, 54, 34, 165, 66, 51, 240, 18, 219, 228, 134, 103, 37, 191, 147, 169, 219, 83, 143, 10],
-		[226, 171, 93, 228, 164, 250, 46, 251, 152, 115, 49, 168, 213, 217, 81, 130, 120, 201, 158, 252, 213, 233, 49, 250, 98, 208, 118, 243, 124, 211, 43, 142],
-		[85, 152, 41, 40, 81, 220, 17, 74, 180, 174, 108, 213, 235, 22, 1, 245, 16, 25, 34, 228, 69, 79, 193, 130, 43, 89, 19, 127, 124, 234, 126, 66],
-		[206, 133, 249, 108, 128, 186, 204, 135, 70, 189, 69, 221, 96, 60, 68, 68, 223, 235, 112, 242, 234, 32, 51, 88, 158, 160, 100, 5, 203, 171, 146, 195],
+		[232, 55, 37, 7, 50, 233, 112, 185, 23, 26, 171, 150, 118, 156, 215, 4, 5, 193, 93, 165, 106, 38, 4, 180, 105, 100, 177, 168, 33, 11, 93, 94],
+		[147, 150, 126, 68, 238, 0, 43, 50, 134, 92, 139, 194, 252, 15, 100, 154, 124, 5, 240, 33, 30, 53, 117, 150, 152, 222, 221, 189, 29, 61, 185, 90],
+		[105, 242, 28, 1, 170, 16, 53, 11, 255, 138, 165, 246, 111, 59, 149, 175, 22, 54, 24, 168, 38, 20, 212, 55, 199, 69, 197, 221, 28, 221, 30, 79],
+		[254, 43, 101, 20, 170, 54, 92, 110, 103, 220, 53, 65, 205, 14, 89, 101, 48, 155, 63, 100, 175, 220, 62, 78, 111, 229, 104, 97, 111, 254, 206, 94],
 	],
 	[
-		[94, 212, 28, 131, 180, 16, 76, 185, 210, 39, 137, 98, 55, 148, 94, 92, 126, 137, 58, 85, 97, 232, 251, 229, 89, 91, 23, 252, 120, 59, 108, 225],
-		[100, 179, 255, 219, 54, 155, 7, 170, 230, 74, 13, 85, 170, 98, 88, 102, 96, 211, 143, 59, 64, 249, 99, 112, 44, 32, 235, 244, 187, 92, 27, 167],
-		[189, 45, 64, 144, 92, 187, 54, 216, 144, 161, 166, 135, 190, 2, 185, 112, 0, 13, 15, 170, 89, 108, 144, 219, 85, 225, 230, 151, 98, 114, 96, 95],
-		[129, 202, 114, 208, 193, 1, 38, 72, 232, 192, 207, 64, 214, 58, 217, 200, 213, 22, 132, 97, 189, 221, 11, 61, 11, 44, 186, 236, 123, 37, 118, 51],
+		[2, 144, 247, 245, 78, 172, 207, 106, 217, 117, 113, 92, 91, 177, 57, 186, 66, 204, 120, 180, 148, 108, 117, 15, 113, 244, 28, 97, 194, 213, 151, 111],
+		[248, 167, 141, 74, 25, 126, 87, 1, 39, 46, 198, 56, 84, 106, 140, 14, 192, 8, 104, 229, 32, 124, 89, 87, 40, 198, 60, 19, 158, 105, 37, 52],
+		[220, 6, 231, 103, 189, 235, 23, 24, 34, 100, 41, 205, 82, 251, 135, 226, 2, 70, 145, 49, 78, 184, 58, 10, 252, 228, 113, 149, 242, 67, 111, 180],
+		[86, 30, 133, 209, 182, 201, 68, 5, 204, 56, 55, 66, 6, 87, 30, 64, 174, 209, 254, 49, 159, 121, 109, 175, 222, 67, 51, 212, 87, 95, 190, 29],
 	],
 	[
-		[58, 32, 32, 236, 7, 12, 118, 154, 9, 31, 221, 77, 27, 110, 216, 25, 165, 48, 52, 23, 54, 205, 223, 177, 185, 191, 2, 144, 165, 159, 57, 222],
-		[217, 237, 234, 130, 41, 25, 215, 90, 46, 149, 207, 202, 63, 249, 4, 84, 97, 150, 76, 60, 41, 10, 2, 178, 134, 124, 154, 91, 179, 174, 54, 128],
-		[253, 63, 32, 8, 236, 159, 93, 255, 30, 171, 209, 47, 9, 231, 82, 78, 23, 143, 201, 128, 61, 175, 129, 172, 81, 248, 191, 105, 64, 206, 88, 94],
-		[51, 16, 230, 4, 20, 22, 163, 136, 200, 233, 176, 193, 82, 139, 63, 133, 95, 12, 197, 166, 44, 189, 71, 147, 101, 74, 94, 148, 11, 217, 199, 216],
+		[52, 66, 39, 38, 53, 27, 216, 208, 68, 179, 29, 155, 174, 176, 28, 227, 241, 71, 227, 245, 154, 9, 211, 165, 238, 183, 57, 63, 73, 48, 239, 165],
+		[15, 237, 60, 125, 149, 26, 55, 42, 88, 93, 0, 81, 93, 9, 1, 14, 146, 227, 32, 226, 8, 79, 207, 29, 168, 40, 99, 201, 230, 193, 178, 138],
+		[165, 133, 150, 95, 7, 67, 117, 126, 254, 189, 202, 171, 145, 118, 97, 167, 59, 178, 113, 12, 230, 226, 189, 67, 64, 57, 235, 181, 209, 115, 197, 168],
+		[103, 159, 242, 166, 200, 251, 145, 49, 195, 2, 244, 3, 232, 175, 226, 114, 1, 196, 61, 51, 125, 247, 133, 31, 103, 203, 173, 19, 221, 81, 135, 204],
 	],
 	[
-		[3, 241, 9, 72, 128, 49, 247, 42, 241, 58, 201, 124, 6, 223, 31, 70, 33, 237, 49, 150, 228, 216, 95, 28, 8, 80, 250, 23, 37, 242, 130, 224],
-		[223, 55, 100, 212, 39, 135, 159, 113, 174, 207, 255, 232, 220, 68, 217, 6, 161, 238, 123, 249, 76, 73, 66, 62, 41, 34, 31, 106, 181, 71, 102, 102],
-		[192, 146, 155, 6, 36, 108, 97, 208, 62, 71, 177, 192, 94, 208, 34, 169, 241, 245, 98, 148, 30, 152, 116, 49, 187, 119, 214, 236, 144, 16, 134, 112],
-		[188, 168, 191, 222, 113, 240, 17, 83, 95, 163, 149, 210, 113, 90, 104, 161, 29, 249, 123, 105, 15, 178, 222, 139, 85, 48, 243, 202, 158, 165, 182, 140],
+		[237, 157, 66, 170, 254, 33, 240, 50, 110, 13, 174, 255, 95, 49, 5, 214, 101, 163, 8, 74, 24, 77, 242, 117, 65, 148, 209, 158, 19, 89, 20, 17],
+		[127, 31, 163, 71, 231, 248, 92, 111, 61, 161, 164, 3, 127, 156, 232, 18, 248, 245, 93, 77, 158, 251, 213, 215, 125, 186, 103, 165, 250, 209, 49, 223],
+		[7, 179, 145, 118, 103, 71, 10, 70, 148, 61, 149, 165, 195, 185, 122, 99, 50, 144, 50, 210, 102, 114, 21, 154, 152, 209, 104, 126, 11, 100, 1, 27],
+		[99, 178, 122, 169, 144, 81, 51, 229, 38, 3, 218, 128, 56, 168, 153, 94, 62, 28, 128, 60, 228, 131, 33, 78, 202, 30, 82, 73, 113, 85, 218, 5],
 	],
 	[
-		[250, 102, 72, 185, 162, 75, 226, 71, 245, 101, 57, 89, 151, 76, 106, 250, 64, 45, 240, 254, 42, 231, 149, 41, 165, 113, 140, 191, 253, 53, 15, 48],
-		[66, 34, 251, 252, 86, 212, 239, 152, 1, 207, 249, 143, 249, 19, 138, 56, 16, 216, 172, 233, 85, 248, 94, 21, 42, 42, 141, 178, 126, 110, 53, 9],
-		[147, 242, 28, 7, 96, 144, 235, 234, 109, 184, 59, 39, 9, 110, 34, 180, 171, 3, 197, 179, 158, 251, 14, 187, 57, 226, 75, 186, 41, 106, 189, 161],
-		[241, 0, 84, 149, 249, 92, 137, 229, 65, 156, 135, 228, 44, 84, 232, 15, 108, 130, 205, 131, 54, 72, 0, 168, 245, 79, 102, 12, 108, 47, 88, 175],
+		[231, 46, 122, 250, 99, 89, 99, 137, 58, 110, 199, 199, 61, 216, 206, 94, 120, 100, 190, 52, 111, 5, 165, 228, 233, 86, 42, 168, 96, 168, 65, 84],
+		[138, 29, 239, 160, 185, 143, 239, 198, 70, 131, 211, 55, 103, 217, 53, 165, 111, 86, 212, 75, 97, 157, 13, 251, 204, 129, 96, 76, 92, 44, 121, 156],
+		[43, 82, 42, 69, 40, 146, 98, 233, 139, 197, 207, 33, 103, 190, 229, 247, 99, 88, 54, 223, 17, 13, 58, 236, 135, 185, 232, 245, 108, 47, 169, 12],
+		[40, 201, 84, 158, 247, 234, 87, 246, 196, 164, 72, 177, 217, 236, 175, 227, 153, 115, 30, 160, 178, 214, 126, 245, 170, 212, 50, 93, 16, 177, 205, 176],
 	],
 	[
-		[50, 252, 167, 48, 236, 81, 150, 25, 157, 62, 167, 0, 207, 234, 135, 212, 184, 126, 117, 125, 206, 131, 8, 25, 189, 2, 81, 14, 89, 35, 120, 145],
-		[95, 244, 120, 244, 185, 113, 5, 36, 161, 162, 220, 180, 207, 87, 250, 135, 187, 206, 232, 213, 219, 8, 247, 96, 166, 15, 100, 7, 220, 63, 26, 31],
-		[125, 227, 68, 107, 87, 14, 121, 153, 169, 211, 60, 233, 213, 6, 199, 56, 115, 165, 176, 248, 195, 232, 147, 169, 253, 118, 153, 44, 193, 21, 199, 105],
-		[83, 215, 22, 90, 133, 122, 11, 114, 106, 5, 114, 136, 238, 80, 38, 65, 60, 242, 82, 185, 128, 220, 189, 110, 169, 151, 90, 241, 231, 67, 236, 155],
+		[81, 89, 190, 163, 40, 184, 194, 244, 217, 191, 191, 182, 104, 59, 255, 155, 148, 179, 56, 163, 21, 71, 160, 89, 11, 252, 215, 168, 95, 182, 151, 219],
+		[68, 63, 209, 95, 252, 78, 249, 11, 46, 54, 98, 29, 247, 214, 145, 36, 179, 10, 159, 61, 49, 81, 118, 172, 60, 52, 106, 93, 9, 91, 175, 68],
+		[135, 38, 24, 152, 231, 87, 41, 235, 71, 162, 161, 111, 54, 199, 5, 249, 228, 34, 227, 190, 179, 144, 185, 100, 235, 169, 161, 68, 183, 3, 142, 76],
+		[170, 110, 211, 232, 214, 151, 233, 197, 94, 101, 8, 166, 204, 102, 182, 13, 254, 159, 180, 226, 67, 237, 55, 126, 194, 109, 3, 181, 72, 221, 121, 195],
 	],
 	[
-		[132, 187, 75, 76, 30, 117, 156, 240, 243, 246, 252, 123, 25, 135, 156, 140, 89, 126, 112, 144, 66, 4, 89, 167, 13, 1, 41, 102, 189, 213, 15, 207],
-		[121, 142, 144, 221, 117, 188, 180, 173, 215, 8, 143, 64, 224, 247, 217, 7, 205, 241, 80, 10, 183, 128, 192, 81, 235, 160, 172, 1, 60, 61, 63, 77],
-		[129, 40, 174, 184, 5, 231, 193, 231, 163, 201, 209, 144, 87, 54, 142, 175, 189, 118, 163, 27, 254, 160, 60, 102, 226, 248, 54, 41, 177, 157, 230, 210],
-		[128, 4, 41, 241, 120, 108, 209, 251, 82, 75, 79, 91, 91, 26, 66, 95, 153, 96, 76, 3, 159, 155, 234, 210, 79, 253, 225, 207, 151, 189, 149, 29],
+		[151, 230, 124, 79, 187, 147, 150, 32, 46, 58, 182, 110, 80, 217, 172, 135, 147, 140, 37, 122, 210, 37, 100, 70, 181, 249, 125, 165, 144, 142, 151, 172],
+		[83, 1, 36, 189, 221, 241, 48, 140, 184, 3, 209, 111, 51, 248, 181, 232, 192, 167, 88, 145, 8, 30, 150, 71, 179, 191, 31, 70, 80, 172, 178, 114],
+		[3, 119, 16, 177, 184, 48, 6, 227, 143, 121, 46, 215, 56, 98, 57, 234, 141, 120, 110, 96, 170, 44, 8, 130, 221, 41, 253, 106, 90, 173, 100, 177],
+		[3, 195, 119, 244, 120, 86, 153, 44, 89, 167, 116, 35, 254, 153, 213, 200, 228, 0, 73, 194, 248, 83, 180, 172, 7, 47, 230, 46, 17, 37, 116, 196],
 	],
 	[
-		[231, 130, 141, 127, 225, 117, 8, 112, 246, 52, 112, 38, 59, 11, 170, 84, 191, 95, 38, 21, 76, 117, 75, 77, 50, 251, 167, 65, 75, 7, 197, 135],
-		[239, 202, 137, 200, 194, 80, 137, 144, 202, 104, 184, 51, 57, 131, 141, 35, 30, 6, 9, 18, 215, 34, 29, 110, 211, 235, 143, 62, 156, 238, 180, 192],
-		[106, 69, 137, 30, 45, 255, 170, 145, 185, 70, 24, 20, 245, 129, 192, 95, 225, 30, 239, 96, 156, 6, 111, 79, 133, 115, 128, 173, 124, 146, 121, 68],
-		[177, 47, 98, 65, 202, 90, 51, 128, 217, 243, 98, 136, 157, 22, 107, 25, 58, 82, 198, 216, 215, 197, 49, 16, 247, 225, 167, 48, 184, 97, 139, 128],
+		[130, 40, 65, 131, 190, 208, 28, 207, 65, 248, 124, 209, 109, 71, 233, 160, 7, 91, 106, 202, 36, 101, 16, 239, 178, 96, 113, 71, 129, 232, 155, 215],
+		[120, 105, 155, 86, 29, 164, 44, 148, 58, 162, 54, 211, 44, 143, 215, 69, 190, 88, 19, 140, 96, 200, 109, 31, 1, 37, 241, 49, 235, 221, 115, 25],
+		[197, 225, 168, 246, 228, 48, 163, 41, 192, 233, 3, 46, 88, 79, 183, 57, 23, 159, 157, 102, 75, 47, 76, 167, 65, 76, 128, 208, 85, 42, 12, 89],
+		[222, 212, 69, 196, 195, 236, 118, 23, 43, 9, 229, 99, 252, 145, 199, 222, 82, 1, 226, 42, 10, 220, 141, 92, 96, 84, 235, 229, 147, 59, 69, 190],
 	],
 	[
-		[186, 45, 15, 54, 188, 92, 125, 57, 250, 55, 129, 109, 132, 139, 125, 163, 79, 97, 19, 11, 80, 2, 205, 110, 102, 170, 182, 56, 18, 48, 254, 151],
-		[143, 61, 155, 56, 192, 139, 69, 1, 96, 174, 64, 63, 150, 117, 112, 7, 228, 146, 46, 84, 115, 189, 17, 82, 28, 168, 64, 21, 112, 25, 253, 181],
-		[236, 22, 62, 199, 253, 154, 139, 5, 121, 77, 226, 77, 7, 105, 15, 66, 82, 219, 138, 152, 253, 214, 117, 54, 4, 31, 246, 86, 159, 243, 252, 38],
-		[38, 133, 136, 152, 137, 96, 10, 174, 228, 252, 155, 151, 58, 185, 120, 45, 121, 73, 103, 7, 69, 107, 135, 8, 46, 222, 230, 136, 86, 132, 44, 143],
+		[177, 218, 106, 49, 218, 76, 58, 17, 192, 118, 17, 255, 53, 113, 91, 33, 72, 133, 175, 199, 238, 143, 113, 91, 207, 102, 136, 247, 16, 13, 40, 56],
+		[65, 248, 156, 86, 222, 133, 184, 143, 31, 242, 51, 244, 224, 151, 5, 199, 16, 180, 55, 192, 3, 69, 75, 242, 237, 209, 101, 13, 59, 107, 133, 243],
+		[29, 110, 154, 175, 50, 8, 93, 174, 151, 28, 215, 115, 226, 7, 160, 58, 107, 100, 151, 218, 124, 57, 108, 28, 185, 248, 155, 221, 224, 221, 226, 135],
+		[161, 234, 69, 128, 191, 224, 113, 254, 23, 254, 37, 162, 222, 9, 212, 10, 39, 190, 243, 47, 92, 161, 180, 238, 242, 64, 140, 81, 19, 10, 189, 39],
 	],
 	[
-		[78, 18, 153, 78, 85, 87, 122, 100, 47, 72, 183, 37, 52, 194, 198, 169, 225, 16, 226, 229, 87, 201, 24, 160, 73, 249, 221, 30, 239, 221, 148, 201],
-		[51, 184, 163, 249, 137, 208, 131, 170, 42, 132, 50, 172, 93, 131, 192, 115, 52, 19, 72, 12, 16, 51, 158, 88, 210, 61, 143, 234, 213, 48, 119, 75],
-		[7, 36, 185, 154, 83, 180, 115, 30, 119, 45, 189, 130, 35, 145, 242, 135, 191, 145, 33, 82, 192, 135, 138, 232, 67, 179, 55, 53, 57, 144, 250, 75],
-		[70, 139, 185, 78, 167, 250, 96, 77, 19, 255, 181, 220, 138, 194, 239, 59, 149, 206, 9, 183, 71, 208, 142, 239, 175, 55, 171, 41, 101, 153, 167, 163],
+		[236, 125, 176, 82, 110, 120, 178, 112, 199, 122, 74, 251, 30, 46, 112, 164, 147, 0, 227, 199, 68, 10, 237, 16, 166, 54, 146, 138, 228, 191, 42, 140],
+		[203, 74, 111, 70, 213, 100, 230, 120, 65, 217, 176, 114, 46, 179, 156, 92, 59, 164, 252, 102, 212, 110, 14, 233, 64, 142, 12, 31, 66, 200, 50, 156],
+		[166, 61, 33, 95, 40, 210, 91, 109, 0, 175, 129, 91, 175, 176, 183, 67, 250, 239, 31, 44, 168, 73, 147, 223, 39, 196, 178, 177, 124, 226, 162, 92],
+		[53, 90, 219, 166, 152, 162, 89, 156, 148, 191, 63, 238, 173, 174, 126, 136, 227, 48, 88, 37, 212, 152, 60, 173, 82, 9, 13, 242, 136, 218, 50, 150],
 	],
 	[
-		[204, 125, 167, 246, 82, 176, 31, 158, 96, 117, 9, 2, 183, 142, 148, 84, 146, 173, 9, 17, 196, 247, 220, 4, 95, 215, 78, 207, 188, 168, 244, 144],
-		[152, 242, 28, 19, 192, 65, 192, 114, 177, 128, 94, 183, 155, 72, 220, 81, 160, 125, 18, 139, 2, 63, 101, 57, 209, 117, 21, 75, 200, 209, 174, 58],
-		[106, 13, 133, 249, 106, 224, 64, 178, 29, 19, 135, 23, 99, 151, 56, 66, 76, 7, 193, 112, 249, 222, 148, 165, 191, 33, 254, 237, 21, 37, 119, 134],
-		[101, 226, 66, 19, 176, 236, 78, 51, 184, 9, 128, 127, 117, 207, 147, 130, 89, 218, 64, 119, 55, 226, 36, 47, 44, 212, 152, 5, 249, 99, 155, 39],
+		[167, 94, 155, 12, 97, 73, 43, 90, 4, 51, 232, 145, 27, 164, 170, 175, 15, 105, 81, 151, 254, 83, 148, 52, 77, 76, 147, 155, 105, 32, 136, 48],
+		[43, 43, 106, 18, 195, 167, 47, 162, 221, 193, 87, 118, 189, 228, 113, 29, 95, 174, 85, 48, 120, 144, 164, 142, 173, 16, 53, 253, 129, 39, 182, 7],
+		[90, 241, 138, 113, 228, 159, 202, 141, 25, 56, 72, 59, 234, 212, 200, 188, 39, 193, 5, 100, 178, 56, 221, 154, 221, 208, 238, 237, 94, 118, 172, 208],
+		[164, 225, 196, 246, 25, 90, 225, 136, 98, 22, 133, 143, 30, 243, 47, 255, 60, 24, 186, 105, 63, 211, 202, 183, 198, 192, 201, 235, 145, 132, 185, 46],
 	],
 	[
-		[25, 27, 38, 50, 60, 49, 62, 197, 137, 180, 185, 243, 159, 7, 203, 1, 37, 116, 6, 225, 138, 135, 111, 3, 120, 254, 85, 206, 59, 220, 2, 178],
-		[106, 41, 227, 186, 32, 130, 126, 88, 149, 147, 182, 231, 74, 34, 197, 65, 33, 11, 8, 79, 249, 108, 109, 98, 127, 114, 48, 151, 163, 106, 13, 177],
-		[230, 28, 49, 54, 34, 227, 136, 155, 39, 234, 247, 239, 214, 33, 60, 238, 92, 166, 255, 73, 53, 73, 160, 5, 117, 210, 173, 25, 137, 179, 92, 190],
-		[14, 202, 13, 249, 190, 92, 5, 23, 137, 219, 237, 233, 197, 253, 137, 132, 0, 36, 146, 112, 214, 42, 36, 73, 24, 88, 121, 105, 189, 153, 1, 58],
+		[149, 245, 43, 122, 164, 103, 46, 11, 153, 188, 27, 13, 103, 69, 185, 130, 39, 150, 26, 196, 105, 44, 88, 138, 233, 220, 136, 61, 147, 250, 221, 243],
+		[88, 110, 13, 163, 207, 14, 238, 16, 89, 27, 183, 104, 133, 221, 133, 98, 248, 138, 147, 128, 48, 64, 113, 41, 187, 186, 1, 120, 168, 135, 25, 190],
+		[145, 103, 238, 132, 112, 30, 130, 116, 219, 207, 255, 218, 57, 61, 0, 131, 249, 166, 92, 88, 122, 50, 180, 221, 241, 230, 192, 125, 77, 106, 62, 184],
+		[217, 173, 56, 151, 32, 113, 237, 200, 153, 73, 10, 228, 14, 216, 208, 91, 166, 188, 46, 78, 104, 120, 88, 119, 25, 85, 125, 54, 90, 231, 232, 105],
 	],
 	[
-		[120, 90, 19, 224, 237, 5, 145, 160, 169, 105, 246, 179, 179, 66, 156, 199, 174, 127, 114, 89, 235, 113, 137, 192, 203, 197, 83, 55, 45, 233, 111, 103],
-		[107, 226, 18, 151, 86, 180, 30, 98, 32, 116, 27, 1, 166, 21, 81, 160, 184, 215, 176, 187, 222, 204, 254, 139, 209, 148, 228, 244, 228, 131, 215, 171],
-		[60, 42, 62, 16, 39, 87, 20, 144, 72, 117, 141, 110, 5, 246, 122, 172, 200, 181, 156, 71, 178, 79, 73, 205, 155, 37, 135, 121, 156, 173, 58, 168],
-		[227, 142, 73, 80, 48, 159, 129, 183, 200, 56, 42, 11, 109, 92, 141, 231, 192, 211, 169, 3, 20, 138, 169, 197, 170, 101, 165, 75, 228, 154, 65, 197],
+		[65, 16, 125, 178, 234, 160, 178, 52, 206, 199, 208, 35, 222, 84, 208, 173, 11, 209, 173, 84, 254, 86, 32, 174, 162, 68, 129, 0, 137, 26, 23, 90],
+		[140, 77, 48, 55, 68, 167, 173, 247, 38, 170, 25, 65, 30, 214, 61, 78, 83, 75, 86, 197, 191, 191, 151, 16, 70, 38, 168, 178, 87, 249, 223, 31],
+		[224, 221, 227, 249, 219, 25, 9, 71, 202, 97, 172, 191, 236, 228, 134, 80, 134, 1, 126, 215, 254, 131, 207, 23, 89, 253, 89, 171, 142, 252, 255, 228],
+		[157, 166, 184, 127, 171, 186, 133, 251, 204, 16, 13, 164, 2, 215, 53, 36, 206, 101, 63, 138, 214, 116, 218, 10, 146, 138, 246, 140, 224, 78, 121, 108],
 	],
 	[
-		[225, 231, 10, 111, 131, 243, 88, 146, 1, 169, 3, 59, 84, 46, 51, 159, 133, 109, 253, 56, 22, 43, 112, 217, 170, 192, 169, 202, 122, 52, 94, 203],
-		[44, 10, 74, 79, 147, 233, 4, 140, 171, 59, 224, 238, 165, 217, 185, 228, 56, 75, 12, 53, 121, 54, 251, 1, 71, 2, 248, 183, 177, 57, 87, 210],
-		[42, 215, 90, 204, 182, 91, 57, 219, 93, 143, 116, 167, 116, 9, 172, 96, 241, 31, 6, 194, 59, 80, 108, 143, 192, 223, 227, 255, 127, 52, 29, 16],
-		[171, 65, 59, 174, 146, 27, 11, 59, 2, 77, 69, 104, 30, 216, 186, 11, 203, 107, 253, 153, 104, 175, 4, 68, 187, 179, 205, 145, 242, 120, 96, 78],
+		[26, 35, 251, 77, 136, 201, 72, 51, 194, 125, 191, 95, 23, 84, 104, 232, 168, 197, 158, 124, 214, 48, 241, 91, 233, 247, 191, 62, 18, 129, 131, 67],
+		[191, 208, 81, 5, 171, 131, 188, 247, 32, 7, 108, 87, 169, 97, 182, 210, 150, 155, 95, 73, 9, 209, 236, 88, 63, 221, 140, 193, 220, 180, 225, 96],
+		[210, 251, 74, 61, 94, 74, 204, 45, 196, 96, 9, 166, 10, 225, 122, 94, 34, 138, 120, 162, 137, 92, 218, 56, 132, 71, 234, 205, 100, 133, 191, 184],
+		[127, 139, 99, 63, 50, 239, 148, 117, 94, 184, 31, 90, 208, 128, 7, 235, 237, 171, 254, 156, 84, 225, 119, 14, 27, 54, 83, 93, 238, 49, 222, 193],
 	],
 	[
-		[252, 101, 41, 54, 137, 222, 249, 99, 81, 248, 76, 222, 167, 70, 7, 50, 141, 234, 189, 185, 246, 25, 255, 234, 34, 109, 222, 145, 173, 169, 64, 108],
-		[200, 233, 221, 123, 50, 224, 70, 181, 5, 92, 166, 107, 179, 80, 161, 199, 10, 130, 223, 178, 216, 29, 226, 141, 184, 144, 188, 83, 190, 166, 240, 176],
-		[124, 232, 1, 161, 125, 35, 48, 177, 40, 72, 171, 242, 110, 105, 144, 87, 91, 213, 51, 28, 0, 249, 8, 185, 7, 86, 239, 156, 216, 89, 29, 22],
-		[143, 76, 48, 13, 9, 105, 194, 225, 112, 224, 162, 183, 218, 23, 77, 159, 16, 24, 255, 125, 190, 31, 58, 179, 16, 65, 249, 255, 239, 136, 178, 69],
+		[87, 189, 97, 143, 195, 80, 182, 140, 37, 56, 131, 96, 113, 136, 189, 97, 129, 49, 186, 136, 18, 230, 85, 125, 144, 116, 132, 9, 163, 243, 91, 147],
+		[17, 15, 238, 127, 105, 227, 72, 194, 248, 94, 163, 198, 196, 101, 83, 233, 206, 83, 104, 207, 64, 48, 176, 211, 205, 49, 255, 180, 50, 146, 218, 89],
+		[135, 210, 37, 251, 54, 80, 151, 170, 42, 139, 6, 37, 227, 27, 180, 53, 40, 20, 0, 172, 154, 129, 132, 73, 2, 244, 36, 81, 111, 176, 136, 204],
+		[87, 199, 44, 31, 184, 164, 170, 202, 71, 29, 147, 73, 212, 69, 84, 149, 206, 214, 229, 104, 115, 124, 172, 238, 93, 224, 39, 181, 98, 224, 4, 162],
 	],
 	[
-		[195, 44, 118, 133, 0, 248, 255, 162, 178, 48, 116, 18, 18, 219, 251, 88, 85, 187, 63, 165, 43, 72, 212, 13, 250, 227, 218, 154, 109, 121, 77, 25],
-		[19, 108, 130, 1, 29, 102, 84, 62, 56, 101, 102, 47, 27, 209, 76, 8, 105, 249, 53, 234, 117, 194, 0, 227, 118, 70, 48, 86, 107, 182, 54, 110],
-		[213, 66, 23, 206, 71, 32, 140, 82, 22, 173, 6, 183, 49, 55, 79, 141, 181, 16, 135, 113, 45, 50, 222, 71, 102, 104, 205, 126, 141, 40, 254, 170],
-		[58, 99, 33, 154, 51, 217, 187, 47, 35, 16, 186, 126, 23, 183, 115, 45, 101, 135, 186, 103, 182, 137, 79, 157, 130, 191, 3, 190, 238, 54, 166, 130],
+		[210, 24, 249, 243, 91, 80, 176, 126, 21, 58, 58, 168, 121, 159, 26, 143, 179, 21, 8, 130, 237, 75, 212, 108, 179, 32, 94, 9, 229, 148, 25, 170],
+		[226, 25, 18, 238, 222, 34, 13, 189, 153, 25, 18, 134, 92, 133, 109, 217, 9, 125, 90, 17, 44, 162, 99, 20, 190, 122, 54, 135, 194, 102, 95, 63],
+		[199, 200, 59, 87, 159, 20, 187, 57, 128, 239, 155, 60, 249, 75, 23, 154, 182, 203, 122, 237, 164, 137, 143, 189, 240, 208, 70, 31, 106, 123, 63, 80],
+		[239, 105, 165, 248, 201, 205, 216, 117, 7, 246, 211, 0, 88, 98, 179, 51, 172, 4, 157, 168, 229, 79, 49, 229, 25, 189, 63, 255, 17, 226, 50, 81],
 	],
 	[
-		[234, 118, 143, 2, 220, 40, 22, 91, 39, 72, 120, 145, 179, 245, 208, 86, 186, 79, 21, 219, 200, 123, 203, 200, 249, 143, 145, 250, 198, 18, 122, 3],
-		[132, 69, 248, 192, 211, 139, 130, 207, 253, 52, 253, 234, 178, 97, 49, 181, 77, 53, 129, 86, 181, 79, 169, 153, 134, 97, 120, 9, 97, 112, 12, 70],
-		[38, 163, 6, 55, 209, 15, 234, 225, 87, 16, 107, 182, 206, 107, 151, 222, 254, 1, 70, 199, 166, 91, 7, 227, 169, 210, 65, 155, 107, 184, 241, 149],
-		[204, 135, 201, 140, 115, 15, 39, 183, 25, 130, 53, 231, 244, 103, 212, 147, 15, 2, 135, 239, 67, 122, 30, 67, 150, 86, 82, 206, 17, 236, 136, 220],
+		[68, 161, 107, 84, 4, 115, 252, 13, 112, 174, 228, 63, 12, 40, 175, 26, 73, 95, 69, 91, 74, 41, 33, 226, 205, 254, 185, 118, 105, 253, 220, 167],
+		[190, 114, 3, 209, 93, 5, 57, 99, 53, 252, 160, 237, 88, 162, 90, 214, 156, 251, 39, 143, 7, 132, 51, 58, 222, 13, 222, 98, 63, 34, 48, 80],
+		[166, 173, 229, 248, 197, 235, 92, 35, 187, 10, 154, 193, 16, 90, 103, 191, 137, 247, 152, 240, 138, 4, 80, 143, 252, 138, 241, 4, 156, 146, 10, 237],
+		[10, 242, 91, 172, 191, 253, 138, 66, 29, 240, 216, 158, 188, 113, 94, 18, 252, 123, 249, 199, 224, 149, 240, 238, 55, 147, 225, 141, 104, 43, 229, 11],
 	],
 	[
-		[54, 66, 204, 210, 215, 219, 171, 16, 56, 127, 106, 11, 247, 209, 178, 86, 104, 36, 0, 179, 186, 231, 159, 4, 212, 31, 223, 124, 52, 74, 22, 61],
-		[213, 84, 13, 151, 169, 190, 18, 67, 196, 141, 64, 194, 176, 202, 45, 155, 51, 212, 75, 185, 148, 132, 33, 19, 37, 52, 184, 82, 255, 86, 250, 224],
-		[163, 67, 34, 168, 18, 233, 207, 128, 226, 46, 129, 221, 31, 110, 195, 173, 194, 44, 51, 164, 104, 109, 163, 208, 112, 42, 2, 252, 205, 196, 15, 223],
-		[115, 9, 35, 232, 67, 218, 24, 154, 115, 90, 141, 214, 62, 78, 26, 39, 196, 189, 212, 168, 168, 92, 213, 150, 190, 213, 223, 242, 100, 204, 206, 218],
+		[24, 185, 102, 10, 120, 135, 219, 243, 135, 25, 73, 150, 128, 221, 177, 10, 210, 32, 39, 126, 54, 5, 48, 92, 227, 70, 185, 199, 169, 166, 50, 166],
+		[84, 225, 178, 65, 185, 58, 221, 229, 128, 90, 203, 172, 137, 230, 21, 228, 110, 138, 50, 155, 49, 39, 158, 27, 240, 116, 189, 145, 1, 26, 40, 155],
+		[228, 161, 149, 111, 74, 223, 164, 193, 88, 146, 216, 226, 81, 57, 43, 217, 143, 73, 31, 160, 63, 186, 195, 118, 183, 119, 237, 213, 91, 73, 186, 26],
+		[254, 102, 242, 41, 252, 139, 20, 229, 190, 129, 243, 82, 198, 141, 111, 164, 25, 184, 48, 250, 54, 39, 85, 48, 122, 90, 236, 228, 61, 0, 70, 183],
 	],
 	[
-		[211, 6, 40, 166, 250, 53, 174, 200, 79, 128, 196, 95, 112, 18, 104, 182, 37, 35, 106, 159, 143, 60, 97, 11, 186, 223, 161, 25, 209, 217, 64, 55],
-		[234, 45, 121, 193, 105, 225, 230, 27, 239, 193, 131, 40, 225, 48, 127, 217, 10, 20, 115, 233, 244, 90, 94, 152, 237, 185, 109, 2, 137, 247, 133, 10],
-		[241, 42, 232, 126, 254, 240, 150, 79, 202, 147, 172, 7, 56, 135, 142, 2, 125, 201, 59, 43, 95, 48, 122, 232, 11, 91, 101, 35, 15, 249, 246, 8],
-		[176, 188, 128, 33, 237, 66, 99, 216, 172, 139, 215, 41, 147, 204, 129, 207, 61, 250, 192, 55, 149, 215, 34, 122, 205, 202, 234, 202, 196, 242, 49, 177],
+		[21, 68, 20, 157, 175, 198, 145, 15, 168, 135, 82, 61, 200, 194, 188, 68, 79, 135, 138, 247, 4, 102, 175, 111, 229, 168, 217, 209, 117, 205, 15, 25],
+		[20, 194, 186, 45, 234, 7, 116, 157, 68, 16, 203, 89, 54, 9, 106, 179, 101, 204, 6, 252, 137, 98, 69, 28, 189, 191, 35, 58, 137, 100, 147, 37],
+		[188, 171, 103, 68, 190, 93, 202, 124, 55, 240, 41, 140, 228, 228, 169, 242, 36, 100, 144, 226, 177, 76, 87, 41, 214, 164, 186, 3, 198, 8, 26, 197],
+		[51, 163, 122, 156, 116, 20, 162, 145, 219, 117, 194, 233, 238, 134, 156, 215, 13, 98, 79, 149, 121, 167, 130, 120, 236, 68, 11, 22, 97, 3, 248, 184],
 	],
 	[
-		[212, 216, 109, 73, 146, 18, 214, 209, 209, 127, 133, 212, 126, 54, 228, 155, 214, 23, 74, 111, 38, 128, 168, 210, 10, 85, 46, 134, 84, 112, 12, 102],
-		[210, 247, 234, 11, 179, 118, 46, 218, 252, 197, 39, 241, 151, 2, 114, 34, 8, 244, 13, 126, 200, 35, 167, 82, 221, 3, 234, 122, 109, 74, 77, 16],
-		[8, 74, 198, 238, 121, 216, 186, 165, 182, 119, 127, 248, 142, 40, 237, 247, 236, 110, 198, 85, 104, 199, 148, 170, 11, 68, 182, 91, 104, 177, 145, 50],
-		[161, 240, 255, 151, 238, 83, 69, 19, 38, 31, 128, 223, 113, 122, 224, 136, 249, 173, 160, 36, 147, 203, 202, 3, 87, 154, 157, 139, 121, 22, 153, 152],
+		[84, 79, 76, 155, 40, 118, 23, 24, 141, 166, 64, 37, 10, 186, 10, 205, 92, 179, 154, 174, 26, 168, 19, 109, 198, 125, 76, 122, 218, 99, 33, 165],
+		[241, 158, 151, 245, 88, 121, 213, 16, 117, 80, 245, 154, 225, 152, 26, 28, 101, 193, 188, 104, 48, 42, 183, 22, 99, 221, 206, 39, 164, 76, 75, 94],
+		[67, 35, 10, 133, 137, 190, 68, 135, 26, 142, 122, 225, 238, 229, 165, 146, 238, 43, 81, 27, 77, 125, 134, 221, 13, 157, 30, 120, 49, 83, 104, 72],
+		[9, 240, 191, 246, 20, 206, 55, 40, 128, 168, 162, 23, 76, 103, 112, 99, 191, 132, 235, 134, 84, 5, 15, 253, 147, 69, 238, 126, 81, 198, 76, 151],
 	],
 	[
-		[25, 12, 248, 185, 71, 185, 202, 214, 46, 118, 189, 111, 165, 246, 170, 8, 172, 204, 227, 9, 52, 201, 83, 64, 148, 158, 9, 54, 212, 175, 215, 222],
-		[176, 5, 214, 233, 73, 16, 174, 80, 84, 144, 246, 113, 23, 110, 80, 133, 112, 207, 48, 142, 181, 94, 113, 102, 187, 195, 21, 101, 43, 221, 155, 241],
-		[46, 108, 162, 205, 178, 83, 235, 164, 37, 116, 75, 47, 103, 242, 80, 209, 147, 135, 234, 136, 91, 64, 43, 94, 192, 22, 225, 40, 96, 172, 75, 156],
-		[76, 143, 128, 218, 155, 189, 255, 60, 217, 249, 217, 64, 207, 137, 122, 8, 146, 65, 89, 214, 41, 27, 177, 189, 168, 21, 164, 141, 4, 255, 96, 42],
+		[19, 255, 244, 123, 66, 179, 56, 75, 85, 250, 179, 49, 220, 34, 63, 153, 133, 32, 207, 212, 229, 123, 10, 90, 119, 238, 116, 214, 163, 190, 253, 192],
+		[113, 68, 163, 238, 215, 237, 12, 60, 8, 70, 175, 116, 18, 73, 197, 125, 58, 70, 102, 222, 84, 96, 162, 62, 147, 248, 167, 18, 133, 144, 242, 220],
+		[113, 253, 70, 19, 219, 85, 81, 56, 70, 43, 237, 84, 175, 84, 134, 12, 245, 238, 252, 223, 123, 12, 45, 172, 43, 105, 66, 14, 139, 18, 95, 25],
+		[9, 191, 210, 168, 19, 149, 22, 174, 12, 156, 20, 232, 60, 87, 126, 202, 97, 229, 33, 240, 74, 253, 56, 28, 54, 98, 189, 136, 187, 108, 248, 41],
 	],
 	[
-		[196, 22, 106, 42, 255, 182, 97, 130, 172, 87, 140, 172, 230, 219, 231, 174, 216, 127, 221, 38, 228, 139, 77, 89, 192, 104, 173, 115, 88, 135, 246, 232],
-		[223, 113, 154, 102, 143, 140, 113, 3, 114, 33, 248, 6, 212, 218, 193, 140, 21, 200, 114, 1, 235, 203, 218, 169, 77, 70, 254, 131, 55, 42, 113, 206],
-		[25, 49, 192, 15, 132, 26, 167, 142, 6, 127, 165, 125, 125, 75, 12, 72, 248, 109, 121, 137, 241, 127, 173, 255, 47, 179, 55, 216, 152, 204, 111, 96],
-		[163, 78, 199, 6, 222, 153, 243, 246, 131, 125, 82, 45, 169, 193, 198, 132, 84, 158, 21, 8, 76, 47, 30, 171, 141, 246, 90, 42, 72, 242, 190, 9],
+		[185, 116, 198, 67, 206, 18, 243, 162, 84, 72, 208, 218, 115, 3, 141, 8, 233, 140, 53, 168, 33, 14, 27, 40, 212, 87, 189, 176, 133, 122, 77, 136],
+		[91, 148, 140, 118, 48, 218, 24, 27, 220, 17, 49, 210, 174, 4, 240, 116, 194, 144, 6, 97, 115, 197, 233, 140, 7, 61, 27, 253, 174, 108, 172, 156],
+		[45, 111, 169, 68, 83, 115, 235, 31, 162, 33, 69, 17, 41, 106, 33, 92, 84, 68, 177, 1, 85, 35, 59, 110, 124, 129, 103, 177, 190, 18, 40, 95],
+		[194, 26, 32, 232, 155, 192, 86, 67, 76, 81, 239, 34, 61, 55, 172, 198, 135, 222, 71, 32, 95, 218, 24, 116, 81, 37, 153, 37, 6, 141, 198, 136],
 	],
 	[
-		[248, 190, 219, 2, 49, 79, 53, 25, 227, 61, 124, 23, 254, 13, 63, 41, 59, 9, 205, 127, 148, 149, 53, 60, 30, 228, 196, 230, 144, 240, 170, 96],
-		[251, 207, 206, 95, 244, 42, 234, 116, 208, 95, 160, 139, 13, 207, 129, 90, 108, 57, 167, 95, 102, 212, 140, 249, 3, 10, 254, 144, 184, 64, 33, 153],
-		[75, 54, 127, 139, 6, 140, 211, 206, 144, 64, 44, 190, 41, 139, 222, 26, 63, 1, 42, 92, 36, 109, 127, 72, 111, 164, 196, 58, 206, 141, 98, 92],
-		[83, 170, 91, 50, 249, 28, 149, 101, 80, 43, 116, 133, 221, 254, 69, 186, 204, 173, 59, 238, 59, 89, 202, 195, 106, 64, 100, 171, 128, 156, 180, 227],
+		[74, 21, 160, 150, 66, 197, 81, 136, 141, 35, 133, 156, 171, 20, 90, 101, 236, 0, 237, 182, 72, 2, 174, 208, 178, 21, 126, 36, 113, 223, 27, 69],
+		[135, 225, 255, 92, 137, 65, 178, 235, 116, 180, 184, 152, 105, 169, 207, 197, 225, 3, 235, 134, 92, 17, 3, 138, 36, 169, 64, 89, 133, 76, 146, 153],
+		[63, 147, 33, 144, 38, 85, 190, 44, 184, 27, 61, 77, 41, 38, 41, 77, 177, 13, 210, 184, 195, 40, 75, 133, 200, 67, 223, 195, 175, 223, 10, 202],
+		[94, 29, 107, 214, 249, 74, 64, 46, 21, 104, 191, 126, 189, 32, 56, 104, 191, 105, 158, 242, 80, 234, 67, 138, 43, 100, 215, 93, 97, 122, 144, 4],
 	],
 	[
-		[214, 250, 220, 219, 162, 246, 88, 120, 176, 150, 43, 83, 37, 180, 247, 123, 143, 164, 206, 169, 22, 158, 120, 29, 131, 185, 151, 245, 226, 175, 50, 91],
-		[84, 173, 183, 183, 215, 149, 43, 144, 99, 47, 214, 146, 100, 5, 240, 184, 67, 28, 88, 7, 194, 94, 249, 96, 104, 177, 224, 131, 164, 95, 117, 11],
-		[204, 122, 141, 203, 238, 48, 218, 247, 107, 15, 128, 228, 148, 203, 77, 118, 17, 235, 19, 29, 64, 228, 249, 63, 35, 22, 40, 172, 230, 121, 0, 169],
-		[176, 62, 216, 188, 192, 211, 32, 230, 185, 204, 71, 212, 28, 164, 127, 148, 178, 19, 191, 219, 28, 247, 243, 83, 233, 24, 136, 71, 229, 197, 57, 97],
+		[12, 169, 163, 202, 42, 24, 246, 15, 41, 129, 191, 230, 172, 77, 149, 138, 181, 82, 130, 83, 81, 126, 60, 14, 200, 159, 160, 221, 203, 247, 107, 23],
+		[234, 0, 71, 202, 75, 165, 176, 240, 123, 39, 205, 64, 13, 95, 91, 98, 116, 95, 137, 191, 65, 197, 115, 57, 125, 67, 230, 73, 157, 20, 202, 106],
+		[187, 186, 104, 206, 42, 52, 115, 53, 226, 183, 242, 251, 147, 8, 64, 210, 188, 44, 37, 180, 67, 218, 59, 94, 9, 232, 185, 244, 89, 171, 238, 187],
+		[2, 143, 94, 220, 231, 29, 154, 185, 38, 212, 231, 126, 7, 37, 139, 221, 201, 86, 119, 178, 175, 109, 190, 234, 210, 227, 76, 98, 247, 95, 94, 38],
 	],
 	[
-		[124, 21, 39, 85, 78, 25, 137, 69, 46, 235, 147, 109, 150, 106, 192, 172, 189, 157, 190, 250, 254, 107, 110, 198, 60, 243, 96, 106, 16, 37, 197, 0],
-		[29, 207, 30, 183, 99, 218, 194, 138, 94, 233, 232, 115, 111, 13, 66, 58, 193, 124, 143, 17, 169, 99, 84, 169, 150, 27, 37, 212, 138, 185, 153, 57],
-		[224, 243, 210, 204, 99, 77, 46, 156, 80, 189, 250, 53, 64, 206, 37, 208, 239, 196, 191, 155, 117, 17, 123, 102, 90, 145, 217, 203, 131, 156, 157, 208],
-		[79, 165, 241, 44, 239, 6, 120, 108, 228, 237, 62, 169, 245, 111, 97, 229, 239, 92, 238, 121, 209, 92, 207, 236, 244, 244, 208, 142, 2, 72, 124, 21],
+		[241, 225, 133, 200, 42, 217, 103, 38, 66, 2, 205, 89, 159, 234, 168, 102, 124, 170, 159, 121, 1, 157, 127, 34, 76, 188, 7, 199, 104, 136, 111, 194],
+		[231, 25, 132, 177, 130, 85, 29, 9, 251, 201, 141, 185, 116, 78, 55, 95, 5, 194, 163, 198, 8, 44, 61, 181, 246, 225, 4, 194, 74, 226, 150, 190],
+		[245, 90, 195, 115, 26, 222, 118, 161, 90, 125, 237, 189, 91, 93, 165, 182, 230, 87, 211, 205, 44, 246, 180, 232, 242, 66, 228, 63, 3, 102, 160, 11],
+		[221, 107, 151, 133, 55, 205, 207, 106, 18, 68, 162, 174, 50, 144, 144, 190, 249, 244, 3, 139, 170, 253, 92, 207, 243, 233, 68, 105, 125, 193, 228, 242],
 	],
 	[
-		[142, 37, 33, 123, 180, 243, 79, 7, 179, 204, 196, 128, 202, 76, 68, 144, 149, 2, 90, 91, 37, 208, 91, 211, 205, 72, 110, 133, 104, 10, 204, 194],
-		[113, 206, 61, 185, 8, 199, 99, 146, 197, 65, 128, 231, 114, 96, 52, 241, 191, 139, 233, 40, 100, 65, 6, 150, 41, 2, 150, 190, 206, 118, 34, 205],
-		[94, 193, 218, 43, 76, 92, 1, 217, 207, 41, 150, 183, 166, 11, 242, 98, 119, 23, 72, 27, 174, 18, 107, 18, 252, 77, 111, 78, 144, 92, 197, 88],
-		[179, 176, 97, 47, 176, 91, 213, 16, 74, 169, 232, 98, 210, 227, 102, 29, 236, 71, 149, 237, 188, 193, 44, 142, 30, 160, 56, 30, 152, 237, 25, 104],
+		[95, 187, 115, 24, 112, 30, 65, 34, 8, 201, 175, 54, 105, 63, 160, 106, 199, 1, 127, 163, 103, 30, 141, 186, 166, 198, 33, 221, 30, 219, 122, 55],
+		[227, 250, 192, 64, 21, 143, 57, 251, 222, 159, 22, 222, 248, 254, 152, 141, 202, 14, 117, 38, 223, 230, 65, 54, 152, 195, 252, 16, 192, 146, 235, 159],
+		[201, 82, 48, 164, 36, 205, 178, 212, 80, 28, 66, 233, 36, 46, 227, 126, 68, 116, 241, 41, 154, 122, 216, 115, 10, 222, 16, 125, 42, 82, 213, 213],
+		[182, 246, 3, 225, 14, 215, 140, 115, 28, 62, 96, 206, 8, 204, 213, 85, 134, 93, 251, 61, 95, 130, 248, 245, 246, 163, 165, 117, 134, 245, 201, 191],
 	],
 	[
-		[51, 254, 54, 82, 6, 117, 73, 153, 174, 227, 248, 12, 84, 19, 73, 132, 241, 27, 190, 58, 41, 144, 44, 27, 31, 147, 68, 52, 46, 103, 86, 152],
-		[190, 149, 186, 59, 94, 63, 57, 185, 84, 21, 115, 232, 70, 180, 252, 164, 206, 206, 218, 127, 180, 21, 211, 117, 222, 187, 119, 224, 41, 135, 91, 78],
-		[92, 0, 175, 56, 143, 141, 52, 128, 13, 49, 158, 95, 135, 10, 142, 199, 205, 13, 166, 48, 130, 50, 26, 19, 7, 141, 27, 10, 181, 7, 151, 133],
-		[155, 5, 111, 223, 154, 1, 23, 93, 216, 234, 219, 45, 66, 99, 241, 73, 62, 58, 18, 210, 82, 187, 30, 35, 1, 146, 230, 99, 81, 116, 49, 80],
+		[26, 130, 26, 91, 229, 83, 95, 226, 105, 159, 35, 103, 206, 179, 53, 144, 207, 137, 87, 48, 9, 70, 167, 8, 99, 42, 106, 239, 189, 146, 162, 94],
+		[36, 2, 249, 103, 88, 119, 91, 224, 70, 146, 5, 217, 60, 40, 212, 91, 99, 246, 212, 55, 211, 188, 48, 115, 155, 92, 125, 163, 229, 186, 78, 197],
+		[15, 167, 128, 88, 137, 229, 58, 124, 231, 233, 75, 89, 218, 228, 38, 75, 209, 253, 209, 14, 254, 91, 24, 40, 207, 223, 186, 154, 13, 209, 141, 228],
+		[164, 98, 2, 54, 45, 129, 33, 149, 18, 112, 143, 84, 83, 189, 143, 180, 191, 30, 169, 240, 195, 134, 139, 65, 89, 16, 77, 236, 45, 174, 106, 143],
 	],
 	[
-		[221, 146, 207, 34, 226, 231, 127, 144, 227, 193, 210, 44, 55, 139, 55, 81, 238, 34, 26, 169, 12, 72, 47, 82, 158, 186, 157, 129, 240, 86, 111, 156],
-		[211, 152, 230, 225, 136, 146, 115, 152, 122, 93, 168, 206, 229, 173, 234, 15, 238, 206, 98, 165, 172, 173, 208, 45, 213, 136, 200, 184, 137, 79, 84, 53],
-		[46, 138, 32, 22, 138, 181, 240, 115, 71, 95, 242, 203, 143, 88, 200, 245, 115, 227, 29, 162, 132, 97, 146, 50, 177, 213, 244, 241, 111, 107, 159, 145],
-		[88, 66, 49, 83, 207, 230, 234, 132, 168, 135, 68, 122, 17, 122, 185, 235, 151, 90, 128, 166, 104, 56, 30, 216, 56, 133, 16, 254, 194, 210, 47, 145],
+		[2, 220, 19, 140, 116, 233, 180, 26, 145, 219, 19, 52, 136, 44, 246, 215, 39, 56, 98, 215, 168, 41, 6, 56, 98, 114, 246, 221, 189, 77, 126, 146],
+		[163, 81, 191, 170, 94, 232, 214, 208, 142, 65, 195, 195, 207, 148, 202, 214, 121, 30, 89, 67, 25, 61, 67, 199, 109, 107, 178, 213, 150, 211, 16, 12],
+		[113, 202, 2, 186, 118, 196, 253, 188, 151, 198, 153, 44, 69, 132, 217, 113, 158, 41, 101, 233, 35, 16, 94, 45, 37, 113, 255, 47, 249, 47, 197, 168],
+		[49, 229, 69, 149, 218, 213, 173, 183, 161, 3, 164, 59, 244, 8, 18, 26, 7, 241, 66, 216, 177, 235, 49, 157, 94, 154, 86, 171, 218, 20, 27, 32],
 	],
 	[
-		[17, 3, 120, 93, 252, 184, 78, 180, 184, 155, 109, 76, 185, 38, 98, 121, 78, 8, 232, 205, 115, 8, 221, 232, 78, 53, 37, 61, 38, 154, 75, 108],
-		[148, 55, 249, 103, 152, 142, 202, 104, 105, 159, 28, 1, 56, 206, 84, 7, 157, 151, 148, 18, 66, 84, 190, 180, 209, 52, 38, 130, 34, 153, 112, 219],
-		[177, 150, 122, 205, 219, 144, 226, 83, 164, 92, 23, 137, 25, 236, 148, 222, 106, 196, 233, 187, 40, 194, 191, 239, 36, 13, 91, 196, 140, 128, 30, 205],
-		[116, 122, 84, 153, 177, 209, 58, 156, 172, 93, 43, 97, 202, 80, 41, 45, 239, 164, 16, 66, 223, 45, 172, 106, 32, 240, 161, 173, 191, 96, 94, 230],
+		[60, 179, 88, 8, 142, 76, 215, 0, 12, 53, 96, 112, 74, 43, 82, 42, 45, 183, 8, 243, 230, 52, 179, 50, 250, 90, 108, 21, 67, 89, 144, 75],
+		[181, 1, 56, 133, 112, 91, 115, 250, 64, 205, 145, 117, 94, 129, 59, 89, 127, 214, 186, 3, 235, 130, 101, 78, 4, 98, 54, 87, 106, 60, 84, 130],
+		[45, 29, 2, 124, 188, 250, 243, 189, 59, 217, 64, 225, 214, 96, 180, 183, 155, 241, 208, 184, 83, 156, 34, 84, 199, 119, 195, 190, 236, 40, 71, 204],
+		[216, 185, 193, 132, 121, 110, 165, 57, 175, 68, 94, 166, 67, 212, 124, 133, 201, 94, 134, 228, 78, 235, 16, 95, 238, 147, 33, 213, 119, 228, 133, 229],
 	],
 	[
-		[129, 33, 212, 0, 138, 194, 176, 210, 98, 10, 21, 50, 66, 64, 26, 38, 59, 56, 229, 228, 70, 241, 158, 0, 197, 86, 141, 34, 81, 162, 189, 3],
-		[44, 25, 96, 190, 20, 179, 151, 241, 84, 158, 190, 58, 14, 190, 68, 67, 124, 117, 15, 173, 87, 57, 64, 40, 235, 157, 169, 15, 101, 122, 149, 162],
-		[128, 109, 151, 58, 107, 128, 62, 232, 14, 252, 33, 113, 208, 95, 156, 85, 243, 120, 105, 59, 174, 0, 56, 44, 217, 54, 120, 135, 18, 158, 180, 151],
-		[184, 144, 81, 171, 204, 10, 153, 8, 228, 120, 87, 218, 151, 160, 139, 157, 73, 191, 118, 46, 194, 114, 86, 89, 74, 223, 64, 138, 216, 30, 246, 60],
+		[132, 185, 34, 177, 110, 182, 222, 88, 222, 195, 188, 244, 11, 104, 158, 86, 138, 10, 89, 215, 127, 15, 111, 156, 204, 33, 96, 89, 73, 181, 254, 97],
+		[118, 56, 109, 157, 141, 195, 192, 102, 100, 122, 182, 24, 133, 24, 205, 193, 59, 157, 14, 205, 32, 64, 100, 93, 168, 90, 19, 139, 197, 123, 159, 233],
+		[30, 78, 148, 155, 227, 242, 165, 57, 19, 199, 11, 251, 136, 103, 54, 139, 40, 80, 209, 169, 63, 100, 218, 54, 157, 236, 165, 188, 168, 52, 230, 10],
+		[248, 20, 63, 243, 165, 24, 14, 204, 115, 252, 208, 148, 210, 100, 76, 38, 61, 213, 238, 70, 235, 246, 33, 30, 100, 169, 192, 38, 172, 24, 195, 189],
 	],
 	[
-		[168, 192, 123, 161, 223, 46, 144, 229, 13, 197, 77, 90, 76, 101, 191, 252, 4, 251, 244, 216, 54, 243, 231, 121, 229, 200, 111, 168, 72, 237, 65, 86],
-		[88, 183, 13, 86, 6, 49, 37, 45, 95, 163, 141, 56, 255, 151, 159, 76, 130, 230, 213, 36, 32, 148, 217, 31, 87, 188, 35, 77, 49, 90, 147, 115],
-		[172, 216, 80, 187, 239, 209, 152, 236, 80, 193, 80, 205, 0, 226, 128, 173, 29, 6, 132, 29, 188, 87, 230, 210, 251, 188, 251, 36, 88, 136, 72, 177],
-		[226, 94, 176, 141, 168, 65, 46, 67, 66, 100, 210, 159, 119, 121, 133, 175, 96, 31, 206, 99, 196, 230, 142, 15, 43, 27, 247, 200, 153, 167, 73, 229],
+		[89, 200, 159, 128, 250, 238, 125, 7, 236, 198, 52, 185, 139, 136, 197, 217, 182, 80, 82, 181, 82, 207, 246, 127, 183, 20, 38, 221, 173, 194, 225, 179],
+		[119, 59, 30, 9, 242, 67, 210, 41, 192, 190, 220, 126, 4, 69, 28, 248, 57, 187, 199, 146, 149, 214, 36, 80, 179, 81, 77, 168, 182, 91, 126, 65],
+		[115, 45, 190, 21, 245, 204, 47, 91, 148, 18, 129, 172, 137, 251, 252, 109, 168, 192, 2, 72, 100, 190, 185, 51, 75, 19, 73, 2, 176, 40, 175, 187],
+		[111, 222, 141, 153, 233, 60, 154, 160, 65, 26, 16, 172, 133, 102, 9, 194, 95, 185, 59, 255, 182, 200, 189, 103, 214, 201, 105, 26, 218, 3, 197, 91],
 	],
 	[
-		[58, 8, 191, 151, 68, 10, 151, 145, 132, 148, 39, 136, 93, 39, 230, 69, 235, 193, 110, 253, 171, 239, 217, 142, 208, 61, 83, 166, 14, 58, 20, 153],
-		[198, 139, 3, 143, 183, 32, 128, 135, 72, 183, 199, 160, 162, 116, 175, 16, 198, 111, 39, 174, 152, 173, 27, 89, 174, 63, 138, 60, 152, 221, 215, 31],
-		[187, 220, 189, 241, 24, 98, 187, 107, 171, 90, 52, 13, 65, 91, 53, 0, 237, 195, 59, 235, 187, 200, 154, 74, 76, 211, 215, 0, 119, 1, 136, 131],
-		[248, 230, 32, 216, 70, 64, 128, 70, 31, 154, 132, 239, 61, 159, 136, 171, 254, 164, 83, 174, 222, 121, 192, 77, 15, 70, 141, 6, 41, 163, 5, 173],
+		[109, 79, 241, 255, 252, 94, 212, 8, 64, 161, 162, 174, 197, 0, 163, 27, 28, 98, 246, 92, 191, 236, 137, 83, 48, 22, 237, 161, 236, 244, 73, 51],
+		[62, 17, 178, 18, 90, 90, 13, 235, 217, 110, 208, 36, 232, 239, 166, 111, 224, 122, 183, 184, 179, 70, 5, 109, 9, 53, 120, 20, 59, 113, 146, 77],
+		[21, 138, 86, 203, 17, 214, 113, 217, 17, 176, 117, 96, 103, 139, 227, 233, 152, 74, 177, 179, 244, 93, 226, 2, 220, 103, 232, 92, 134, 117, 33, 69],
+		[240, 252, 154, 59, 151, 225, 23, 190, 78, 146, 99, 8, 182, 172, 35, 16, 121, 55, 67, 192, 26, 246, 95, 141, 247, 177, 113, 121, 224, 13, 208, 152],
 	],
 	[
-		[126, 101, 216, 2, 29, 197, 126, 191, 31, 13, 154, 167, 92, 24, 175, 104, 134, 149, 162, 129, 62, 237, 169, 14, 177, 221, 179, 139, 163, 51, 124, 236],
-		[252, 8, 110, 184, 104, 232, 138, 207, 25, 19, 173, 146, 251, 160, 106, 113, 128, 83, 101, 2, 29, 188, 246, 78, 94, 216, 196, 214, 1, 6, 9, 184],
-		[115, 235, 2, 44, 128, 207, 59, 194, 148, 229, 229, 236, 201, 226, 231, 56, 63, 12, 213, 184, 188, 186, 182, 63, 124, 116, 59, 139, 63, 189, 180, 79],
-		[241, 104, 63, 160, 208, 252, 195, 120, 78, 144, 154, 254, 156, 160, 32, 91, 174, 101, 112, 134, 179, 59, 84, 246, 208, 209, 204, 68, 14, 184, 9, 157],
+		[236, 155, 79, 102, 54, 218, 185, 180, 47, 76, 214, 110, 100, 195, 97, 8, 246, 144, 55, 164, 141, 190, 220, 124, 77, 104, 16, 64, 80, 92, 76, 178],
+		[157, 36, 183, 49, 9, 191, 91, 149, 29, 106, 148, 192, 9, 209, 35, 246, 107, 171, 137, 39, 7, 58, 233, 231, 140, 234, 7, 139, 80, 124, 98, 25],
+		[132, 34, 166, 131, 181, 252, 184, 86, 15, 175, 146, 54, 255, 225, 204, 175, 99, 131, 197, 61, 121, 152, 102, 83, 173, 233, 118, 100, 7, 224, 152, 156],
+		[60, 104, 20, 137, 239, 148, 207, 124, 96, 12, 48, 66, 221, 145, 167, 3, 172, 83, 208, 15, 225, 21, 231, 44, 219, 62, 117, 233, 183, 14, 142, 208],
 	],
 	[
-		[23, 164, 188, 210, 163, 77, 142, 60, 81, 15, 52, 194, 68, 235, 26, 207, 26, 128, 149, 245, 248, 246, 60, 8, 102, 203, 130, 140, 47, 47, 255, 219],
-		[92, 158, 67, 111, 132, 187, 191, 29, 40, 17, 104, 181, 228, 146, 112, 78, 112, 98, 6, 110, 126, 26, 198, 172, 134, 242, 154, 166, 67, 158, 51, 104],
-		[193, 22, 9, 120, 202, 24, 15, 115, 194, 250, 179, 171, 179, 75, 128, 42, 94, 221, 54, 123, 84, 187, 129, 61, 47, 84, 99, 239, 200, 150, 224, 220],
-		[156, 132, 216, 169, 187, 140, 254, 120, 20, 80, 197, 79, 223, 187, 245, 246, 6, 88, 251, 89, 114, 100, 198, 106, 237, 71, 105, 195, 241, 136, 123, 77],
+		[38, 34, 223, 152, 129, 66, 57, 69, 121, 224, 251, 212, 205, 70, 129, 208, 234, 125, 140, 116, 209, 104, 215, 151, 120, 17, 68, 211, 153, 236, 17, 49],
+		[41, 58, 61, 173, 255, 60, 144, 229, 253, 95, 235, 170, 197, 49, 18, 181, 70, 250, 39, 68, 62, 100, 22, 48, 98, 155, 194, 10, 47, 85, 22, 212],
+		[167, 91, 118, 124, 150, 140, 148, 113, 252, 207, 203, 157, 72, 211, 249, 4, 89, 175, 172, 67, 9, 34, 133, 165, 116, 199, 220, 103, 14, 229, 22, 179],
+		[61, 221, 157, 76, 184, 219, 199, 189, 190, 155, 228, 175, 94, 72, 158, 231, 203, 211, 204, 227, 131, 221, 164, 162, 71, 141, 254, 186, 101, 19, 193, 238],
 	],
 	[
-		[151, 207, 61, 35, 229, 114, 190, 35, 76, 219, 147, 54, 254, 23, 130, 158, 33, 191, 134, 18, 106, 135, 238, 152, 166, 105, 101, 168, 253, 232, 130, 85],
-		[87, 118, 170, 83, 133, 18, 91, 241, 81, 12, 100, 211, 242, 41, 52, 178, 159, 187, 20, 119, 92, 145, 68, 198, 101, 72, 93, 15, 163, 142, 237, 59],
-		[119, 154, 12, 22, 238, 75, 143, 60, 1, 163, 50, 199, 163, 242, 4, 5, 88, 187, 150, 169, 179, 88, 229, 247, 136, 156, 111, 214, 161, 246, 208, 183],
-		[64, 48, 230, 9, 250, 35, 178, 193, 27, 105, 149, 247, 150, 22, 202, 204, 51, 62, 61, 143, 205, 162, 75, 166, 203, 60, 221, 34, 0, 61, 39, 138],
+		[44, 14, 31, 91, 229, 171, 233, 180, 230, 15, 251, 100, 30, 63, 106, 44, 211, 140, 51, 55, 99, 112, 48, 83, 178, 159, 77, 245, 177, 194, 7, 159],
+		[81, 37, 118, 69, 98, 241, 72, 122, 8, 153, 31, 228, 9, 125, 194, 255, 152, 233, 172, 65, 135, 142, 70, 181, 115, 196, 147, 151, 214, 2, 211, 145],
+		[170, 218, 142, 39, 144, 183, 75, 64, 69, 116, 75, 190, 64, 92, 228, 238, 9, 186, 56, 106, 194, 195, 106, 104, 39, 141, 8, 10, 176, 66, 4, 105],
+		[132, 183, 130, 3, 116, 167, 8, 42, 24, 26, 72, 49, 22, 96, 142, 127, 99, 73, 145, 105, 65, 117, 21, 167, 34, 176, 213, 156, 83, 167, 199, 83],
 	],
 	[
-		[10, 176, 82, 85, 114, 95, 237, 178, 180, 131, 14, 178, 181, 199, 66, 151, 225, 81, 136, 37, 175, 34, 165, 199, 152, 207, 73, 177, 148, 89, 85, 225],
-		[251, 101, 61, 243, 180, 196, 45, 201, 199, 113, 170, 249, 182, 89, 183, 143, 230, 109, 142, 178, 90, 87, 14, 32, 55, 31, 147, 120, 84, 134, 103, 94],
-		[95, 26, 162, 122, 9, 88, 192, 182, 127, 8, 174, 243, 207, 70, 251, 46, 26, 37, 44, 78, 53, 234, 145, 164, 33, 59, 56, 13, 173, 129, 126, 172],
-		[51, 26, 59, 162, 53, 204, 2, 62, 78, 7, 101, 63, 27, 196, 134, 231, 36, 81, 178, 235, 144, 111, 225, 9, 131, 250, 177, 243, 51, 75, 106, 237],
+		[38, 92, 227, 139, 152, 91, 31, 239, 20, 54, 53, 22, 242, 93, 76, 26, 72, 152, 50, 243, 108, 243, 29, 226, 238, 137, 195, 113, 234, 235, 176, 179],
+		[240, 22, 169, 102, 64, 54, 113, 184, 42, 183, 159, 254, 59, 91, 195, 44, 100, 147, 38, 56, 107, 197, 38, 191, 83, 221, 161, 60, 179, 142, 208, 35],
+		[6, 26, 38, 53, 67, 203, 19, 55, 27, 117, 17, 183, 40, 82, 6, 75, 116, 20, 196, 226, 242, 221, 21, 157, 1, 21, 240, 21, 87, 165, 237, 97],
+		[241, 173, 197, 162, 14, 247, 74, 197, 182, 41, 35, 118, 242, 252, 157, 197, 92, 85, 27, 197, 111, 132, 1, 10, 225, 43, 45, 176, 58, 255, 200, 27],
 	],
 	[
-		[180, 76, 102, 157, 238, 77, 38, 222, 92, 8, 146, 18, 164, 237, 229, 123, 236, 31, 19, 248, 227, 47, 140, 46, 148, 219, 48, 131, 91, 44, 76, 42],
-		[184, 94, 139, 190, 180, 164, 98, 27, 255, 195, 124, 132, 34, 6, 71, 226, 141, 57, 157, 118, 195, 89, 160, 127, 6, 194, 224, 105, 1, 102, 36, 149],
-		[25, 158, 249, 235, 204, 177, 12, 198, 136, 74, 210, 211, 173, 30, 131, 115, 26, 89, 12, 38, 230, 108, 185, 34, 125, 57, 107, 167, 211, 94, 85, 148],
-		[236, 112, 58, 179, 32, 73, 7, 113, 46, 28, 57, 226, 93, 69, 47, 78, 150, 194, 12, 113, 86, 134, 230, 137, 130, 6, 148, 113, 41, 233, 177, 243],
+		[50, 201, 8, 234, 135, 13, 129, 27, 47, 24, 78, 133, 4, 193, 81, 58, 105, 103, 180, 188, 246, 211, 130, 206, 68, 212, 194, 17, 115, 225, 177, 81],
+		[10, 246, 191, 71, 167, 110, 112, 255, 39, 10, 159, 253, 152, 25, 66, 80, 251, 1, 232, 49, 168, 30, 137, 174, 75, 249, 18, 144, 99, 69, 129, 18],
+		[196, 40, 117, 151, 176, 16, 107, 194, 137, 124, 24, 6, 221, 13, 217, 45, 230, 233, 65, 103, 87, 16, 159, 68, 8, 213, 240, 98, 4, 110, 84, 129],
+		[249, 45, 70, 169, 232, 204, 236, 177, 164, 76, 68, 135, 83, 177, 73, 227, 232, 111, 39, 65, 141, 130, 27, 40, 83, 54, 38, 174, 34, 240, 230, 222],
 	],
 	[
-		[233, 253, 12, 69, 164, 129, 184, 41, 103, 26, 55, 85, 248, 113, 2, 240, 4, 226, 247, 141, 73, 92, 60, 7, 53, 30, 143, 117, 90, 43, 161, 133],
-		[124, 61, 250, 116, 72, 19, 132, 82, 18, 65, 81, 198, 152, 81, 4, 150, 99, 174, 132, 105, 58, 49, 84, 194, 136, 247, 57, 179, 167, 95, 241, 89],
-		[61, 216, 79, 59, 51, 90, 239, 82, 54, 11, 155, 220, 150, 241, 135, 137, 133, 7, 200, 187, 41, 181, 74, 181, 76, 0, 104, 117, 189, 6, 45, 173],
-		[113, 68, 175, 185, 253, 152, 197, 156, 150, 28, 5, 48, 146, 213, 119, 54, 111, 191, 114, 194, 37, 156, 83, 205, 192, 162, 116, 55, 236, 41, 252, 54],
+		[71, 98, 172, 0, 32, 247, 223, 246, 219, 51, 145, 182, 184, 104, 110, 93, 95, 157, 228, 111, 80, 35, 125, 73, 194, 166, 110, 62, 107, 197, 211, 136],
+		[127, 100, 139, 201, 134, 97, 219, 237, 143, 182, 4, 214, 225, 69, 47, 84, 130, 178, 60, 171, 216, 158, 155, 221, 176, 35, 20, 212, 221, 177, 188, 139],
+		[226, 89, 82, 139, 248, 174, 211, 37, 15, 149, 168, 115, 52, 135, 197, 200, 239, 64, 174, 3, 117, 131, 78, 31, 217, 188, 119, 204, 8, 247, 149, 112],
+		[1, 103, 137, 213, 196, 58, 206, 147, 171, 215, 173, 90, 186, 111, 203, 27, 248, 184, 241, 249, 2, 118, 130, 107, 166, 202, 102, 126, 119, 228, 182, 203],
 	],
 	[
-		[77, 27, 151, 172, 65, 225, 210, 18, 150, 39, 65, 41, 88, 235, 8, 211, 123, 65, 190, 193, 49, 204, 99, 41, 207, 188, 218, 189, 70, 109, 175, 46],
-		[207, 225, 142, 113, 215, 187, 129, 233, 247, 149, 219, 84, 88, 106, 203, 137, 214, 123, 75, 81, 93, 107, 96, 210, 188, 220, 251, 109, 218, 104, 190, 7],
-		[99, 128, 214, 236, 211, 30, 85, 108, 138, 58, 253, 92, 75, 159, 180, 157, 127, 7, 199, 31, 14, 68, 135, 2, 223, 57, 161, 91, 126, 130, 5, 12],
-		[30, 190, 164, 49, 134, 104, 201, 235, 233, 57, 164, 148, 34, 10, 52, 243, 242, 137, 174, 230, 65, 98, 200, 185, 244, 113, 143, 147, 101, 162, 41, 132],
+		[54, 97, 176, 249, 9, 86, 106, 214, 235, 73, 79, 219, 139, 146, 16, 164, 152, 10, 239, 3, 231, 209, 67, 45, 0, 249, 228, 68, 74, 191, 99, 135],
+		[183, 58, 59, 171, 39, 143, 66, 219, 166, 139, 43, 142, 22, 59, 223, 184, 103, 40, 250, 61, 236, 157, 115, 87, 187, 138, 72, 145, 59, 135, 19, 107],
+		[92, 151, 176, 12, 0, 100, 151, 176, 95, 221, 234, 25, 36, 252, 131, 218, 179, 205, 41, 152, 201, 62, 219, 103, 106, 159, 68, 44, 61, 62, 55, 85],
+		[55, 214, 254, 88, 90, 234, 103, 186, 157, 92, 198, 9, 89, 45, 122, 30, 123, 250, 136, 159, 94, 33, 12, 251, 230, 67, 66, 93, 13, 38, 248, 113],
 	],
 	[
-		[97, 20, 224, 162, 195, 223, 111, 244, 174, 44, 72, 70, 128, 216, 228, 18, 153, 203, 119, 25, 216, 73, 67, 69, 95, 18, 177, 104, 161, 132, 22, 102],
-		[59, 40, 50, 41, 18, 4, 65, 168, 174, 53, 92, 231, 203, 48, 208, 247, 127, 70, 219, 199, 120, 105, 243, 29, 168, 239, 138, 180, 23, 51, 57, 196],
-		[122, 67, 80, 252, 170, 219, 8, 107, 147, 250, 254, 57, 119, 70, 158, 62, 135, 214, 240, 91, 244, 107, 104, 247, 1, 64, 218, 20, 223, 112, 37, 157],
-		[21, 35, 27, 199, 154, 222, 32, 117, 226, 76, 37, 236, 71, 2, 40, 49, 206, 107, 14, 14, 20, 135, 89, 8, 70, 129, 153, 91, 152, 242, 65, 150],
+		[29, 146, 207, 23, 252, 123, 253, 134, 101, 74, 96, 185, 179, 232, 3, 83, 199, 100, 169, 52, 22, 32, 208, 185, 95, 163, 157, 97, 40, 108, 140, 30],
+		[136, 150, 176, 176, 119, 85, 129, 205, 90, 163, 192, 117, 217, 117, 43, 204, 118, 160, 123, 170, 130, 181, 171, 56, 107, 24, 142, 35, 116, 211, 210, 234],
+		[252, 41, 3, 76, 149, 37, 39, 36, 161, 65, 186, 215, 70, 175, 179, 24, 144, 120, 19, 61, 44, 26, 179, 140, 64, 20, 75, 83, 15, 224, 10, 51],
+		[104, 172, 106, 142, 153, 150, 163, 181, 61, 239, 30, 78, 213, 244, 85, 239, 4, 137, 247, 119, 189, 62, 227, 2, 167, 159, 210, 121, 203, 199, 185, 208],
 	],
 	[
-		[147, 61, 0, 130, 1, 111, 155, 152, 222, 148, 244, 89, 59, 221, 205, 99, 154, 168, 101, 48, 171, 68, 162, 220, 185, 43, 113, 73, 27, 167, 111, 49],
-		[135, 151, 151, 69, 105, 81, 149, 152, 193, 45, 15, 14, 79, 23, 132, 80, 31, 87, 80, 221, 189, 187, 126, 82, 196, 126, 111, 22, 7, 24, 176, 205],
-		[81, 55, 4, 153, 144, 80, 200, 156, 1, 57, 102, 57, 35, 151, 133, 65, 103, 217, 84, 145, 176, 118, 45, 196, 160, 71, 46, 249, 12, 45, 104, 93],
-		[197, 107, 180, 226, 19, 96, 233, 142, 172, 45, 33, 5, 97, 254, 248, 82, 70, 35, 142, 215, 222, 180, 43, 200, 116, 126, 19, 212, 217, 165, 78, 114],
+		[248, 14, 182, 176, 195, 238, 96, 136, 4, 97, 97, 98, 55, 1, 242, 112, 185, 218, 112, 68, 198, 255, 28, 250, 19, 218, 139, 221, 134, 213, 235, 198],
+		[68, 105, 216, 194, 17, 172, 131, 65, 55, 195, 32, 139, 146, 83, 160, 62, 125, 115, 130, 29, 118, 160, 199, 97, 14, 171, 26, 193, 207, 218, 140, 39],
+		[42, 180, 84, 215, 136, 107, 78, 75, 235, 8, 210, 169, 35, 5, 45, 94, 252, 176, 106, 238, 119, 240, 212, 240, 247, 111, 62, 120, 173, 45, 151, 72],
+		[118, 33, 16, 65, 90, 8, 91, 78, 1, 172, 219, 59, 244, 210, 26, 120, 119, 88, 72, 144, 3, 51, 0, 247, 49, 199, 95, 238, 68, 37, 67, 25],
 	],
 	[
-		[168, 168, 20, 176, 205, 224, 239, 82, 121, 161, 91, 226, 112, 114, 132, 77, 8, 120, 218, 117, 164, 18, 231, 93, 92, 29, 205, 2, 140, 181, 12, 26],
-		[143, 127, 165, 193, 159, 207, 40, 90, 162, 230, 219, 190, 204, 119, 58, 48, 152, 251, 161, 11, 73, 29, 34, 107, 1, 159, 111, 213, 44, 18, 85, 144],
-		[50, 97, 117, 116, 2, 205, 138, 164, 189, 205, 136, 49, 0, 31, 177, 170, 58, 83, 75, 194, 81, 154, 134, 231, 15, 82, 84, 22, 156, 234, 131, 202],
-		[52, 251, 138, 140, 67, 31, 106, 179, 16, 138, 220, 207, 70, 46, 229, 242, 56, 120, 196, 127, 229, 37, 93, 184, 143, 130, 35, 141, 107, 58, 6, 238],
+		[29, 179, 216, 92, 46, 69, 149, 128, 203, 186, 6, 225, 152, 112, 68, 108, 77, 12, 224, 141, 22, 244, 23, 247, 86, 232, 100, 98, 245, 189, 47, 35],
+		[177, 232, 167, 8, 187, 34, 199, 32, 16, 215, 13, 24, 40, 1, 42, 147, 25, 202, 83, 220, 76, 212, 214, 32, 61, 133, 105, 152, 157, 199, 200, 106],
+		[147, 74, 11, 161, 61, 240, 4, 78, 31, 167, 255, 13, 134, 110, 204, 48, 199, 89, 234, 1, 250, 3, 141, 206, 42, 161, 214, 9, 96, 62, 126, 160],
+		[137, 13, 214, 12, 184, 64, 95, 126, 116, 232, 190, 112, 63, 41, 51, 24, 161, 167, 75, 201, 96, 86, 107, 177, 12, 108, 71, 232, 102, 158, 148, 164],
 	],
 	[
-		[156, 22, 151, 130, 72, 115, 195, 84, 237, 145, 8, 213, 16, 57, 210, 179, 155, 67, 138, 246, 95, 40, 174, 22, 246, 198, 222, 212, 106, 5, 218, 74],
-		[70, 159, 85, 158, 247, 60, 181, 88, 152, 153, 201, 93, 26, 129, 221, 1, 25, 157, 152, 140, 116, 187, 59, 252, 56, 171, 72, 157, 239, 133, 160, 64],
-		[73, 101, 243, 110, 16, 106, 56, 35, 12, 138, 164, 96, 172, 99, 88, 148, 27, 173, 188, 145, 127, 191, 210, 119, 37, 144, 34, 117, 187, 44, 179, 132],
-		[187, 236, 109, 248, 243, 154, 122, 90, 198, 103, 64, 230, 18, 88, 99, 180, 156, 188, 79, 62, 17, 14, 14, 215, 61, 34, 81, 6, 20, 120, 220, 234],
+		[9, 255, 171, 72, 227, 9, 88, 198, 54, 178, 109, 198, 50, 141, 107, 98, 209, 37, 11, 31, 94, 185, 168, 28, 224, 84, 44, 204, 187, 219, 121, 98],
+		[71, 99, 110, 47, 154, 218, 109, 114, 176, 185, 63, 248, 208, 214, 153, 187, 19, 76, 202, 234, 157, 108, 192, 68, 174, 197, 250, 63, 218, 117, 197, 226],
+		[12, 235, 224, 178, 129, 131, 119, 84, 26, 151, 166, 243, 34, 58, 13, 250, 212, 230, 50, 226, 121, 139, 229, 45, 244, 11, 96, 124, 153, 66, 182, 227],
+		[195, 140, 146, 57, 243, 144, 82, 185, 20, 237, 135, 6, 15, 158, 93, 47, 168, 246, 181, 237, 196, 100, 103, 175, 112, 240, 101, 19, 52, 160, 204, 171],
 	],
 	[
-		[91, 117, 57, 189, 35, 28, 121, 176, 31, 30, 130, 149, 197, 174, 32, 196, 104, 118, 56, 29, 136, 178, 137, 49, 177, 88, 177, 114, 41, 21, 237, 220],
-		[57, 159, 150, 115, 14, 253, 225, 87, 208, 133, 226, 148, 174, 130, 10, 154, 50, 215, 130, 44, 224, 10, 5, 122, 243, 132, 175, 32, 71, 241, 18, 109],
-		[202, 189, 135, 65, 123, 225, 168, 115, 36, 74, 175, 251, 158, 156, 194, 115, 201, 195, 115, 241, 78, 122, 62, 25, 121, 236, 113, 142, 199, 212, 202, 219],
-		[254, 253, 19, 146, 115, 79, 225, 47, 61, 233, 225, 41, 81, 4, 178, 215, 181, 87, 61, 7, 191, 143, 244, 231, 86, 66, 78, 228, 67, 146, 154, 186],
+		[45, 85, 100, 219, 116, 174, 110, 212, 220, 147, 69, 246, 131, 2, 153, 20, 73, 134, 19, 178, 182, 13, 6, 89, 135, 145, 87, 51, 209, 206, 13, 152],
+		[78, 190, 89, 64, 23, 42, 76, 69, 2, 151, 96, 111, 109, 173, 97, 139, 79, 136, 220, 167, 30, 119, 244, 121, 211, 190, 174, 98, 70, 50, 57, 94],
+		[53, 156, 188, 130, 159, 108, 82, 210, 171, 156, 133, 156, 116, 148, 142, 157, 22, 227, 136, 72, 67, 169, 197, 176, 153, 9, 192, 239, 97, 85, 181, 99],
+		[161, 138, 146, 141, 159, 245, 90, 35, 224, 205, 253, 253, 246, 233, 42, 141, 1, 198, 80, 1, 181, 109, 165, 192, 67, 19, 227, 162, 169, 253, 87, 168],
 	],
 	[
-		[77, 125, 213, 50, 97, 252, 64, 67, 243, 174, 57, 100, 243, 122, 179, 31, 106, 226, 224, 172, 152, 159, 168, 177, 140, 82, 133, 150, 81, 175, 24, 72],
-		[255, 221, 124, 177, 212, 216, 106, 28, 96, 38, 230, 16, 145, 247, 59, 223, 173, 101, 2, 1, 237, 160, 54, 37, 238, 155, 120, 178, 206, 42, 23, 89],
-		[213, 149, 137, 186, 190, 78, 202, 203, 59, 190, 1, 67, 104, 118, 24, 227, 219, 207, 177, 184, 190, 131, 38, 107, 80, 31, 231, 173, 47, 186, 108, 11],
-		[241, 26, 76, 51, 228, 138, 90, 48, 225, 213, 61, 3, 251, 168, 245, 47, 202, 58, 42, 244, 232, 68, 78, 37, 214, 55, 235, 29, 255, 68, 32, 23],
+		[137, 56, 227, 138, 165, 182, 95, 27, 60, 97, 187, 14, 232, 237, 228, 224, 117, 96, 9, 151, 141, 155, 133, 126, 4, 132, 38, 75, 152, 82, 28, 42],
+		[133, 81, 161, 50, 114, 255, 55, 106, 241, 1, 166, 228, 131, 189, 142, 49, 59, 239, 248, 82, 37, 84, 145, 51, 7, 116, 108, 67, 206, 83, 147, 161],
+		[113, 196, 75, 163, 113, 97, 147, 220, 132, 98, 73, 83, 31, 57, 116, 12, 225, 76, 47, 168, 159, 174, 103, 35, 241, 138, 138, 90, 77, 76, 13, 133],
+		[133, 90, 181, 194, 42, 190, 178, 180, 104, 9, 118, 62, 28, 40, 134, 185, 192, 193, 125, 136, 222, 25, 111, 34, 108, 226, 180, 123, 46, 38, 214, 38],
 	],
 	[
-		[249, 126, 91, 91, 196, 251, 177, 92, 145, 250, 167, 98, 239, 150, 174, 210, 162, 87, 222, 89, 49, 85, 45, 22, 79, 118, 202, 177, 251, 64, 120, 164],
-		[68, 92, 120, 191, 113, 237, 70, 65, 188, 78, 93, 162, 222, 80, 251, 40, 231, 150, 178, 82, 215, 49, 130, 33, 107, 101, 217, 134, 187, 153, 52, 200],
-		[183, 102, 83, 87, 139, 135, 213, 5, 194, 124, 11, 169, 253, 242, 235, 93, 157, 54, 11, 140, 36, 48, 225, 56, 208, 219, 149, 220, 175, 36, 61, 176],
-		[48, 245, 77, 58, 199, 93, 28, 244, 26, 139, 140, 201, 146, 202, 126, 191, 206, 173, 68, 58, 65, 24, 230, 254, 67, 142, 145, 174, 74, 55, 150, 28],
+		[17, 87, 42, 160, 178, 203, 105, 11, 42, 118, 64, 122, 2, 229, 232, 150, 72, 44, 243, 7, 108, 155, 87, 215, 49, 46, 25, 163, 71, 73, 225, 108],
+		[140, 182, 92, 180, 102, 174, 223, 105, 57, 255, 164, 180, 118, 183, 47, 171, 197, 153, 91, 101, 228, 56, 88, 63, 52, 7, 5, 144, 46, 15, 199, 147],
+		[247, 253, 212, 213, 243, 144, 119, 44, 87, 142, 136, 196, 166, 175, 242, 172, 237, 75, 169, 16, 78, 174, 40, 157, 243, 135, 93, 42, 188, 78, 5, 88],
+		[108, 176, 199, 88, 82, 86, 191, 98, 197, 154, 12, 197, 213, 38, 161, 226, 22, 155, 201, 129, 8, 133, 79, 145, 175, 209, 55, 92, 122, 233, 18, 34],
 	],
 	[
-		[156, 239, 99, 59, 31, 161, 109, 99, 126, 117, 70, 178, 55, 65, 187, 178, 33, 81, 42, 70, 188, 8, 6, 149, 232, 73, 189, 204, 16, 13, 107, 230],
-		[119, 168, 218, 223, 175, 243, 202, 237, 17, 131, 119, 243, 196, 15, 1, 119, 55, 244, 204, 243, 209, 135, 31, 42, 56, 93, 178, 236, 52, 114, 94, 157],
-		[81, 156, 65, 168, 204, 184, 48, 56, 211, 80, 110, 40, 90, 87, 44, 241, 50, 242, 52, 94, 221, 101, 49, 9, 196, 201, 184, 228, 155, 53, 154, 152],
-		[162, 13, 92, 198, 19, 128, 55, 178, 237, 182, 194, 111, 128, 150, 118, 233, 216, 129, 198, 75, 156, 123, 218, 211, 176, 251, 151, 43, 150, 108, 184, 223],
+		[106, 127, 2, 149, 82, 175, 94, 149, 82, 159, 95, 236, 197, 218, 23, 144, 129, 102, 224, 1, 228, 67, 232, 213, 104, 53, 161, 223, 194, 124, 193, 7],
+		[64, 194, 247, 97, 68, 5, 91, 51, 238, 185, 231, 163, 156, 85, 135, 252, 39, 208, 106, 27, 116, 137, 112, 81, 147, 252, 27, 33, 228, 35, 175, 114],
+		[117, 7, 183, 2, 27, 3, 89, 74, 79, 10, 125, 1, 163, 197, 60, 63, 88, 6, 143, 82, 10, 186, 204, 230, 198, 101, 24, 113, 76, 52, 219, 155],
+		[166, 38, 31, 118, 43, 144, 224, 84, 129, 202, 91, 76, 245, 82, 106, 141, 158, 69, 105, 159, 66, 62, 252, 26, 170, 208, 240, 3, 167, 238, 36, 8],
 	],
 	[
-		[99, 144, 251, 241, 239, 187, 127, 132, 84, 15, 41, 15, 139, 87, 100, 223, 247, 9, 40, 130, 182, 218, 185, 220, 105, 74, 225, 21, 122, 22, 63, 153],
-		[78, 241, 84, 164, 234, 73, 180, 215, 245, 12, 35, 7, 15, 159, 230, 22, 235, 217, 69, 17, 236, 218, 203, 74, 5, 35, 124, 206, 136, 200, 61, 113],
-		[183, 238, 177, 223, 4, 28, 65, 8, 191, 195, 109, 178, 25, 51, 9, 95, 218, 100, 165, 9, 175, 156, 12, 199, 230, 93, 133, 156, 141, 80, 100, 58],
-		[231, 179, 239, 211, 150, 223, 154, 159, 51, 95, 112, 187, 215, 234, 5, 172, 23, 131, 83, 45, 128, 123, 116, 32, 22, 135, 178, 224, 244, 134, 9, 28],
+		[166, 155, 70, 245, 254, 202, 123, 13, 244, 116, 114, 134, 178, 162, 177, 237, 182, 88, 125, 66, 17, 44, 134, 46, 12, 115, 237, 43, 86, 94, 119, 234],
+		[194, 151, 13, 243, 123, 37, 219, 219, 55, 127, 196, 76, 137, 15, 215, 132, 68, 20, 83, 6, 22, 153, 58, 181, 152, 183, 135, 60, 195, 247, 248, 17],
+		[151, 208, 2, 44, 177, 90, 97, 237, 180, 112, 119, 102, 241, 101, 147, 43, 201, 29, 86, 106, 104, 124, 100, 183, 202, 218, 79, 79, 165, 151, 109, 172],
+		[230, 57, 212, 156, 140, 217, 220, 166, 9, 35, 117, 187, 50, 32, 116, 91, 27, 0, 40, 58, 50, 152, 117, 8, 58, 146, 125, 242, 118, 159, 254, 225],
 	],
 	[
-		[166, 93, 186, 170, 141, 226, 98, 122, 228, 251, 215, 108, 75, 96, 6, 147, 209, 151, 135, 114, 108, 226, 63, 111, 104, 135, 201, 10, 17, 166, 2, 50],
-		[71, 120, 108, 56, 18, 86, 208, 0, 164, 59, 204, 83, 128, 98, 43, 86, 182, 216, 190, 84, 13, 47, 47, 173, 215, 163, 151, 29, 222, 241, 83, 229],
-		[59, 168, 150, 215, 139, 202, 138, 116, 14, 221, 236, 143, 44, 152, 118, 18, 101, 192, 167, 43, 110, 214, 65, 50, 209, 62, 208, 240, 1, 95, 77, 110],
-		[214, 112, 198, 149, 247, 209, 40, 44, 158, 66, 151, 116, 254, 225, 155, 97, 218, 237, 123, 105, 2, 20, 77, 26, 226, 252, 176, 214, 194, 42, 153, 86],
+		[209, 67, 27, 254, 141, 239, 64, 122, 226, 53, 29, 84, 247, 150, 167, 19, 77, 67, 31, 30, 60, 162, 159, 200, 219, 55, 133, 15, 97, 104, 166, 25],
+		[52, 58, 59, 192, 109, 119, 69, 64, 108, 208, 59, 250, 160, 120, 144, 124, 198, 30, 115, 93, 208, 31, 157, 116, 56, 241, 16, 29, 17, 164, 119, 207],
+		[53, 208, 69, 143, 152, 135, 221, 193, 239, 202, 151, 101, 12, 89, 254, 198, 159, 113, 83, 204, 212, 15, 205, 116, 112, 4, 14, 108, 55, 91, 186, 1],
+		[231, 221, 47, 215, 190, 99, 129, 115, 150, 204, 37, 83, 49, 195, 211, 86, 143, 191, 114, 206, 189, 67, 100, 198, 2, 126, 212, 120, 49, 47, 105, 175],
 	],
 	[
-		[245, 198, 207, 214, 186, 94, 215, 0, 74, 252, 178, 56, 20, 153, 121, 35, 16, 128, 21, 40, 0, 227, 233, 215, 11, 188, 236, 222, 156, 49, 161, 46],
-		[42, 184, 107, 220, 87, 101, 39, 6, 62, 244, 86, 211, 82, 128, 247, 76, 178, 103, 145, 14, 67, 41, 88, 91, 24, 185, 98, 185, 219, 105, 17, 81],
-		[69, 99, 34, 93, 43, 89, 35, 112, 158, 198, 157, 14, 129, 8, 2, 233, 175, 216, 9, 81, 161, 135, 14, 138, 4, 109, 66, 59, 27, 225, 81, 14],
-		[51, 8, 18, 165, 38, 108, 152, 51, 123, 69, 59, 40, 116, 178, 83, 136, 53, 48, 31, 169, 53, 251, 125, 24, 107, 95, 48, 6, 115, 218, 142, 227],
+		[216, 82, 171, 100, 78, 253, 158, 54, 98, 253, 112, 235, 140, 139, 82, 236, 85, 93, 74, 47, 38, 187, 206, 184, 80, 51, 206, 24, 101, 183, 92, 40],
+		[189, 82, 68, 102, 114, 86, 189, 51, 0, 253, 14, 134, 190, 192, 9, 221, 101, 39, 25, 128, 154, 147, 205, 6, 155, 129, 8, 202, 211, 21, 45, 207],
+		[127, 122, 100, 108, 5, 251, 41, 97, 143, 165, 251, 31, 234, 169, 125, 55, 149, 77, 161, 181, 199, 221, 136, 124, 168, 205, 94, 196, 136, 38, 215, 48],
+		[156, 197, 152, 80, 27, 119, 68, 144, 209, 143, 164, 250, 39, 132, 170, 230, 247, 184, 176, 12, 219, 104, 192, 67, 174, 105, 71, 218, 207, 211, 89, 77],
 	],
 	[
-		[73, 5, 204, 160, 51, 183, 18, 141, 205, 97, 78, 12, 224, 134, 142, 108, 141, 227, 13, 104, 178, 4, 196, 146, 25, 196, 25, 216, 163, 142, 190, 72],
-		[188, 253, 199, 40, 131, 181, 252, 215, 197, 178, 71, 155, 197, 226, 251, 147, 206, 100, 171, 243, 61, 92, 252, 185, 22, 242, 178, 148, 1, 77, 3, 86],
-		[225, 148, 0, 176, 199, 111, 37, 199, 4, 136, 34, 21, 96, 96, 141, 178, 4, 41, 39, 199, 134, 32, 191, 170, 184, 239, 141, 84, 229, 221, 154, 47],
-		[96, 122, 209, 128, 202, 152, 14, 88, 230, 214, 55, 210, 161, 220, 2, 156, 71, 50, 98, 103, 39, 28, 249, 158, 221, 105, 33, 57, 252, 25, 71, 54],
+		[69, 113, 208, 208, 68, 253, 205, 42, 47, 50, 239, 0, 252, 195, 198, 62, 101, 13, 43, 208, 218, 152, 214, 138, 237, 122, 252, 185, 202, 33, 112, 202],
+		[52, 14, 213, 243, 137, 255, 38, 164, 15, 109, 158, 227, 59, 87, 57, 37, 116, 28, 252, 84, 171, 73, 212, 156, 251, 246, 122, 235, 146, 50, 85, 166],
+		[57, 191, 23, 236, 73, 154, 65, 38, 178, 35, 37, 147, 190, 162, 10, 161, 37, 51, 163, 141, 109, 142, 84, 27, 75, 159, 250, 90, 8, 28, 177, 177],
+		[241, 225, 61, 8, 95, 144, 68, 171, 127, 59, 64, 84, 230, 185, 102, 233, 162, 242, 103, 100, 99, 230, 149, 28, 89, 219, 225, 129, 139, 251, 232, 57],
 	],
 	[
-		[6, 102, 110, 89, 136, 117, 96, 209, 78, 39, 197, 202, 134, 170, 246, 150, 98, 3, 151, 169, 92, 207, 48, 223, 130, 184, 9, 235, 248, 194, 175, 238],
-		[180, 150, 83, 0, 244, 17, 50, 3, 141, 180, 232, 244, 52, 79, 133, 158, 121, 49, 130, 136, 121, 123, 19, 242, 60, 42, 100, 182, 94, 40, 173, 91],
-		[162, 66, 6, 90, 216, 143, 182, 6, 14, 47, 240, 206, 119, 217, 180, 160, 105, 149, 161, 213, 173, 52, 235, 193, 225, 170, 188, 125, 232, 149, 20, 171],
-		[163, 119, 242, 52, 49, 228, 162, 31, 67, 210, 114, 214, 228, 161, 162, 148, 51, 100, 172, 4, 208, 3, 216, 143, 43, 148, 176, 32, 108, 4, 39, 178],
+		[136, 63, 4, 171, 162, 70, 121, 74, 45, 185, 114, 134, 206, 143, 122, 250, 57, 116, 63, 86, 36, 220, 106, 33, 150, 80, 5, 167, 218, 134, 170, 10],
+		[232, 196, 75, 128, 160, 122, 167, 15, 48, 80, 57, 215, 241, 215, 117, 134, 92, 146, 162, 23, 30, 70, 88, 103, 140, 55, 149, 184, 239, 168, 139, 85],
+		[155, 93, 158, 170, 22, 145, 237, 168, 55, 42, 81, 120, 114, 54, 2, 65, 186, 107, 87, 22, 252, 144, 207, 171, 91, 187, 35, 81, 151, 245, 43, 223],
+		[254, 85, 164, 177, 242, 200, 152, 196, 243, 192, 153, 233, 15, 254, 178, 186, 73, 103, 64, 178, 211, 21, 99, 26, 26, 34, 228, 128, 183, 100, 247, 31],
 	],
 	[
-		[9, 19, 175, 106, 99, 149, 139, 37, 60, 78, 173, 2, 87, 8, 184, 215, 205, 97, 47, 72, 222, 139, 170, 70, 103, 225, 123, 42, 205, 224, 72, 21],
-		[75, 211, 36, 84, 114, 19, 100, 212, 17, 103, 29, 70, 89, 3, 5, 226, 180, 52, 5, 250, 190, 27, 231, 100, 45, 41, 163, 151, 208, 97, 31, 236],
-		[204, 201, 11, 154, 121, 232, 176, 21, 1, 92, 177, 17, 234, 27, 211, 82, 7, 204, 147, 27, 54, 127, 246, 171, 235, 193, 22, 160, 224, 30, 11, 181],
-		[198, 126, 34, 99, 201, 21, 48, 28, 201, 169, 215, 182, 66, 122, 86, 17, 224, 3, 51, 9, 79, 143, 44, 28, 141, 248, 196, 247, 108, 217, 82, 14],
+		[26, 165, 57, 220, 15, 112, 50, 15, 210, 7, 11, 77, 64, 38, 233, 211, 190, 1, 205, 243, 218, 212, 1, 16, 156, 11, 190, 173, 196, 100, 168, 158],
+		[116, 93, 80, 31, 90, 144, 149, 79, 106, 241, 110, 115, 184, 156, 32, 155, 76, 115, 139, 212, 56, 198, 61, 61, 94, 195, 160, 85, 60, 94, 217, 45],
+		[68, 96, 38, 144, 231, 141, 90, 182, 159, 10, 49, 211, 19, 196, 98, 232, 236, 76, 200, 92, 233, 19, 27, 82, 12, 70, 162, 28, 87, 6, 163, 156],
+		[140, 192, 114, 33, 95, 153, 64, 184, 165, 55, 167, 196, 217, 116, 199, 30, 155, 86, 118, 102, 37, 113, 209, 125, 32, 115, 145, 156, 149, 180, 161, 216],
 	],
 	[
-		[177, 233, 52, 248, 98, 62, 174, 240, 169, 4, 155, 255, 94, 90, 42, 1, 50, 67, 167, 160, 184, 99, 148, 194, 243, 220, 155, 56, 92, 193, 164, 227],
-		[75, 242, 45, 42, 128, 194, 94, 104, 117, 196, 136, 96, 213, 188, 56, 214, 77, 178, 4, 179, 140, 154, 102, 244, 29, 251, 32, 8, 166, 73, 56, 60],
-		[67, 252, 114, 210, 224, 127, 69, 72, 4, 80, 70, 120, 106, 31, 178, 253, 111, 56, 65, 11, 202, 94, 143, 110, 118, 44, 23, 83, 217, 138, 48, 237],
-		[207, 219, 154, 254, 207, 181, 40, 181, 171, 183, 146, 76, 135, 222, 36, 126, 239, 25, 113, 92, 12, 51, 76, 204, 170, 141, 24, 19, 78, 126, 141, 146],
+		[61, 7, 11, 187, 147, 40, 86, 73, 87, 158, 181, 55, 110, 208, 36, 22, 116, 150, 137, 181, 36, 249, 62, 12, 138, 102, 60, 176, 118, 109, 98, 93],
+		[32, 147, 67, 64, 162, 14, 37, 18, 238, 29, 12, 22, 132, 150, 146, 128, 102, 122, 10, 65, 212, 242, 166, 196, 55, 184, 188, 105, 34, 31, 72, 102],
+		[26, 222, 179, 191, 163, 94, 25, 133, 114, 90, 160, 139, 12, 49, 45, 99, 127, 252, 36, 124, 233, 217, 52, 254, 14, 1, 213, 121, 16, 81, 251, 185],
+		[216, 72, 82, 109, 95, 37, 144, 182, 148, 222, 140, 208, 29, 39, 254, 147, 171, 114, 35, 88, 245, 242, 220, 152, 108, 169, 236, 24, 171, 87, 194, 215],
 	],
 	[
-		[43, 145, 238, 128, 37, 65, 156, 24, 182, 4, 196, 247, 78, 27, 183, 130, 155, 110, 230, 24, 141, 100, 10, 115, 145, 87, 202, 240, 153, 78, 252, 159],
-		[116, 226, 15, 13, 67, 57, 237, 80, 203, 124, 115, 45, 184, 112, 113, 242, 132, 213, 155, 105, 1, 92, 50, 12, 22, 185, 254, 235, 201, 234, 169, 183],
-		[27, 203, 112, 70, 217, 44, 251, 233, 121, 233, 215, 198, 240, 130, 166, 216, 179, 134, 129, 237, 54, 148, 103, 31, 203, 241, 49, 120, 188, 165, 51, 206],
-		[134, 29, 140, 213, 89, 166, 70, 69, 244, 27, 78, 162, 87, 70, 96, 215, 155, 176, 90, 17, 181, 147, 11, 52, 243, 225, 64, 17, 61, 11, 28, 103],
+		[190, 228, 186, 240, 19, 219, 103, 155, 69, 27, 32, 115, 136, 239, 154, 119, 225, 193, 179, 206, 197, 191, 167, 254, 55, 98, 8, 58, 164, 145, 49, 55],
+		[61, 93, 29, 129, 47, 13, 12, 31, 165, 216, 178, 228, 243, 77, 73, 217, 184, 58, 102, 82, 160, 137, 187, 112, 39, 161, 181, 35, 128, 120, 69, 64],
+		[92, 173, 36, 139, 8, 220, 189, 184, 23, 131, 7, 199, 160, 86, 101, 140, 5, 94, 231, 227, 151, 143, 229, 53, 4, 244, 45, 105, 227, 241, 97, 106],
+		[69, 230, 92, 1, 37, 164, 91, 88, 185, 122, 207, 198, 147, 33, 26, 178, 197, 195, 67, 230, 213, 191, 242, 150, 111, 226, 129, 254, 221, 156, 72, 164],
 	],
 	[
-		[247, 48, 176, 249, 247, 153, 184, 115, 194, 31, 169, 92, 158, 45, 181, 200, 94, 224, 131, 49, 187, 222, 5, 42, 94, 225, 91, 212, 167, 54, 180, 219],
-		[15, 179, 82, 169, 187, 235, 91, 246, 97, 77, 150, 236, 108, 62, 230, 187, 248, 13, 131, 169, 47, 13, 178, 182, 55, 74, 97, 37, 195, 217, 255, 37],
-		[223, 155, 192, 74, 178, 30, 6, 244, 253, 156, 142, 12, 37, 86, 183, 246, 121, 155, 141, 113, 169, 163, 33, 185, 231, 154, 27, 186, 207, 24, 18, 78],
-		[20, 211, 254, 79, 89, 62, 200, 212, 41, 102, 68, 212, 53, 47, 109, 185, 240, 87, 175, 187, 52, 250, 210, 6, 244, 153, 85, 56, 46, 174, 79, 161],
+		[42, 63, 181, 136, 88, 193, 226, 36, 187, 34, 8, 239, 189, 84, 26, 246, 60, 49, 167, 124, 82, 120, 3, 4, 195, 68, 63, 118, 204, 69, 146, 190],
+		[251, 21, 26, 68, 4, 44, 187, 43, 248, 65, 254, 225, 111, 101, 2, 138, 155, 94, 144, 44, 55, 103, 25, 223, 8, 54, 119, 109, 119, 72, 133, 88],
+		[91, 175, 202, 117, 179, 200, 221, 118, 158, 55, 241, 219, 125, 219, 186, 136, 21, 153, 0, 230, 115, 91, 173, 201, 199, 146, 88, 188, 207, 153, 34, 235],
+		[22, 217, 171, 6, 255, 98, 175, 220, 247, 120, 47, 25, 193, 1, 224, 228, 17, 229, 23, 90, 27, 174, 68, 250, 236, 203, 99, 235, 69, 44, 225, 165],
 	],
 	[
-		[176, 50, 21, 14, 172, 7, 83, 169, 129, 222, 40, 51, 91, 212, 213, 61, 221, 244, 133, 33, 192, 200, 39, 223, 170, 107, 231, 135, 203, 226, 72, 75],
-		[71, 190, 94, 124, 16, 14, 237, 74, 145, 174, 192, 135, 117, 39, 55, 41, 234, 174, 208, 216, 193, 55, 195, 38, 166, 130, 24, 201, 27, 193, 78, 84],
-		[136, 225, 138, 131, 17, 172, 103, 43, 73, 129, 180, 202, 197, 187, 50, 199, 32, 128, 161, 92, 135, 240, 49, 64, 196, 160, 205, 89, 194, 245, 222, 22],
-		[33, 27, 3, 160, 180, 14, 248, 239, 180, 170, 236, 100, 3, 110, 99, 135, 98, 155, 94, 46, 171, 109, 245, 230, 135, 207, 40, 183, 101, 227, 114, 168],
+		[111, 218, 190, 118, 242, 178, 164, 182, 75, 141, 14, 51, 34, 72, 63, 238, 244, 181, 232, 235, 160, 34, 189, 66, 175, 11, 60, 182, 5, 111, 89, 13],
+		[79, 93, 190, 140, 58, 31, 138, 38, 116, 208, 15, 209, 22, 246, 205, 12, 104, 203, 5, 180, 215, 98, 160, 38, 52, 234, 55, 115, 175, 29, 158, 197],
+		[144, 155, 83, 146, 19, 74, 194, 36, 247, 204, 68, 49, 30, 214, 181, 245, 102, 106, 34, 171, 200, 56, 100, 32, 73, 158, 246, 171, 104, 73, 135, 196],
+		[184, 212, 244, 58, 224, 110, 75, 176, 5, 161, 122, 161, 105, 162, 35, 196, 90, 246, 112, 57, 154, 165, 110, 84, 194, 130, 113, 224, 30, 49, 61, 135],
 	],
 	[
-		[21, 27, 86, 66, 9, 203, 247, 0, 17, 122, 105, 179, 163, 94, 126, 245, 13, 196, 16, 201, 224, 124, 243, 151, 94, 49, 129, 199, 227, 243, 13, 229],
-		[58, 109, 170, 247, 221, 41, 45, 131, 133, 205, 239, 50, 51, 224, 190, 246, 172, 87, 145, 217, 27, 99, 50, 108, 62, 60, 51, 189, 177, 142, 216, 141],
-		[12, 252, 252, 108, 78, 242, 217, 17, 94, 35, 181, 207, 152, 99, 222, 219, 228, 214, 255, 81, 166, 232, 148, 75, 231, 177, 236, 174, 50, 160, 152, 96],
-		[242, 140, 48, 6, 241, 185, 55, 81, 248, 227, 25, 162, 173, 59, 108, 100, 108, 52, 102, 41, 68, 75, 208, 126, 131, 151, 138, 160, 216, 29, 5, 27],
+		[21, 80, 168, 247, 183, 35, 69, 105, 132, 205, 227, 202, 232, 243, 121, 232, 26, 234, 240, 225, 98, 189, 4, 232, 49, 203, 34, 204, 97, 230, 95, 102],
+		[64, 214, 10, 181, 96, 214, 126, 42, 118, 38, 156, 242, 36, 124, 23, 206, 124, 205, 11, 226, 166, 233, 44, 150, 90, 127, 16, 55, 8, 26, 153, 61],
+		[154, 39, 38, 224, 54, 210, 13, 3, 176, 243, 81, 60, 67, 227, 27, 49, 74, 236, 179, 181, 65, 246, 114, 246, 88, 220, 183, 193, 173, 152, 145, 38],
+		[48, 86, 173, 81, 138, 138, 163, 227, 244, 128, 127, 198, 215, 245, 2, 227, 186, 178, 54, 230, 254, 189, 94, 249, 53, 227, 124, 107, 226, 127, 136, 133],
 	],
 	[
-		[66, 235, 68, 182, 75, 126, 172, 134, 145, 9, 94, 229, 165, 36, 161, 202, 191, 255, 167, 34, 202, 32, 197, 37, 137, 31, 76, 213, 155, 164, 50, 63],
-		[179, 10, 7, 113, 143, 125, 76, 72, 214, 4, 210, 64, 138, 65, 39, 199, 69, 200, 152, 64, 29, 75, 230, 198, 126, 85, 94, 80, 167, 156, 89, 146],
-		[207, 253, 164, 237, 223, 224, 130, 118, 254, 217, 55, 74, 189, 14, 228, 103, 22, 66, 95, 151, 217, 158, 213, 77, 126, 12, 202, 204, 235, 187, 182, 153],
-		[37, 167, 181, 19, 137, 119, 121, 217, 207, 136, 163, 53, 188, 34, 100, 126, 188, 36, 30, 52, 13, 252, 57, 55, 113, 45, 202, 208, 50, 241, 142, 156],
+		[5, 145, 2, 149, 17, 143, 232, 174, 158, 21, 162, 186, 111, 51, 217, 189, 108, 134, 25, 37, 89, 217, 131, 26, 131, 197, 208, 223, 251, 215, 18, 160],
+		[218, 249, 151, 201, 218, 183, 37, 197, 185, 171, 97, 237, 224, 68, 42, 237, 105, 39, 213, 141, 55, 91, 47, 230, 233, 216, 9, 126, 112, 196, 141, 69],
+		[38, 63, 141, 58, 217, 61, 217, 34, 61, 168, 139, 185, 121, 42, 222, 22, 164, 152, 80, 146, 27, 42, 130, 152, 226, 126, 54, 56, 3, 152, 67, 185],
+		[142, 73, 97, 158, 189, 78, 16, 178, 247, 108, 240, 157, 25, 19, 11, 161, 236, 103, 195, 195, 159, 172, 90, 195, 7, 166, 244, 209, 47, 220, 163, 220],
 	],
 	[
-		[225, 153, 182, 29, 175, 157, 226, 167, 135, 87, 58, 171, 249, 23, 89, 120, 175, 215, 162, 216, 193, 210, 144, 52, 54, 1, 170, 4, 26, 180, 230, 205],
-		[177, 166, 201, 53, 196, 226, 127, 117, 196, 178, 173, 184, 68, 158, 37, 213, 82, 88, 61, 104, 45, 212, 170, 158, 87, 190, 29, 217, 99, 58, 188, 102],
-		[11, 27, 102, 90, 49, 181, 209, 125, 51, 28, 215, 207, 82, 192, 164, 238, 137, 99, 210, 185, 171, 63, 116, 126, 147, 28, 241, 102, 235, 87, 3, 165],
-		[231, 93, 18, 101, 236, 204, 221, 57, 103, 30, 104, 29, 128, 93, 38, 76, 183, 189, 206, 95, 79, 166, 16, 47, 39, 213, 138, 178, 149, 25, 22, 33],
+		[111, 195, 129, 216, 229, 237, 253, 135, 232, 59, 138, 253, 119, 70, 19, 49, 242, 207, 128, 15, 204, 210, 126, 158, 119, 98, 193, 126, 194, 41, 149, 12],
+		[197, 77, 93, 95, 7, 212, 154, 100, 68, 76, 22, 167, 31, 253, 51, 218, 77, 176, 251, 126, 108, 39, 161, 235, 38, 73, 105, 174, 13, 85, 91, 105],
+		[198, 191, 58, 48, 83, 74, 74, 216, 126, 219, 14, 21, 209, 134, 41, 250, 38, 153, 193, 253, 254, 114, 26, 181, 158, 58, 225, 2, 49, 185, 18, 202],
+		[200, 43, 122, 208, 59, 240, 105, 37, 209, 91, 223, 128, 252, 205, 126, 167, 217, 87, 127, 104, 65, 24, 134, 47, 223, 165, 41, 171, 122, 187, 203, 95],
 	],
 	[
-		[175, 54, 0, 181, 195, 203, 62, 52, 139, 16, 168, 237, 191, 127, 255, 130, 191, 222, 96, 29, 8, 220, 21, 40, 79, 105, 175, 209, 159, 129, 209, 191],
-		[30, 11, 10, 11, 3, 181, 165, 137, 185, 135, 4, 83, 96, 192, 43, 24, 51, 94, 43, 151, 53, 217, 185, 228, 173, 69, 35, 14, 229, 102, 122, 96],
-		[218, 103, 231, 177, 194, 103, 103, 113, 144, 152, 20, 46, 174, 129, 5, 185, 103, 162, 117, 224, 47, 243, 215, 248, 247, 167, 62, 193, 14, 198, 20, 200],
-		[44, 135, 209, 202, 226, 43, 34, 79, 63, 96, 183, 174, 165, 161, 244, 185, 180, 0, 135, 129, 84, 56, 7, 81, 28, 161, 129, 229, 8, 70, 10, 149],
+		[233, 122, 149, 225, 112, 138, 152, 8, 102, 204, 195, 237, 132, 147, 207, 153, 205, 208, 222, 79, 190, 39, 16, 170, 220, 179, 218, 121, 81, 48, 4, 80],
+		[224, 9, 75, 138, 31, 170, 26, 218, 117, 242, 252, 61, 110, 106, 101, 219, 163, 42, 9, 74, 97, 246, 121, 221, 45, 144, 23, 142, 184, 0, 223, 77],
+		[242, 22, 178, 212, 184, 172, 147, 75, 199, 154, 38, 178, 42, 42, 47, 115, 176, 81, 129, 140, 174, 67, 206, 49, 49, 106, 10, 5, 191, 120, 242, 20],
+		[243, 205, 21, 66, 175, 192, 87, 68, 71, 111, 242, 20, 49, 10, 212, 209, 219, 173, 48, 164, 98, 125, 71, 178, 196, 78, 203, 48, 103, 162, 125, 30],
 	],
 	[
-		[186, 227, 156, 176, 7, 192, 142, 134, 23, 72, 139, 229, 172, 81, 34, 212, 171, 158, 3, 107, 18, 65, 192, 31, 178, 227, 171, 179, 232, 126, 71, 8],
-		[117, 46, 252, 140, 198, 82, 51, 96, 3, 245, 255, 114, 104, 208, 26, 205, 87, 24, 176, 81, 161, 42, 191, 208, 45, 109, 61, 2, 39, 228, 144, 184],
-		[209, 31, 69, 17, 143, 253, 211, 148, 140, 93, 108, 41, 244, 16, 134, 168, 243, 11, 255, 192, 131, 135, 18, 186, 178, 20, 32, 145, 95, 8, 98, 171],
-		[212, 59, 223, 40, 73, 29, 116, 200, 228, 160, 136, 153, 217, 238, 112, 143, 77, 191, 109, 54, 170, 142, 185, 189, 226, 1, 126, 241, 152, 99, 228, 102],
+		[157, 155, 152, 224, 59, 60, 161, 152, 32, 203, 103, 162, 159, 162, 117, 172, 3, 101, 114, 167, 234, 157, 87, 115, 192, 158, 183, 255, 242, 119, 107, 154],
+		[55, 158, 42, 248, 135, 226, 21, 23, 20, 116, 184, 96, 105, 200, 57, 37, 181, 186, 226, 35, 108, 192, 81, 108, 90, 229, 84, 37, 100, 80, 9, 111],
+		[47, 3, 110, 198, 75, 103, 205, 78, 101, 75, 17, 207, 103, 209, 246, 125, 170, 47, 148, 7, 206, 217, 226, 33, 52, 148, 165, 113, 255, 52, 83, 96],
+		[157, 59, 61, 69, 172, 44, 114, 226, 152, 102, 199, 136, 164, 39, 188, 116, 164, 217, 178, 43, 199, 217, 35, 116, 85, 86, 76, 24, 255, 94, 71, 224],
 	],
 	[
-		[1, 125, 232, 140, 162, 138, 169, 66, 129, 62, 103, 215, 213, 5, 228, 64, 86, 255, 233, 89, 170, 137, 119, 31, 74, 12, 10, 194, 3, 185, 158, 227],
-		[12, 115, 62, 90, 100, 241, 39, 84, 83, 163, 148, 111, 92, 228, 43, 227, 34, 26, 95, 2, 111, 237, 208, 79, 234, 217, 246, 145, 5, 50, 88, 242],
-		[200, 167, 75, 205, 36, 207, 105, 222, 139, 167, 143, 163, 131, 30, 6, 108, 117, 154, 77, 119, 133, 55, 122, 52, 123, 65, 113, 254, 157, 170, 8, 143],
-		[47, 58, 36, 255, 210, 170, 161, 45, 99, 115, 208, 235, 135, 185, 133, 136, 108, 158, 239, 0, 123, 24, 119, 198, 7, 187, 239, 16, 112, 174, 170, 24],
+		[168, 18, 29, 211, 11, 254, 212, 118, 7, 212, 164, 239, 231, 145, 69, 223, 48, 237, 178, 38, 21, 142, 37, 110, 42, 124, 253, 35, 21, 170, 219, 187],
+		[197, 18, 118, 55, 211, 84, 127, 96, 66, 70, 49, 1, 99, 166, 252, 251, 1, 134, 255, 151, 253, 109, 96, 9, 4, 109, 163, 110, 63, 222, 234, 225],
+		[99, 246, 157, 189, 109, 243, 135, 71, 101, 163, 216, 42, 64, 61, 47, 98, 253, 75, 166, 76, 76, 6, 161, 94, 251, 136, 173, 81, 108, 64, 219, 61],
+		[242, 165, 238, 78, 71, 162, 55, 231, 54, 109, 84, 34, 48, 146, 233, 64, 90, 33, 182, 194, 35, 48, 176, 90, 61, 52, 123, 77, 250, 131, 84, 32],
 	],
 	[
-		[126, 6, 68, 125, 151, 98, 231, 250, 144, 162, 48, 158, 186, 166, 153, 217, 254, 218, 133, 17, 236, 85, 188, 50, 1, 105, 203, 94, 186, 55, 47, 235],
-		[57, 48, 7, 30, 5, 223, 197, 131, 18, 118, 243, 131, 190, 55, 162, 76, 218, 191, 232, 198, 88, 185, 42, 185, 37, 172, 38, 77, 135, 254, 189, 173],
-		[224, 61, 16, 27, 98, 13, 90, 238, 127, 9, 191, 166, 184, 56, 82, 142, 120, 36, 178, 133, 67, 16, 139, 23, 29, 181, 91, 82, 210, 231, 43, 185],
-		[112, 241, 9, 150, 86, 183, 231, 131, 7, 121, 213, 7, 23, 133, 109, 230, 231, 96, 127, 165, 227, 120, 136, 62, 42, 95, 169, 230, 172, 223, 14, 14],
+		[143, 150, 118, 20, 251, 124, 102, 119, 122, 225, 70, 249, 91, 178, 11, 109, 119, 216, 204, 215, 255, 138, 168, 45, 143, 247, 42, 241, 58, 182, 229, 38],
+		[33, 112, 253, 229, 251, 122, 47, 105, 156, 160, 27, 173, 133, 184, 82, 178, 175, 87, 179, 53, 85, 168, 182, 50, 186, 250, 169, 209, 183, 152, 168, 159],
+		[15, 66, 143, 241, 87, 135, 172, 5, 173, 218, 66, 19, 146, 187, 36, 65, 14, 196, 236, 142, 141, 102, 166, 184, 226, 76, 1, 130, 94, 154, 89, 178],
+		[251, 78, 42, 71, 131, 205, 175, 21, 85, 75, 66, 115, 47, 173, 139, 37, 62, 188, 5, 43, 50, 188, 240, 240, 159, 120, 163, 180, 64, 122, 20, 144],
 	],
 	[
-		[159, 17, 96, 59, 227, 177, 21, 135, 138, 57, 24, 106, 2, 159, 195, 88, 214, 124, 15, 178, 25, 228, 26, 2, 223, 155, 178, 40, 86, 133, 213, 195],
-		[24, 181, 83, 219, 182, 38, 125, 117, 154, 158, 36, 102, 104, 86, 169, 28, 234, 138, 57, 123, 221, 59, 250, 99, 242, 208, 162, 40, 176, 231, 57, 213],
-		[58, 204, 177, 244, 115, 139, 61, 39, 207, 60, 222, 68, 174, 9, 223, 72, 31, 5, 122, 120, 8, 15, 71, 100, 34, 38, 130, 177, 179, 48, 34, 104],
-		[20, 59, 59, 152, 23, 31, 24, 148, 134, 191, 104, 219, 211, 113, 96, 164, 187, 46, 255, 217, 15, 113, 127, 18, 61, 8, 37, 16, 83, 53, 158, 243],
+		[19, 182, 235, 97, 41, 25, 81, 112, 233, 202, 245, 58, 130, 180, 19, 51, 8, 116, 51, 107, 7, 87, 211, 84, 224, 138, 245, 246, 78, 175, 197, 106],
+		[47, 129, 120, 214, 168, 216, 177, 63, 169, 170, 142, 7, 174, 36, 24, 225, 36, 78, 234, 207, 58, 239, 207, 252, 170, 204, 131, 202, 187, 125, 196, 174],
+		[242, 93, 206, 201, 119, 166, 60, 231, 244, 193, 15, 67, 163, 20, 221, 149, 246, 68, 2, 33, 188, 69, 185, 31, 43, 167, 99, 8, 37, 232, 69, 51],
+		[67, 163, 59, 163, 11, 84, 166, 5, 46, 11, 124, 149, 126, 158, 250, 134, 63, 104, 147, 101, 12, 146, 166, 21, 220, 59, 170, 97, 232, 12, 96, 106],
 	],
 	[
-		[18, 176, 29, 17, 179, 143, 123, 166, 188, 184, 17, 175, 72, 14, 121, 182, 12, 49, 27, 136, 174, 114, 64, 144, 166, 67, 156, 84, 162, 93, 185, 176],
-		[4, 120, 227, 91, 239, 54, 253, 253, 134, 31, 234, 204, 225, 64, 161, 173, 217, 81, 139, 122, 35, 151, 213, 84, 246, 249, 179, 63, 113, 204, 211, 99],
-		[104, 179, 213, 98, 206, 142, 132, 8, 3, 114, 141, 161, 94, 88, 22, 13, 137, 168, 192, 91, 182, 141, 217, 111, 229, 41, 129, 177, 145, 243, 50, 17],
-		[170, 149, 85, 223, 15, 215, 21, 113, 211, 204, 239, 96, 155, 233, 229, 181, 105, 183, 36, 142, 103, 247, 117, 162, 116, 203, 19, 68, 117, 81, 141, 219],
+		[19, 91, 110, 211, 235, 212, 108, 144, 54, 32, 107, 240, 60, 91, 135, 31, 22, 117, 48, 142, 187, 22, 198, 7, 249, 158, 161, 62, 220, 152, 12, 7],
+		[0, 243, 51, 253, 91, 134, 75, 184, 150, 150, 67, 237, 26, 120, 224, 254, 15, 46, 238, 126, 22, 183, 15, 35, 240, 221, 220, 155, 239, 191, 86, 192],
+		[37, 119, 91, 216, 44, 193, 55, 203, 218, 221, 153, 193, 122, 38, 226, 69, 12, 143, 234, 137, 40, 15, 189, 198, 197, 62, 107, 45, 120, 11, 38, 131],
+		[152, 226, 43, 93, 7, 33, 37, 144, 162, 1, 131, 37, 237, 223, 111, 76, 35, 41, 0, 163, 46, 53, 146, 41, 195, 94, 183, 240, 185, 78, 200, 37],
 	],
 	[
-		[19, 132, 196, 221, 44, 231, 230, 9, 72, 203, 140, 54, 254, 188, 190, 107, 90, 186, 71, 53, 47, 209, 65, 144, 20, 174, 67, 162, 111, 77, 108, 27],
-		[26, 251, 4, 119, 164, 59, 73, 159, 26, 22, 169, 171, 49, 191, 234, 136, 140, 252, 53, 212, 27, 211, 80, 0, 183, 23, 75, 74, 175, 238, 143, 223],
-		[155, 134, 35, 42, 233, 132, 105, 77, 161, 189, 93, 218, 120, 33, 53, 224, 66, 194, 4, 54, 178, 237, 193, 174, 10, 211, 240, 159, 172, 43, 197, 101],
-		[78, 99, 94, 52, 3, 105, 156, 52, 190, 203, 18, 58, 196, 172, 136, 18, 161, 44, 217, 109, 104, 142, 139, 78, 137, 130, 227, 104, 60, 114, 195, 208],
+		[170, 13, 41, 97, 79, 245, 140, 123, 117, 114, 26, 176, 135, 126, 56, 150, 57, 239, 26, 162, 221, 93, 15, 26, 35, 223, 81, 154, 180, 177, 137, 129],
+		[193, 85, 109, 127, 103, 174, 124, 151, 148, 199, 63, 177, 249, 34, 211, 215, 34, 242, 169, 237, 184, 19, 33, 196, 46, 207, 253, 10, 55, 8, 61, 7],
+		[81, 152, 114, 236, 43, 97, 220, 157, 5, 8, 36, 81, 233, 62, 112, 123, 209, 237, 201, 178, 213, 98, 36, 171, 88, 32, 196, 4, 71, 1, 38, 241],
+		[168, 82, 51, 182, 119, 65, 8, 81, 202, 173, 133, 130, 247, 111, 17, 31, 245, 103, 229, 71, 242, 217, 236, 97, 111, 75, 10, 32, 98, 43, 176, 153],
 	],
 	[
-		[207, 17, 77, 126, 244, 167, 110, 117, 200, 248, 141, 175, 106, 138, 37, 219, 120, 201, 69, 105, 170, 221, 153, 29, 217, 82, 170, 128, 217, 135, 225, 209],
-		[194, 128, 55, 239, 226, 91, 128, 180, 47, 16, 177, 139, 158, 27, 87, 164, 99, 208, 26, 40, 203, 65, 21, 141, 245, 156, 162, 33, 189, 162, 8, 225],
-		[182, 87, 56, 142, 130, 234, 248, 111, 125, 93, 11, 151, 14, 1, 150, 147, 120, 93, 180, 202, 113, 139, 205, 192, 237, 253, 37, 221, 220, 104, 195, 199],
-		[19, 24, 108, 198, 214, 187, 213, 79, 38, 81, 26, 184, 29, 118, 46, 220, 187, 140, 121, 40, 124, 172, 117, 45, 111, 92, 20, 221, 154, 132, 67, 197],
+		[248, 220, 108, 22, 119, 234, 231, 211, 191, 159, 124, 237, 30, 39, 165, 180, 98, 163, 88, 64, 80, 49, 1, 169, 231, 220, 105, 135, 133, 97, 196, 192],
+		[90, 245, 116, 206, 88, 20, 156, 101, 180, 236, 48, 229, 239, 86, 192, 254, 34, 147, 137, 245, 65, 114, 243, 176, 119, 7, 15, 36, 52, 31, 68, 48],
+		[65, 24, 50, 54, 208, 188, 136, 132, 77, 222, 60, 139, 123, 138, 143, 101, 123, 203, 186, 17, 233, 247, 81, 250, 253, 14, 163, 246, 148, 47, 17, 107],
+		[121, 152, 59, 140, 33, 58, 58, 25, 191, 137, 28, 141, 83, 202, 209, 170, 242, 146, 44, 10, 151, 43, 134, 155, 12, 17, 70, 73, 209, 245, 85, 57],
 	],
 	[
-		[104, 98, 240, 65, 116, 6, 229, 1, 176, 206, 118, 24, 248, 197, 14, 82, 53, 189, 50, 210, 71, 232, 180, 176, 29, 65, 110, 223, 189, 1, 201, 192],
-		[124, 158, 219, 214, 125, 213, 21, 165, 143, 115, 185, 36, 167, 110, 121, 21, 54, 11, 255, 82, 87, 14, 154, 90, 217, 42, 247, 207, 103, 71, 91, 58],
-		[32, 77, 101, 233, 238, 108, 254, 85, 243, 240, 63, 241, 4, 65, 194, 242, 70, 133, 224, 38, 51, 161, 163, 28, 232, 133, 166, 80, 172, 253, 27, 168],
-		[124, 20, 73, 20, 165, 243, 234, 149, 84, 107, 98, 186, 104, 96, 234, 122, 199, 129, 218, 72, 180, 243, 11, 231, 97, 203, 238, 218, 181, 202, 177, 204],
+		[25, 34, 37, 217, 2, 47, 222, 233, 118, 113, 130, 173, 102, 146, 163, 50, 4, 149, 124, 239, 111, 179, 12, 204, 238, 141, 249, 35, 226, 235, 178, 69],
+		[141, 58, 20, 172, 60, 72, 237, 141, 155, 150, 75, 250, 24, 47, 10, 97, 147, 171, 223, 114, 117, 72, 122, 119, 92, 101, 198, 131, 42, 4, 107, 94],
+		[115, 16, 29, 185, 188, 62, 116, 67, 162, 226, 55, 86, 90, 131, 200, 24, 81, 89, 48, 223, 8, 93, 217, 57, 34, 99, 62, 41, 107, 102, 24, 160],
+		[31, 141, 25, 93, 94, 214, 251, 35, 54, 122, 122, 72, 91, 210, 172, 0, 75, 243, 101, 56, 90, 133, 67, 113, 101, 159, 202, 243, 107, 173, 109, 157],
 	],
 	[
-		[112, 145, 81, 126, 125, 179, 71, 84, 202, 144, 106, 253, 114, 217, 118, 170, 33, 113, 57, 100, 243, 154, 165, 63, 148, 98, 43, 130, 18, 137, 78, 226],
-		[0, 139, 60, 151, 137, 218, 22, 252, 116, 101, 211, 174, 239, 182, 171, 98, 168, 252, 22, 105, 63, 3, 102, 167, 68, 92, 44, 92, 123, 222, 7, 105],
-		[242, 130, 128, 180, 2, 81, 210, 91, 22, 15, 146, 16, 6, 73, 190, 105, 171, 105, 19, 52, 148, 103, 250, 158, 196, 114, 59, 48, 136, 113, 122, 110],
-		[237, 231, 164, 27, 59, 113, 38, 178, 74, 148, 219, 5, 101, 223, 223, 112, 56, 199, 213, 55, 5, 167, 65, 8, 232, 140, 173, 85, 126, 157, 109, 108],
+		[76, 54, 114, 212, 251, 173, 6, 121, 124, 184, 85, 33, 61, 70, 16, 41, 41, 235, 209, 44, 250, 193, 229, 147, 59, 201, 42, 47, 101, 89, 235, 188],
+		[160, 185, 242, 233, 165, 244, 157, 137, 188, 247, 134, 4, 131, 42, 121, 150, 59, 103, 119, 212, 165, 122, 204, 19, 127, 161, 158, 246, 208, 238, 248, 52],
+		[183, 88, 12, 211, 80, 135, 26, 181, 37, 189, 36, 51, 160, 101, 11, 176, 190, 223, 95, 77, 202, 248, 13, 159, 124, 38, 94, 251, 227, 162, 227, 206],
+		[241, 133, 251, 209, 202, 74, 235, 29, 16, 201, 83, 70, 250, 31, 162, 28, 178, 44, 152, 30, 102, 36, 145, 189, 197, 94, 123, 148, 248, 243, 189, 157],
 	],
 	[
-		[241, 54, 190, 226, 216, 190, 81, 26, 206, 75, 64, 119, 105, 187, 209, 86, 14, 227, 70, 111, 164, 68, 96, 98, 198, 250, 147, 97, 89, 192, 173, 228],
-		[78, 245, 55, 133, 20, 247, 110, 114, 93, 21, 4, 136, 240, 215, 28, 26, 196, 196, 32, 78, 105, 148, 218, 148, 49, 230, 189, 151, 193, 234, 196, 75],
-		[204, 57, 91, 196, 25, 218, 165, 250, 224, 46, 94, 180, 221, 102, 34, 19, 116, 108, 33, 153, 48, 211, 73, 29, 60, 14, 10, 206, 221, 134, 173, 156],
-		[203, 121, 191, 39, 224, 17, 176, 93, 37, 51, 147, 3, 87, 185, 113, 104, 221, 113, 112, 202, 21, 137, 159, 155, 109, 32, 253, 2, 94, 43, 216, 165],
+		[132, 145, 9, 192, 159, 239, 154, 88, 127, 83, 28, 116, 157, 3, 39, 107, 255, 137, 172, 6, 37, 192, 198, 69, 159, 182, 9, 105, 23, 38, 170, 238],
+		[249, 208, 70, 147, 35, 2, 172, 142, 35, 144, 79, 255, 56, 73, 195, 161, 183, 46, 60, 85, 115, 182, 133, 70, 130, 27, 61, 75, 54, 25, 71, 86],
+		[205, 139, 155, 77, 8, 71, 108, 90, 58, 23, 34, 126, 144, 3, 156, 12, 226, 222, 97, 153, 25, 118, 180, 180, 97, 194, 130, 62, 236, 97, 243, 30],
+		[217, 191, 187, 64, 78, 217, 63, 43, 6, 23, 69, 151, 235, 110, 112, 31, 24, 38, 34, 169, 219, 137, 199, 114, 68, 49, 168, 67, 70, 176, 160, 89],
 	],
 	[
-		[246, 109, 173, 244, 229, 163, 129, 10, 74, 181, 8, 13, 171, 189, 6, 47, 18, 251, 23, 199, 122, 66, 86, 4, 224, 31, 239, 38, 72, 208, 107, 55],
-		[238, 19, 88, 134, 195, 228, 48, 44, 149, 111, 145, 136, 55, 248, 255, 237, 18, 20, 213, 232, 168, 23, 138, 34, 142, 213, 17, 217, 101, 158, 251, 145],
-		[80, 170, 232, 169, 230, 229, 100, 197, 38, 157, 240, 102, 163, 46, 165, 99, 222, 211, 40, 173, 80, 44, 128, 159, 229, 208, 114, 243, 58, 28, 173, 242],
-		[87, 17, 91, 128, 30, 70, 193, 18, 244, 173, 208, 26, 95, 23, 100, 159, 84, 114, 62, 100, 39, 141, 167, 116, 27, 88, 34, 22, 208, 6, 105, 213],
+		[228, 252, 104, 119, 32, 75, 232, 54, 54, 112, 157, 5, 59, 213, 192, 46, 12, 20, 111, 159, 50, 229, 245, 130, 213, 189, 169, 110, 252, 112, 1, 213],
+		[55, 165, 102, 26, 169, 229, 28, 195, 177, 110, 43, 39, 122, 122, 179, 49, 38, 130, 56, 214, 23, 235, 244, 92, 30, 32, 83, 58, 29, 66, 117, 72],
+		[202, 133, 89, 66, 215, 243, 118, 104, 252, 67, 167, 235, 236, 67, 34, 245, 18, 97, 42, 70, 250, 101, 228, 121, 5, 65, 188, 226, 125, 115, 135, 8],
+		[227, 35, 157, 196, 98, 241, 113, 39, 231, 227, 159, 142, 36, 146, 58, 131, 126, 112, 22, 59, 50, 111, 139, 253, 196, 198, 69, 178, 231, 179, 254, 201],
 	],
 	[
-		[126, 108, 216, 228, 56, 151, 24, 151, 147, 206, 203, 25, 32, 17, 57, 106, 153, 3, 92, 207, 251, 164, 67, 24, 61, 35, 169, 85, 51, 8, 68, 192],
-		[244, 159, 41, 138, 47, 89, 58, 62, 189, 184, 65, 149, 3, 250, 216, 69, 215, 116, 25, 19, 86, 87, 61, 254, 16, 101, 226, 167, 238, 126, 178, 139],
-		[221, 209, 128, 226, 52, 161, 35, 155, 252, 172, 76, 188, 46, 9, 29, 5, 132, 41, 62, 209, 189, 207, 180, 246, 115, 99, 28, 227, 187, 142, 52, 14],
-		[35, 248, 185, 128, 169, 213, 122, 92, 162, 213, 160, 59, 136, 79, 84, 95, 249, 66, 15, 217, 186, 71, 50, 4, 14, 250, 255, 39, 68, 177, 219, 231],
+		[48, 23, 108, 222, 33, 10, 197, 247, 216, 101, 246, 251, 73, 163, 93, 198, 252, 18, 32, 38, 107, 158, 221, 75, 213, 208, 55, 23, 97, 80, 111, 231],
+		[96, 180, 202, 22, 247, 21, 1, 100, 41, 113, 220, 10, 93, 189, 75, 121, 123, 163, 178, 152, 251, 113, 34, 133, 67, 78, 211, 32, 164, 14, 46, 210],
+		[111, 104, 239, 145, 167, 33, 188, 186, 84, 58, 29, 134, 104, 163, 110, 123, 164, 226, 131, 232, 186, 214, 56, 80, 156, 122, 10, 114, 91, 202, 226, 211],
+		[191, 118, 222, 43, 184, 220, 97, 33, 78, 58, 37, 173, 82, 84, 254, 251, 249, 213, 118, 69, 224, 105, 104, 218, 229, 10, 215, 222, 216, 234, 144, 104],
 	],
 	[
-		[252, 148, 50, 227, 234, 59, 47, 219, 59, 86, 87, 43, 177, 199, 147, 98, 154, 79, 214, 122, 166, 197, 164, 190, 235, 152, 109, 162, 176, 198, 22, 86],
-		[62, 43, 177, 121, 153, 94, 0, 58, 192, 98, 253, 139, 68, 7, 51, 192, 35, 251, 121, 28, 40, 201, 233, 23, 91, 81, 152, 116, 143, 240, 197, 16],
-		[165, 32, 142, 134, 237, 73, 167, 54, 94, 46, 88, 212, 122, 80, 73, 114, 135, 8, 252, 50, 29, 177, 80, 27, 241, 82, 139, 109, 53, 197, 147, 192],
-		[71, 170, 13, 115, 35, 139, 78, 119, 47, 22, 90, 244, 179, 103, 130, 14, 38, 73, 192, 130, 181, 37, 40, 100, 216, 96, 149, 199, 163, 27, 213, 81],
+		[162, 167, 249, 105, 234, 136, 226, 178, 42, 175, 76, 11, 25, 103, 139, 245, 242, 57, 119, 128, 16, 33, 28, 239, 54, 199, 88, 66, 124, 132, 168, 93],
+		[0, 118, 94, 248, 133, 177, 192, 26, 168, 109, 175, 100, 71, 16, 141, 101, 16, 62, 239, 230, 18, 80, 49, 12, 22, 87, 7, 44, 206, 55, 58, 16],
+		[71, 251, 230, 208, 219, 77, 56, 7, 192, 195, 5, 235, 13, 128, 138, 65, 0, 180, 95, 148, 106, 2, 70, 174, 157, 20, 201, 42, 224, 239, 11, 42],
+		[50, 17, 180, 91, 204, 124, 102, 204, 172, 18, 181, 4, 8, 249, 117, 15, 163, 12, 54, 232, 52, 251, 113, 136, 242, 98, 240, 151, 221, 172, 93, 243],
 	],
 	[
-		[43, 205, 188, 41, 128, 84, 189, 130, 148, 108, 35, 232, 66, 63, 27, 105, 138, 73, 71, 180, 180, 94, 103, 106, 218, 154, 79, 178, 93, 149, 116, 216],
-		[182, 52, 147, 136, 74, 138, 38, 254, 139, 157, 98, 222, 153, 4, 91, 142, 199, 252, 89, 105, 151, 206, 0, 35, 140, 47, 172, 252, 18, 254, 242, 197],
-		[39, 180, 175, 123, 154, 125, 146, 122, 230, 89, 218, 102, 28, 125, 206, 248, 10, 113, 105, 217, 247, 226, 43, 157, 151, 20, 13, 92, 246, 94, 163, 78],
-		[101, 224, 27, 47, 192, 54, 70, 103, 44, 43, 111, 49, 138, 162, 246, 129, 166, 163, 194, 199, 19, 56, 145, 135, 163, 207, 222, 67, 216, 253, 208, 78],
+		[180, 112, 53, 158, 246, 6, 109, 64, 162, 31, 222, 98, 128, 193, 239, 5, 0, 162, 26, 184, 143, 32, 50, 103, 137, 251, 250, 252, 28, 23, 122, 92],
+		[55, 137, 238, 146, 217, 202, 198, 43, 84, 176, 228, 11, 114, 7, 185, 171, 252, 173, 158, 182, 164, 179, 130, 13, 229, 141, 50, 142, 201, 151, 212, 21],
+		[238, 119, 119, 76, 170, 21, 229, 97, 151, 128, 240, 86, 217, 199, 104, 123, 219, 107, 58, 38, 122, 13, 124, 109, 186, 192, 210, 114, 162, 10, 223, 106],
+		[87, 181, 248, 190, 165, 73, 187, 144, 153, 1, 125, 14, 86, 204, 142, 120, 108, 143, 42, 175, 121, 21, 94, 37, 155, 239, 180, 1, 13, 123, 80, 21],
 	],
 	[
-		[167, 151, 254, 199, 34, 107, 148, 236, 38, 95, 133, 153, 13, 26, 26, 237, 73, 14, 208, 69, 161, 168, 63, 47, 43, 113, 108, 7, 160, 16, 41, 93],
-		[46, 76, 220, 22, 4, 41, 124, 128, 139, 247, 58, 181, 169, 7, 48, 76, 184, 220, 56, 190, 238, 153, 77, 93, 132, 197, 163, 179, 207, 45, 133, 35],
-		[59, 61, 146, 220, 21, 250, 67, 152, 227, 222, 213, 33, 236, 72, 243, 46, 168, 25, 66, 9, 126, 44, 187, 38, 217, 252, 217, 60, 97, 234, 41, 226],
-		[97, 31, 93, 202, 152, 217, 160, 215, 39, 189, 136, 69, 33, 118, 80, 51, 71, 178, 124, 51, 95, 170, 15, 211, 40, 73, 69, 243, 93, 7, 45, 218],
+		[165, 48, 214, 189, 32, 197, 132, 134, 4, 131, 162, 160, 233, 87, 194, 98, 56, 240, 29, 231, 68, 216, 39, 32, 81, 14, 146, 224, 26, 53, 51, 29],
+		[241, 49, 137, 39, 72, 153, 85, 15, 160, 232, 245, 108, 219, 234, 56, 42, 69, 22, 179, 138, 94, 160, 230, 33, 220, 185, 180, 3, 108, 177, 4, 35],
+		[87, 241, 125, 149, 56, 239, 167, 226, 10, 92, 139, 126, 213, 58, 138, 174, 64, 46, 168, 174, 106, 107, 154, 68, 70, 237, 25, 21, 119, 149, 61, 70],
+		[55, 76, 97, 28, 91, 202, 73, 176, 111, 200, 226, 119, 21, 15, 206, 232, 72, 97, 53, 250, 64, 7, 127, 148, 9, 60, 189, 197, 58, 201, 36, 169],
 	],
 	[
-		[200, 167, 146, 27, 101, 8, 134, 87, 85, 97, 6, 126, 193, 55, 115, 232, 239, 202, 83, 252, 228, 207, 205, 103, 234, 89, 179, 88, 123, 42, 24, 6],
-		[154, 137, 101, 182, 175, 181, 88, 46, 171, 4, 174, 36, 116, 82, 21, 230, 82, 40, 162, 222, 41, 134, 106, 68, 158, 7, 32, 125, 91, 165, 48, 158],
-		[213, 133, 90, 1, 244, 177, 217, 102, 79, 123, 184, 247, 170, 112, 137, 66, 136, 28, 53, 119, 233, 121, 149, 88, 217, 173, 121, 175, 163, 28, 53, 94],
-		[248, 45, 188, 132, 204, 211, 63, 193, 242, 155, 120, 160, 214, 75, 226, 241, 179, 97, 218, 201, 208, 14, 64, 225, 246, 96, 200, 239, 61, 122, 152, 2],
+		[254, 153, 89, 170, 196, 82, 116, 48, 185, 235, 151, 135, 241, 222, 204, 108, 125, 153, 124, 172, 49, 6, 5, 129, 128, 240, 14, 43, 189, 230, 51, 236],
+		[82, 69, 109, 4, 46, 132, 97, 170, 233, 94, 61, 104, 249, 168, 129, 223, 244, 198, 179, 71, 67, 191, 105, 10, 88, 42, 23, 247, 113, 122, 159, 85],
+		[134, 152, 96, 132, 244, 161, 67, 119, 236, 193, 126, 92, 170, 74, 121, 75, 217, 78, 159, 119, 169, 89, 77, 37, 239, 157, 241, 239, 94, 219, 48, 131],
+		[80, 14, 248, 236, 245, 80, 208, 252, 82, 132, 67, 240, 209, 178, 244, 3, 248, 81, 62, 54, 3, 201, 133, 144, 181, 130, 78, 55, 114, 111, 70, 84],
 	],
 	[
-		[129, 93, 1, 96, 252, 166, 96, 56, 96, 48, 83, 167, 11, 217, 177, 209, 56, 207, 160, 209, 98, 194, 158, 52, 152, 213, 157, 133, 117, 205, 12, 50],
-		[209, 149, 186, 39, 177, 44, 199, 208, 162, 252, 101, 202, 238, 112, 39, 200, 30, 53, 229, 100, 64, 41, 168, 122, 249, 66, 29, 174, 213, 118, 216, 55],
-		[5, 239, 141, 107, 71, 42, 253, 167, 4, 42, 89, 201, 24, 210, 252, 251, 226, 232, 98, 154, 255, 85, 24, 106, 24, 39, 140, 163, 252, 69, 172, 24],
-		[206, 12, 218, 220, 67, 81, 80, 148, 41, 209, 83, 41, 23, 159, 105, 52, 196, 43, 203, 242, 49, 81, 226, 142, 186, 136, 214, 225, 212, 54, 106, 183],
+		[74, 116, 57, 38, 118, 182, 18, 251, 232, 116, 230, 85, 71, 161, 222, 70, 96, 74, 142, 45, 215, 246, 116, 99, 26, 159, 177, 150, 155, 145, 40, 217],
+		[207, 178, 195, 125, 250, 83, 162, 29, 84, 168, 150, 205, 173, 242, 235, 221, 252, 193, 194, 6, 89, 181, 194, 103, 138, 168, 158, 250, 215, 36, 227, 128],
+		[195, 68, 122, 132, 231, 221, 116, 151, 189, 65, 80, 141, 88, 196, 217, 91, 126, 83, 158, 115, 227, 217, 128, 81, 75, 70, 97, 163, 242, 127, 210, 242],
+		[32, 12, 34, 131, 50, 160, 59, 47, 189, 160, 99, 7, 184, 134, 21, 211, 47, 85, 245, 202, 196, 87, 215, 247, 15, 87, 160, 217, 108, 102, 60, 193],
 	],
 	[
-		[253, 191, 45, 5, 209, 211, 214, 31, 237, 43, 169, 239, 53, 36, 141, 236, 67, 172, 215, 230, 155, 128, 209, 49, 212, 147, 88, 19, 170, 83, 24, 3],
-		[178, 29, 51, 71, 194, 168, 95, 240, 175, 1, 111, 47, 161, 52, 89, 188, 5, 145, 168, 48, 43, 37, 212, 11, 121, 172, 183, 158, 186, 38, 148, 51],
-		[189, 109, 202, 146, 35, 219, 130, 66, 4, 18, 14, 50, 118, 21, 112, 3, 66, 87, 36, 138, 77, 248, 177, 38, 75, 158, 94, 70, 75, 75, 22, 220],
-		[199, 215, 196, 121, 82, 53, 28, 50, 182, 13, 200, 116, 59, 113, 206, 230, 205, 204, 187, 189, 147, 173, 197, 37, 204, 130, 221, 75, 39, 31, 102, 130],
+		[223, 2, 73, 116, 227, 112, 167, 103, 117, 25, 55, 123, 121, 189, 232, 244, 167, 63, 38, 172, 242, 163, 98, 210, 50, 236, 223, 30, 230, 119, 154, 150],
+		[160, 108, 39, 80, 229, 101, 171, 57, 215, 235, 54, 13, 200, 166, 39, 242, 134, 24, 102, 187, 229, 52, 2, 59, 149, 187, 66, 99, 145, 175, 53, 166],
+		[87, 196, 8, 65, 119, 135, 253, 143, 130, 143, 119, 204, 69, 150, 77, 231, 245, 227, 31, 228, 19, 51, 42, 208, 3, 236, 250, 202, 175, 75, 89, 64],
+		[25, 111, 97, 188, 212, 201, 44, 226, 68, 201, 135, 44, 238, 207, 98, 233, 202, 197, 17, 7, 130, 34, 169, 138, 123, 52, 128, 210, 108, 118, 91, 63],
 	],
 	[
-		[229, 58, 48, 134, 218, 86, 94, 229, 152, 172, 127, 49, 122, 227, 177, 241, 128, 66, 197, 75, 80, 234, 140, 162, 101, 53, 149, 157, 146, 235, 140, 25],
-		[72, 162, 105, 186, 95, 248, 159, 49, 242, 13, 238, 41, 237, 17, 98, 248, 55, 152, 85, 136, 183, 186, 175, 127, 9, 2, 34, 146, 118, 86, 5, 80],
-		[104, 71, 52, 91, 172, 146, 87, 188, 125, 41, 22, 74, 121, 238, 61, 227, 51, 25, 91, 152, 116, 141, 56, 215, 188, 136, 135, 221, 100, 46, 187, 39],
-		[3, 103, 1, 213, 57, 107, 40, 64, 22, 55, 234, 232, 62, 8, 62, 104, 72, 200, 61, 45, 207, 197, 77, 127, 212, 241, 216, 146, 111, 135, 104, 65],
+		[9, 15, 48, 122, 108, 213, 254, 37, 77, 186, 139, 113, 182, 89, 216, 84, 1, 6, 64, 255, 161, 131, 124, 8, 118, 139, 137, 187, 233, 71, 21, 100],
+		[120, 62, 211, 128, 210, 234, 35, 185, 140, 143, 124, 84, 179, 41, 235, 173, 162, 30, 124, 69, 203, 19, 189, 126, 63, 220, 111, 53, 113, 87, 202, 9],
+		[154, 233, 238, 160, 150, 88, 216, 60, 89, 232, 218, 70, 5, 228, 109, 3, 79, 249, 104, 113, 153, 50, 129, 173, 228, 191, 59, 132, 125, 116, 6, 113],
+		[189, 57, 4, 93, 208, 125, 149, 143, 50, 195, 240, 81, 177, 169, 207, 88, 100, 18, 82, 249, 201, 46, 73, 39, 202, 242, 184, 148, 108, 27, 29, 230],
 	],
 	[
-		[198, 152, 102, 101, 150, 200, 241, 113, 69, 48, 19, 113, 225, 146, 62, 71, 197, 206, 102, 170, 247, 43, 25, 58, 132, 221, 186, 208, 21, 57, 205, 59],
-		[46, 43, 38, 5, 153, 192, 183, 144, 30, 32, 203, 77, 242, 248, 114, 96, 102, 62, 254, 28, 2, 43, 116, 233, 61, 89, 18, 145, 190, 19, 255, 198],
-		[14, 55, 124, 19, 223, 179, 220, 126, 210, 7, 117, 38, 243, 71, 195, 239, 191, 225, 62, 26, 121, 29, 78, 12, 111, 158, 104, 226, 197, 237, 141, 129],
-		[76, 29, 162, 197, 246, 119, 14, 52, 19, 60, 55, 111, 119, 31, 32, 199, 221, 52, 114, 115, 169, 66, 68, 76, 245, 183, 36, 153, 76, 146, 105, 85],
+		[102, 171, 159, 60, 172, 164, 170, 149, 66, 15, 71, 249, 222, 216, 255, 113, 109, 16, 14, 98, 58, 87, 175, 141, 200, 221, 121, 212, 190, 228, 111, 144],
+		[189, 185, 166, 119, 184, 246, 82, 133, 69, 43, 194, 3, 86, 194, 83, 41, 101, 210, 168, 79, 162, 164, 189, 149, 221, 96, 56, 136, 127, 91, 148, 133],
+		[108, 8, 193, 212, 170, 33, 67, 122, 203, 83, 121, 145, 247, 46, 10, 50, 92, 254, 18, 217, 87, 149, 80, 49, 176, 15, 140, 218, 96, 97, 80, 235],
+		[171, 67, 1, 118, 210, 120, 178, 136, 13, 159, 214, 85, 177, 18, 147, 11, 10, 112, 204, 31, 59, 196, 44, 17, 232, 159, 218, 154, 170, 191, 51, 135],
 	],
 	[
-		[97, 217, 11, 129, 61, 194, 156, 205, 236, 75, 208, 43, 125, 226, 115, 32, 70, 68, 202, 162, 200, 77, 209, 42, 47, 206, 80, 191, 73, 36, 165, 84],
-		[84, 3, 249, 115, 230, 98, 117, 129, 159, 91, 156, 70, 240, 250, 115, 75, 152, 46, 97, 144, 111, 72, 232, 42, 38, 174, 255, 194, 87, 188, 3, 230],
-		[232, 130, 237, 173, 20, 172, 194, 116, 123, 236, 141, 186, 137, 194, 252, 8, 43, 39, 216, 219, 245, 149, 77, 8, 213, 107, 36, 198, 18, 71, 218, 23],
-		[170, 131, 233, 0, 59, 151, 19, 35, 231, 220, 147, 101, 22, 93, 24, 30, 228, 205, 126, 141, 169, 178, 204, 248, 217, 239, 126, 20, 197, 153, 39, 165],
+		[206, 154, 116, 30, 232, 73, 170, 174, 50, 137, 98, 243, 244, 199, 190, 175, 249, 81, 189, 183, 103, 48, 48, 143, 142, 149, 77, 169, 20, 64, 216, 110],
+		[52, 114, 163, 85, 155, 218, 56, 1, 63, 158, 53, 77, 48, 127, 23, 170, 153, 143, 211, 225, 78, 250, 91, 240, 134, 125, 169, 211, 155, 162, 182, 230],
+		[6, 60, 128, 97, 218, 177, 112, 119, 150, 219, 30, 249, 106, 59, 171, 232, 60, 253, 152, 152, 139, 45, 237, 194, 163, 76, 219, 5, 233, 189, 62, 70],
+		[208, 114, 94, 242, 127, 112, 29, 93, 156, 3, 162, 133, 250, 165, 134, 126, 178, 171, 224, 9, 41, 235, 83, 209, 3, 200, 87, 91, 193, 224, 116, 168],
 	],
 	[
-		[159, 59, 130, 7, 243, 21, 4, 65, 56, 30, 171, 208, 130, 63, 191, 134, 47, 231, 237, 157, 213, 9, 195, 244, 64, 171, 77, 227, 203, 22, 160, 155],
-		[187, 112, 111, 48, 198, 164, 240, 162, 103, 179, 79, 32, 47, 93, 56, 183, 219, 56, 9, 12, 85, 30, 43, 173, 66, 42, 116, 181, 48, 234, 69, 25],
-		[61, 229, 174, 122, 117, 8, 236, 198, 117, 181, 41, 53, 168, 200, 244, 112, 129, 217, 138, 202, 197, 233, 93, 151, 4, 35, 28, 146, 105, 97, 8, 232],
-		[116, 56, 155, 84, 241, 90, 52, 10, 173, 185, 46, 148, 43, 161, 240, 11, 228, 6, 144, 74, 251, 125, 224, 166, 170, 178, 134, 46, 168, 26, 223, 66],
+		[180, 139, 201, 71, 39, 29, 73, 127, 147, 104, 218, 12, 76, 209, 196, 214, 45, 109, 252, 183, 230, 23, 34, 126, 196, 182, 80, 76, 143, 214, 88, 159],
+		[209, 143, 118, 14, 148, 100, 179, 179, 100, 24, 247, 108, 207, 32, 170, 132, 192, 18, 228, 179, 17, 8, 127, 13, 195, 5, 247, 6, 147, 15, 209, 6],
+		[21, 243, 124, 215, 172, 165, 29, 178, 211, 112, 76, 73, 3, 66, 112, 238, 146, 135, 10, 20, 30, 228, 70, 99, 8, 249, 68, 10, 126, 114, 33, 108],
+		[22, 57, 189, 253, 129, 95, 97, 231, 111, 52, 236, 63, 67, 20, 95, 199, 99, 77, 134, 26, 244, 239, 97, 129, 81, 5, 162, 152, 151, 246, 103, 35],
 	],
 	[
-		[103, 104, 199, 151, 128, 25, 101, 223, 73, 120, 232, 247, 61, 146, 113, 101, 32, 94, 101, 212, 87, 98, 118, 26, 48, 111, 94, 220, 198, 88, 34, 227],
-		[22, 139, 225, 135, 84, 28, 9, 133, 76, 50, 202, 119, 109, 197, 228, 20, 148, 64, 32, 250, 8, 114, 111, 160, 133, 46, 204, 184, 206, 102, 241, 153],
-		[167, 235, 244, 71, 116, 74, 160, 192, 213, 127, 90, 217, 239, 17, 45, 132, 68, 77, 115, 176, 173, 178, 218, 153, 7, 136, 140, 21, 183, 207, 68, 142],
-		[90, 234, 167, 89, 232, 157, 43, 198, 200, 65, 175, 103, 31, 35, 44, 246, 88, 80, 241, 88, 164, 95, 89, 175, 139, 158, 237, 7, 103, 116, 74, 83],
+		[76, 29, 128, 159, 24, 235, 81, 88, 62, 160, 15, 62, 57, 143, 197, 93, 149, 41, 18, 189, 188, 253, 0, 240, 2, 188, 107, 5, 22, 145, 68, 165],
+		[90, 163, 228, 77, 73, 183, 240, 206, 28, 237, 54, 237, 212, 4, 97, 22, 23, 134, 155, 158, 37, 85, 213, 53, 150, 75, 42, 252, 221, 196, 68, 64],
+		[138, 181, 248, 213, 16, 168, 55, 206, 228, 233, 4, 67, 58, 206, 130, 143, 44, 231, 241, 106, 211, 18, 151, 172, 136, 226, 180, 209, 179, 53, 226, 156],
+		[61, 198, 255, 85, 199, 96, 73, 253, 122, 142, 229, 10, 1, 82, 98, 140, 163, 17, 232, 249, 111, 42, 231, 162, 165, 200, 164, 156, 55, 30, 229, 46],
 	],
 	[
-		[220, 146, 190, 237, 228, 63, 215, 252, 196, 212, 207, 22, 5, 149, 93, 19, 139, 31, 131, 73, 205, 83, 116, 148, 29, 164, 227, 54, 21, 229, 17, 207],
-		[49, 78, 4, 210, 40, 128, 148, 128, 6, 125, 55, 189, 154, 232, 239, 92, 2, 18, 77, 74, 95, 18, 79, 61, 79, 167, 5, 146, 39, 188, 190, 137],
-		[102, 156, 112, 202, 243, 108, 201, 224, 24, 231, 116, 14, 104, 143, 129, 210, 122, 10, 160, 221, 17, 179, 206, 156, 127, 117, 138, 115, 182, 163, 232, 12],
-		[123, 38, 209, 142, 142, 152, 50, 136, 198, 178, 169, 15, 4, 135, 95, 63, 75, 139, 19, 71, 107, 38, 218, 90, 146, 139, 22, 107, 244, 44, 37, 56],
+		[10, 103, 9, 13, 139, 157, 21, 63, 33, 235, 185, 202, 248, 101, 24, 77, 62, 101, 123, 35, 91, 36, 30, 143, 225, 2, 253, 29, 188, 239, 141, 18],
+		[23, 192, 233, 112, 173, 233, 229, 102, 192, 221, 203, 242, 190, 205, 14, 146, 138, 60, 24, 202, 41, 134, 21, 246, 61, 134, 159, 89, 28, 249, 210, 9],
+		[190, 30, 166, 233, 121, 106, 78, 228, 51, 75, 254, 207, 91, 68, 252, 51, 143, 94, 199, 21, 55, 176, 205, 43, 11, 231, 107, 151, 9, 128, 35, 102],
+		[95, 218, 26, 130, 84, 78, 126, 214, 237, 61, 64, 27, 214, 233, 196, 255, 2, 172, 173, 48, 160, 244, 163, 25, 179, 204, 164, 165, 228, 122, 38, 227],
 	],
 	[
-		[21, 213, 147, 4, 114, 125, 137, 148, 37, 182, 13, 78, 152, 205, 69, 62, 108, 64, 166, 205, 175, 80, 172, 230, 140, 93, 64, 77, 208, 226, 65, 135],
-		[125, 172, 159, 55, 113, 39, 223, 153, 111, 164, 177, 170, 124, 10, 95, 229, 52, 174, 241, 176, 111, 194, 146, 33, 151, 7, 251, 191, 224, 54, 203, 134],
-		[227, 121, 183, 225, 79, 89, 180, 134, 176, 254, 121, 81, 113, 164, 12, 0, 204, 228, 251, 105, 196, 217, 70, 230, 66, 69, 62, 232, 41, 158, 21, 161],
-		[173, 213, 195, 0, 54, 194, 100, 231, 212, 96, 144, 56, 141, 144, 106, 172, 18, 135, 227, 63, 223, 179, 228, 244, 130, 10, 133, 89, 204, 28, 155, 21],
+		[117, 104, 144, 28, 246, 46, 144, 198, 156, 93, 213, 36, 163, 43, 246, 112, 83, 150, 4, 215, 1, 12, 19, 185, 231, 140, 163, 158, 120, 185, 187, 92],
+		[146, 240, 13, 99, 71, 239, 243, 216, 244, 93, 181, 92, 40, 134, 0, 144, 77, 169, 234, 23, 41, 195, 138, 178, 111, 255, 29, 27, 217, 77, 11, 48],
+		[1, 204, 238, 152, 211, 99, 193, 56, 128, 32, 98, 75, 200, 191, 36, 226, 234, 115, 146, 182, 135, 172, 112, 89, 163, 129, 242, 190, 125, 225, 63, 133],
+		[13, 192, 7, 140, 76, 147, 63, 152, 78, 139, 1, 44, 185, 86, 156, 208, 74, 80, 16, 60, 28, 37, 104, 176, 67, 152, 193, 68, 160, 155, 62, 181],
 	],
 	[
-		[12, 46, 143, 132, 124, 239, 126, 133, 163, 186, 241, 111, 84, 187, 220, 253, 143, 172, 33, 252, 7, 48, 157, 157, 144, 134, 152, 204, 195, 72, 252, 39],
-		[218, 120, 186, 115, 0, 114, 135, 245, 42, 166, 179, 235, 214, 78, 215, 187, 13, 36, 100, 239, 245, 81, 49, 7, 34, 15, 106, 53, 44, 104, 114, 180],
-		[32, 43, 107, 46, 129, 250, 174, 171, 79, 149, 85, 223, 184, 77, 236, 252, 185, 42, 135, 109, 162, 114, 120, 250, 224, 56, 177, 45, 246, 130, 230, 184],
-		[254, 21, 0, 121, 218, 44, 161, 240, 167, 138, 58, 121, 237, 82, 3, 188, 15, 160, 206, 45, 187, 169, 185, 247, 224, 66, 199, 172, 143, 122, 183, 105],
+		[48, 109, 44, 32, 51, 14, 72, 27, 66, 137, 151, 103, 72, 118, 165, 14, 167, 160, 38, 22, 147, 151, 191, 16, 103, 54, 157, 149, 55, 232, 177, 75],
+		[155, 48, 184, 103, 213, 125, 210, 184, 177, 189, 98, 215, 215, 12, 179, 86, 77, 142, 16, 83, 85, 169, 130, 126, 112, 41, 189, 189, 11, 213, 214, 14],
+		[123, 119, 14, 139, 224, 191, 118, 205, 106, 61, 182, 148, 91, 0, 73, 10, 201, 56, 40, 70, 34, 78, 64, 106, 65, 59, 103, 197, 132, 5, 157, 77],
+		[64, 121, 111, 56, 156, 110, 173, 246, 201, 214, 182, 54, 200, 33, 191, 5, 35, 188, 121, 131, 98, 83, 91, 227, 167, 240, 117, 117, 30, 240, 137, 60],
 	],
 	[
-		[175, 14, 145, 88, 10, 167, 246, 100, 11, 213, 147, 116, 111, 138, 46, 112, 59, 187, 68, 228, 76, 38, 228, 166, 229, 241, 216, 3, 91, 86, 45, 227],
-		[186, 233, 106, 213, 43, 239, 172, 91, 40, 220, 158, 157, 83, 22, 84, 15, 123, 225, 164, 123, 237, 247, 63, 250, 107, 51, 186, 230, 13, 222, 42, 43],
-		[170, 156, 22, 221, 101, 226, 26, 107, 108, 48, 78, 60, 101, 40, 225, 93, 204, 247, 227, 86, 189, 69, 0, 93, 208, 49, 184, 210, 163, 186, 27, 157],
-		[71, 174, 162, 20, 42, 41, 126, 60, 249, 78, 94, 92, 22, 80, 43, 140, 27, 161, 201, 14, 55, 230, 54, 136, 190, 110, 243, 4, 27, 8, 111, 61],
+		[110, 219, 169, 140, 248, 32, 252, 153, 187, 30, 81, 74, 146, 244, 142, 220, 2, 104, 61, 109, 119, 64, 91, 78, 228, 227, 58, 22, 70, 196, 222, 66],
+		[235, 238, 202, 0, 136, 90, 78, 80, 174, 184, 30, 151, 199, 59, 130, 201, 214, 130, 197, 66, 128, 106, 113, 182, 129, 161, 87, 243, 25, 142, 85, 98],
+		[232, 199, 104, 153, 93, 67, 193, 13, 146, 187, 170, 184, 186, 114, 56, 237, 250, 245, 29, 22, 134, 13, 149, 60, 227, 54, 35, 165, 203, 33, 247, 54],
+		[184, 98, 84, 239, 143, 77, 103, 235, 97, 165, 238, 121, 21, 232, 86, 36, 72, 250, 11, 241, 6, 74, 28, 172, 146, 16, 237, 90, 162, 15, 161, 25],
 	],
 	[
-		[19, 38, 148, 222, 179, 55, 168, 11, 14, 247, 149, 139, 231, 238, 255, 96, 87, 65, 160, 129, 52, 218, 47, 229, 92, 100, 132, 85, 97, 200, 203, 26],
-		[78, 132, 243, 167, 47, 26, 46, 187, 30, 59, 16, 128, 143, 149, 88, 242, 215, 89, 74, 3, 66, 83, 102, 171, 68, 38, 134, 231, 209, 231, 248, 153],
-		[138, 166, 58, 254, 255, 75, 69, 12, 37, 42, 14, 182, 66, 134, 106, 183, 2, 6, 52, 174, 4, 62, 65, 158, 231, 184, 96, 214, 112, 20, 153, 178],
-		[141, 83, 173, 52, 187, 205, 58, 225, 108, 59, 3, 94, 248, 41, 228, 136, 54, 87, 10, 118, 253, 64, 33, 223, 198, 30, 81, 124, 131, 107, 229, 237],
+		[179, 85, 35, 161, 37, 7, 0, 86, 197, 252, 194, 100, 66, 160, 169, 253, 79, 215, 21, 142, 199, 64, 141, 53, 19, 213, 136, 120, 27, 153, 186, 185],
+		[58, 96, 66, 159, 50, 62, 83, 75, 163, 252, 55, 218, 78, 209, 138, 87, 120, 182, 86, 86, 73, 200, 152, 164, 254, 84, 253, 118, 104, 138, 183, 190],
+		[78, 134, 239, 63, 45, 40, 184, 96, 162, 108, 31, 226, 114, 204, 96, 71, 43, 9, 219, 239, 5, 228, 64, 74, 5, 151, 67, 149, 131, 11, 220, 134],
+		[59, 129, 174, 75, 240, 216, 28, 225, 17, 72, 218, 199, 170, 156, 143, 126, 40, 177, 26, 161, 77, 162, 12, 254, 112, 231, 246, 225, 234, 219, 249, 81],
 	],
 	[
-		[212, 2, 73, 8, 16, 96, 65, 67, 17, 223, 78, 223, 15, 163, 1, 12, 155, 164, 80, 47, 146, 46, 89, 74, 77, 208, 205, 71, 214, 174, 229, 46],
-		[92, 3, 17, 222, 178, 39, 108, 126, 238, 242, 25, 47, 221, 241, 93, 141, 72, 78, 140, 189, 132, 13, 194, 41, 238, 185, 139, 242, 200, 157, 177, 110],
-		[74, 164, 125, 225, 228, 44, 58, 129, 89, 125, 160, 112, 93, 174, 245, 65, 88, 16, 46, 101, 102, 60, 205, 123, 220, 162, 7, 6, 182, 188, 70, 92],
-		[5, 57, 206, 30, 253, 70, 217, 118, 137, 225, 133, 115, 43, 173, 169, 117, 134, 217, 41, 121, 87, 31, 55, 111, 213, 189, 50, 149, 79, 207, 108, 147],
+		[96, 105, 184, 21, 48, 242, 250, 185, 57, 22, 206, 134, 220, 170, 20, 197, 192, 119, 158, 58, 166, 51, 231, 6, 124, 237, 213, 26, 188, 149, 128, 137],
+		[74, 50, 178, 92, 246, 130, 68, 241, 221, 91, 235, 138, 210, 192, 209, 182, 50, 125, 177, 0, 55, 143, 151, 147, 180, 106, 171, 209, 95, 147, 186, 106],
+		[197, 7, 174, 33, 80, 174, 233, 102, 48, 65, 175, 174, 190, 125, 143, 168, 38, 1, 189, 106, 159, 155, 75, 10, 140, 113, 53, 195, 144, 156, 136, 19],
+		[26, 113, 119, 73, 242, 72, 218, 75, 170, 115, 93, 173, 192, 161, 200, 116, 70, 26, 137, 11, 101, 238, 122, 107, 233, 171, 129, 18, 239, 187, 164, 103],
 	],
 	[
-		[226, 197, 251, 6, 17, 45, 33, 57, 132, 156, 227, 197, 139, 149, 95, 129, 26, 140, 153, 235, 9, 45, 39, 127, 87, 12, 185, 196, 25, 118, 120, 133],
-		[241, 92, 18, 224, 71, 188, 116, 174, 236, 155, 75, 105, 198, 40, 121, 24, 184, 203, 19, 53, 128, 117, 240, 220, 107, 47, 69, 52, 76, 214, 44, 205],
-		[28, 165, 112, 95, 169, 243, 71, 64, 219, 244, 230, 146, 158, 114, 135, 34, 55, 223, 247, 111, 121, 24, 178, 5, 90, 71, 169, 56, 151, 105, 87, 66],
-		[191, 16, 85, 138, 244, 51, 15, 114, 134, 120, 62, 118, 103, 228, 92, 248, 250, 14, 23, 255, 182, 194, 177, 78, 23, 141, 8, 154, 132, 71, 75, 139],
+		[58, 54, 39, 122, 58, 229, 48, 67, 243, 154, 251, 37, 117, 183, 1, 82, 56, 64, 222, 55, 217, 118, 247, 16, 41, 51, 169, 5, 169, 150, 150, 141],
+		[237, 126, 66, 42, 255, 152, 124, 92, 151, 92, 219, 219, 167, 54, 126, 154, 99, 165, 159, 246, 178, 215, 47, 2, 63, 95, 135, 190, 160, 223, 15, 223],
+		[120, 101, 35, 169, 254, 225, 246, 51, 151, 8, 84, 16, 67, 127, 196, 213, 232, 167, 60, 87, 34, 53, 147, 40, 145, 136, 77, 175, 59, 123, 213, 149],
+		[176, 14, 109, 214, 121, 84, 89, 136, 55, 234, 98, 20, 110, 99, 99, 237, 141, 137, 245, 218, 16, 42, 12, 154, 96, 24, 44, 141, 115, 151, 50, 96],
 	],
 	[
-		[154, 52, 85, 210, 140, 200, 158, 130, 212, 232, 206, 245, 222, 95, 220, 11, 223, 175, 89, 76, 29, 99, 18, 219, 247, 179, 59, 16, 33, 35, 214, 172],
-		[142, 24, 14, 172, 224, 41, 203, 79, 207, 27, 241, 29, 59, 217, 255, 167, 77, 116, 195, 166, 79, 83, 80, 124, 139, 242, 5, 128, 194, 83, 73, 87],
-		[252, 162, 223, 125, 114, 181, 191, 65, 233, 149, 107, 245, 83, 153, 103, 48, 248, 120, 101, 141, 22, 174, 69, 225, 99, 190, 250, 2, 29, 245, 170, 207],
-		[14, 85, 109, 76, 226, 55, 39, 114, 225, 234, 10, 40, 168, 41, 78, 144, 30, 177, 234, 238, 228, 232, 85, 182, 162, 112, 125, 210, 137, 75, 186, 172],
+		[9, 90, 196, 179, 132, 20, 104, 177, 115, 76, 105, 173, 197, 173, 23, 95, 212, 48, 128, 225, 1, 174, 52, 233, 181, 85, 157, 159, 240, 155, 240, 40],
+		[103, 89, 100, 190, 238, 167, 46, 34, 86, 228, 43, 157, 157, 238, 194, 88, 86, 55, 237, 78, 149, 22, 90, 206, 68, 105, 79, 48, 40, 57, 219, 100],
+		[22, 235, 229, 62, 241, 156, 142, 88, 54, 245, 244, 171, 72, 88, 102, 169, 87, 143, 83, 88, 23, 202, 144, 173, 127, 241, 151, 175, 157, 210, 44, 13],
+		[149, 143, 16, 67, 198, 115, 133, 73, 163, 29, 83, 165, 63, 216, 185, 221, 196, 232, 188, 47, 159, 6, 175, 92, 175, 108, 108, 124, 105, 98, 50, 167],
 	],
 	[
-		[135, 250, 175, 194, 0, 56, 41, 3, 84, 152, 219, 15, 144, 38, 148, 230, 23, 162, 100, 181, 207, 180, 188, 34, 1, 216, 199, 233, 139, 58, 241, 44],
-		[26, 151, 205, 164, 27, 191, 252, 172, 5, 184, 16, 250, 169, 36, 129, 31, 27, 9, 71, 95, 207, 7, 22, 71, 52, 31, 74, 9, 147, 180, 203, 72],
-		[158, 39, 237, 126, 215, 146, 232, 35, 9, 110, 138, 180, 93, 11, 67, 158, 251, 226, 249, 54, 29, 52, 25, 231, 88, 124, 179, 198, 169, 3, 233, 208],
-		[44, 65, 254, 200, 42, 115, 217, 212, 97, 84, 180, 59, 67, 22, 57, 174, 188, 101, 230, 132, 42, 58, 87, 112, 146, 46, 141, 102, 208, 139, 121, 148],
+		[77, 17, 12, 166, 162, 103, 51, 70, 59, 222, 96, 92, 137, 70, 123, 177, 185, 163, 254, 143, 50, 127, 190, 209, 176, 247, 25, 155, 40, 64, 24, 105],
+		[34, 209, 230, 228, 119, 142, 84, 203, 88, 205, 148, 246, 52, 83, 204, 165, 210, 148, 251, 67, 170, 116, 136, 132, 185, 2, 4, 189, 130, 112, 75, 38],
+		[18, 206, 189, 37, 208, 207, 196, 67, 84, 57, 96, 82, 72, 13, 18, 112, 207, 87, 30, 239, 26, 190, 134, 139, 214, 96, 62, 83, 151, 14, 126, 48],
+		[224, 36, 19, 202, 202, 201, 114, 6, 95, 119, 221, 109, 77, 168, 119, 30, 238, 57, 254, 120, 204, 148, 235, 12, 147, 96, 251, 68, 47, 60, 106, 190],
 	],
 	[
-		[163, 198, 51, 147, 80, 217, 138, 147, 45, 156, 65, 23, 247, 140, 35, 56, 112, 251, 116, 63, 3, 34, 13, 2, 114, 165, 71, 201, 68, 215, 35, 114],
-		[249, 61, 224, 105, 83, 77, 239, 0, 215, 147, 94, 71, 86, 246, 245, 59, 107, 108, 156, 16, 90, 39, 172, 168, 121, 78, 103, 249, 28, 217, 246, 34],
-		[35, 195, 245, 232, 14, 171, 57, 125, 28, 83, 4, 208, 144, 175, 155, 27, 9, 154, 252, 216, 178, 57, 91, 209, 123, 193, 203, 227, 170, 3, 119, 100],
-		[240, 130, 15, 86, 96, 254, 29, 59, 142, 177, 70, 154, 171, 55, 151, 105, 200, 91, 248, 165, 109, 9, 27, 128, 167, 167, 232, 23, 54, 20, 43, 185],
+		[220, 253, 79, 28, 109, 1, 219, 254, 114, 14, 145, 72, 222, 65, 120, 28, 157, 227, 178, 36, 42, 59, 227, 182, 78, 72, 245, 155, 155, 109, 87, 189],
+		[156, 27, 57, 187, 122, 89, 178, 177, 202, 191, 59, 10, 89, 24, 135, 14, 48, 93, 52, 145, 98, 197, 53, 182, 48, 81, 102, 80, 184, 64, 193, 55],
+		[92, 219, 58, 113, 43, 88, 53, 25, 162, 191, 248, 139, 77, 120, 120, 21, 4, 78, 170, 222, 204, 196, 223, 76, 159, 184, 209, 160, 183, 50, 18, 213],
+		[83, 160, 234, 86, 214, 117, 72, 29, 195, 131, 39, 250, 104, 80, 71, 166, 209, 244, 124, 216, 3, 71, 127, 65, 44, 23, 138, 240, 89, 86, 83, 100],
 	],
 	[
-		[136, 73, 79, 94, 207, 52, 166, 28, 150, 182, 18, 135, 204, 41, 127, 207, 63, 15, 129, 154, 182, 151, 45, 229, 58, 244, 3, 140, 242, 139, 204, 5],
-		[53, 197, 87, 255, 116, 236, 151, 181, 186, 206, 250, 55, 235, 230, 162, 178, 231, 236, 27, 91, 24, 195, 70, 25, 172, 137, 28, 171, 180, 237, 177, 62],
-		[23, 188, 200, 181, 125, 33, 95, 179, 61, 245, 103, 206, 188, 40, 186, 89, 5, 81, 198, 220, 3, 208, 178, 188, 104, 41, 12, 195, 58, 130, 155, 183],
-		[24, 245, 175, 158, 237, 39, 118, 155, 224, 100, 230, 208, 19, 103, 153, 241, 4, 140, 13, 1, 138, 225, 184, 211, 201, 0, 179, 184, 185, 20, 18, 3],
+		[240, 142, 47, 243, 223, 164, 15, 88, 244, 230, 221, 123, 62, 227, 16, 52, 26, 10, 107, 115, 103, 55, 50, 186, 255, 37, 162, 120, 193, 155, 207, 158],
+		[151, 163, 138, 46, 82, 61, 117, 121, 248, 129, 106, 71, 42, 174, 242, 140, 81, 71, 7, 95, 238, 122, 130, 185, 232, 120, 95, 5, 89, 71, 231, 51],
+		[212, 125, 150, 201, 0, 43, 47, 40, 145, 28, 134, 214, 72, 241, 78, 171, 120, 54, 35, 21, 205, 88, 214, 224, 167, 169, 210, 141, 87, 105, 5, 242],
+		[134, 74, 241, 244, 70, 238, 159, 86, 117, 133, 92, 214, 170, 66, 224, 210, 255, 238, 104, 204, 138, 249, 178, 26, 140, 182, 246, 227, 114, 15, 18, 228],
 	],
 	[
-		[193, 43, 94, 106, 4, 132, 122, 234, 87, 144, 209, 171, 241, 20, 156, 166, 171, 245, 72, 126, 251, 224, 18, 167, 190, 130, 148, 173, 207, 36, 255, 157],
-		[166, 204, 225, 94, 147, 26, 200, 124, 68, 225, 132, 135, 4, 85, 34, 141, 45, 36, 158, 89, 40, 199, 194, 248, 37, 68, 65, 11, 193, 103, 60, 128],
-		[182, 98, 84, 69, 30, 25, 46, 2, 123, 108, 61, 153, 214, 201, 201, 161, 161, 198, 142, 56, 106, 53, 32, 190, 112, 128, 160, 50, 7, 173, 197, 89],
-		[167, 182, 123, 204, 46, 160, 248, 210, 235, 14, 69, 155, 27, 116, 138, 185, 252, 60, 214, 134, 196, 76, 172, 165, 44, 158, 157, 36, 66, 88, 221, 175],
+		[11, 90, 89, 2, 110, 89, 148, 213, 132, 93, 143, 41, 69, 177, 11, 15, 218, 224, 145, 122, 120, 72, 119, 245, 60, 194, 238, 77, 66, 140, 195, 206],
+		[209, 153, 132, 34, 16, 147, 249, 178, 141, 33, 128, 246, 22, 113, 42, 216, 173, 114, 24, 182, 112, 192, 219, 84, 194, 30, 8, 69, 94, 247, 96, 87],
+		[225, 178, 198, 179, 162, 108, 40, 229, 70, 177, 20, 13, 13, 254, 0, 174, 45, 24, 70, 238, 239, 198, 238, 183, 89, 180, 198, 78, 196, 54, 223, 208],
+		[127, 1, 81, 205, 162, 51, 10, 91, 107, 91, 248, 102, 128, 101, 45, 83, 135, 20, 203, 171, 239, 91, 216, 155, 31, 116, 167, 57, 163, 174, 120, 0],
 	],
 	[
-		[139, 96, 195, 155, 59, 190, 199, 168, 0, 74, 66, 14, 245, 174, 51, 158, 247, 95, 228, 132, 143, 211, 244, 170, 190, 162, 179, 59, 240, 76, 169, 153],
-		[75, 145, 172, 35, 192, 216, 74, 165, 144, 169, 47, 163, 30, 218, 242, 17, 105, 81, 243, 213, 205, 191, 112, 47, 109, 29, 205, 133, 227, 80, 244, 19],
-		[51, 164, 190, 44, 7, 200, 156, 67, 96, 233, 103, 130, 185, 194, 232, 189, 104, 48, 105, 204, 183, 204, 14, 102, 128, 248, 29, 220, 95, 5, 188, 216],
-		[32, 71, 245, 130, 34, 86, 62, 189, 251, 202, 18, 108, 99, 39, 241, 113, 143, 126, 0, 110, 47, 248, 225, 185, 105, 85, 224, 9, 69, 176, 143, 135],
+		[237, 61, 85, 3, 83, 11, 148, 84, 251, 17, 182, 82, 112, 250, 85, 32, 35, 101, 118, 246, 154, 24, 57, 237, 205, 130, 208, 155, 85, 240, 90, 35],
+		[149, 131, 1, 34, 238, 69, 41, 247, 196, 84, 194, 180, 29, 55, 6, 242, 213, 179, 190, 3, 213, 28, 156, 250, 218, 218, 219, 79, 155, 239, 38, 220],
+		[38, 121, 194, 133, 203, 138, 92, 158, 237, 41, 18, 214, 211, 15, 55, 161, 117, 60, 106, 198, 122, 204, 119, 65, 69, 91, 173, 211, 16, 64, 99, 36],
+		[219, 193, 38, 171, 222, 61, 12, 64, 106, 124, 138, 134, 60, 129, 185, 235, 185, 95, 38, 164, 40, 157, 252, 212, 214, 192, 70, 240, 209, 174, 73, 194],
 	],
 	[
-		[128, 205, 35, 100, 197, 27, 176, 242, 132, 151, 236, 116, 74, 120, 207, 201, 158, 98, 10, 19, 159, 18, 65, 152, 211, 126, 40, 107, 247, 64, 67, 129],
-		[255, 140, 194, 242, 19, 213, 151, 168, 240, 7, 77, 54, 237, 80, 191, 180, 3, 31, 67, 184, 155, 99, 46, 109, 171, 82, 84, 120, 204, 173, 28, 30],
-		[213, 85, 76, 120, 243, 73, 237, 74, 239, 51, 86, 250, 215, 84, 198, 72, 12, 181, 167, 43, 4, 129, 17, 82, 183, 165, 64, 45, 107, 202, 152, 100],
-		[150, 132, 231, 134, 149, 135, 83, 58, 248, 139, 19, 208, 198, 149, 29, 64, 93, 101, 60, 183, 184, 147, 48, 246, 132, 234, 245, 119, 57, 21, 48, 241],
+		[7, 218, 185, 159, 14, 183, 214, 193, 10, 156, 48, 33, 128, 71, 24, 127, 60, 8, 45, 210, 29, 194, 154, 205, 58, 153, 46, 65, 103, 158, 141, 15],
+		[183, 141, 133, 24, 244, 158, 147, 7, 33, 138, 218, 220, 248, 3, 66, 94, 246, 178, 142, 210, 201, 219, 156, 206, 195, 22, 164, 249, 37, 25, 178, 225],
+		[5, 152, 224, 189, 21, 56, 50, 182, 16, 2, 66, 173, 33, 25, 229, 104, 111, 35, 94, 217, 19, 124, 248, 165, 98, 35, 69, 49, 105, 241, 158, 32],
+		[20, 99, 120, 142, 136, 27, 29, 98, 20, 51, 174, 45, 136, 47, 81, 111, 114, 241, 131, 123, 53, 233, 28, 190, 218, 231, 93, 193, 111, 37, 254, 80],
 	],
 	[
-		[53, 98, 43, 143, 21, 152, 149, 6, 191, 182, 242, 213, 20, 73, 255, 214, 64, 116, 130, 96, 148, 252, 110, 207, 162, 203, 192, 240, 24, 118, 144, 54],
-		[170, 246, 176, 48, 188, 87, 200, 119, 59, 137, 111, 140, 2, 25, 63, 33, 59, 130, 217, 115, 236, 195, 240, 91, 57, 192, 202, 1, 249, 196, 205, 29],
-		[62, 196, 203, 135, 213, 137, 137, 158, 14, 149, 145, 95, 212, 65, 26, 254, 161, 235, 236, 97, 199, 94, 204, 185, 80, 175, 11, 252, 48, 84, 101, 44],
-		[49, 73, 169, 58, 164, 111, 21, 90, 115, 167, 124, 200, 23, 227, 137, 196, 63, 235, 70, 78, 254, 155, 236, 215, 175, 213, 103, 156, 50, 220, 6, 141],
+		[182, 65, 207, 150, 179, 137, 160, 127, 5, 67, 61, 55, 229, 203, 220, 203, 108, 152, 137, 97, 66, 246, 236, 17, 123, 151, 229, 196, 25, 212, 36, 200],
+		[152, 212, 127, 27, 2, 230, 231, 186, 195, 127, 14, 208, 174, 68, 70, 182, 169, 174, 88, 71, 87, 143, 86, 34, 42, 140, 180, 16, 49, 237, 99, 220],
+		[98, 166, 250, 52, 71, 235, 27, 158, 21, 72, 42, 147, 62, 122, 220, 3, 87, 113, 235, 199, 173, 188, 35, 140, 209, 154, 67, 145, 175, 138, 207, 153],
+		[6, 16, 220, 169, 15, 180, 5, 40, 99, 129, 55, 233, 100, 178, 39, 96, 146, 110, 76, 78, 118, 183, 244, 184, 5, 198, 174, 102, 95, 178, 88, 204],
 	],
 	[
-		[254, 103, 95, 175, 74, 211, 48, 64, 38, 56, 112, 232, 186, 108, 172, 96, 71, 74, 55, 182, 114, 94, 109, 235, 158, 168, 83, 59, 244, 141, 37, 5],
-		[107, 138, 199, 249, 10, 17, 155, 232, 15, 74, 105, 159, 156, 217, 103, 195, 209, 205, 72, 15, 250, 59, 126, 60, 227, 66, 45, 135, 144, 181, 5, 161],
-		[82, 200, 9, 12, 209, 238, 17, 241, 109, 71, 68, 36, 198, 80, 190, 86, 82, 118, 135, 146, 93, 125, 2, 3, 222, 65, 200, 134, 220, 223, 146, 0],
-		[11, 156, 159, 226, 31, 174, 229, 189, 153, 75, 111, 240, 41, 7, 232, 150, 95, 59, 46, 76, 242, 92, 152, 219, 191, 54, 96, 163, 239, 112, 238, 87],
+		[53, 57, 170, 128, 19, 121, 244, 69, 52, 79, 122, 40, 82, 124, 18, 61, 191, 186, 27, 80, 176, 174, 209, 126, 155, 112, 192, 199, 84, 157, 140, 80],
+		[125, 136, 59, 144, 178, 163, 161, 26, 247, 159, 214, 242, 187, 213, 237, 33, 36, 255, 75, 23, 165, 190, 45, 80, 249, 159, 154, 249, 119, 75, 205, 236],
+		[251, 143, 206, 8, 101, 196, 8, 118, 82, 157, 149, 196, 38, 141, 12, 189, 85, 99, 246, 14, 208, 130, 171, 158, 70, 221, 186, 193, 20, 87, 143, 239],
+		[219, 237, 86, 30, 27, 48, 38, 226, 27, 109, 195, 23, 123, 97, 66, 190, 4, 3, 2, 26, 116, 67, 93, 96, 21, 239, 155, 103, 113, 235, 35, 175],
 	],
 	[
-		[153, 191, 45, 67, 29, 203, 102, 43, 139, 44, 161, 245, 99, 142, 171, 60, 189, 85, 55, 150, 243, 119, 100, 110, 234, 4, 80, 186, 149, 84, 131, 192],
-		[25, 146, 250, 233, 19, 88, 248, 121, 157, 70, 216, 153, 30, 9, 84, 61, 78, 191, 131, 91, 121, 243, 153, 241, 221, 225, 20, 131, 30, 115, 217, 152],
-		[65, 20, 93, 177, 111, 113, 183, 129, 54, 226, 51, 4, 134, 8, 182, 180, 6, 153, 125, 242, 41, 28, 77, 239, 49, 102, 223, 31, 50, 8, 76, 229],
-		[18, 147, 38, 23, 125, 131, 203, 145, 101, 120, 215, 196, 130, 48, 179, 66, 77, 150, 184, 59, 80, 245, 127, 185, 120, 191, 85, 181, 70, 183, 133, 15],
+		[52, 66, 102, 63, 239, 13, 201, 225, 251, 119, 105, 203, 76, 20, 188, 154, 32, 84, 81, 172, 123, 72, 7, 31, 147, 188, 237, 255, 142, 85, 17, 188],
+		[225, 155, 229, 81, 0, 217, 82, 177, 193, 195, 149, 92, 93, 179, 137, 60, 170, 215, 51, 153, 232, 187, 118, 192, 62, 11, 57, 36, 115, 128, 15, 221],
+		[88, 18, 104, 98, 234, 81, 111, 134, 116, 75, 246, 120, 191, 128, 21, 165, 211, 129, 63, 69, 27, 34, 67, 206, 84, 116, 32, 245, 62, 61, 197, 227],
+		[26, 226, 143, 98, 93, 251, 51, 248, 0, 113, 138, 192, 165, 19, 194, 114, 12, 3, 187, 218, 78, 181, 30, 59, 38, 160, 127, 44, 78, 50, 223, 82],
 	],
 	[
-		[52, 89, 81, 180, 225, 143, 232, 201, 66, 236, 214, 102, 214, 189, 81, 180, 73, 237, 94, 110, 223, 128, 39, 117, 244, 230, 11, 220, 39, 195, 196, 79],
-		[220, 89, 163, 16, 154, 157, 173, 191, 10, 251, 65, 14, 79, 162, 39, 132, 25, 116, 79, 87, 107, 60, 35, 31, 103, 161, 123, 10, 122, 53, 226, 33],
-		[203, 235, 34, 249, 197, 140, 234, 116, 59, 221, 182, 169, 170, 36, 192, 121, 198, 253, 70, 28, 83, 103, 89, 1, 144, 129, 156, 178, 24, 37, 136, 10],
-		[61, 93, 230, 22, 76, 211, 218, 166, 9, 182, 154, 120, 136, 153, 98, 191, 31, 208, 48, 246, 40, 109, 134, 135, 88, 130, 169, 225, 52, 120, 234, 187],
+		[129, 106, 59, 153, 43, 11, 177, 93, 118, 131, 97, 30, 77, 68, 85, 147, 83, 83, 230, 93, 118, 91, 40, 159, 235, 91, 190, 156, 175, 155, 139, 43],
+		[193, 240, 184, 120, 125, 241, 15, 104, 224, 129, 91, 111, 71, 246, 34, 212, 24, 68, 147, 60, 67, 246, 29, 126, 44, 47, 114, 218, 205, 7, 90, 129],
+		[25, 183, 198, 251, 19, 92, 22, 118, 210, 199, 104, 51, 59, 77, 229, 45, 74, 172, 106, 246, 101, 63, 195, 230, 229, 249, 3, 140, 246, 241, 195, 161],
+		[73, 218, 148, 118, 168, 181, 145, 130, 101, 253, 152, 245, 128, 47, 92, 218, 169, 169, 141, 22, 163, 187, 215, 100, 192, 30, 189, 194, 18, 69, 59, 68],
 	],
 	[
-		[9, 66, 37, 151, 62, 32, 248, 131, 13, 131, 28, 22, 99, 123, 4, 225, 144, 163, 96, 241, 40, 120, 59, 159, 170, 253, 18, 28, 12, 225, 172, 184],
-		[35, 218, 154, 252, 225, 150, 113, 101, 179, 11, 218, 72, 123, 192, 36, 63, 160, 223, 148, 227, 207, 206, 33, 97, 152, 64, 251, 110, 23, 104, 254, 113],
-		[138, 253, 134, 177, 175, 225, 109, 127, 141, 121, 249, 186, 216, 174, 62, 83, 102, 144, 47, 176, 225, 117, 30, 93, 51, 221, 37, 172, 71, 126, 110, 183],
-		[24, 180, 73, 178, 81, 140, 140, 76, 95, 70, 223, 164, 53, 39, 72, 3, 78, 227, 192, 128, 20, 162, 95, 53, 207, 178, 143, 149, 134, 98, 93, 188],
+		[132, 46, 133, 117, 146, 9, 4, 2, 220, 115, 126, 1, 103, 201, 156, 94, 177, 200, 193, 1, 33, 89, 244, 174, 249, 16, 3, 252, 46, 25, 214, 25],
+		[66, 92, 183, 99, 196, 157, 74, 217, 5, 184, 242, 95, 162, 139, 177, 8, 81, 156, 244, 101, 43, 194, 225, 24, 245, 186, 58, 165, 226, 205, 228, 13],
+		[214, 125, 87, 170, 66, 41, 179, 70, 234, 7, 8, 6, 136, 71, 82, 38, 95, 214, 170, 26, 87, 169, 235, 110, 97, 81, 203, 144, 114, 6, 29, 195],
+		[194, 61, 55, 66, 124, 94, 148, 146, 192, 11, 50, 130, 189, 155, 244, 90, 53, 143, 70, 56, 163, 241, 115, 230, 97, 54, 229, 55, 174, 100, 126, 129],
 	],
 	[
-		[43, 40, 210, 187, 6, 58, 62, 120, 196, 156, 145, 86, 53, 87, 95, 159, 161, 203, 159, 10, 15, 216, 50, 209, 182, 114, 154, 104, 2, 27, 16, 139],
-		[29, 164, 57, 135, 55, 14, 93, 113, 191, 148, 196, 83, 52, 205, 123, 194, 237, 233, 23, 184, 15, 7, 250, 24, 81, 252, 195, 25, 149, 69, 69, 99],
-		[118, 21, 237, 180, 26, 16, 49, 6, 172, 146, 59, 191, 76, 235, 9, 102, 238, 143, 142, 12, 153, 165, 143, 99, 194, 111, 243, 49, 214, 79, 35, 75],
-		[235, 96, 181, 34, 39, 34, 159, 227, 33, 121, 134, 91, 195, 70, 72, 154, 134, 46, 129, 124, 199, 226, 20, 228, 179, 110, 177, 233, 7, 11, 118, 193],
+		[250, 126, 156, 200, 182, 15, 97, 205, 255, 197, 190, 101, 159, 158, 38, 135, 37, 254, 156, 105, 23, 11, 34, 135, 38, 206, 246, 76, 211, 243, 59, 105],
+		[160, 58, 82, 222, 83, 248, 24, 161, 17, 255, 109, 147, 207, 156, 198, 220, 162, 4, 169, 118, 151, 20, 138, 207, 93, 51, 209, 254, 195, 201, 14, 77],
+		[120, 188, 149, 178, 122, 217, 234, 166, 8, 157, 26, 171, 239, 37, 72, 35, 24, 182, 71, 223, 108, 38, 198, 6, 17, 48, 183, 145, 75, 2, 218, 133],
+		[114, 253, 11, 243, 207, 171, 109, 184, 27, 180, 81, 184, 127, 222, 180, 85, 80, 193, 47, 20, 55, 180, 182, 63, 173, 38, 237, 219, 168, 33, 196, 93],
 	],
 	[
-		[17, 173, 101, 215, 242, 119, 135, 220, 149, 12, 251, 47, 131, 229, 239, 138, 27, 70, 10, 242, 140, 173, 88, 5, 33, 18, 50, 216, 249, 146, 168, 170],
-		[201, 157, 244, 115, 216, 79, 80, 209, 43, 81, 142, 113, 216, 10, 83, 156, 50, 198, 226, 140, 52, 74, 199, 125, 140, 98, 180, 0, 132, 157, 36, 112],
-		[76, 84, 38, 75, 128, 45, 178, 128, 65, 83, 56, 14, 22, 31, 8, 176, 159, 7, 170, 124, 36, 131, 117, 130, 16, 163, 4, 7, 146, 18, 151, 213],
-		[190, 165, 179, 94, 150, 80, 21, 24, 230, 90, 105, 1, 112, 122, 80, 123, 61, 216, 14, 82, 4, 63, 103, 133, 169, 106, 157, 71, 87, 161, 230, 15],
+		[169, 37, 100, 242, 28, 8, 66, 255, 113, 91, 247, 188, 226, 67, 249, 154, 140, 34, 192, 19, 232, 128, 53, 102, 233, 199, 181, 120, 90, 203, 182, 217],
+		[116, 24, 88, 125, 12, 28, 209, 137, 122, 173, 205, 14, 39, 13, 99, 31, 210, 165, 125, 80, 56, 29, 24, 234, 98, 232, 197, 22, 201, 47, 99, 66],
+		[74, 230, 211, 29, 57, 194, 206, 52, 15, 84, 241, 210, 191, 96, 102, 195, 85, 110, 157, 144, 90, 30, 191, 9, 183, 7, 54, 173, 192, 228, 107, 139],
+		[21, 21, 144, 59, 15, 183, 228, 27, 88, 219, 82, 239, 100, 198, 248, 198, 47, 45, 110, 19, 210, 92, 254, 188, 102, 135, 88, 82, 131, 61, 14, 24],
 	],
 	[
-		[123, 14, 0, 199, 140, 80, 109, 134, 208, 13, 127, 22, 94, 17, 50, 143, 188, 249, 209, 49, 94, 36, 209, 116, 248, 14, 222, 170, 89, 69, 217, 149],
-		[200, 98, 81, 127, 184, 41, 133, 15, 67, 12, 179, 255, 218, 83, 83, 225, 34, 126, 49, 106, 5, 59, 94, 253, 14, 163, 139, 162, 143, 175, 88, 89],
-		[74, 236, 98, 204, 255, 240, 199, 142, 195, 29, 78, 81, 15, 21, 245, 213, 33, 155, 61, 206, 28, 234, 10, 238, 155, 95, 14, 67, 23, 172, 225, 136],
-		[191, 158, 101, 96, 98, 17, 218, 21, 168, 50, 44, 40, 45, 116, 0, 177, 153, 222, 210, 128, 59, 80, 99, 243, 54, 119, 182, 194, 21, 175, 29, 170],
+		[180, 128, 123, 187, 59, 167, 49, 176, 197, 43, 153, 28, 126, 148, 13, 66, 251, 121, 167, 172, 217, 34, 170, 39, 54, 182, 181, 51, 174, 35, 85, 224],
+		[115, 212, 70, 16, 116, 213, 53, 255, 163, 226, 124, 36, 235, 171, 155, 66, 148, 200, 129, 235, 152, 191, 26, 136, 159, 158, 58, 51, 241, 40, 141, 219],
+		[157, 38, 14, 140, 157, 249, 100, 252, 27, 120, 84, 72, 109, 10, 214, 76, 137, 24, 114, 67, 53, 159, 22, 77, 101, 42, 57, 26, 185, 134, 43, 211],
+		[6, 114, 4, 123, 198, 238, 149, 187, 95, 144, 76, 21, 103, 187, 199, 172, 233, 37, 116, 23, 165, 58, 246, 57, 42, 14, 216, 5, 136, 221, 16, 58],
 	],
 	[
-		[246, 14, 247, 90, 167, 52, 6, 58, 2, 17, 137, 49, 89, 90, 167, 19, 50, 54, 228, 104, 114, 64, 34, 225, 138, 248, 3, 163, 87, 44, 9, 31],
-		[163, 37, 30, 212, 70, 11, 184, 235, 128, 181, 34, 91, 214, 88, 165, 31, 245, 53, 142, 218, 89, 155, 220, 164, 208, 253, 78, 74, 178, 139, 41, 224],
-		[119, 10, 142, 136, 6, 147, 253, 72, 126, 13, 70, 194, 188, 111, 220, 194, 184, 182, 159, 56, 64, 144, 128, 39, 27, 22, 10, 34, 205, 77, 8, 163],
-		[75, 38, 116, 1, 29, 71, 2, 20, 229, 79, 254, 192, 52, 221, 39, 177, 167, 124, 11, 193, 126, 234, 169, 228, 181, 244, 235, 127, 223, 162, 153, 43],
+		[168, 196, 135, 155, 73, 190, 171, 65, 163, 36, 163, 6, 65, 70, 90, 4, 166, 140, 218, 134, 32, 148, 147, 124, 226, 13, 241, 234, 75, 152, 227, 185],
+		[149, 198, 215, 152, 64, 193, 6, 96, 157, 59, 63, 170, 52, 76, 99, 208, 157, 24, 87, 23, 177, 192, 90, 191, 34, 69, 241, 15, 33, 89, 111, 166],
+		[132, 254, 96, 167, 162, 165, 196, 212, 43, 225, 54, 24, 187, 157, 183, 171, 100, 45, 1, 31, 129, 17, 165, 99, 79, 237, 116, 39, 170, 42, 234, 78],
+		[166, 241, 36, 172, 71, 51, 51, 131, 58, 128, 252, 1, 102, 62, 24, 157, 89, 218, 60, 12, 175, 249, 100, 193, 88, 64, 96, 229, 139, 76, 176, 168],
 	],
 	[
-		[159, 115, 241, 140, 202, 191, 90, 46, 31, 23, 58, 252, 164, 1, 162, 16, 142, 73, 237, 40, 60, 195, 23, 251, 179, 46, 245, 163, 45, 247, 161, 202],
-		[98, 159, 1, 160, 69, 173, 95, 146, 150, 238, 7, 135, 201, 216, 69, 175, 152, 235, 192, 146, 14, 183, 254, 187, 54, 68, 93, 5, 107, 39, 189, 191],
-		[150, 208, 120, 201, 121, 159, 135, 106, 246, 224, 118, 146, 210, 34, 194, 64, 35, 101, 47, 152, 159, 22, 200, 26, 216, 99, 222, 127, 75, 193, 221, 63],
-		[122, 93, 208, 8, 158, 223, 134, 102, 26, 78, 147, 17, 210, 131, 141, 135, 39, 49, 234, 106, 56, 129, 242, 67, 142, 49, 174, 77, 147, 242, 115, 207],
+		[167, 48, 203, 47, 109, 163, 147, 127, 1, 137, 195, 76, 115, 128, 56, 254, 134, 204, 213, 88, 17, 224, 243, 12, 153, 190, 105, 103, 198, 8, 145, 159],
+		[69, 67, 40, 221, 70, 225, 181, 167, 201, 145, 49, 62, 122, 98, 233, 14, 223, 30, 139, 196, 170, 138, 95, 251, 135, 41, 11, 221, 154, 229, 223, 175],
+		[117, 230, 255, 143, 143, 62, 217, 53, 79, 207, 48, 6, 129, 145, 250, 96, 99, 94, 142, 182, 166, 103, 166, 27, 228, 226, 253, 82, 173, 30, 55, 225],
+		[47, 175, 50, 185, 41, 14, 224, 95, 133, 95, 54, 152, 15, 67, 32, 225, 151, 29, 87, 89, 12, 168, 195, 40, 34, 0, 171, 77, 63, 230, 254, 30],
 	],
 	[
-		[38, 62, 182, 120, 82, 184, 168, 96, 212, 255, 125, 136, 93, 45, 100, 95, 157, 51, 173, 237, 217, 14, 80, 254, 160, 66, 48, 155, 236, 132, 231, 112],
-		[201, 14, 74, 216, 10, 78, 59, 38, 188, 106, 67, 46, 95, 58, 235, 9, 7, 15, 206, 254, 142, 139, 119, 45, 163, 35, 9, 5, 162, 140, 136, 236],
-		[170, 206, 157, 99, 191, 73, 4, 87, 181, 192, 79, 87, 85, 235, 81, 213, 88, 138, 140, 63, 5, 57, 143, 116, 45, 138, 160, 28, 199, 139, 90, 2],
-		[106, 2, 170, 122, 219, 49, 131, 11, 123, 147, 94, 94, 76, 20, 26, 51, 239, 169, 72, 39, 187, 171, 55, 47, 246, 147, 241, 135, 1, 152, 211, 17],
+		[24, 3, 231, 43, 231, 234, 1, 169, 8, 42, 108, 18, 140, 52, 167, 219, 228, 203, 25, 164, 51, 182, 38, 206, 228, 245, 194, 174, 144, 30, 211, 234],
+		[197, 89, 236, 220, 189, 174, 106, 13, 41, 229, 27, 180, 68, 141, 154, 242, 29, 189, 120, 7, 150, 53, 234, 21, 219, 18, 33, 75, 174, 162, 180, 29],
+		[67, 247, 40, 129, 32, 39, 199, 82, 104, 200, 120, 136, 21, 179, 2, 79, 179, 125, 169, 102, 159, 63, 226, 80, 178, 212, 3, 9, 255, 160, 162, 100],
+		[254, 42, 135, 154, 20, 211, 251, 180, 193, 99, 127, 243, 187, 42, 104, 183, 187, 133, 213, 250, 93, 55, 213, 16, 230, 97, 80, 61, 161, 201, 38, 183],
 	],
 	[
-		[5, 14, 243, 139, 214, 249, 80, 78, 140, 245, 4, 112, 242, 151, 104, 212, 211, 158, 87, 112, 236, 195, 218, 144, 148, 221, 239, 137, 190, 5, 156, 20],
-		[145, 194, 114, 127, 113, 114, 228, 205, 60, 126, 64, 50, 196, 103, 104, 230, 68, 42, 81, 98, 207, 210, 164, 254, 157, 123, 205, 245, 142, 60, 129, 49],
-		[221, 248, 220, 185, 170, 99, 96, 55, 251, 94, 175, 90, 221, 186, 210, 22, 238, 67, 234, 119, 181, 121, 125, 124, 57, 242, 153, 254, 145, 207, 217, 235],
-		[25, 118, 11, 134, 154, 189, 177, 11, 4, 163, 165, 192, 57, 138, 40, 168, 210, 119, 197, 202, 10, 28, 30, 55, 25, 201, 156, 136, 7, 87, 172, 219],
+		[66, 105, 199, 109, 171, 172, 27, 52, 47, 26, 201, 208, 185, 240, 219, 66, 77, 141, 113, 53, 106, 244, 66, 158, 231, 24, 5, 147, 13, 53, 55, 50],
+		[127, 167, 200, 118, 135, 228, 236, 202, 115, 219, 82, 63, 223, 148, 253, 171, 177, 24, 181, 215, 1, 14, 113, 155, 44, 116, 128, 153, 31, 137, 126, 238],
+		[197, 154, 21, 136, 77, 173, 85, 45, 181, 41, 45, 0, 25, 224, 51, 111, 203, 104, 214, 67, 69, 129, 16, 242, 150, 203, 162, 3, 73, 25, 4, 21],
+		[152, 199, 163, 245, 159, 213, 159, 169, 188, 231, 252, 44, 141, 226, 170, 69, 39, 11, 224, 127, 29, 221, 186, 167, 113, 61, 172, 30, 1, 196, 3, 100],
 	],
 	[
-		[251, 193, 85, 118, 192, 111, 238, 222, 251, 193, 250, 198, 227, 104, 202, 200, 99, 47, 187, 4, 144, 255, 149, 41, 244, 144, 222, 120, 121, 250, 67, 138],
-		[25, 194, 80, 70, 247, 24, 10, 153, 204, 155, 21, 232, 219, 8, 45, 228, 43, 135, 25, 185, 190, 228, 123, 40, 89, 9, 124, 205, 7, 102, 48, 211],
-		[164, 219, 230, 162, 155, 245, 186, 23, 2, 13, 129, 208, 70, 43, 9, 205, 91, 146, 31, 193, 61, 194, 84, 41, 239, 247, 27, 253, 74, 112, 8, 11],
-		[110, 149, 59, 248, 78, 182, 140, 249, 8, 221, 102, 243, 35, 139, 193, 235, 129, 225, 131, 241, 252, 79, 153, 0, 44, 34, 3, 60, 104, 53, 28, 102],
+		[114, 116, 206, 133, 126, 79, 86, 200, 120, 18, 164, 242, 115, 62, 188, 65, 217, 60, 11, 183, 10, 139, 146, 142, 151, 220, 181, 103, 152, 102, 19, 205],
+		[216, 65, 13, 166, 122, 221, 65, 202, 146, 171, 72, 98, 39, 193, 177, 141, 176, 31, 182, 242, 140, 97, 12, 15, 46, 37, 61, 217, 142, 0, 140, 232],
+		[50, 70, 15, 184, 73, 216, 219, 219, 168, 101, 166, 167, 14, 105, 212, 186, 189, 0, 112, 48, 112, 204, 99, 171, 255, 5, 246, 122, 4, 151, 213, 97],
+		[160, 109, 32, 59, 1, 212, 186, 163, 169, 79, 47, 133, 254, 37, 220, 12, 160, 77, 80, 82, 252, 230, 188, 8, 167, 234, 115, 63, 133, 0, 151, 46],
 	],
 	[
-		[229, 50, 131, 193, 227, 2, 134, 212, 97, 235, 92, 194, 61, 199, 78, 127, 27, 42, 53, 35, 220, 91, 170, 43, 98, 251, 220, 144, 186, 138, 201, 210],
-		[144, 176, 151, 152, 149, 247, 187, 104, 102, 133, 144, 30, 81, 241, 116, 14, 221, 145, 68, 101, 86, 191, 127, 161, 46, 161, 165, 244, 5, 209, 248, 148],
-		[255, 65, 148, 60, 77, 65, 72, 2, 121, 50, 148, 100, 142, 170, 208, 167, 8, 53, 170, 31, 116, 69, 186, 233, 44, 96, 133, 25, 239, 146, 229, 85],
-		[15, 220, 44, 153, 151, 252, 134, 9, 111, 162, 76, 137, 188, 198, 129, 122, 78, 238, 146, 176, 148, 3, 205, 78, 124, 113, 62, 17, 220, 180, 38, 143],
+		[194, 22, 102, 8, 198, 243, 150, 115, 207, 54, 51, 185, 241, 167, 140, 4, 246, 224, 81, 152, 57, 15, 141, 172, 24, 38, 60, 119, 81, 144, 174, 175],
+		[152, 10, 243, 219, 106, 180, 243, 103, 145, 72, 239, 230, 120, 230, 225, 11, 11, 121, 42, 212, 11, 168, 245, 249, 41, 234, 38, 62, 48, 90, 45, 163],
+		[225, 178, 182, 144, 193, 125, 76, 153, 226, 179, 230, 38, 230, 245, 210, 154, 138, 17, 45, 109, 37, 15, 127, 177, 253, 189, 115, 66, 164, 2, 229, 188],
+		[241, 193, 192, 205, 78, 208, 242, 115, 194, 77, 148, 232, 220, 20, 218, 136, 75, 60, 148, 200, 22, 11, 25, 12, 74, 232, 20, 192, 113, 27, 248, 96],
 	],
 	[
-		[253, 89, 109, 202, 204, 7, 58, 204, 230, 224, 192, 38, 9, 2, 179, 0, 183, 18, 72, 90, 179, 132, 20, 68, 182, 3, 121, 111, 157, 32, 243, 17],
-		[47, 63, 31, 109, 102, 99, 172, 21, 98, 70, 127, 41, 168, 125, 117, 236, 165, 248, 97, 232, 202, 79, 192, 47, 114, 233, 85, 207, 157, 145, 218, 219],
-		[187, 181, 112, 191, 101, 157, 149, 42, 172, 253, 91, 90, 153, 76, 71, 27, 60, 214, 33, 37, 119, 90, 153, 128, 57, 45, 107, 91, 251, 91, 158, 14],
-		[130, 156, 226, 24, 67, 158, 193, 86, 159, 125, 225, 203, 147, 222, 221, 210, 77, 98, 93, 160, 119, 156, 87, 2, 171, 75, 156, 218, 119, 122, 133, 185],
+		[84, 55, 115, 65, 170, 251, 68, 43, 30, 82, 24, 113, 64, 247, 65, 99, 13, 109, 202, 39, 42, 190, 24, 231, 218, 113, 106, 119, 180, 148, 90, 189],
+		[25, 107, 159, 5, 29, 184, 151, 246, 156, 171, 165, 138, 216, 178, 11, 146, 71, 129, 212, 175, 125, 239, 232, 200, 203, 113, 249, 32, 249, 217, 35, 17],
+		[56, 194, 65, 22, 7, 133, 208, 124, 216, 50, 230, 247, 54, 15, 88, 119, 208, 36, 125, 255, 56, 188, 26, 182, 218, 117, 208, 166, 39, 85, 41, 92],
+		[37, 134, 202, 4, 253, 194, 170, 43, 142, 255, 220, 13, 252, 13, 189, 228, 207, 99, 84, 97, 45, 41, 208, 52, 204, 232, 59, 17, 15, 73, 240, 202],
 	],
 	[
-		[14, 146, 83, 113, 210, 224, 146, 97, 58, 231, 224, 32, 77, 189, 195, 79, 201, 209, 223, 30, 99, 66, 47, 56, 131, 60, 40, 87, 230, 202, 31, 148],
-		[208, 230, 183, 53, 103, 62, 144, 181, 131, 142, 74, 232, 94, 226, 98, 57, 198, 162, 100, 36, 227, 125, 87, 196, 210, 162, 31, 128, 215, 118, 218, 39],
-		[22, 87, 244, 219, 30, 7, 220, 215, 10, 62, 216, 127, 36, 141, 246, 64, 218, 200, 72, 120, 245, 94, 76, 120, 9, 129, 169, 188, 106, 27, 236, 11],
-		[237, 129, 162, 90, 213, 79, 233, 221, 59, 156, 182, 3, 93, 205, 162, 57, 240, 2, 224, 181, 146, 30, 253, 52, 181, 127, 201, 255, 199, 156, 75, 202],
+		[152, 14, 88, 201, 18, 2, 197, 146, 80, 157, 165, 143, 59, 170, 38, 199, 221, 144, 68, 29, 22, 27, 245, 100, 72, 214, 205, 88, 115, 167, 132, 79],
+		[48, 174, 141, 231, 98, 163, 109, 207, 89, 34, 3, 175, 90, 88, 12, 139, 75, 142, 7, 87, 53, 108, 1, 114, 248, 171, 180, 138, 3, 17, 42, 207],
+		[101, 118, 228, 227, 54, 119, 253, 168, 13, 60, 251, 175, 13, 96, 9, 222, 2, 123, 247, 38, 72, 208, 32, 78, 216, 122, 25, 217, 236, 171, 224, 162],
+		[178, 136, 222, 3, 26, 147, 173, 252, 112, 2, 127, 113, 95, 44, 53, 129, 129, 253, 248, 41, 230, 242, 101, 143, 153, 254, 240, 62, 14, 49, 32, 70],
 	],
 	[
-		[64, 134, 27, 149, 57, 191, 110, 88, 42, 188, 240, 235, 195, 78, 95, 53, 36, 142, 197, 205, 29, 95, 78, 17, 45, 18, 218, 206, 12, 93, 242, 34],
-		[20, 54, 94, 66, 36, 87, 13, 92, 104, 166, 66, 219, 93, 19, 167, 200, 175, 124, 157, 31, 116, 198, 119, 100, 93, 159, 140, 107, 191, 62, 128, 175],
-		[163, 174, 165, 231, 36, 135, 231, 238, 202, 157, 255, 127, 133, 7, 181, 176, 222, 134, 140, 0, 103, 209, 95, 40, 1, 128, 187, 148, 27, 61, 171, 210],
-		[222, 141, 0, 67, 101, 135, 99, 67, 62, 58, 100, 96, 197, 136, 2, 86, 234, 51, 94, 10, 230, 224, 105, 97, 73, 197, 195, 109, 101, 11, 58, 170],
+		[66, 27, 41, 234, 210, 113, 134, 255, 167, 135, 215, 198, 119, 94, 44, 219, 44, 238, 25, 95, 0, 86, 112, 56, 97, 174, 9, 68, 130, 196, 213, 73],
+		[241, 152, 237, 118, 187, 245, 121, 126, 192, 18, 110, 8, 56, 8, 112, 232, 60, 93, 56, 42, 164, 234, 162, 63, 25, 85, 18, 158, 217, 226, 79, 1],
+		[219, 162, 231, 229, 142, 168, 79, 4, 130, 30, 252, 48, 69, 230, 134, 126, 95, 139, 89, 64, 81, 105, 11, 49, 152, 113, 106, 186, 9, 159, 61, 44],
+		[109, 34, 23, 87, 139, 27, 172, 218, 215, 138, 148, 106, 129, 80, 219, 108, 187, 49, 23, 36, 26, 89, 27, 123, 3, 44, 66, 19, 142, 162, 74, 87],
 	],
 	[
-		[198, 90, 244, 157, 157, 108, 59, 205, 231, 85, 76, 146, 172, 252, 70, 253, 220, 95, 227, 97, 231, 174, 143, 245, 192, 94, 42, 104, 253, 127, 66, 167],
-		[139, 14, 252, 118, 176, 197, 43, 231, 18, 110, 51, 8, 130, 130, 122, 43, 202, 101, 158, 172, 188, 199, 149, 49, 128, 4, 193, 47, 61, 254, 171, 203],
-		[84, 213, 134, 172, 160, 137, 226, 151, 136, 67, 202, 239, 206, 171, 106, 132, 53, 111, 76, 177, 216, 2, 68, 51, 103, 174, 26, 95, 227, 83, 40, 37],
-		[176, 43, 138, 14, 233, 46, 53, 214, 155, 11, 179, 70, 166, 198, 172, 140, 162, 204, 173, 153, 27, 64, 88, 211, 210, 44, 12, 37, 123, 72, 82, 161],
+		[7, 62, 167, 226, 7, 92, 250, 31, 87, 143, 49, 141, 247, 95, 189, 147, 126, 182, 211, 54, 113, 6, 200, 236, 234, 164, 21, 65, 128, 74, 197, 111],
+		[5, 90, 54, 101, 201, 138, 183, 227, 207, 50, 63, 135, 94, 140, 23, 151, 166, 118, 85, 188, 183, 122, 94, 236, 45, 246, 185, 110, 237, 7, 86, 38],
+		[188, 245, 246, 105, 117, 154, 122, 176, 172, 128, 123, 247, 151, 251, 189, 61, 247, 253, 39, 205, 55, 241, 170, 38, 81, 199, 199, 86, 174, 66, 252, 42],
+		[129, 157, 250, 55, 164, 13, 209, 135, 36, 85, 226, 7, 198, 55, 132, 247, 233, 136, 41, 41, 5, 152, 240, 86, 250, 226, 32, 96, 236, 254, 144, 28],
 	],
 	[
-		[86, 165, 48, 176, 184, 219, 137, 199, 146, 212, 218, 126, 11, 159, 74, 96, 161, 132, 114, 236, 204, 108, 124, 60, 205, 243, 149, 24, 199, 10, 251, 90],
-		[47, 46, 225, 135, 103, 141, 23, 130, 95, 204, 120, 43, 31, 242, 27, 136, 131, 229, 48, 20, 44, 184, 59, 66, 230, 48, 166, 235, 237, 90, 3, 76],
-		[84, 113, 217, 5, 37, 36, 92, 153, 51, 90, 241, 181, 60, 206, 134, 205, 179, 161, 243, 14, 149, 150, 90, 229, 198, 234, 176, 223, 133, 34, 121, 48],
-		[49, 236, 34, 170, 93, 207, 45, 105, 31, 16, 126, 45, 31, 179, 69, 2, 110, 173, 157, 203, 207, 4, 13, 193, 173, 79, 33, 119, 35, 24, 249, 197],
+		[136, 0, 176, 63, 211, 3, 105, 69, 205, 249, 219, 117, 3, 147, 10, 219, 181, 234, 16, 128, 7, 197, 105, 11, 134, 38, 83, 138, 220, 112, 108, 100],
+		[53, 38, 235, 194, 68, 54, 212, 188, 223, 180, 123, 54, 11, 89, 156, 88, 142, 13, 15, 234, 147, 148, 151, 212, 254, 27, 131, 81, 177, 91, 4, 113],
+		[166, 35, 232, 103, 248, 207, 154, 212, 216, 145, 212, 149, 19, 228, 161, 229, 255, 80, 247, 153, 4, 91, 99, 245, 63, 226, 103, 169, 203, 244, 117, 91],
+		[19, 132, 217, 217, 85, 192, 157, 116, 172, 156, 188, 241, 149, 219, 122, 228, 187, 108, 66, 171, 187, 28, 225, 148, 33, 205, 181, 179, 90, 118, 233, 216],
 	],
 	[
-		[223, 93, 202, 0, 3, 3, 11, 123, 71, 179, 94, 196, 92, 82, 36, 248, 222, 134, 48, 203, 252, 232, 205, 230, 142, 9, 44, 104, 24, 7, 134, 115],
-		[234, 123, 163, 154, 200, 203, 161, 88, 134, 147, 57, 179, 104, 198, 141, 1, 255, 66, 5, 57, 15, 87, 15, 62, 48, 136, 175, 50, 121, 223, 246, 205],
-		[66, 216, 244, 1, 39, 253, 10, 41, 49, 220, 250, 66, 199, 25, 43, 165, 210, 40, 185, 151, 132, 152, 127, 47, 104, 33, 77, 74, 201, 143, 29, 41],
-		[167, 163, 98, 52, 246, 199, 47, 158, 102, 44, 57, 146, 22, 136, 24, 5, 220, 148, 186, 48, 68, 79, 185, 16, 61, 129, 189, 216, 84, 174, 84, 19],
+		[219, 177, 166, 180, 38, 47, 253, 127, 83, 118, 222, 63, 49, 36, 32, 39, 39, 1, 183, 205, 195, 91, 70, 222, 9, 122, 37, 148, 135, 125, 218, 131],
+		[214, 230, 245, 86, 247, 239, 137, 77, 239, 91, 124, 222, 102, 14, 186, 193, 216, 255, 219, 166, 229, 229, 105, 195, 96, 147, 202, 244, 85, 51, 77, 187],
+		[136, 96, 99, 133, 22, 155, 165, 60, 21, 96, 38, 67, 121, 196, 239, 139, 214, 79, 0, 66, 152, 7, 156, 244, 161, 1, 151, 76, 166, 43, 134, 0],
+		[80, 166, 132, 232, 234, 225, 47, 98, 11, 203, 76, 180, 71, 104, 186, 215, 53, 206, 120, 96, 89, 77, 27, 133, 165, 148, 68, 86, 127, 57, 191, 24],
 	],
 	[
-		[201, 97, 46, 89, 232, 243, 24, 45, 136, 54, 105, 56, 52, 49, 57, 79, 199, 75, 216, 31, 230, 218, 153, 64, 11, 18, 44, 65, 85, 159, 246, 40],
-		[79, 29, 4, 38, 121, 84, 40, 228, 177, 82, 62, 211, 85, 13, 172, 59, 179, 64, 141, 66, 6, 238, 142, 176, 153, 96, 56, 116, 48, 255, 56, 0],
-		[232, 43, 176, 132, 116, 153, 140, 246, 225, 94, 69, 61, 236, 241, 69, 92, 218, 113, 39, 242, 10, 69, 164, 81, 156, 60, 57, 106, 61, 82, 108, 162],
-		[233, 179, 132, 137, 102, 11, 110, 41, 193, 94, 123, 26, 249, 171, 105, 242, 210, 242, 12, 226, 5, 3, 99, 238, 184, 73, 159, 246, 74, 105, 133, 89],
+		[15, 13, 125, 52, 245, 253, 49, 134, 243, 3, 218, 122, 251, 83, 186, 171, 82, 37, 225, 51, 105, 13, 28, 173, 79, 123, 194, 234, 165, 61, 129, 49],
+		[157, 94, 80, 252, 191, 210, 79, 214, 235, 95, 244, 68, 34, 36, 39, 20, 75, 94, 31, 63, 22, 202, 158, 205, 226, 15, 75, 251, 250, 169, 217, 147],
+		[163, 37, 177, 119, 162, 221, 71, 10, 236, 59, 28, 7, 231, 142, 118, 206, 243, 18, 246, 93, 17, 0, 154, 248, 113, 244, 130, 78, 105, 185, 227, 238],
+		[243, 245, 57, 110, 56, 95, 47, 76, 205, 116, 214, 89, 103, 50, 207, 216, 24, 12, 98, 227, 32, 39, 106, 156, 128, 100, 133, 189, 168, 163, 117, 137],
 	],
 	[
-		[162, 4, 194, 101, 32, 10, 31, 198, 221, 145, 135, 230, 196, 140, 90, 32, 149, 7, 23, 220, 73, 174, 33, 208, 123, 230, 115, 139, 67, 60, 169, 217],
-		[103, 158, 54, 60, 161, 43, 171, 232, 153, 106, 11, 163, 3, 67, 176, 127, 86, 153, 85, 151, 217, 65, 159, 17, 143, 165, 21, 64, 122, 24, 205, 29],
-		[125, 252, 37, 139, 183, 188, 91, 14, 15, 11, 35, 18, 209, 44, 178, 243, 73, 111, 15, 120, 250, 244, 3, 203, 127, 196, 78, 185, 94, 108, 22, 202],
-		[71, 10, 247, 185, 42, 94, 25, 110, 142, 95, 238, 81, 68, 151, 36, 21, 84, 141, 203, 102, 34, 88, 10, 70, 136, 14, 29, 172, 165, 33, 187, 70],
+		[27, 79, 189, 23, 19, 8, 217, 114, 147, 180, 26, 236, 46, 111, 100, 104, 112, 101, 59, 14, 186, 114, 59, 128, 21, 75, 41, 145, 138, 97, 95, 114],
+		[150, 123, 149, 42, 12, 107, 135, 227, 18, 174, 211, 37, 226, 64, 181, 13, 61, 179, 86, 52, 173, 201, 115, 68, 32, 229, 195, 114, 82, 78, 154, 16],
+		[196, 63, 197, 21, 190, 66, 99, 188, 45, 236, 58, 165, 179, 253, 88, 172, 70, 223, 83, 182, 71, 114, 100, 61, 99, 187, 226, 108, 48, 39, 180, 35],
+		[28, 203, 67, 248, 19, 128, 97, 42, 0, 133, 105, 228, 38, 173, 102, 11, 82, 58, 38, 10, 183, 95, 255, 19, 220, 78, 220, 66, 247, 182, 176, 209],
 	],
 	[
-		[181, 64, 158, 111, 34, 204, 15, 149, 218, 140, 43, 151, 46, 134, 160, 97, 50, 34, 178, 23, 21, 242, 212, 168, 197, 174, 141, 164, 186, 212, 212, 108],
-		[89, 128, 198, 27, 255, 237, 38, 217, 197, 57, 255, 37, 194, 230, 77, 72, 76, 222, 187, 140, 83, 196, 233, 246, 198, 77, 45, 32, 142, 42, 254, 193],
-		[203, 111, 114, 189, 151, 17, 156, 80, 34, 95, 76, 52, 199, 176, 36, 1, 37, 180, 33, 211, 150, 37, 167, 20, 7, 154, 211, 128, 210, 213, 160, 19],
-		[241, 97, 164, 107, 231, 148, 171, 40, 172, 108, 134, 152, 16, 82, 139, 174, 182, 91, 136, 211, 6, 202, 202, 167, 167, 115, 0, 238, 40, 171, 159, 147],
+		[142, 148, 243, 89, 114, 187, 30, 41, 53, 75, 235, 25, 38, 80, 130, 13, 197, 145, 27, 135, 235, 228, 40, 19, 85, 42, 19, 116, 49, 114, 96, 36],
+		[228, 38, 241, 245, 162, 155, 238, 199, 148, 232, 209, 74, 250, 109, 151, 119, 247, 56, 222, 67, 194, 206, 53, 12, 179, 117, 108, 168, 7, 65, 252, 151],
+		[244, 249, 223, 95, 58, 47, 188, 110, 97, 15, 56, 110, 243, 24, 139, 32, 109, 161, 173, 242, 94, 153, 226, 49, 23, 218, 35, 93, 124, 227, 154, 38],
+		[206, 156, 130, 13, 199, 229, 123, 82, 104, 137, 161, 0, 37, 120, 178, 83, 8, 234, 97, 231, 197, 146, 92, 239, 65, 169, 2, 35, 236, 148, 174, 138],
 	],
 	[
-		[51, 118, 175, 21, 89, 217, 111, 26, 179, 210, 236, 2, 91, 37, 209, 9, 242, 89, 30, 5, 240, 247, 197, 148, 79, 82, 183, 16, 43, 151, 79, 142],
-		[98, 28, 146, 232, 198, 182, 96, 45, 244, 169, 64, 115, 17, 127, 172, 1, 71, 126, 119, 173, 102, 157, 39, 52, 225, 244, 220, 198, 114, 158, 221, 194],
-		[111, 49, 66, 92, 109, 6, 191, 15, 199, 133, 33, 169, 193, 32, 251, 113, 101, 145, 18, 151, 115, 68, 176, 5, 105, 43, 240, 111, 199, 168, 223, 114],
-		[248, 0, 81, 157, 0, 197, 243, 179, 22, 57, 158, 138, 39, 226, 181, 215, 77, 214, 209, 74, 239, 97, 193, 136, 83, 255, 237, 8, 239, 14, 173, 41],
+		[63, 68, 114, 77, 11, 138, 128, 249, 210, 29, 196, 76, 177, 230, 96, 165, 151, 17, 48, 23, 247, 232, 121, 241, 93, 174, 128, 65, 60, 44, 109, 29],
+		[159, 45, 164, 106, 184, 229, 206, 180, 39, 40, 198, 43, 244, 53, 124, 71, 182, 127, 61, 235, 176, 135, 145, 45, 183, 198, 221, 80, 141, 142, 35, 72],
+		[69, 150, 88, 96, 239, 122, 150, 50, 121, 87, 35, 4, 166, 192, 143, 172, 241, 93, 248, 194, 53, 228, 236, 164, 155, 63, 169, 55, 98, 103, 158, 16],
+		[39, 109, 42, 250, 110, 130, 74, 229, 229, 227, 179, 24, 95, 18, 220, 182, 181, 129, 121, 169, 66, 189, 120, 9, 92, 107, 148, 155, 109, 58, 140, 37],
 	],
 	[
-		[28, 194, 163, 185, 213, 134, 48, 242, 89, 217, 112, 86, 101, 38, 191, 87, 173, 115, 21, 28, 193, 14, 167, 225, 170, 67, 155, 116, 152, 226, 227, 101],
-		[58, 185, 130, 2, 131, 107, 178, 103, 150, 253, 130, 13, 129, 19, 214, 141, 10, 31, 174, 120, 140, 56, 81, 204, 191, 23, 230, 127, 200, 180, 17, 39],
-		[79, 55, 34, 149, 210, 74, 246, 28, 25, 142, 228, 65, 161, 99, 200, 214, 255, 225, 40, 151, 146, 18, 23, 46, 242, 205, 125, 252, 171, 63, 1, 87],
-		[98, 158, 38, 130, 205, 6, 75, 183, 156, 180, 25, 160, 132, 173, 219, 65, 71, 54, 137, 184, 82, 151, 9, 87, 79, 180, 31, 228, 13, 153, 103, 212],
+		[157, 208, 50, 135, 160, 47, 197, 128, 32, 24, 46, 103, 223, 182, 92, 123, 78, 124, 233, 8, 167, 16, 166, 1, 239, 6, 90, 63, 66, 6, 189, 206],
+		[223, 85, 50, 67, 182, 60, 28, 38, 33, 194, 164, 31, 200, 240, 86, 27, 17, 171, 138, 161, 253, 197, 49, 152, 211, 247, 7, 160, 130, 46, 179, 42],
+		[177, 87, 112, 136, 18, 200, 222, 166, 247, 31, 137, 64, 234, 172, 228, 89, 57, 166, 130, 56, 78, 96, 205, 54, 245, 145, 50, 25, 124, 184, 112, 165],
+		[23, 50, 186, 56, 141, 40, 95, 23, 141, 192, 252, 247, 40, 33, 43, 101, 167, 77, 112, 60, 205, 84, 78, 130, 117, 38, 208, 159, 57, 22, 71, 163],
 	],
 	[
-		[123, 29, 114, 138, 159, 147, 14, 191, 13, 86, 134, 175, 161, 251, 192, 8, 215, 7, 162, 186, 113, 218, 85, 146, 205, 25, 130, 175, 103, 4, 216, 89],
-		[77, 236, 91, 58, 152, 5, 88, 226, 177, 136, 157, 25, 214, 181, 91, 95, 67, 223, 140, 103, 104, 42, 82, 166, 71, 108, 188, 157, 14, 55, 123, 46],
-		[17, 116, 62, 29, 26, 139, 132, 183, 216, 181, 115, 28, 220, 209, 255, 11, 245, 29, 98, 14, 98, 74, 202, 39, 17, 12, 94, 177, 104, 36, 166, 150],
-		[18, 244, 101, 39, 224, 103, 94, 16, 202, 193, 99, 99, 121, 208, 137, 69, 223, 248, 175, 119, 175, 87, 72, 92, 51, 211, 173, 209, 213, 5, 237, 7],
+		[165, 171, 212, 185, 184, 15, 48, 40, 235, 105, 215, 15, 6, 222, 223, 165, 196, 137, 55, 153, 11, 204, 240, 83, 95, 242, 60, 145, 95, 200, 53, 168],
+		[87, 114, 141, 205, 175, 196, 86, 3, 89, 122, 116, 125, 253, 235, 158, 173, 11, 28, 162, 114, 39, 106, 38, 39, 232, 44, 206, 93, 43, 32, 171, 194],
+		[4, 103, 92, 191, 21, 215, 133, 49, 250, 4, 163, 230, 10, 99, 227, 84, 60, 160, 21, 33, 19, 117, 79, 37, 134, 191, 209, 32, 226, 5, 169, 158],
+		[194, 116, 36, 195, 35, 26, 139, 214, 78, 208, 174, 55, 166, 106, 131, 223, 153, 10, 143, 81, 199, 33, 254, 173, 143, 146, 120, 110, 238, 77, 60, 14],
 	],
 	[
-		[161, 97, 24, 218, 119, 28, 136, 110, 51, 158, 87, 211, 104, 24, 82, 124, 95, 137, 77, 76, 119, 78, 143, 81, 11, 69, 130, 87, 109, 11, 97, 231],
-		[65, 15, 196, 229, 131, 231, 181, 221, 2, 116, 45, 136, 235, 102, 145, 127, 83, 9, 255, 11, 187, 185, 193, 234, 63, 15, 60, 12, 196, 70, 3, 30],
-		[196, 138, 116, 68, 66, 188, 7, 67, 168, 107, 253, 64, 26, 79, 112, 151, 241, 203, 229, 219, 31, 166, 16, 196, 71, 46, 188, 248, 160, 80, 11, 57],
-		[1, 20, 189, 250, 98, 27, 194, 85, 45, 89, 73, 39, 248, 83, 180, 84, 181, 46, 49, 235, 210, 97, 199, 7, 109, 50, 184, 198, 113, 158, 240, 182],
+		[125, 204, 244, 231, 217, 212, 104, 100, 5, 208, 97, 184, 168, 79, 167, 96, 212, 164, 62, 3, 218, 183, 96, 167, 27, 193, 124, 161, 240, 141, 63, 146],
+		[167, 41, 82, 98, 121, 223, 204, 138, 248, 226, 60, 49, 197, 174, 253, 225, 78, 9, 89, 67, 161, 18, 59, 231, 184, 251, 31, 222, 60, 123, 180, 53],
+		[139, 187, 22, 44, 115, 151, 97, 104, 163, 220, 28, 176, 155, 131, 248, 49, 242, 66, 64, 246, 122, 243, 42, 218, 118, 140, 241, 28, 56, 138, 43, 145],
+		[54, 177, 183, 223, 24, 167, 253, 157, 187, 53, 89, 161, 67, 124, 78, 183, 20, 178, 168, 95, 89, 89, 235, 218, 157, 92, 100, 74, 229, 207, 180, 216],
 	],
 	[
-		[115, 189, 66, 92, 171, 32, 251, 208, 148, 164, 167, 182, 34, 116, 151, 39, 112, 93, 171, 233, 145, 210, 74, 147, 178, 176, 37, 211, 167, 149, 100, 13],
-		[154, 252, 135, 208, 49, 39, 143, 35, 15, 72, 191, 25, 138, 164, 25, 25, 35, 29, 218, 207, 210, 93, 65, 17, 56, 89, 196, 243, 253, 78, 192, 214],
-		[175, 86, 171, 159, 158, 145, 218, 26, 221, 74, 230, 81, 107, 52, 176, 95, 136, 211, 118, 119, 51, 251, 173, 120, 158, 210, 91, 58, 120, 164, 214, 234],
-		[39, 199, 24, 221, 168, 196, 113, 46, 82, 20, 123, 209, 142, 69, 41, 124, 214, 101, 16, 236, 115, 152, 84, 107, 201, 182, 118, 141, 198, 181, 99, 109],
+		[196, 216, 59, 164, 10, 239, 199, 49, 123, 234, 148, 238, 200, 223, 168, 51, 145, 48, 87, 228, 176, 199, 21, 59, 59, 230, 156, 113, 12, 39, 138, 57],
+		[136, 156, 12, 11, 229, 222, 116, 233, 247, 6, 86, 168, 82, 81, 136, 62, 56, 182, 238, 77, 12, 153, 111, 139, 48, 116, 86, 122, 92, 92, 245, 134],
+		[226, 230, 160, 133, 157, 71, 128, 241, 248, 214, 38, 120, 173, 190, 98, 254, 15, 217, 122, 240, 112, 95, 155, 75, 29, 152, 19, 76, 84, 214, 147, 158],
+		[83, 240, 251, 154, 101, 116, 95, 172, 248, 212, 210, 94, 241, 176, 168, 217, 229, 235, 241, 106, 178, 62, 86, 150, 3, 15, 58, 51, 2, 71, 226, 101],
 	],
 	[
-		[10, 149, 174, 241, 17, 7, 53, 13, 185, 174, 158, 56, 198, 30, 28, 131, 145, 161, 80, 183, 117, 5, 236, 4, 124, 17, 120, 141, 255, 55, 201, 31],
-		[209, 156, 29, 78, 59, 211, 197, 32, 123, 40, 220, 247, 111, 188, 90, 58, 82, 210, 11, 54, 11, 227, 252, 113, 229, 173, 12, 197, 8, 230, 37, 78],
-		[110, 116, 141, 187, 149, 79, 134, 167, 40, 8, 222, 148, 32, 139, 85, 230, 246, 237, 103, 206, 33, 182, 192, 249, 18, 182, 174, 121, 170, 186, 62, 224],
-		[221, 120, 198, 41, 191, 201, 88, 237, 117, 233, 208, 65, 115, 246, 195, 223, 247, 12, 16, 219, 118, 56, 107, 48, 40, 25, 203, 73, 164, 108, 17, 30],
+		[5, 0, 114, 0, 36, 85, 175, 157, 1, 55, 135, 127, 18, 197, 167, 249, 6, 59, 87, 120, 3, 245, 117, 217, 127, 141, 213, 181, 192, 73, 104, 230],
+		[59, 43, 34, 30, 108, 103, 243, 180, 126, 30, 225, 126, 225, 65, 17, 123, 184, 208, 164, 220, 161, 53, 169, 16, 70, 103, 181, 85, 79, 254, 228, 144],
+		[4, 81, 104, 64, 169, 120, 218, 3, 210, 21, 152, 158, 104, 50, 200, 135, 101, 49, 116, 185, 145, 165, 210, 106, 147, 27, 76, 3, 186, 186, 108, 138],
+		[83, 185, 151, 72, 159, 12, 39, 102, 165, 190, 178, 8, 44, 122, 110, 225, 64, 74, 227, 244, 237, 213, 19, 134, 186, 123, 122, 116, 16, 42, 54, 115],
 	],
 	[
-		[104, 28, 255, 253, 146, 48, 165, 121, 154, 150, 227, 68, 57, 85, 209, 182, 98, 29, 12, 53, 141, 169, 97, 133, 207, 38, 103, 10, 73, 102, 229, 233],
-		[83, 40, 16, 144, 33, 68, 0, 25, 19, 82, 92, 99, 224, 106, 17, 215, 122, 50, 146, 234, 171, 210, 140, 215, 179, 163, 43, 112, 218, 65, 129, 193],
-		[45, 209, 185, 108, 120, 251, 77, 1, 36, 224, 51, 35, 121, 222, 161, 50, 10, 44, 118, 1, 169, 116, 82, 110, 144, 91, 152, 117, 88, 229, 245, 225],
-		[33, 212, 85, 172, 56, 104, 148, 84, 35, 14, 195, 231, 54, 156, 248, 150, 225, 28, 41, 145, 168, 240, 13, 31, 231, 209, 224, 234, 48, 202, 25, 141],
+		[160, 49, 198, 162, 39, 189, 218, 227, 147, 90, 46, 56, 40, 59, 84, 70, 188, 27, 215, 53, 147, 128, 58, 165, 135, 81, 198, 89, 144, 145, 77, 148],
+		[177, 126, 123, 134, 34, 85, 83, 75, 244, 162, 103, 7, 45, 44, 183, 171, 118, 124, 93, 97, 221, 111, 178, 168, 198, 62, 100, 183, 230, 199, 77, 240],
+		[204, 223, 218, 236, 220, 40, 223, 19, 224, 150, 71, 231, 111, 36, 122, 141, 110, 73, 237, 110, 207, 98, 189, 94, 238, 178, 207, 195, 228, 100, 176, 207],
+		[55, 162, 150, 57, 63, 212, 123, 83, 184, 5, 215, 170, 172, 198, 59, 174, 79, 247, 176, 134, 72, 157, 113, 54, 129, 15, 216, 193, 208, 70, 100, 173],
 	],
 	[
-		[17, 244, 122, 101, 236, 75, 195, 182, 57, 64, 9, 202, 141, 112, 245, 77, 206, 116, 203, 45, 34, 241, 185, 37, 46, 2, 213, 250, 245, 150, 104, 163],
-		[77, 221, 64, 182, 6, 185, 205, 93, 76, 245, 12, 174, 97, 65, 212, 208, 85, 142, 102, 162, 18, 3, 39, 118, 66, 198, 253, 48, 216, 129, 186, 77],
-		[137, 46, 154, 3, 209, 137, 176, 177, 250, 113, 111, 4, 66, 102, 207, 5, 61, 66, 51, 185, 3, 3, 235, 71, 141, 12, 191, 208, 157, 182, 175, 76],
-		[18, 207, 96, 44, 215, 11, 201, 151, 194, 68, 250, 96, 223, 141, 41, 66, 171, 114, 123, 71, 13, 2, 35, 254, 67, 106, 111, 187, 138, 90, 62, 140],
+		[48, 181, 153, 121, 13, 246, 119, 140, 41, 106, 51, 27, 226, 242, 131, 149, 23, 9, 94, 118, 230, 247, 99, 253, 176, 109, 226, 29, 63, 85, 10, 80],
+		[90, 227, 79, 196, 19, 137, 18, 212, 183, 148, 104, 227, 91, 41, 45, 142, 124, 196, 155, 115, 158, 167, 142, 93, 142, 116, 242, 248, 143, 99, 196, 134],
+		[22, 169, 166, 53, 28, 226, 166, 186, 236, 121, 247, 235, 245, 150, 50, 85, 55, 49, 11, 110, 33, 42, 31, 196, 233, 235, 221, 133, 243, 129, 48, 95],
+		[162, 62, 153, 70, 24, 104, 27, 157, 218, 88, 95, 240, 3, 52, 58, 224, 223, 95, 242, 192, 121, 161, 170, 240, 139, 207, 73, 12, 68, 246, 233, 238],
 	],
 	[
-		[27, 177, 252, 72, 189, 177, 36, 103, 19, 195, 48, 38, 137, 158, 102, 32, 196, 238, 81, 69, 214, 15, 17, 124, 80, 100, 140, 158, 227, 177, 129, 151],
-		[103, 160, 42, 155, 176, 155, 200, 247, 255, 97, 145, 159, 217, 200, 245, 201, 187, 158, 106, 99, 57, 51, 221, 153, 77, 227, 81, 72, 105, 14, 66, 150],
-		[127, 102, 87, 55, 117, 242, 19, 138, 88, 0, 92, 223, 10, 87, 159, 159, 118, 4, 3, 192, 30, 175, 81, 36, 235, 224, 171, 11, 94, 67, 177, 232],
-		[227, 8, 109, 174, 163, 139, 37, 87, 226, 172, 26, 78, 113, 200, 221, 19, 110, 89, 174, 54, 74, 114, 253, 31, 68, 167, 39, 49, 30, 157, 214, 191],
+		[206, 120, 175, 194, 98, 222, 233, 227, 203, 196, 130, 58, 132, 126, 116, 115, 118, 103, 223, 17, 176, 106, 66, 132, 5, 172, 243, 5, 228, 62, 45, 108],
+		[143, 120, 191, 241, 238, 139, 185, 204, 45, 69, 45, 242, 226, 247, 37, 181, 200, 100, 29, 30, 225, 152, 183, 122, 130, 251, 151, 72, 88, 147, 116, 172],
+		[42, 43, 74, 252, 79, 38, 127, 194, 209, 161, 8, 142, 169, 227, 45, 44, 208, 31, 70, 186, 205, 41, 121, 127, 110, 211, 161, 76, 54, 28, 252, 67],
+		[234, 8, 31, 72, 87, 192, 177, 151, 149, 198, 245, 62, 238, 82, 99, 94, 98, 44, 189, 137, 199, 95, 220, 198, 65, 104, 106, 190, 223, 140, 76, 10],
 	],
 	[
-		[179, 83, 212, 195, 20, 1, 42, 187, 161, 216, 89, 111, 164, 92, 213, 149, 41, 135, 202, 33, 126, 158, 160, 134, 176, 43, 89, 21, 168, 208, 183, 99],
-		[244, 38, 131, 124, 162, 61, 130, 234, 207, 171, 239, 125, 219, 191, 199, 177, 153, 244, 125, 251, 193, 136, 193, 185, 65, 55, 210, 182, 182, 169, 57, 202],
-		[194, 71, 10, 254, 111, 160, 233, 29, 142, 69, 248, 93, 96, 153, 194, 7, 106, 62, 195, 95, 203, 124, 27, 15, 53, 181, 254, 16, 218, 73, 74, 243],
-		[89, 135, 67, 195, 211, 236, 195, 222, 242, 237, 40, 26, 206, 228, 113, 132, 60, 94, 195, 127, 255, 214, 225, 122, 137, 57, 242, 12, 133, 193, 168, 101],
+		[51, 242, 210, 151, 93, 195, 86, 246, 211, 224, 169, 178, 27, 57, 231, 234, 131, 140, 247, 243, 40, 168, 249, 201, 133, 244, 250, 253, 65, 208, 95, 66],
+		[6, 135, 74, 195, 198, 142, 202, 169, 161, 224, 239, 80, 60, 56, 121, 8, 157, 154, 206, 201, 74, 31, 40, 195, 111, 90, 84, 131, 104, 192, 37, 22],
+		[193, 5, 140, 20, 253, 170, 52, 10, 172, 185, 224, 254, 126, 201, 17, 45, 203, 150, 117, 250, 253, 72, 144, 19, 96, 36, 138, 168, 1, 185, 208, 69],
+		[70, 255, 120, 215, 151, 228, 128, 154, 131, 199, 75, 11, 56, 39, 149, 112, 247, 201, 27, 137, 10, 137, 109, 173, 193, 79, 28, 9, 56, 43, 126, 178],
 	],
 	[
-		[44, 237, 122, 168, 17, 112, 26, 30, 105, 31, 6, 194, 157, 88, 129, 175, 186, 11, 233, 129, 43, 239, 111, 163, 225, 70, 188, 171, 198, 178, 118, 189],
-		[174, 91, 202, 244, 123, 59, 45, 9, 208, 92, 236, 97, 79, 222, 157, 186, 112, 92, 216, 140, 240, 2, 217, 236, 116, 18, 158, 5, 89, 17, 113, 169],
-		[27, 139, 117, 200, 191, 87, 191, 172, 198, 186, 166, 153, 232, 232, 160, 210, 176, 118, 75, 158, 144, 14, 126, 142, 74, 183, 72, 198, 78, 71, 48, 135],
-		[129, 222, 42, 249, 198, 28, 44, 14, 136, 230, 223, 28, 37, 240, 50, 223, 200, 222, 138, 206, 88, 162, 244, 39, 148, 10, 146, 236, 59, 77, 34, 2],
+		[134, 232, 213, 59, 26, 88, 230, 69, 14, 154, 19, 82, 108, 129, 11, 34, 27, 112, 130, 16, 8, 233, 204, 61, 110, 188, 89, 229, 186, 174, 91, 142],
+		[29, 36, 224, 251, 17, 24, 227, 144, 14, 173, 57, 191, 134, 38, 12, 60, 175, 112, 247, 50, 239, 217, 33, 169, 181, 208, 183, 37, 112, 217, 211, 150],
+		[194, 120, 117, 13, 48, 222, 212, 87, 35, 132, 70, 232, 138, 43, 141, 118, 7, 37, 11, 140, 175, 53, 171, 163, 0, 95, 69, 74, 226, 31, 142, 151],
+		[186, 202, 18, 197, 99, 10, 144, 57, 21, 76, 155, 201, 240, 163, 244, 7, 63, 132, 152, 193, 198, 89, 106, 219, 153, 184, 211, 216, 171, 53, 238, 49],
 	],
 	[
-		[205, 8, 146, 255, 19, 181, 213, 66, 173, 62, 119, 98, 25, 80, 242, 200, 239, 82, 188, 211, 211, 59, 124, 217, 126, 219, 46, 252, 99, 33, 200, 15],
-		[182, 164, 192, 255, 172, 19, 242, 81, 229, 178, 52, 45, 248, 133, 59, 142, 134, 178, 154, 62, 1, 63, 31, 57, 170, 238, 73, 148, 71, 69, 191, 20],
-		[89, 15, 68, 88, 115, 219, 142, 121, 146, 170, 92, 152, 123, 204, 241, 166, 23, 11, 89, 248, 108, 122, 233, 25, 222, 44, 69, 110, 194, 123, 240, 196],
-		[211, 132, 13, 16, 205, 117, 242, 189, 17, 131, 86, 124, 39, 168, 116, 212, 57, 189, 174, 244, 5, 118, 67, 50, 112, 49, 81, 199, 248, 152, 246, 160],
+		[192, 201, 94, 53, 30, 129, 63, 47, 203, 163, 200, 136, 85, 117, 149, 148, 124, 173, 51, 68, 224, 96, 230, 234, 50, 221, 197, 85, 102, 48, 36, 225],
+		[33, 242, 170, 56, 2, 116, 175, 40, 226, 149, 39, 67, 78, 70, 119, 144, 88, 58, 113, 140, 232, 229, 95, 132, 18, 194, 166, 241, 126, 226, 161, 213],
+		[214, 202, 121, 181, 118, 11, 8, 116, 66, 222, 92, 19, 161, 251, 195, 128, 180, 111, 77, 166, 164, 16, 206, 171, 139, 42, 131, 198, 97, 234, 174, 197],
+		[160, 71, 215, 68, 58, 176, 167, 107, 75, 223, 178, 141, 239, 136, 199, 77, 68, 147, 218, 163, 250, 15, 202, 243, 51, 228, 174, 170, 12, 200, 192, 131],
 	],
 	[
-		[94, 56, 35, 244, 149, 241, 162, 43, 25, 1, 217, 221, 131, 132, 12, 186, 62, 3, 103, 149, 214, 52, 249, 141, 118, 208, 238, 122, 56, 186, 164, 139],
-		[165, 227, 132, 215, 85, 252, 101, 45, 122, 175, 67, 112, 133, 173, 24, 128, 100, 143, 133, 240, 208, 178, 115, 121, 115, 141, 168, 184, 152, 67, 81, 26],
-		[56, 47, 38, 11, 180, 8, 237, 39, 95, 193, 85, 69, 232, 98, 41, 124, 252, 186, 143, 182, 37, 37, 154, 80, 249, 236, 192, 8, 81, 175, 235, 241],
-		[47, 14, 34, 79, 202, 86, 13, 130, 187, 212, 16, 35, 182, 76, 220, 82, 27, 251, 59, 201, 195, 41, 90, 164, 209, 164, 87, 109, 35, 154, 86, 145],
+		[138, 173, 135, 124, 228, 6, 249, 87, 86, 38, 224, 17, 82, 225, 72, 146, 53, 230, 206, 94, 13, 161, 53, 112, 243, 149, 175, 167, 186, 179, 170, 206],
+		[187, 72, 104, 171, 7, 170, 64, 93, 130, 9, 215, 65, 5, 16, 15, 185, 254, 15, 169, 61, 142, 135, 47, 166, 54, 244, 190, 62, 63, 70, 1, 243],
+		[98, 240, 10, 130, 26, 237, 136, 57, 78, 134, 50, 227, 43, 206, 124, 69, 58, 1, 18, 248, 134, 90, 52, 116, 1, 214, 18, 133, 67, 94, 144, 186],
+		[228, 17, 106, 123, 61, 226, 121, 11, 245, 163, 169, 139, 4, 107, 97, 108, 86, 213, 54, 34, 139, 23, 21, 53, 220, 83, 31, 129, 119, 160, 162, 47],
 	],
 	[
-		[24, 106, 59, 55, 70, 189, 116, 57, 16, 130, 36, 136, 169, 184, 115, 88, 98, 185, 175, 239, 9, 48, 90, 157, 65, 62, 33, 79, 160, 128, 2, 198],
-		[66, 245, 207, 53, 15, 38, 186, 14, 237, 199, 205, 15, 212, 172, 104, 147, 194, 241, 243, 170, 194, 102, 149, 59, 93, 34, 63, 190, 115, 125, 79, 105],
-		[45, 189, 81, 128, 244, 120, 219, 92, 86, 37, 147, 140, 229, 107, 203, 191, 142, 195, 58, 91, 214, 40, 233, 237, 253, 113, 62, 35, 255, 63, 39, 66],
-		[139, 213, 99, 250, 15, 250, 227, 110, 13, 112, 147, 176, 161, 152, 7, 4, 110, 18, 65, 225, 177, 46, 104, 107, 109, 132, 84, 81, 173, 232, 137, 65],
+		[197, 14, 225, 5, 69, 31, 4, 151, 43, 136, 195, 43, 26, 76, 4, 235, 174, 5, 47, 129, 14, 189, 2, 0, 250, 230, 97, 133, 88, 123, 195, 61],
+		[32, 199, 242, 224, 153, 91, 227, 47, 161, 104, 55, 131, 215, 214, 208, 25, 3, 102, 166, 117, 154, 210, 234, 184, 254, 226, 112, 8, 230, 30, 239, 228],
+		[200, 156, 68, 125, 55, 145, 175, 57, 19, 207, 138, 209, 137, 224, 143, 111, 249, 234, 218, 100, 39, 115, 91, 29, 29, 127, 173, 12, 238, 57, 51, 79],
+		[69, 218, 240, 51, 99, 52, 107, 25, 63, 223, 17, 43, 99, 163, 14, 75, 181, 141, 174, 31, 170, 5, 193, 123, 159, 180, 193, 25, 253, 101, 139, 202],
 	],
 	[
-		[7, 44, 189, 136, 231, 236, 30, 171, 47, 132, 215, 14, 44, 254, 135, 56, 5, 76, 89, 40, 2, 204, 71, 232, 6, 23, 144, 226, 43, 76, 214, 144],
-		[170, 12, 92, 80, 123, 147, 159, 108, 209, 202, 255, 81, 68, 230, 192, 203, 193, 26, 158, 244, 179, 42, 122, 171, 110, 25, 91, 55, 88, 70, 254, 148],
-		[234, 7, 124, 147, 93, 184, 194, 15, 134, 123, 217, 55, 58, 162, 0, 19, 57, 119, 33, 33, 221, 117, 134, 231, 138, 93, 14, 101, 56, 197, 57, 200],
-		[234, 96, 188, 174, 22, 33, 33, 209, 130, 46, 105, 20, 151, 143, 70, 139, 234, 135, 19, 166, 137, 74, 195, 82, 196, 76, 168, 225, 32, 146, 55, 149],
+		[48, 19, 83, 80, 86, 215, 163, 164, 56, 108, 202, 59, 86, 181, 40, 38, 255, 191, 131, 30, 144, 123, 120, 178, 97, 152, 220, 120, 207, 178, 20, 156],
+		[49, 131, 178, 31, 239, 85, 19, 93, 46, 161, 109, 254, 236, 158, 62, 233, 242, 203, 104, 15, 73, 150, 87, 152, 108, 27, 17, 110, 86, 90, 7, 153],
+		[113, 205, 236, 189, 167, 97, 103, 89, 76, 185, 161, 155, 218, 46, 209, 180, 228, 221, 169, 189, 224, 3, 153, 130, 196, 207, 42, 14, 215, 132, 237, 44],
+		[208, 108, 80, 157, 153, 76, 238, 180, 225, 233, 182, 47, 219, 42, 47, 2, 125, 165, 41, 98, 222, 213, 129, 246, 125, 189, 48, 185, 7, 198, 73, 179],
 	],
 	[
-		[171, 208, 100, 46, 83, 214, 150, 89, 83, 100, 112, 147, 77, 107, 138, 60, 105, 213, 46, 116, 16, 213, 48, 12, 24, 37, 31, 92, 231, 199, 57, 198],
-		[206, 222, 4, 58, 33, 229, 144, 219, 200, 40, 21, 181, 49, 193, 7, 36, 106, 153, 93, 32, 7, 200, 228, 202, 81, 36, 39, 255, 79, 135, 236, 146],
-		[41, 163, 101, 103, 120, 109, 89, 129, 95, 229, 165, 246, 42, 22, 232, 17, 189, 144, 206, 140, 214, 124, 145, 187, 238, 80, 1, 252, 16, 249, 56, 160],
-		[20, 80, 10, 73, 69, 21, 191, 65, 153, 233, 3, 215, 20, 118, 146, 90, 6, 100, 209, 245, 149, 115, 78, 2, 143, 80, 56, 177, 209, 240, 160, 70],
+		[169, 200, 61, 90, 117, 185, 117, 173, 246, 60, 61, 194, 115, 219, 212, 151, 113, 215, 35, 98, 36, 209, 41, 102, 143, 91, 63, 149, 237, 29, 48, 136],
+		[194, 202, 136, 213, 200, 63, 99, 241, 107, 141, 227, 196, 7, 47, 248, 4, 179, 213, 246, 214, 178, 114, 132, 165, 254, 15, 135, 179, 3, 241, 36, 128],
+		[31, 222, 142, 140, 123, 199, 186, 206, 66, 39, 83, 159, 184, 32, 139, 203, 205, 143, 164, 45, 175, 133, 84, 247, 5, 140, 206, 18, 184, 38, 124, 68],
+		[75, 233, 155, 22, 79, 139, 21, 185, 144, 96, 14, 253, 225, 144, 210, 138, 50, 212, 190, 41, 54, 245, 221, 64, 127, 182, 21, 92, 60, 86, 7, 83],
 	],
 	[
-		[222, 222, 51, 126, 230, 114, 248, 237, 224, 31, 83, 236, 197, 244, 156, 47, 107, 65, 171, 185, 21, 46, 123, 163, 103, 147, 98, 168, 109, 212, 249, 12],
-		[233, 29, 120, 84, 39, 245, 208, 104, 103, 80, 58, 234, 21, 164, 178, 214, 197, 134, 234, 148, 162, 57, 140, 126, 88, 48, 52, 85, 6, 120, 230, 185],
-		[156, 165, 46, 129, 202, 230, 92, 254, 222, 91, 15, 56, 113, 75, 94, 98, 192, 212, 156, 246, 106, 39, 147, 152, 204, 135, 210, 55, 228, 13, 125, 140],
-		[163, 4, 192, 198, 231, 253, 87, 128, 41, 87, 41, 96, 142, 214, 128, 10, 91, 127, 93, 10, 100, 244, 145, 92, 165, 165, 111, 132, 195, 218, 9, 27],
+		[44, 174, 207, 238, 84, 99, 227, 247, 133, 81, 58, 27, 178, 83, 105, 201, 241, 27, 50, 3, 94, 175, 14, 240, 193, 42, 181, 194, 202, 169, 90, 96],
+		[150, 36, 3, 197, 166, 84, 7, 120, 169, 224, 26, 69, 43, 150, 252, 11, 229, 150, 50, 5, 220, 67, 232, 122, 174, 180, 168, 134, 15, 166, 35, 130],
+		[140, 16, 175, 252, 188, 131, 226, 125, 82, 195, 128, 52, 158, 107, 158, 204, 192, 174, 157, 211, 239, 138, 160, 215, 153, 123, 173, 41, 255, 21, 217, 175],
+		[246, 239, 238, 171, 190, 230, 210, 19, 130, 28, 78, 70, 17, 197, 91, 242, 122, 27, 61, 231, 193, 180, 165, 152, 39, 204, 35, 7, 35, 249, 25, 216],
 	],
 	[
-		[42, 235, 187, 255, 177, 87, 226, 179, 55, 33, 229, 142, 20, 91, 154, 196, 34, 76, 74, 4, 167, 143, 185, 116, 139, 37, 236, 50, 190, 136, 134, 50],
-		[78, 156, 245, 51, 111, 108, 21, 64, 194, 101, 249, 108, 164, 79, 89, 39, 115, 246, 234, 139, 98, 53, 123, 144, 83, 232, 186, 29, 252, 103, 239, 150],
-		[122, 69, 134, 65, 84, 113, 239, 211, 108, 194, 194, 139, 219, 29, 1, 205, 187, 61, 154, 99, 21, 108, 90, 184, 135, 114, 59, 55, 134, 81, 75, 9],
-		[251, 166, 109, 250, 3, 207, 31, 220, 247, 7, 194, 113, 221, 20, 126, 15, 61, 88, 8, 132, 100, 203, 180, 72, 65, 191, 164, 47, 123, 161, 109, 44],
+		[242, 53, 60, 92, 183, 130, 89, 107, 6, 173, 243, 48, 164, 190, 109, 68, 51, 92, 40, 212, 197, 208, 10, 44, 152, 61, 166, 182, 162, 156, 67, 88],
+		[58, 113, 185, 81, 12, 153, 5, 69, 10, 1, 43, 73, 157, 103, 45, 209, 117, 108, 61, 251, 67, 198, 43, 251, 239, 184, 84, 55, 149, 178, 178, 67],
+		[206, 208, 247, 192, 127, 186, 121, 196, 230, 51, 111, 189, 228, 99, 21, 28, 222, 133, 147, 92, 20, 29, 211, 241, 35, 61, 40, 83, 135, 174, 227, 217],
+		[184, 39, 50, 228, 178, 112, 163, 146, 141, 49, 132, 15, 217, 81, 21, 97, 31, 144, 251, 71, 111, 147, 140, 96, 195, 159, 31, 250, 176, 58, 78, 238],
 	],
 	[
-		[48, 143, 123, 211, 150, 147, 53, 198, 213, 107, 186, 3, 57, 11, 134, 141, 82, 17, 113, 218, 80, 242, 229, 158, 169, 180, 40, 216, 94, 253, 78, 11],
-		[202, 72, 69, 70, 241, 20, 188, 157, 204, 140, 207, 20, 250, 95, 37, 230, 125, 189, 201, 227, 173, 84, 172, 148, 51, 197, 44, 219, 231, 191, 74, 167],
-		[212, 105, 254, 171, 245, 92, 104, 80, 94, 229, 159, 220, 201, 251, 98, 242, 22, 68, 1, 84, 18, 172, 202, 250, 219, 239, 55, 247, 176, 162, 242, 211],
-		[125, 151, 10, 136, 167, 244, 127, 225, 139, 21, 174, 185, 249, 19, 187, 96, 173, 125, 179, 57, 17, 162, 249, 219, 181, 196, 76, 88, 17, 76, 146, 189],
+		[197, 46, 239, 157, 60, 198, 12, 105, 242, 106, 222, 29, 47, 54, 228, 109, 89, 60, 135, 248, 45, 56, 6, 122, 124, 102, 228, 120, 0, 184, 140, 219],
+		[250, 174, 105, 207, 134, 131, 15, 85, 187, 168, 64, 242, 10, 15, 253, 174, 62, 236, 192, 30, 148, 37, 201, 113, 233, 41, 117, 181, 140, 235, 91, 154],
+		[127, 247, 13, 129, 135, 208, 126, 69, 170, 223, 27, 227, 75, 72, 98, 31, 89, 149, 65, 225, 109, 210, 246, 254, 67, 69, 77, 190, 31, 167, 30, 84],
+		[139, 93, 140, 65, 228, 34, 91, 126, 125, 163, 2, 110, 132, 113, 253, 21, 58, 204, 167, 133, 232, 44, 33, 125, 116, 176, 100, 208, 42, 60, 119, 11],
 	],
 	[
-		[10, 173, 159, 183, 65, 24, 176, 66, 136, 89, 188, 177, 31, 141, 35, 174, 48, 241, 122, 0, 216, 180, 214, 16, 192, 50, 121, 126, 184, 138, 121, 227],
-		[134, 186, 97, 127, 105, 64, 109, 78, 121, 109, 36, 197, 178, 249, 10, 76, 31, 63, 31, 132, 22, 250, 95, 142, 7, 11, 251, 86, 86, 121, 89, 10],
-		[249, 46, 84, 242, 43, 104, 57, 26, 153, 25, 191, 86, 91, 229, 239, 163, 196, 176, 58, 194, 195, 92, 234, 143, 79, 157, 84, 128, 165, 176, 133, 238],
-		[39, 242, 188, 222, 27, 105, 121, 110, 160, 218, 207, 192, 227, 123, 222, 147, 194, 205, 196, 10, 60, 233, 156, 69, 90, 103, 35, 64, 43, 171, 57, 44],
+		[75, 63, 201, 209, 54, 157, 209, 220, 225, 84, 4, 100, 136, 253, 192, 50, 171, 56, 242, 121, 95, 38, 26, 145, 36, 234, 66, 24, 108, 118, 169, 22],
+		[176, 21, 229, 107, 224, 60, 211, 173, 188, 243, 12, 96, 5, 144, 247, 81, 119, 212, 45, 68, 255, 99, 32, 182, 64, 100, 240, 33, 206, 210, 35, 134],
+		[86, 213, 123, 220, 216, 227, 144, 0, 149, 189, 52, 17, 205, 163, 16, 160, 152, 182, 231, 213, 222, 111, 173, 31, 200, 84, 222, 60, 186, 99, 152, 152],
+		[159, 191, 13, 7, 139, 21, 178, 15, 238, 11, 208, 242, 237, 208, 141, 28, 182, 78, 173, 219, 192, 46, 146, 31, 252, 101, 128, 223, 199, 71, 24, 50],
 	],
 	[
-		[13, 52, 81, 229, 152, 34, 11, 156, 142, 55, 206, 252, 156, 204, 199, 73, 200, 78, 139, 245, 143, 207, 203, 248, 110, 157, 160, 37, 161, 20, 111, 10],
-		[179, 211, 220, 214, 74, 3, 175, 4, 102, 29, 88, 169, 125, 93, 190, 132, 76, 118, 220, 54, 158, 58, 97, 199, 12, 10, 99, 247, 146, 7, 180, 12],
-		[102, 161, 49, 60, 158, 217, 63, 189, 73, 224, 96, 112, 64, 52, 118, 11, 91, 212, 223, 0, 234, 195, 117, 100, 114, 209, 14, 172, 63, 204, 83, 100],
-		[78, 12, 1, 111, 66, 201, 199, 174, 111, 57, 247, 231, 243, 58, 17, 127, 146, 100, 179, 224, 160, 225, 91, 28, 137, 147, 159, 195, 157, 167, 116, 66],
+		[225, 75, 35, 211, 53, 8, 192, 157, 55, 199, 254, 154, 109, 188, 21, 163, 107, 247, 105, 224, 98, 52, 118, 75, 171, 24, 175, 250, 220, 16, 231, 50],
+		[138, 82, 2, 59, 196, 86, 181, 199, 144, 59, 201, 22, 13, 80, 66, 252, 141, 164, 115, 106, 166, 175, 199, 40, 196, 6, 55, 99, 127, 7, 46, 40],
+		[84, 110, 5, 175, 26, 50, 245, 100, 55, 75, 57, 194, 200, 14, 101, 138, 227, 188, 65, 138, 148, 155, 27, 178, 232, 32, 22, 180, 122, 152, 2, 22],
+		[109, 91, 240, 107, 109, 9, 180, 191, 29, 93, 185, 191, 93, 36, 79, 229, 174, 217, 149, 161, 109, 78, 59, 219, 182, 79, 197, 61, 143, 44, 135, 46],
 	],
 	[
-		[155, 197, 88, 13, 151, 35, 182, 233, 111, 215, 207, 43, 180, 152, 114, 102, 122, 194, 155, 149, 160, 58, 232, 177, 250, 254, 90, 101, 132, 161, 54, 204],
-		[20, 146, 209, 215, 123, 98, 89, 139, 70, 20, 73, 216, 229, 143, 234, 22, 28, 232, 111, 90, 143, 170, 3, 123, 188, 217, 235, 55, 44, 221, 194, 27],
-		[42, 115, 106, 65, 114, 219, 173, 235, 144, 99, 145, 26, 104, 238, 46, 48, 234, 56, 157, 131, 74, 45, 41, 131, 50, 5, 220, 41, 172, 251, 37, 136],
-		[252, 78, 122, 123, 240, 116, 253, 39, 249, 134, 134, 250, 122, 188, 203, 201, 227, 155, 42, 140, 134, 142, 33, 126, 30, 179, 112, 104, 87, 78, 249, 170],
+		[199, 196, 34, 165, 230, 25, 197, 197, 157, 202, 201, 136, 182, 111, 20, 245, 235, 230, 80, 68, 149, 198, 230, 131, 57, 96, 190, 79, 30, 11, 76, 57],
+		[108, 162, 43, 69, 166, 49, 39, 141, 104, 155, 54, 134, 9, 23, 243, 99, 83, 129, 31, 30, 43, 220, 26, 46, 245, 108, 69, 135, 13, 77, 9, 9],
+		[121, 206, 8, 5, 197, 90, 116, 23, 92, 158, 169, 212, 121, 128, 163, 192, 6, 103, 191, 117, 187, 127, 236, 110, 123, 156, 220, 80, 128, 82, 46, 214],
+		[55, 194, 74, 48, 244, 218, 185, 172, 174, 53, 85, 185, 133, 39, 203, 107, 97, 108, 12, 159, 8, 29, 153, 48, 126, 197, 125, 93, 10, 49, 136, 39],
 	],
 	[
-		[199, 103, 103, 119, 157, 103, 214, 233, 179, 92, 154, 206, 200, 85, 50, 253, 214, 24, 110, 32, 173, 230, 161, 220, 3, 56, 133, 49, 169, 74, 139, 214],
-		[68, 245, 191, 155, 103, 151, 227, 215, 250, 208, 149, 126, 142, 66, 104, 163, 83, 240, 159, 38, 56, 67, 8, 78, 45, 20, 149, 11, 132, 180, 36, 233],
-		[162, 135, 215, 13, 57, 50, 224, 245, 221, 14, 177, 208, 129, 192, 135, 25, 128, 156, 245, 72, 183, 226, 235, 111, 227, 158, 190, 2, 30, 145, 81, 227],
-		[203, 212, 239, 40, 76, 38, 171, 94, 87, 29, 39, 27, 84, 171, 234, 96, 23, 7, 83, 59, 167, 157, 180, 121, 82, 67, 160, 134, 13, 197, 142, 86],
+		[14, 214, 42, 214, 229, 88, 225, 53, 180, 87, 20, 202, 0, 71, 50, 224, 14, 5, 72, 151, 185, 155, 160, 199, 158, 79, 78, 163, 57, 196, 253, 230],
+		[136, 231, 203, 74, 210, 192, 242, 213, 178, 97, 57, 28, 129, 253, 188, 18, 149, 79, 202, 34, 167, 84, 219, 149, 110, 247, 148, 28, 24, 215, 12, 110],
+		[65, 145, 255, 64, 241, 186, 232, 238, 55, 152, 24, 35, 110, 202, 35, 107, 189, 168, 250, 94, 68, 209, 90, 103, 181, 248, 215, 253, 145, 46, 184, 184],
+		[104, 12, 127, 229, 75, 38, 27, 98, 208, 174, 65, 219, 29, 31, 57, 132, 146, 196, 35, 248, 169, 31, 143, 87, 223, 161, 32, 36, 25, 151, 144, 109],
 	],
 	[
-		[103, 16, 203, 203, 188, 164, 250, 223, 66, 98, 248, 124, 7, 215, 232, 236, 169, 238, 97, 44, 248, 123, 250, 6, 64, 154, 193, 201, 238, 136, 117, 179],
-		[22, 236, 54, 0, 44, 197, 3, 205, 12, 64, 69, 245, 26, 214, 144, 115, 159, 191, 194, 223, 172, 119, 255, 238, 186, 235, 232, 194, 218, 239, 138, 106],
-		[210, 157, 10, 57, 91, 235, 221, 132, 61, 68, 156, 173, 46, 166, 88, 2, 134, 128, 163, 14, 205, 79, 244, 228, 52, 69, 7, 227, 104, 106, 144, 145],
-		[251, 252, 215, 248, 64, 247, 92, 117, 172, 164, 213, 151, 176, 66, 154, 38, 71, 245, 191, 159, 240, 71, 238, 103, 205, 82, 73, 58, 82, 217, 18, 196],
+		[45, 141, 21, 150, 218, 0, 30, 132, 19, 6, 62, 11, 207, 137, 116, 230, 136, 104, 117, 109, 44, 241, 248, 218, 177, 115, 152, 171, 87, 247, 123, 208],
+		[12, 162, 197, 176, 160, 50, 53, 157, 1, 195, 97, 209, 41, 245, 50, 142, 66, 162, 214, 208, 153, 122, 150, 39, 157, 98, 252, 21, 183, 191, 49, 79],
+		[209, 67, 106, 159, 102, 107, 28, 83, 157, 247, 72, 61, 96, 66, 43, 68, 112, 41, 46, 201, 12, 135, 145, 29, 238, 115, 64, 170, 30, 66, 17, 187],
+		[137, 37, 73, 184, 113, 94, 90, 252, 176, 163, 221, 3, 79, 173, 23, 184, 135, 133, 9, 97, 49, 206, 50, 32, 161, 18, 77, 45, 135, 42, 33, 9],
 	],
 	[
-		[126, 115, 239, 43, 24, 92, 39, 231, 70, 203, 197, 43, 159, 186, 45, 53, 219, 60, 213, 214, 193, 35, 155, 233, 51, 73, 57, 187, 94, 35, 209, 142],
-		[121, 78, 245, 103, 136, 245, 31, 208, 247, 198, 65, 131, 44, 35, 42, 178, 135, 182, 146, 127, 147, 16, 216, 83, 182, 44, 182, 251, 147, 58, 223, 193],
-		[154, 211, 6, 113, 190, 82, 67, 29, 221, 18, 208, 139, 239, 7, 224, 160, 122, 118, 199, 5, 214, 3, 156, 210, 11, 93, 224, 6, 99, 164, 216, 31],
-		[124, 153, 55, 232, 114, 26, 181, 127, 62, 149, 91, 29, 34, 11, 186, 170, 247, 197, 199, 203, 175, 101, 92, 54, 39, 3, 99, 214, 19, 205, 125, 159],
+		[24, 82, 150, 208, 150, 80, 165, 3, 10, 165, 33, 13, 79, 254, 111, 181, 85, 8, 159, 150, 122, 252, 146, 211, 56, 130, 216, 237, 112, 254, 240, 212],
+		[207, 159, 132, 255, 239, 115, 78, 72, 32, 86, 204, 247, 28, 242, 116, 128, 165, 138, 223, 21, 131, 220, 187, 137, 152, 157, 98, 84, 245, 225, 249, 66],
+		[187, 178, 33, 11, 56, 27, 96, 118, 93, 190, 208, 4, 5, 24, 18, 220, 75, 208, 251, 172, 6, 32, 226, 139, 252, 124, 99, 56, 82, 106, 80, 199],
+		[20, 210, 192, 46, 189, 112, 138, 228, 63, 31, 14, 108, 129, 247, 27, 11, 111, 243, 165, 98, 133, 233, 12, 170, 112, 36, 29, 86, 195, 50, 10, 211],
 	],
 	[
-		[242, 39, 227, 37, 58, 203, 30, 250, 16, 32, 112, 243, 213, 123, 200, 236, 94, 139, 144, 165, 205, 175, 46, 119, 158, 16, 104, 223, 107, 213, 40, 145],
-		[41, 39, 251, 125, 88, 188, 233, 139, 142, 90, 198, 44, 137, 56, 112, 129, 233, 76, 185, 182, 181, 155, 219, 144, 20, 215, 179, 199, 253, 194, 212, 113],
-		[241, 79, 41, 152, 167, 249, 66, 174, 129, 149, 147, 26, 212, 167, 39, 94, 89, 43, 46, 83, 105, 0, 117, 149, 135, 64, 179, 88, 183, 26, 56, 167],
-		[32, 55, 177, 168, 4, 197, 201, 1, 169, 194, 69, 20, 173, 169, 175, 143, 88, 208, 35, 4, 132, 187, 28, 3, 45, 61, 211, 100, 81, 32, 91, 42],
+		[230, 202, 137, 47, 222, 215, 54, 115, 111, 177, 83, 151, 199, 213, 159, 91, 249, 88, 73, 194, 193, 192, 38, 44, 85, 77, 252, 159, 159, 50, 213, 234],
+		[171, 15, 246, 211, 75, 23, 205, 193, 135, 222, 89, 91, 177, 47, 149, 155, 85, 114, 177, 214, 211, 14, 64, 245, 192, 48, 230, 22, 233, 41, 164, 130],
+		[36, 71, 22, 191, 133, 115, 231, 217, 196, 145, 1, 19, 135, 22, 200, 149, 64, 63, 87, 134, 97, 27, 115, 169, 69, 197, 137, 241, 234, 20, 10, 9],
+		[167, 133, 250, 20, 125, 26, 94, 21, 14, 128, 21, 165, 122, 205, 90, 155, 51, 76, 42, 204, 114, 21, 189, 106, 108, 10, 54, 222, 80, 194, 126, 226],
 	],
 	[
-		[226, 56, 172, 189, 211, 189, 10, 167, 175, 63, 144, 36, 108, 243, 225, 171, 241, 196, 16, 230, 73, 132, 202, 89, 179, 226, 13, 185, 74, 170, 214, 170],
-		[74, 157, 224, 131, 125, 60, 242, 107, 185, 63, 149, 134, 81, 161, 248, 119, 249, 248, 194, 203, 0, 233, 208, 113, 204, 55, 239, 55, 193, 13, 159, 98],
-		[51, 226, 77, 110, 136, 112, 96, 211, 151, 73, 105, 54, 254, 65, 15, 135, 89, 63, 156, 202, 177, 245, 53, 111, 106, 159, 74, 196, 158, 94, 231, 150],
-		[93, 62, 118, 223, 170, 249, 80, 94, 129, 174, 122, 181, 138, 189, 243, 249, 58, 225, 188, 240, 207, 148, 174, 255, 209, 75, 162, 135, 2, 137, 22, 8],
+		[1, 72, 249, 179, 195, 118, 74, 170, 112, 79, 215, 133, 54, 92, 70, 191, 127, 98, 242, 82, 32, 228, 156, 175, 56, 142, 186, 194, 24, 234, 255, 156],
+		[208, 110, 142, 77, 132, 205, 102, 90, 178, 148, 148, 102, 155, 68, 2, 168, 38, 89, 21, 27, 92, 6, 18, 94, 205, 244, 169, 81, 183, 118, 132, 185],
+		[94, 2, 28, 189, 224, 195, 196, 219, 27, 217, 2, 157, 226, 84, 154, 149, 229, 28, 68, 25, 67, 186, 48, 214, 202, 110, 1, 110, 147, 62, 117, 47],
+		[13, 114, 234, 10, 225, 220, 203, 232, 253, 242, 100, 186, 209, 12, 96, 228, 127, 75, 168, 50, 151, 129, 235, 163, 138, 42, 187, 50, 167, 140, 199, 155],
 	],
 	[
-		[116, 24, 35, 2, 35, 99, 177, 8, 163, 159, 253, 122, 60, 131, 165, 201, 126, 34, 219, 12, 25, 222, 120, 189, 35, 142, 51, 205, 105, 216, 120, 236],
-		[14, 0, 17, 228, 156, 21, 253, 44, 12, 30, 246, 103, 83, 111, 77, 145, 149, 199, 246, 109, 3, 88, 76, 51, 44, 41, 121, 135, 188, 114, 227, 128],
-		[149, 152, 146, 103, 62, 70, 142, 131, 19, 237, 103, 195, 111, 72, 198, 142, 224, 207, 42, 243, 248, 43, 183, 67, 115, 21, 122, 40, 53, 29, 15, 130],
-		[90, 120, 67, 195, 233, 168, 23, 63, 213, 17, 134, 51, 21, 212, 169, 170, 175, 207, 181, 57, 81, 237, 142, 95, 230, 206, 99, 122, 251, 87, 160, 159],
+		[27, 138, 152, 192, 142, 212, 219, 153, 158, 200, 136, 39, 25, 250, 21, 42, 89, 171, 103, 10, 245, 52, 135, 109, 179, 141, 69, 219, 206, 99, 108, 59],
+		[64, 66, 93, 12, 3, 213, 77, 187, 7, 206, 208, 206, 34, 176, 200, 228, 247, 5, 114, 92, 4, 84, 234, 229, 211, 213, 145, 75, 106, 145, 186, 17],
+		[83, 163, 251, 37, 189, 123, 2, 74, 253, 190, 167, 49, 249, 103, 232, 180, 63, 15, 54, 212, 67, 71, 116, 222, 199, 10, 228, 63, 130, 3, 253, 173],
+		[126, 164, 153, 45, 89, 204, 1, 127, 107, 94, 153, 2, 129, 213, 243, 98, 58, 1, 185, 90, 243, 106, 66, 7, 249, 82, 236, 217, 68, 41, 23, 106],
 	],
 	[
-		[163, 138, 172, 152, 113, 110, 2, 3, 93, 197, 45, 74, 46, 198, 19, 66, 120, 157, 230, 130, 255, 135, 31, 242, 193, 163, 221, 96, 13, 49, 124, 48],
-		[213, 102, 49, 244, 79, 237, 169, 26, 69, 141, 157, 202, 95, 36, 40, 114, 85, 121, 46, 75, 236, 53, 199, 57, 92, 97, 30, 252, 0, 43, 89, 35],
-		[189, 43, 44, 146, 149, 45, 191, 42, 163, 191, 228, 40, 132, 249, 212, 8, 91, 174, 122, 215, 105, 195, 1, 80, 95, 108, 68, 144, 69, 51, 186, 177],
-		[168, 66, 176, 47, 216, 176, 26, 167, 239, 178, 245, 176, 50, 141, 207, 182, 232, 100, 58, 247, 242, 226, 97, 133, 208, 1, 195, 226, 29, 38, 94, 15],
+		[14, 33, 112, 226, 90, 203, 128, 72, 54, 103, 163, 3, 150, 254, 143, 199, 60, 174, 203, 56, 129, 66, 137, 135, 226, 168, 154, 171, 226, 228, 171, 14],
+		[78, 43, 221, 219, 217, 26, 81, 119, 39, 248, 56, 18, 95, 57, 124, 144, 97, 9, 118, 29, 217, 90, 180, 197, 125, 117, 42, 137, 38, 25, 208, 27],
+		[157, 116, 246, 62, 73, 217, 22, 96, 98, 10, 132, 14, 82, 190, 53, 141, 164, 3, 131, 106, 197, 252, 101, 30, 235, 107, 147, 242, 204, 7, 246, 160],
+		[231, 142, 187, 75, 230, 201, 244, 144, 41, 113, 80, 26, 218, 254, 102, 173, 40, 22, 170, 21, 248, 170, 160, 248, 62, 212, 4, 48, 35, 83, 4, 68],
 	],
 	[
-		[197, 51, 214, 225, 152, 27, 181, 119, 34, 252, 255, 7, 75, 90, 244, 104, 237, 74, 38, 142, 81, 40, 25, 27, 29, 234, 69, 201, 194, 19, 129, 11],
-		[233, 192, 103, 6, 126, 45, 229, 4, 6, 85, 184, 179, 241, 53, 216, 103, 185, 127, 249, 5, 94, 39, 40, 140, 51, 45, 62, 69, 74, 218, 70, 67],
-		[80, 115, 174, 122, 14, 250, 140, 209, 13, 119, 229, 155, 238, 111, 55, 99, 103, 0, 178, 139, 134, 168, 160, 182, 157, 83, 229, 26, 75, 200, 52, 49],
-		[79, 75, 191, 54, 108, 172, 82, 152, 53, 153, 24, 167, 22, 156, 89, 127, 147, 182, 144, 87, 5, 252, 8, 107, 108, 204, 230, 51, 194, 144, 69, 97],
+		[248, 147, 1, 120, 206, 163, 16, 188, 213, 31, 112, 101, 79, 23, 103, 69, 60, 125, 44, 151, 223, 87, 62, 246, 206, 8, 10, 154, 227, 132, 90, 9],
+		[252, 161, 71, 77, 95, 178, 43, 31, 239, 59, 191, 123, 11, 253, 11, 47, 74, 37, 96, 127, 114, 53, 79, 238, 58, 242, 57, 192, 36, 148, 93, 199],
+		[142, 128, 113, 244, 98, 106, 116, 179, 75, 60, 36, 240, 107, 1, 52, 245, 0, 40, 244, 31, 194, 86, 217, 75, 29, 12, 79, 186, 130, 62, 182, 42],
+		[33, 79, 5, 236, 0, 183, 167, 70, 52, 222, 195, 211, 121, 37, 108, 83, 59, 44, 131, 44, 252, 232, 76, 4, 31, 10, 198, 201, 88, 42, 25, 81],
 	],
 	[
-		[66, 139, 188, 51, 105, 199, 254, 210, 88, 169, 39, 29, 185, 219, 101, 125, 171, 171, 90, 184, 129, 142, 159, 51, 98, 32, 246, 162, 151, 185, 191, 70],
-		[106, 33, 147, 223, 20, 115, 72, 253, 90, 245, 224, 113, 237, 246, 165, 42, 149, 163, 130, 206, 128, 49, 190, 137, 5, 221, 18, 139, 44, 229, 73, 207],
-		[215, 218, 220, 229, 137, 165, 66, 162, 182, 0, 208, 3, 186, 102, 161, 116, 214, 155, 241, 158, 97, 144, 227, 122, 100, 102, 102, 168, 53, 81, 49, 172],
-		[26, 163, 134, 231, 32, 141, 194, 175, 40, 254, 64, 89, 96, 1, 162, 128, 207, 26, 208, 80, 193, 35, 52, 163, 41, 225, 91, 153, 196, 255, 75, 28],
+		[83, 103, 221, 224, 79, 195, 91, 51, 160, 211, 51, 153, 30, 58, 89, 61, 8, 18, 109, 151, 95, 233, 14, 244, 131, 7, 243, 184, 180, 89, 241, 154],
+		[3, 225, 185, 79, 65, 136, 102, 44, 6, 92, 150, 131, 2, 12, 158, 18, 151, 96, 204, 248, 93, 29, 50, 157, 32, 39, 159, 250, 189, 63, 56, 46],
+		[33, 73, 217, 241, 65, 106, 15, 115, 122, 248, 68, 175, 169, 19, 189, 189, 245, 208, 181, 198, 119, 218, 217, 185, 77, 129, 76, 219, 204, 202, 203, 56],
+		[20, 64, 141, 88, 237, 13, 178, 24, 171, 201, 58, 215, 63, 101, 86, 108, 54, 130, 83, 235, 208, 236, 100, 36, 245, 80, 98, 219, 169, 67, 2, 184],
 	],
 	[
-		[107, 206, 145, 36, 31, 117, 70, 238, 62, 70, 128, 200, 160, 83, 20, 239, 10, 67, 99, 152, 58, 24, 183, 224, 222, 213, 78, 226, 177, 105, 229, 98],
-		[177, 108, 105, 154, 9, 169, 73, 145, 42, 248, 137, 111, 71, 141, 31, 155, 194, 75, 54, 65, 155, 245, 14, 101, 159, 0, 181, 23, 104, 39, 95, 199],
-		[23, 9, 148, 207, 131, 209, 33, 126, 186, 138, 207, 115, 136, 198, 99, 141, 239, 253, 165, 92, 190, 68, 201, 71, 176, 112, 194, 173, 105, 134, 247, 241],
-		[199, 185, 150, 217, 37, 13, 254, 66, 98, 2, 120, 167, 39, 123, 248, 35, 151, 196, 11, 79, 196, 187, 22, 11, 113, 191, 201, 154, 51, 192, 138, 18],
+		[250, 95, 77, 55, 48, 29, 164, 236, 60, 128, 223, 226, 153, 238, 230, 28, 204, 226, 14, 252, 9, 77, 240, 35, 13, 176, 238, 123, 185, 33, 122, 13],
+		[213, 187, 12, 57, 243, 32, 98, 21, 178, 236, 86, 105, 229, 148, 229, 155, 224, 163, 90, 54, 93, 228, 183, 96, 31, 207, 57, 96, 75, 187, 44, 106],
+		[27, 142, 105, 171, 187, 70, 113, 179, 148, 55, 94, 108, 66, 118, 233, 225, 93, 108, 198, 0, 162, 50, 131, 210, 247, 179, 251, 45, 229, 148, 255, 104],
+		[112, 106, 103, 177, 43, 164, 129, 215, 185, 149, 82, 18, 247, 120, 82, 16, 157, 65, 56, 62, 227, 137, 18, 237, 176, 205, 108, 184, 244, 28, 94, 235],
 	],
 	[
-		[243, 111, 134, 229, 140, 32, 216, 86, 204, 157, 228, 137, 252, 67, 32, 170, 177, 222, 161, 25, 206, 237, 3, 140, 77, 152, 50, 222, 200, 182, 28, 36],
-		[238, 193, 135, 65, 12, 122, 73, 213, 70, 104, 37, 212, 88, 27, 61, 152, 171, 201, 140, 133, 227, 161, 80, 4, 39, 170, 33, 165, 76, 31, 111, 96],
-		[137, 112, 154, 15, 171, 39, 32, 183, 107, 244, 48, 253, 54, 127, 193, 34, 223, 156, 12, 91, 98, 133, 6, 140, 135, 218, 192, 84, 15, 183, 46, 111],
-		[183, 9, 100, 77, 58, 157, 169, 129, 72, 108, 89, 138, 88, 180, 136, 24, 200, 188, 114, 129, 128, 252, 94, 8, 241, 64, 87, 190, 154, 95, 66, 60],
+		[222, 99, 65, 141, 224, 89, 130, 242, 245, 8, 175, 189, 146, 12, 88, 249, 21, 148, 142, 31, 147, 152, 181, 28, 43, 59, 122, 32, 202, 222, 222, 232],
+		[185, 162, 178, 178, 125, 54, 220, 167, 94, 39, 41, 63, 85, 169, 164, 84, 173, 27, 96, 208, 199, 231, 197, 213, 177, 50, 196, 243, 116, 61, 220, 222],
+		[200, 230, 206, 152, 158, 86, 79, 154, 197, 165, 64, 139, 107, 11, 164, 177, 255, 249, 84, 201, 253, 6, 45, 73, 165, 216, 152, 14, 203, 149, 4, 203],
+		[32, 245, 38, 213, 183, 168, 30, 211, 205, 49, 45, 172, 136, 83, 94, 12, 75, 62, 62, 158, 186, 241, 7, 176, 136, 160, 228, 153, 239, 187, 154, 227],
 	],
 	[
-		[227, 63, 28, 103, 115, 57, 53, 7, 150, 208, 223, 248, 154, 121, 4, 105, 253, 227, 36, 249, 79, 242, 0, 205, 226, 159, 204, 145, 219, 3, 29, 52],
-		[5, 99, 219, 247, 31, 234, 69, 194, 69, 235, 75, 39, 12, 168, 197, 205, 200, 200, 147, 124, 185, 118, 129, 7, 180, 96, 118, 227, 32, 244, 165, 3],
-		[166, 64, 85, 151, 28, 18, 125, 156, 251, 180, 69, 233, 156, 37, 37, 60, 177, 108, 203, 121, 41, 193, 14, 113, 160, 88, 185, 148, 141, 148, 228, 107],
-		[178, 209, 97, 82, 237, 17, 97, 214, 96, 141, 255, 130, 215, 52, 181, 243, 146, 80, 191, 165, 18, 136, 63, 191, 196, 133, 39, 224, 42, 188, 215, 4],
+		[124, 150, 63, 105, 202, 88, 16, 149, 161, 34, 4, 197, 171, 229, 19, 9, 51, 236, 58, 20, 55, 250, 168, 123, 167, 95, 82, 54, 7, 83, 155, 217],
+		[111, 229, 98, 197, 235, 252, 7, 206, 133, 87, 198, 217, 140, 124, 246, 149, 72, 96, 15, 67, 14, 97, 45, 221, 209, 37, 200, 227, 245, 71, 86, 79],
+		[239, 118, 138, 27, 77, 43, 6, 154, 147, 6, 172, 89, 250, 197, 98, 69, 239, 63, 19, 225, 69, 228, 165, 104, 24, 178, 148, 244, 176, 29, 122, 225],
+		[32, 254, 69, 41, 212, 247, 254, 163, 54, 167, 45, 252, 10, 59, 94, 104, 127, 233, 189, 188, 187, 106, 96, 130, 214, 42, 15, 35, 238, 241, 46, 72],
 	],
 	[
-		[46, 101, 203, 152, 122, 67, 39, 56, 186, 49, 94, 1, 195, 56, 236, 117, 6, 238, 41, 89, 240, 41, 203, 93, 96, 89, 122, 88, 23, 58, 110, 25],
-		[103, 163, 217, 18, 13, 66, 124, 214, 242, 89, 196, 181, 4, 147, 179, 210, 97, 49, 103, 204, 168, 236, 4, 58, 156, 10, 191, 148, 148, 201, 88, 115],
-		[174, 76, 180, 235, 226, 249, 217, 111, 175, 223, 50, 49, 88, 138, 211, 196, 109, 66, 162, 208, 79, 73, 99, 254, 0, 11, 252, 5, 92, 64, 97, 132],
-		[172, 41, 247, 236, 185, 55, 126, 227, 131, 184, 72, 201, 110, 125, 245, 119, 117, 215, 42, 234, 148, 117, 207, 84, 136, 176, 58, 216, 227, 32, 83, 236],
+		[201, 15, 170, 25, 69, 188, 6, 247, 190, 79, 129, 60, 234, 163, 128, 110, 2, 26, 167, 90, 163, 119, 47, 168, 191, 56, 129, 49, 210, 48, 122, 39],
+		[93, 112, 209, 27, 46, 71, 186, 134, 33, 37, 235, 135, 223, 255, 41, 62, 33, 103, 75, 50, 46, 15, 181, 161, 7, 150, 89, 201, 80, 146, 69, 179],
+		[33, 48, 191, 193, 106, 56, 45, 57, 168, 201, 86, 253, 86, 63, 63, 145, 37, 80, 102, 207, 253, 97, 51, 184, 100, 111, 42, 228, 150, 227, 27, 33],
+		[217, 20, 98, 191, 180, 71, 204, 110, 23, 15, 164, 24, 11, 22, 103, 4, 84, 72, 181, 213, 122, 6, 183, 254, 241, 126, 244, 19, 23, 60, 21, 36],
 	],
 	[
-		[77, 145, 46, 84, 102, 12, 241, 133, 243, 21, 202, 77, 227, 204, 50, 198, 141, 59, 188, 207, 18, 116, 238, 234, 64, 211, 130, 47, 219, 170, 202, 167],
-		[44, 153, 177, 171, 8, 69, 25, 72, 148, 75, 161, 35, 56, 187, 27, 25, 8, 118, 50, 18, 95, 241, 209, 0, 96, 135, 191, 230, 248, 72, 155, 73],
-		[3, 217, 70, 129, 150, 240, 116, 153, 66, 13, 76, 94, 235, 6, 6, 203, 98, 108, 53, 75, 97, 221, 130, 7, 33, 18, 20, 131, 71, 232, 242, 14],
-		[220, 76, 48, 255, 217, 87, 38, 161, 234, 230, 219, 72, 59, 67, 135, 225, 210, 193, 207, 133, 70, 30, 14, 21, 179, 32, 55, 126, 47, 112, 130, 208],
+		[45, 84, 35, 219, 40, 116, 20, 198, 159, 67, 24, 20, 191, 69, 175, 220, 135, 248, 7, 128, 86, 107, 237, 4, 166, 209, 213, 71, 109, 78, 56, 137],
+		[171, 108, 249, 51, 214, 1, 187, 142, 177, 97, 166, 111, 154, 152, 39, 44, 36, 205, 201, 213, 80, 38, 248, 83, 121, 180, 56, 149, 76, 182, 127, 228],
+		[145, 134, 64, 187, 9, 44, 93, 100, 217, 133, 178, 8, 23, 42, 93, 23, 160, 159, 220, 202, 175, 16, 215, 185, 76, 18, 78, 15, 95, 191, 75, 64],
+		[215, 75, 53, 34, 12, 135, 13, 130, 126, 211, 34, 240, 28, 220, 138, 9, 122, 94, 202, 154, 2, 95, 222, 12, 18, 69, 37, 92, 76, 154, 128, 19],
 	],
 	[
-		[108, 28, 54, 215, 167, 194, 92, 121, 144, 124, 80, 33, 235, 222, 22, 90, 110, 159, 2, 65, 63, 48, 92, 48, 128, 157, 127, 97, 160, 19, 191, 52],
-		[4, 212, 58, 95, 213, 70, 169, 2, 49, 48, 143, 151, 98, 226, 140, 48, 48, 252, 28, 139, 44, 234, 232, 37, 156, 108, 32, 21, 40, 138, 146, 136],
-		[188, 44, 238, 234, 130, 197, 89, 217, 140, 85, 71, 247, 2, 170, 32, 20, 143, 114, 181, 220, 30, 128, 54, 250, 130, 114, 30, 44, 189, 33, 143, 146],
-		[52, 172, 87, 145, 209, 52, 8, 42, 59, 144, 246, 148, 14, 120, 249, 88, 2, 147, 222, 164, 236, 175, 118, 220, 213, 113, 94, 220, 158, 159, 111, 91],
+		[202, 214, 62, 146, 227, 212, 32, 255, 81, 38, 151, 4, 239, 21, 135, 44, 204, 180, 75, 209, 79, 33, 236, 12, 89, 114, 31, 43, 212, 39, 234, 106],
+		[103, 5, 26, 120, 172, 65, 206, 154, 122, 115, 93, 205, 196, 244, 4, 109, 81, 162, 183, 200, 47, 160, 168, 119, 127, 167, 142, 205, 153, 181, 35, 79],
+		[24, 56, 68, 117, 161, 80, 249, 87, 176, 121, 99, 75, 196, 170, 159, 238, 97, 163, 22, 5, 175, 4, 5, 130, 34, 230, 21, 205, 98, 78, 205, 77],
+		[16, 253, 246, 235, 7, 190, 240, 145, 33, 158, 91, 211, 130, 23, 145, 176, 179, 85, 214, 61, 168, 122, 114, 124, 57, 3, 82, 150, 192, 242, 134, 15],
 	],
 	[
-		[5, 99, 23, 89, 176, 223, 32, 38, 121, 221, 234, 82, 190, 86, 1, 144, 232, 108, 189, 132, 107, 6, 240, 82, 74, 16, 233, 91, 108, 186, 225, 133],
-		[195, 169, 48, 142, 212, 66, 207, 61, 78, 182, 69, 6, 196, 85, 124, 232, 96, 213, 161, 127, 176, 104, 76, 124, 124, 45, 145, 77, 74, 155, 148, 191],
-		[142, 211, 127, 193, 148, 166, 212, 174, 82, 142, 32, 227, 110, 56, 156, 153, 54, 58, 9, 33, 120, 211, 183, 106, 2, 67, 167, 245, 155, 183, 214, 230],
-		[203, 159, 168, 29, 89, 113, 204, 146, 194, 58, 88, 133, 182, 122, 252, 246, 198, 0, 40, 182, 68, 190, 27, 149, 198, 241, 192, 161, 63, 106, 242, 89],
+		[91, 30, 138, 103, 200, 74, 196, 198, 40, 226, 239, 191, 133, 45, 155, 64, 201, 164, 82, 83, 81, 105, 39, 65, 147, 157, 253, 224, 128, 171, 21, 136],
+		[66, 193, 208, 2, 181, 13, 50, 176, 129, 233, 74, 236, 209, 65, 30, 66, 78, 93, 85, 78, 45, 141, 213, 153, 100, 153, 214, 185, 94, 31, 173, 145],
+		[113, 79, 197, 84, 49, 13, 51, 39, 26, 110, 241, 90, 238, 177, 63, 102, 53, 228, 192, 145, 87, 165, 65, 233, 147, 154, 249, 152, 59, 122, 142, 70],
+		[81, 103, 188, 103, 39, 72, 52, 85, 182, 25, 211, 172, 81, 18, 162, 201, 115, 69, 121, 169, 86, 180, 7, 183, 252, 107, 46, 71, 187, 105, 255, 237],
 	],
 	[
-		[145, 161, 48, 234, 186, 168, 48, 111, 25, 96, 130, 106, 142, 18, 227, 254, 189, 18, 139, 196, 64, 11, 198, 236, 242, 147, 138, 144, 245, 112, 78, 186],
-		[72, 135, 97, 140, 219, 149, 173, 83, 19, 99, 222, 64, 81, 121, 133, 242, 37, 66, 57, 60, 141, 65, 186, 157, 93, 22, 198, 98, 148, 148, 79, 35],
-		[7, 74, 147, 53, 216, 144, 172, 223, 131, 13, 15, 35, 172, 28, 187, 18, 189, 71, 58, 29, 250, 139, 159, 191, 241, 241, 61, 226, 184, 226, 129, 172],
-		[139, 5, 175, 198, 115, 204, 39, 214, 207, 203, 12, 171, 20, 218, 238, 222, 244, 134, 230, 211, 193, 216, 235, 112, 63, 0, 104, 147, 67, 115, 5, 144],
+		[179, 23, 167, 131, 60, 38, 242, 226, 172, 138, 152, 191, 5, 86, 155, 103, 221, 43, 120, 45, 116, 217, 100, 101, 212, 4, 235, 215, 209, 16, 128, 24],
+		[107, 155, 205, 156, 87, 50, 74, 175, 193, 251, 173, 66, 36, 80, 55, 186, 164, 137, 178, 145, 218, 247, 12, 122, 206, 216, 70, 49, 74, 73, 93, 215],
+		[102, 6, 131, 106, 114, 149, 62, 86, 96, 144, 214, 143, 255, 176, 172, 47, 207, 158, 159, 103, 40, 24, 142, 202, 243, 166, 128, 220, 118, 231, 131, 32],
+		[239, 229, 192, 156, 2, 65, 117, 101, 253, 23, 201, 18, 218, 85, 223, 41, 19, 227, 157, 51, 133, 116, 220, 104, 155, 108, 219, 76, 172, 193, 240, 149],
 	],
 	[
-		[66, 56, 159, 34, 94, 145, 187, 255, 145, 20, 200, 71, 109, 168, 174, 136, 59, 69, 70, 45, 189, 226, 49, 123, 246, 122, 201, 255, 210, 163, 178, 81],
-		[109, 189, 243, 245, 157, 87, 52, 62, 107, 6, 159, 73, 73, 120, 192, 145, 208, 49, 83, 124, 80, 227, 69, 143, 60, 143, 153, 66, 180, 154, 30, 84],
-		[69, 230, 247, 156, 208, 196, 85, 141, 229, 140, 28, 141, 221, 216, 53, 48, 93, 115, 219, 189, 183, 129, 97, 242, 41, 199, 83, 167, 137, 176, 29, 78],
-		[142, 98, 17, 89, 0, 192, 3, 113, 13, 46, 192, 203, 68, 250, 196, 101, 82, 20, 210, 54, 117, 185, 233, 67, 119, 41, 137, 210, 158, 241, 90, 186],
+		[229, 74, 146, 164, 146, 137, 241, 31, 28, 221, 223, 92, 126, 212, 106, 12, 25, 43, 126, 30, 81, 226, 19, 180, 82, 248, 66, 28, 220, 128, 162, 153],
+		[28, 73, 212, 160, 48, 84, 22, 214, 250, 172, 216, 178, 146, 179, 105, 62, 81, 102, 240, 181, 198, 21, 5, 70, 56, 195, 88, 160, 90, 198, 53, 142],
+		[82, 234, 245, 24, 132, 242, 166, 60, 84, 52, 132, 17, 253, 45, 90, 232, 169, 210, 167, 245, 28, 208, 120, 117, 81, 216, 241, 2, 238, 197, 94, 242],
+		[47, 219, 163, 172, 11, 97, 112, 159, 79, 10, 16, 132, 181, 162, 60, 23, 173, 210, 103, 99, 227, 97, 22, 170, 1, 97, 216, 192, 180, 104, 94, 228],
 	],
 	[
-		[2, 164, 186, 5, 67, 204, 14, 17, 145, 15, 53, 154, 196, 147, 49, 178, 199, 191, 7, 22, 34, 241, 238, 88, 63, 107, 201, 115, 137, 251, 77, 133],
-		[30, 184, 148, 182, 126, 137, 113, 10, 62, 29, 58, 34, 60, 28, 34, 190, 182, 190, 194, 46, 82, 176, 151, 30, 238, 40, 80, 38, 118, 28, 84, 139],
-		[181, 118, 209, 43, 65, 187, 254, 71, 205, 40, 217, 143, 140, 113, 133, 141, 141, 83, 184, 93, 172, 126, 76, 175, 178, 108, 66, 125, 42, 9, 165, 75],
-		[11, 196, 188, 178, 160, 236, 206, 6, 184, 46, 188, 30, 55, 108, 165, 241, 78, 131, 28, 199, 164, 121, 122, 111, 176, 134, 202, 206, 61, 75, 7, 242],
+		[112, 103, 7, 17, 23, 34, 158, 246, 194, 200, 170, 40, 228, 239, 255, 227, 250, 163, 63, 9, 109, 77, 253, 149, 21, 240, 108, 56, 92, 111, 244, 79],
+		[112, 127, 134, 193, 3, 138, 44, 152, 54, 197, 3, 4, 33, 123, 78, 94, 162, 93, 176, 201, 195, 227, 164, 7, 158, 160, 96, 247, 254, 246, 188, 233],
+		[89, 119, 70, 18, 84, 212, 35, 5, 46, 133, 225, 145, 141, 161, 167, 2, 77, 79, 171, 109, 182, 91, 112, 197, 63, 72, 84, 246, 250, 73, 149, 132],
+		[167, 52, 244, 19, 111, 137, 136, 175, 37, 157, 252, 169, 75, 36, 90, 41, 127, 223, 208, 142, 196, 125, 233, 255, 35, 239, 186, 156, 40, 149, 249, 19],
 	],
 	[
-		[132, 115, 195, 37, 146, 143, 163, 168, 152, 227, 110, 52, 80, 191, 146, 128, 105, 106, 85, 236, 83, 41, 204, 53, 207, 190, 219, 210, 119, 135, 99, 136],
-		[149, 26, 40, 248, 117, 201, 232, 135, 214, 215, 122, 162, 4, 197, 154, 33, 24, 220, 133, 80, 105, 170, 254, 57, 226, 196, 124, 108, 7, 24, 132, 113],
-		[72, 4, 9, 25, 141, 210, 100, 43, 138, 255, 135, 40, 48, 6, 139, 132, 192, 130, 32, 82, 30, 4, 249, 93, 48, 82, 95, 11, 53, 128, 22, 174],
-		[98, 222, 146, 83, 26, 3, 119, 206, 224, 135, 52, 47, 125, 67, 82, 129, 92, 20, 70, 212, 96, 60, 107, 230, 75, 95, 113, 135, 123, 50, 4, 138],
+		[157, 180, 253, 169, 110, 27, 17, 199, 106, 118, 93, 226, 231, 69, 95, 109, 64, 44, 202, 241, 32, 212, 222, 7, 32, 173, 18, 188, 160, 2, 245, 102],
+		[177, 176, 139, 14, 155, 199, 204, 215, 212, 14, 164, 202, 14, 196, 221, 209, 255, 32, 194, 246, 20, 49, 172, 163, 44, 15, 64, 230, 174, 136, 122, 99],
+		[177, 66, 251, 168, 94, 119, 236, 98, 147, 169, 107, 171, 214, 136, 212, 16, 37, 232, 247, 252, 162, 107, 78, 101, 128, 170, 68, 40, 213, 164, 107, 190],
+		[137, 251, 173, 15, 195, 239, 142, 16, 168, 99, 29, 12, 151, 59, 177, 136, 86, 147, 102, 162, 121, 199, 17, 152, 213, 233, 157, 25, 36, 238, 204, 176],
 	],
 	[
-		[75, 93, 35, 115, 35, 158, 74, 119, 30, 201, 137, 72, 56, 86, 82, 19, 44, 117, 112, 166, 113, 159, 173, 11, 162, 229, 38, 235, 164, 137, 166, 52],
-		[126, 72, 235, 60, 31, 32, 157, 63, 59, 206, 255, 116, 73, 111, 219, 146, 73, 77, 81, 17, 39, 199, 152, 147, 231, 76, 216, 55, 96, 219, 153, 34],
-		[43, 162, 174, 232, 174, 83, 170, 225, 123, 64, 94, 121, 192, 222, 54, 231, 38, 53, 165, 10, 67, 29, 112, 106, 96, 106, 2, 48, 119, 120, 8, 7],
-		[25, 7, 181, 144, 74, 58, 143, 24, 117, 83, 121, 97, 106, 155, 247, 23, 38, 137, 17, 73, 108, 77, 135, 139, 122, 240, 151, 203, 234, 125, 165, 191],
+		[116, 15, 188, 223, 38, 180, 14, 170, 143, 108, 65, 40, 223, 255, 12, 255, 13, 214, 47, 203, 153, 18, 230, 141, 189, 246, 49, 50, 177, 112, 4, 61],
+		[202, 118, 212, 120, 239, 228, 165, 222, 20, 117, 15, 155, 194, 149, 166, 46, 149, 144, 179, 153, 211, 81, 177, 21, 70, 74, 103, 61, 38, 35, 20, 86],
+		[21, 46, 153, 163, 11, 207, 22, 3, 139, 196, 128, 18, 110, 99, 231, 171, 190, 32, 68, 9, 252, 58, 85, 191, 2, 192, 131, 108, 28, 243, 44, 99],
+		[234, 200, 157, 96, 25, 187, 133, 85, 111, 142, 21, 155, 107, 143, 82, 86, 42, 154, 47, 151, 243, 209, 153, 115, 150, 56, 176, 8, 19, 243, 203, 18],
 	],
 	[
-		[143, 170, 135, 149, 132, 36, 34, 46, 101, 210, 29, 1, 37, 143, 187, 102, 173, 25, 102, 245, 108, 223, 113, 236, 112, 200, 12, 38, 111, 250, 194, 156],
-		[194, 96, 57, 146, 124, 235, 61, 216, 37, 59, 9, 135, 199, 13, 12, 37, 191, 147, 105, 20, 56, 133, 234, 76, 151, 137, 200, 31, 204, 90, 212, 21],
-		[250, 216, 43, 136, 98, 206, 136, 14, 61, 49, 234, 166, 232, 135, 222, 174, 17, 6, 76, 4, 14, 85, 130, 92, 170, 26, 0, 5, 30, 191, 137, 38],
-		[53, 89, 121, 177, 162, 175, 50, 82, 126, 14, 118, 66, 37, 64, 49, 9, 251, 47, 113, 240, 161, 43, 151, 78, 9, 120, 33, 226, 46, 163, 145, 33],
+		[55, 122, 110, 156, 188, 217, 73, 105, 67, 107, 23, 198, 176, 34, 227, 138, 237, 251, 92, 60, 20, 237, 177, 178, 160, 237, 122, 167, 17, 226, 21, 153],
+		[108, 163, 217, 123, 213, 35, 106, 223, 198, 232, 180, 112, 30, 93, 35, 64, 159, 34, 227, 239, 78, 222, 89, 234, 186, 136, 82, 226, 147, 155, 248, 139],
+		[157, 18, 205, 33, 173, 54, 2, 19, 181, 151, 198, 216, 175, 170, 66, 10, 98, 5, 96, 100, 27, 47, 179, 157, 252, 114, 7, 84, 17, 211, 112, 97],
+		[214, 222, 251, 134, 161, 9, 15, 253, 131, 234, 57, 201, 18, 64, 21, 178, 88, 31, 53, 62, 41, 129, 175, 106, 54, 14, 170, 62, 22, 253, 157, 19],
 	],
 	[
-		[33, 248, 98, 181, 179, 44, 159, 191, 87, 251, 67, 249, 155, 81, 60, 197, 151, 59, 184, 3, 141, 121, 116, 1, 231, 15, 105, 209, 206, 151, 19, 26],
-		[40, 41, 82, 199, 210, 81, 71, 167, 194, 144, 112, 20, 78, 131, 158, 63, 30, 88, 233, 243, 134, 188, 197, 157, 165, 147, 168, 170, 83, 114, 27, 145],
-		[101, 48, 162, 137, 100, 203, 145, 118, 52, 65, 88, 250, 115, 127, 58, 220, 189, 173, 223, 3, 122, 185, 123, 243, 192, 111, 82, 170, 32, 54, 20, 201],
-		[152, 70, 181, 238, 196, 93, 164, 229, 201, 141, 42, 67, 66, 117, 13, 140, 210, 128, 158, 112, 148, 101, 74, 179, 98, 105, 51, 115, 190, 79, 175, 38],
+		[26, 110, 7, 128, 244, 235, 97, 96, 31, 191, 111, 187, 228, 9, 189, 154, 100, 89, 141, 132, 143, 200, 239, 141, 243, 3, 44, 204, 210, 216, 221, 168],
+		[240, 113, 249, 208, 62, 44, 91, 254, 251, 203, 72, 168, 241, 127, 255, 184, 213, 64, 187, 63, 72, 94, 225, 131, 192, 115, 114, 244, 132, 114, 95, 230],
+		[182, 249, 226, 53, 158, 13, 18, 94, 198, 185, 251, 54, 45, 189, 108, 120, 251, 44, 102, 67, 214, 83, 79, 107, 196, 149, 128, 139, 18, 170, 141, 18],
+		[87, 64, 4, 51, 194, 134, 210, 144, 111, 81, 79, 44, 76, 46, 67, 143, 165, 240, 38, 9, 11, 222, 164, 40, 244, 204, 1, 132, 56, 199, 62, 191],
 	],
 	[
-		[76, 80, 103, 33, 233, 13, 64, 216, 239, 44, 212, 58, 188, 240, 139, 15, 33, 43, 202, 207, 179, 34, 6, 104, 229, 54, 18, 124, 119, 111, 75, 0],
-		[2, 151, 4, 255, 19, 192, 48, 215, 139, 10, 230, 100, 77, 32, 239, 127, 2, 126, 117, 37, 230, 144, 122, 30, 145, 59, 88, 16, 209, 100, 160, 236],
-		[25, 38, 176, 65, 74, 250, 174, 137, 214, 12, 168, 123, 189, 157, 148, 100, 150, 49, 109, 146, 188, 151, 122, 143, 101, 95, 97, 100, 119, 72, 128, 69],
-		[75, 200, 86, 105, 4, 14, 216, 86, 235, 66, 225, 195, 113, 176, 78, 98, 55, 159, 99, 213, 44, 190, 135, 88, 40, 207, 122, 87, 64, 176, 42, 5],
+		[35, 169, 217, 34, 35, 87, 116, 173, 53, 215, 113, 215, 37, 110, 49, 35, 204, 227, 120, 203, 1, 126, 174, 117, 98, 255, 176, 177, 24, 75, 93, 140],
+		[220, 14, 87, 184, 4, 103, 171, 181, 48, 21, 126, 140, 136, 120, 101, 124, 58, 189, 244, 8, 234, 156, 11, 32, 1, 241, 49, 181, 180, 53, 170, 242],
+		[9, 106, 87, 193, 18, 160, 67, 78, 183, 228, 145, 140, 221, 47, 148, 99, 68, 82, 90, 72, 186, 149, 20, 252, 96, 250, 20, 253, 21, 75, 247, 32],
+		[55, 73, 63, 251, 126, 56, 106, 8, 119, 55, 87, 52, 250, 227, 153, 92, 182, 3, 126, 133, 221, 30, 232, 226, 10, 96, 53, 119, 246, 229, 36, 199],
 	],
 	[
-		[182, 60, 16, 249, 232, 187, 148, 236, 74, 252, 53, 154, 103, 146, 82, 90, 50, 30, 130, 134, 255, 132, 34, 239, 93, 148, 196, 179, 7, 57, 130, 10],
-		[55, 149, 189, 137, 84, 25, 13, 238, 178, 189, 139, 48, 149, 67, 224, 107, 16, 74, 143, 39, 83, 95, 98, 89, 227, 86, 205, 250, 174, 106, 112, 102],
-		[4, 69, 150, 97, 175, 178, 149, 65, 151, 8, 169, 156, 44, 81, 129, 168, 222, 233, 89, 159, 78, 17, 251, 231, 235, 236, 60, 160, 140, 61, 241, 191],
-		[81, 209, 95, 213, 20, 44, 77, 131, 202, 25, 218, 159, 195, 104, 173, 235, 125, 233, 215, 210, 8, 244, 219, 67, 75, 184, 241, 218, 70, 189, 120, 150],
+		[106, 28, 139, 139, 181, 50, 43, 123, 240, 3, 232, 78, 102, 61, 41, 208, 23, 181, 104, 68, 0, 20, 208, 239, 50, 164, 146, 235, 176, 250, 72, 55],
+		[89, 243, 97, 31, 15, 231, 88, 32, 44, 87, 141, 9, 51, 114, 53, 243, 96, 188, 161, 232, 33, 183, 100, 148, 240, 62, 119, 151, 236, 100, 114, 191],
+		[62, 175, 193, 46, 60, 4, 88, 59, 29, 91, 18, 118, 56, 66, 30, 131, 51, 234, 193, 84, 227, 91, 124, 222, 238, 238, 224, 101, 126, 147, 89, 73],
+		[213, 78, 192, 204, 169, 252, 47, 50, 197, 251, 127, 166, 201, 193, 107, 93, 16, 157, 134, 220, 152, 118, 152, 68, 207, 90, 53, 217, 77, 197, 16, 132],
 	],
 	[
-		[224, 252, 185, 137, 140, 27, 228, 225, 84, 37, 4, 64, 159, 233, 223, 111, 213, 179, 130, 162, 216, 94, 17, 42, 57, 81, 114, 35, 215, 165, 2, 40],
-		[242, 185, 36, 183, 111, 72, 120, 19, 204, 186, 135, 179, 112, 171, 69, 212, 223, 234, 182, 233, 87, 31, 127, 243, 10, 176, 246, 182, 64, 128, 97, 205],
-		[69, 201, 155, 119, 114, 36, 148, 69, 126, 26, 227, 207, 244, 202, 84, 108, 238, 245, 73, 131, 85, 154, 42, 110, 165, 75, 81, 127, 224, 128, 43, 63],
-		[131, 194, 208, 40, 248, 107, 141, 155, 91, 101, 115, 43, 181, 213, 51, 141, 136, 144, 166, 157, 201, 203, 135, 66, 4, 15, 162, 77, 14, 192, 10, 22],
+		[117, 209, 184, 231, 70, 240, 187, 120, 171, 172, 111, 115, 106, 34, 28, 225, 27, 183, 6, 104, 205, 72, 141, 92, 13, 69, 8, 193, 38, 184, 181, 132],
+		[134, 148, 161, 56, 111, 159, 231, 47, 141, 126, 235, 59, 47, 100, 204, 69, 99, 28, 236, 237, 25, 84, 41, 80, 73, 186, 244, 156, 110, 15, 242, 195],
+		[172, 19, 21, 118, 121, 52, 109, 106, 194, 197, 85, 136, 10, 163, 14, 102, 27, 171, 149, 181, 253, 179, 20, 47, 215, 250, 58, 25, 123, 9, 50, 22],
+		[2, 163, 36, 149, 218, 47, 208, 76, 115, 99, 76, 247, 159, 12, 205, 181, 96, 46, 145, 237, 248, 178, 185, 133, 37, 225, 211, 101, 2, 124, 237, 36],
 	],
 	[
-		[141, 139, 178, 156, 23, 163, 173, 168, 98, 243, 129, 246, 77, 140, 183, 27, 139, 42, 129, 31, 25, 125, 250, 244, 233, 75, 246, 60, 3, 110, 50, 201],
-		[95, 159, 200, 246, 59, 235, 82, 158, 184, 223, 179, 3, 109, 211, 48, 114, 120, 23, 127, 62, 21, 253, 27, 3, 188, 48, 12, 174, 79, 196, 123, 155],
-		[140, 22, 85, 241, 175, 152, 226, 137, 75, 30, 119, 28, 34, 105, 15, 45, 115, 64, 62, 79, 63, 122, 252, 214, 225, 136, 12, 130, 138, 36, 144, 239],
-		[179, 154, 7, 187, 171, 97, 20, 117, 42, 22, 149, 110, 19, 181, 189, 120, 61, 241, 194, 172, 82, 27, 8, 195, 210, 73, 77, 195, 181, 240, 53, 15],
+		[251, 162, 92, 235, 223, 65, 253, 194, 53, 181, 75, 176, 117, 156, 125, 74, 33, 27, 90, 50, 252, 82, 15, 110, 235, 126, 11, 181, 110, 244, 71, 44],
+		[146, 252, 143, 10, 9, 43, 232, 195, 49, 36, 111, 216, 102, 179, 133, 5, 18, 61, 145, 181, 198, 3, 19, 18, 48, 93, 65, 140, 13, 172, 77, 207],
+		[241, 26, 141, 116, 37, 29, 114, 185, 117, 141, 154, 28, 64, 56, 28, 183, 207, 101, 73, 32, 161, 22, 7, 106, 209, 98, 152, 99, 243, 169, 227, 74],
+		[10, 20, 90, 212, 97, 118, 208, 205, 55, 107, 154, 163, 253, 109, 234, 168, 181, 24, 55, 142, 27, 218, 57, 163, 40, 240, 141, 24, 114, 142, 0, 102],
 	],
 	[
-		[76, 181, 200, 67, 191, 222, 21, 82, 52, 153, 158, 21, 244, 188, 52, 158, 152, 40, 141, 96, 3, 215, 127, 103, 134, 36, 112, 250, 194, 135, 228, 34],
-		[198, 54, 81, 34, 198, 82, 40, 50, 148, 117, 187, 45, 95, 102, 17, 221, 75, 3, 167, 206, 88, 149, 61, 244, 98, 26, 134, 235, 211, 180, 58, 22],
-		[201, 139, 237, 11, 108, 142, 230, 92, 173, 91, 108, 160, 25, 34, 198, 77, 184, 216, 174, 26, 209, 239, 107, 59, 111, 85, 30, 240, 79, 242, 156, 33],
-		[133, 227, 35, 97, 189, 54, 137, 59, 239, 139, 209, 1, 114, 190, 104, 21, 165, 34, 245, 206, 48, 19, 5, 101, 162, 212, 79, 137, 87, 98, 56, 9],
+		[194, 94, 171, 3, 137, 208, 246, 0, 109, 253, 255, 65, 196, 161, 49, 113, 38, 29, 67, 100, 35, 31, 114, 73, 92, 61, 111, 187, 64, 222, 95, 75],
+		[252, 41, 167, 14, 224, 170, 170, 21, 192, 62, 185, 187, 113, 75, 47, 42, 23, 184, 97, 6, 97, 64, 141, 131, 1, 239, 9, 172, 32, 90, 42, 234],
+		[129, 187, 243, 104, 119, 189, 68, 156, 19, 35, 246, 77, 122, 120, 144, 122, 102, 188, 173, 52, 227, 153, 253, 229, 101, 56, 11, 224, 109, 154, 207, 205],
+		[111, 83, 48, 228, 58, 190, 140, 249, 213, 94, 26, 48, 248, 223, 22, 144, 73, 72, 234, 206, 213, 150, 137, 60, 33, 225, 135, 251, 10, 32, 97, 12],
 	],
 	[
-		[15, 191, 117, 102, 238, 172, 248, 58, 109, 37, 151, 137, 51, 10, 65, 125, 227, 118, 148, 85, 147, 211, 11, 185, 2, 96, 31, 219, 169, 56, 82, 175],
-		[13, 87, 218, 169, 174, 142, 136, 70, 157, 227, 105, 98, 223, 150, 137, 56, 226, 217, 157, 91, 234, 148, 11, 149, 164, 107, 16, 204, 1, 107, 235, 160],
-		[245, 48, 163, 172, 137, 96, 144, 155, 108, 71, 21, 54, 202, 129, 224, 2, 18, 9, 144, 33, 148, 71, 70, 14, 81, 154, 104, 143, 139, 101, 225, 138],
-		[98, 136, 127, 220, 123, 63, 193, 5, 149, 232, 216, 216, 182, 113, 25, 24, 89, 54, 181, 140, 28, 131, 252, 80, 128, 166, 36, 139, 95, 180, 65, 165],
+		[181, 175, 146, 110, 29, 3, 144, 112, 100, 128, 214, 5, 50, 33, 216, 32, 212, 73, 215, 192, 59, 250, 182, 247, 16, 140, 4, 246, 67, 234, 169, 66],
+		[18, 102, 184, 14, 226, 253, 75, 155, 58, 155, 24, 112, 25, 141, 18, 43, 191, 163, 69, 73, 189, 231, 118, 76, 221, 89, 29, 200, 52, 8, 154, 72],
+		[132, 0, 200, 81, 124, 187, 237, 199, 181, 41, 221, 118, 104, 201, 233, 254, 100, 16, 232, 63, 106, 213, 82, 59, 75, 236, 174, 159, 147, 181, 22, 225],
+		[66, 196, 63, 36, 183, 122, 146, 46, 131, 253, 36, 134, 143, 254, 130, 255, 91, 144, 76, 67, 12, 192, 145, 147, 73, 161, 30, 92, 129, 55, 194, 110],
 	],
 	[
-		[56, 75, 213, 87, 93, 3, 193, 134, 237, 1, 129, 40, 82, 23, 28, 67, 116, 179, 23, 191, 223, 92, 163, 249, 196, 146, 110, 143, 143, 181, 121, 233],
-		[227, 38, 248, 91, 157, 47, 193, 187, 152, 9, 161, 202, 185, 227, 32, 17, 138, 137, 99, 116, 26, 232, 118, 204, 124, 59, 251, 127, 121, 86, 190, 133],
-		[104, 0, 100, 21, 49, 168, 241, 221, 60, 202, 184, 147, 189, 159, 165, 136, 118, 30, 255, 36, 118, 158, 96, 196, 100, 200, 82, 213, 179, 177, 23, 25],
-		[155, 201, 174, 68, 165, 215, 15, 111, 32, 118, 36, 175, 230, 90, 218, 132, 117, 136, 238, 203, 106, 212, 201, 131, 198, 155, 166, 216, 21, 42, 111, 195],
+		[96, 172, 35, 56, 153, 87, 39, 46, 179, 232, 3, 14, 125, 42, 157, 48, 16, 73, 18, 156, 92, 77, 177, 225, 187, 4, 18, 208, 37, 209, 120, 78],
+		[189, 97, 150, 175, 55, 8, 135, 184, 191, 80, 97, 246, 239, 15, 77, 239, 33, 119, 177, 146, 45, 199, 226, 31, 30, 205, 11, 237, 229, 76, 249, 224],
+		[22, 168, 181, 8, 37, 137, 58, 220, 68, 166, 40, 114, 30, 160, 242, 180, 185, 184, 225, 19, 218, 145, 43, 38, 73, 70, 27, 197, 115, 73, 35, 236],
+		[110, 201, 161, 189, 106, 254, 187, 119, 74, 67, 228, 29, 144, 47, 251, 174, 248, 199, 17, 223, 209, 116, 35, 16, 168, 48, 229, 50, 217, 98, 186, 32],
 	],
 	[
-		[3, 154, 52, 225, 124, 189, 193, 201, 228, 203, 75, 232, 150, 102, 6, 214, 112, 211, 200, 252, 88, 31, 92, 240, 49, 15, 213, 228, 64, 175, 18, 49],
-		[167, 83, 1, 12, 122, 129, 203, 91, 212, 241, 85, 71, 217, 7, 234, 55, 78, 154, 117, 112, 95, 6, 222, 175, 165, 111, 21, 106, 210, 184, 237, 143],
-		[74, 24, 184, 241, 191, 195, 106, 90, 241, 81, 10, 159, 17, 125, 112, 111, 107, 184, 255, 195, 220, 163, 216, 27, 127, 28, 16, 7, 165, 21, 65, 231],
-		[212, 80, 77, 28, 3, 180, 195, 203, 38, 105, 36, 26, 176, 45, 4, 106, 158, 112, 47, 174, 170, 213, 7, 138, 119, 86, 172, 38, 142, 109, 144, 204],
+		[16, 143, 27, 106, 57, 82, 4, 224, 190, 44, 151, 59, 62, 77, 127, 108, 71, 196, 132, 127, 37, 222, 199, 228, 250, 123, 254, 162, 60, 108, 201, 70],
+		[34, 89, 245, 78, 221, 47, 161, 104, 176, 44, 97, 219, 164, 92, 115, 125, 175, 62, 198, 209, 243, 84, 248, 128, 235, 41, 163, 152, 71, 29, 94, 209],
+		[205, 57, 146, 52, 161, 103, 110, 223, 95, 34, 0, 159, 167, 82, 142, 45, 6, 65, 3, 169, 7, 186, 57, 202, 190, 32, 180, 227, 53, 96, 64, 224],
+		[83, 174, 82, 174, 37, 65, 143, 60, 92, 55, 168, 136, 11, 232, 54, 233, 191, 67, 196, 140, 84, 81, 193, 28, 157, 196, 54, 164, 176, 131, 53, 240],
 	],
 	[
-		[20, 191, 91, 84, 48, 194, 177, 33, 82, 177, 171, 56, 199, 194, 2, 49, 37, 99, 17, 106, 2, 146, 175, 48, 229, 243, 132, 124, 186, 85, 108, 14],
-		[196, 116, 42, 19, 123, 105, 138, 158, 153, 239, 246, 5, 110, 239, 66, 119, 10, 52, 58, 177, 148, 188, 179, 147, 190, 135, 57, 87, 134, 85, 99, 23],
-		[93, 179, 132, 95, 251, 126, 199, 168, 21, 17, 220, 142, 201, 61, 234, 108, 10, 216, 163, 72, 83, 151, 237, 11, 125, 174, 139, 233, 20, 37, 26, 234],
-		[63, 2, 181, 103, 200, 160, 9, 148, 68, 88, 238, 191, 135, 157, 85, 90, 213, 23, 240, 171, 224, 65, 204, 187, 43, 149, 184, 234, 65, 89, 192, 37],
+		[67, 86, 38, 4, 30, 78, 33, 167, 25, 248, 238, 159, 170, 85, 157, 181, 212, 145, 160, 241, 183, 157, 24, 227, 156, 15, 44, 126, 4, 138, 167, 154],
+		[59, 87, 77, 202, 168, 138, 20, 57, 216, 66, 18, 36, 43, 110, 251, 67, 141, 235, 223, 106, 71, 159, 64, 187, 220, 66, 192, 237, 173, 206, 33, 59],
+		[80, 35, 226, 60, 73, 59, 94, 69, 18, 26, 189, 236, 76, 98, 18, 2, 8, 211, 224, 122, 78, 223, 35, 233, 220, 145, 41, 134, 49, 136, 105, 14],
+		[81, 66, 6, 178, 147, 254, 8, 115, 55, 26, 86, 172, 75, 245, 193, 224, 63, 93, 190, 24, 201, 242, 1, 92, 216, 200, 5, 90, 238, 233, 193, 65],
 	],
 	[
-		[198, 247, 216, 36, 150, 254, 79, 41, 28, 112, 77, 106, 79, 3, 96, 164, 149, 56, 30, 72, 243, 150, 151, 103, 136, 73, 59, 80, 226, 64, 111, 215],
-		[252, 18, 111, 66, 138, 19, 234, 2, 132, 212, 109, 159, 175, 33, 146, 74, 14, 253, 233, 232, 63, 63, 65, 46, 205, 236, 99, 17, 100, 187, 84, 154],
-		[48, 178, 129, 138, 76, 1, 134, 194, 221, 69, 45, 10, 223, 126, 26, 125, 10, 91, 163, 120, 49, 218, 144, 20, 231, 203, 190, 218, 230, 155, 11, 171],
-		[204, 192, 180, 142, 20, 140, 120, 180, 119, 49, 86, 236, 17, 37, 138, 202, 105, 64, 137, 251, 166, 121, 90, 111, 161, 124, 175, 33, 11, 150, 130, 16],
+		[126, 241, 144, 3, 43, 222, 247, 158, 100, 81, 83, 105, 198, 123, 44, 104, 162, 234, 158, 83, 6, 14, 169, 244, 186, 203, 221, 176, 243, 2, 33, 223],
+		[51, 222, 206, 32, 34, 24, 73, 167, 98, 110, 6, 135, 55, 122, 208, 229, 103, 46, 125, 215, 211, 200, 241, 217, 99, 68, 209, 205, 20, 77, 61, 142],
+		[194, 140, 3, 58, 107, 99, 207, 14, 55, 153, 210, 177, 47, 223, 202, 191, 235, 138, 92, 110, 178, 34, 175, 30, 146, 104, 193, 122, 36, 67, 47, 60],
+		[52, 235, 97, 152, 14, 252, 52, 150, 114, 61, 213, 59, 62, 213, 225, 90, 237, 152, 107, 105, 34, 66, 245, 77, 203, 86, 62, 50, 71, 114, 63, 221],
 	],
 	[
-		[1, 179, 19, 93, 129, 254, 252, 43, 190, 86, 201, 249, 14, 136, 108, 145, 40, 140, 180, 32, 31, 250, 69, 63, 204, 128, 212, 180, 15, 160, 103, 92],
-		[117, 133, 78, 46, 161, 173, 30, 161, 231, 4, 136, 185, 229, 14, 112, 66, 58, 115, 212, 4, 171, 94, 108, 218, 93, 102, 62, 86, 169, 23, 1, 232],
-		[13, 53, 45, 2, 28, 37, 8, 104, 196, 132, 37, 113, 222, 138, 203, 160, 190, 32, 164, 255, 153, 98, 62, 31, 204, 25, 33, 249, 51, 174, 200, 2],
-		[39, 234, 218, 207, 25, 245, 193, 1, 69, 7, 198, 222, 208, 57, 198, 221, 112, 5, 137, 21, 227, 73, 104, 116, 44, 43, 226, 211, 27, 5, 105, 20],
+		[146, 49, 42, 133, 135, 191, 136, 2, 65, 79, 8, 61, 25, 214, 128, 202, 241, 89, 175, 55, 13, 131, 47, 239, 81, 214, 209, 102, 210, 120, 218, 163],
+		[220, 249, 45, 36, 74, 52, 78, 236, 130, 180, 183, 232, 174, 82, 251, 134, 41, 161, 245, 20, 58, 153, 239, 134, 178, 116, 205, 173, 247, 102, 230, 185],
+		[76, 38, 19, 235, 25, 27, 149, 147, 75, 29, 199, 46, 157, 248, 131, 4, 201, 57, 200, 183, 140, 20, 219, 140, 220, 193, 199, 61, 232, 112, 48, 218],
+		[200, 219, 8, 38, 11, 35, 2, 41, 51, 131, 211, 221, 48, 97, 139, 188, 83, 240, 250, 91, 43, 189, 131, 215, 90, 240, 255, 48, 105, 134, 234, 213],
 	],
 	[
-		[84, 147, 24, 241, 61, 203, 102, 152, 173, 26, 38, 161, 40, 47, 96, 66, 252, 248, 0, 12, 13, 255, 131, 215, 247, 33, 178, 61, 217, 196, 244, 182],
-		[172, 202, 67, 83, 130, 105, 164, 113, 89, 57, 175, 7, 239, 118, 242, 51, 102, 65, 193, 244, 135, 254, 90, 123, 56, 18, 35, 227, 3, 145, 103, 222],
-		[249, 197, 72, 44, 253, 208, 121, 60, 34, 118, 31, 144, 156, 119, 67, 215, 146, 18, 221, 108, 87, 232, 51, 35, 202, 133, 229, 219, 25, 104, 239, 129],
-		[27, 245, 94, 230, 132, 188, 33, 240, 61, 45, 45, 42, 94, 10, 31, 251, 10, 131, 52, 79, 92, 79, 178, 140, 188, 88, 220, 95, 15, 56, 140, 70],
+		[11, 172, 4, 49, 157, 4, 20, 252, 92, 92, 71, 217, 59, 136, 23, 108, 106, 203, 21, 97, 61, 131, 110, 18, 13, 194, 221, 64, 226, 115, 197, 146],
+		[238, 107, 195, 138, 77, 232, 61, 10, 95, 151, 111, 201, 192, 248, 243, 121, 239, 59, 249, 224, 224, 95, 20, 140, 56, 161, 98, 47, 16, 18, 105, 114],
+		[155, 79, 134, 166, 197, 109, 81, 100, 232, 59, 173, 176, 132, 85, 223, 29, 117, 8, 234, 172, 70, 250, 108, 54, 77, 168, 17, 20, 12, 207, 224, 157],
+		[92, 250, 115, 113, 174, 56, 210, 210, 104, 22, 186, 7, 144, 153, 19, 184, 54, 146, 163, 67, 170, 2, 242, 228, 103, 139, 251, 1, 128, 143, 243, 65],
 	],
 	[
-		[206, 160, 78, 24, 18, 248, 86, 87, 138, 119, 201, 85, 58, 183, 245, 13, 165, 132, 237, 21, 229, 31, 255, 152, 46, 255, 171, 68, 246, 204, 237, 68],
-		[91, 170, 199, 72, 68, 97, 240, 76, 53, 134, 233, 20, 210, 152, 109, 209, 227, 189, 60, 202, 133, 30, 123, 156, 15, 20, 9, 72, 177, 222, 52, 128],
-		[13, 57, 90, 157, 130, 63, 51, 171, 218, 223, 158, 99, 225, 12, 74, 143, 18, 144, 247, 230, 16, 12, 16, 77, 198, 161, 207, 58, 147, 84, 188, 64],
-		[52, 108, 48, 28, 184, 103, 232, 127, 192, 255, 52, 18, 49, 4, 56, 174, 30, 23, 35, 5, 165, 107, 176, 233, 111, 16, 7, 107, 37, 56, 204, 96],
+		[225, 152, 92, 61, 132, 48, 65, 230, 255, 135, 65, 238, 191, 147, 155, 151, 163, 61, 46, 171, 166, 109, 11, 244, 64, 57, 216, 118, 246, 184, 175, 12],
+		[236, 7, 181, 202, 74, 178, 124, 25, 220, 237, 42, 100, 196, 35, 74, 231, 223, 198, 81, 137, 148, 135, 203, 139, 124, 126, 9, 145, 20, 112, 153, 38],
+		[186, 62, 170, 232, 204, 1, 119, 55, 202, 129, 89, 207, 6, 50, 214, 123, 124, 119, 5, 159, 241, 235, 170, 114, 156, 234, 41, 217, 43, 246, 21, 163],
+		[83, 50, 103, 32, 208, 211, 38, 145, 96, 15, 248, 196, 242, 125, 232, 52, 251, 132, 48, 31, 239, 189, 245, 222, 102, 247, 38, 60, 26, 3, 226, 84],
 	],
 	[
-		[145, 120, 32, 31, 107, 26, 61, 67, 226, 221, 14, 96, 126, 23, 153, 39, 246, 3, 179, 2, 70, 10, 77, 221, 174, 78, 19, 90, 62, 121, 63, 238],
-		[55, 66, 177, 125, 192, 93, 181, 119, 133, 170, 215, 139, 31, 154, 69, 46, 45, 66, 129, 87, 45, 141, 108, 155, 105, 212, 101, 177, 92, 108, 249, 38],
-		[7, 199, 198, 164, 212, 63, 107, 16, 84, 175, 82, 134, 164, 101, 89, 202, 24, 1, 137, 158, 218, 102, 36, 109, 0, 67, 11, 160, 241, 120, 50, 73],
-		[187, 60, 142, 74, 26, 192, 203, 55, 209, 26, 177, 188, 227, 26, 193, 96, 75, 49, 97, 6, 81, 73, 43, 113, 155, 85, 0, 226, 216, 126, 205, 137],
+		[157, 140, 95, 138, 172, 228, 149, 135, 1, 202, 209, 45, 207, 57, 174, 141, 170, 50, 87, 101, 215, 78, 222, 247, 143, 88, 66, 193, 14, 154, 4, 14],
+		[168, 54, 1, 77, 48, 166, 14, 124, 242, 216, 174, 22, 101, 87, 233, 73, 8, 132, 16, 207, 145, 105, 75, 221, 180, 200, 157, 9, 76, 88, 178, 216],
+		[207, 143, 117, 25, 86, 198, 118, 228, 164, 107, 19, 214, 25, 155, 231, 136, 222, 134, 84, 124, 65, 218, 88, 130, 158, 253, 202, 62, 67, 53, 180, 132],
+		[116, 146, 168, 209, 250, 191, 16, 91, 151, 169, 79, 247, 140, 181, 239, 8, 102, 64, 222, 14, 210, 170, 1, 186, 228, 29, 234, 233, 161, 229, 185, 162],
 	],
 	[
-		[167, 64, 223, 114, 46, 136, 21, 216, 47, 80, 217, 116, 125, 190, 107, 36, 119, 226, 92, 34, 221, 190, 57, 211, 165, 186, 233, 197, 195, 67, 178, 90],
-		[38, 64, 187, 62, 146, 232, 255, 96, 204, 40, 182, 186, 14, 182, 134, 141, 1, 189, 154, 11, 70, 1, 244, 213, 45, 199, 116, 109, 161, 9, 216, 167],
-		[198, 45, 46, 74, 79, 86, 108, 195, 162, 20, 92, 171, 132, 71, 131, 195, 221, 222, 31, 207, 104, 22, 115, 26, 76, 80, 17, 115, 148, 217, 120, 188],
-		[97, 185, 13, 104, 22, 219, 67, 127, 62, 25, 9, 59, 88, 18, 39, 219, 2, 136, 85, 92, 147, 102, 70, 139, 46, 208, 47, 21, 183, 74, 196, 194],
+		[139, 78, 133, 186, 147, 167, 252, 65, 251, 76, 66, 93, 45, 171, 219, 39, 5, 217, 51, 78, 75, 60, 149, 102, 136, 140, 129, 233, 253, 92, 77, 156],
+		[43, 4, 207, 100, 187, 142, 247, 96, 63, 49, 133, 36, 32, 7, 98, 116, 213, 70, 142, 65, 112, 101, 182, 219, 30, 71, 138, 68, 141, 3, 81, 24],
+		[35, 45, 137, 178, 243, 111, 242, 28, 161, 16, 171, 51, 182, 18, 55, 226, 175, 172, 36, 209, 80, 172, 33, 100, 135, 43, 249, 74, 46, 146, 81, 157],
+		[54, 30, 79, 47, 25, 167, 26, 81, 128, 252, 62, 69, 90, 142, 146, 112, 93, 85, 139, 155, 209, 234, 195, 173, 128, 229, 216, 31, 106, 166, 245, 80],
 	],
 	[
-		[196, 249, 150, 180, 248, 158, 92, 193, 151, 207, 31, 244, 83, 5, 228, 80, 75, 56, 79, 146, 76, 165, 47, 16, 16, 192, 103, 244, 184, 110, 224, 158],
-		[169, 129, 6, 218, 108, 67, 131, 173, 142, 37, 189, 231, 113, 63, 11, 208, 128, 16, 84, 123, 79, 187, 202, 23, 47, 21, 187, 47, 26, 126, 220, 17],
-		[67, 93, 7, 22, 137, 83, 97, 113, 202, 78, 156, 164, 100, 211, 106, 94, 157, 52, 225, 192, 217, 160, 83, 115, 135, 202, 164, 236, 132, 247, 248, 128],
-		[135, 201, 180, 200, 109, 233, 233, 156, 157, 132, 99, 123, 187, 54, 76, 189, 76, 208, 91, 158, 181, 254, 239, 72, 160, 154, 198, 233, 4, 60, 115, 62],
+		[215, 215, 182, 83, 50, 57, 244, 227, 15, 175, 35, 74, 231, 237, 233, 96, 46, 52, 135, 48, 179, 187, 168, 241, 66, 39, 188, 196, 13, 16, 22, 158],
+		[254, 79, 63, 23, 176, 254, 131, 73, 168, 156, 36, 18, 50, 70, 196, 74, 72, 49, 173, 50, 82, 239, 164, 185, 219, 155, 64, 67, 34, 252, 37, 16],
+		[131, 200, 253, 78, 68, 36, 42, 207, 19, 63, 185, 33, 121, 195, 157, 56, 99, 203, 83, 57, 172, 248, 171, 196, 78, 15, 90, 214, 99, 29, 154, 26],
+		[255, 164, 211, 137, 165, 157, 83, 54, 25, 225, 33, 105, 127, 109, 72, 184, 193, 101, 115, 255, 98, 111, 23, 90, 114, 213, 211, 167, 201, 204, 155, 189],
 	],
 	[
-		[249, 154, 153, 124, 127, 64, 8, 172, 126, 61, 112, 198, 158, 75, 75, 112, 218, 254, 240, 33, 188, 110, 84, 111, 0, 143, 220, 223, 170, 72, 137, 131],
-		[15, 124, 83, 216, 219, 200, 192, 111, 245, 19, 39, 96, 99, 47, 108, 107, 49, 134, 60, 194, 129, 228, 254, 136, 43, 249, 25, 120, 72, 13, 249, 47],
-		[39, 195, 66, 116, 1, 242, 57, 128, 158, 34, 159, 205, 185, 85, 195, 172, 221, 139, 93, 223, 247, 155, 47, 154, 165, 72, 73, 125, 72, 78, 166, 190],
-		[183, 105, 3, 123, 92, 10, 19, 193, 99, 151, 232, 167, 49, 14, 108, 240, 147, 76, 226, 121, 64, 36, 79, 33, 26, 98, 112, 223, 59, 78, 55, 190],
+		[13, 94, 30, 50, 78, 169, 231, 171, 71, 156, 211, 229, 26, 151, 95, 37, 231, 173, 62, 12, 66, 7, 101, 68, 161, 53, 83, 194, 7, 194, 11, 159],
+		[200, 114, 218, 97, 76, 87, 118, 164, 191, 158, 24, 40, 1, 248, 138, 249, 44, 20, 63, 105, 215, 230, 178, 213, 165, 91, 76, 130, 3, 186, 87, 33],
+		[49, 55, 241, 44, 118, 23, 42, 164, 134, 8, 76, 109, 11, 184, 22, 167, 213, 74, 212, 66, 93, 188, 179, 41, 228, 49, 183, 247, 112, 183, 50, 14],
+		[245, 18, 216, 87, 115, 108, 159, 90, 11, 141, 248, 107, 168, 125, 53, 78, 223, 242, 228, 6, 66, 84, 106, 113, 150, 62, 169, 190, 42, 224, 12, 2],
 	],
 	[
-		[197, 143, 102, 203, 224, 39, 255, 85, 106, 235, 244, 74, 86, 6, 170, 0, 185, 74, 173, 43, 160, 140, 125, 204, 55, 25, 56, 60, 255, 190, 29, 146],
-		[113, 246, 152, 219, 231, 123, 158, 159, 221, 87, 50, 242, 69, 108, 240, 255, 10, 2, 178, 132, 41, 209, 188, 140, 170, 51, 133, 208, 226, 88, 78, 238],
-		[241, 155, 185, 162, 1, 33, 61, 97, 195, 112, 83, 55, 216, 101, 253, 24, 41, 183, 76, 193, 223, 8, 93, 227, 29, 15, 84, 24, 9, 227, 39, 192],
-		[40, 104, 64, 246, 43, 188, 210, 15, 46, 209, 131, 25, 116, 191, 57, 138, 216, 178, 120, 183, 98, 203, 152, 86, 111, 105, 16, 245, 101, 12, 102, 193],
+		[79, 204, 100, 122, 80, 55, 155, 211, 206, 57, 98, 6, 234, 118, 159, 246, 235, 12, 103, 175, 121, 206, 240, 178, 24, 192, 198, 70, 93, 202, 215, 110],
+		[139, 216, 237, 73, 187, 59, 85, 33, 17, 221, 232, 249, 239, 136, 184, 6, 113, 76, 0, 126, 26, 220, 219, 219, 43, 156, 166, 152, 187, 249, 51, 215],
+		[133, 218, 239, 123, 37, 181, 117, 174, 80, 79, 111, 230, 182, 22, 141, 244, 74, 50, 59, 147, 103, 36, 11, 224, 181, 219, 8, 7, 227, 95, 140, 93],
+		[97, 0, 101, 212, 226, 241, 120, 238, 251, 149, 64, 149, 52, 192, 36, 14, 114, 162, 144, 42, 121, 109, 47, 66, 2, 3, 41, 177, 216, 105, 102, 104],
 	],
 	[
-		[59, 118, 59, 253, 215, 185, 160, 61, 158, 145, 93, 50, 113, 64, 110, 189, 153, 147, 104, 152, 167, 3, 70, 53, 207, 108, 37, 222, 78, 122, 170, 223],
-		[198, 223, 226, 209, 134, 127, 102, 227, 1, 237, 129, 81, 155, 183, 94, 197, 187, 185, 248, 14, 144, 253, 150, 112, 18, 203, 121, 24, 100, 84, 3, 21],
-		[161, 246, 146, 98, 241, 198, 132, 75, 106, 171, 246, 103, 242, 8, 156, 57, 242, 105, 235, 250, 100, 66, 246, 91, 76, 141, 169, 233, 60, 135, 51, 181],
-		[31, 22, 254, 44, 50, 235, 168, 114, 103, 193, 21, 100, 186, 54, 232, 4, 228, 94, 62, 175, 32, 79, 254, 14, 85, 21, 224, 86, 120, 45, 30, 41],
+		[140, 87, 187, 223, 94, 120, 191, 216, 101, 243, 219, 242, 2, 191, 119, 58, 86, 171, 9, 191, 242, 133, 122, 146, 173, 208, 190, 13, 64, 27, 254, 62],
+		[245, 64, 27, 25, 168, 240, 155, 123, 18, 20, 25, 197, 97, 27, 228, 102, 39, 31, 129, 21, 101, 186, 79, 113, 240, 202, 36, 85, 168, 195, 169, 20],
+		[149, 83, 167, 226, 127, 213, 217, 105, 244, 161, 41, 183, 236, 174, 159, 65, 174, 194, 231, 106, 225, 143, 88, 255, 72, 252, 238, 183, 132, 112, 226, 141],
+		[230, 255, 219, 14, 123, 15, 120, 252, 9, 206, 245, 111, 88, 183, 8, 155, 29, 63, 217, 81, 172, 182, 207, 198, 74, 23, 175, 25, 88, 113, 112, 208],
 	],
 	[
-		[63, 138, 30, 240, 32, 198, 53, 141, 189, 26, 216, 43, 128, 112, 32, 127, 60, 23, 20, 232, 103, 16, 235, 104, 26, 43, 216, 94, 123, 102, 24, 12],
-		[5, 159, 146, 238, 101, 76, 188, 135, 225, 91, 101, 174, 181, 116, 238, 30, 208, 29, 156, 207, 66, 109, 211, 47, 162, 253, 183, 180, 236, 134, 38, 211],
-		[64, 178, 43, 130, 52, 72, 72, 218, 127, 35, 226, 41, 120, 173, 97, 177, 230, 79, 228, 75, 233, 119, 134, 127, 215, 24, 164, 15, 215, 195, 220, 203],
-		[154, 51, 144, 33, 113, 106, 182, 73, 128, 223, 152, 40, 53, 54, 219, 226, 245, 120, 132, 29, 118, 18, 66, 31, 44, 70, 0, 108, 198, 152, 220, 110],
+		[13, 205, 82, 137, 20, 132, 252, 168, 46, 41, 121, 122, 2, 32, 185, 90, 200, 195, 90, 249, 209, 97, 24, 97, 67, 0, 87, 30, 38, 171, 120, 161],
+		[176, 42, 202, 128, 88, 55, 13, 15, 242, 14, 224, 182, 41, 24, 235, 222, 47, 95, 89, 221, 126, 137, 54, 28, 251, 36, 242, 211, 22, 193, 118, 85],
+		[238, 29, 53, 208, 236, 240, 200, 186, 25, 54, 220, 229, 192, 136, 221, 203, 176, 26, 111, 42, 54, 24, 224, 50, 142, 95, 206, 167, 12, 43, 60, 22],
+		[100, 4, 111, 208, 62, 115, 57, 144, 172, 99, 1, 219, 141, 88, 19, 43, 172, 75, 30, 50, 148, 204, 51, 88, 152, 126, 198, 246, 227, 191, 31, 185],
 	],
 	[
-		[5, 98, 101, 78, 140, 129, 65, 83, 7, 104, 37, 54, 69, 127, 100, 84, 40, 9, 156, 55, 132, 144, 231, 81, 253, 41, 133, 100, 246, 246, 75, 12],
-		[159, 13, 133, 114, 187, 112, 26, 132, 73, 209, 162, 87, 160, 166, 166, 119, 76, 101, 46, 222, 30, 100, 246, 41, 245, 186, 171, 44, 70, 160, 46, 103],
-		[218, 53, 6, 233, 105, 95, 244, 145, 250, 226, 211, 32, 76, 146, 167, 203, 106, 111, 215, 43, 50, 31, 175, 2, 190, 99, 12, 141, 26, 28, 5, 76],
-		[90, 240, 52, 186, 232, 147, 102, 54, 114, 117, 167, 240, 242, 225, 39, 132, 236, 231, 115, 143, 40, 218, 164, 97, 30, 74, 253, 35, 78, 72, 161, 181],
+		[152, 255, 92, 197, 124, 42, 89, 222, 140, 63, 200, 56, 189, 98, 44, 133, 245, 138, 146, 5, 182, 117, 97, 10, 173, 215, 78, 105, 136, 19, 54, 9],
+		[203, 217, 7, 126, 238, 135, 69, 214, 35, 5, 5, 101, 88, 222, 122, 108, 69, 120, 184, 209, 24, 135, 210, 149, 214, 169, 74, 225, 217, 197, 23, 142],
+		[122, 173, 237, 59, 40, 156, 131, 188, 106, 48, 108, 122, 116, 105, 216, 128, 248, 66, 182, 73, 130, 8, 150, 232, 128, 114, 183, 162, 27, 232, 12, 240],
+		[3, 154, 18, 155, 241, 204, 1, 172, 141, 36, 241, 215, 188, 228, 123, 116, 159, 36, 251, 47, 88, 128, 217, 46, 251, 100, 97, 254, 88, 53, 120, 179],
 	],
 	[
-		[231, 113, 205, 100, 198, 172, 201, 103, 175, 234, 198, 194, 21, 20, 6, 66, 13, 67, 179, 129, 96, 206, 254, 195, 177, 131, 199, 235, 173, 181, 179, 206],
-		[25, 2, 163, 253, 173, 178, 112, 81, 223, 148, 212, 9, 69, 39, 58, 50, 215, 252, 64, 237, 135, 1, 136, 229, 101, 130, 219, 68, 35, 185, 204, 179],
-		[131, 49, 44, 210, 85, 130, 209, 15, 223, 19, 199, 31, 19, 203, 202, 75, 153, 23, 136, 195, 113, 120, 190, 245, 70, 3, 61, 229, 181, 208, 57, 109],
-		[39, 121, 11, 212, 92, 175, 66, 153, 160, 206, 34, 118, 213, 160, 85, 65, 193, 114, 101, 14, 37, 110, 176, 118, 114, 244, 41, 128, 197, 72, 104, 158],
+		[42, 88, 162, 47, 25, 98, 26, 40, 252, 192, 140, 40, 223, 31, 245, 125, 114, 214, 253, 209, 224, 85, 127, 233, 254, 140, 20, 11, 200, 235, 193, 159],
+		[196, 21, 79, 61, 178, 140, 154, 107, 218, 117, 6, 194, 88, 98, 203, 215, 219, 36, 156, 116, 64, 52, 79, 174, 218, 15, 180, 254, 202, 184, 36, 140],
+		[18, 74, 233, 87, 135, 251, 88, 240, 170, 135, 88, 4, 104, 132, 233, 47, 149, 75, 186, 99, 32, 70, 29, 211, 4, 195, 240, 65, 249, 95, 121, 89],
+		[44, 46, 108, 38, 149, 154, 94, 74, 234, 173, 134, 233, 240, 185, 44, 155, 156, 243, 238, 66, 87, 49, 112, 226, 46, 227, 40, 62, 211, 11, 204, 3],
 	],
 	[
-		[154, 251, 114, 136, 153, 208, 243, 99, 215, 1, 23, 232, 122, 111, 128, 192, 255, 93, 127, 235, 88, 95, 89, 85, 233, 141, 146, 44, 44, 145, 28, 51],
-		[153, 162, 102, 148, 176, 35, 125, 62, 222, 237, 154, 18, 105, 44, 238, 18, 204, 0, 249, 111, 41, 31, 219, 57, 18, 26, 205, 118, 158, 234, 28, 160],
-		[167, 172, 254, 83, 161, 28, 99, 0, 179, 8, 217, 182, 150, 218, 75, 227, 238, 170, 123, 68, 202, 104, 10, 4, 136, 114, 235, 249, 225, 109, 120, 8],
-		[189, 230, 208, 238, 160, 42, 15, 92, 148, 71, 233, 29, 186, 231, 3, 108, 214, 207, 203, 90, 96, 115, 219, 246, 79, 204, 190, 1, 40, 226, 27, 54],
+		[87, 65, 173, 87, 93, 187, 132, 253, 65, 75, 240, 163, 64, 73, 7, 230, 147, 202, 100, 142, 79, 249, 62, 215, 243, 249, 24, 168, 211, 143, 178, 4],
+		[62, 113, 162, 97, 160, 217, 131, 136, 5, 170, 144, 234, 44, 147, 237, 243, 228, 14, 180, 29, 242, 32, 47, 108, 250, 167, 148, 77, 254, 20, 115, 192],
+		[27, 47, 242, 153, 93, 234, 139, 137, 77, 155, 78, 78, 125, 135, 192, 169, 35, 42, 9, 120, 200, 188, 35, 18, 132, 52, 166, 91, 251, 9, 162, 201],
+		[207, 14, 195, 187, 117, 126, 8, 63, 60, 242, 228, 99, 116, 91, 63, 139, 137, 36, 183, 132, 112, 162, 177, 189, 115, 209, 183, 189, 193, 94, 225, 140],
 	],
 	[
-		[108, 30, 172, 185, 113, 188, 76, 124, 132, 226, 87, 7, 11, 70, 50, 224, 10, 85, 6, 102, 110, 64, 242, 69, 87, 84, 37, 44, 222, 63, 27, 29],
-		[70, 205, 188, 178, 134, 144, 178, 93, 41, 82, 197, 46, 124, 166, 31, 145, 119, 199, 110, 165, 73, 228, 132, 109, 167, 83, 158, 156, 135, 97, 59, 162],
-		[169, 153, 43, 38, 110, 253, 79, 244, 10, 1, 197, 70, 254, 206, 46, 218, 212, 30, 7, 133, 151, 144, 74, 12, 159, 204, 135, 224, 28, 33, 247, 184],
-		[222, 175, 79, 245, 149, 81, 36, 22, 231, 47, 59, 60, 138, 242, 30, 221, 9, 40, 212, 111, 141, 31, 231, 162, 9, 123, 235, 104, 246, 174, 159, 4],
+		[197, 144, 244, 67, 239, 56, 166, 17, 188, 231, 164, 238, 149, 116, 225, 108, 177, 56, 223, 32, 214, 40, 27, 22, 47, 156, 240, 196, 64, 223, 187, 212],
+		[78, 35, 227, 52, 3, 2, 219, 234, 20, 198, 149, 40, 66, 46, 30, 198, 243, 240, 85, 119, 194, 242, 160, 118, 147, 128, 111, 177, 4, 120, 175, 213],
+		[40, 91, 118, 132, 42, 133, 92, 101, 189, 192, 136, 224, 252, 134, 187, 160, 125, 243, 47, 165, 57, 232, 7, 83, 103, 121, 177, 117, 97, 216, 190, 34],
+		[10, 243, 89, 145, 210, 35, 189, 139, 204, 159, 65, 166, 38, 112, 231, 83, 11, 187, 244, 132, 158, 22, 167, 244, 79, 93, 18, 25, 127, 127, 215, 217],
 	],
 	[
-		[13, 113, 184, 76, 6, 115, 229, 8, 153, 48, 239, 127, 248, 96, 26, 66, 73, 168, 220, 246, 206, 199, 182, 151, 146, 36, 84, 167, 111, 4, 246, 92],
-		[223, 159, 153, 53, 234, 141, 118, 209, 232, 248, 176, 41, 167, 254, 23, 1, 183, 85, 12, 220, 170, 197, 128, 231, 132, 32, 98, 89, 140, 154, 195, 204],
-		[156, 81, 99, 92, 11, 211, 217, 136, 110, 68, 198, 119, 77, 113, 24, 25, 249, 121, 176, 230, 76, 5, 77, 140, 205, 171, 93, 70, 131, 189, 70, 55],
-		[66, 236, 170, 27, 167, 140, 23, 195, 125, 118, 238, 82, 33, 17, 30, 51, 80, 215, 110, 128, 19, 78, 213, 59, 212, 124, 46, 80, 7, 4, 143, 87],
+		[188, 104, 232, 119, 41, 114, 80, 165, 172, 12, 50, 32, 18, 125, 237, 207, 45, 245, 186, 208, 40, 80, 126, 176, 218, 82, 40, 18, 208, 106, 250, 239],
+		[130, 6, 45, 249, 7, 218, 182, 186, 255, 254, 81, 107, 204, 178, 52, 190, 200, 171, 165, 193, 198, 26, 108, 45, 107, 173, 37, 243, 141, 136, 47, 242],
+		[77, 134, 193, 228, 224, 253, 252, 201, 200, 116, 235, 125, 140, 155, 117, 158, 28, 139, 149, 54, 88, 182, 84, 76, 187, 25, 212, 66, 250, 9, 153, 171],
+		[223, 230, 224, 176, 199, 76, 15, 224, 76, 42, 231, 73, 195, 207, 2, 142, 189, 243, 134, 198, 106, 197, 6, 112, 52, 191, 133, 130, 181, 138, 166, 45],
 	],
 	[
-		[150, 134, 203, 254, 220, 223, 205, 104, 103, 98, 55, 233, 58, 192, 181, 180, 220, 230, 220, 136, 117, 113, 51, 193, 231, 86, 114, 94, 65, 252, 208, 76],
-		[53, 120, 236, 102, 253, 243, 254, 118, 83, 142, 45, 226, 223, 35, 47, 146, 1, 228, 252, 224, 79, 52, 6, 197, 57, 78, 76, 38, 77, 148, 28, 187],
-		[4, 133, 144, 177, 6, 125, 8, 33, 243, 25, 103, 119, 31, 63, 167, 70, 85, 103, 5, 164, 162, 227, 183, 25, 27, 138, 190, 81, 208, 16, 202, 88],
-		[237, 138, 158, 118, 112, 153, 147, 59, 116, 224, 247, 39, 98, 207, 235, 129, 213, 28, 228, 240, 208, 243, 176, 211, 17, 77, 164, 223, 120, 10, 63, 199],
+		[202, 79, 41, 54, 229, 233, 120, 117, 175, 114, 239, 98, 175, 94, 239, 192, 169, 135, 189, 40, 219, 150, 163, 205, 45, 48, 122, 63, 186, 109, 161, 211],
+		[232, 169, 176, 133, 240, 184, 160, 211, 51, 216, 88, 206, 59, 236, 112, 249, 91, 207, 197, 61, 164, 236, 177, 0, 83, 232, 242, 173, 87, 99, 101, 90],
+		[71, 81, 206, 204, 21, 135, 139, 74, 210, 143, 27, 128, 28, 66, 60, 148, 110, 178, 73, 238, 17, 87, 188, 224, 42, 226, 50, 66, 73, 28, 84, 172],
+		[207, 177, 210, 8, 104, 182, 38, 68, 223, 96, 83, 248, 89, 28, 55, 238, 77, 41, 151, 152, 30, 81, 224, 204, 107, 115, 227, 155, 231, 85, 229, 169],
 	],
 	[
-		[153, 106, 152, 225, 131, 112, 114, 253, 210, 7, 0, 107, 120, 113, 5, 176, 211, 228, 101, 141, 14, 175, 0, 84, 249, 128, 196, 230, 94, 116, 100, 155],
-		[64, 91, 32, 33, 44, 89, 61, 100, 7, 230, 190, 15, 228, 251, 72, 214, 15, 141, 114, 144, 197, 13, 80, 193, 7, 122, 225, 96, 219, 209, 98, 169],
-		[159, 113, 42, 180, 187, 113, 82, 195, 126, 254, 65, 241, 244, 170, 217, 124, 231, 85, 140, 51, 82, 26, 137, 192, 239, 110, 93, 55, 211, 169, 133, 173],
-		[226, 121, 229, 0, 80, 188, 75, 44, 51, 111, 67, 138, 166, 101, 253, 247, 210, 50, 242, 159, 33, 81, 99, 122, 207, 61, 65, 57, 33, 55, 73, 131],
+		[102, 246, 96, 238, 98, 170, 117, 205, 190, 49, 253, 176, 208, 236, 234, 118, 127, 119, 105, 118, 12, 80, 108, 197, 252, 194, 212, 68, 202, 17, 23, 101],
+		[36, 252, 93, 203, 182, 157, 189, 190, 254, 42, 246, 95, 92, 118, 187, 41, 111, 58, 92, 82, 125, 88, 255, 135, 240, 229, 94, 54, 203, 108, 169, 227],
+		[142, 67, 71, 52, 50, 224, 4, 124, 123, 204, 119, 210, 31, 72, 25, 52, 210, 139, 174, 177, 3, 96, 241, 121, 111, 174, 156, 178, 14, 118, 48, 72],
+		[202, 197, 177, 137, 26, 190, 112, 211, 66, 140, 47, 34, 215, 55, 134, 5, 59, 45, 7, 159, 17, 119, 86, 83, 96, 173, 69, 147, 163, 165, 34, 162],
 	],
 	[
-		[189, 71, 225, 88, 53, 214, 187, 74, 207, 210, 10, 69, 92, 12, 214, 53, 243, 108, 178, 32, 60, 149, 246, 186, 78, 152, 36, 16, 130, 63, 85, 154],
-		[173, 115, 61, 215, 145, 155, 121, 241, 13, 159, 189, 180, 34, 206, 124, 150, 11, 233, 179, 10, 123, 29, 60, 130, 242, 145, 160, 255, 223, 78, 51, 94],
-		[55, 108, 15, 239, 230, 211, 108, 45, 67, 235, 33, 67, 234, 62, 145, 110, 2, 80, 121, 81, 104, 38, 214, 120, 92, 110, 56, 245, 144, 93, 114, 63],
-		[66, 28, 41, 174, 114, 245, 16, 197, 28, 77, 98, 9, 233, 121, 236, 184, 78, 40, 24, 230, 239, 213, 104, 172, 154, 94, 19, 159, 66, 228, 108, 179],
+		[221, 59, 116, 235, 138, 161, 232, 55, 197, 28, 3, 214, 202, 195, 70, 169, 35, 63, 163, 218, 18, 185, 110, 105, 30, 52, 45, 213, 48, 222, 94, 64],
+		[72, 72, 38, 68, 223, 0, 245, 224, 108, 136, 38, 79, 175, 6, 180, 141, 254, 158, 54, 80, 34, 196, 48, 186, 127, 162, 169, 132, 199, 130, 217, 223],
+		[238, 246, 32, 14, 185, 132, 143, 147, 27, 233, 170, 135, 153, 228, 236, 132, 73, 164, 245, 254, 90, 102, 241, 31, 67, 189, 237, 227, 53, 140, 45, 157],
+		[175, 143, 252, 6, 242, 89, 117, 192, 5, 206, 249, 176, 31, 251, 165, 164, 140, 255, 105, 92, 40, 243, 128, 243, 211, 1, 100, 56, 52, 103, 108, 217],
 	],
 	[
-		[205, 17, 57, 225, 124, 68, 9, 180, 30, 80, 62, 72, 104, 66, 239, 51, 181, 38, 162, 191, 50, 126, 126, 39, 109, 231, 25, 159, 103, 160, 242, 238],
-		[177, 226, 46, 148, 153, 183, 5, 180, 234, 130, 13, 208, 152, 190, 3, 158, 66, 151, 92, 196, 187, 108, 42, 131, 135, 175, 161, 92, 80, 10, 208, 138],
-		[159, 137, 92, 220, 12, 16, 156, 40, 12, 5, 249, 142, 77, 19, 46, 157, 193, 199, 28, 132, 154, 8, 133, 118, 147, 28, 6, 69, 52, 145, 31, 188],
-		[176, 191, 18, 212, 9, 4, 205, 48, 28, 180, 106, 90, 186, 29, 190, 1, 247, 19, 171, 92, 17, 124, 0, 71, 20, 49, 43, 107, 85, 49, 103, 29],
+		[240, 250, 93, 125, 228, 180, 162, 148, 5, 2, 246, 130, 38, 150, 224, 168, 203, 168, 179, 204, 203, 77, 183, 81, 69, 120, 140, 221, 249, 234, 87, 53],
+		[55, 209, 211, 28, 143, 54, 111, 133, 168, 203, 92, 166, 16, 233, 135, 152, 123, 62, 190, 18, 182, 214, 155, 232, 162, 13, 189, 144, 118, 210, 4, 140],
+		[205, 19, 108, 50, 161, 116, 233, 65, 193, 209, 183, 209, 249, 58, 247, 227, 21, 103, 89, 169, 217, 116, 159, 226, 57, 203, 193, 175, 44, 238, 136, 234],
+		[49, 120, 110, 32, 28, 186, 141, 233, 227, 208, 144, 157, 176, 197, 89, 123, 18, 187, 80, 199, 84, 0, 102, 119, 202, 251, 238, 78, 10, 209, 189, 236],
 	],
 	[
-		[73, 242, 135, 222, 26, 197, 117, 221, 187, 162, 14, 168, 78, 61, 146, 56, 246, 32, 236, 247, 253, 139, 182, 182, 200, 167, 252, 254, 59, 3, 253, 149],
-		[221, 88, 143, 55, 79, 53, 151, 76, 125, 111, 152, 49, 3, 196, 100, 11, 14, 228, 79, 30, 89, 98, 47, 17, 10, 8, 2, 133, 103, 9, 0, 68],
-		[157, 100, 27, 250, 230, 18, 217, 218, 166, 171, 240, 114, 174, 20, 55, 81, 225, 198, 80, 114, 118, 69, 68, 93, 219, 204, 74, 75, 91, 123, 41, 212],
-		[137, 182, 201, 18, 245, 199, 93, 95, 155, 111, 133, 16, 0, 143, 172, 44, 124, 28, 151, 63, 57, 134, 252, 9, 138, 63, 8, 98, 228, 144, 88, 129],
+		[76, 138, 168, 62, 120, 153, 106, 217, 188, 24, 210, 180, 76, 32, 165, 20, 78, 129, 180, 31, 78, 224, 151, 113, 187, 164, 231, 241, 145, 222, 153, 215],
+		[232, 121, 90, 218, 56, 0, 241, 193, 117, 29, 190, 16, 170, 246, 242, 209, 124, 169, 145, 238, 100, 37, 21, 3, 48, 78, 176, 77, 191, 33, 156, 239],
+		[140, 147, 58, 113, 54, 254, 146, 152, 234, 128, 139, 225, 54, 29, 208, 127, 208, 56, 123, 117, 247, 160, 29, 250, 170, 143, 134, 75, 177, 11, 204, 66],
+		[13, 17, 128, 151, 25, 239, 41, 234, 196, 26, 75, 232, 110, 229, 136, 160, 58, 117, 21, 94, 26, 140, 200, 254, 131, 199, 56, 62, 128, 153, 205, 185],
 	],
 	[
-		[22, 20, 207, 38, 35, 87, 129, 248, 247, 181, 217, 146, 16, 41, 189, 202, 105, 245, 5, 33, 139, 231, 31, 75, 79, 50, 13, 242, 174, 217, 176, 76],
-		[240, 2, 13, 204, 30, 238, 129, 83, 148, 97, 68, 101, 232, 159, 4, 124, 210, 232, 217, 213, 12, 53, 62, 44, 89, 125, 118, 196, 76, 122, 154, 240],
-		[51, 52, 220, 84, 64, 204, 243, 240, 25, 219, 186, 220, 117, 145, 38, 165, 237, 223, 109, 61, 121, 172, 67, 95, 215, 21, 116, 83, 23, 52, 71, 217],
-		[91, 158, 197, 230, 18, 85, 223, 186, 33, 95, 90, 48, 27, 134, 25, 121, 228, 178, 31, 105, 146, 165, 8, 141, 5, 181, 72, 211, 222, 189, 173, 154],
+		[172, 142, 41, 41, 139, 115, 159, 242, 136, 142, 214, 156, 228, 143, 7, 218, 211, 183, 242, 156, 172, 192, 64, 110, 229, 218, 229, 129, 16, 253, 117, 160],
+		[24, 46, 82, 58, 84, 205, 142, 68, 114, 56, 81, 7, 86, 4, 79, 248, 185, 206, 147, 105, 212, 17, 102, 138, 245, 76, 108, 188, 5, 134, 205, 128],
+		[94, 252, 197, 95, 89, 189, 115, 148, 94, 9, 15, 36, 174, 56, 187, 182, 240, 124, 63, 239, 147, 31, 25, 253, 37, 78, 119, 17, 62, 212, 63, 88],
+		[19, 206, 25, 134, 207, 196, 200, 33, 34, 19, 55, 235, 212, 11, 61, 8, 124, 15, 185, 110, 231, 243, 78, 100, 56, 226, 146, 15, 243, 111, 29, 137],
 	],
 	[
-		[107, 26, 62, 237, 240, 114, 155, 130, 138, 73, 229, 254, 111, 65, 231, 61, 166, 31, 69, 110, 71, 148, 50, 90, 164, 135, 166, 78, 5, 127, 197, 91],
-		[244, 88, 235, 254, 115, 240, 212, 206, 64, 121, 158, 140, 153, 41, 99, 123, 215, 83, 244, 88, 202, 7, 130, 160, 22, 10, 196, 117, 211, 43, 116, 62],
-		[29, 163, 36, 234, 25, 217, 0, 226, 2, 16, 103, 8, 133, 137, 149, 99, 242, 184, 34, 176, 55, 233, 223, 55, 185, 155, 27, 169, 51, 95, 138, 178],
-		[249, 100, 64, 107, 205, 86, 111, 137, 39, 230, 117, 21, 248, 119, 37, 208, 159, 87, 90, 28, 124, 232, 232, 123, 105, 48, 45, 207, 9, 191, 230, 110],
+		[236, 6, 9, 252, 113, 6, 176, 101, 29, 24, 11, 136, 154, 140, 124, 145, 137, 245, 210, 28, 56, 108, 37, 220, 139, 108, 108, 108, 66, 179, 192, 170],
+		[199, 222, 93, 98, 39, 164, 138, 206, 236, 227, 228, 187, 47, 48, 129, 233, 141, 175, 108, 235, 195, 92, 53, 90, 74, 110, 37, 75, 147, 150, 99, 172],
+		[209, 120, 170, 51, 35, 222, 150, 180, 106, 17, 171, 231, 83, 30, 70, 111, 124, 84, 4, 56, 162, 95, 30, 203, 159, 20, 8, 235, 117, 170, 122, 92],
+		[163, 49, 67, 26, 202, 38, 85, 124, 205, 182, 186, 183, 9, 38, 233, 170, 124, 138, 53, 252, 14, 29, 118, 10, 73, 242, 197, 66, 167, 239, 84, 141],
 	],
 	[
-		[115, 110, 142, 146, 91, 38, 235, 215, 218, 181, 135, 21, 142, 224, 244, 115, 196, 92, 192, 137, 45, 161, 31, 27, 220, 64, 231, 83, 241, 183, 87, 81],
-		[24, 38, 9, 25, 84, 132, 235, 6, 138, 4, 104, 116, 33, 228, 149, 37, 162, 155, 209, 215, 246, 132, 37, 57, 65, 106, 137, 32, 203, 48, 83, 231],
-		[133, 128, 175, 99, 55, 156, 115, 83, 169, 40, 142, 187, 208, 84, 73, 125, 114, 45, 37, 84, 108, 146, 144, 204, 239, 190, 229, 203, 122, 130, 135, 238],
-		[133, 197, 252, 1, 138, 179, 184, 160, 127, 33, 13, 229, 136, 200, 178, 91, 149, 166, 56, 85, 193, 135, 140, 242, 60, 243, 80, 37, 48, 49, 166, 216],
+		[13, 79, 61, 85, 75, 250, 26, 33, 131, 235, 134, 65, 124, 150, 180, 43, 217, 108, 59, 77, 109, 166, 213, 28, 117, 135, 237, 123, 166, 15, 70, 104],
+		[197, 133, 125, 247, 127, 194, 30, 52, 103, 234, 143, 23, 250, 47, 159, 34, 36, 204, 134, 193, 61, 136, 25, 77, 237, 164, 156, 21, 163, 209, 137, 149],
+		[209, 102, 35, 56, 38, 103, 96, 61, 31, 67, 125, 7, 223, 59, 173, 67, 173, 123, 96, 224, 193, 147, 74, 117, 113, 208, 180, 115, 96, 92, 150, 152],
+		[67, 130, 45, 24, 86, 144, 52, 134, 23, 156, 62, 110, 181, 12, 141, 60, 169, 239, 65, 4, 250, 20, 27, 30, 211, 200, 33, 4, 251, 49, 40, 49],
 	],
 	[
-		[41, 120, 102, 97, 119, 168, 104, 78, 127, 153, 138, 175, 54, 60, 119, 155, 203, 181, 158, 40, 81, 82, 44, 101, 206, 122, 192, 24, 35, 113, 97, 158],
-		[17, 198, 135, 40, 198, 98, 235, 245, 89, 3, 142, 226, 237, 208, 215, 246, 61, 52, 133, 255, 64, 156, 107, 86, 28, 250, 96, 135, 193, 202, 114, 149],
-		[146, 82, 219, 247, 145, 232, 215, 101, 183, 198, 224, 190, 89, 68, 108, 208, 248, 85, 233, 222, 54, 132, 40, 142, 214, 90, 48, 201, 181, 56, 150, 66],
-		[232, 253, 88, 13, 231, 29, 49, 248, 206, 255, 54, 204, 217, 152, 63, 207, 160, 57, 12, 42, 172, 144, 134, 9, 169, 5, 51, 31, 180, 199, 81, 179],
+		[192, 123, 74, 231, 60, 221, 124, 150, 183, 145, 21, 111, 99, 181, 221, 203, 143, 38, 249, 61, 114, 225, 88, 191, 43, 43, 203, 203, 75, 133, 175, 31],
+		[171, 226, 51, 139, 181, 227, 61, 145, 233, 32, 114, 39, 168, 164, 80, 12, 178, 12, 164, 225, 255, 34, 25, 39, 27, 244, 163, 215, 31, 135, 180, 130],
+		[237, 239, 64, 63, 101, 114, 152, 22, 101, 196, 179, 44, 25, 162, 116, 41, 96, 234, 120, 113, 5, 48, 116, 141, 39, 101, 172, 87, 177, 145, 95, 169],
+		[145, 94, 215, 144, 22, 93, 124, 168, 174, 7, 132, 145, 211, 127, 221, 103, 207, 238, 11, 242, 35, 125, 151, 105, 221, 59, 135, 148, 24, 85, 5, 58],
 	],
 	[
-		[192, 113, 249, 74, 86, 128, 67, 203, 211, 138, 176, 181, 201, 163, 85, 168, 153, 55, 149, 211, 82, 158, 233, 65, 64, 120, 230, 19, 62, 32, 6, 138],
-		[58, 165, 77, 144, 216, 36, 17, 181, 205, 88, 250, 78, 228, 129, 70, 37, 235, 163, 80, 46, 86, 249, 14, 177, 64, 137, 127, 139, 223, 169, 211, 9],
-		[95, 94, 86, 91, 0, 91, 233, 59, 214, 175, 78, 129, 116, 170, 212, 75, 180, 151, 229, 16, 68, 81, 143, 57, 95, 193, 225, 158, 121, 60, 137, 40],
-		[93, 246, 100, 133, 145, 150, 104, 191, 99, 192, 186, 97, 9, 162, 108, 91, 177, 128, 178, 181, 139, 216, 128, 202, 254, 128, 124, 249, 107, 183, 46, 173],
+		[79, 255, 103, 150, 82, 176, 64, 195, 125, 5, 192, 130, 47, 164, 38, 218, 178, 245, 81, 194, 150, 106, 108, 30, 133, 193, 243, 127, 48, 114, 215, 240],
+		[65, 19, 52, 81, 249, 139, 30, 230, 58, 67, 222, 8, 103, 237, 89, 234, 239, 232, 22, 183, 208, 204, 150, 203, 26, 33, 125, 97, 150, 204, 242, 6],
+		[181, 214, 57, 50, 80, 165, 128, 244, 224, 5, 3, 34, 112, 200, 121, 5, 234, 120, 54, 72, 54, 175, 37, 12, 96, 11, 71, 11, 36, 223, 21, 145],
+		[138, 192, 226, 178, 241, 246, 156, 155, 85, 165, 210, 215, 16, 3, 96, 68, 189, 4, 144, 64, 143, 211, 133, 225, 65, 181, 57, 142, 60, 164, 200, 5],
 	],
 	[
-		[56, 206, 18, 53, 105, 115, 63, 168, 181, 154, 95, 202, 25, 140, 207, 74, 167, 15, 125, 87, 27, 121, 36, 104, 137, 246, 94, 163, 121, 93, 96, 131],
-		[76, 216, 224, 173, 168, 179, 1, 48, 202, 180, 210, 43, 38, 18, 9, 119, 222, 104, 8, 2, 73, 60, 134, 144, 125, 85, 203, 215, 183, 64, 204, 82],
-		[193, 0, 144, 213, 110, 6, 244, 108, 242, 212, 246, 140, 178, 121, 11, 27, 175, 209, 220, 61, 8, 140, 92, 243, 163, 80, 4, 104, 87, 168, 104, 134],
-		[5, 62, 128, 0, 241, 90, 196, 3, 237, 120, 91, 221, 250, 208, 107, 54, 0, 70, 59, 85, 79, 161, 121, 61, 158, 222, 66, 111, 8, 247, 238, 60],
+		[240, 130, 218, 223, 107, 254, 2, 14, 124, 50, 163, 55, 228, 220, 155, 105, 64, 128, 220, 156, 245, 44, 98, 218, 90, 3, 74, 138, 58, 106, 75, 137],
+		[158, 210, 170, 37, 214, 239, 217, 113, 122, 72, 158, 15, 170, 127, 105, 254, 224, 62, 104, 27, 207, 178, 41, 59, 255, 215, 40, 168, 190, 178, 220, 19],
+		[40, 59, 250, 219, 183, 213, 29, 34, 5, 124, 66, 0, 4, 33, 1, 88, 218, 250, 54, 216, 86, 194, 214, 67, 134, 119, 227, 177, 93, 87, 22, 94],
+		[54, 82, 104, 30, 226, 49, 48, 215, 86, 174, 119, 49, 137, 190, 142, 22, 157, 86, 27, 52, 184, 210, 86, 83, 206, 42, 130, 9, 199, 66, 42, 52],
 	],
 	[
-		[52, 244, 190, 235, 98, 240, 71, 83, 66, 230, 101, 125, 106, 37, 139, 34, 135, 43, 211, 245, 246, 20, 85, 46, 200, 128, 32, 241, 169, 189, 250, 145],
-		[110, 239, 36, 174, 117, 5, 9, 44, 254, 215, 73, 93, 149, 242, 174, 207, 191, 236, 139, 149, 243, 250, 55, 66, 111, 21, 10, 251, 8, 103, 137, 60],
-		[133, 203, 21, 234, 81, 218, 79, 236, 2, 137, 76, 132, 237, 198, 228, 194, 142, 3, 255, 69, 72, 97, 78, 29, 138, 89, 66, 162, 38, 209, 47, 193],
-		[254, 59, 177, 147, 149, 136, 209, 194, 79, 177, 100, 98, 95, 131, 198, 121, 90, 187, 180, 123, 88, 51, 36, 140, 251, 3, 247, 120, 84, 206, 69, 204],
+		[95, 91, 194, 123, 152, 201, 231, 190, 12, 229, 206, 91, 202, 199, 174, 117, 164, 202, 89, 171, 96, 129, 0, 186, 237, 228, 151, 74, 220, 237, 107, 129],
+		[164, 242, 203, 71, 188, 193, 130, 90, 73, 153, 50, 70, 172, 67, 236, 207, 130, 54, 208, 187, 177, 250, 22, 223, 131, 58, 149, 0, 225, 198, 159, 236],
+		[135, 192, 15, 187, 216, 47, 195, 21, 4, 83, 118, 212, 36, 217, 35, 37, 86, 115, 99, 64, 198, 132, 225, 121, 41, 20, 98, 141, 62, 201, 144, 172],
+		[100, 35, 76, 244, 51, 140, 202, 175, 242, 106, 231, 39, 14, 196, 151, 179, 143, 238, 119, 215, 223, 49, 184, 4, 234, 185, 170, 237, 101, 173, 168, 230],
 	],
 	[
-		[35, 200, 141, 230, 94, 209, 47, 30, 57, 8, 127, 207, 7, 9, 218, 6, 41, 54, 24, 195, 5, 149, 221, 228, 110, 239, 60, 179, 108, 49, 70, 68],
-		[220, 220, 232, 40, 245, 57, 174, 21, 193, 94, 241, 105, 130, 40, 20, 45, 81, 57, 199, 30, 58, 106, 188, 58, 50, 0, 212, 244, 137, 119, 180, 242],
-		[135, 68, 87, 33, 138, 234, 62, 36, 254, 237, 192, 38, 119, 93, 31, 177, 150, 41, 161, 79, 33, 206, 212, 44, 6, 132, 244, 29, 187, 153, 108, 28],
-		[2, 157, 205, 105, 2, 232, 240, 241, 224, 176, 170, 107, 171, 71, 31, 98, 137, 143, 85, 10, 62, 133, 198, 162, 114, 30, 97, 234, 185, 116, 239, 0],
+		[71, 236, 170, 40, 132, 124, 134, 152, 234, 234, 215, 133, 199, 167, 18, 248, 152, 64, 190, 94, 205, 137, 123, 0, 241, 7, 144, 172, 63, 123, 58, 101],
+		[73, 150, 110, 9, 61, 229, 121, 24, 38, 225, 20, 194, 198, 103, 69, 43, 94, 195, 123, 128, 97, 182, 207, 184, 142, 4, 132, 139, 248, 255, 236, 44],
+		[74, 180, 99, 83, 104, 194, 111, 143, 107, 51, 71, 21, 59, 127, 35, 221, 29, 46, 195, 44, 139, 48, 73, 150, 89, 196, 156, 149, 183, 140, 237, 192],
+		[108, 59, 92, 214, 57, 110, 142, 140, 23, 81, 101, 93, 113, 81, 181, 48, 144, 102, 175, 78, 42, 195, 181, 236, 160, 16, 77, 221, 189, 42, 80, 210],
 	],
 	[
-		[61, 253, 176, 81, 101, 171, 209, 113, 65, 199, 102, 161, 54, 187, 176, 140, 180, 216, 119, 83, 174, 162, 185, 37, 219, 105, 31, 10, 113, 53, 180, 164],
-		[45, 42, 99, 130, 144, 234, 25, 197, 123, 197, 216, 118, 181, 144, 252, 206, 192, 154, 97, 102, 53, 165, 184, 135, 222, 237, 139, 33, 138, 207, 35, 6],
-		[66, 144, 124, 7, 144, 76, 105, 179, 87, 89, 118, 141, 176, 186, 29, 254, 75, 236, 7, 3, 40, 182, 29, 80, 241, 192, 63, 165, 192, 94, 137, 148],
-		[181, 78, 56, 156, 125, 180, 223, 58, 77, 212, 22, 253, 111, 210, 233, 103, 136, 105, 44, 208, 96, 30, 155, 60, 231, 153, 90, 9, 244, 254, 176, 182],
+		[56, 141, 45, 21, 39, 210, 131, 190, 195, 161, 80, 181, 156, 91, 127, 102, 206, 65, 91, 231, 151, 190, 89, 74, 211, 188, 11, 22, 8, 143, 97, 191],
+		[145, 196, 50, 72, 94, 136, 12, 68, 135, 227, 172, 160, 101, 178, 206, 113, 231, 154, 109, 27, 234, 130, 184, 65, 193, 118, 126, 208, 224, 36, 249, 150],
+		[165, 183, 148, 209, 50, 247, 101, 51, 154, 221, 118, 2, 241, 122, 83, 211, 62, 183, 33, 68, 26, 54, 122, 94, 19, 64, 192, 4, 220, 208, 242, 106],
+		[41, 20, 213, 31, 50, 49, 225, 250, 116, 13, 14, 138, 244, 213, 33, 58, 191, 40, 31, 218, 220, 34, 117, 116, 122, 236, 20, 246, 188, 119, 206, 199],
 	],
 	[
-		[222, 26, 237, 46, 83, 86, 238, 115, 19, 120, 20, 204, 81, 208, 6, 126, 223, 2, 233, 156, 92, 191, 0, 4, 165, 235, 61, 188, 255, 253, 203, 30],
-		[30, 242, 10, 180, 157, 64, 138, 81, 170, 12, 105, 212, 220, 198, 81, 161, 44, 81, 48, 177, 235, 18, 244, 9, 116, 114, 8, 30, 177, 23, 213, 45],
-		[75, 95, 103, 215, 244, 197, 13, 234, 76, 159, 236, 174, 69, 250, 182, 193, 255, 153, 118, 70, 1, 215, 203, 32, 60, 71, 78, 7, 235, 242, 131, 230],
-		[85, 32, 136, 250, 231, 4, 139, 197, 12, 154, 201, 129, 121, 125, 207, 93, 25, 163, 211, 134, 116, 47, 122, 208, 39, 55, 196, 160, 5, 25, 80, 163],
+		[120, 6, 101, 130, 201, 219, 37, 197, 66, 58, 12, 127, 153, 141, 133, 142, 57, 83, 178, 240, 45, 65, 107, 91, 127, 10, 138, 119, 161, 77, 140, 29],
+		[177, 204, 246, 19, 85, 152, 105, 7, 248, 16, 174, 232, 60, 52, 126, 86, 242, 45, 37, 23, 134, 102, 77, 90, 90, 232, 166, 37, 82, 230, 50, 90],
+		[60, 208, 255, 218, 96, 202, 157, 102, 34, 6, 135, 15, 79, 48, 236, 236, 224, 56, 252, 196, 236, 191, 73, 217, 147, 127, 18, 84, 201, 119, 16, 7],
+		[92, 4, 38, 56, 91, 38, 109, 42, 99, 168, 186, 99, 95, 195, 79, 76, 43, 0, 199, 24, 37, 86, 45, 8, 49, 157, 208, 217, 146, 17, 15, 184],
 	],
 	[
-		[90, 210, 193, 55, 163, 187, 181, 20, 76, 119, 89, 135, 164, 139, 80, 138, 52, 128, 99, 107, 32, 97, 109, 244, 128, 115, 16, 217, 57, 122, 178, 154],
-		[240, 147, 106, 89, 197, 106, 88, 78, 28, 66, 216, 156, 155, 52, 9, 67, 89, 171, 44, 99, 130, 105, 151, 101, 239, 249, 10, 27, 35, 65, 207, 59],
-		[195, 188, 60, 201, 15, 80, 155, 235, 36, 251, 199, 181, 118, 153, 169, 183, 101, 164, 38, 116, 240, 55, 28, 195, 252, 158, 125, 131, 157, 28, 137, 74],
-		[245, 97, 11, 192, 30, 220, 73, 136, 77, 97, 171, 229, 64, 86, 0, 48, 248, 148, 26, 147, 65, 116, 22, 157, 219, 172, 33, 62, 148, 226, 45, 156],
+		[192, 202, 172, 164, 129, 44, 68, 217, 107, 59, 15, 240, 105, 44, 71, 168, 72, 0, 210, 24, 69, 47, 75, 121, 89, 222, 136, 156, 122, 125, 223, 218],
+		[33, 85, 101, 44, 209, 119, 75, 182, 248, 248, 30, 75, 207, 127, 156, 7, 248, 92, 13, 22, 168, 31, 149, 77, 152, 108, 15, 204, 70, 90, 175, 34],
+		[121, 223, 147, 189, 191, 254, 139, 234, 217, 61, 163, 67, 112, 113, 141, 255, 136, 127, 9, 62, 109, 161, 219, 121, 51, 118, 208, 234, 64, 14, 62, 155],
+		[118, 231, 82, 56, 181, 178, 208, 172, 244, 237, 53, 70, 227, 178, 26, 248, 183, 159, 130, 129, 227, 198, 63, 212, 10, 179, 182, 142, 104, 194, 161, 145],
 	],
 	[
-		[89, 83, 143, 72, 78, 150, 179, 155, 10, 11, 135, 16, 178, 235, 160, 142, 255, 48, 167, 93, 151, 199, 56, 212, 180, 240, 37, 168, 158, 221, 202, 114],
-		[40, 160, 241, 152, 24, 195, 55, 169, 103, 152, 71, 183, 89, 87, 105, 250, 150, 118, 116, 90, 87, 197, 21, 10, 16, 210, 191, 23, 94, 131, 30, 236],
-		[208, 210, 155, 43, 122, 186, 142, 32, 0, 75, 241, 74, 91, 142, 231, 100, 194, 65, 76, 195, 107, 56, 28, 181, 230, 126, 212, 214, 166, 120, 125, 21],
-		[41, 187, 12, 75, 217, 94, 151, 63, 20, 96, 101, 73, 104, 22, 160, 76, 178, 212, 25, 199, 147, 145, 115, 170, 15, 206, 70, 59, 244, 9, 141, 112],
+		[5, 171, 45, 153, 46, 145, 69, 222, 179, 91, 4, 228, 36, 140, 95, 24, 106, 165, 147, 120, 144, 4, 237, 174, 93, 92, 129, 43, 64, 39, 42, 26],
+		[81, 232, 2, 146, 120, 98, 63, 0, 248, 85, 47, 96, 60, 91, 140, 136, 229, 230, 214, 142, 228, 57, 236, 110, 227, 182, 180, 223, 129, 117, 242, 165],
+		[151, 48, 181, 85, 84, 234, 34, 204, 51, 247, 174, 6, 43, 50, 101, 87, 137, 35, 92, 7, 155, 73, 60, 253, 42, 99, 206, 19, 188, 218, 29, 105],
+		[173, 239, 178, 80, 171, 110, 205, 59, 20, 211, 208, 72, 179, 145, 175, 95, 104, 95, 229, 60, 156, 175, 201, 211, 23, 58, 167, 13, 86, 195, 75, 16],
 	],
 	[
-		[98, 170, 153, 189, 212, 32, 1, 186, 202, 128, 83, 100, 205, 221, 9, 91, 156, 102, 252, 40, 210, 147, 154, 205, 235, 172, 132, 37, 90, 154, 91, 170],
-		[59, 53, 246, 167, 247, 126, 158, 169, 74, 89, 177, 211, 119, 40, 106, 236, 40, 104, 235, 230, 115, 150, 226, 56, 121, 45, 253, 22, 158, 87, 138, 238],
-		[149, 151, 216, 156, 146, 174, 16, 255, 172, 3, 128, 197, 168, 111, 236, 21, 111, 32, 106, 170, 171, 248, 157, 246, 183, 245, 49, 194, 223, 64, 62, 63],
-		[136, 165, 35, 126, 227, 87, 190, 91, 2, 73, 184, 71, 144, 145, 138, 143, 16, 151, 70, 161, 137, 190, 7, 206, 66, 250, 99, 182, 159, 166, 129, 93],
+		[236, 75, 210, 240, 54, 166, 47, 183, 12, 3, 0, 153, 216, 26, 247, 135, 196, 120, 114, 72, 30, 67, 177, 183, 192, 243, 142, 208, 163, 167, 152, 113],
+		[171, 253, 4, 137, 177, 178, 222, 234, 155, 167, 12, 134, 173, 24, 115, 52, 64, 95, 104, 43, 238, 86, 205, 3, 29, 14, 161, 227, 254, 140, 229, 87],
+		[184, 167, 241, 211, 251, 55, 209, 121, 166, 116, 21, 150, 140, 35, 101, 241, 40, 42, 242, 233, 119, 27, 44, 32, 18, 39, 194, 0, 97, 60, 145, 220],
+		[176, 64, 168, 171, 221, 70, 46, 211, 255, 246, 210, 70, 39, 240, 27, 101, 239, 201, 179, 65, 43, 111, 158, 255, 167, 65, 120, 116, 65, 229, 182, 77],
 	],
 	[
-		[214, 59, 192, 117, 143, 22, 100, 145, 159, 165, 239, 1, 86, 56, 6, 154, 248, 212, 185, 204, 43, 152, 42, 134, 18, 129, 136, 247, 241, 155, 10, 102],
-		[48, 62, 56, 15, 187, 236, 197, 9, 236, 115, 193, 184, 188, 246, 194, 37, 197, 196, 160, 70, 160, 249, 235, 110, 111, 191, 111, 231, 18, 159, 137, 42],
-		[192, 251, 238, 137, 53, 162, 125, 107, 231, 203, 134, 118, 43, 198, 151, 109, 59, 219, 233, 225, 142, 61, 226, 59, 231, 213, 69, 10, 65, 186, 7, 112],
-		[18, 175, 91, 166, 183, 97, 102, 219, 90, 214, 18, 235, 210, 37, 184, 45, 73, 98, 253, 2, 40, 89, 13, 150, 246, 220, 254, 99, 155, 96, 67, 77],
+		[248, 152, 201, 74, 208, 14, 117, 103, 191, 45, 93, 254, 116, 32, 22, 230, 93, 192, 75, 101, 204, 182, 110, 251, 48, 158, 72, 19, 153, 198, 173, 223],
+		[222, 244, 199, 127, 169, 32, 11, 1, 67, 223, 101, 85, 178, 127, 58, 146, 42, 29, 7, 216, 10, 107, 53, 102, 250, 193, 201, 17, 10, 94, 76, 112],
+		[187, 86, 74, 61, 203, 10, 54, 39, 143, 186, 205, 121, 70, 31, 150, 85, 106, 37, 63, 202, 31, 138, 23, 158, 226, 118, 63, 0, 137, 125, 153, 86],
+		[111, 56, 127, 76, 12, 190, 162, 245, 237, 70, 159, 144, 137, 28, 44, 218, 106, 14, 198, 251, 17, 75, 246, 191, 107, 163, 177, 195, 152, 4, 132, 43],
 	],
 	[
-		[255, 210, 183, 124, 175, 170, 219, 130, 5, 237, 122, 38, 225, 210, 37, 229, 60, 152, 75, 234, 62, 246, 99, 177, 215, 90, 189, 34, 77, 164, 42, 242],
-		[222, 37, 125, 25, 53, 211, 180, 45, 12, 11, 240, 149, 132, 97, 16, 119, 113, 181, 58, 86, 88, 57, 139, 142, 127, 189, 156, 252, 113, 46, 19, 210],
-		[178, 23, 130, 143, 73, 137, 20, 181, 158, 150, 146, 84, 120, 107, 131, 113, 196, 140, 226, 32, 224, 25, 107, 27, 30, 33, 179, 192, 98, 82, 17, 114],
-		[41, 203, 62, 117, 151, 94, 218, 90, 59, 80, 176, 91, 51, 233, 63, 22, 186, 132, 140, 120, 32, 98, 75, 46, 16, 241, 214, 139, 112, 207, 125, 14],
+		[14, 241, 246, 5, 221, 204, 98, 17, 218, 58, 112, 222, 90, 38, 250, 195, 162, 165, 109, 45, 32, 136, 174, 103, 102, 64, 142, 229, 77, 159, 154, 20],
+		[22, 215, 69, 46, 62, 11, 94, 43, 16, 74, 102, 160, 123, 96, 13, 195, 215, 18, 223, 208, 112, 12, 41, 150, 84, 81, 71, 67, 249, 227, 170, 78],
+		[90, 39, 154, 199, 113, 52, 191, 135, 248, 235, 140, 73, 184, 58, 148, 147, 198, 103, 212, 70, 132, 246, 39, 85, 254, 27, 44, 66, 87, 43, 231, 32],
+		[143, 213, 113, 171, 119, 61, 172, 180, 254, 66, 2, 20, 116, 213, 168, 103, 184, 31, 208, 147, 11, 182, 227, 191, 96, 77, 158, 76, 226, 195, 61, 147],
 	],
 	[
-		[88, 230, 229, 233, 226, 109, 198, 190, 207, 4, 180, 230, 46, 85, 188, 102, 221, 186, 16, 73, 27, 151, 243, 204, 54, 21, 145, 56, 160, 155, 244, 223],
-		[171, 65, 91, 230, 53, 34, 198, 56, 123, 243, 147, 56, 163, 122, 222, 223, 105, 148, 67, 23, 125, 67, 13, 31, 29, 115, 64, 5, 28, 230, 154, 199],
-		[30, 142, 195, 185, 46, 186, 224, 251, 41, 206, 44, 146, 187, 169, 142, 18, 169, 141, 232, 84, 92, 177, 17, 147, 193, 98, 41, 35, 134, 6, 117, 173],
-		[31, 164, 67, 142, 63, 236, 59, 17, 184, 76, 50, 200, 145, 248, 201, 141, 235, 52, 208, 219, 196, 149, 196, 90, 134, 139, 87, 189, 197, 15, 82, 105],
+		[171, 225, 3, 207, 201, 153, 184, 79, 98, 141, 226, 203, 192, 4, 174, 186, 104, 136, 222, 96, 229, 222, 105, 206, 170, 37, 104, 39, 85, 49, 137, 218],
+		[202, 144, 130, 233, 150, 164, 2, 61, 39, 104, 78, 34, 126, 50, 135, 174, 50, 140, 132, 215, 128, 95, 13, 246, 221, 168, 13, 123, 232, 14, 219, 77],
+		[72, 66, 226, 198, 129, 99, 176, 73, 205, 128, 176, 234, 159, 165, 44, 233, 120, 231, 116, 143, 78, 102, 223, 74, 169, 224, 207, 87, 237, 81, 3, 211],
+		[163, 65, 3, 249, 172, 97, 175, 36, 133, 152, 58, 90, 4, 118, 157, 158, 230, 152, 188, 130, 13, 239, 42, 149, 43, 244, 1, 166, 248, 131, 163, 133],
 	],
 	[
-		[236, 17, 190, 255, 224, 28, 36, 236, 135, 27, 214, 184, 238, 85, 227, 28, 94, 255, 185, 188, 220, 157, 108, 227, 148, 153, 239, 137, 181, 212, 47, 101],
-		[170, 138, 76, 250, 15, 19, 100, 106, 235, 123, 193, 97, 15, 170, 174, 101, 11, 188, 23, 177, 2, 253, 52, 69, 9, 27, 53, 233, 177, 95, 213, 198],
-		[23, 73, 233, 58, 242, 41, 216, 73, 158, 38, 133, 23, 250, 108, 157, 232, 215, 3, 140, 64, 47, 159, 209, 28, 47, 39, 157, 234, 83, 32, 147, 161],
-		[249, 124, 200, 3, 134, 168, 43, 106, 247, 19, 9, 138, 112, 154, 21, 204, 210, 0, 253, 225, 255, 131, 224, 155, 221, 177, 97, 193, 111, 181, 97, 156],
+		[67, 147, 16, 3, 190, 143, 196, 176, 12, 52, 89, 2, 86, 27, 59, 183, 102, 118, 52, 116, 146, 227, 8, 56, 74, 160, 234, 194, 215, 26, 233, 53],
+		[51, 234, 42, 205, 195, 47, 230, 100, 35, 173, 34, 28, 219, 53, 131, 141, 172, 12, 178, 205, 14, 57, 186, 213, 83, 212, 66, 254, 225, 182, 96, 5],
+		[103, 173, 22, 240, 128, 197, 113, 64, 159, 187, 87, 168, 176, 91, 56, 150, 2, 51, 14, 180, 55, 152, 202, 133, 72, 150, 45, 214, 252, 84, 200, 143],
+		[239, 78, 70, 207, 80, 101, 145, 16, 130, 93, 14, 83, 246, 13, 94, 186, 204, 29, 118, 190, 69, 74, 47, 109, 10, 171, 30, 212, 87, 157, 139, 34],
 	],
 	[
-		[33, 245, 87, 214, 75, 125, 136, 9, 9, 25, 2, 169, 61, 83, 229, 241, 17, 28, 98, 172, 235, 121, 40, 185, 44, 147, 68, 114, 151, 116, 69, 218],
-		[10, 146, 139, 237, 91, 149, 96, 12, 214, 111, 33, 20, 226, 177, 162, 211, 113, 70, 193, 251, 145, 33, 137, 180, 206, 158, 187, 195, 68, 217, 43, 63],
-		[146, 22, 164, 79, 124, 143, 192, 163, 40, 240, 7, 115, 169, 126, 187, 37, 152, 209, 107, 124, 192, 253, 57, 170, 77, 176, 119, 238, 121, 157, 13, 75],
-		[223, 44, 5, 21, 167, 117, 94, 43, 42, 177, 177, 51, 163, 16, 58, 215, 50, 150, 90, 189, 134, 246, 166, 30, 80, 90, 128, 147, 18, 149, 137, 212],
+		[43, 226, 30, 66, 201, 230, 100, 103, 193, 52, 39, 209, 73, 200, 175, 247, 90, 179, 143, 229, 11, 69, 145, 82, 237, 11, 76, 52, 129, 146, 117, 234],
+		[3, 114, 131, 196, 231, 250, 183, 209, 68, 78, 39, 130, 228, 200, 41, 126, 64, 25, 249, 90, 130, 179, 5, 136, 3, 127, 212, 9, 229, 163, 70, 30],
+		[151, 191, 59, 44, 242, 121, 177, 248, 222, 167, 106, 37, 250, 11, 137, 136, 178, 232, 25, 36, 189, 78, 20, 252, 180, 178, 216, 27, 201, 149, 210, 169],
+		[185, 57, 145, 203, 250, 159, 186, 39, 114, 130, 182, 57, 23, 144, 16, 147, 10, 186, 94, 212, 59, 77, 108, 116, 132, 132, 111, 209, 171, 99, 156, 197],
 	],
 	[
-		[146, 191, 154, 34, 111, 198, 42, 98, 124, 134, 37, 182, 160, 107, 238, 208, 137, 96, 129, 49, 213, 132, 131, 27, 101, 29, 76, 136, 118, 128, 110, 101],
-		[27, 105, 47, 122, 186, 95, 182, 22, 106, 164, 3, 131, 50, 240, 148, 251, 71, 146, 177, 52, 164, 203, 173, 78, 143, 169, 166, 99, 55, 239, 25, 28],
-		[25, 106, 39, 69, 33, 155, 46, 245, 201, 212, 178, 101, 100, 177, 11, 131, 104, 248, 197, 84, 69, 199, 231, 113, 187, 165, 198, 23, 167, 49, 200, 77],
-		[176, 31, 246, 127, 131, 194, 48, 42, 253, 87, 173, 105, 255, 36, 127, 94, 187, 61, 215, 219, 108, 126, 3, 80, 172, 96, 109, 134, 142, 49, 158, 198],
+		[62, 12, 97, 51, 172, 139, 165, 84, 115, 66, 63, 56, 62, 70, 120, 183, 80, 63, 143, 170, 90, 171, 127, 5, 105, 87, 121, 180, 127, 208, 8, 27],
+		[121, 155, 232, 171, 222, 91, 157, 187, 159, 217, 30, 141, 76, 30, 133, 167, 206, 173, 189, 166, 240, 214, 4, 176, 178, 175, 175, 190, 53, 155, 132, 157],
+		[107, 253, 211, 63, 11, 164, 183, 242, 247, 109, 57, 67, 161, 231, 59, 199, 19, 161, 19, 170, 188, 194, 89, 243, 233, 222, 39, 203, 56, 33, 89, 57],
+		[33, 84, 98, 25, 58, 11, 47, 5, 114, 89, 92, 64, 96, 241, 52, 107, 99, 116, 11, 216, 72, 162, 84, 105, 2, 180, 172, 130, 119, 7, 111, 176],
 	],
 	[
-		[217, 97, 140, 166, 235, 155, 154, 53, 79, 94, 117, 149, 63, 181, 34, 84, 203, 169, 65, 133, 114, 56, 26, 160, 54, 239, 251, 108, 13, 173, 138, 100],
-		[190, 115, 176, 129, 158, 160, 157, 136, 242, 106, 111, 130, 11, 186, 216, 119, 85, 204, 8, 229, 88, 30, 104, 32, 160, 58, 38, 116, 199, 116, 238, 73],
-		[207, 39, 79, 123, 166, 197, 238, 242, 178, 21, 128, 0, 185, 109, 164, 22, 184, 210, 232, 61, 7, 156, 215, 216, 140, 223, 98, 100, 96, 119, 136, 2],
-		[205, 68, 231, 117, 233, 127, 192, 204, 123, 28, 4, 176, 193, 233, 220, 118, 77, 173, 190, 207, 172, 248, 77, 1, 159, 31, 221, 93, 251, 164, 50, 199],
+		[17, 165, 78, 144, 97, 175, 133, 217, 178, 247, 173, 125, 250, 58, 149, 61, 217, 78, 32, 208, 7, 10, 56, 222, 254, 204, 100, 11, 191, 210, 61, 206],
+		[181, 70, 247, 72, 47, 52, 243, 125, 155, 41, 31, 26, 121, 16, 158, 72, 130, 1, 175, 10, 180, 208, 82, 221, 79, 224, 165, 155, 100, 51, 43, 85],
+		[206, 77, 64, 184, 85, 249, 222, 187, 58, 24, 84, 222, 5, 161, 106, 250, 191, 237, 22, 237, 39, 233, 68, 62, 229, 44, 59, 237, 178, 44, 118, 225],
+		[117, 106, 104, 139, 177, 144, 248, 194, 136, 201, 150, 62, 41, 64, 216, 98, 163, 233, 104, 8, 94, 176, 195, 27, 193, 219, 207, 94, 16, 120, 218, 45],
 	],
 	[
-		[146, 171, 54, 215, 145, 204, 134, 19, 65, 243, 9, 19, 182, 20, 132, 41, 250, 195, 208, 15, 118, 229, 252, 18, 143, 126, 144, 80, 34, 198, 20, 80],
-		[44, 242, 222, 92, 68, 195, 53, 0, 147, 161, 232, 146, 244, 188, 196, 249, 204, 144, 223, 231, 86, 20, 92, 166, 230, 96, 100, 44, 114, 116, 14, 213],
-		[40, 185, 234, 207, 113, 41, 73, 28, 92, 80, 84, 132, 186, 245, 117, 70, 32, 225, 156, 44, 93, 13, 178, 4, 37, 199, 216, 195, 126, 196, 104, 100],
-		[10, 81, 60, 249, 167, 219, 199, 57, 176, 214, 207, 172, 190, 71, 124, 184, 87, 209, 104, 104, 177, 200, 239, 143, 213, 215, 43, 55, 39, 128, 36, 81],
+		[217, 86, 55, 26, 141, 112, 144, 254, 244, 245, 235, 100, 140, 145, 115, 27, 63, 99, 98, 63, 159, 11, 233, 191, 68, 112, 176, 202, 52, 228, 24, 167],
+		[48, 61, 166, 194, 36, 26, 146, 227, 104, 68, 225, 85, 128, 249, 64, 107, 142, 33, 240, 149, 251, 231, 117, 65, 244, 65, 192, 105, 42, 129, 41, 159],
+		[118, 172, 105, 219, 204, 184, 205, 11, 86, 144, 85, 242, 48, 23, 189, 198, 241, 12, 129, 108, 132, 250, 251, 6, 157, 53, 35, 7, 63, 51, 93, 50],
+		[74, 181, 246, 204, 65, 71, 221, 17, 14, 92, 101, 37, 117, 175, 43, 121, 204, 62, 93, 16, 70, 39, 211, 158, 145, 106, 92, 10, 207, 124, 68, 97],
 	],
 	[
-		[230, 133, 157, 235, 56, 194, 73, 176, 215, 145, 111, 101, 97, 144, 192, 96, 225, 206, 74, 134, 207, 17, 176, 13, 76, 36, 14, 122, 158, 207, 80, 179],
-		[229, 106, 181, 251, 254, 100, 176, 120, 153, 86, 233, 15, 182, 81, 97, 172, 135, 62, 21, 79, 220, 213, 135, 238, 7, 199, 221, 44, 117, 124, 17, 219],
-		[149, 10, 113, 64, 200, 6, 218, 247, 137, 46, 132, 79, 14, 52, 190, 124, 9, 130, 117, 178, 202, 218, 237, 109, 198, 210, 192, 58, 113, 194, 31, 139],
-		[177, 80, 69, 90, 109, 125, 183, 141, 178, 214, 25, 33, 218, 197, 147, 227, 4, 132, 236, 1, 203, 205, 55, 29, 39, 154, 145, 248, 114, 132, 206, 75],
+		[149, 35, 251, 79, 197, 85, 198, 186, 107, 254, 144, 180, 229, 180, 92, 184, 36, 104, 139, 233, 100, 81, 244, 65, 204, 94, 134, 156, 245, 217, 18, 141],
+		[173, 168, 227, 233, 150, 75, 133, 13, 217, 152, 13, 235, 134, 100, 206, 96, 33, 107, 198, 153, 209, 76, 180, 73, 183, 55, 37, 7, 240, 29, 32, 209],
+		[62, 207, 23, 63, 91, 94, 165, 43, 50, 127, 70, 242, 206, 78, 208, 167, 199, 47, 105, 63, 95, 136, 212, 87, 10, 169, 5, 239, 125, 97, 116, 238],
+		[20, 145, 162, 219, 5, 156, 139, 212, 227, 186, 3, 216, 157, 110, 165, 103, 213, 196, 202, 139, 20, 31, 218, 89, 254, 25, 81, 93, 147, 123, 209, 139],
 	],
 	[
-		[147, 74, 194, 147, 81, 36, 237, 56, 73, 126, 129, 101, 106, 20, 211, 192, 230, 106, 11, 42, 140, 177, 109, 188, 27, 239, 40, 38, 25, 158, 150, 228],
-		[250, 147, 22, 16, 190, 102, 88, 8, 89, 93, 181, 162, 253, 174, 31, 130, 234, 127, 61, 57, 73, 116, 180, 228, 5, 154, 34, 175, 31, 248, 138, 132],
-		[122, 218, 47, 90, 181, 77, 183, 24, 223, 179, 185, 67, 246, 190, 173, 204, 107, 255, 235, 111, 191, 114, 59, 251, 79, 12, 232, 148, 163, 216, 72, 67],
-		[83, 244, 150, 19, 193, 138, 219, 193, 81, 107, 54, 38, 193, 105, 93, 212, 239, 161, 115, 191, 168, 232, 24, 159, 226, 12, 54, 57, 146, 249, 108, 208],
+		[129, 244, 11, 174, 45, 29, 204, 210, 122, 83, 201, 48, 210, 33, 22, 19, 242, 253, 120, 1, 26, 104, 16, 44, 191, 111, 164, 47, 4, 26, 39, 90],
+		[94, 29, 18, 206, 83, 21, 154, 241, 130, 114, 233, 168, 72, 130, 83, 91, 171, 148, 44, 243, 79, 4, 218, 81, 37, 99, 204, 71, 2, 252, 144, 38],
+		[43, 90, 62, 19, 98, 125, 139, 229, 20, 8, 92, 31, 81, 34, 188, 202, 182, 175, 41, 19, 198, 182, 221, 203, 209, 168, 92, 91, 105, 213, 47, 25],
+		[144, 100, 213, 224, 234, 239, 224, 91, 33, 101, 228, 227, 217, 96, 27, 149, 19, 154, 6, 230, 144, 50, 131, 80, 149, 173, 217, 122, 73, 254, 217, 214],
 	],
 	[
-		[235, 135, 157, 36, 236, 56, 54, 236, 229, 125, 72, 107, 1, 92, 210, 211, 246, 131, 196, 19, 60, 115, 61, 4, 238, 15, 96, 66, 238, 188, 141, 19],
-		[116, 9, 247, 252, 150, 209, 116, 57, 79, 75, 56, 113, 182, 10, 1, 121, 176, 236, 75, 125, 247, 202, 227, 247, 12, 54, 29, 125, 90, 42, 53, 49],
-		[43, 210, 77, 221, 130, 251, 81, 22, 167, 149, 17, 208, 228, 245, 41, 191, 66, 206, 144, 124, 224, 246, 170, 205, 195, 29, 253, 120, 218, 28, 201, 13],
-		[163, 1, 15, 151, 255, 170, 180, 6, 72, 33, 96, 137, 131, 34, 172, 122, 17, 5, 130, 250, 215, 81, 80, 110, 252, 54, 95, 51, 133, 84, 229, 3],
+		[228, 122, 30, 239, 93, 166, 47, 148, 87, 141, 231, 91, 172, 207, 86, 179, 144, 131, 2, 215, 126, 64, 46, 253, 225, 130, 187, 56, 40, 186, 198, 230],
+		[63, 99, 95, 110, 169, 212, 250, 240, 17, 172, 118, 79, 68, 183, 89, 190, 157, 163, 215, 126, 85, 36, 27, 130, 79, 84, 174, 3, 47, 236, 121, 146],
+		[209, 200, 249, 23, 250, 161, 176, 149, 173, 127, 18, 52, 179, 60, 20, 54, 56, 67, 126, 166, 185, 199, 41, 11, 185, 73, 161, 149, 62, 210, 54, 218],
+		[57, 247, 0, 189, 141, 148, 1, 213, 166, 55, 28, 231, 111, 4, 135, 223, 57, 68, 126, 59, 161, 237, 244, 234, 8, 89, 70, 183, 171, 96, 144, 63],
 	],
 	[
-		[135, 51, 190, 219, 144, 28, 50, 158, 141, 206, 192, 227, 208, 42, 144, 47, 21, 15, 196, 108, 112, 44, 84, 185, 85, 41, 125, 178, 129, 38, 64, 149],
-		[27, 1, 182, 145, 204, 186, 49, 234, 65, 40, 197, 177, 72, 4, 166, 64, 150, 97, 73, 146, 9, 96, 176, 186, 185, 164, 66, 218, 38, 15, 238, 156],
-		[167, 179, 30, 45, 213, 241, 119, 116, 237, 239, 216, 91, 150, 240, 86, 14, 32, 88, 190, 183, 241, 172, 41, 87, 132, 103, 94, 133, 51, 172, 202, 241],
-		[226, 31, 233, 43, 175, 246, 47, 217, 245, 169, 212, 230, 10, 155, 109, 233, 185, 155, 42, 194, 163, 230, 24, 202, 161, 190, 201, 103, 66, 5, 98, 229],
+		[150, 185, 244, 218, 43, 200, 85, 245, 182, 214, 87, 37, 253, 106, 18, 23, 143, 141, 237, 4, 171, 61, 150, 90, 122, 129, 120, 120, 45, 230, 209, 149],
+		[87, 255, 216, 249, 55, 71, 155, 45, 212, 232, 184, 163, 114, 230, 53, 38, 133, 145, 253, 67, 151, 18, 102, 172, 116, 188, 59, 229, 198, 108, 30, 76],
+		[91, 152, 202, 64, 27, 57, 98, 196, 55, 15, 242, 51, 203, 68, 153, 178, 162, 154, 241, 99, 31, 30, 105, 71, 138, 229, 202, 173, 198, 170, 9, 131],
+		[14, 73, 78, 90, 67, 216, 194, 43, 40, 24, 20, 177, 82, 58, 148, 228, 219, 5, 14, 59, 136, 13, 74, 45, 207, 218, 152, 186, 244, 0, 124, 84],
 	],
 	[
-		[244, 255, 64, 19, 234, 240, 139, 235, 149, 97, 114, 56, 253, 167, 45, 163, 63, 151, 78, 104, 0, 253, 151, 110, 210, 179, 227, 47, 99, 51, 195, 133],
-		[78, 236, 85, 93, 156, 75, 59, 193, 143, 1, 207, 228, 149, 202, 130, 41, 49, 95, 232, 109, 201, 72, 204, 191, 244, 244, 48, 175, 177, 248, 108, 193],
-		[83, 150, 23, 61, 101, 173, 14, 254, 129, 195, 194, 75, 160, 147, 10, 81, 47, 118, 40, 15, 128, 153, 160, 191, 250, 218, 131, 170, 36, 230, 61, 36],
-		[192, 223, 74, 18, 152, 180, 156, 220, 85, 70, 198, 187, 202, 158, 169, 206, 68, 63, 237, 196, 52, 74, 130, 94, 3, 105, 58, 88, 5, 173, 127, 172],
+		[253, 195, 168, 99, 215, 34, 221, 136, 251, 230, 54, 90, 13, 208, 206, 55, 40, 93, 113, 86, 76, 255, 53, 62, 200, 43, 31, 220, 21, 128, 191, 177],
+		[217, 15, 250, 246, 32, 46, 38, 232, 53, 184, 139, 208, 172, 119, 226, 128, 108, 45, 211, 222, 93, 212, 27, 2, 223, 89, 57, 73, 111, 179, 160, 207],
+		[172, 88, 117, 138, 145, 168, 91, 190, 248, 121, 182, 127, 57, 231, 16, 49, 235, 78, 92, 57, 161, 188, 195, 107, 210, 58, 30, 219, 23, 56, 194, 74],
+		[126, 39, 98, 161, 40, 170, 83, 116, 129, 141, 92, 251, 201, 124, 176, 6, 55, 196, 238, 72, 243, 223, 18, 79, 120, 234, 38, 124, 170, 136, 185, 229],
 	],
 	[
-		[47, 78, 100, 25, 188, 211, 97, 36, 1, 208, 239, 43, 63, 30, 240, 147, 6, 127, 71, 81, 210, 177, 138, 16, 121, 213, 10, 253, 7, 67, 211, 76],
-		[150, 168, 23, 239, 30, 87, 130, 66, 95, 225, 53, 221, 113, 12, 39, 156, 247, 255, 30, 40, 135, 223, 228, 241, 199, 28, 81, 232, 205, 47, 40, 129],
-		[147, 215, 90, 202, 69, 154, 75, 106, 66, 38, 175, 163, 147, 74, 96, 63, 179, 25, 57, 220, 20, 237, 44, 99, 134, 171, 241, 220, 74, 158, 161, 30],
-		[153, 182, 203, 127, 64, 74, 247, 129, 21, 59, 174, 190, 215, 194, 147, 229, 28, 175, 10, 214, 4, 182, 179, 99, 80, 222, 69, 215, 90, 80, 65, 165],
+		[52, 211, 24, 236, 44, 210, 31, 25, 21, 201, 167, 96, 69, 63, 91, 169, 135, 222, 186, 149, 53, 172, 32, 249, 157, 90, 243, 90, 55, 166, 196, 174],
+		[232, 38, 52, 63, 112, 80, 112, 252, 56, 115, 200, 66, 28, 43, 241, 249, 212, 230, 141, 205, 110, 202, 208, 237, 57, 211, 210, 74, 126, 235, 251, 113],
+		[97, 232, 128, 32, 164, 63, 79, 142, 237, 43, 187, 218, 74, 196, 78, 119, 176, 51, 127, 46, 136, 37, 181, 152, 209, 232, 254, 255, 125, 47, 39, 96],
+		[255, 54, 132, 253, 134, 168, 120, 26, 143, 5, 142, 39, 162, 66, 65, 215, 217, 74, 21, 19, 37, 220, 67, 23, 251, 28, 53, 255, 31, 228, 14, 129],
 	],
 	[
-		[31, 122, 174, 252, 32, 191, 152, 12, 177, 179, 59, 153, 37, 167, 151, 241, 113, 195, 25, 40, 11, 40, 203, 251, 163, 252, 130, 100, 213, 28, 31, 157],
-		[46, 255, 255, 97, 143, 53, 17, 105, 242, 178, 40, 242, 148, 173, 178, 15, 159, 225, 167, 186, 166, 125, 191, 92, 170, 147, 253, 225, 65, 147, 81, 70],
-		[213, 71, 242, 205, 85, 238, 192, 244, 168, 153, 46, 166, 181, 234, 65, 15, 54, 242, 3, 17, 145, 232, 0, 212, 145, 251, 29, 181, 26, 72, 66, 14],
-		[116, 47, 114, 147, 45, 132, 249, 115, 1, 106, 200, 131, 193, 228, 38, 48, 154, 7, 1, 4, 78, 113, 251, 145, 205, 200, 161, 168, 115, 251, 149, 215],
+		[161, 22, 162, 14, 146, 82, 1, 220, 159, 198, 137, 253, 147, 223, 142, 230, 18, 220, 112, 248, 153, 64, 9, 90, 70, 155, 223, 22, 149, 172, 2, 62],
+		[218, 45, 214, 157, 83, 60, 3, 186, 206, 59, 250, 21, 79, 6, 229, 23, 49, 53, 245, 98, 238, 67, 47, 174, 104, 230, 60, 225, 88, 172, 122, 105],
+		[69, 27, 37, 165, 37, 229, 49, 176, 37, 154, 43, 64, 163, 185, 229, 34, 74, 76, 41, 224, 159, 252, 96, 73, 83, 186, 244, 173, 133, 212, 135, 147],
+		[185, 8, 120, 108, 68, 107, 234, 121, 5, 237, 140, 191, 206, 11, 23, 236, 155, 9, 90, 1, 136, 42, 150, 46, 80, 238, 62, 13, 77, 144, 226, 225],
 	],
 	[
-		[103, 227, 40, 66, 79, 211, 174, 147, 84, 4, 128, 50, 102, 97, 44, 166, 6, 226, 103, 148, 209, 243, 6, 91, 27, 133, 37, 199, 3, 61, 130, 91],
-		[107, 45, 5, 243, 210, 63, 59, 98, 81, 26, 57, 56, 137, 63, 210, 212, 206, 32, 18, 9, 188, 247, 141, 13, 64, 20, 207, 68, 156, 115, 191, 236],
-		[44, 41, 58, 161, 209, 122, 134, 72, 199, 81, 115, 118, 7, 60, 102, 39, 104, 195, 231, 121, 89, 66, 187, 18, 155, 75, 30, 26, 49, 20, 237, 27],
-		[6, 165, 54, 235, 192, 189, 177, 112, 137, 228, 10, 216, 184, 111, 241, 189, 83, 228, 29, 5, 254, 133, 57, 214, 154, 143, 138, 97, 146, 71, 226, 56],
+		[95, 169, 5, 153, 168, 53, 58, 210, 174, 129, 220, 121, 122, 36, 55, 90, 163, 44, 218, 155, 127, 139, 99, 230, 75, 217, 74, 79, 130, 71, 50, 79],
+		[185, 86, 216, 59, 61, 159, 55, 194, 204, 156, 53, 92, 70, 184, 36, 119, 71, 215, 161, 209, 168, 75, 113, 6, 54, 223, 223, 115, 146, 220, 214, 210],
+		[253, 215, 110, 202, 180, 92, 52, 2, 179, 107, 4, 116, 161, 233, 233, 193, 73, 205, 24, 106, 142, 218, 177, 73, 171, 29, 244, 158, 60, 223, 222, 99],
+		[247, 124, 173, 17, 16, 117, 233, 245, 90, 201, 174, 233, 156, 212, 255, 167, 34, 121, 186, 19, 213, 172, 146, 205, 190, 30, 135, 252, 60, 25, 143, 54],
 	],
 	[
-		[228, 181, 94, 157, 21, 117, 74, 50, 24, 64, 79, 131, 39, 28, 246, 155, 160, 59, 145, 44, 19, 239, 123, 242, 77, 169, 104, 155, 126, 172, 112, 142],
-		[124, 245, 145, 120, 115, 238, 238, 23, 6, 176, 141, 188, 176, 251, 245, 46, 135, 96, 183, 63, 243, 222, 193, 127, 232, 147, 29, 246, 115, 203, 73, 27],
-		[238, 19, 100, 98, 24, 234, 39, 214, 90, 156, 190, 173, 145, 94, 125, 84, 80, 197, 189, 90, 0, 2, 34, 198, 138, 74, 214, 65, 160, 90, 176, 68],
-		[203, 156, 30, 163, 96, 46, 81, 133, 60, 117, 235, 84, 100, 203, 74, 116, 10, 36, 2, 156, 199, 15, 107, 238, 193, 174, 4, 224, 240, 150, 206, 40],
+		[155, 55, 87, 56, 54, 111, 245, 113, 70, 0, 82, 127, 198, 5, 104, 118, 38, 17, 102, 93, 229, 203, 37, 139, 38, 120, 145, 157, 75, 1, 250, 143],
+		[91, 74, 204, 47, 72, 176, 70, 73, 124, 211, 227, 172, 252, 173, 54, 69, 225, 134, 28, 165, 41, 179, 150, 46, 197, 105, 227, 219, 142, 188, 191, 111],
+		[72, 41, 148, 216, 97, 66, 173, 12, 1, 77, 170, 229, 129, 67, 58, 203, 147, 206, 31, 35, 78, 170, 94, 192, 56, 44, 67, 134, 13, 196, 236, 63],
+		[135, 120, 17, 162, 225, 142, 55, 97, 37, 183, 144, 160, 169, 201, 82, 66, 62, 236, 132, 62, 243, 91, 35, 153, 180, 191, 242, 197, 129, 204, 125, 38],
 	],
 	[
-		[169, 161, 132, 150, 62, 95, 53, 93, 32, 142, 134, 97, 168, 220, 22, 15, 11, 39, 59, 138, 116, 150, 10, 137, 140, 67, 23, 229, 91, 65, 228, 99],
-		[252, 71, 5, 170, 79, 70, 124, 174, 33, 120, 48, 169, 58, 251, 131, 3, 153, 124, 188, 168, 107, 76, 92, 181, 117, 118, 86, 217, 12, 91, 244, 64],
-		[176, 143, 78, 60, 79, 243, 65, 120, 60, 137, 7, 26, 238, 164, 96, 113, 158, 113, 70, 3, 217, 183, 22, 15, 152, 244, 238, 112, 50, 229, 101, 171],
-		[23, 250, 90, 236, 206, 36, 203, 81, 154, 241, 84, 40, 40, 152, 238, 34, 255, 175, 230, 143, 23, 61, 31, 210, 190, 195, 75, 75, 160, 130, 165, 194],
+		[18, 183, 12, 105, 186, 112, 137, 53, 209, 60, 158, 218, 90, 73, 212, 41, 112, 110, 173, 140, 112, 142, 187, 253, 167, 244, 72, 118, 15, 35, 73, 57],
+		[205, 125, 180, 75, 153, 1, 226, 54, 76, 228, 210, 203, 246, 47, 34, 240, 184, 152, 89, 251, 36, 55, 220, 118, 128, 9, 102, 47, 9, 207, 170, 16],
+		[98, 95, 94, 82, 44, 93, 16, 203, 188, 21, 217, 136, 57, 255, 16, 204, 205, 112, 219, 218, 69, 78, 131, 226, 227, 68, 175, 178, 140, 161, 236, 29],
+		[93, 101, 226, 213, 237, 251, 233, 244, 56, 245, 142, 247, 203, 74, 14, 124, 36, 79, 190, 206, 118, 98, 88, 105, 70, 38, 36, 204, 29, 216, 218, 188],
 	],
 	[
-		[192, 2, 62, 164, 205, 174, 13, 35, 171, 180, 52, 184, 10, 32, 100, 142, 205, 77, 189, 143, 148, 108, 219, 230, 41, 25, 40, 69, 16, 196, 229, 115],
-		[157, 110, 13, 128, 54, 107, 205, 154, 174, 217, 147, 23, 126, 100, 231, 107, 92, 20, 105, 207, 80, 197, 18, 182, 161, 70, 34, 222, 34, 211, 13, 82],
-		[253, 87, 151, 126, 63, 180, 98, 117, 117, 210, 244, 234, 172, 156, 34, 191, 240, 79, 133, 192, 226, 53, 227, 120, 35, 155, 201, 201, 170, 191, 187, 109],
-		[118, 190, 141, 52, 224, 47, 219, 116, 181, 175, 39, 155, 217, 185, 89, 247, 115, 98, 217, 235, 176, 35, 23, 107, 247, 23, 14, 213, 216, 184, 231, 208],
+		[220, 200, 87, 215, 251, 243, 104, 11, 197, 118, 161, 226, 53, 0, 124, 85, 247, 252, 132, 135, 10, 88, 128, 62, 238, 176, 169, 163, 48, 103, 53, 61],
+		[50, 160, 88, 10, 248, 1, 115, 129, 245, 178, 208, 52, 11, 54, 69, 13, 111, 100, 174, 171, 95, 46, 73, 206, 66, 127, 82, 181, 78, 227, 167, 134],
+		[88, 208, 105, 88, 148, 141, 105, 198, 188, 23, 3, 17, 153, 182, 29, 228, 32, 5, 65, 218, 64, 100, 158, 104, 191, 17, 178, 106, 94, 227, 108, 70],
+		[243, 83, 242, 130, 87, 133, 107, 143, 67, 233, 103, 3, 8, 22, 251, 185, 92, 63, 108, 254, 128, 52, 88, 189, 27, 180, 119, 38, 235, 226, 8, 222],
 	],
 	[
-		[52, 12, 17, 36, 247, 120, 220, 52, 146, 159, 10, 29, 14, 40, 242, 242, 71, 158, 110, 121, 241, 74, 78, 47, 21, 56, 15, 132, 197, 109, 141, 132],
-		[83, 140, 186, 40, 3, 28, 168, 54, 89, 182, 86, 100, 209, 185, 192, 158, 111, 123, 204, 15, 14, 210, 2, 219, 126, 178, 38, 114, 83, 35, 163, 87],
-		[4, 186, 54, 186, 109, 163, 177, 10, 67, 246, 107, 222, 32, 138, 116, 63, 228, 67, 188, 200, 26, 180, 214, 157, 147, 164, 211, 235, 239, 146, 145, 181],
-		[104, 42, 130, 3, 47, 83, 47, 255, 17, 138, 144, 37, 73, 141, 155, 109, 64, 205, 167, 35, 16, 156, 50, 214, 41, 255, 63, 174, 212, 134, 215, 84],
+		[118, 215, 169, 205, 128, 89, 134, 9, 69, 137, 22, 94, 64, 38, 20, 122, 37, 248, 131, 158, 160, 58, 45, 155, 159, 198, 225, 184, 11, 140, 218, 105],
+		[206, 133, 5, 49, 238, 14, 157, 147, 194, 73, 66, 125, 254, 199, 113, 113, 101, 129, 4, 97, 5, 169, 240, 115, 149, 55, 46, 240, 238, 39, 96, 142],
+		[211, 142, 144, 86, 111, 205, 232, 140, 110, 140, 234, 103, 195, 20, 175, 197, 176, 222, 32, 37, 141, 243, 158, 50, 91, 187, 5, 137, 52, 244, 20, 22],
+		[169, 112, 154, 23, 54, 36, 138, 188, 52, 42, 101, 246, 142, 147, 161, 62, 125, 63, 121, 141, 203, 11, 195, 173, 129, 124, 68, 134, 61, 0, 109, 195],
 	],
 	[
-		[158, 47, 91, 244, 158, 146, 244, 200, 69, 76, 115, 133, 228, 182, 110, 71, 230, 182, 32, 45, 239, 79, 127, 137, 198, 65, 45, 250, 160, 76, 23, 200],
-		[21, 53, 20, 43, 200, 115, 26, 226, 168, 112, 163, 142, 254, 241, 37, 115, 59, 74, 22, 3, 111, 113, 29, 29, 222, 189, 181, 190, 40, 137, 55, 23],
-		[31, 17, 241, 159, 238, 217, 221, 113, 111, 131, 221, 90, 64, 130, 7, 214, 52, 134, 18, 103, 141, 200, 21, 9, 131, 253, 224, 132, 201, 82, 101, 203],
-		[187, 97, 90, 198, 210, 55, 17, 195, 66, 226, 234, 192, 226, 104, 9, 220, 19, 108, 0, 38, 35, 228, 255, 222, 40, 88, 253, 221, 173, 175, 91, 55],
+		[74, 55, 47, 95, 221, 142, 222, 20, 31, 46, 102, 183, 227, 156, 241, 48, 166, 145, 151, 63, 151, 30, 172, 227, 115, 79, 205, 52, 167, 192, 149, 64],
+		[121, 131, 190, 179, 14, 139, 60, 164, 221, 192, 160, 48, 239, 48, 97, 238, 26, 193, 203, 254, 163, 36, 197, 108, 127, 35, 234, 73, 222, 248, 67, 145],
+		[87, 100, 52, 87, 149, 53, 237, 20, 203, 169, 27, 144, 231, 167, 244, 168, 152, 28, 157, 244, 108, 26, 238, 82, 56, 64, 247, 67, 64, 38, 57, 174],
+		[211, 93, 116, 195, 73, 56, 86, 235, 171, 22, 166, 225, 193, 11, 169, 77, 83, 31, 219, 109, 83, 251, 29, 12, 233, 178, 194, 111, 10, 157, 107, 205],
 	],
 	[
-		[22, 228, 80, 81, 184, 248, 19, 155, 39, 108, 186, 188, 215, 124, 19, 189, 16, 70, 2, 66, 47, 119, 207, 108, 116, 170, 248, 46, 120, 40, 2, 174],
-		[103, 92, 101, 200, 97, 35, 156, 0, 163, 9, 209, 141, 164, 142, 143, 3, 40, 58, 228, 253, 135, 74, 215, 17, 32, 121, 210, 122, 24, 16, 18, 79],
-		[114, 136, 77, 14, 163, 19, 204, 231, 66, 34, 173, 11, 36, 228, 46, 166, 103, 64, 17, 32, 139, 64, 25, 12, 214, 223, 4, 72, 224, 182, 21, 78],
-		[110, 76, 49, 103, 70, 193, 169, 205, 42, 235, 169, 2, 204, 210, 152, 58, 212, 119, 180, 239, 42, 195, 17, 229, 210, 238, 213, 129, 24, 41, 149, 48],
+		[238, 152, 217, 131, 180, 130, 229, 156, 163, 230, 212, 60, 172, 2, 166, 79, 35, 159, 4, 108, 37, 78, 50, 168, 57, 191, 190, 135, 76, 144, 199, 167],
+		[196, 28, 10, 21, 246, 124, 211, 15, 248, 2, 233, 248, 46, 64, 25, 14, 149, 82, 152, 61, 115, 105, 9, 188, 74, 193, 61, 173, 17, 33, 229, 84],
+		[153, 143, 146, 226, 13, 87, 156, 1, 221, 20, 133, 217, 12, 235, 229, 48, 126, 77, 224, 171, 139, 225, 245, 201, 228, 148, 180, 92, 42, 246, 216, 149],
+		[8, 238, 159, 225, 150, 248, 120, 157, 139, 71, 226, 84, 54, 183, 235, 62, 101, 192, 230, 242, 60, 148, 195, 88, 38, 130, 253, 120, 153, 220, 172, 239],
 	],
 	[
-		[63, 205, 220, 160, 136, 202, 98, 141, 162, 66, 125, 19, 135, 183, 46, 54, 127, 182, 200, 70, 216, 132, 26, 102, 148, 110, 164, 66, 210, 97, 94, 111],
-		[7, 175, 46, 90, 108, 192, 175, 201, 120, 231, 95, 207, 246, 13, 16, 40, 196, 116, 85, 203, 38, 205, 96, 71, 14, 92, 220, 251, 62, 170, 128, 113],
-		[97, 109, 215, 159, 26, 116, 37, 37, 214, 134, 74, 123, 32, 91, 47, 203, 246, 13, 117, 0, 144, 195, 237, 249, 170, 213, 102, 66, 37, 39, 41, 182],
-		[70, 44, 164, 217, 19, 32, 109, 80, 17, 130, 244, 181, 171, 93, 159, 123, 170, 57, 236, 219, 50, 128, 110, 246, 20, 138, 135, 225, 207, 62, 54, 65],
+		[105, 94, 9, 149, 251, 61, 161, 4, 34, 238, 18, 4, 107, 184, 242, 92, 249, 233, 123, 239, 33, 209, 168, 152, 111, 139, 169, 22, 40, 239, 218, 204],
+		[192, 185, 97, 158, 100, 93, 94, 165, 94, 51, 40, 87, 23, 195, 85, 9, 217, 55, 194, 77, 14, 100, 163, 77, 209, 173, 171, 98, 222, 146, 52, 51],
+		[153, 106, 214, 129, 191, 215, 166, 65, 65, 67, 248, 157, 212, 153, 27, 144, 15, 87, 138, 137, 210, 102, 149, 130, 52, 0, 191, 197, 58, 250, 214, 159],
+		[120, 167, 190, 5, 174, 105, 137, 133, 1, 58, 151, 155, 238, 29, 245, 183, 237, 50, 126, 152, 143, 47, 204, 148, 54, 86, 226, 13, 165, 34, 27, 145],
 	],
 	[
-		[229, 199, 28, 82, 60, 81, 166, 7, 218, 183, 211, 55, 110, 26, 209, 203, 213, 203, 5, 158, 190, 61, 127, 159, 199, 14, 84, 248, 73, 235, 40, 223],
-		[104, 190, 99, 202, 40, 233, 57, 5, 231, 172, 240, 194, 37, 199, 174, 23, 209, 201, 7, 207, 118, 24, 53, 20, 61, 65, 104, 118, 10, 8, 13, 211],
-		[141, 239, 34, 46, 220, 117, 107, 152, 119, 89, 107, 99, 96, 56, 130, 190, 166, 204, 152, 87, 58, 21, 45, 228, 8, 156, 70, 132, 170, 225, 239, 45],
-		[240, 187, 121, 121, 86, 25, 125, 189, 97, 102, 82, 127, 111, 12, 136, 48, 72, 246, 36, 56, 120, 119, 39, 216, 165, 100, 46, 4, 80, 143, 56, 233],
+		[27, 106, 199, 247, 198, 71, 86, 84, 133, 206, 233, 227, 129, 129, 123, 239, 110, 94, 244, 110, 11, 105, 215, 245, 54, 17, 164, 144, 216, 252, 185, 95],
+		[240, 141, 21, 158, 13, 117, 112, 131, 42, 8, 115, 43, 218, 56, 13, 184, 8, 64, 219, 38, 123, 228, 44, 171, 167, 206, 11, 35, 53, 65, 143, 114],
+		[68, 101, 13, 251, 0, 218, 167, 175, 28, 186, 88, 5, 251, 58, 240, 174, 102, 143, 104, 177, 108, 80, 244, 59, 200, 250, 34, 222, 151, 163, 181, 62],
+		[248, 171, 240, 255, 34, 103, 85, 102, 35, 248, 13, 36, 177, 58, 140, 177, 51, 5, 40, 116, 13, 106, 111, 31, 241, 167, 219, 189, 232, 123, 87, 9],
 	],
 	[
-		[22, 189, 68, 59, 59, 229, 40, 81, 69, 186, 233, 49, 218, 174, 53, 197, 233, 168, 145, 235, 183, 92, 222, 246, 130, 249, 123, 80, 0, 92, 96, 148],
-		[111, 23, 113, 108, 160, 34, 209, 107, 118, 42, 221, 70, 195, 50, 189, 131, 141, 30, 47, 9, 4, 232, 177, 230, 142, 253, 171, 182, 244, 170, 44, 39],
-		[245, 127, 148, 71, 120, 63, 129, 215, 10, 39, 225, 124, 227, 184, 240, 64, 69, 217, 252, 65, 201, 228, 196, 199, 102, 121, 171, 82, 101, 103, 206, 107],
-		[80, 1, 83, 211, 64, 185, 192, 197, 219, 179, 243, 220, 119, 140, 15, 183, 137, 5, 248, 201, 232, 216, 234, 126, 248, 166, 18, 252, 106, 47, 222, 163],
+		[20, 158, 151, 55, 201, 41, 79, 7, 47, 95, 143, 72, 46, 221, 110, 194, 133, 215, 6, 27, 162, 125, 42, 234, 138, 122, 34, 72, 50, 116, 229, 38],
+		[88, 251, 74, 115, 22, 226, 223, 133, 131, 193, 74, 160, 29, 197, 53, 109, 205, 94, 71, 72, 246, 58, 16, 159, 5, 188, 41, 240, 31, 217, 87, 219],
+		[54, 253, 41, 151, 78, 66, 143, 154, 217, 127, 53, 117, 3, 253, 212, 29, 27, 120, 58, 12, 77, 153, 42, 65, 145, 244, 181, 67, 171, 205, 245, 90],
+		[8, 60, 23, 54, 208, 11, 211, 3, 79, 233, 10, 48, 216, 33, 203, 19, 91, 207, 190, 179, 120, 209, 59, 124, 35, 36, 71, 47, 57, 221, 184, 217],
 	],
 	[
-		[234, 171, 96, 35, 248, 118, 110, 178, 117, 108, 219, 124, 119, 18, 133, 232, 254, 184, 143, 166, 216, 231, 150, 59, 72, 244, 160, 224, 189, 82, 147, 215],
-		[251, 194, 88, 16, 171, 85, 166, 164, 54, 5, 20, 129, 204, 149, 72, 252, 72, 38, 119, 115, 9, 99, 205, 60, 14, 24, 45, 204, 173, 102, 21, 139],
-		[94, 106, 0, 248, 217, 145, 174, 186, 202, 233, 129, 163, 2, 58, 1, 23, 84, 196, 57, 15, 219, 127, 180, 170, 27, 16, 138, 99, 227, 100, 123, 141],
-		[13, 229, 225, 166, 106, 129, 67, 236, 35, 40, 39, 195, 48, 43, 7, 211, 14, 113, 29, 106, 127, 126, 255, 198, 15, 244, 117, 211, 64, 127, 229, 155],
+		[62, 67, 141, 111, 41, 199, 27, 42, 48, 51, 76, 2, 238, 208, 88, 77, 155, 26, 192, 220, 189, 147, 191, 171, 87, 202, 90, 171, 10, 82, 90, 192],
+		[25, 53, 94, 126, 219, 221, 22, 137, 4, 250, 193, 97, 122, 202, 4, 32, 12, 191, 167, 127, 190, 143, 119, 97, 219, 244, 177, 126, 130, 208, 178, 27],
+		[11, 126, 50, 40, 2, 10, 54, 51, 60, 126, 184, 227, 152, 178, 222, 239, 55, 117, 204, 47, 4, 49, 114, 105, 22, 56, 242, 33, 102, 190, 39, 32],
+		[42, 244, 142, 115, 247, 246, 166, 255, 185, 181, 32, 16, 99, 242, 2, 126, 240, 165, 144, 242, 91, 106, 100, 62, 14, 66, 121, 12, 156, 183, 164, 23],
 	],
 	[
-		[239, 190, 242, 129, 153, 130, 152, 255, 97, 166, 202, 86, 198, 17, 247, 247, 83, 37, 77, 99, 101, 190, 222, 237, 122, 224, 67, 14, 240, 123, 195, 224],
-		[203, 89, 142, 200, 120, 69, 51, 198, 31, 245, 1, 226, 5, 140, 120, 223, 229, 11, 213, 108, 84, 48, 20, 224, 27, 26, 100, 229, 56, 162, 210, 214],
-		[237, 3, 30, 40, 125, 87, 74, 141, 247, 95, 227, 251, 122, 100, 226, 107, 105, 12, 73, 45, 63, 198, 36, 134, 42, 75, 189, 63, 5, 254, 167, 195],
-		[98, 211, 157, 165, 37, 45, 126, 169, 51, 81, 179, 62, 145, 106, 215, 16, 165, 93, 150, 23, 55, 174, 71, 100, 226, 252, 236, 33, 74, 38, 57, 52],
+		[180, 176, 240, 115, 248, 48, 140, 14, 215, 138, 29, 201, 89, 28, 188, 42, 199, 21, 176, 248, 231, 235, 39, 191, 6, 147, 174, 101, 93, 211, 179, 109],
+		[80, 59, 58, 77, 199, 216, 129, 83, 240, 152, 30, 243, 4, 144, 91, 247, 253, 231, 226, 182, 219, 211, 82, 143, 230, 216, 135, 158, 180, 73, 206, 179],
+		[231, 129, 214, 250, 75, 57, 185, 23, 197, 59, 105, 175, 241, 218, 76, 196, 70, 11, 29, 227, 196, 14, 188, 57, 56, 179, 105, 201, 157, 109, 169, 162],
+		[108, 211, 70, 64, 140, 88, 225, 52, 76, 170, 243, 98, 163, 138, 224, 235, 205, 179, 210, 77, 103, 109, 183, 98, 152, 95, 180, 126, 231, 102, 174, 58],
 	],
-];
\ No newline at end of file
+];
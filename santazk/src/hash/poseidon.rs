@@ -0,0 +1,306 @@
+//! Poseidon counterpart of [`Hash`](super::Hash), with matching native and
+//! constrained implementations, and the [`Poseidon`] config the circuits and the
+//! Merkle tree are instantiated with.
+//!
+//! Inputs are bytes like with Pedersen: they are packed 31 bytes per field
+//! element, after an element holding the input length. Digests are field
+//! elements, serialized as 32 little-endian bytes instead of the 64 bytes of a
+//! JubJub point.
+
+use ark_crypto_primitives::crh::{
+    poseidon::{
+        constraints::{CRHGadget, CRHParametersVar, TwoToOneCRHGadget},
+        TwoToOneCRH, CRH,
+    },
+    CRHScheme, CRHSchemeGadget, TwoToOneCRHScheme, TwoToOneCRHSchemeGadget,
+};
+use ark_crypto_primitives::sponge::poseidon::{find_poseidon_ark_and_mds, PoseidonConfig};
+use ark_ed_on_bls12_381::Fq as Fr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+use super::{HashConfig, MerklePathVar, NativeHash};
+use crate::merkle::poseidon::{PoseidonMerkleTreeParams, PoseidonMerkleTreeParamsVar};
+
+pub const POSEIDON_DIGEST_SIZE: usize = 32;
+
+/// Number of input bytes packed in one field element, so that any chunk fits.
+const BYTES_PER_ELEMENT: usize = 31;
+
+// arkworks' parameters optimized for constraints, for a 255-bit field and a rate of 2
+const RATE: usize = 2;
+const ALPHA: u64 = 17;
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 31;
+
+/// Returns the Poseidon parameters shared by the hash and the Merkle tree. The
+/// round constants and MDS matrix are derived from the Grain LFSR, so they are
+/// reproducible by anyone.
+pub fn poseidon_config() -> PoseidonConfig<Fr> {
+    let (ark, mds) = find_poseidon_ark_and_mds::<Fr>(
+        u64::from(Fr::MODULUS_BIT_SIZE),
+        RATE,
+        FULL_ROUNDS as u64,
+        PARTIAL_ROUNDS as u64,
+        0,
+    );
+    PoseidonConfig::new(FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, mds, ark, RATE, 1)
+}
+
+pub struct PoseidonHash {
+    params: PoseidonConfig<Fr>,
+}
+
+impl PoseidonHash {
+    pub fn new() -> Self {
+        Self {
+            params: poseidon_config(),
+        }
+    }
+
+    pub fn h1(&self, input: &[u8]) -> Vec<u8> {
+        serialize_digest(&self.h1_field(input))
+    }
+
+    pub fn h2(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        let l = self.h1_field(left);
+        let r = self.h1_field(right);
+        let h = TwoToOneCRH::<Fr>::compress(&self.params, l, r).unwrap();
+        serialize_digest(&h)
+    }
+
+    /// Compresses two digests, like an inner node of the Merkle tree.
+    pub fn h2c(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        let l = deserialize_digest(left).expect("invalid Poseidon digest");
+        let r = deserialize_digest(right).expect("invalid Poseidon digest");
+        let h = TwoToOneCRH::<Fr>::compress(&self.params, l, r).unwrap();
+        serialize_digest(&h)
+    }
+
+    fn h1_field(&self, input: &[u8]) -> Fr {
+        CRH::<Fr>::evaluate(&self.params, bytes_to_field_elements(input)).unwrap()
+    }
+}
+
+impl Default for PoseidonHash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NativeHash for PoseidonHash {
+    fn h1(&self, input: &[u8]) -> Vec<u8> {
+        PoseidonHash::h1(self, input)
+    }
+
+    fn h2(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        PoseidonHash::h2(self, left, right)
+    }
+
+    fn h2c(&self, left: &[u8], right: &[u8]) -> Vec<u8> {
+        PoseidonHash::h2c(self, left, right)
+    }
+}
+
+/// The [`PoseidonHash`], whose digests are field elements.
+#[derive(Clone, Copy, Debug)]
+pub struct Poseidon;
+
+impl HashConfig for Poseidon {
+    const DIGEST_SIZE: usize = POSEIDON_DIGEST_SIZE;
+
+    type Hash = PoseidonHash;
+    type Digest = Fr;
+    type MerkleConfig = PoseidonMerkleTreeParams;
+    type MerkleConfigVar = PoseidonMerkleTreeParamsVar;
+    type MerkleLeaf = Vec<Fr>;
+    type ParamsVar = CRHParametersVar<Fr>;
+
+    fn hash() -> PoseidonHash {
+        PoseidonHash::new()
+    }
+
+    fn merkle_leaf(bytes: &[u8]) -> Vec<Fr> {
+        bytes_to_field_elements(bytes)
+    }
+
+    fn merkle_params() -> (PoseidonConfig<Fr>, PoseidonConfig<Fr>) {
+        (poseidon_config(), poseidon_config())
+    }
+
+    fn serialize_digest(digest: &Fr) -> Vec<u8> {
+        serialize_digest(digest)
+    }
+
+    fn deserialize_digest(bytes: &[u8]) -> Option<Fr> {
+        deserialize_digest(bytes)
+    }
+
+    fn digest_to_field_elements(digest: &Fr) -> Vec<Fr> {
+        vec![*digest]
+    }
+
+    fn params_var(cs: ConstraintSystemRef<Fr>) -> Result<CRHParametersVar<Fr>, SynthesisError> {
+        CRHParametersVar::new_constant(ark_relations::ns!(cs, "poseidon_params"), poseidon_config())
+    }
+
+    fn h1_var(
+        params: &CRHParametersVar<Fr>,
+        input: &[UInt8<Fr>],
+    ) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+        h1_var(params, input)
+    }
+
+    fn h2_var(
+        params: &CRHParametersVar<Fr>,
+        left: &[UInt8<Fr>],
+        right: &[UInt8<Fr>],
+    ) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+        h2_var(params, left, right)
+    }
+
+    fn verify_membership(
+        params: &CRHParametersVar<Fr>,
+        path: &MerklePathVar<Self>,
+        root: &FpVar<Fr>,
+        leaf: &[UInt8<Fr>],
+    ) -> Result<Boolean<Fr>, SynthesisError> {
+        path.verify_membership(params, params, root, &bytes_to_field_element_vars(leaf)?)
+    }
+}
+
+pub fn serialize_digest(digest: &Fr) -> Vec<u8> {
+    digest.into_bigint().to_bytes_le()
+}
+
+/// Returns none unless `bytes` is the encoding of a field element, of
+/// [`POSEIDON_DIGEST_SIZE`] bytes.
+pub fn deserialize_digest(bytes: &[u8]) -> Option<Fr> {
+    if bytes.len() != POSEIDON_DIGEST_SIZE {
+        return None;
+    }
+    Fr::from_bigint(BigInteger::from_bits_le(&bytes_to_bits_le(bytes)))
+}
+
+/// Packs `bytes` into field elements, prefixed with their length so that inputs
+/// differing only by trailing zeros don't collide.
+pub fn bytes_to_field_elements(bytes: &[u8]) -> Vec<Fr> {
+    let mut elements = vec![Fr::from(bytes.len() as u64)];
+    elements.extend(
+        bytes
+            .chunks(BYTES_PER_ELEMENT)
+            .map(Fr::from_le_bytes_mod_order),
+    );
+    elements
+}
+
+fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| byte & (1 << i) != 0))
+        .collect()
+}
+
+/// Constrained counterpart of [`PoseidonHash::h1`].
+pub fn h1_var(
+    params: &CRHParametersVar<Fr>,
+    input: &[UInt8<Fr>],
+) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    h1_field_var(params, input)?.to_bytes()
+}
+
+/// Constrained counterpart of [`PoseidonHash::h2`].
+pub fn h2_var(
+    params: &CRHParametersVar<Fr>,
+    left: &[UInt8<Fr>],
+    right: &[UInt8<Fr>],
+) -> Result<Vec<UInt8<Fr>>, SynthesisError> {
+    let l = h1_field_var(params, left)?;
+    let r = h1_field_var(params, right)?;
+    TwoToOneCRHGadget::<Fr>::compress(params, &l, &r)?.to_bytes()
+}
+
+/// Constrained counterpart of [`bytes_to_field_elements`].
+pub fn bytes_to_field_element_vars(bytes: &[UInt8<Fr>]) -> Result<Vec<FpVar<Fr>>, SynthesisError> {
+    let mut elements = vec![FpVar::constant(Fr::from(bytes.len() as u64))];
+    for chunk in bytes.chunks(BYTES_PER_ELEMENT) {
+        let bits = chunk
+            .iter()
+            .map(UInt8::to_bits_le)
+            .collect::<Result<Vec<_>, _>>()?
+            .concat();
+        elements.push(Boolean::le_bits_to_fp_var(&bits)?);
+    }
+    Ok(elements)
+}
+
+fn h1_field_var(
+    params: &CRHParametersVar<Fr>,
+    input: &[UInt8<Fr>],
+) -> Result<FpVar<Fr>, SynthesisError> {
+    CRHGadget::<Fr>::evaluate(params, &bytes_to_field_element_vars(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+
+    fn params_var(cs: &ark_relations::r1cs::ConstraintSystemRef<Fr>) -> CRHParametersVar<Fr> {
+        CRHParametersVar::new_constant(cs.clone(), poseidon_config()).unwrap()
+    }
+
+    #[test]
+    fn native_and_constrained_hashes_match() {
+        let hash = PoseidonHash::new();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let params = params_var(&cs);
+
+        for (left, right) in [
+            (vec![1u8; 32], vec![2u8; 32]),
+            (vec![3u8; 64], vec![4u8; 112]),
+            (vec![], vec![5u8; 1]),
+        ] {
+            let left_var = UInt8::new_witness_vec(cs.clone(), &left).unwrap();
+            let right_var = UInt8::new_witness_vec(cs.clone(), &right).unwrap();
+
+            let h1 = h1_var(&params, &left_var).unwrap();
+            assert_eq!(h1.value().unwrap(), hash.h1(&left));
+
+            let h2 = h2_var(&params, &left_var, &right_var).unwrap();
+            assert_eq!(h2.value().unwrap(), hash.h2(&left, &right));
+        }
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn inputs_are_length_prefixed() {
+        let hash = PoseidonHash::new();
+        assert_eq!(hash.h1(&[1u8; 32]).len(), POSEIDON_DIGEST_SIZE);
+        assert_ne!(
+            hash.h1(&[1u8; 31]),
+            hash.h1(&[[1u8; 31].as_slice(), &[0]].concat())
+        );
+        assert_ne!(hash.h1(&[]), hash.h1(&[0]));
+    }
+
+    #[test]
+    fn digests_round_trip() {
+        let hash = PoseidonHash::new();
+        let l = hash.h1(&[1u8; 32]);
+        let r = hash.h1(&[2u8; 32]);
+        assert_eq!(serialize_digest(&deserialize_digest(&l).unwrap()), l);
+        assert_eq!(hash.h2c(&l, &r), hash.h2(&[1u8; 32], &[2u8; 32]));
+    }
+
+    #[test]
+    fn non_canonical_digests_are_rejected() {
+        // at least the modulus, or of another size
+        assert_eq!(deserialize_digest(&[0xff; POSEIDON_DIGEST_SIZE]), None);
+        assert_eq!(deserialize_digest(&[1u8; POSEIDON_DIGEST_SIZE + 1]), None);
+        assert_eq!(deserialize_digest(&[]), None);
+    }
+}
@@ -1,10 +1,11 @@
 #![allow(unused)]
 
-use ark_crypto_primitives::crh::{pedersen::Parameters, CRHScheme, TwoToOneCRHScheme};
-use ark_ed_on_bls12_381::{EdwardsAffine as JubJubAffine, EdwardsProjective as JubJub};
+use ark_crypto_primitives::crh::pedersen::{Parameters, Window};
+use ark_ec::{AffineRepr, Group};
+use ark_ed_on_bls12_381::{EdwardsAffine as JubJubAffine, EdwardsProjective as JubJub, Fq};
+use ark_ff::{PrimeField, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-
-use rand_core::OsRng;
+use sha2::{Digest, Sha512};
 
 use super::common::*;
 
@@ -31,33 +32,80 @@ pub fn serialize_pedersen_params(params: &Parameters<JubJub>) -> Vec<Vec<Vec<u8>
     serialized
 }
 
-pub fn generate_pedersen_params(name_prefix: &str, two_to_one: bool) {
-    let rng = &mut OsRng;
+/// Derives the generators of a [`Window4x256`] Pedersen hash from a public
+/// `seed`, so that no one knows discrete-log relations between them.
+///
+/// The base of each window is hashed to the curve by try-and-increment: the
+/// SHA-512 digest of `seed || window || counter` is taken as a y-coordinate
+/// until it is on the curve and its multiple by the cofactor isn't the
+/// identity. As with the random setup of arkworks, the other generators of the
+/// window are the successive doublings of its base.
+pub fn derive_pedersen_params(seed: &[u8]) -> Parameters<JubJub> {
+    let generators = (0..Window4x256::NUM_WINDOWS)
+        .map(|window| {
+            let mut base = hash_to_curve(seed, window as u32);
+            let mut powers = Vec::with_capacity(Window4x256::WINDOW_SIZE);
+            for _ in 0..Window4x256::WINDOW_SIZE {
+                powers.push(base);
+                base.double_in_place();
+            }
+            powers
+        })
+        .collect();
+    Parameters::<JubJub> { generators }
+}
 
-    let params = if two_to_one {
-        TwoToOneCRH::setup(rng).unwrap()
-    } else {
-        CRH::setup(rng).unwrap()
-    };
+fn hash_to_curve(seed: &[u8], window: u32) -> JubJub {
+    (0u32..)
+        .find_map(|counter| {
+            let digest = Sha512::new()
+                .chain_update(seed)
+                .chain_update(window.to_le_bytes())
+                .chain_update(counter.to_le_bytes())
+                .finalize();
+            let y = Fq::from_le_bytes_mod_order(&digest);
+            let point = JubJubAffine::get_point_from_y_unchecked(y, false)?;
+            let point = point.mul_by_cofactor_to_group();
+            (!point.is_zero()).then_some(point)
+        })
+        .unwrap()
+}
 
-    let serialized = serialize_pedersen_params(&params);
+/// Returns the Rust source of the `{name_prefix}_PEDERSEN_PARAMS_BYTES` static
+/// holding the generators derived from `seed`.
+pub fn generate_pedersen_params(name_prefix: &str, seed: &[u8]) -> String {
+    let serialized = serialize_pedersen_params(&derive_pedersen_params(seed));
 
     let n1 = serialized.len();
     let n2 = serialized[0].len();
     let n3 = serialized[0][0].len();
 
-    println!(
-        "pub const {}_PEDERSEN_PARAMS_BYTES: [[[u8; {}]; {}]; {}] =\n[",
+    let mut source = format!(
+        "pub static {}_PEDERSEN_PARAMS_BYTES: [[[u8; {}]; {}]; {}] =\n[\n",
         name_prefix, n3, n2, n1
     );
     for a in serialized.iter() {
-        println!("\t[");
+        source.push_str("\t[\n");
         for b in a.iter() {
-            println!("\t\t{:?},", b);
+            source.push_str(&format!("\t\t{:?},\n", b));
         }
-        println!("\t],");
+        source.push_str("\t],\n");
     }
-    println!("];");
+    source.push_str("];\n");
+    source
+}
+
+/// Tells whether the embedded `params_bytes` are the generators derived from `seed`.
+pub fn check_pedersen_params<const A: usize, const B: usize, const C: usize>(
+    params_bytes: &[[[u8; A]; B]; C],
+    seed: &[u8],
+) -> bool {
+    let derived = serialize_pedersen_params(&derive_pedersen_params(seed));
+    derived.len() == C
+        && derived
+            .iter()
+            .zip(params_bytes.iter())
+            .all(|(a, b)| a.len() == B && a.iter().zip(b.iter()).all(|(x, y)| x == y))
 }
 
 pub fn load_pedersen_params<const A: usize, const B: usize, const C: usize>(
@@ -74,3 +122,28 @@ pub fn load_pedersen_params<const A: usize, const B: usize, const C: usize>(
     }
     Parameters::<JubJub> { generators }
 }
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::CurveGroup;
+
+    use super::*;
+    use crate::hash::pedersen_params::*;
+
+    #[test]
+    fn embedded_params_are_derived_from_their_seeds() {
+        assert!(check_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES, H1_PEDERSEN_SEED));
+        assert!(check_pedersen_params(&H2_PEDERSEN_PARAMS_BYTES, H2_PEDERSEN_SEED));
+        assert!(!check_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES, H2_PEDERSEN_SEED));
+    }
+
+    #[test]
+    fn generators_are_in_the_prime_order_subgroup() {
+        let params = derive_pedersen_params(H1_PEDERSEN_SEED);
+        for g in params.generators.iter().flatten() {
+            let g = g.into_affine();
+            assert!(!g.is_zero());
+            assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
@@ -3,4 +3,7 @@ pub mod merkle;
 pub mod circuits;
 pub mod serialization;
 pub mod proofs;
-pub mod crypto;
\ No newline at end of file
+pub mod crypto;
+pub mod participant;
+#[cfg(feature = "wasm")]
+pub mod wasm;
\ No newline at end of file
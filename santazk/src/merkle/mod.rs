@@ -1,3 +1,5 @@
+use std::borrow::Borrow;
+
 use ark_crypto_primitives::crh::{pedersen, TwoToOneCRHScheme, TwoToOneCRHSchemeGadget};
 use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
 
@@ -25,10 +27,10 @@ use rand_core::OsRng;
 
 use ark_ff::ToConstraintField;
 
-use crate::{
-    hash::{common::*, pedersen_params::*, serialization::load_pedersen_params},
-    serialization::*,
-};
+use crate::hash::{common::*, HashConfig, Pedersen};
+
+#[cfg(feature = "poseidon")]
+pub mod poseidon;
 
 type LeafH = CRH;
 type LeafHG = CRHGadget;
@@ -38,6 +40,7 @@ type CompressHG = TwoToOneCRHGadget;
 
 type LeafVar<ConstraintF> = [UInt8<ConstraintF>];
 
+#[derive(Clone)]
 pub struct JubJubMerkleTreeParams;
 impl Config for JubJubMerkleTreeParams {
     type Leaf = [u8];
@@ -50,7 +53,7 @@ impl Config for JubJubMerkleTreeParams {
 }
 
 type ConstraintF = Fr;
-pub(crate) struct JubJubMerkleTreeParamsVar;
+pub struct JubJubMerkleTreeParamsVar;
 impl ConfigGadget<JubJubMerkleTreeParams, ConstraintF> for JubJubMerkleTreeParamsVar {
     type Leaf = LeafVar<ConstraintF>;
     type LeafDigest = <LeafHG as CRHSchemeGadget<LeafH, ConstraintF>>::OutputVar;
@@ -60,71 +63,75 @@ impl ConfigGadget<JubJubMerkleTreeParams, ConstraintF> for JubJubMerkleTreeParam
     type TwoToOneHash = CompressHG;
 }
 
-type JubJubMerkleTree = ArkMerkleTree<JubJubMerkleTreeParams>;
+/// Merkle tree of byte leaves, hashed with `H`. Missing leaves are zeros.
+pub struct MerkleTreeOf<H: HashConfig>(ArkMerkleTree<H::MerkleConfig>);
 
-pub struct MerkleTree(JubJubMerkleTree);
-impl MerkleTree {
+/// The Merkle tree of the chain.
+pub type MerkleTree = MerkleTreeOf<Pedersen>;
+
+impl<H: HashConfig> MerkleTreeOf<H> {
     pub fn new(depth: usize, leaves: &[Vec<u8>]) -> Self {
-        let leaf_crh_params = load_pedersen_params(&H1_PEDERSEN_PARAMS_BYTES);
-        let two_to_one_crh_params = load_pedersen_params(&H2_PEDERSEN_PARAMS_BYTES);
-        let mut complete_leaves = Vec::from(leaves);
+        let (leaf_crh_params, two_to_one_crh_params) = H::merkle_params();
+        let mut complete_leaves: Vec<_> = leaves.iter().map(|l| H::merkle_leaf(l)).collect();
         for _ in 0..((1 << depth) - leaves.len()) {
-            complete_leaves.push(vec![0u8; 64]);
+            complete_leaves.push(H::merkle_leaf(&vec![0u8; H::DIGEST_SIZE]));
         }
-        let tree = JubJubMerkleTree::new(
+        let tree = ArkMerkleTree::new(
             &leaf_crh_params,
             &two_to_one_crh_params,
-            complete_leaves.iter().map(|v| v.as_slice()),
+            complete_leaves.iter().map(Borrow::borrow),
         )
         .unwrap();
         Self(tree)
     }
 
     pub fn root(&self) -> Vec<u8> {
-        serialize_jub_jub_affine_point(&self.0.root())
+        H::serialize_digest(&self.0.root())
     }
 
     pub fn update(&mut self, leaf_index: usize, leaf: &[u8]) {
-        self.0.update(leaf_index, leaf).unwrap();
+        self.0
+            .update(leaf_index, H::merkle_leaf(leaf).borrow())
+            .unwrap();
     }
 
     pub fn generate_proof(&self, leaf_index: usize) -> Option<Vec<u8>> {
         let proof = self.0.generate_proof(leaf_index).ok()?;
-        Some(MerkleTree::serialize_path(&proof))
+        Some(Self::serialize_path(&proof))
     }
 
-    pub(crate) fn serialize_path(path: &Path<JubJubMerkleTreeParams>) -> Vec<u8> {
+    pub fn serialize_path(path: &Path<H::MerkleConfig>) -> Vec<u8> {
         let mut bytes = Vec::new();
-        bytes.extend_from_slice(&serialize_jub_jub_affine_point(&path.leaf_sibling_hash));
+        bytes.extend_from_slice(&H::serialize_digest(&path.leaf_sibling_hash));
         for node in path.auth_path.iter() {
-            bytes.extend_from_slice(&serialize_jub_jub_affine_point(node));
+            bytes.extend_from_slice(&H::serialize_digest(node));
         }
         bytes.extend_from_slice(&(path.leaf_index as u32).to_le_bytes());
         bytes
     }
 
-    pub(crate) fn deserialize_path(bytes: &[u8]) -> Path<JubJubMerkleTreeParams> {
-        let mut i: usize = 0;
-
-        let leaf_sibling_hash =
-            deserialize_jub_jub_affine_point(&bytes[i..i + JUBJUB_AFFINE_POINT_SIZE]);
-        i += JUBJUB_AFFINE_POINT_SIZE;
-
-        let mut auth_path = Vec::new();
-        while i < bytes.len() - 4 {
-            auth_path.push(deserialize_jub_jub_affine_point(
-                &bytes[i..i + JUBJUB_AFFINE_POINT_SIZE],
-            ));
-            i += JUBJUB_AFFINE_POINT_SIZE;
+    /// Returns none if `bytes` isn't the encoding of a path, made of digests
+    /// followed by the 4-byte leaf index.
+    pub fn deserialize_path(bytes: &[u8]) -> Option<Path<H::MerkleConfig>> {
+        let (nodes, leaf_index) = bytes.split_at(bytes.len().checked_sub(4)?);
+        if nodes.is_empty() || nodes.len() % H::DIGEST_SIZE != 0 {
+            return None;
         }
-
-        let leaf_index: usize = u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap())
+        let mut nodes = nodes
+            .chunks(H::DIGEST_SIZE)
+            .map(H::deserialize_digest)
+            .collect::<Option<Vec<_>>>()?
+            .into_iter();
+        let leaf_sibling_hash = nodes.next()?;
+        let auth_path = nodes.collect();
+
+        let leaf_index = u32::from_le_bytes(leaf_index.try_into().ok()?)
             .try_into()
-            .unwrap();
-        Path {
+            .ok()?;
+        Some(Path {
             leaf_sibling_hash,
             auth_path,
             leaf_index,
-        }
+        })
     }
 }
@@ -0,0 +1,104 @@
+//! Merkle tree config of [`Poseidon`]. Leaves are bytes hashed like
+//! [`PoseidonHash::h1`](crate::hash::poseidon::PoseidonHash::h1) and inner nodes
+//! are compressed like [`PoseidonHash::h2c`](crate::hash::poseidon::PoseidonHash::h2c).
+
+use ark_crypto_primitives::crh::{
+    poseidon::{
+        constraints::{CRHGadget, TwoToOneCRHGadget},
+        TwoToOneCRH, CRH,
+    },
+    CRHScheme, CRHSchemeGadget, TwoToOneCRHScheme, TwoToOneCRHSchemeGadget,
+};
+use ark_crypto_primitives::merkle_tree::{
+    constraints::ConfigGadget, Config, IdentityDigestConverter,
+};
+use ark_ed_on_bls12_381::Fq as Fr;
+use ark_r1cs_std::fields::fp::FpVar;
+
+use super::MerkleTreeOf;
+use crate::hash::poseidon::Poseidon;
+
+type LeafH = CRH<Fr>;
+type LeafHG = CRHGadget<Fr>;
+
+type CompressH = TwoToOneCRH<Fr>;
+type CompressHG = TwoToOneCRHGadget<Fr>;
+
+#[derive(Clone)]
+pub struct PoseidonMerkleTreeParams;
+impl Config for PoseidonMerkleTreeParams {
+    type Leaf = [Fr];
+    type LeafDigest = <LeafH as CRHScheme>::Output;
+    type LeafInnerDigestConverter = IdentityDigestConverter<Fr>;
+
+    type InnerDigest = <CompressH as TwoToOneCRHScheme>::Output;
+    type LeafHash = LeafH;
+    type TwoToOneHash = CompressH;
+}
+
+type ConstraintF = Fr;
+pub struct PoseidonMerkleTreeParamsVar;
+impl ConfigGadget<PoseidonMerkleTreeParams, ConstraintF> for PoseidonMerkleTreeParamsVar {
+    type Leaf = [FpVar<ConstraintF>];
+    type LeafDigest = <LeafHG as CRHSchemeGadget<LeafH, ConstraintF>>::OutputVar;
+    type LeafInnerConverter = IdentityDigestConverter<FpVar<ConstraintF>>;
+    type InnerDigest = <CompressHG as TwoToOneCRHSchemeGadget<CompressH, ConstraintF>>::OutputVar;
+    type LeafHash = LeafHG;
+    type TwoToOneHash = CompressHG;
+}
+
+pub type PoseidonMerkleTree = MerkleTreeOf<Poseidon>;
+
+#[cfg(test)]
+mod tests {
+    use ark_crypto_primitives::crh::poseidon::constraints::CRHParametersVar;
+    use ark_crypto_primitives::merkle_tree::constraints::PathVar;
+    use ark_r1cs_std::prelude::*;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+    use crate::hash::poseidon::{
+        bytes_to_field_element_vars, deserialize_digest, poseidon_config, PoseidonHash,
+    };
+
+    #[test]
+    fn native_and_constrained_membership_match() {
+        let hash = PoseidonHash::new();
+        let leaves: Vec<_> = (0..5u8).map(|i| hash.h1(&[i; 32])).collect();
+        let mt = PoseidonMerkleTree::new(7, &leaves);
+
+        let path_bytes = mt.generate_proof(3).unwrap();
+        let path = PoseidonMerkleTree::deserialize_path(&path_bytes).unwrap();
+        assert_eq!(PoseidonMerkleTree::serialize_path(&path), path_bytes);
+        assert!(PoseidonMerkleTree::deserialize_path(&path_bytes[1..]).is_none());
+        assert!(PoseidonMerkleTree::deserialize_path(&[]).is_none());
+
+        let cs = ConstraintSystem::<ConstraintF>::new_ref();
+        let params = CRHParametersVar::new_constant(cs.clone(), poseidon_config()).unwrap();
+        let root =
+            FpVar::new_input(cs.clone(), || Ok(deserialize_digest(&mt.root()).unwrap())).unwrap();
+        let path_var: PathVar<PoseidonMerkleTreeParams, ConstraintF, PoseidonMerkleTreeParamsVar> =
+            PathVar::new_witness(cs.clone(), || Ok(path)).unwrap();
+
+        for (leaf, is_member) in [(&leaves[3], true), (&leaves[4], false)] {
+            let leaf_var = UInt8::new_witness_vec(cs.clone(), leaf).unwrap();
+            let leaf_var = bytes_to_field_element_vars(&leaf_var).unwrap();
+            let member = path_var
+                .verify_membership(&params, &params, &root, &leaf_var)
+                .unwrap();
+            assert_eq!(member.value().unwrap(), is_member);
+        }
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn leaves_hash_like_h1() {
+        let hash = PoseidonHash::new();
+        let leaves = vec![hash.h1(&[1u8; 32]), hash.h1(&[2u8; 32])];
+        let mt = PoseidonMerkleTree::new(1, &leaves);
+        assert_eq!(
+            mt.root(),
+            hash.h2c(&hash.h1(&leaves[0]), &hash.h1(&leaves[1]))
+        );
+    }
+}
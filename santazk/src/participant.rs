@@ -0,0 +1,506 @@
+//! Building the ENTER, CHOICE and REVEAL transactions of a participant.
+//!
+//! A [`Participant`] holds the keys of a participant, and makes the contents of
+//! their transactions against a [`ProtocolState`], the view of the chain a client
+//! fetched. Clients put the contents into their own transaction format, and pass
+//! in the provers so that the proving keys are only loaded once.
+
+use std::io::{self, Error, ErrorKind};
+
+use rand_core::{CryptoRng, OsRng, RngCore};
+
+use crate::{
+    crypto::{
+        derive_invite_commitment, derive_invite_nullifier, derive_participation_pubkey,
+        sign_choice_tx, sign_reveal_tx,
+    },
+    hash::Hash,
+    merkle::MerkleTree,
+    proofs::{
+        ChoiceAuthProver, EnterAuthProver, InviteEnterAuthProver, ProofSystem, ProvingStage,
+        RevealAuthProver,
+    },
+};
+
+/// Depth of the Merkle tree of the entered public keys, which bounds the size of a group.
+pub const MERKLE_TREE_DEPTH: usize = 7;
+/// Depth of the allowlist Merkle tree, which bounds the number of invites of a group.
+pub const INVITE_TREE_DEPTH: usize = 7;
+/// Size of the secret key, nullifier and Diffie-Hellman key of a participant.
+pub const KEY_SIZE: usize = 64;
+
+/// What a participant knows of the chain. Points are in their 64-byte encoding,
+/// and clients only fill in what the transactions they make need.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProtocolState {
+    /// Id of the chain the transactions are bound to, 32 bytes long.
+    pub chain_id: Vec<u8>,
+    /// Root of the Merkle tree of the entered public keys, as reported by the node.
+    pub merkle_root: Vec<u8>,
+    /// Public keys that entered the group, in the order they entered.
+    pub merkle_leaves: Vec<Vec<u8>>,
+    /// Root of the allowlist Merkle tree, set if the group is invite-only.
+    pub invite_root: Option<Vec<u8>>,
+    /// Invite commitments the allowlist Merkle tree is built from.
+    pub invites: Vec<Vec<u8>>,
+    /// Nullifiers of the invites already redeemed.
+    pub invite_nullifiers: Vec<Vec<u8>>,
+    /// Public keys that revealed, with the ciphertext of their REVEAL.
+    pub reveals: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl ProtocolState {
+    /// Returns the state of an open group on `chain_id` that `merkle_leaves` entered.
+    pub fn new(chain_id: &[u8], merkle_leaves: Vec<Vec<u8>>) -> Self {
+        let merkle_root = MerkleTree::new(MERKLE_TREE_DEPTH, &merkle_leaves).root();
+        Self {
+            chain_id: chain_id.to_vec(),
+            merkle_root,
+            merkle_leaves,
+            ..Default::default()
+        }
+    }
+}
+
+/// Contents of an ENTER transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnterTx {
+    pub pub_key: Vec<u8>,
+    /// Nullifier of the invite redeemed, in invite-only groups.
+    pub invite_nullifier: Option<Vec<u8>>,
+    pub proof: Vec<u8>,
+}
+
+/// Contents of a CHOICE transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChoiceTx {
+    pub choice: Vec<u8>,
+    pub nullifier: Vec<u8>,
+    pub dh_pub_key: Vec<u8>,
+    pub signature: Vec<u8>,
+    pub proof: Vec<u8>,
+}
+
+/// Contents of a REVEAL transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RevealTx {
+    pub pub_key: Vec<u8>,
+    pub ciphertext_hash: Vec<u8>,
+    pub dh_pub_key: Vec<u8>,
+    pub signature: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+    pub proof: Vec<u8>,
+}
+
+/// The keys of a participant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Participant {
+    pub secret_key: Vec<u8>,
+    pub nullifier: Vec<u8>,
+    pub pub_key: Vec<u8>,
+    pub dh_pub_key: Vec<u8>,
+}
+
+impl Participant {
+    /// Returns the participant with these keys, deriving their public key.
+    pub fn new(secret_key: Vec<u8>, nullifier: Vec<u8>, dh_pub_key: Vec<u8>) -> Self {
+        let pub_key = derive_participation_pubkey(&Hash::new(), &secret_key, &nullifier);
+        Self {
+            secret_key,
+            nullifier,
+            pub_key,
+            dh_pub_key,
+        }
+    }
+
+    /// Generates the keys of a new participant from `rng`.
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut random_bytes = || {
+            let mut bytes = vec![0u8; KEY_SIZE];
+            rng.fill_bytes(&mut bytes);
+            bytes
+        };
+        let (secret_key, nullifier, dh_pub_key) = (random_bytes(), random_bytes(), random_bytes());
+        Self::new(secret_key, nullifier, dh_pub_key)
+    }
+
+    /// Generates the keys of a new participant from the OS's secure randomness.
+    pub fn random() -> Self {
+        Self::generate(&mut OsRng)
+    }
+
+    /// Makes the ENTER of an open group.
+    pub fn enter_tx(&self, prover: &EnterAuthProver, state: &ProtocolState) -> io::Result<EnterTx> {
+        self.enter_tx_with_progress(prover, state, |_| {})
+    }
+
+    /// Like [`Self::enter_tx`], calling `progress` as each [`ProvingStage`] starts.
+    pub fn enter_tx_with_progress(
+        &self,
+        prover: &EnterAuthProver,
+        state: &ProtocolState,
+        progress: impl FnMut(ProvingStage),
+    ) -> io::Result<EnterTx> {
+        if state.invite_root.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the group is invite-only, enter with an invite",
+            ));
+        }
+
+        // prove that we know the secret key behind the public key, for this chain only
+        let proof = prover.prove_with_progress(
+            &self.secret_key,
+            &self.nullifier,
+            &self.pub_key,
+            &state.chain_id,
+            progress,
+        );
+        Ok(EnterTx {
+            pub_key: self.pub_key.clone(),
+            invite_nullifier: None,
+            proof,
+        })
+    }
+
+    /// Makes the ENTER of an invite-only group, redeeming the invite token
+    /// (`invite_secret`, `invite_salt`).
+    pub fn invite_enter_tx(
+        &self,
+        prover: &InviteEnterAuthProver,
+        state: &ProtocolState,
+        invite_secret: &[u8],
+        invite_salt: &[u8],
+    ) -> io::Result<EnterTx> {
+        self.invite_enter_tx_with_progress(prover, state, invite_secret, invite_salt, |_| {})
+    }
+
+    /// Like [`Self::invite_enter_tx`], calling `progress` as each [`ProvingStage`] starts.
+    pub fn invite_enter_tx_with_progress(
+        &self,
+        prover: &InviteEnterAuthProver,
+        state: &ProtocolState,
+        invite_secret: &[u8],
+        invite_salt: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> io::Result<EnterTx> {
+        let Some(invite_root) = &state.invite_root else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the group is open, enter without an invite",
+            ));
+        };
+
+        let hasher = Hash::new();
+        let commitment = derive_invite_commitment(&hasher, invite_secret, invite_salt);
+        let invite_nullifier = derive_invite_nullifier(&hasher, invite_secret);
+
+        // the invite's Merkle path is built locally so that the node doesn't learn which invite it is
+        let index = state
+            .invites
+            .iter()
+            .position(|invite| *invite == commitment)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    "the invite is not on the allowlist",
+                )
+            })?;
+        let mt = MerkleTree::new(INVITE_TREE_DEPTH, &state.invites);
+        let root = mt.root();
+        if root != *invite_root {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invites don't match the allowlist root reported by the node",
+            ));
+        }
+        if state.invite_nullifiers.contains(&invite_nullifier) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the invite has already been redeemed",
+            ));
+        }
+
+        // also prove that we hold an invite on the allowlist, without telling which
+        let proof = prover.prove_with_progress(
+            &self.secret_key,
+            &self.nullifier,
+            &self.pub_key,
+            &state.chain_id,
+            invite_secret,
+            invite_salt,
+            &root,
+            &mt.generate_proof(index).unwrap(),
+            &invite_nullifier,
+            progress,
+        )?;
+        Ok(EnterTx {
+            pub_key: self.pub_key.clone(),
+            invite_nullifier: Some(invite_nullifier),
+            proof,
+        })
+    }
+
+    /// Makes the CHOICE of the public key `choice`, proven against the entered
+    /// public keys of `state` without telling which one is ours.
+    pub fn choice_tx<S: ProofSystem>(
+        &self,
+        prover: &ChoiceAuthProver<S>,
+        state: &ProtocolState,
+        choice: &[u8],
+    ) -> io::Result<ChoiceTx> {
+        self.choice_tx_with_progress(prover, state, choice, |_| {})
+    }
+
+    /// Like [`Self::choice_tx`], calling `progress` as each [`ProvingStage`] starts.
+    pub fn choice_tx_with_progress<S: ProofSystem>(
+        &self,
+        prover: &ChoiceAuthProver<S>,
+        state: &ProtocolState,
+        choice: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> io::Result<ChoiceTx> {
+        let leaf_index = state
+            .merkle_leaves
+            .iter()
+            .position(|leaf| *leaf == self.pub_key)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    "the key hasn't entered the group on this chain",
+                )
+            })?;
+        let mt = MerkleTree::new(MERKLE_TREE_DEPTH, &state.merkle_leaves);
+        let root = mt.root();
+        if root != state.merkle_root {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Merkle leaves don't match the Merkle root reported by the node",
+            ));
+        }
+
+        let signature = sign_choice_tx(
+            &Hash::new(),
+            &self.secret_key,
+            &self.nullifier,
+            &state.chain_id,
+            choice,
+            &self.dh_pub_key,
+        );
+        let proof = prover.prove_with_progress(
+            &self.secret_key,
+            &self.nullifier,
+            &root,
+            &mt.generate_proof(leaf_index).unwrap(),
+            choice,
+            &state.chain_id,
+            &self.dh_pub_key,
+            &signature,
+            progress,
+        )?;
+        Ok(ChoiceTx {
+            choice: choice.to_vec(),
+            nullifier: self.nullifier.clone(),
+            dh_pub_key: self.dh_pub_key.clone(),
+            signature,
+            proof,
+        })
+    }
+
+    /// Makes the REVEAL of `payload` to whoever chose us.
+    ///
+    /// REVEALs aren't encrypted yet, so the ciphertext is `payload` itself and
+    /// anyone can read it. Encrypting to the santa's Diffie-Hellman key belongs
+    /// here, and decrypting next to [`Self::find_reveal`].
+    pub fn reveal_tx<S: ProofSystem>(
+        &self,
+        prover: &RevealAuthProver<S>,
+        state: &ProtocolState,
+        payload: &[u8],
+    ) -> io::Result<RevealTx> {
+        self.reveal_tx_with_progress(prover, state, payload, |_| {})
+    }
+
+    /// Like [`Self::reveal_tx`], calling `progress` as each [`ProvingStage`] starts.
+    pub fn reveal_tx_with_progress<S: ProofSystem>(
+        &self,
+        prover: &RevealAuthProver<S>,
+        state: &ProtocolState,
+        payload: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> io::Result<RevealTx> {
+        let hasher = Hash::new();
+        let ciphertext = payload.to_vec();
+        let ciphertext_hash = hasher.h1(&ciphertext);
+        let signature = sign_reveal_tx(
+            &hasher,
+            &self.secret_key,
+            &self.nullifier,
+            &state.chain_id,
+            &ciphertext_hash,
+            &self.dh_pub_key,
+        );
+        let proof = prover.prove_with_progress(
+            &self.secret_key,
+            &self.nullifier,
+            &self.pub_key,
+            &ciphertext_hash,
+            &state.chain_id,
+            &self.dh_pub_key,
+            &signature,
+            progress,
+        );
+        Ok(RevealTx {
+            pub_key: self.pub_key.clone(),
+            ciphertext_hash,
+            dh_pub_key: self.dh_pub_key.clone(),
+            signature,
+            ciphertext,
+            proof,
+        })
+    }
+
+    /// Returns the ciphertext the public key `chosen` revealed in `state`, or none
+    /// if it hasn't revealed yet. It is the plaintext until REVEALs are encrypted,
+    /// see [`Self::reveal_tx`].
+    pub fn find_reveal(state: &ProtocolState, chosen: &[u8]) -> Option<Vec<u8>> {
+        state
+            .reveals
+            .iter()
+            .find(|(pub_key, _)| pub_key == chosen)
+            .map(|(_, ciphertext)| ciphertext.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_crypto_primitives::snark::SNARK;
+    use ark_groth16::Groth16 as ArkGroth16;
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::proofs::Groth16;
+
+    const CHAIN_ID: [u8; 32] = [7u8; 32];
+
+    /// Knows `w` with `w^2 = a`, for provers that must fail before proving.
+    struct SquareCircuit;
+
+    impl ConstraintSynthesizer<Fr> for SquareCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let a = cs.new_input_variable(|| Ok(Fr::from(4u64)))?;
+            let w = cs.new_witness_variable(|| Ok(Fr::from(2u64)))?;
+            cs.enforce_constraint(lc!() + w, lc!() + w, lc!() + a)?;
+            Ok(())
+        }
+    }
+
+    fn unusable_key() -> <Groth16 as ProofSystem>::ProvingKey {
+        let (pk, _) =
+            ArkGroth16::<Bls12_381>::circuit_specific_setup(SquareCircuit, &mut OsRng).unwrap();
+        pk
+    }
+
+    #[test]
+    fn participants_are_generated_from_the_rng() {
+        let a = Participant::generate(&mut StdRng::seed_from_u64(1));
+        assert_eq!(a, Participant::generate(&mut StdRng::seed_from_u64(1)));
+        assert_ne!(a, Participant::generate(&mut StdRng::seed_from_u64(2)));
+        assert_eq!(a.secret_key.len(), KEY_SIZE);
+        assert_eq!(
+            a.pub_key,
+            derive_participation_pubkey(&Hash::new(), &a.secret_key, &a.nullifier)
+        );
+    }
+
+    #[test]
+    fn states_that_dont_allow_the_transaction_are_refused() {
+        let a = Participant::random();
+        let b = Participant::random();
+        let mut state = ProtocolState::new(&CHAIN_ID, vec![b.pub_key.clone()]);
+
+        let prover = ChoiceAuthProver::<Groth16>::from_key(unusable_key());
+        let err = a.choice_tx(&prover, &state, &b.pub_key).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        state.merkle_leaves.push(a.pub_key.clone());
+        let err = a.choice_tx(&prover, &state, &b.pub_key).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        let (invite_secret, invite_salt) = ([1u8; KEY_SIZE], [2u8; KEY_SIZE]);
+        let commitment = derive_invite_commitment(&Hash::new(), &invite_secret, &invite_salt);
+        state.invites = vec![commitment];
+        state.invite_root = Some(MerkleTree::new(INVITE_TREE_DEPTH, &state.invites).root());
+        let err = a
+            .enter_tx(&EnterAuthProver::from_key(unusable_key()), &state)
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        let prover = InviteEnterAuthProver::from_key(unusable_key());
+        let err = a
+            .invite_enter_tx(&prover, &state, &invite_secret, &[3u8; KEY_SIZE])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "the invite is not on the allowlist");
+        state.invite_nullifiers = vec![derive_invite_nullifier(&Hash::new(), &invite_secret)];
+        let err = a
+            .invite_enter_tx(&prover, &state, &invite_secret, &invite_salt)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "the invite has already been redeemed");
+    }
+
+    #[test]
+    fn reveals_are_found_by_public_key() {
+        let a = Participant::random();
+        let b = Participant::random();
+        let mut state = ProtocolState::new(&CHAIN_ID, vec![a.pub_key.clone(), b.pub_key.clone()]);
+        assert_eq!(Participant::find_reveal(&state, &b.pub_key), None);
+
+        state.reveals.push((b.pub_key.clone(), b"hi".to_vec()));
+        assert_eq!(
+            Participant::find_reveal(&state, &b.pub_key),
+            Some(b"hi".to_vec())
+        );
+        assert_eq!(Participant::find_reveal(&state, &a.pub_key), None);
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-keys")]
+    #[ignore = "loads the CHOICE and REVEAL proving keys, run with `cargo test --release -- --ignored`"]
+    fn choices_and_reveals_verify() {
+        use crate::proofs::{ChoiceAuthVerifier, RevealAuthVerifier};
+
+        let a = Participant::random();
+        let b = Participant::random();
+        let state = ProtocolState::new(&CHAIN_ID, vec![a.pub_key.clone(), b.pub_key.clone()]);
+
+        let choice = a
+            .choice_tx(&ChoiceAuthProver::new(), &state, &b.pub_key)
+            .unwrap();
+        assert!(ChoiceAuthVerifier::new().verify(
+            &choice.proof,
+            &choice.nullifier,
+            &state.merkle_root,
+            &choice.choice,
+            &state.chain_id,
+            &choice.dh_pub_key,
+            &choice.signature,
+        ));
+
+        let reveal = b
+            .reveal_tx(&RevealAuthProver::new(), &state, b"hi")
+            .unwrap();
+        assert!(RevealAuthVerifier::new().verify(
+            &reveal.proof,
+            &reveal.pub_key,
+            &reveal.ciphertext_hash,
+            &state.chain_id,
+            &reveal.dh_pub_key,
+            &reveal.signature,
+        ));
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::{self, Error, ErrorKind};
+
+use ark_bls12_381::{Bls12_381, Config as Bls12_381Config, Fr};
+use ark_crypto_primitives::snark::SNARK;
+use ark_ec::bls12::Bls12;
+use ark_ff::UniformRand;
+use ark_groth16::{Groth16 as ArkGroth16, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, OptimizationGoal};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use rand_core::OsRng;
+
+use super::{ProofSystem, ProvingStage};
+
+/// Size of a compressed Groth16 proof. Proofs made before they were tagged with
+/// their proof system have exactly this size.
+pub const GROTH16_PROOF_SIZE: usize = 192;
+
+/// Groth16 over BLS12-381, with circuit-specific keys.
+pub struct Groth16;
+
+impl ProofSystem for Groth16 {
+    const ID: u8 = 1;
+    type ProvingKey = ProvingKey<Bls12<Bls12_381Config>>;
+    type VerifyingKey = PreparedVerifyingKey<Bls12<Bls12_381Config>>;
+
+    fn prove_with_progress<C: ConstraintSynthesizer<Fr>>(
+        pk: &Self::ProvingKey,
+        circuit: C,
+        mut progress: impl FnMut(ProvingStage),
+    ) -> Vec<u8> {
+        // what ArkGroth16::prove does, with the synthesis done here to report it
+        progress(ProvingStage::Witness);
+        let cs = ConstraintSystem::new_ref();
+        cs.set_optimization_goal(OptimizationGoal::Constraints);
+        circuit.generate_constraints(cs.clone()).unwrap();
+        cs.finalize();
+        let matrices = cs.to_matrices().unwrap();
+        let assignment = {
+            let cs = cs.borrow().unwrap();
+            [&cs.instance_assignment[..], &cs.witness_assignment[..]].concat()
+        };
+
+        progress(ProvingStage::Proof);
+        let r = Fr::rand(&mut OsRng);
+        let s = Fr::rand(&mut OsRng);
+        let proof = ArkGroth16::<Bls12_381>::create_proof_with_reduction_and_matrices(
+            pk,
+            r,
+            s,
+            &matrices,
+            cs.num_instance_variables(),
+            cs.num_constraints(),
+            &assignment,
+        )
+        .unwrap();
+        serialize_proof(&proof)
+    }
+
+    fn verify(vk: &Self::VerifyingKey, public_inputs: &[Fr], proof: &[u8]) -> bool {
+        let Some(proof) = deserialize_proof(proof) else {
+            return false;
+        };
+        ArkGroth16::<Bls12_381>::verify_with_processed_vk(vk, public_inputs, &proof)
+            .unwrap_or(false)
+    }
+}
+
+impl Groth16 {
+    /// Reads a compressed proving key without the curve and subgroup checks of its
+    /// points, which would otherwise take most of the time. The key must be as
+    /// trusted as the code, e.g. fetched from where the code is served.
+    pub fn deserialize_proving_key(bytes: &[u8]) -> io::Result<<Self as ProofSystem>::ProvingKey> {
+        ProvingKey::deserialize_compressed_unchecked(bytes)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    }
+
+    /// Samples keys for `circuit`. Whoever knows the randomness can forge proofs.
+    pub fn setup<C: ConstraintSynthesizer<Fr>>(
+        circuit: C,
+    ) -> (
+        <Self as ProofSystem>::ProvingKey,
+        <Self as ProofSystem>::VerifyingKey,
+    ) {
+        let (pk, vk) =
+            ArkGroth16::<Bls12_381>::circuit_specific_setup(circuit, &mut OsRng).unwrap();
+        (pk, ArkGroth16::<Bls12_381>::process_vk(&vk).unwrap())
+    }
+
+    /// Serializes `pk` like the proving keys in `params/`.
+    pub fn serialize_proving_key(pk: &<Self as ProofSystem>::ProvingKey) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        pk.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    /// Serializes `vk` like the verifying keys in `params/`.
+    pub fn serialize_verifying_key(vk: &<Self as ProofSystem>::VerifyingKey) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        vk.vk.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+}
+
+/// Loads one of the proving keys embedded in the binary.
+#[cfg(feature = "embedded-keys")]
+pub(crate) fn load_proving_key(bytes: &[u8]) -> ProvingKey<Bls12<Bls12_381Config>> {
+    Groth16::deserialize_proving_key(bytes).unwrap()
+}
+
+pub(crate) fn load_verifying_key(bytes: &[u8]) -> PreparedVerifyingKey<Bls12<Bls12_381Config>> {
+    let bytes = bytes.to_vec();
+    let vk = VerifyingKey::deserialize_compressed(&mut bytes.as_slice()).unwrap();
+    ArkGroth16::<Bls12_381>::process_vk(&vk).unwrap()
+}
+
+fn serialize_proof(proof: &Proof<Bls12<Bls12_381Config>>) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    proof.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+/// Returns none if `bytes` isn't a valid proof, e.g. when it comes from a malformed transaction.
+pub(crate) fn deserialize_proof(bytes: &[u8]) -> Option<Proof<Bls12<Bls12_381Config>>> {
+    Proof::deserialize_compressed(bytes).ok()
+}
@@ -0,0 +1,156 @@
+//! Marlin over BLS12-381, with a universal SRS. A circuit's keys are derived
+//! from the SRS by indexing, which anyone can rerun, so changing a circuit only
+//! needs a new index as long as it fits in the SRS.
+//!
+//! The SRS the committed verifying keys were indexed from is pinned by
+//! [`MARLIN_SRS_SHA256`]. It was sampled by a single party with
+//! [`Marlin::universal_setup`], which is why this module is behind the `marlin`
+//! feature until an SRS from a ceremony replaces it.
+
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::Path,
+};
+
+use ark_bls12_381::{Bls12_381, Fr};
+use ark_crypto_primitives::sponge::poseidon::PoseidonSponge;
+use ark_marlin::{IndexProverKey, IndexVerifierKey, Marlin as ArkMarlin, UniversalSRS};
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::marlin_pc::MarlinKZG10;
+use ark_relations::r1cs::ConstraintSynthesizer;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use sha2::{Digest, Sha256};
+
+use rand_core::OsRng;
+
+use super::{ProofSystem, ProvingStage};
+
+type Sponge = PoseidonSponge<Fr>;
+type MarlinKzg = MarlinKZG10<Bls12_381, DensePolynomial<Fr>, Sponge>;
+type MarlinInst = ArkMarlin<Fr, MarlinKzg, Sponge, Sha256>;
+
+pub type UniversalSrs = UniversalSRS<Fr, MarlinKzg, Sponge>;
+
+/// Number of constraints (and variables) the universal SRS supports.
+/// CHOICE at a Merkle depth of 7 has about 103k constraints.
+pub const SRS_MAX_CONSTRAINTS: usize = 1 << 17;
+/// Number of non-zero entries per constraint matrix the universal SRS supports.
+/// CHOICE at a Merkle depth of 7 has about 148k.
+pub const SRS_MAX_NON_ZERO: usize = 1 << 18;
+
+/// Where the universal SRS is expected. Like the Groth16 proving keys, it's too
+/// big to commit and is distributed separately.
+pub const MARLIN_SRS_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/params/universal.marlin.srs");
+
+/// SHA-256 digest of the file at [`MARLIN_SRS_PATH`] that the committed verifying
+/// keys were indexed from.
+pub const MARLIN_SRS_SHA256: &str =
+    "b29ed0c8e9be65ae4034100f9462128261b2ec1efd6db92a34f04647a295b75e";
+
+pub(crate) const CHOICE_AUTH_VERIFIER_PARAMS: &[u8; 1053] =
+    include_bytes!("../../params/choice_auth.marlin.vk");
+pub(crate) const REVEAL_AUTH_VERIFIER_PARAMS: &[u8; 1053] =
+    include_bytes!("../../params/reveal_auth.marlin.vk");
+
+/// Marlin over BLS12-381, with keys indexed from a universal SRS.
+pub struct Marlin;
+
+impl ProofSystem for Marlin {
+    const ID: u8 = 2;
+    type ProvingKey = IndexProverKey<Fr, MarlinKzg, Sponge>;
+    type VerifyingKey = IndexVerifierKey<Fr, MarlinKzg, Sponge>;
+
+    fn prove_with_progress<C: ConstraintSynthesizer<Fr>>(
+        pk: &Self::ProvingKey,
+        circuit: C,
+        mut progress: impl FnMut(ProvingStage),
+    ) -> Vec<u8> {
+        // the Marlin prover computes the witness itself
+        progress(ProvingStage::Proof);
+        let proof = MarlinInst::prove(pk, circuit, &mut OsRng).unwrap();
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn verify(vk: &Self::VerifyingKey, public_inputs: &[Fr], proof: &[u8]) -> bool {
+        let Ok(proof) = CanonicalDeserialize::deserialize_compressed(proof) else {
+            return false;
+        };
+        MarlinInst::verify(vk, public_inputs, &proof, &mut OsRng).unwrap_or(false)
+    }
+}
+
+impl Marlin {
+    /// Samples a fresh SRS for circuits of up to `max_constraints` constraints (and
+    /// variables) and `max_non_zero` non-zero matrix entries. Whoever runs this
+    /// learns the trapdoor, so a deployment should use the output of a ceremony
+    /// instead.
+    pub fn universal_setup(max_constraints: usize, max_non_zero: usize) -> UniversalSrs {
+        MarlinInst::universal_setup(max_constraints, max_constraints, max_non_zero, &mut OsRng)
+            .unwrap()
+    }
+
+    /// Derives the proving and verifying keys of `circuit` from `srs`. Only the
+    /// shape of `circuit` matters, not its assignment.
+    pub fn index<C: ConstraintSynthesizer<Fr>>(
+        srs: &UniversalSrs,
+        circuit: C,
+    ) -> io::Result<(
+        <Self as ProofSystem>::ProvingKey,
+        <Self as ProofSystem>::VerifyingKey,
+    )> {
+        MarlinInst::index(srs, circuit).map_err(|err| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to index circuit: {err:?}"),
+            )
+        })
+    }
+
+    /// Reads the SRS written by [`Marlin::save_srs`] whose digest is
+    /// [`MARLIN_SRS_SHA256`]. Since its digest is checked, it's stored
+    /// uncompressed and not validated, which makes loading it take seconds
+    /// instead of minutes.
+    pub fn load_srs(path: impl AsRef<Path>) -> io::Result<UniversalSrs> {
+        let bytes = fs::read(path)?;
+        let digest = srs_digest(&bytes);
+        if digest != MARLIN_SRS_SHA256 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("the SRS has digest {digest}, not MARLIN_SRS_SHA256 {MARLIN_SRS_SHA256}"),
+            ));
+        }
+        UniversalSrs::deserialize_with_mode(&bytes[..], Compress::No, Validate::No)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err))
+    }
+
+    /// Writes `srs` to `path` and returns its hex SHA-256 digest, to pin as
+    /// [`MARLIN_SRS_SHA256`].
+    pub fn save_srs(srs: &UniversalSrs, path: impl AsRef<Path>) -> io::Result<String> {
+        let mut bytes = Vec::new();
+        srs.serialize_uncompressed(&mut bytes)
+            .map_err(Error::other)?;
+        fs::write(path, &bytes)?;
+        Ok(srs_digest(&bytes))
+    }
+
+    pub fn serialize_verifying_key(vk: &<Self as ProofSystem>::VerifyingKey) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        vk.serialize_compressed(&mut bytes).unwrap();
+        bytes
+    }
+}
+
+fn srs_digest(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+pub(crate) fn load_verifying_key(bytes: &[u8]) -> <Marlin as ProofSystem>::VerifyingKey {
+    IndexVerifierKey::deserialize_compressed(bytes).unwrap()
+}
@@ -1,37 +1,518 @@
-use ark_bls12_381::{Bls12_381, Config as Bls12_381Config, Fr};
-use ark_crypto_primitives::snark::SNARK;
+//! Provers and verifiers for the santazk circuits.
+//!
+//! CHOICE and REVEAL proofs can be made with any [`ProofSystem`]: Groth16 with
+//! the circuit-specific keys in `params/`, or, with the `marlin` feature, Marlin
+//! with keys indexed from a universal SRS. Their proofs start with the [`ProofSystem::ID`] of the system
+//! that made them, so a verifier can tell which one to check them with.
+//! ENTER proofs are Groth16 only and aren't tagged.
+//!
+//! Provers and verifiers are also generic over the [`HashConfig`] of their
+//! circuit. The chain uses [`Pedersen`]; with the `poseidon` feature, Groth16 keys
+//! of the [`Poseidon`] circuits are in `params/` too, see their `embedded`.
+
+use std::{io, marker::PhantomData};
+
+use ark_bls12_381::{Config as Bls12_381Config, Fr};
+use ark_crypto_primitives::merkle_tree::Path;
 use ark_ec::bls12::Bls12;
 use ark_ff::ToConstraintField;
-use ark_groth16::{Groth16, PreparedVerifyingKey, Proof, ProvingKey, VerifyingKey};
-use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-
-use rand_core::OsRng;
+use ark_groth16::{PreparedVerifyingKey, ProvingKey};
+use ark_relations::r1cs::ConstraintSynthesizer;
 
+#[cfg(feature = "poseidon")]
+use crate::hash::poseidon::Poseidon;
 use crate::{
-    circuits::{ChoiceAuthCircuit, RevealAuthCircuit},
-    merkle::MerkleTree,
-    serialization::deserialize_jub_jub_affine_point,
+    circuits::{ChoiceAuthCircuit, EnterAuthCircuit, InviteEnterAuthCircuit, RevealAuthCircuit},
+    crypto::{
+        derive_invite_commitment, derive_invite_nullifier, derive_participation_pubkey,
+        sign_choice_tx, sign_reveal_tx,
+    },
+    hash::{HashConfig, NativeHash, Pedersen},
+    merkle::MerkleTreeOf,
+    participant::{INVITE_TREE_DEPTH, MERKLE_TREE_DEPTH},
 };
 
-const CHOICE_AUTH_PROVER_PARAMS: &[u8; 29015568] =
+pub mod groth16;
+#[cfg(feature = "marlin")]
+pub mod marlin;
+pub mod snarkjs;
+pub mod solidity;
+
+pub use groth16::Groth16;
+#[cfg(feature = "marlin")]
+pub use marlin::Marlin;
+
+#[cfg(feature = "embedded-keys")]
+use groth16::load_proving_key;
+use groth16::load_verifying_key;
+
+#[cfg(feature = "embedded-keys")]
+const ENTER_AUTH_PROVER_PARAMS: &[u8; 6415056] =
+    include_bytes!("../../params/enter_auth.groth16.pk");
+const ENTER_AUTH_VERIFIER_PARAMS: &[u8; 632] = include_bytes!("../../params/enter_auth.groth16.vk");
+#[cfg(feature = "embedded-keys")]
+const INVITE_ENTER_AUTH_PROVER_PARAMS: &[u8; 25922208] =
+    include_bytes!("../../params/invite_enter_auth.groth16.pk");
+const INVITE_ENTER_AUTH_VERIFIER_PARAMS: &[u8; 872] =
+    include_bytes!("../../params/invite_enter_auth.groth16.vk");
+#[cfg(feature = "embedded-keys")]
+const CHOICE_AUTH_PROVER_PARAMS: &[u8; 29122848] =
     include_bytes!("../../params/choice_auth.groth16.pk");
-const CHOICE_AUTH_VERIFIER_PARAMS: &[u8; 1064] =
+const CHOICE_AUTH_VERIFIER_PARAMS: &[u8; 1160] =
     include_bytes!("../../params/choice_auth.groth16.vk");
-const REVEAL_AUTH_PROVER_PARAMS: &[u8; 15463440] =
+#[cfg(feature = "embedded-keys")]
+const REVEAL_AUTH_PROVER_PARAMS: &[u8; 15325680] =
     include_bytes!("../../params/reveal_auth.groth16.pk");
-const REVEAL_AUTH_VERIFIER_PARAMS: &[u8; 968] =
+const REVEAL_AUTH_VERIFIER_PARAMS: &[u8; 1064] =
     include_bytes!("../../params/reveal_auth.groth16.vk");
 
-pub struct ChoiceAuthProver {
+// keys of the Poseidon circuits, see `santazkparams poseidon-setup`
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+const POSEIDON_ENTER_AUTH_PROVER_PARAMS: &[u8; 1827168] =
+    include_bytes!("../../params/enter_auth.poseidon.groth16.pk");
+#[cfg(feature = "poseidon")]
+const POSEIDON_ENTER_AUTH_VERIFIER_PARAMS: &[u8; 584] =
+    include_bytes!("../../params/enter_auth.poseidon.groth16.vk");
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+const POSEIDON_INVITE_ENTER_AUTH_PROVER_PARAMS: &[u8; 3876624] =
+    include_bytes!("../../params/invite_enter_auth.poseidon.groth16.pk");
+#[cfg(feature = "poseidon")]
+const POSEIDON_INVITE_ENTER_AUTH_VERIFIER_PARAMS: &[u8; 728] =
+    include_bytes!("../../params/invite_enter_auth.poseidon.groth16.vk");
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+const POSEIDON_CHOICE_AUTH_PROVER_PARAMS: &[u8; 5346096] =
+    include_bytes!("../../params/choice_auth.poseidon.groth16.pk");
+#[cfg(feature = "poseidon")]
+const POSEIDON_CHOICE_AUTH_VERIFIER_PARAMS: &[u8; 1016] =
+    include_bytes!("../../params/choice_auth.poseidon.groth16.vk");
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+const POSEIDON_REVEAL_AUTH_PROVER_PARAMS: &[u8; 3969504] =
+    include_bytes!("../../params/reveal_auth.poseidon.groth16.pk");
+#[cfg(feature = "poseidon")]
+const POSEIDON_REVEAL_AUTH_VERIFIER_PARAMS: &[u8; 920] =
+    include_bytes!("../../params/reveal_auth.poseidon.groth16.vk");
+
+/// Stage of proving, reported to the progress callback of `prove_with_progress`
+/// when it starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProvingStage {
+    /// Computing the assignment of every variable of the circuit from the inputs.
+    Witness,
+    /// Computing the proof from the assignment, which takes most of the time.
+    Proof,
+}
+
+/// A SNARK over the BLS12-381 scalar field, which is the field the circuits are
+/// defined over.
+pub trait ProofSystem {
+    /// The byte that proofs of this system start with.
+    const ID: u8;
+    type ProvingKey;
+    type VerifyingKey;
+
+    /// Proves that `circuit` is satisfied. The proof isn't tagged with [`Self::ID`].
+    fn prove<C: ConstraintSynthesizer<Fr>>(pk: &Self::ProvingKey, circuit: C) -> Vec<u8> {
+        Self::prove_with_progress(pk, circuit, |_| {})
+    }
+
+    /// Like [`ProofSystem::prove`], calling `progress` as each [`ProvingStage`]
+    /// starts. Systems that don't separate the stages only report the last one.
+    fn prove_with_progress<C: ConstraintSynthesizer<Fr>>(
+        pk: &Self::ProvingKey,
+        circuit: C,
+        progress: impl FnMut(ProvingStage),
+    ) -> Vec<u8>;
+
+    /// Returns false if `proof` isn't a valid (untagged) proof, e.g. when it comes
+    /// from a malformed transaction.
+    fn verify(vk: &Self::VerifyingKey, public_inputs: &[Fr], proof: &[u8]) -> bool;
+}
+
+/// Returns the [`ProofSystem::ID`] of the system that made `proof`, or none for an
+/// empty proof.
+pub fn proof_system_id(proof: &[u8]) -> Option<u8> {
+    proof.first().copied()
+}
+
+fn tag_proof<S: ProofSystem>(proof: Vec<u8>) -> Vec<u8> {
+    let mut tagged = Vec::with_capacity(proof.len() + 1);
+    tagged.push(S::ID);
+    tagged.extend(proof);
+    tagged
+}
+
+/// Strips the tag off `proof`, or returns none if it wasn't made by `S`.
+fn untag_proof<S: ProofSystem>(proof: &[u8]) -> Option<&[u8]> {
+    match proof.split_first() {
+        Some((&id, proof)) if id == S::ID => Some(proof),
+        _ => None,
+    }
+}
+
+/// Decodes the Merkle root and path a prover was given.
+fn deserialize_root_and_path<H: HashConfig>(
+    root: &[u8],
+    path: &[u8],
+) -> io::Result<(H::Digest, Path<H::MerkleConfig>)> {
+    let invalid =
+        |name: &str| io::Error::new(io::ErrorKind::InvalidInput, format!("invalid {name}"));
+    let root = H::deserialize_digest(root).ok_or_else(|| invalid("Merkle root"))?;
+    let path = MerkleTreeOf::<H>::deserialize_path(path).ok_or_else(|| invalid("Merkle path"))?;
+    Ok((root, path))
+}
+
+pub struct EnterAuthProver<H: HashConfig = Pedersen> {
+    pk: ProvingKey<Bls12<Bls12_381Config>>,
+    hash: PhantomData<H>,
+}
+
+impl EnterAuthProver {
+    #[cfg(feature = "embedded-keys")]
+    pub fn new() -> Self {
+        Self::from_key(load_proving_key(ENTER_AUTH_PROVER_PARAMS))
+    }
+}
+
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+impl EnterAuthProver<Poseidon> {
+    /// Uses the embedded proving key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_proving_key(POSEIDON_ENTER_AUTH_PROVER_PARAMS))
+    }
+}
+
+impl<H: HashConfig> EnterAuthProver<H> {
+    pub fn from_key(pk: ProvingKey<Bls12<Bls12_381Config>>) -> Self {
+        Self {
+            pk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &ProvingKey<Bls12<Bls12_381Config>> {
+        &self.pk
+    }
+
+    /// Samples new keys. Whoever runs it can forge proofs with the randomness it
+    /// used, so keys must come from a setup nobody can cheat.
+    pub fn setup() -> (Self, EnterAuthVerifier<H>) {
+        let (pk, vk) = Groth16::setup(enter_auth_circuit_shape::<H>());
+        (Self::from_key(pk), EnterAuthVerifier::from_key(vk))
+    }
+
+    pub fn prove(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        pub_key: &[u8],
+        chain_id: &[u8],
+    ) -> Vec<u8> {
+        self.prove_with_progress(secret_key, nullifier, pub_key, chain_id, |_| {})
+    }
+
+    /// Like [`Self::prove`], calling `progress` as each [`ProvingStage`] starts.
+    pub fn prove_with_progress(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        pub_key: &[u8],
+        chain_id: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> Vec<u8> {
+        let ckt = EnterAuthCircuit::<H> {
+            secret_key: secret_key.to_vec(),
+            nullifier: nullifier.to_vec(),
+            pub_key: pub_key.to_vec(),
+            chain_id: chain_id.to_vec(),
+            hash: PhantomData,
+        };
+
+        Groth16::prove_with_progress(&self.pk, ckt, progress)
+    }
+}
+
+#[cfg(feature = "embedded-keys")]
+impl Default for EnterAuthProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct EnterAuthVerifier<H: HashConfig = Pedersen> {
+    pvk: PreparedVerifyingKey<Bls12<Bls12_381Config>>,
+    hash: PhantomData<H>,
+}
+
+impl EnterAuthVerifier {
+    pub fn new() -> Self {
+        Self::from_key(load_verifying_key(ENTER_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl EnterAuthVerifier<Poseidon> {
+    /// Uses the committed verifying key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_verifying_key(POSEIDON_ENTER_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+impl<H: HashConfig> EnterAuthVerifier<H> {
+    pub fn from_key(pvk: PreparedVerifyingKey<Bls12<Bls12_381Config>>) -> Self {
+        Self {
+            pvk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &PreparedVerifyingKey<Bls12<Bls12_381Config>> {
+        &self.pvk
+    }
+
+    pub fn verify(&self, proof: &[u8], pub_key: &[u8], chain_id: &[u8]) -> bool {
+        let pub_key_pub = ToConstraintField::<Fr>::to_field_elements(pub_key).unwrap();
+        let chain_id_pub = ToConstraintField::<Fr>::to_field_elements(chain_id).unwrap();
+
+        let mut pub_inp = Vec::new();
+        pub_inp.extend(pub_key_pub);
+        pub_inp.extend(chain_id_pub);
+
+        Groth16::verify(&self.pvk, &pub_inp, proof)
+    }
+}
+
+impl Default for EnterAuthVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct InviteEnterAuthProver<H: HashConfig = Pedersen> {
     pk: ProvingKey<Bls12<Bls12_381Config>>,
+    hash: PhantomData<H>,
+}
+
+impl InviteEnterAuthProver {
+    #[cfg(feature = "embedded-keys")]
+    pub fn new() -> Self {
+        Self::from_key(load_proving_key(INVITE_ENTER_AUTH_PROVER_PARAMS))
+    }
+}
+
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+impl InviteEnterAuthProver<Poseidon> {
+    /// Uses the embedded proving key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_proving_key(POSEIDON_INVITE_ENTER_AUTH_PROVER_PARAMS))
+    }
+}
+
+impl<H: HashConfig> InviteEnterAuthProver<H> {
+    pub fn from_key(pk: ProvingKey<Bls12<Bls12_381Config>>) -> Self {
+        Self {
+            pk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &ProvingKey<Bls12<Bls12_381Config>> {
+        &self.pk
+    }
+
+    /// Samples new keys for allowlists of depth [`INVITE_TREE_DEPTH`], see [`EnterAuthProver::setup`].
+    pub fn setup() -> (Self, InviteEnterAuthVerifier<H>) {
+        let (pk, vk) = Groth16::setup(invite_enter_auth_circuit_shape::<H>(INVITE_TREE_DEPTH));
+        (Self::from_key(pk), InviteEnterAuthVerifier::from_key(vk))
+    }
+
+    /// Fails if `invite_root` or `invite_path` isn't the encoding of a Merkle root or path.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        pub_key: &[u8],
+        chain_id: &[u8],
+        invite_secret: &[u8],
+        invite_salt: &[u8],
+        invite_root: &[u8],
+        invite_path: &[u8],
+        invite_nullifier: &[u8],
+    ) -> io::Result<Vec<u8>> {
+        self.prove_with_progress(
+            secret_key,
+            nullifier,
+            pub_key,
+            chain_id,
+            invite_secret,
+            invite_salt,
+            invite_root,
+            invite_path,
+            invite_nullifier,
+            |_| {},
+        )
+    }
+
+    /// Like [`Self::prove`], calling `progress` as each [`ProvingStage`] starts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_with_progress(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        pub_key: &[u8],
+        chain_id: &[u8],
+        invite_secret: &[u8],
+        invite_salt: &[u8],
+        invite_root: &[u8],
+        invite_path: &[u8],
+        invite_nullifier: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> io::Result<Vec<u8>> {
+        let (invite_root, invite_path) = deserialize_root_and_path::<H>(invite_root, invite_path)?;
+        let ckt = InviteEnterAuthCircuit::<H> {
+            secret_key: secret_key.to_vec(),
+            nullifier: nullifier.to_vec(),
+            pub_key: pub_key.to_vec(),
+            chain_id: chain_id.to_vec(),
+            invite_secret: invite_secret.to_vec(),
+            invite_salt: invite_salt.to_vec(),
+            invite_root,
+            invite_path,
+            invite_nullifier: invite_nullifier.to_vec(),
+        };
+
+        Ok(Groth16::prove_with_progress(&self.pk, ckt, progress))
+    }
+}
+
+#[cfg(feature = "embedded-keys")]
+impl Default for InviteEnterAuthProver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct InviteEnterAuthVerifier<H: HashConfig = Pedersen> {
+    pvk: PreparedVerifyingKey<Bls12<Bls12_381Config>>,
+    hash: PhantomData<H>,
+}
+
+impl InviteEnterAuthVerifier {
+    pub fn new() -> Self {
+        Self::from_key(load_verifying_key(INVITE_ENTER_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl InviteEnterAuthVerifier<Poseidon> {
+    /// Uses the committed verifying key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_verifying_key(
+            POSEIDON_INVITE_ENTER_AUTH_VERIFIER_PARAMS,
+        ))
+    }
+}
+
+impl<H: HashConfig> InviteEnterAuthVerifier<H> {
+    pub fn from_key(pvk: PreparedVerifyingKey<Bls12<Bls12_381Config>>) -> Self {
+        Self {
+            pvk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &PreparedVerifyingKey<Bls12<Bls12_381Config>> {
+        &self.pvk
+    }
+
+    pub fn verify(
+        &self,
+        proof: &[u8],
+        pub_key: &[u8],
+        chain_id: &[u8],
+        invite_root: &[u8],
+        invite_nullifier: &[u8],
+    ) -> bool {
+        let Some(invite_root) = H::deserialize_digest(invite_root) else {
+            return false;
+        };
+
+        let pub_key_pub = ToConstraintField::<Fr>::to_field_elements(pub_key).unwrap();
+        let chain_id_pub = ToConstraintField::<Fr>::to_field_elements(chain_id).unwrap();
+        let invite_root_pub = H::digest_to_field_elements(&invite_root);
+        let invite_nullifier_pub =
+            ToConstraintField::<Fr>::to_field_elements(invite_nullifier).unwrap();
+
+        let mut pub_inp = Vec::new();
+        pub_inp.extend(pub_key_pub);
+        pub_inp.extend(chain_id_pub);
+        pub_inp.extend(invite_root_pub);
+        pub_inp.extend(invite_nullifier_pub);
+
+        Groth16::verify(&self.pvk, &pub_inp, proof)
+    }
+}
+
+impl Default for InviteEnterAuthVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct ChoiceAuthProver<S: ProofSystem = Groth16, H: HashConfig = Pedersen> {
+    pk: S::ProvingKey,
+    hash: PhantomData<H>,
 }
 
 impl ChoiceAuthProver {
+    #[cfg(feature = "embedded-keys")]
     pub fn new() -> Self {
-        let pk = load_proving_key(CHOICE_AUTH_PROVER_PARAMS);
-        Self { pk }
+        Self::from_key(load_proving_key(CHOICE_AUTH_PROVER_PARAMS))
     }
+}
 
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+impl ChoiceAuthProver<Groth16, Poseidon> {
+    /// Uses the embedded proving key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_proving_key(POSEIDON_CHOICE_AUTH_PROVER_PARAMS))
+    }
+}
+
+impl<H: HashConfig> ChoiceAuthProver<Groth16, H> {
+    /// Samples new keys for Merkle trees of depth [`MERKLE_TREE_DEPTH`], see [`EnterAuthProver::setup`].
+    pub fn setup() -> (Self, ChoiceAuthVerifier<Groth16, H>) {
+        let (pk, vk) = Groth16::setup(choice_auth_circuit_shape::<H>(MERKLE_TREE_DEPTH));
+        (Self::from_key(pk), ChoiceAuthVerifier::from_key(vk))
+    }
+}
+
+#[cfg(feature = "marlin")]
+impl<H: HashConfig> ChoiceAuthProver<Marlin, H> {
+    /// Indexes the circuit for Merkle trees of depth `merkle_tree_depth` from the
+    /// universal `srs`, which takes about a minute.
+    pub fn index(
+        srs: &marlin::UniversalSrs,
+        merkle_tree_depth: usize,
+    ) -> io::Result<(Self, ChoiceAuthVerifier<Marlin, H>)> {
+        let (pk, vk) = Marlin::index(srs, choice_auth_circuit_shape::<H>(merkle_tree_depth))?;
+        Ok((Self::from_key(pk), ChoiceAuthVerifier::from_key(vk)))
+    }
+}
+
+impl<S: ProofSystem, H: HashConfig> ChoiceAuthProver<S, H> {
+    pub fn from_key(pk: S::ProvingKey) -> Self {
+        Self {
+            pk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &S::ProvingKey {
+        &self.pk
+    }
+
+    /// Fails if `root` or `merkle_path` isn't the encoding of a Merkle root or path.
     #[allow(clippy::too_many_arguments)]
     pub fn prove(
         &self,
@@ -40,74 +521,197 @@ impl ChoiceAuthProver {
         root: &[u8],
         merkle_path: &[u8],
         choice: &[u8],
+        chain_id: &[u8],
         dh_pub_key: &[u8],
         signature: &[u8],
-    ) -> Vec<u8> {
-        let root = deserialize_jub_jub_affine_point(root);
-        let ckt = ChoiceAuthCircuit {
+    ) -> io::Result<Vec<u8>> {
+        self.prove_with_progress(
+            secret_key,
+            nullifier,
+            root,
+            merkle_path,
+            choice,
+            chain_id,
+            dh_pub_key,
+            signature,
+            |_| {},
+        )
+    }
+
+    /// Like [`Self::prove`], calling `progress` as each [`ProvingStage`] starts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_with_progress(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        root: &[u8],
+        merkle_path: &[u8],
+        choice: &[u8],
+        chain_id: &[u8],
+        dh_pub_key: &[u8],
+        signature: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> io::Result<Vec<u8>> {
+        let (root, merkle_path) = deserialize_root_and_path::<H>(root, merkle_path)?;
+        let ckt = ChoiceAuthCircuit::<H> {
             secret_key: secret_key.to_vec(),
             nullifier: nullifier.to_vec(),
             root,
-            merkle_path: MerkleTree::deserialize_path(merkle_path),
+            merkle_path,
             choice: choice.to_vec(),
+            chain_id: chain_id.to_vec(),
             dh_pub_key: dh_pub_key.to_vec(),
             signature: signature.to_vec(),
         };
 
-        let mut rng = &mut OsRng;
-        let proof = Groth16::<Bls12_381>::prove(&self.pk, ckt, &mut rng).unwrap();
-        serialize_proof(&proof)
+        Ok(tag_proof::<S>(S::prove_with_progress(
+            &self.pk, ckt, progress,
+        )))
     }
 }
 
-pub struct ChoiceAuthVerifier {
-    pvk: PreparedVerifyingKey<Bls12<Bls12_381Config>>,
+pub struct ChoiceAuthVerifier<S: ProofSystem = Groth16, H: HashConfig = Pedersen> {
+    vk: S::VerifyingKey,
+    hash: PhantomData<H>,
 }
 
 impl ChoiceAuthVerifier {
     pub fn new() -> Self {
-        let vk = load_verifying_key(CHOICE_AUTH_VERIFIER_PARAMS);
-        let pvk = Groth16::<Bls12_381>::process_vk(&vk).unwrap();
-        Self { pvk }
+        Self::from_key(load_verifying_key(CHOICE_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl ChoiceAuthVerifier<Groth16, Poseidon> {
+    /// Uses the committed verifying key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_verifying_key(POSEIDON_CHOICE_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+#[cfg(feature = "marlin")]
+impl ChoiceAuthVerifier<Marlin> {
+    /// Uses the committed verifying key of the Pedersen circuit, indexed from the
+    /// SRS at [`marlin::MARLIN_SRS_PATH`] for Merkle trees of depth 7.
+    pub fn embedded() -> Self {
+        Self::from_key(marlin::load_verifying_key(
+            marlin::CHOICE_AUTH_VERIFIER_PARAMS,
+        ))
+    }
+}
+
+impl<S: ProofSystem, H: HashConfig> ChoiceAuthVerifier<S, H> {
+    pub fn from_key(vk: S::VerifyingKey) -> Self {
+        Self {
+            vk,
+            hash: PhantomData,
+        }
     }
 
+    pub fn key(&self) -> &S::VerifyingKey {
+        &self.vk
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn verify(
         &self,
         proof: &[u8],
         nullifier: &[u8],
         root: &[u8],
         choice: &[u8],
+        chain_id: &[u8],
         dh_pub_key: &[u8],
         signature: &[u8],
     ) -> bool {
-        let proof = deserialize_proof(proof);
-        let root = deserialize_jub_jub_affine_point(root);
+        let Some(proof) = untag_proof::<S>(proof) else {
+            return false;
+        };
+        let Some(pub_inp) =
+            choice_public_inputs::<H>(nullifier, root, choice, chain_id, dh_pub_key, signature)
+        else {
+            return false;
+        };
 
-        let nullifier_pub = ToConstraintField::<Fr>::to_field_elements(nullifier).unwrap();
-        let root_pub = vec![root.x, root.y];
-        let choice_pub = ToConstraintField::<Fr>::to_field_elements(choice).unwrap();
-        let dh_pub_key_pub = ToConstraintField::<Fr>::to_field_elements(dh_pub_key).unwrap();
-        let signature_pub = ToConstraintField::<Fr>::to_field_elements(signature).unwrap();
+        S::verify(&self.vk, &pub_inp, proof)
+    }
+}
 
-        let mut pub_inp = Vec::new();
-        pub_inp.extend(nullifier_pub);
-        pub_inp.extend(root_pub);
-        pub_inp.extend(choice_pub);
-        pub_inp.extend(dh_pub_key_pub);
-        pub_inp.extend(signature_pub);
+/// The public inputs of the CHOICE circuit, in the order its proofs are checked
+/// against, or none if `root` isn't a digest.
+pub fn choice_public_inputs<H: HashConfig>(
+    nullifier: &[u8],
+    root: &[u8],
+    choice: &[u8],
+    chain_id: &[u8],
+    dh_pub_key: &[u8],
+    signature: &[u8],
+) -> Option<Vec<Fr>> {
+    let root = H::deserialize_digest(root)?;
 
-        Groth16::<Bls12_381>::verify_with_processed_vk(&self.pvk, &pub_inp, &proof).is_ok()
-    }
+    let nullifier_pub = ToConstraintField::<Fr>::to_field_elements(nullifier).unwrap();
+    let root_pub = H::digest_to_field_elements(&root);
+    let choice_pub = ToConstraintField::<Fr>::to_field_elements(choice).unwrap();
+    let chain_id_pub = ToConstraintField::<Fr>::to_field_elements(chain_id).unwrap();
+    let dh_pub_key_pub = ToConstraintField::<Fr>::to_field_elements(dh_pub_key).unwrap();
+    let signature_pub = ToConstraintField::<Fr>::to_field_elements(signature).unwrap();
+
+    let mut pub_inp = Vec::new();
+    pub_inp.extend(nullifier_pub);
+    pub_inp.extend(root_pub);
+    pub_inp.extend(choice_pub);
+    pub_inp.extend(chain_id_pub);
+    pub_inp.extend(dh_pub_key_pub);
+    pub_inp.extend(signature_pub);
+    Some(pub_inp)
 }
 
-pub struct RevealAuthProver {
-    pk: ProvingKey<Bls12<Bls12_381Config>>,
+pub struct RevealAuthProver<S: ProofSystem = Groth16, H: HashConfig = Pedersen> {
+    pk: S::ProvingKey,
+    hash: PhantomData<H>,
 }
 
 impl RevealAuthProver {
+    #[cfg(feature = "embedded-keys")]
     pub fn new() -> Self {
-        let pk = load_proving_key(REVEAL_AUTH_PROVER_PARAMS);
-        Self { pk }
+        Self::from_key(load_proving_key(REVEAL_AUTH_PROVER_PARAMS))
+    }
+}
+
+#[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+impl RevealAuthProver<Groth16, Poseidon> {
+    /// Uses the embedded proving key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_proving_key(POSEIDON_REVEAL_AUTH_PROVER_PARAMS))
+    }
+}
+
+impl<H: HashConfig> RevealAuthProver<Groth16, H> {
+    /// Samples new keys, see [`EnterAuthProver::setup`].
+    pub fn setup() -> (Self, RevealAuthVerifier<Groth16, H>) {
+        let (pk, vk) = Groth16::setup(reveal_auth_circuit_shape::<H>());
+        (Self::from_key(pk), RevealAuthVerifier::from_key(vk))
+    }
+}
+
+#[cfg(feature = "marlin")]
+impl<H: HashConfig> RevealAuthProver<Marlin, H> {
+    /// Indexes the circuit from the universal `srs`, which takes about a minute.
+    pub fn index(srs: &marlin::UniversalSrs) -> io::Result<(Self, RevealAuthVerifier<Marlin, H>)> {
+        let (pk, vk) = Marlin::index(srs, reveal_auth_circuit_shape::<H>())?;
+        Ok((Self::from_key(pk), RevealAuthVerifier::from_key(vk)))
+    }
+}
+
+impl<S: ProofSystem, H: HashConfig> RevealAuthProver<S, H> {
+    pub fn from_key(pk: S::ProvingKey) -> Self {
+        Self {
+            pk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &S::ProvingKey {
+        &self.pk
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -117,33 +721,90 @@ impl RevealAuthProver {
         nullifier: &[u8],
         pub_key: &[u8],
         ciphertext_hash: &[u8],
+        chain_id: &[u8],
         dh_pub_key: &[u8],
         signature: &[u8],
     ) -> Vec<u8> {
-        let ckt = RevealAuthCircuit {
+        self.prove_with_progress(
+            secret_key,
+            nullifier,
+            pub_key,
+            ciphertext_hash,
+            chain_id,
+            dh_pub_key,
+            signature,
+            |_| {},
+        )
+    }
+
+    /// Like [`Self::prove`], calling `progress` as each [`ProvingStage`] starts.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_with_progress(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        pub_key: &[u8],
+        ciphertext_hash: &[u8],
+        chain_id: &[u8],
+        dh_pub_key: &[u8],
+        signature: &[u8],
+        progress: impl FnMut(ProvingStage),
+    ) -> Vec<u8> {
+        let ckt = RevealAuthCircuit::<H> {
             secret_key: secret_key.to_vec(),
             nullifier: nullifier.to_vec(),
             pub_key: pub_key.to_vec(),
             ciphertext_hash: ciphertext_hash.to_vec(),
+            chain_id: chain_id.to_vec(),
             dh_pub_key: dh_pub_key.to_vec(),
             signature: signature.to_vec(),
+            hash: PhantomData,
         };
 
-        let mut rng = &mut OsRng;
-        let proof = Groth16::<Bls12_381>::prove(&self.pk, ckt, &mut rng).unwrap();
-        serialize_proof(&proof)
+        tag_proof::<S>(S::prove_with_progress(&self.pk, ckt, progress))
     }
 }
 
-pub struct RevealAuthVerifier {
-    pvk: PreparedVerifyingKey<Bls12<Bls12_381Config>>,
+pub struct RevealAuthVerifier<S: ProofSystem = Groth16, H: HashConfig = Pedersen> {
+    vk: S::VerifyingKey,
+    hash: PhantomData<H>,
 }
 
 impl RevealAuthVerifier {
     pub fn new() -> Self {
-        let vk = load_verifying_key(REVEAL_AUTH_VERIFIER_PARAMS);
-        let pvk = Groth16::<Bls12_381>::process_vk(&vk).unwrap();
-        Self { pvk }
+        Self::from_key(load_verifying_key(REVEAL_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+#[cfg(feature = "poseidon")]
+impl RevealAuthVerifier<Groth16, Poseidon> {
+    /// Uses the committed verifying key of the Poseidon circuit.
+    pub fn embedded() -> Self {
+        Self::from_key(load_verifying_key(POSEIDON_REVEAL_AUTH_VERIFIER_PARAMS))
+    }
+}
+
+#[cfg(feature = "marlin")]
+impl RevealAuthVerifier<Marlin> {
+    /// Uses the committed verifying key of the Pedersen circuit, indexed from the
+    /// SRS at [`marlin::MARLIN_SRS_PATH`].
+    pub fn embedded() -> Self {
+        Self::from_key(marlin::load_verifying_key(
+            marlin::REVEAL_AUTH_VERIFIER_PARAMS,
+        ))
+    }
+}
+
+impl<S: ProofSystem, H: HashConfig> RevealAuthVerifier<S, H> {
+    pub fn from_key(vk: S::VerifyingKey) -> Self {
+        Self {
+            vk,
+            hash: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &S::VerifyingKey {
+        &self.vk
     }
 
     pub fn verify(
@@ -151,44 +812,404 @@ impl RevealAuthVerifier {
         proof: &[u8],
         pub_key: &[u8],
         ciphertext_hash: &[u8],
+        chain_id: &[u8],
         dh_pub_key: &[u8],
         signature: &[u8],
     ) -> bool {
-        let proof = deserialize_proof(proof);
+        let Some(proof) = untag_proof::<S>(proof) else {
+            return false;
+        };
+        let pub_inp =
+            reveal_public_inputs(pub_key, ciphertext_hash, chain_id, dh_pub_key, signature);
 
-        let pub_key_pub = ToConstraintField::<Fr>::to_field_elements(pub_key).unwrap();
-        let ciphertext_hash_pub =
-            ToConstraintField::<Fr>::to_field_elements(ciphertext_hash).unwrap();
-        let dh_pub_key_pub = ToConstraintField::<Fr>::to_field_elements(dh_pub_key).unwrap();
-        let signature_pub = ToConstraintField::<Fr>::to_field_elements(signature).unwrap();
+        S::verify(&self.vk, &pub_inp, proof)
+    }
+}
 
-        let mut pub_inp = Vec::new();
-        pub_inp.extend(pub_key_pub);
-        pub_inp.extend(ciphertext_hash_pub);
-        pub_inp.extend(dh_pub_key_pub);
-        pub_inp.extend(signature_pub);
+/// The public inputs of the REVEAL circuit, in the order its proofs are checked
+/// against.
+pub fn reveal_public_inputs(
+    pub_key: &[u8],
+    ciphertext_hash: &[u8],
+    chain_id: &[u8],
+    dh_pub_key: &[u8],
+    signature: &[u8],
+) -> Vec<Fr> {
+    let pub_key_pub = ToConstraintField::<Fr>::to_field_elements(pub_key).unwrap();
+    let ciphertext_hash_pub = ToConstraintField::<Fr>::to_field_elements(ciphertext_hash).unwrap();
+    let chain_id_pub = ToConstraintField::<Fr>::to_field_elements(chain_id).unwrap();
+    let dh_pub_key_pub = ToConstraintField::<Fr>::to_field_elements(dh_pub_key).unwrap();
+    let signature_pub = ToConstraintField::<Fr>::to_field_elements(signature).unwrap();
 
-        Groth16::<Bls12_381>::verify_with_processed_vk(&self.pvk, &pub_inp, &proof).is_ok()
+    let mut pub_inp = Vec::new();
+    pub_inp.extend(pub_key_pub);
+    pub_inp.extend(ciphertext_hash_pub);
+    pub_inp.extend(chain_id_pub);
+    pub_inp.extend(dh_pub_key_pub);
+    pub_inp.extend(signature_pub);
+    pub_inp
+}
+
+/// A satisfied ENTER circuit with inputs of the sizes the VM uses, to make keys for.
+fn enter_auth_circuit_shape<H: HashConfig>() -> EnterAuthCircuit<H> {
+    let hasher = H::hash();
+    let (secret_key, nullifier) = (vec![1u8; 64], vec![2u8; 64]);
+    let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+
+    EnterAuthCircuit {
+        secret_key,
+        nullifier,
+        pub_key,
+        chain_id: vec![4u8; 32],
+        hash: PhantomData,
     }
 }
 
-fn load_proving_key(bytes: &[u8]) -> ProvingKey<Bls12<Bls12_381Config>> {
-    let bytes = bytes.to_vec();
-    ProvingKey::deserialize_compressed(&mut bytes.as_slice()).unwrap()
+/// A satisfied invite-gated ENTER circuit with inputs of the sizes the VM uses, to
+/// make keys for.
+fn invite_enter_auth_circuit_shape<H: HashConfig>(
+    invite_tree_depth: usize,
+) -> InviteEnterAuthCircuit<H> {
+    let hasher = H::hash();
+    let (secret_key, nullifier) = (vec![1u8; 64], vec![2u8; 64]);
+    let (invite_secret, invite_salt) = (vec![6u8; 32], vec![7u8; 32]);
+    let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+    let invite = derive_invite_commitment(&hasher, &invite_secret, &invite_salt);
+    let mt = MerkleTreeOf::<H>::new(invite_tree_depth, &[invite]);
+
+    InviteEnterAuthCircuit {
+        secret_key,
+        nullifier,
+        pub_key,
+        chain_id: vec![4u8; 32],
+        invite_nullifier: derive_invite_nullifier(&hasher, &invite_secret),
+        invite_secret,
+        invite_salt,
+        invite_root: H::deserialize_digest(&mt.root()).unwrap(),
+        invite_path: MerkleTreeOf::<H>::deserialize_path(&mt.generate_proof(0).unwrap()).unwrap(),
+    }
 }
 
-fn load_verifying_key(bytes: &[u8]) -> VerifyingKey<Bls12<Bls12_381Config>> {
-    let bytes = bytes.to_vec();
-    VerifyingKey::deserialize_compressed(&mut bytes.as_slice()).unwrap()
+/// A satisfied CHOICE circuit with inputs of the sizes the VM uses, to make keys
+/// for or index.
+fn choice_auth_circuit_shape<H: HashConfig>(merkle_tree_depth: usize) -> ChoiceAuthCircuit<H> {
+    let hasher = H::hash();
+    let (secret_key, nullifier) = (vec![1u8; 64], vec![2u8; 64]);
+    let (choice, chain_id, dh_pub_key) = (vec![3u8; H::DIGEST_SIZE], vec![4u8; 32], vec![5u8; 64]);
+    let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+    let signature = sign_choice_tx(
+        &hasher,
+        &secret_key,
+        &nullifier,
+        &chain_id,
+        &choice,
+        &dh_pub_key,
+    );
+    let mt = MerkleTreeOf::<H>::new(merkle_tree_depth, &[pub_key]);
+
+    ChoiceAuthCircuit {
+        secret_key,
+        nullifier,
+        root: H::deserialize_digest(&mt.root()).unwrap(),
+        merkle_path: MerkleTreeOf::<H>::deserialize_path(&mt.generate_proof(0).unwrap()).unwrap(),
+        choice,
+        chain_id,
+        dh_pub_key,
+        signature,
+    }
 }
 
-fn serialize_proof(proof: &Proof<Bls12<Bls12_381Config>>) -> Vec<u8> {
-    let mut bytes = Vec::new();
-    proof.serialize_compressed(&mut bytes).unwrap();
-    bytes
+/// A satisfied REVEAL circuit with inputs of the sizes the VM uses, to make keys
+/// for or index.
+fn reveal_auth_circuit_shape<H: HashConfig>() -> RevealAuthCircuit<H> {
+    let hasher = H::hash();
+    let (secret_key, nullifier) = (vec![1u8; 64], vec![2u8; 64]);
+    let (chain_id, dh_pub_key) = (vec![4u8; 32], vec![5u8; 64]);
+    let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+    let ciphertext_hash = hasher.h1(b"ciphertext");
+    let signature = sign_reveal_tx(
+        &hasher,
+        &secret_key,
+        &nullifier,
+        &chain_id,
+        &ciphertext_hash,
+        &dh_pub_key,
+    );
+
+    RevealAuthCircuit {
+        secret_key,
+        nullifier,
+        pub_key,
+        ciphertext_hash,
+        chain_id,
+        dh_pub_key,
+        signature,
+        hash: PhantomData,
+    }
 }
 
-fn deserialize_proof(bytes: &[u8]) -> Proof<Bls12<Bls12_381Config>> {
-    let bytes = bytes.to_vec();
-    Proof::deserialize_compressed(&mut bytes.as_slice()).unwrap()
+#[cfg(test)]
+mod tests {
+    use super::{groth16::GROTH16_PROOF_SIZE, *};
+    use ark_bls12_381::Bls12_381;
+    use ark_crypto_primitives::snark::SNARK;
+    use ark_ff::Field;
+    use ark_groth16::Groth16 as ArkGroth16;
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSystemRef, SynthesisError},
+    };
+    use rand_core::OsRng;
+
+    /// Knowledge of an `x` with `x^(2^8) = y`. A few constraints are needed since
+    /// Marlin's domains can't be trivial.
+    #[derive(Clone)]
+    struct RepeatedSquaringCircuit {
+        x: Fr,
+        y: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for RepeatedSquaringCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let y = cs.new_input_variable(|| Ok(self.y))?;
+            let mut value = self.x;
+            let mut var = cs.new_witness_variable(|| Ok(value))?;
+            for _ in 0..7 {
+                let square = value * value;
+                let square_var = cs.new_witness_variable(|| Ok(square))?;
+                cs.enforce_constraint(lc!() + var, lc!() + var, lc!() + square_var)?;
+                (value, var) = (square, square_var);
+            }
+            cs.enforce_constraint(lc!() + var, lc!() + var, lc!() + y)
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "marlin")]
+    fn marlin_proofs_are_tagged_and_verify() {
+        let y = Fr::from(3u64).pow([256]);
+        let ckt = RepeatedSquaringCircuit {
+            x: Fr::from(3u64),
+            y,
+        };
+        let srs = Marlin::universal_setup(32, 32);
+        let (pk, vk) = Marlin::index(&srs, ckt.clone()).unwrap();
+
+        let proof = tag_proof::<Marlin>(Marlin::prove(&pk, ckt));
+        assert_eq!(proof_system_id(&proof), Some(Marlin::ID));
+        assert!(untag_proof::<Groth16>(&proof).is_none());
+
+        let untagged = untag_proof::<Marlin>(&proof).unwrap();
+        assert!(Marlin::verify(&vk, &[y], untagged));
+        assert!(!Marlin::verify(&vk, &[y + Fr::from(1u64)], untagged));
+        assert!(!Marlin::verify(&vk, &[y], &untagged[1..]));
+        assert!(!Marlin::verify(&vk, &[y], &[]));
+    }
+
+    #[test]
+    #[cfg(feature = "marlin")]
+    #[ignore = "needs the Marlin SRS pinned by MARLIN_SRS_SHA256, run with `cargo test --release --features marlin -- --ignored`"]
+    fn embedded_marlin_keys_are_indexed_from_the_pinned_srs() {
+        let srs = Marlin::load_srs(marlin::MARLIN_SRS_PATH).unwrap();
+
+        let (_, verifier) = ChoiceAuthProver::<Marlin>::index(&srs, 7).unwrap();
+        assert_eq!(
+            Marlin::serialize_verifying_key(verifier.key()),
+            &marlin::CHOICE_AUTH_VERIFIER_PARAMS[..]
+        );
+
+        let (_, verifier) = RevealAuthProver::<Marlin>::index(&srs).unwrap();
+        assert_eq!(
+            Marlin::serialize_verifying_key(verifier.key()),
+            &marlin::REVEAL_AUTH_VERIFIER_PARAMS[..]
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "poseidon", feature = "embedded-keys"))]
+    #[ignore = "loads the Poseidon proving keys, run with `cargo test --release --features poseidon -- --ignored`"]
+    fn embedded_poseidon_keys_prove_and_verify() {
+        use ark_serialize::CanonicalSerialize;
+
+        fn vk_bytes(pk: &<Groth16 as ProofSystem>::ProvingKey) -> Vec<u8> {
+            let mut bytes = Vec::new();
+            pk.vk.serialize_compressed(&mut bytes).unwrap();
+            bytes
+        }
+        assert_eq!(
+            vk_bytes(EnterAuthProver::<Poseidon>::embedded().key()),
+            &POSEIDON_ENTER_AUTH_VERIFIER_PARAMS[..]
+        );
+        assert_eq!(
+            vk_bytes(InviteEnterAuthProver::<Poseidon>::embedded().key()),
+            &POSEIDON_INVITE_ENTER_AUTH_VERIFIER_PARAMS[..]
+        );
+
+        let hasher = Poseidon::hash();
+        let (secret_key, nullifier) = ([1u8; 64], [2u8; 64]);
+        let (choice, chain_id, dh_pub_key) = ([3u8; 32], [4u8; 32], [5u8; 64]);
+        let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+        let mt = MerkleTreeOf::<Poseidon>::new(MERKLE_TREE_DEPTH, std::slice::from_ref(&pub_key));
+        let (root, merkle_path) = (mt.root(), mt.generate_proof(0).unwrap());
+
+        let prover = ChoiceAuthProver::<Groth16, Poseidon>::embedded();
+        assert_eq!(
+            vk_bytes(prover.key()),
+            &POSEIDON_CHOICE_AUTH_VERIFIER_PARAMS[..]
+        );
+        let signature = sign_choice_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &choice,
+            &dh_pub_key,
+        );
+        let proof = prover
+            .prove(
+                &secret_key,
+                &nullifier,
+                &root,
+                &merkle_path,
+                &choice,
+                &chain_id,
+                &dh_pub_key,
+                &signature,
+            )
+            .unwrap();
+        let verifier = ChoiceAuthVerifier::<Groth16, Poseidon>::embedded();
+        assert!(verifier.verify(
+            &proof,
+            &nullifier,
+            &root,
+            &choice,
+            &chain_id,
+            &dh_pub_key,
+            &signature
+        ));
+        assert!(!verifier.verify(
+            &proof,
+            &nullifier,
+            &root,
+            &[6u8; 32],
+            &chain_id,
+            &dh_pub_key,
+            &signature
+        ));
+
+        let prover = RevealAuthProver::<Groth16, Poseidon>::embedded();
+        assert_eq!(
+            vk_bytes(prover.key()),
+            &POSEIDON_REVEAL_AUTH_VERIFIER_PARAMS[..]
+        );
+        let ciphertext_hash = hasher.h1(b"ciphertext");
+        let signature = sign_reveal_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &ciphertext_hash,
+            &dh_pub_key,
+        );
+        let proof = prover.prove(
+            &secret_key,
+            &nullifier,
+            &pub_key,
+            &ciphertext_hash,
+            &chain_id,
+            &dh_pub_key,
+            &signature,
+        );
+        let verifier = RevealAuthVerifier::<Groth16, Poseidon>::embedded();
+        assert!(verifier.verify(
+            &proof,
+            &pub_key,
+            &ciphertext_hash,
+            &chain_id,
+            &dh_pub_key,
+            &signature
+        ));
+    }
+
+    #[test]
+    fn proving_stages_are_reported_in_order() {
+        let y = Fr::from(3u64).pow([256]);
+        let ckt = RepeatedSquaringCircuit {
+            x: Fr::from(3u64),
+            y,
+        };
+
+        let (pk, vk) =
+            ArkGroth16::<Bls12_381>::circuit_specific_setup(ckt.clone(), &mut OsRng).unwrap();
+        let pvk = ArkGroth16::<Bls12_381>::process_vk(&vk).unwrap();
+        let mut stages = Vec::new();
+        let proof = Groth16::prove_with_progress(&pk, ckt.clone(), |stage| stages.push(stage));
+        assert_eq!(stages, [ProvingStage::Witness, ProvingStage::Proof]);
+        assert!(Groth16::verify(&pvk, &[y], &proof));
+
+        #[cfg(feature = "marlin")]
+        {
+            let srs = Marlin::universal_setup(32, 32);
+            let (pk, _) = Marlin::index(&srs, ckt.clone()).unwrap();
+            let mut stages = Vec::new();
+            Marlin::prove_with_progress(&pk, ckt, |stage| stages.push(stage));
+            assert_eq!(stages, [ProvingStage::Proof]);
+        }
+    }
+
+    #[test]
+    fn untagged_proofs_are_rejected() {
+        let untagged = vec![0xffu8; GROTH16_PROOF_SIZE];
+        assert_eq!(proof_system_id(&untagged), Some(0xff));
+        assert!(untag_proof::<Groth16>(&untagged).is_none());
+        #[cfg(feature = "marlin")]
+        assert!(untag_proof::<Marlin>(&untagged).is_none());
+
+        let tagged = tag_proof::<Groth16>(untagged.clone());
+        assert_eq!(proof_system_id(&tagged), Some(Groth16::ID));
+        assert_eq!(untag_proof::<Groth16>(&tagged), Some(&untagged[..]));
+
+        assert_eq!(proof_system_id(&[]), None);
+    }
+
+    #[test]
+    fn malformed_roots_and_paths_are_rejected() {
+        let mt = MerkleTreeOf::<Pedersen>::new(7, &[vec![1u8; 64]]);
+        let (root, path) = (mt.root(), mt.generate_proof(0).unwrap());
+        assert!(deserialize_root_and_path::<Pedersen>(&root, &path).is_ok());
+
+        let not_a_point = [0xffu8; 64];
+        assert!(deserialize_root_and_path::<Pedersen>(&not_a_point, &path).is_err());
+        assert!(deserialize_root_and_path::<Pedersen>(&root, &path[1..]).is_err());
+        assert!(deserialize_root_and_path::<Pedersen>(&root, &[]).is_err());
+
+        let proof = tag_proof::<Groth16>(vec![0u8; GROTH16_PROOF_SIZE]);
+        let (nullifier, choice, chain_id) = ([2u8; 64], [3u8; 64], [4u8; 32]);
+        let (dh_pub_key, signature) = ([5u8; 64], [6u8; 64]);
+        assert!(choice_public_inputs::<Pedersen>(
+            &nullifier,
+            &not_a_point,
+            &choice,
+            &chain_id,
+            &dh_pub_key,
+            &signature
+        )
+        .is_none());
+        assert!(!ChoiceAuthVerifier::new().verify(
+            &proof,
+            &nullifier,
+            &not_a_point,
+            &choice,
+            &chain_id,
+            &dh_pub_key,
+            &signature
+        ));
+        assert!(!InviteEnterAuthVerifier::new().verify(
+            &proof,
+            &choice,
+            &chain_id,
+            &not_a_point,
+            &nullifier
+        ));
+    }
 }
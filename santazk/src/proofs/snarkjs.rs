@@ -0,0 +1,310 @@
+//! Groth16 proofs, verifying keys and public inputs in the JSON format of
+//! snarkjs, so that they can be checked with `snarkjs groth16 verify` or any
+//! other tool that reads it.
+//!
+//! Numbers are decimal strings, points are projective with `z = 1` (the point at
+//! infinity is `0, 1, 0`) and elements of Fq2 are `[c0, c1]`.
+
+use std::io::{self, Error, ErrorKind};
+
+use ark_bls12_381::{Bls12_381, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{One, PrimeField, Zero};
+use ark_groth16::{Proof, VerifyingKey};
+use ark_serialize::CanonicalSerialize;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    groth16::deserialize_proof, tag_proof, untag_proof, ChoiceAuthVerifier, Groth16,
+    RevealAuthVerifier,
+};
+
+pub const PROTOCOL: &str = "groth16";
+pub const CURVE: &str = "bls12381";
+
+pub type G1Json = [String; 3];
+pub type G2Json = [[String; 2]; 3];
+
+/// A proof, as in the `proof.json` of snarkjs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    pub pi_a: G1Json,
+    pub pi_b: G2Json,
+    pub pi_c: G1Json,
+    pub protocol: String,
+    pub curve: String,
+}
+
+/// A verifying key, as in the `verification_key.json` of snarkjs.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsVerifyingKey {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: G1Json,
+    pub vk_beta_2: G2Json,
+    pub vk_gamma_2: G2Json,
+    pub vk_delta_2: G2Json,
+    #[serde(rename = "IC")]
+    pub ic: Vec<G1Json>,
+}
+
+fn invalid(what: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, format!("invalid snarkjs {what}"))
+}
+
+fn check_header(protocol: &str, curve: &str) -> io::Result<()> {
+    if protocol != PROTOCOL || curve != CURVE {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("expected a {PROTOCOL} proof over {CURVE}, not {protocol} over {curve}"),
+        ));
+    }
+    Ok(())
+}
+
+fn export_field<F: PrimeField>(x: &F) -> String {
+    // not `x.to_string()`, which prints zero as an empty string
+    x.into_bigint().to_string()
+}
+
+/// Parses a decimal number, rejecting the ones that aren't canonical field
+/// elements rather than reducing them.
+fn import_field<F: PrimeField>(s: &str) -> Option<F> {
+    let x = F::from_str(s).ok()?;
+    (export_field(&x) == s).then_some(x)
+}
+
+fn export_g1(point: &G1Affine) -> G1Json {
+    match point.xy() {
+        Some((x, y)) => [export_field(x), export_field(y), "1".into()],
+        None => ["0".into(), "1".into(), "0".into()],
+    }
+}
+
+fn export_fq2(x: &Fq2) -> [String; 2] {
+    [export_field(&x.c0), export_field(&x.c1)]
+}
+
+fn export_g2(point: &G2Affine) -> G2Json {
+    match point.xy() {
+        Some((x, y)) => [export_fq2(x), export_fq2(y), export_fq2(&Fq2::one())],
+        None => [
+            export_fq2(&Fq2::zero()),
+            export_fq2(&Fq2::one()),
+            export_fq2(&Fq2::zero()),
+        ],
+    }
+}
+
+/// Returns none unless `point` is an affine point of the prime order subgroup, or
+/// the point at infinity.
+fn import_g1(point: &G1Json) -> Option<G1Affine> {
+    let [x, y, z] = point;
+    let (x, y) = (import_field::<Fq>(x)?, import_field::<Fq>(y)?);
+    match z.as_str() {
+        "0" => (x.is_zero() && y.is_one()).then(G1Affine::identity),
+        "1" => {
+            let point = G1Affine::new_unchecked(x, y);
+            (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve())
+                .then_some(point)
+        }
+        _ => None,
+    }
+}
+
+fn import_fq2(x: &[String; 2]) -> Option<Fq2> {
+    Some(Fq2::new(import_field(&x[0])?, import_field(&x[1])?))
+}
+
+/// Like [`import_g1`], for G2.
+fn import_g2(point: &G2Json) -> Option<G2Affine> {
+    let [x, y, z] = point;
+    let (x, y, z) = (import_fq2(x)?, import_fq2(y)?, import_fq2(z)?);
+    if z.is_zero() {
+        return (x.is_zero() && y.is_one()).then(G2Affine::identity);
+    }
+    if !z.is_one() {
+        return None;
+    }
+    let point = G2Affine::new_unchecked(x, y);
+    (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+}
+
+/// Exports a (tagged) Groth16 proof, or returns none if `proof` isn't one.
+pub fn export_proof(proof: &[u8]) -> Option<SnarkjsProof> {
+    let proof = deserialize_proof(untag_proof::<Groth16>(proof)?)?;
+    Some(SnarkjsProof {
+        pi_a: export_g1(&proof.a),
+        pi_b: export_g2(&proof.b),
+        pi_c: export_g1(&proof.c),
+        protocol: PROTOCOL.into(),
+        curve: CURVE.into(),
+    })
+}
+
+/// Imports a proof as the tagged Groth16 proof the verifiers take.
+pub fn import_proof(proof: &SnarkjsProof) -> io::Result<Vec<u8>> {
+    check_header(&proof.protocol, &proof.curve)?;
+    let proof = Proof::<Bls12_381> {
+        a: import_g1(&proof.pi_a).ok_or_else(|| invalid("pi_a"))?,
+        b: import_g2(&proof.pi_b).ok_or_else(|| invalid("pi_b"))?,
+        c: import_g1(&proof.pi_c).ok_or_else(|| invalid("pi_c"))?,
+    };
+    let mut bytes = Vec::new();
+    proof.serialize_compressed(&mut bytes).unwrap();
+    Ok(tag_proof::<Groth16>(bytes))
+}
+
+pub fn export_verifying_key(vk: &VerifyingKey<Bls12_381>) -> SnarkjsVerifyingKey {
+    SnarkjsVerifyingKey {
+        protocol: PROTOCOL.into(),
+        curve: CURVE.into(),
+        n_public: vk.gamma_abc_g1.len() - 1,
+        vk_alpha_1: export_g1(&vk.alpha_g1),
+        vk_beta_2: export_g2(&vk.beta_g2),
+        vk_gamma_2: export_g2(&vk.gamma_g2),
+        vk_delta_2: export_g2(&vk.delta_g2),
+        ic: vk.gamma_abc_g1.iter().map(export_g1).collect(),
+    }
+}
+
+pub fn import_verifying_key(vk: &SnarkjsVerifyingKey) -> io::Result<VerifyingKey<Bls12_381>> {
+    check_header(&vk.protocol, &vk.curve)?;
+    if vk.ic.len() != vk.n_public + 1 {
+        return Err(invalid("verifying key, IC doesn't have nPublic + 1 points"));
+    }
+    Ok(VerifyingKey {
+        alpha_g1: import_g1(&vk.vk_alpha_1).ok_or_else(|| invalid("vk_alpha_1"))?,
+        beta_g2: import_g2(&vk.vk_beta_2).ok_or_else(|| invalid("vk_beta_2"))?,
+        gamma_g2: import_g2(&vk.vk_gamma_2).ok_or_else(|| invalid("vk_gamma_2"))?,
+        delta_g2: import_g2(&vk.vk_delta_2).ok_or_else(|| invalid("vk_delta_2"))?,
+        gamma_abc_g1: vk
+            .ic
+            .iter()
+            .map(|point| import_g1(point).ok_or_else(|| invalid("IC")))
+            .collect::<io::Result<_>>()?,
+    })
+}
+
+/// Exports public inputs as the `public.json` of snarkjs.
+pub fn export_public_inputs(inputs: &[Fr]) -> Vec<String> {
+    inputs.iter().map(export_field).collect()
+}
+
+pub fn import_public_inputs(inputs: &[String]) -> io::Result<Vec<Fr>> {
+    inputs
+        .iter()
+        .map(|input| import_field(input).ok_or_else(|| invalid("public input")))
+        .collect()
+}
+
+/// The embedded CHOICE verifying key, for the inputs of
+/// [`super::choice_public_inputs`].
+pub fn choice_verifying_key() -> SnarkjsVerifyingKey {
+    export_verifying_key(&ChoiceAuthVerifier::new().key().vk)
+}
+
+/// The embedded REVEAL verifying key, for the inputs of
+/// [`super::reveal_public_inputs`].
+pub fn reveal_verifying_key() -> SnarkjsVerifyingKey {
+    export_verifying_key(&RevealAuthVerifier::new().key().vk)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_crypto_primitives::snark::SNARK;
+    use ark_ff::Field;
+    use ark_groth16::Groth16 as ArkGroth16;
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::proofs::ProofSystem;
+
+    /// Knows `w` with `w^2 = a`.
+    struct SquareCircuit {
+        w: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for SquareCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let a = cs.new_input_variable(|| Ok(self.w.square()))?;
+            let w = cs.new_witness_variable(|| Ok(self.w))?;
+            cs.enforce_constraint(lc!() + w, lc!() + w, lc!() + a)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn exports_round_trip_through_json() {
+        let w = Fr::from(5u64);
+        let (pk, vk) =
+            ArkGroth16::<Bls12_381>::circuit_specific_setup(SquareCircuit { w }, &mut OsRng)
+                .unwrap();
+        let proof = tag_proof::<Groth16>(Groth16::prove(&pk, SquareCircuit { w }));
+
+        let proof_json = serde_json::to_string(&export_proof(&proof).unwrap()).unwrap();
+        let vk_json = serde_json::to_string(&export_verifying_key(&vk)).unwrap();
+        let public_json = serde_json::to_string(&export_public_inputs(&[w.square()])).unwrap();
+        assert!(vk_json.contains(r#""nPublic":1"#));
+        assert_eq!(public_json, r#"["25"]"#);
+
+        let proof = import_proof(&serde_json::from_str(&proof_json).unwrap()).unwrap();
+        let vk = import_verifying_key(&serde_json::from_str(&vk_json).unwrap()).unwrap();
+        let inputs =
+            import_public_inputs(&serde_json::from_str::<Vec<_>>(&public_json).unwrap()).unwrap();
+        let pvk = ArkGroth16::<Bls12_381>::process_vk(&vk).unwrap();
+        assert!(Groth16::verify(
+            &pvk,
+            &inputs,
+            untag_proof::<Groth16>(&proof).unwrap()
+        ));
+    }
+
+    #[test]
+    fn imports_are_strict() {
+        let modulus = Fr::MODULUS.to_string();
+        assert!(import_public_inputs(&[modulus]).is_err());
+        assert!(import_public_inputs(&["025".into()]).is_err());
+        assert_eq!(import_public_inputs(&["0".into()]).unwrap(), [Fr::zero()]);
+
+        let generator = export_g1(&G1Affine::generator());
+        let mut off_curve = generator.clone();
+        off_curve[1] = "2".into();
+        assert!(import_g1(&generator).is_some());
+        assert!(import_g1(&off_curve).is_none());
+        assert_eq!(
+            import_g1(&export_g1(&G1Affine::identity())),
+            Some(G1Affine::identity())
+        );
+        assert_eq!(
+            import_g2(&export_g2(&G2Affine::identity())),
+            Some(G2Affine::identity())
+        );
+
+        assert!(export_proof(&[]).is_none());
+        let mut proof = SnarkjsProof {
+            pi_a: generator.clone(),
+            pi_b: export_g2(&G2Affine::generator()),
+            pi_c: generator,
+            protocol: PROTOCOL.into(),
+            curve: CURVE.into(),
+        };
+        assert!(import_proof(&proof).is_ok());
+        proof.curve = "bn128".into();
+        assert!(import_proof(&proof).is_err());
+    }
+
+    #[test]
+    fn embedded_keys_match_the_verifiers() {
+        let vk = import_verifying_key(&choice_verifying_key()).unwrap();
+        assert_eq!(vk, ChoiceAuthVerifier::new().key().vk);
+        assert_eq!(choice_verifying_key().n_public, 16);
+        assert_eq!(reveal_verifying_key().n_public, 14);
+    }
+}
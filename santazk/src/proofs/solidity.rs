@@ -0,0 +1,699 @@
+//! Solidity verifiers for the Groth16 CHOICE and REVEAL keys, for EVM chains with
+//! the BLS12-381 precompiles of EIP-2537, and the calldata that calls them.
+//!
+//! Points are in the encoding of the precompiles: a base field element is 64
+//! big-endian bytes (16 zero bytes, then the 48 of the element), a G1 point is
+//! `x ‖ y`, a G2 point is `x.c0 ‖ x.c1 ‖ y.c0 ‖ y.c1`, and the point at infinity
+//! is all zeros. Public inputs are `uint256`s below the scalar field order.
+
+use std::fmt::Write;
+
+use ark_bls12_381::{Bls12_381, Fq, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::VerifyingKey;
+use sha3::{Digest, Keccak256};
+
+use super::{
+    choice_public_inputs, groth16::deserialize_proof, reveal_public_inputs, untag_proof,
+    ChoiceAuthVerifier, Groth16, RevealAuthVerifier,
+};
+use crate::hash::Pedersen;
+
+/// Size of a G1 point in the precompile encoding.
+pub const G1_SIZE: usize = 128;
+/// Size of a G2 point in the precompile encoding.
+pub const G2_SIZE: usize = 256;
+/// Size of a proof in the precompile encoding: `A ‖ B ‖ C`.
+pub const EVM_PROOF_SIZE: usize = 2 * G1_SIZE + G2_SIZE;
+
+/// Address of the G1 multi-scalar multiplication precompile.
+const BLS12_G1MSM: u8 = 0x0c;
+/// Address of the pairing check precompile.
+const BLS12_PAIRING_CHECK: u8 = 0x0f;
+
+fn encode_fq(out: &mut Vec<u8>, x: &Fq) {
+    out.extend([0u8; 16]);
+    out.extend(x.into_bigint().to_bytes_be());
+}
+
+pub fn encode_g1(point: &G1Affine) -> Vec<u8> {
+    let mut out = Vec::with_capacity(G1_SIZE);
+    match point.xy() {
+        Some((x, y)) => {
+            encode_fq(&mut out, x);
+            encode_fq(&mut out, y);
+        }
+        None => out.resize(G1_SIZE, 0),
+    }
+    out
+}
+
+pub fn encode_g2(point: &G2Affine) -> Vec<u8> {
+    let mut out = Vec::with_capacity(G2_SIZE);
+    match point.xy() {
+        Some((x, y)) => {
+            for c in [&x.c0, &x.c1, &y.c0, &y.c1] {
+                encode_fq(&mut out, c);
+            }
+        }
+        None => out.resize(G2_SIZE, 0),
+    }
+    out
+}
+
+/// Encodes a public input as the `uint256` the contract takes.
+pub fn encode_scalar(x: &Fr) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out.copy_from_slice(&x.into_bigint().to_bytes_be());
+    out
+}
+
+/// Re-encodes a (tagged) Groth16 proof for the verifier contracts, or returns none
+/// if `proof` isn't one.
+pub fn encode_proof(proof: &[u8]) -> Option<Vec<u8>> {
+    let proof = deserialize_proof(untag_proof::<Groth16>(proof)?)?;
+    let mut out = encode_g1(&proof.a);
+    out.extend(encode_g2(&proof.b));
+    out.extend(encode_g1(&proof.c));
+    Some(out)
+}
+
+/// The Solidity signature of `verifyProof` for `num_inputs` public inputs.
+fn verify_proof_signature(num_inputs: usize) -> String {
+    format!("verifyProof(bytes,uint256[{num_inputs}])")
+}
+
+/// ABI-encodes a call of `verifyProof(proof, public_inputs)`, or returns none if
+/// `proof` isn't a Groth16 proof.
+pub fn calldata(proof: &[u8], public_inputs: &[Fr]) -> Option<Vec<u8>> {
+    let proof = encode_proof(proof)?;
+
+    let selector = Keccak256::digest(verify_proof_signature(public_inputs.len()));
+    let mut out = selector[..4].to_vec();
+    // the offset of the proof, which comes after its own offset and the inputs
+    out.extend(encode_scalar(&Fr::from(
+        (32 * (1 + public_inputs.len())) as u64,
+    )));
+    for input in public_inputs {
+        out.extend(encode_scalar(input));
+    }
+    out.extend(encode_scalar(&Fr::from(proof.len() as u64)));
+    out.extend(proof);
+    Some(out)
+}
+
+/// The calldata checking a CHOICE proof with the contract from
+/// [`choice_verifier_contract`]. Takes what [`ChoiceAuthVerifier::verify`] takes.
+pub fn choice_calldata(
+    proof: &[u8],
+    nullifier: &[u8],
+    root: &[u8],
+    choice: &[u8],
+    chain_id: &[u8],
+    dh_pub_key: &[u8],
+    signature: &[u8],
+) -> Option<Vec<u8>> {
+    let inputs =
+        choice_public_inputs::<Pedersen>(nullifier, root, choice, chain_id, dh_pub_key, signature)?;
+    calldata(proof, &inputs)
+}
+
+/// The calldata checking a REVEAL proof with the contract from
+/// [`reveal_verifier_contract`]. Takes what [`RevealAuthVerifier::verify`] takes.
+pub fn reveal_calldata(
+    proof: &[u8],
+    pub_key: &[u8],
+    ciphertext_hash: &[u8],
+    chain_id: &[u8],
+    dh_pub_key: &[u8],
+    signature: &[u8],
+) -> Option<Vec<u8>> {
+    let inputs = reveal_public_inputs(pub_key, ciphertext_hash, chain_id, dh_pub_key, signature);
+    calldata(proof, &inputs)
+}
+
+/// The contract checking CHOICE proofs against the embedded verifying key.
+pub fn choice_verifier_contract() -> String {
+    verifier_contract("ChoiceAuthVerifier", &ChoiceAuthVerifier::new().key().vk)
+}
+
+/// The contract checking REVEAL proofs against the embedded verifying key.
+pub fn reveal_verifier_contract() -> String {
+    verifier_contract("RevealAuthVerifier", &RevealAuthVerifier::new().key().vk)
+}
+
+fn hex_literal(bytes: &[u8]) -> String {
+    let mut out = String::from("hex\"");
+    for byte in bytes {
+        write!(out, "{byte:02x}").unwrap();
+    }
+    out.push('"');
+    out
+}
+
+/// Solidity source of a contract named `name` checking proofs against `vk`.
+///
+/// The verifying key is baked in with alpha, gamma and delta negated, so that the
+/// check `e(A, B) = e(alpha, beta) e(vk_x, gamma) e(C, delta)` is a single call of
+/// the pairing precompile on four pairs.
+pub fn verifier_contract(name: &str, vk: &VerifyingKey<Bls12_381>) -> String {
+    let num_inputs = vk.gamma_abc_g1.len() - 1;
+    let ic = vk
+        .gamma_abc_g1
+        .iter()
+        .map(|p| format!("\n        {}", hex_literal(&encode_g1(p))))
+        .collect::<String>();
+
+    format!(
+        r#"// SPDX-License-Identifier: Apache-2.0
+// Generated by `cargo run -p santazk --bin santazkparams -- solidity`, do not edit.
+pragma solidity ^0.8.24;
+
+/// @notice Checks Groth16 proofs over BLS12-381 against a fixed verifying key,
+/// with the precompiles of EIP-2537. Proofs and inputs are encoded by
+/// `santazk::proofs::solidity`.
+contract {name} {{
+    /// Order of the BLS12-381 scalar field, which every public input is below.
+    uint256 internal constant R = 0x{r};
+    address internal constant BLS12_G1MSM = address(0x{msm:02x});
+    address internal constant BLS12_PAIRING_CHECK = address(0x{pairing:02x});
+
+    // The verifying key, with alpha, gamma and delta negated.
+    bytes internal constant ALPHA_NEG = {alpha_neg};
+    bytes internal constant BETA = {beta};
+    bytes internal constant GAMMA_NEG = {gamma_neg};
+    bytes internal constant DELTA_NEG = {delta_neg};
+    // The G1 points the public inputs are multiplied with, the first one by 1.
+    bytes internal constant IC ={ic};
+
+    /// @param proof The points A, B and C of the proof.
+    /// @param input The public inputs.
+    /// @return Whether the proof is valid for the inputs.
+    function verifyProof(bytes calldata proof, uint256[{num_inputs}] calldata input) external view returns (bool) {{
+        if (proof.length != {proof_size}) {{
+            return false;
+        }}
+
+        // vk_x = IC[0] + input[0] IC[1] + ... + input[{last}] IC[{num_inputs}]
+        bytes memory ic = IC;
+        bytes memory msm = new bytes({msm_pair} * {num_points});
+        for (uint256 i = 0; i < {num_points}; i++) {{
+            uint256 scalar = i == 0 ? 1 : input[i - 1];
+            if (scalar >= R) {{
+                return false;
+            }}
+            assembly ("memory-safe") {{
+                let src := add(add(ic, 32), mul(i, {g1}))
+                let dst := add(add(msm, 32), mul(i, {msm_pair}))
+                mstore(dst, mload(src))
+                mstore(add(dst, 32), mload(add(src, 32)))
+                mstore(add(dst, 64), mload(add(src, 64)))
+                mstore(add(dst, 96), mload(add(src, 96)))
+                mstore(add(dst, {g1}), scalar)
+            }}
+        }}
+        (bool ok, bytes memory vkX) = BLS12_G1MSM.staticcall(msm);
+        if (!ok || vkX.length != {g1}) {{
+            return false;
+        }}
+
+        // e(A, B) e(-alpha, beta) e(vk_x, -gamma) e(C, -delta) == 1
+        bytes memory pairs = abi.encodePacked(
+            proof[0:{b_end}], ALPHA_NEG, BETA, vkX, GAMMA_NEG, proof[{b_end}:{proof_size}], DELTA_NEG
+        );
+        bytes memory result;
+        (ok, result) = BLS12_PAIRING_CHECK.staticcall(pairs);
+        return ok && result.length == 32 && abi.decode(result, (uint256)) == 1;
+    }}
+}}
+"#,
+        r = Fr::MODULUS
+            .to_bytes_be()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>(),
+        msm = BLS12_G1MSM,
+        pairing = BLS12_PAIRING_CHECK,
+        alpha_neg = hex_literal(&encode_g1(&(-vk.alpha_g1))),
+        beta = hex_literal(&encode_g2(&vk.beta_g2)),
+        gamma_neg = hex_literal(&encode_g2(&(-vk.gamma_g2))),
+        delta_neg = hex_literal(&encode_g2(&(-vk.delta_g2))),
+        last = num_inputs - 1,
+        num_points = num_inputs + 1,
+        msm_pair = G1_SIZE + 32,
+        g1 = G1_SIZE,
+        b_end = G1_SIZE + G2_SIZE,
+        proof_size = EVM_PROOF_SIZE,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bls12_381::{Fq2, G1Projective};
+    use ark_crypto_primitives::snark::SNARK;
+    use ark_ec::{pairing::Pairing, CurveGroup, VariableBaseMSM};
+    use ark_ff::{Field, One, Zero};
+    use ark_groth16::Groth16 as ArkGroth16;
+    use ark_relations::{
+        lc,
+        r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+    };
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::proofs::{tag_proof, ProofSystem};
+
+    /// Reads the bytes of `bytes internal constant NAME = hex"..." ...;` in `source`.
+    fn constant(source: &str, name: &str) -> Vec<u8> {
+        let start = source
+            .find(&format!("bytes internal constant {name} ="))
+            .unwrap();
+        let value = &source[start..start + source[start..].find(';').unwrap()];
+        value
+            .split("hex\"")
+            .skip(1)
+            .flat_map(|literal| {
+                let hex = &literal[..literal.find('"').unwrap()];
+                (0..hex.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Decodes a base field element the way the precompiles do, rejecting
+    /// non-zero padding and non-canonical encodings.
+    fn decode_fq(bytes: &[u8]) -> Option<Fq> {
+        let (padding, bytes) = bytes.split_at(16);
+        let x = Fq::from_be_bytes_mod_order(bytes);
+        (padding.iter().all(|&b| b == 0) && x.into_bigint().to_bytes_be() == bytes).then_some(x)
+    }
+
+    fn decode_g1(bytes: &[u8]) -> Option<G1Affine> {
+        if bytes.iter().all(|&b| b == 0) {
+            return Some(G1Affine::identity());
+        }
+        let point = G1Affine::new_unchecked(decode_fq(&bytes[..64])?, decode_fq(&bytes[64..])?);
+        (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+    }
+
+    fn decode_g2(bytes: &[u8]) -> Option<G2Affine> {
+        if bytes.iter().all(|&b| b == 0) {
+            return Some(G2Affine::identity());
+        }
+        let fq2 = |bytes: &[u8]| Some(Fq2::new(decode_fq(&bytes[..64])?, decode_fq(&bytes[64..])?));
+        let point = G2Affine::new_unchecked(fq2(&bytes[..128])?, fq2(&bytes[128..])?);
+        (point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve()).then_some(point)
+    }
+
+    fn word(calldata: &[u8], offset: usize) -> usize {
+        let word = &calldata[offset..offset + 32];
+        assert!(word[..24].iter().all(|&b| b == 0));
+        usize::from_be_bytes(word[24..].try_into().unwrap())
+    }
+
+    /// What the contract in `source` returns for `calldata`, with arkworks in
+    /// place of the precompiles.
+    fn run_contract(source: &str, calldata: &[u8]) -> bool {
+        let ic = constant(source, "IC");
+        let num_inputs = ic.len() / G1_SIZE - 1;
+        let selector = Keccak256::digest(verify_proof_signature(num_inputs));
+        assert_eq!(calldata[..4], selector[..4]);
+        let args = &calldata[4..];
+
+        let proof_offset = word(args, 0);
+        let proof_len = word(args, proof_offset);
+        let proof = &args[proof_offset + 32..proof_offset + 32 + proof_len];
+        if proof.len() != EVM_PROOF_SIZE {
+            return false;
+        }
+
+        let mut scalars = vec![Fr::one()];
+        for i in 1..=num_inputs {
+            let input = &args[32 * i..32 * (i + 1)];
+            if input >= &Fr::MODULUS.to_bytes_be()[..] {
+                return false;
+            }
+            scalars.push(Fr::from_be_bytes_mod_order(input));
+        }
+        let points: Vec<_> = ic.chunks(G1_SIZE).map(|p| decode_g1(p).unwrap()).collect();
+        let vk_x = G1Projective::msm(&points, &scalars).unwrap().into_affine();
+
+        let (Some(a), Some(b), Some(c)) = (
+            decode_g1(&proof[..G1_SIZE]),
+            decode_g2(&proof[G1_SIZE..G1_SIZE + G2_SIZE]),
+            decode_g1(&proof[G1_SIZE + G2_SIZE..]),
+        ) else {
+            // the pairing precompile fails on invalid points
+            return false;
+        };
+        let product = Bls12_381::multi_pairing(
+            [
+                a,
+                decode_g1(&constant(source, "ALPHA_NEG")).unwrap(),
+                vk_x,
+                c,
+            ],
+            [
+                b,
+                decode_g2(&constant(source, "BETA")).unwrap(),
+                decode_g2(&constant(source, "GAMMA_NEG")).unwrap(),
+                decode_g2(&constant(source, "DELTA_NEG")).unwrap(),
+            ],
+        );
+        product.is_zero()
+    }
+
+    /// A verifier contract, run with arkworks in place of the precompiles and,
+    /// with the `evm-tests` feature, on a local EVM too.
+    struct Contract {
+        source: String,
+        #[cfg(feature = "evm-tests")]
+        evm: evm::Anvil,
+    }
+
+    impl Contract {
+        fn new(source: String) -> Self {
+            Self {
+                #[cfg(feature = "evm-tests")]
+                evm: evm::Anvil::deploy(&source),
+                source,
+            }
+        }
+
+        /// Whether the contract returns true for `calldata`.
+        fn accepts(&self, calldata: &[u8]) -> bool {
+            let accepted = run_contract(&self.source, calldata);
+            #[cfg(feature = "evm-tests")]
+            assert_eq!(self.evm.call(calldata), accepted, "the EVM disagrees");
+            accepted
+        }
+    }
+
+    /// Compiles the contracts with `solc` and runs them on `anvil` with the
+    /// Prague hardfork, which has the EIP-2537 precompiles. Needs solc 0.8.29 or
+    /// later and Foundry's `anvil` and `cast` on the `PATH`.
+    #[cfg(feature = "evm-tests")]
+    mod evm {
+        use std::{
+            fs,
+            net::TcpListener,
+            process::{Child, Command, Stdio},
+            thread::sleep,
+            time::Duration,
+        };
+
+        /// Address the contract is deployed at.
+        const ADDRESS: &str = "0x00000000000000000000000000000000000a11ce";
+
+        fn hex(bytes: &[u8]) -> String {
+            bytes.iter().fold(String::from("0x"), |mut out, b| {
+                out.push_str(&format!("{b:02x}"));
+                out
+            })
+        }
+
+        /// Runs `program` with `args` and returns its stdout, or `None` if it failed.
+        fn output(program: &str, args: &[&str]) -> Option<String> {
+            let output = Command::new(program)
+                .args(args)
+                .output()
+                .unwrap_or_else(|e| panic!("failed to run {program}: {e}"));
+            output
+                .status
+                .success()
+                .then(|| String::from_utf8(output.stdout).unwrap())
+        }
+
+        /// Returns the runtime bytecode of the only contract in `source`.
+        fn compile(source: &str) -> String {
+            let dir = std::env::temp_dir().join(format!("santazk-solc-{}", std::process::id()));
+            fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("Verifier.sol");
+            fs::write(&path, source).unwrap();
+            let out = output(
+                "solc",
+                &[
+                    "--bin-runtime",
+                    "--optimize",
+                    "--evm-version",
+                    "prague",
+                    path.to_str().unwrap(),
+                ],
+            )
+            .expect("solc failed to compile the contract");
+            fs::remove_dir_all(&dir).unwrap();
+
+            let code = out
+                .lines()
+                .rev()
+                .find(|line| !line.trim().is_empty())
+                .unwrap();
+            format!("0x{}", code.trim())
+        }
+
+        /// An anvil node with the contract deployed at [`ADDRESS`], killed on drop.
+        pub struct Anvil {
+            node: Child,
+            rpc_url: String,
+        }
+
+        impl Anvil {
+            pub fn deploy(source: &str) -> Self {
+                let code = compile(source);
+
+                let port = TcpListener::bind("127.0.0.1:0")
+                    .unwrap()
+                    .local_addr()
+                    .unwrap()
+                    .port();
+                let node = Command::new("anvil")
+                    .args(["--hardfork", "prague", "--port", &port.to_string()])
+                    .stdout(Stdio::null())
+                    .spawn()
+                    .expect("failed to run anvil");
+                let anvil = Self {
+                    node,
+                    rpc_url: format!("http://127.0.0.1:{port}"),
+                };
+
+                let mut ready = false;
+                for _ in 0..100 {
+                    ready = anvil.cast(&["chain-id"]).is_some();
+                    if ready {
+                        break;
+                    }
+                    sleep(Duration::from_millis(100));
+                }
+                assert!(ready, "anvil didn't start");
+
+                anvil
+                    .cast(&["rpc", "anvil_setCode", ADDRESS, &code])
+                    .expect("failed to deploy the contract");
+                anvil
+            }
+
+            fn cast(&self, args: &[&str]) -> Option<String> {
+                let mut args = args.to_vec();
+                args.extend(["--rpc-url", &self.rpc_url]);
+                output("cast", &args)
+            }
+
+            /// Whether the contract returns true for `calldata`. Panics if it reverts.
+            pub fn call(&self, calldata: &[u8]) -> bool {
+                let tx = format!(r#"{{"to":"{ADDRESS}","data":"{}"}}"#, hex(calldata));
+                let result = self
+                    .cast(&["rpc", "eth_call", &tx, "latest"])
+                    .expect("the contract reverted");
+                let result: String = serde_json::from_str(result.trim()).unwrap();
+                match result.as_str() {
+                    "0x0000000000000000000000000000000000000000000000000000000000000001" => true,
+                    "0x0000000000000000000000000000000000000000000000000000000000000000" => false,
+                    _ => panic!("unexpected return data {result}"),
+                }
+            }
+        }
+
+        impl Drop for Anvil {
+            fn drop(&mut self) {
+                let _ = self.node.kill();
+                let _ = self.node.wait();
+            }
+        }
+    }
+
+    /// Knows `w` with `w^2 = a` and `w^3 = b`.
+    struct PowersCircuit {
+        w: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for PowersCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let a = cs.new_input_variable(|| Ok(self.w.square()))?;
+            let b = cs.new_input_variable(|| Ok(self.w.square() * self.w))?;
+            let w = cs.new_witness_variable(|| Ok(self.w))?;
+            cs.enforce_constraint(lc!() + w, lc!() + w, lc!() + a)?;
+            cs.enforce_constraint(lc!() + a, lc!() + w, lc!() + b)?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn contract_accepts_valid_calldata_only() {
+        let w = Fr::from(3u64);
+        let (pk, vk) =
+            ArkGroth16::<Bls12_381>::circuit_specific_setup(PowersCircuit { w }, &mut OsRng)
+                .unwrap();
+        let proof = tag_proof::<Groth16>(Groth16::prove(&pk, PowersCircuit { w }));
+        let contract = Contract::new(verifier_contract("PowersVerifier", &vk));
+
+        let inputs = [Fr::from(9u64), Fr::from(27u64)];
+        assert!(contract.accepts(&calldata(&proof, &inputs).unwrap()));
+
+        let wrong_inputs = [Fr::from(9u64), Fr::from(28u64)];
+        assert!(!contract.accepts(&calldata(&proof, &wrong_inputs).unwrap()));
+
+        // an input that is only equal to the right one modulo the field order
+        let mut unreduced = calldata(&proof, &inputs).unwrap();
+        let mut first_input = inputs[0].into_bigint();
+        first_input.add_with_carry(&Fr::MODULUS);
+        unreduced[4 + 32..4 + 64].copy_from_slice(&first_input.to_bytes_be());
+        assert!(!contract.accepts(&unreduced));
+
+        // a proof made for other inputs
+        let other = tag_proof::<Groth16>(Groth16::prove(&pk, PowersCircuit { w: Fr::from(2u64) }));
+        assert!(!contract.accepts(&calldata(&other, &inputs).unwrap()));
+    }
+
+    #[test]
+    fn committed_contracts_are_up_to_date() {
+        assert_eq!(
+            include_str!("../../contracts/ChoiceAuthVerifier.sol"),
+            choice_verifier_contract(),
+            "regenerate with `cargo run -p santazk --bin santazkparams -- solidity`"
+        );
+        assert_eq!(
+            include_str!("../../contracts/RevealAuthVerifier.sol"),
+            reveal_verifier_contract(),
+            "regenerate with `cargo run -p santazk --bin santazkparams -- solidity`"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-keys")]
+    #[ignore = "loads the CHOICE proving key, run with `cargo test --release -- --ignored`"]
+    fn choice_calldata_matches_the_verifier() {
+        use crate::{
+            crypto::{derive_participation_pubkey, sign_choice_tx},
+            hash::Hash,
+            merkle::MerkleTree,
+            proofs::ChoiceAuthProver,
+        };
+
+        let hasher = Hash::new();
+        let (secret_key, nullifier) = ([1u8; 64], [2u8; 64]);
+        let (chain_id, dh_pub_key) = ([4u8; 32], [5u8; 64]);
+        let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+        let choice = derive_participation_pubkey(&hasher, &[3u8; 64], &[4u8; 64]);
+        let mt = MerkleTree::new(7, &[pub_key, choice.clone()]);
+        let root = mt.root();
+        let signature = sign_choice_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &choice,
+            &dh_pub_key,
+        );
+        let proof = ChoiceAuthProver::new()
+            .prove(
+                &secret_key,
+                &nullifier,
+                &root,
+                &mt.generate_proof(0).unwrap(),
+                &choice,
+                &chain_id,
+                &dh_pub_key,
+                &signature,
+            )
+            .unwrap();
+
+        let verifier = ChoiceAuthVerifier::new();
+        let contract = Contract::new(choice_verifier_contract());
+        let check = |dh_pub_key: &[u8]| {
+            let calldata = choice_calldata(
+                &proof, &nullifier, &root, &choice, &chain_id, dh_pub_key, &signature,
+            )
+            .unwrap();
+            let valid = verifier.verify(
+                &proof, &nullifier, &root, &choice, &chain_id, dh_pub_key, &signature,
+            );
+            assert_eq!(contract.accepts(&calldata), valid);
+            valid
+        };
+        assert!(check(&dh_pub_key));
+        assert!(!check(&[6u8; 64]));
+    }
+
+    #[test]
+    #[cfg(feature = "embedded-keys")]
+    #[ignore = "loads the REVEAL proving key, run with `cargo test --release -- --ignored`"]
+    fn reveal_calldata_matches_the_verifier() {
+        use crate::{
+            crypto::{derive_participation_pubkey, sign_reveal_tx},
+            hash::Hash,
+            proofs::RevealAuthProver,
+        };
+
+        let hasher = Hash::new();
+        let (secret_key, nullifier) = ([1u8; 64], [2u8; 64]);
+        let (chain_id, dh_pub_key) = ([4u8; 32], [5u8; 64]);
+        let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+        let ciphertext_hash = hasher.h1(b"ciphertext");
+        let signature = sign_reveal_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &ciphertext_hash,
+            &dh_pub_key,
+        );
+        let proof = RevealAuthProver::new().prove(
+            &secret_key,
+            &nullifier,
+            &pub_key,
+            &ciphertext_hash,
+            &chain_id,
+            &dh_pub_key,
+            &signature,
+        );
+
+        let verifier = RevealAuthVerifier::new();
+        let contract = Contract::new(reveal_verifier_contract());
+        let check = |ciphertext_hash: &[u8]| {
+            let calldata = reveal_calldata(
+                &proof,
+                &pub_key,
+                ciphertext_hash,
+                &chain_id,
+                &dh_pub_key,
+                &signature,
+            )
+            .unwrap();
+            let valid = verifier.verify(
+                &proof,
+                &pub_key,
+                ciphertext_hash,
+                &chain_id,
+                &dh_pub_key,
+                &signature,
+            );
+            assert_eq!(contract.accepts(&calldata), valid);
+            valid
+        };
+        assert!(check(&ciphertext_hash));
+        assert!(!check(&hasher.h1(b"other ciphertext")));
+    }
+}
@@ -1,8 +1,12 @@
 use ark_ed_on_bls12_381::{EdwardsAffine as JubJubAffine, Fq as Fr};
 use ark_ff::BigInteger;
 use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 
+/// Size of the x‖y encoding of points, which the circuits hash.
 pub const JUBJUB_AFFINE_POINT_SIZE: usize = 64;
+/// Size of the compressed encoding of points: y, with the sign of x in the top bit.
+pub const COMPRESSED_POINT_SIZE: usize = 32;
 
 pub fn serialize_jub_jub_affine_point(el: &JubJubAffine) -> Vec<u8> {
     let mut bits: Vec<bool> = el.x.into_bigint().to_bits_le(); bits.pop();
@@ -24,18 +28,117 @@ pub fn serialize_jub_jub_affine_point(el: &JubJubAffine) -> Vec<u8> {
         .collect()
 }
 
+/// # Panics
+/// If `bytes` isn't a valid point, see [`try_deserialize_jub_jub_affine_point`].
 pub fn deserialize_jub_jub_affine_point(bytes: &[u8]) -> JubJubAffine {
+    try_deserialize_jub_jub_affine_point(bytes).expect("invalid JubJub point")
+}
+
+/// Decodes the x‖y encoding of a point, checking that the encoding is canonical
+/// and that the point is on the curve and in the prime-order subgroup.
+pub fn try_deserialize_jub_jub_affine_point(
+    bytes: &[u8],
+) -> Result<JubJubAffine, SerializationError> {
+    if bytes.len() != JUBJUB_AFFINE_POINT_SIZE {
+        return Err(SerializationError::InvalidData);
+    }
+
     let mut bits = Vec::new();
     for byte in bytes.iter() {
         for i in 0..8 {
             bits.push(byte & (1 << i) != 0);
         }
     }
-    bits.pop();
-    bits.pop(); // remove padding
+    // padding
+    if bits.pop() != Some(false) || bits.pop() != Some(false) {
+        return Err(SerializationError::InvalidData);
+    }
 
-    let x = Fr::from_bigint(BigInteger::from_bits_le(&bits[..255])).unwrap();
-    let y = Fr::from_bigint(BigInteger::from_bits_le(&bits[255..])).unwrap();
+    let x = Fr::from_bigint(BigInteger::from_bits_le(&bits[..255]))
+        .ok_or(SerializationError::InvalidData)?;
+    let y = Fr::from_bigint(BigInteger::from_bits_le(&bits[255..]))
+        .ok_or(SerializationError::InvalidData)?;
 
-    JubJubAffine { x, y }
+    let point = JubJubAffine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(point)
+}
+
+/// Converts a point from the x‖y encoding to the compressed one.
+pub fn compress_point(bytes: &[u8]) -> Result<Vec<u8>, SerializationError> {
+    let point = try_deserialize_jub_jub_affine_point(bytes)?;
+    let mut compressed = Vec::with_capacity(COMPRESSED_POINT_SIZE);
+    point.serialize_compressed(&mut compressed)?;
+    Ok(compressed)
+}
+
+/// Converts a point from the compressed encoding to the x‖y one, checking that
+/// the encoding is canonical and that the point is in the prime-order subgroup.
+pub fn decompress_point(bytes: &[u8]) -> Result<Vec<u8>, SerializationError> {
+    if bytes.len() != COMPRESSED_POINT_SIZE {
+        return Err(SerializationError::InvalidData);
+    }
+    let point = JubJubAffine::deserialize_compressed(bytes)?;
+
+    // the sign of x is ignored when x = 0, so only one of its two encodings is valid
+    let mut canonical = Vec::with_capacity(COMPRESSED_POINT_SIZE);
+    point.serialize_compressed(&mut canonical)?;
+    if canonical != bytes {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(serialize_jub_jub_affine_point(&point))
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ff::{One, Zero};
+
+    use super::*;
+    use crate::hash::Hash;
+
+    #[test]
+    fn points_round_trip_through_the_compressed_encoding() {
+        let hash = Hash::new();
+        for input in [&[1u8; 32][..], &[2u8; 64], &[]] {
+            let point = hash.h1(input);
+            let compressed = compress_point(&point).unwrap();
+            assert_eq!(compressed.len(), COMPRESSED_POINT_SIZE);
+            assert_eq!(decompress_point(&compressed).unwrap(), point);
+        }
+    }
+
+    #[test]
+    fn invalid_points_are_rejected() {
+        let point = Hash::new().h1(&[1u8; 32]);
+
+        // not on the curve
+        let mut off_curve = point.clone();
+        off_curve[0] ^= 1;
+        assert!(try_deserialize_jub_jub_affine_point(&off_curve).is_err());
+        assert!(compress_point(&off_curve).is_err());
+
+        // on the curve, but of order 2
+        let low_order = JubJubAffine::new_unchecked(Fr::zero(), -Fr::one());
+        let low_order = serialize_jub_jub_affine_point(&low_order);
+        assert!(try_deserialize_jub_jub_affine_point(&low_order).is_err());
+        let mut compressed = Vec::new();
+        JubJubAffine::new_unchecked(Fr::zero(), -Fr::one())
+            .serialize_compressed(&mut compressed)
+            .unwrap();
+        assert!(decompress_point(&compressed).is_err());
+
+        // non-canonical encodings
+        let mut padded = point.clone();
+        padded[63] |= 0x80;
+        assert!(try_deserialize_jub_jub_affine_point(&padded).is_err());
+        let mut identity = compress_point(&serialize_jub_jub_affine_point(&JubJubAffine::zero()))
+            .unwrap();
+        identity[31] |= 0x80;
+        assert!(decompress_point(&identity).is_err());
+
+        assert!(try_deserialize_jub_jub_affine_point(&point[..32]).is_err());
+        assert!(decompress_point(&[0xffu8; 32]).is_err());
+    }
 }
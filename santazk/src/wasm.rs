@@ -0,0 +1,375 @@
+//! wasm-bindgen exports for making keys, signatures and proofs in a browser.
+//!
+//! ```sh
+//! cargo rustc -p santazk --lib --release --target wasm32-unknown-unknown \
+//!     --no-default-features --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/santazk.wasm
+//! ```
+//!
+//! Only this build asks for a cdylib, so native builds don't link one.
+//!
+//! The proving keys aren't compiled in: pages fetch the `.groth16.pk` files as
+//! bytes and make the provers from them. Points, keys and Merkle paths are byte
+//! arrays in the encodings the rest of santazk and zkretvm use, and lists of
+//! Merkle leaves are their 64-byte encodings concatenated.
+//!
+//! The tests run in Node with `cargo test -p santazk --lib --target
+//! wasm32-unknown-unknown --no-default-features --features wasm`, see
+//! `.cargo/config.toml`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    crypto,
+    hash::Hash,
+    merkle::MerkleTree,
+    participant::{Participant, MERKLE_TREE_DEPTH},
+    proofs::{ChoiceAuthProver, Groth16, RevealAuthProver},
+    serialization::try_deserialize_jub_jub_affine_point,
+};
+
+/// Size of an encoded point, such as a public key or a Merkle leaf.
+const POINT_SIZE: usize = 64;
+/// Size of a chain Id.
+const CHAIN_ID_SIZE: usize = 32;
+/// Size of the Merkle paths the CHOICE proving key is for: the nodes from the
+/// leaf's sibling up to a child of the root, and the leaf index.
+const MERKLE_PATH_SIZE: usize = MERKLE_TREE_DEPTH * POINT_SIZE + 4;
+
+/// Checks that `bytes`, the `name` input of a prover, has the size the proving
+/// key is for. Any other size would make another circuit than the key's.
+fn check_size(bytes: &[u8], size: usize, name: &str) -> Result<(), JsError> {
+    if bytes.len() != size {
+        return Err(JsError::new(&format!("expected a {name} of {size} bytes")));
+    }
+    Ok(())
+}
+
+fn check_point(bytes: &[u8], name: &str) -> Result<(), JsError> {
+    try_deserialize_jub_jub_affine_point(bytes)
+        .map(drop)
+        .map_err(|_| JsError::new(&format!("invalid {name}")))
+}
+
+fn check_merkle_path(merkle_path: &[u8]) -> Result<(), JsError> {
+    check_size(merkle_path, MERKLE_PATH_SIZE, "Merkle path")?;
+    merkle_path[..MERKLE_PATH_SIZE - 4]
+        .chunks(POINT_SIZE)
+        .try_for_each(|node| check_point(node, "Merkle path node"))
+}
+
+/// A participant's secrets and the public key derived from them.
+#[wasm_bindgen]
+pub struct ParticipantKey {
+    secret_key: Vec<u8>,
+    nullifier: Vec<u8>,
+    pub_key: Vec<u8>,
+    dh_pub_key: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl ParticipantKey {
+    #[wasm_bindgen(getter, js_name = secretKey)]
+    pub fn secret_key(&self) -> Vec<u8> {
+        self.secret_key.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn nullifier(&self) -> Vec<u8> {
+        self.nullifier.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = pubKey)]
+    pub fn pub_key(&self) -> Vec<u8> {
+        self.pub_key.clone()
+    }
+
+    #[wasm_bindgen(getter, js_name = dhPubKey)]
+    pub fn dh_pub_key(&self) -> Vec<u8> {
+        self.dh_pub_key.clone()
+    }
+}
+
+/// Generates a key like `zkretctl keygen`, from the browser's secure randomness.
+#[wasm_bindgen(js_name = generateKey)]
+pub fn generate_key() -> ParticipantKey {
+    let participant = Participant::random();
+    ParticipantKey {
+        secret_key: participant.secret_key,
+        nullifier: participant.nullifier,
+        pub_key: participant.pub_key,
+        dh_pub_key: participant.dh_pub_key,
+    }
+}
+
+#[wasm_bindgen(js_name = deriveParticipationPubkey)]
+pub fn derive_participation_pubkey(secret_key: &[u8], nullifier: &[u8]) -> Vec<u8> {
+    crypto::derive_participation_pubkey(&Hash::new(), secret_key, nullifier)
+}
+
+#[wasm_bindgen(js_name = signChoiceTx)]
+pub fn sign_choice_tx(
+    secret_key: &[u8],
+    nullifier: &[u8],
+    chain_id: &[u8],
+    choice: &[u8],
+    dh_pub_key: &[u8],
+) -> Vec<u8> {
+    crypto::sign_choice_tx(
+        &Hash::new(),
+        secret_key,
+        nullifier,
+        chain_id,
+        choice,
+        dh_pub_key,
+    )
+}
+
+#[wasm_bindgen(js_name = signRevealTx)]
+pub fn sign_reveal_tx(
+    secret_key: &[u8],
+    nullifier: &[u8],
+    chain_id: &[u8],
+    ciphertext_hash: &[u8],
+    dh_pub_key: &[u8],
+) -> Vec<u8> {
+    crypto::sign_reveal_tx(
+        &Hash::new(),
+        secret_key,
+        nullifier,
+        chain_id,
+        ciphertext_hash,
+        dh_pub_key,
+    )
+}
+
+/// The hash of a REVEAL ciphertext, which its proof and signature commit to.
+#[wasm_bindgen(js_name = ciphertextHash)]
+pub fn ciphertext_hash(ciphertext: &[u8]) -> Vec<u8> {
+    Hash::new().h1(ciphertext)
+}
+
+fn merkle_tree(depth: usize, leaves: &[u8]) -> Result<MerkleTree, JsError> {
+    if !leaves.len().is_multiple_of(POINT_SIZE) || leaves.len() / POINT_SIZE > 1 << depth {
+        return Err(JsError::new(&format!(
+            "expected at most {} leaves of {POINT_SIZE} bytes",
+            1 << depth
+        )));
+    }
+    let leaves = leaves
+        .chunks(POINT_SIZE)
+        .map(<[u8]>::to_vec)
+        .collect::<Vec<_>>();
+    Ok(MerkleTree::new(depth, &leaves))
+}
+
+#[wasm_bindgen(js_name = merkleRoot)]
+pub fn merkle_root(depth: usize, leaves: &[u8]) -> Result<Vec<u8>, JsError> {
+    Ok(merkle_tree(depth, leaves)?.root())
+}
+
+/// The path of the leaf at `index` to the root, for [`ChoiceProver::prove`].
+#[wasm_bindgen(js_name = merklePath)]
+pub fn merkle_path(depth: usize, leaves: &[u8], index: usize) -> Result<Vec<u8>, JsError> {
+    merkle_tree(depth, leaves)?
+        .generate_proof(index)
+        .ok_or_else(|| JsError::new(&format!("no leaf at index {index}")))
+}
+
+#[wasm_bindgen]
+pub struct ChoiceProver(ChoiceAuthProver);
+
+#[wasm_bindgen]
+impl ChoiceProver {
+    /// Makes a prover from the bytes of `choice_auth.groth16.pk`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(proving_key: &[u8]) -> Result<ChoiceProver, JsError> {
+        let pk = Groth16::deserialize_proving_key(proving_key)?;
+        Ok(Self(ChoiceAuthProver::from_key(pk)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        root: &[u8],
+        merkle_path: &[u8],
+        choice: &[u8],
+        chain_id: &[u8],
+        dh_pub_key: &[u8],
+        signature: &[u8],
+    ) -> Result<Vec<u8>, JsError> {
+        check_size(secret_key, POINT_SIZE, "secret key")?;
+        check_size(nullifier, POINT_SIZE, "nullifier")?;
+        check_point(root, "Merkle root")?;
+        check_merkle_path(merkle_path)?;
+        check_size(choice, POINT_SIZE, "choice")?;
+        check_size(chain_id, CHAIN_ID_SIZE, "chain id")?;
+        check_size(dh_pub_key, POINT_SIZE, "DH public key")?;
+        check_size(signature, POINT_SIZE, "signature")?;
+
+        Ok(self.0.prove(
+            secret_key,
+            nullifier,
+            root,
+            merkle_path,
+            choice,
+            chain_id,
+            dh_pub_key,
+            signature,
+        )?)
+    }
+}
+
+#[wasm_bindgen]
+pub struct RevealProver(RevealAuthProver);
+
+#[wasm_bindgen]
+impl RevealProver {
+    /// Makes a prover from the bytes of `reveal_auth.groth16.pk`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(proving_key: &[u8]) -> Result<RevealProver, JsError> {
+        let pk = Groth16::deserialize_proving_key(proving_key)?;
+        Ok(Self(RevealAuthProver::from_key(pk)))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove(
+        &self,
+        secret_key: &[u8],
+        nullifier: &[u8],
+        pub_key: &[u8],
+        ciphertext_hash: &[u8],
+        chain_id: &[u8],
+        dh_pub_key: &[u8],
+        signature: &[u8],
+    ) -> Result<Vec<u8>, JsError> {
+        check_size(secret_key, POINT_SIZE, "secret key")?;
+        check_size(nullifier, POINT_SIZE, "nullifier")?;
+        check_size(pub_key, POINT_SIZE, "public key")?;
+        check_size(ciphertext_hash, POINT_SIZE, "ciphertext hash")?;
+        check_size(chain_id, CHAIN_ID_SIZE, "chain id")?;
+        check_size(dh_pub_key, POINT_SIZE, "DH public key")?;
+        check_size(signature, POINT_SIZE, "signature")?;
+
+        Ok(self.0.prove(
+            secret_key,
+            nullifier,
+            pub_key,
+            ciphertext_hash,
+            chain_id,
+            dh_pub_key,
+            signature,
+        ))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::proofs::RevealAuthVerifier;
+
+    #[wasm_bindgen_test]
+    fn keys_sign_and_merkle_paths() {
+        let key = generate_key();
+        assert_eq!(
+            derive_participation_pubkey(&key.secret_key(), &key.nullifier()),
+            key.pub_key()
+        );
+        assert_ne!(generate_key().secret_key(), key.secret_key());
+
+        let other = generate_key();
+        let leaves = [key.pub_key(), other.pub_key()].concat();
+        let root = merkle_root(7, &leaves).unwrap_or_else(|_| panic!("two leaves fit"));
+        assert_eq!(
+            root,
+            MerkleTree::new(7, &[key.pub_key(), other.pub_key()]).root()
+        );
+        let path = merkle_path(MERKLE_TREE_DEPTH, &leaves, 1)
+            .unwrap_or_else(|_| panic!("there is a leaf at 1"));
+        assert!(check_merkle_path(&path).is_ok());
+        assert!(merkle_path(7, &leaves[1..], 0).is_err());
+
+        let signature = sign_choice_tx(
+            &key.secret_key(),
+            &key.nullifier(),
+            &[4u8; 32],
+            &other.pub_key(),
+            &key.dh_pub_key(),
+        );
+        assert_eq!(signature.len(), POINT_SIZE);
+    }
+
+    #[wasm_bindgen_test]
+    fn reveal_proofs_verify() {
+        let prover = RevealProver::new(include_bytes!("../params/reveal_auth.groth16.pk"))
+            .unwrap_or_else(|_| panic!("the key is valid"));
+        let key = generate_key();
+        let chain_id = [4u8; 32];
+        let ct_hash = ciphertext_hash(b"ciphertext");
+        let signature = sign_reveal_tx(
+            &key.secret_key(),
+            &key.nullifier(),
+            &chain_id,
+            &ct_hash,
+            &key.dh_pub_key(),
+        );
+
+        let proof = prover
+            .prove(
+                &key.secret_key(),
+                &key.nullifier(),
+                &key.pub_key(),
+                &ct_hash,
+                &chain_id,
+                &key.dh_pub_key(),
+                &signature,
+            )
+            .unwrap_or_else(|_| panic!("the inputs are valid"));
+        assert!(RevealAuthVerifier::new().verify(
+            &proof,
+            &key.pub_key(),
+            &ct_hash,
+            &chain_id,
+            &key.dh_pub_key(),
+            &signature,
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn malformed_choice_inputs_are_errors() {
+        let prover = ChoiceProver::new(include_bytes!("../params/choice_auth.groth16.pk"))
+            .unwrap_or_else(|_| panic!("the key is valid"));
+        let key = generate_key();
+        let leaves = [key.pub_key(), generate_key().pub_key()].concat();
+        let root =
+            merkle_root(MERKLE_TREE_DEPTH, &leaves).unwrap_or_else(|_| panic!("two leaves fit"));
+        let path = merkle_path(MERKLE_TREE_DEPTH, &leaves, 0)
+            .unwrap_or_else(|_| panic!("there is a leaf at 0"));
+        let prove = |root: &[u8], path: &[u8]| {
+            prover
+                .prove(
+                    &key.secret_key(),
+                    &key.nullifier(),
+                    root,
+                    path,
+                    &key.pub_key(),
+                    &[4u8; 32],
+                    &key.dh_pub_key(),
+                    &[0u8; POINT_SIZE],
+                )
+                .is_err()
+        };
+
+        // not a point, of the wrong size, and a path with a node that isn't a point
+        assert!(prove(&[0xffu8; POINT_SIZE], &path));
+        assert!(prove(&root[1..], &path));
+        let mut bad_path = path.clone();
+        bad_path[..POINT_SIZE].fill(0xff);
+        assert!(prove(&root, &bad_path));
+        assert!(prove(&root, &path[..path.len() - 1]));
+    }
+}
@@ -0,0 +1,55 @@
+#!/usr/bin/env bash
+# vendor/ark-marlin is the pinned upstream release below with vendor/ark-marlin.patch
+# applied. This script checks that the vendored copy is exactly that, or rewrites
+# the patch after the vendored copy was changed.
+#
+#   scripts/ark-marlin.sh check
+#   scripts/ark-marlin.sh diff
+#
+# The release is downloaded from crates.io, or read from ARK_MARLIN_CRATE if set,
+# and is checked against its pinned SHA-256 digest either way.
+set -euo pipefail
+
+# ark-marlin 0.3.0 on crates.io, published from arkworks-rs/marlin at
+# ed675e586d5866fda84594371f83fb0c9bd3a0e8
+VERSION=0.3.0
+SHA256=caa8510faa8e64f0a6841ee4b58efe2d56f7a80d86fa0ce9891bbb3aa20166d9
+
+ROOT=$(cd "$(dirname "$0")/.." && pwd)
+VENDORED=$ROOT/vendor/ark-marlin
+PATCH=$ROOT/vendor/ark-marlin.patch
+
+TMP=$(mktemp -d)
+trap 'rm -rf "$TMP"' EXIT
+
+CRATE=${ARK_MARLIN_CRATE:-$TMP/ark-marlin-$VERSION.crate}
+if [ -z "${ARK_MARLIN_CRATE:-}" ]; then
+    curl -sSfL "https://static.crates.io/crates/ark-marlin/ark-marlin-$VERSION.crate" -o "$CRATE"
+fi
+echo "$SHA256  $CRATE" | sha256sum -c --quiet
+
+# the tree as it is at the git revision: crates.io normalizes Cargo.toml and
+# keeps the original as Cargo.toml.orig
+tar xzf "$CRATE" -C "$TMP"
+mkdir "$TMP/a"
+mv "$TMP/ark-marlin-$VERSION"/* "$TMP/a/"
+mv "$TMP/a/Cargo.toml.orig" "$TMP/a/Cargo.toml"
+cp -r "$VENDORED" "$TMP/b"
+
+case "${1:-}" in
+    check)
+        patch -s -p1 -d "$TMP/a" < "$PATCH"
+        find "$TMP/a" -name '*.orig' -delete
+        diff -r "$TMP/a" "$TMP/b"
+        echo "vendor/ark-marlin is ark-marlin $VERSION with vendor/ark-marlin.patch applied"
+        ;;
+    diff)
+        # without the timestamps, so that the patch only changes with the port
+        (cd "$TMP" && diff -ruN a b || true) | sed -E 's/^((---|\+\+\+) [^\t]*)\t.*/\1/' > "$PATCH"
+        echo "wrote vendor/ark-marlin.patch"
+        ;;
+    *)
+        echo "usage: $0 <check|diff>" >&2
+        exit 1
+        ;;
+esac
@@ -2,15 +2,23 @@
 //! To be served via `[HOST]/ext/bc/[CHAIN ID]/rpc`.
 
 use crate::{
-    block::{transaction::Transaction, Block},
+    block::{
+        transaction::{SBytes64, Transaction},
+        Block,
+    },
+    state::index::{paginate, StateIndex, DEFAULT_PAGE_LIMIT},
     vm::Vm,
 };
-use avalanche_types::{ids, proto::http::Element, subnet::rpc::http::handle::Handle};
+use avalanche_types::{
+    ids,
+    proto::http::Element,
+    subnet::rpc::{http::handle::Handle, snow::engine::common::appsender::AppSender},
+};
 use bytes::Bytes;
 use jsonrpc_core::{BoxFuture, Error, ErrorCode, IoHandler, Result};
 use jsonrpc_derive::rpc;
 use serde::{Deserialize, Serialize};
-use std::{borrow::Borrow, io, marker::PhantomData, str::FromStr};
+use std::{borrow::Borrow, io, marker::PhantomData, str::FromStr, sync::Arc};
 
 use super::de_request;
 
@@ -21,6 +29,10 @@ pub trait Rpc {
     #[rpc(name = "ping", alias("zkretvm.ping"))]
     fn ping(&self) -> BoxFuture<Result<crate::api::PingResponse>>;
 
+    /// Fetches the Id of the chain, which ENTER proofs are bound to.
+    #[rpc(name = "getChainId", alias("zkretvm.getChainId"))]
+    fn get_chain_id(&self) -> BoxFuture<Result<GetChainIdResponse>>;
+
     /// Proposes the arbitrary data.
     #[rpc(name = "proposeBlock", alias("zkretvm.proposeBlock"))]
     fn propose_block(&self, args: ProposeBlockArgs) -> BoxFuture<Result<ProposeBlockResponse>>;
@@ -32,6 +44,44 @@ pub trait Rpc {
     /// Fetches the block.
     #[rpc(name = "getBlock", alias("zkretvm.getBlock"))]
     fn get_block(&self, args: GetBlockArgs) -> BoxFuture<Result<GetBlockResponse>>;
+
+    /// Fetches a page of the public keys that have not been chosen yet.
+    #[rpc(name = "getUnclaimedPubKeys", alias("zkretvm.getUnclaimedPubKeys"))]
+    fn get_unclaimed_pub_keys(&self, args: PageArgs) -> BoxFuture<Result<PubKeysResponse>>;
+
+    /// Fetches a page of the Merkle leaves, i.e. every entered public key.
+    #[rpc(name = "getMerkleLeaves", alias("zkretvm.getMerkleLeaves"))]
+    fn get_merkle_leaves(&self, args: PageArgs) -> BoxFuture<Result<MerkleLeavesResponse>>;
+
+    /// Fetches the Merkle path of an entered public key.
+    /// Note that this tells the node which key is about to make a choice;
+    /// privacy-conscious clients should build the path from `getMerkleLeaves`.
+    #[rpc(name = "getMerklePath", alias("zkretvm.getMerklePath"))]
+    fn get_merkle_path(&self, args: PubKeyArgs) -> BoxFuture<Result<MerklePathResponse>>;
+
+    /// Fetches a page of the invite commitments of an invite-only group.
+    #[rpc(name = "getInvites", alias("zkretvm.getInvites"))]
+    fn get_invites(&self, args: PageArgs) -> BoxFuture<Result<InvitesResponse>>;
+
+    /// Checks whether an invite nullifier has been used by an ENTER transaction.
+    #[rpc(name = "isInviteSpent", alias("zkretvm.isInviteSpent"))]
+    fn is_invite_spent(&self, args: NullifierArgs) -> BoxFuture<Result<IsNullifierSpentResponse>>;
+
+    /// Checks whether a nullifier has been used by a CHOICE transaction.
+    #[rpc(name = "isNullifierSpent", alias("zkretvm.isNullifierSpent"))]
+    fn is_nullifier_spent(
+        &self,
+        args: NullifierArgs,
+    ) -> BoxFuture<Result<IsNullifierSpentResponse>>;
+
+    /// Fetches the ciphertext revealed by a public key, if any.
+    #[rpc(name = "getRevealFor", alias("zkretvm.getRevealFor"))]
+    fn get_reveal_for(&self, args: PubKeyArgs) -> BoxFuture<Result<GetRevealForResponse>>;
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetChainIdResponse {
+    pub chain_id: ids::Id,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -63,6 +113,94 @@ pub struct GetBlockResponse {
     pub block: Block,
 }
 
+fn default_page_limit() -> usize {
+    DEFAULT_PAGE_LIMIT
+}
+
+/// Selects a page of a list held in state.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PageArgs {
+    #[serde(default)]
+    pub offset: usize,
+    /// Number of items to return, from 1 to [`MAX_PAGE_LIMIT`](crate::state::index::MAX_PAGE_LIMIT).
+    #[serde(default = "default_page_limit")]
+    pub limit: usize,
+}
+
+impl Default for PageArgs {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: DEFAULT_PAGE_LIMIT,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PubKeysResponse {
+    /// Id of the accepted block the page was read from.
+    pub block_id: ids::Id,
+    pub pub_keys: Vec<SBytes64>,
+    pub total: usize,
+    /// Offset of the next page, if there is one.
+    pub next_offset: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerkleLeavesResponse {
+    /// Id of the accepted block the page was read from.
+    pub block_id: ids::Id,
+    pub merkle_root: SBytes64,
+    pub leaves: Vec<SBytes64>,
+    pub total: usize,
+    /// Offset of the next page, if there is one.
+    pub next_offset: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PubKeyArgs {
+    pub pub_key: SBytes64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct MerklePathResponse {
+    pub block_id: ids::Id,
+    pub merkle_root: SBytes64,
+    pub leaf_index: usize,
+    /// Serialized Merkle path, as consumed by the CHOICE prover.
+    pub path: Vec<u8>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct InvitesResponse {
+    /// Id of the accepted block the page was read from.
+    pub block_id: ids::Id,
+    /// Root of the allowlist Merkle tree, or none if anyone can ENTER.
+    pub invite_root: Option<SBytes64>,
+    pub invites: Vec<SBytes64>,
+    pub total: usize,
+    /// Offset of the next page, if there is one.
+    pub next_offset: Option<usize>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NullifierArgs {
+    pub nullifier: SBytes64,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct IsNullifierSpentResponse {
+    pub block_id: ids::Id,
+    pub spent: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct GetRevealForResponse {
+    pub block_id: ids::Id,
+    /// Revealed ciphertext, or none if the key has not revealed yet.
+    pub ct: Option<Vec<u8>>,
+}
+
 /// Implements API services for the chain-specific handlers.
 #[derive(Clone)]
 pub struct ChainService<A> {
@@ -77,13 +215,24 @@ impl<A> ChainService<A> {
 
 impl<A> Rpc for ChainService<A>
 where
-    A: Send + Sync + Clone + 'static,
+    A: AppSender + Send + Sync + Clone + 'static,
 {
     fn ping(&self) -> BoxFuture<Result<crate::api::PingResponse>> {
         log::debug!("ping called");
         Box::pin(async move { Ok(crate::api::PingResponse { success: true }) })
     }
 
+    fn get_chain_id(&self) -> BoxFuture<Result<GetChainIdResponse>> {
+        log::debug!("get_chain_id called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            Ok(GetChainIdResponse {
+                chain_id: vm.chain_id().await,
+            })
+        })
+    }
+
     fn propose_block(&self, args: ProposeBlockArgs) -> BoxFuture<Result<ProposeBlockResponse>> {
         log::debug!("propose_block called");
         let vm = self.vm.clone();
@@ -143,6 +292,137 @@ where
             })
         })
     }
+
+    fn get_unclaimed_pub_keys(&self, args: PageArgs) -> BoxFuture<Result<PubKeysResponse>> {
+        log::debug!("get_unclaimed_pub_keys called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            let upks = &index.block_state().unclaimed_pub_keys;
+            let (pub_keys, next_offset) = paginate(upks, args.offset, args.limit);
+
+            Ok(PubKeysResponse {
+                block_id: index.block_id(),
+                pub_keys,
+                total: upks.len(),
+                next_offset,
+            })
+        })
+    }
+
+    fn get_merkle_leaves(&self, args: PageArgs) -> BoxFuture<Result<MerkleLeavesResponse>> {
+        log::debug!("get_merkle_leaves called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            let block_state = index.block_state();
+            let (leaves, next_offset) =
+                paginate(&block_state.merkle_leaves, args.offset, args.limit);
+
+            Ok(MerkleLeavesResponse {
+                block_id: index.block_id(),
+                merkle_root: block_state.merkle_root,
+                leaves,
+                total: block_state.merkle_leaves.len(),
+                next_offset,
+            })
+        })
+    }
+
+    fn get_merkle_path(&self, args: PubKeyArgs) -> BoxFuture<Result<MerklePathResponse>> {
+        log::debug!("get_merkle_path called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            let (leaf_index, path) = index
+                .merkle_path(&args.pub_key)
+                .ok_or_else(|| Error::invalid_params("public key has not entered"))?;
+
+            Ok(MerklePathResponse {
+                block_id: index.block_id(),
+                merkle_root: index.block_state().merkle_root,
+                leaf_index,
+                path,
+            })
+        })
+    }
+
+    fn get_invites(&self, args: PageArgs) -> BoxFuture<Result<InvitesResponse>> {
+        log::debug!("get_invites called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            let block_state = index.block_state();
+            let (invites, next_offset) = paginate(&block_state.invites, args.offset, args.limit);
+
+            Ok(InvitesResponse {
+                block_id: index.block_id(),
+                invite_root: block_state.invite_root,
+                invites,
+                total: block_state.invites.len(),
+                next_offset,
+            })
+        })
+    }
+
+    fn is_invite_spent(&self, args: NullifierArgs) -> BoxFuture<Result<IsNullifierSpentResponse>> {
+        log::debug!("is_invite_spent called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            Ok(IsNullifierSpentResponse {
+                block_id: index.block_id(),
+                spent: index.is_invite_spent(&args.nullifier),
+            })
+        })
+    }
+
+    fn is_nullifier_spent(
+        &self,
+        args: NullifierArgs,
+    ) -> BoxFuture<Result<IsNullifierSpentResponse>> {
+        log::debug!("is_nullifier_spent called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            Ok(IsNullifierSpentResponse {
+                block_id: index.block_id(),
+                spent: index.is_nullifier_spent(&args.nullifier),
+            })
+        })
+    }
+
+    fn get_reveal_for(&self, args: PubKeyArgs) -> BoxFuture<Result<GetRevealForResponse>> {
+        log::debug!("get_reveal_for called");
+        let vm = self.vm.clone();
+
+        Box::pin(async move {
+            let index = state_index(&vm).await?;
+            Ok(GetRevealForResponse {
+                block_id: index.block_id(),
+                ct: index.reveal_for(&args.pub_key).map(<[u8]>::to_vec),
+            })
+        })
+    }
+}
+
+/// Returns the index over the state of the last accepted block.
+async fn state_index<A>(vm: &Vm<A>) -> Result<Arc<StateIndex>> {
+    let vm_state = vm.state.read().await;
+    match &vm_state.state {
+        Some(state) => state.index().await.map_err(create_jsonrpc_error),
+        None => Err(Error {
+            code: ErrorCode::InternalError,
+            message: String::from("no state manager found"),
+            data: None,
+        }),
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -1,4 +1,5 @@
 pub mod chain_handlers;
+pub mod server;
 pub mod static_handlers;
 
 use std::io;
@@ -0,0 +1,93 @@
+//! Serves the chain-specific handlers over plain HTTP, without avalanchego.
+
+use std::{
+    convert::Infallible,
+    future::Future,
+    io::{self, Error, ErrorKind},
+    net::SocketAddr,
+};
+
+use avalanche_types::subnet::rpc::http::handle::Handle;
+use hyper::{
+    body,
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+
+/// Returns the path avalanchego serves the chain handlers of `chain_id` at.
+#[must_use]
+pub fn rpc_path(chain_id: &str) -> String {
+    format!("/ext/bc/{chain_id}/rpc")
+}
+
+/// Binds `addr` and serves `handler` at the avalanchego path for `chain_id`, so that
+/// [`ZkretClient`](crate::client::ZkretClient) works unchanged, until `shutdown` resolves.
+/// Returns the bound address and the server future.
+/// # Errors
+/// Fails if `addr` can't be bound.
+pub fn serve<H>(
+    addr: SocketAddr,
+    chain_id: &str,
+    handler: H,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> io::Result<(SocketAddr, impl Future<Output = io::Result<()>>)>
+where
+    H: Handle + Clone + Send + Sync + 'static,
+{
+    let path = rpc_path(chain_id);
+    let make_svc = make_service_fn(move |_| {
+        let handler = handler.clone();
+        let path = path.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                let handler = handler.clone();
+                let path = path.clone();
+                async move { Ok::<_, Infallible>(respond(&handler, &path, req).await) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)
+        .map_err(|e| Error::new(ErrorKind::AddrInUse, format!("failed to bind {addr} {e}")))?
+        .serve(make_svc);
+    let local_addr = server.local_addr();
+    log::info!("serving chain handlers at http://{local_addr}{}", rpc_path(chain_id));
+
+    let server = async move {
+        server
+            .with_graceful_shutdown(shutdown)
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("http server failed {e}")))
+    };
+    Ok((local_addr, server))
+}
+
+async fn respond<H: Handle>(handler: &H, path: &str, req: Request<Body>) -> Response<Body> {
+    if req.uri().path() != path {
+        return status_response(StatusCode::NOT_FOUND, "not found");
+    }
+    if req.method() != Method::POST {
+        return status_response(StatusCode::METHOD_NOT_ALLOWED, "method not allowed");
+    }
+
+    let req_body = match body::to_bytes(req.into_body()).await {
+        Ok(b) => b,
+        Err(e) => return status_response(StatusCode::BAD_REQUEST, &e.to_string()),
+    };
+
+    match handler.request(&req_body, &[]).await {
+        Ok((resp, _)) => Response::builder()
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(resp))
+            .expect("valid response"),
+        Err(e) => status_response(StatusCode::BAD_REQUEST, &e.to_string()),
+    }
+}
+
+fn status_response(status: StatusCode, msg: &str) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .body(Body::from(msg.to_string()))
+        .expect("valid response")
+}
@@ -0,0 +1,372 @@
+//! Offline audit of a whole chain, for organisers to show that a round was fair.
+//!
+//! A [`ChainExport`] holds every accepted block from the genesis block to the
+//! last accepted block, as the bytes their Ids are derived from. [`audit`] replays
+//! them the way validators verified them, proofs included, and sums the round up
+//! in an [`AuditReport`] that the organiser signs with a secp256k1 key, so that
+//! anyone holding the export can check the report against it.
+
+use std::io::{self, Error, ErrorKind};
+
+use avalanche_types::{hash, ids, key::secp256k1};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    block::{transaction::SBytes64, Block},
+    client::ZkretClient,
+    state::State,
+};
+
+/// Magic bytes a chain export starts with, versioning its format.
+const EXPORT_MAGIC: &[u8; 16] = b"ZKRETVM_CHAIN_V1";
+
+/// Every accepted block of a chain.
+/// It is encoded as [`EXPORT_MAGIC`], the chain Id and then the bytes of each
+/// block, oldest first, prefixed with their length as a big endian u32.
+#[derive(Debug, Clone)]
+pub struct ChainExport {
+    /// Id of the chain that transaction proofs are bound to.
+    pub chain_id: ids::Id,
+    /// Accepted blocks, from the genesis block to the last accepted block.
+    pub blocks: Vec<Block>,
+}
+
+impl ChainExport {
+    /// Walks `state` back from its last accepted block to the genesis block.
+    /// # Errors
+    /// Fails if no block has been accepted or if a block can't be read.
+    pub async fn from_state(state: &State) -> io::Result<Self> {
+        if !state.has_last_accepted_block().await? {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                "no block has been accepted yet",
+            ));
+        }
+
+        let mut blocks = Vec::new();
+        let mut id = state.get_last_accepted_block_id().await?;
+        loop {
+            let block = state.get_block(&id).await?;
+            let is_genesis = block.height() == 0;
+            id = block.parent_id();
+            blocks.push(block);
+            if is_genesis {
+                break;
+            }
+        }
+
+        blocks.reverse();
+        Ok(Self {
+            chain_id: state.chain_id,
+            blocks,
+        })
+    }
+
+    /// Fetches every accepted block of the chain `client` is for, from a
+    /// running node.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn from_client(client: &ZkretClient) -> io::Result<Self> {
+        Ok(Self {
+            chain_id: client.chain_id().await?,
+            blocks: client.accepted_blocks().await?,
+        })
+    }
+
+    /// Encodes the export.
+    /// # Errors
+    /// Fails if a block is larger than 4 GiB.
+    pub fn to_vec(&self) -> io::Result<Vec<u8>> {
+        let mut b = Vec::new();
+        b.extend_from_slice(EXPORT_MAGIC);
+        b.extend_from_slice(&self.chain_id.to_vec());
+        for block in &self.blocks {
+            let len = u32::try_from(block.bytes().len()).map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("block {} is too large to export", block.id()),
+                )
+            })?;
+            b.extend_from_slice(&len.to_be_bytes());
+            b.extend_from_slice(block.bytes());
+        }
+        Ok(b)
+    }
+
+    /// Loads an export, deriving the Id of each block from its bytes.
+    /// # Errors
+    /// Fails if `d` isn't an export, is truncated or holds no block.
+    pub fn from_slice(d: impl AsRef<[u8]>) -> io::Result<Self> {
+        let mut rest = d
+            .as_ref()
+            .strip_prefix(EXPORT_MAGIC.as_slice())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "not a chain export"))?;
+
+        let chain_id = ids::Id::from_slice(take(&mut rest, ids::LEN)?);
+        let mut blocks = Vec::new();
+        while !rest.is_empty() {
+            let len: [u8; 4] = take(&mut rest, 4)?.try_into().map_err(|_| truncated())?;
+            blocks.push(Block::from_slice(take(
+                &mut rest,
+                u32::from_be_bytes(len) as usize,
+            )?)?);
+        }
+        if blocks.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "the chain export holds no block",
+            ));
+        }
+
+        Ok(Self { chain_id, blocks })
+    }
+}
+
+fn truncated() -> Error {
+    Error::new(ErrorKind::UnexpectedEof, "the chain export is truncated")
+}
+
+/// Splits the first `n` bytes off `rest`.
+fn take<'a>(rest: &mut &'a [u8], n: usize) -> io::Result<&'a [u8]> {
+    if rest.len() < n {
+        return Err(truncated());
+    }
+    let (head, tail) = rest.split_at(n);
+    *rest = tail;
+    Ok(head)
+}
+
+/// A check that a block of the chain failed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Anomaly {
+    pub height: u64,
+    pub block_id: ids::Id,
+    pub message: String,
+}
+
+impl Anomaly {
+    fn new(block: &Block, e: &Error) -> Self {
+        Self {
+            height: block.height(),
+            block_id: block.id(),
+            message: e.to_string(),
+        }
+    }
+}
+
+/// What [`audit`] found replaying a chain.
+/// The counts and nullifiers are those of the state of the last block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditReport {
+    pub chain_id: ids::Id,
+    pub genesis_block_id: ids::Id,
+    pub last_block_id: ids::Id,
+    /// Number of blocks replayed, the genesis block included.
+    pub blocks: usize,
+
+    /// Number of participants who entered the group.
+    pub participants: usize,
+    /// Number of invites in the allowlist, zero if the group is open.
+    pub invites: usize,
+    /// Number of CHOICE transactions.
+    pub choices: usize,
+    /// Number of REVEAL transactions.
+    pub reveals: usize,
+
+    /// Nullifiers spent by CHOICE transactions, hex-encoded.
+    pub nullifiers: Vec<String>,
+    /// Nullifiers of the invites redeemed by ENTER transactions, hex-encoded.
+    pub invite_nullifiers: Vec<String>,
+
+    /// Checks that failed, by height. A fair round has none.
+    pub anomalies: Vec<Anomaly>,
+}
+
+/// Replays every block of `export` on top of its parent: the first block must
+/// be a genesis block, the transaction of every other block must verify against
+/// the state of its parent and lead to its state, and the state of every block
+/// must match its Merkle roots. Blocks are checked against the state their parent
+/// embeds, so each anomaly is reported on the block it is in.
+/// # Errors
+/// Fails if the export holds no block.
+pub fn audit(export: &ChainExport) -> io::Result<AuditReport> {
+    let (Some(genesis), Some(last)) = (export.blocks.first(), export.blocks.last()) else {
+        return Err(Error::new(ErrorKind::InvalidInput, "no blocks to audit"));
+    };
+
+    let mut anomalies = Vec::new();
+    if let Err(e) = genesis.verify_genesis() {
+        anomalies.push(Anomaly::new(genesis, &e));
+    }
+    if let Err(e) = genesis.block_state().verify_roots() {
+        anomalies.push(Anomaly::new(genesis, &e));
+    }
+    for pair in export.blocks.windows(2) {
+        if let Err(e) = pair[1].verify_against_parent(&pair[0], &export.chain_id) {
+            anomalies.push(Anomaly::new(&pair[1], &e));
+        }
+        if let Err(e) = pair[1].block_state().verify_roots() {
+            anomalies.push(Anomaly::new(&pair[1], &e));
+        }
+    }
+
+    let to_hex = |points: &[SBytes64]| {
+        points
+            .iter()
+            .map(|point| hex::encode(point.to_vec()))
+            .collect()
+    };
+    let state = last.block_state();
+    Ok(AuditReport {
+        chain_id: export.chain_id,
+        genesis_block_id: genesis.id(),
+        last_block_id: last.id(),
+        blocks: export.blocks.len(),
+        participants: state.merkle_leaves.len(),
+        invites: state.invites.len(),
+        choices: state.nullifiers.len(),
+        reveals: state.revealed_pub_keys.len(),
+        nullifiers: to_hex(&state.nullifiers),
+        invite_nullifiers: to_hex(&state.invite_nullifiers),
+        anomalies,
+    })
+}
+
+/// An [`AuditReport`] with the signature of the organiser who ran the audit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedReport {
+    pub report: AuditReport,
+    /// Compressed secp256k1 public key of the signer, hex-encoded.
+    pub public_key: String,
+    /// Recoverable signature of the SHA-256 digest of the JSON encoding of the
+    /// report, hex-encoded.
+    pub signature: String,
+}
+
+impl AuditReport {
+    fn digest(&self) -> io::Result<Vec<u8>> {
+        let report = serde_json::to_vec(self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize AuditReport to JSON bytes {e}"),
+            )
+        })?;
+        Ok(hash::sha256(report))
+    }
+
+    /// Signs the report with `key`.
+    /// # Errors
+    /// Fails if the report can't be serialized or signed.
+    pub fn sign(self, key: &secp256k1::private_key::Key) -> io::Result<SignedReport> {
+        let signature = key
+            .sign_digest(&self.digest()?)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to sign the report {e}")))?;
+
+        Ok(SignedReport {
+            public_key: hex::encode(key.to_public_key().to_compressed_bytes()),
+            signature: hex::encode(signature.to_bytes()),
+            report: self,
+        })
+    }
+}
+
+impl SignedReport {
+    /// Checks that the report was signed by the key in `public_key`.
+    /// # Errors
+    /// Fails if the key or the signature can't be decoded or if the signature is invalid.
+    pub fn verify(&self) -> io::Result<()> {
+        let invalid = |what: &str| Error::new(ErrorKind::InvalidData, format!("invalid {what}"));
+
+        let public_key = hex::decode(&self.public_key)
+            .ok()
+            .and_then(|b| secp256k1::public_key::Key::from_sec1_bytes(&b).ok())
+            .ok_or_else(|| invalid("public key"))?;
+        let signature = hex::decode(&self.signature).map_err(|_| invalid("signature"))?;
+
+        match public_key.verify(&self.report.digest()?, &signature) {
+            Ok(true) => Ok(()),
+            _ => Err(invalid("signature")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        block::transaction::BlockState,
+        testing::{self, child_block, child_block_with_state, genesis_block},
+    };
+
+    #[tokio::test]
+    async fn exported_chains_are_audited_and_signed() {
+        let mut state = State::default();
+        state.chain_id = ids::Id::from_slice(&[7u8; 32]);
+        assert!(ChainExport::from_state(&state).await.is_err());
+
+        let genesis = genesis_block(b"test");
+        let tx = testing::enter_tx(&testing::participant(1), &state.chain_id);
+        let entered = child_block(&genesis, tx);
+        state.write_accepted_block(&genesis).await.unwrap();
+        state.write_accepted_block(&entered).await.unwrap();
+
+        let export = ChainExport::from_state(&state).await.unwrap();
+        let export = ChainExport::from_slice(export.to_vec().unwrap()).unwrap();
+        assert_eq!(export.chain_id, state.chain_id);
+        assert_eq!(
+            export.blocks.iter().map(Block::id).collect::<Vec<_>>(),
+            vec![genesis.id(), entered.id()]
+        );
+        assert!(ChainExport::from_slice(&export.to_vec().unwrap()[..40]).is_err());
+
+        let report = audit(&export).unwrap();
+        assert_eq!(report.last_block_id, entered.id());
+        assert_eq!(
+            (report.blocks, report.participants, report.choices),
+            (2, 1, 0)
+        );
+        assert!(report.anomalies.is_empty());
+
+        let key = secp256k1::private_key::Key::generate().unwrap();
+        let mut signed = report.sign(&key).unwrap();
+        assert!(signed.verify().is_ok());
+        signed.report.participants = 2;
+        assert!(signed.verify().is_err());
+
+        // proofs are bound to the chain
+        let other_chain = ChainExport {
+            chain_id: ids::Id::from_slice(&[8u8; 32]),
+            ..export
+        };
+        assert_eq!(audit(&other_chain).unwrap().anomalies.len(), 1);
+    }
+
+    #[test]
+    fn forged_states_are_reported() {
+        let chain_id = ids::Id::from_slice(&[7u8; 32]);
+        let genesis = genesis_block(b"test");
+        let tx = testing::enter_tx(&testing::participant(1), &chain_id);
+
+        // a leaf added without an ENTER
+        let mut block_state = BlockState::default();
+        tx.update_state(&mut block_state);
+        block_state
+            .merkle_leaves
+            .push(SBytes64::from_bytes(&[3u8; 64]));
+        let forged = child_block_with_state(&genesis, tx, block_state);
+
+        let report = audit(&ChainExport {
+            chain_id,
+            blocks: vec![genesis, forged.clone()],
+        })
+        .unwrap();
+        assert_eq!(report.participants, 2);
+        let anomalies: Vec<_> = report.anomalies.iter().map(|a| a.height).collect();
+        assert_eq!(anomalies, vec![1, 1]);
+        assert_eq!(report.anomalies[1].block_id, forged.id());
+        assert_eq!(
+            report.anomalies[1].message,
+            "Merkle leaves don't match the Merkle root"
+        );
+    }
+}
@@ -2,8 +2,9 @@ use clap::{arg, Command};
 use std::io;
 use zkretvm::block::transaction::SBytes64;
 
+use crate::config::RpcOptions;
 use crate::keygen::read_key;
-use crate::utils::RpcClient;
+use crate::utils::{client_for_key, verified_state};
 
 pub const NAME: &str = "checkmysanta";
 
@@ -14,11 +15,12 @@ pub fn command() -> Command {
         .arg(arg!(-k [KEY_PATH] "zkret key path"))
 }
 
-pub async fn check_santa(key_path: &str) -> io::Result<()> {
-    let key = read_key(key_path);
-    let client = RpcClient::new(key.chain_id.as_str());
+pub async fn check_santa(key_path: &str, opts: &RpcOptions) -> io::Result<()> {
+    let key = read_key(key_path)?;
+    let client = client_for_key(&key, opts)?;
 
-    let upks = client.get_current_unclaimed_pub_keys().await?;
+    let state = verified_state(&client, &key.chain_id, key.checkpoint.as_ref(), opts).await?;
+    let upks = &state.block_state().unclaimed_pub_keys;
     upks.iter().position(|upk| *upk == SBytes64::from_bytes(&key.pub_key))
         .map(|_| println!("You don't have a santa yet!"))
         .unwrap_or_else(|| println!("You have a santa! You should complete the REVEAL phase to let them know your information."));
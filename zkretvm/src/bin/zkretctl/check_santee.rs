@@ -1,9 +1,10 @@
 use clap::{arg, Command};
+use santazk::participant::Participant;
 use std::io;
-use zkretvm::block::transaction::SBytes64;
 
+use crate::config::RpcOptions;
 use crate::keygen::read_key;
-use crate::utils::RpcClient;
+use crate::utils::{client_for_key, verified_state};
 
 pub const NAME: &str = "checkmysantee";
 
@@ -14,18 +15,17 @@ pub fn command() -> Command {
         .arg(arg!(-k [KEY_PATH] "zkret key path"))
 }
 
-pub async fn check_santee(key_path: &str) -> io::Result<()> {
-    let key = read_key(key_path);
-    let client = RpcClient::new(key.chain_id.as_str());
+pub async fn check_santee(key_path: &str, opts: &RpcOptions) -> io::Result<()> {
+    let key = read_key(key_path)?;
+    let client = client_for_key(&key, opts)?;
 
-    let rpks = client.get_current_revealed_pub_keys().await?;
-    let rcts = client.get_current_revealed_cts().await?;
-    rpks.iter()
-        .position(|rpk| *rpk == SBytes64::from_bytes(&key.chosen_pub_key))
-        .map(|i| {
+    let state = verified_state(&client, &key.chain_id, key.checkpoint.as_ref(), opts).await?;
+
+    Participant::find_reveal(&state.protocol_state(), &key.chosen_pub_key)
+        .map(|ct| {
             println!(
                 "Your santee has revealed their information. This is what they said:\n{}",
-                String::from_utf8(rcts[i].clone()).unwrap()
+                String::from_utf8(ct).unwrap()
             )
         })
         .unwrap_or_else(|| println!("Your santee has not revealed their information yet."));
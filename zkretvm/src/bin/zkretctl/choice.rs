@@ -1,17 +1,29 @@
 use std::io;
 
+use avalanche_types::ids;
 use clap::{arg, Command};
-use santazk::{crypto::sign_choice_tx, hash::Hash, merkle::MerkleTree, proofs::ChoiceAuthProver};
-use zkretvm::block::transaction::{SBytes64, Transaction, TransactionData};
+use santazk::{participant::ProtocolState, proofs::ChoiceAuthProver};
+use zkretvm::{
+    block::transaction::{SBytes64, Transaction},
+    client::ZkretClient,
+};
 
 use crate::{
-    keygen::read_key,
-    utils::{printable_to_pub_key, pub_key_to_printable, RpcClient},
+    config::RpcOptions,
+    keygen::{read_key, ZkretKey},
+    keystore,
+    offline::{read_json, write_json, wrong_bundle, Bundle, TxFile},
+    utils::{
+        client_for_key, print_proving_stage, printable_to_pub_key, pub_key_to_printable,
+        verified_state,
+    },
 };
 
 pub const NAME: &str = "choice";
 pub const CHOICE_LIST: &str = "list";
 pub const CHOICE_MAKE: &str = "make";
+pub const CHOICE_PREPARE: &str = "prepare";
+pub const CHOICE_PROVE: &str = "prove";
 
 #[must_use]
 pub fn list_command() -> Command {
@@ -28,18 +40,42 @@ pub fn make_command() -> Command {
         .arg(arg!(<CHOICE> "Chosen public key"))
 }
 
+#[must_use]
+pub fn prepare_command() -> Command {
+    Command::new(CHOICE_PREPARE)
+        .about("Fetch what a choice is proven against, to prove it on an offline machine")
+        .arg(arg!(-o [BUNDLE_PATH] "Output bundle path"))
+        .arg(arg!(<CHAIN_ID> "Chain ID"))
+        .arg(arg!(<CHOICE> "Chosen public key"))
+}
+
+#[must_use]
+pub fn prove_command() -> Command {
+    Command::new(CHOICE_PROVE)
+        .about("Make a choice from a bundle without going online")
+        .arg(arg!(-k [KEY_PATH] "zkret key path"))
+        .arg(arg!(--bundle <BUNDLE_PATH> "Bundle made by `choice prepare`"))
+        .arg(arg!(-o [TX_PATH] "Output transaction path"))
+}
+
 #[must_use]
 pub fn command() -> Command {
     Command::new(NAME)
         .about("Generate a new zkret key")
-        .subcommands(vec![list_command(), make_command()])
+        .subcommands(vec![
+            list_command(),
+            make_command(),
+            prepare_command(),
+            prove_command(),
+        ])
 }
 
-pub async fn list_choices(key_path: &str) -> io::Result<()> {
-    let key = read_key(key_path);
-    let client = RpcClient::new(key.chain_id.as_str());
+pub async fn list_choices(key_path: &str, opts: &RpcOptions) -> io::Result<()> {
+    let key = read_key(key_path)?;
+    let client = client_for_key(&key, opts)?;
 
-    let mut upks = client.get_current_unclaimed_pub_keys().await?;
+    let state = verified_state(&client, &key.chain_id, key.checkpoint.as_ref(), opts).await?;
+    let mut upks = state.block_state().unclaimed_pub_keys.clone();
     upks.retain(|upk| *upk != SBytes64::from_bytes(&key.pub_key));
 
     for upk in upks {
@@ -49,63 +85,120 @@ pub async fn list_choices(key_path: &str) -> io::Result<()> {
     Ok(())
 }
 
-pub async fn do_choice_make(key_path: &str, choice: &str) -> io::Result<()> {
-    let mut key = read_key(key_path);
-    let choice = printable_to_pub_key(choice).to_vec();
-
-    let client = RpcClient::new(key.chain_id.as_str());
-
-    let merkle_leaves = client.get_current_merkle_leaves().await?;
-    let leaves = merkle_leaves
-        .iter()
-        .map(SBytes64::to_vec)
-        .collect::<Vec<Vec<u8>>>();
-
-    let mt = MerkleTree::new(7, &leaves);
-    let merkle_path = mt
-        .generate_proof(leaves.iter().position(|r| *r == key.pub_key).unwrap())
-        .unwrap();
-    let root = mt.root();
-
-    let hasher = Hash::new();
-    let signature = sign_choice_tx(
-        &hasher,
-        &key.secret_key,
-        &key.nullifier,
-        &choice,
-        &key.dh_pub_key,
-    );
+pub async fn do_choice_make(key_path: &str, opts: &RpcOptions, choice: &str) -> io::Result<()> {
+    let (store, mut key) = keystore::unlock(key_path)?;
 
-    println!("Generating ZK proof...");
-    let ca_prover = ChoiceAuthProver::new();
-    let proof = ca_prover.prove(
-        &key.secret_key,
-        &key.nullifier,
-        &root,
-        &merkle_path,
-        &choice,
-        &key.dh_pub_key,
-        &signature,
-    );
-
-    let tx = Transaction {
-        transaction_type: 2,
-        data: TransactionData(
-            SBytes64::from_bytes(choice.as_slice()),
-            SBytes64::from_bytes(key.nullifier.as_slice()),
-            SBytes64::from_bytes(key.dh_pub_key.as_slice()),
-            SBytes64::from_bytes(signature.as_slice()),
-            proof,
-            Vec::new(),
-        ),
-    };
+    let choice = printable_to_pub_key(choice)?;
+
+    let client = client_for_key(&key, opts)?;
+    let bundle = fetch_bundle(
+        &client,
+        &key.chain_id,
+        key.checkpoint.as_ref(),
+        opts,
+        choice,
+    )
+    .await?;
+    let tx = prove(&key, &bundle)?;
 
     println!("Sending CHOICE transaction...");
-    client.push_tx(tx).await?;
+    client.propose_block(&tx).await?;
     println!("Done.");
 
-    key.chosen_pub_key = choice;
-    std::fs::write(key_path, serde_json::to_string(&key).unwrap()).unwrap();
+    key.chosen_pub_key = choice.to_vec();
+    store.save(key_path, &key)?;
+
+    Ok(())
+}
+
+/// Writes the public state a CHOICE of `choice` is proven against to `bundle_path`,
+/// for [`do_choice_prove`] to use offline.
+pub async fn do_choice_prepare(
+    chain_id: &str,
+    opts: &RpcOptions,
+    choice: &str,
+    bundle_path: &str,
+) -> io::Result<()> {
+    let choice = printable_to_pub_key(choice)?;
+
+    let client = ZkretClient::new(&opts.rpc_url_for(""), chain_id, opts.client_options())?;
+    let bundle = fetch_bundle(&client, chain_id, None, opts, choice).await?;
+    write_json(bundle_path, &bundle)?;
+    println!("Wrote {bundle_path}, prove it offline with `zkretctl choice prove --bundle`.");
 
     Ok(())
 }
+
+/// Proves the CHOICE of the bundle at `bundle_path` without going online, and
+/// writes the transaction to `tx_path`.
+pub fn do_choice_prove(key_path: &str, bundle_path: &str, tx_path: &str) -> io::Result<()> {
+    let (store, mut key) = keystore::unlock(key_path)?;
+    let bundle = read_json(bundle_path)?;
+    let Bundle::Choice {
+        chain_id, choice, ..
+    } = &bundle
+    else {
+        return Err(wrong_bundle(NAME));
+    };
+
+    let transaction = prove(&key, &bundle)?;
+    write_json(
+        tx_path,
+        &TxFile {
+            chain_id: *chain_id,
+            transaction,
+        },
+    )?;
+    key.chosen_pub_key = choice.to_vec();
+    println!("Wrote {tx_path}, send it with `zkretctl tx submit`.");
+
+    store.save(key_path, &key)?;
+
+    Ok(())
+}
+
+/// Fetches the Merkle leaves of the participants, to build the Merkle path locally
+/// so that the node doesn't learn who is choosing.
+async fn fetch_bundle(
+    client: &ZkretClient,
+    chain_id: &str,
+    pinned: Option<&ids::Id>,
+    opts: &RpcOptions,
+    choice: SBytes64,
+) -> io::Result<Bundle> {
+    let state = verified_state(client, chain_id, pinned, opts).await?;
+    let block_state = state.block_state();
+
+    Ok(Bundle::Choice {
+        chain_id: state.chain_id(),
+        choice,
+        merkle_root: block_state.merkle_root,
+        merkle_leaves: block_state.merkle_leaves.clone(),
+    })
+}
+
+/// Makes the CHOICE transaction of `key` against the state in `bundle`.
+fn prove(key: &ZkretKey, bundle: &Bundle) -> io::Result<Transaction> {
+    let Bundle::Choice {
+        chain_id,
+        choice,
+        merkle_root,
+        merkle_leaves,
+    } = bundle
+    else {
+        return Err(wrong_bundle(NAME));
+    };
+    let state = ProtocolState {
+        chain_id: chain_id.to_vec(),
+        merkle_root: merkle_root.to_vec(),
+        merkle_leaves: merkle_leaves.iter().map(SBytes64::to_vec).collect(),
+        ..Default::default()
+    };
+
+    println!("Generating ZK proof...");
+    println!("Loading the proving key...");
+    let ca_prover = ChoiceAuthProver::new();
+    key.participant()
+        .choice_tx_with_progress(&ca_prover, &state, &choice.to_vec(), print_proving_stage)?
+        .try_into()
+}
@@ -0,0 +1,170 @@
+//! Resolves how `zkretctl` reaches the zkretvm RPC endpoint.
+//!
+//! The endpoint is taken, in order of preference, from `--rpc-url`, the
+//! `ZKRET_RPC_URL` environment variable, the profile stored in the key file,
+//! the config file and finally [`DEFAULT_RPC_URL`].
+//!
+//! The state fetched from the endpoint is verified back to a checkpoint, taken
+//! from `--checkpoint`, the `checkpoints` of the config file for the chain, or
+//! the key file, where `enter` and `key recover` pin one. Commands fail if there
+//! is none.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Error, ErrorKind},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use avalanche_types::ids;
+use clap::{arg, ArgMatches};
+use serde::{Deserialize, Serialize};
+use zkretvm::client::ClientOptions;
+
+pub const DEFAULT_RPC_URL: &str = "http://127.0.0.1:9650";
+pub const RPC_URL_ENV: &str = "ZKRET_RPC_URL";
+pub const CONFIG_PATH_ENV: &str = "ZKRET_CONFIG";
+
+const DEFAULT_CONFIG_PATH: &str = ".zkret/config.json"; // relative to $HOME
+const DEFAULT_TIMEOUT_SECS: u64 = 15;
+const DEFAULT_RETRIES: u32 = 3;
+
+/// Contents of the optional `zkretctl` config file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ConfigFile {
+    pub rpc_url: Option<String>,
+    pub timeout_secs: Option<u64>,
+    pub retries: Option<u32>,
+    /// Block Id to verify the chain back to, by chain Id.
+    #[serde(default)]
+    pub checkpoints: HashMap<String, String>,
+}
+
+impl ConfigFile {
+    /// Loads the config file from `ZKRET_CONFIG` or `$HOME/.zkret/config.json`.
+    /// A missing file yields the defaults.
+    pub fn load() -> io::Result<Self> {
+        let path = match std::env::var(CONFIG_PATH_ENV) {
+            Ok(p) => PathBuf::from(p),
+            Err(_) => match std::env::var("HOME") {
+                Ok(home) => PathBuf::from(home).join(DEFAULT_CONFIG_PATH),
+                Err(_) => return Ok(Self::default()),
+            },
+        };
+
+        match fs::read(&path) {
+            Ok(d) => serde_json::from_slice(&d).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("failed to parse config file '{}' {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Global command line arguments controlling RPC access.
+#[must_use]
+pub fn args() -> Vec<clap::Arg> {
+    vec![
+        arg!(--"rpc-url" <URL> "RPC endpoint, e.g. https://node.example.com:9650").global(true),
+        arg!(--timeout <SECS> "RPC request timeout in seconds")
+            .value_parser(clap::value_parser!(u64))
+            .global(true),
+        arg!(--retries <N> "Number of times a failed read-only RPC request is retried, transactions are sent once")
+            .value_parser(clap::value_parser!(u32))
+            .global(true),
+        arg!(--checkpoint <BLOCK_ID> "Trusted block to verify the chain back to, e.g. the genesis block published by the organiser")
+            .global(true),
+    ]
+}
+
+/// Connection settings for the zkretvm RPC endpoint.
+#[derive(Debug, Clone)]
+pub struct RpcOptions {
+    /// Endpoint requested on the command line or through the environment.
+    pub rpc_url: Option<String>,
+    /// Endpoint used when neither the options nor the key file name one.
+    pub fallback_rpc_url: String,
+    pub timeout: Duration,
+    pub retries: u32,
+    /// Checkpoint requested on the command line.
+    pub checkpoint: Option<String>,
+    /// Checkpoints of the config file, by chain Id.
+    pub checkpoints: HashMap<String, String>,
+}
+
+impl RpcOptions {
+    /// Merges the command line, environment and config file settings.
+    pub fn load(matches: &ArgMatches) -> io::Result<Self> {
+        let config = ConfigFile::load()?;
+
+        let rpc_url = matches
+            .get_one::<String>("rpc-url")
+            .cloned()
+            .or_else(|| std::env::var(RPC_URL_ENV).ok());
+        let fallback_rpc_url = config
+            .rpc_url
+            .unwrap_or_else(|| DEFAULT_RPC_URL.to_string());
+        let timeout_secs = matches
+            .get_one::<u64>("timeout")
+            .copied()
+            .or(config.timeout_secs)
+            .unwrap_or(DEFAULT_TIMEOUT_SECS);
+        let retries = matches
+            .get_one::<u32>("retries")
+            .copied()
+            .or(config.retries)
+            .unwrap_or(DEFAULT_RETRIES);
+
+        Ok(Self {
+            rpc_url,
+            fallback_rpc_url,
+            timeout: Duration::from_secs(timeout_secs),
+            retries,
+            checkpoint: matches.get_one::<String>("checkpoint").cloned(),
+            checkpoints: config.checkpoints,
+        })
+    }
+
+    /// Returns the settings for [`ZkretClient`](zkretvm::client::ZkretClient).
+    #[must_use]
+    pub fn client_options(&self) -> ClientOptions {
+        ClientOptions {
+            timeout: self.timeout,
+            retries: self.retries,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the endpoint to use for a key whose profile names `profile_rpc_url`.
+    #[must_use]
+    pub fn rpc_url_for(&self, profile_rpc_url: &str) -> String {
+        match &self.rpc_url {
+            Some(url) => url.clone(),
+            None if !profile_rpc_url.is_empty() => profile_rpc_url.to_string(),
+            None => self.fallback_rpc_url.clone(),
+        }
+    }
+
+    /// Returns the block to verify the chain `chain_id` back to, if one is set.
+    pub fn checkpoint_for(&self, chain_id: &ids::Id) -> io::Result<Option<ids::Id>> {
+        let Some(checkpoint) = self
+            .checkpoint
+            .as_ref()
+            .or_else(|| self.checkpoints.get(&chain_id.to_string()))
+        else {
+            return Ok(None);
+        };
+        ids::Id::from_str(checkpoint).map(Some).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid checkpoint '{checkpoint}' {e}"),
+            )
+        })
+    }
+}
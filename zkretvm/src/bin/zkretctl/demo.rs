@@ -1,20 +1,15 @@
-use santazk::proofs::{ChoiceAuthProver, RevealAuthProver};
+use santazk::proofs::{ChoiceAuthProver, EnterAuthProver, RevealAuthProver};
 use std::io;
 use tokio::time::sleep;
 use tokio::time::Duration;
-use zkretvm::block::transaction::TransactionData;
-use zkretvm::block::transaction::{SBytes64, Transaction};
+use zkretvm::block::transaction::Transaction;
+use zkretvm::client::ZkretClient;
 
-use santazk::crypto::*;
-use santazk::hash::Hash;
-use santazk::merkle::MerkleTree;
+use santazk::participant::{Participant, ProtocolState};
 
 use clap::{arg, Command};
 use colored::Colorize;
 
-use crate::utils::generate_key_tuple;
-use crate::utils::{self};
-
 pub const NAME: &str = "demo";
 
 #[must_use]
@@ -25,25 +20,28 @@ pub fn command() -> Command {
         .arg_required_else_help(true)
 }
 
-pub async fn run_demo(client: &utils::RpcClient) -> io::Result<()> {
-    let hasher = Hash::new();
+pub async fn run_demo(client: &ZkretClient) -> io::Result<()> {
+    let a = Participant::random();
+    let b = Participant::random();
+    let c = Participant::random();
+    let (pA, pB, pC) = (&a.pub_key, &b.pub_key, &c.pub_key);
 
-    let (sA, nA, pA, dA) = generate_key_tuple(&hasher);
-    let (sB, nB, pB, dB) = generate_key_tuple(&hasher);
-    let (sC, nC, pC, dC) = generate_key_tuple(&hasher);
+    let chain_id = client.chain_id().await?.to_vec();
+    let ea_prover = EnterAuthProver::new();
 
-    let txA_enter = create_enter_tx(&pA);
+    let state = ProtocolState::new(&chain_id, Vec::new());
+    let txA_enter = Transaction::try_from(a.enter_tx(&ea_prover, &state)?)?;
     println!("{}", "ENTER".green());
     println!(
         "{}{}",
         "PubKey: ".green(),
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &pA)[..20].green()
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, pA)[..20].green()
     );
     println!("{}\n", serde_json::to_string(&txA_enter).unwrap());
-    client.push_tx(txA_enter).await?;
+    client.propose_block(&txA_enter).await?;
     let _ = sleep(Duration::from_secs(10)).await;
 
-    let txB_enter = create_enter_tx(&pB);
+    let txB_enter = Transaction::try_from(b.enter_tx(&ea_prover, &state)?)?;
     println!("{}", "ENTER".red());
     println!(
         "{}{}",
@@ -51,84 +49,53 @@ pub async fn run_demo(client: &utils::RpcClient) -> io::Result<()> {
         base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &pB[..20]).red()
     );
     println!("{}\n", serde_json::to_string(&txB_enter).unwrap());
-    client.push_tx(txB_enter).await?;
+    client.propose_block(&txB_enter).await?;
     let _ = sleep(Duration::from_secs(10)).await;
 
-    let txC_enter = create_enter_tx(&pC);
+    let txC_enter = Transaction::try_from(c.enter_tx(&ea_prover, &state)?)?;
     println!("{}", "ENTER".yellow());
     println!(
         "{}{}",
         "PubKey: ".yellow(),
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &pC)[..20].yellow()
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, pC)[..20].yellow()
     );
     println!("{}\n", serde_json::to_string(&txC_enter).unwrap());
-    client.push_tx(txC_enter).await?;
-
-    // A chooses B
-    let mt = MerkleTree::new(7, &[pA.clone(), pB.clone(), pC.clone()]);
-    let rpA = mt.generate_proof(0).unwrap();
-    let root = mt.root();
-    let sig_txA_choose = sign_choice_tx(&hasher, &sA, &nA, &pB, &dA);
+    client.propose_block(&txC_enter).await?;
 
-    // generate ZK proof for A choosing B without revealing his pubkey
+    // A chooses B, with a ZK proof that doesn't reveal A's pubkey
+    let state = ProtocolState::new(&chain_id, vec![pA.clone(), pB.clone(), pC.clone()]);
     let ca_prover = ChoiceAuthProver::new();
-    let proof = ca_prover.prove(&sA, &nA, &root, &rpA, &pB, &dA, &sig_txA_choose);
-
-    // finally generate the tx and make the transaction
-    let txA_choose = Transaction {
-        transaction_type: 2,
-        data: TransactionData(
-            SBytes64::from_bytes(&pB),
-            SBytes64::from_bytes(&nA),
-            SBytes64::from_bytes(&dA),
-            SBytes64::from_bytes(&sig_txA_choose),
-            proof,
-            Vec::new(),
-        ),
-    };
+    let txA_choose = Transaction::try_from(a.choice_tx(&ca_prover, &state, pB)?)?;
     println!("{}", "CHOOSE".green());
     println!(
         "{}{}",
         "Choice: ".green(),
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &pB)[..20].green()
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, pB)[..20].green()
     );
     println!(
         "{}{}",
         "DHPubKey: ".green(),
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &dA)[..20].green()
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &a.dh_pub_key)[..20]
+            .green()
     );
     println!("{}\n", serde_json::to_string(&txA_choose).unwrap());
-    client.push_tx(txA_choose).await?;
+    client.propose_block(&txA_choose).await?;
 
     // B reveal their pubkey. The ciphertext message can only be seen by A.
     let ct = b"Hi, I am B. Send me ZCash!".to_vec();
-    let ct_hash = hasher.h1(&ct);
-    let sig_txB_reveal = sign_reveal_tx(&hasher, &sB, &nB, &ct_hash, &dB);
-
     let ra_prover = RevealAuthProver::new();
-    let proof = ra_prover.prove(&sB, &nB, &pB, &ct_hash, &dB, &sig_txB_reveal);
-
-    let txB_reveal = Transaction {
-        transaction_type: 3,
-        data: TransactionData(
-            SBytes64::from_bytes(&pB),
-            SBytes64::from_bytes(&ct_hash),
-            SBytes64::from_bytes(&dB),
-            SBytes64::from_bytes(&sig_txB_reveal),
-            ct.clone(),
-            proof,
-        ),
-    };
+    let txB_reveal = Transaction::try_from(b.reveal_tx(&ra_prover, &state, &ct)?)?;
     println!("{}", "REVEAL".red());
     println!(
         "{}{}",
         "PubKey: ".red(),
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &pB)[..20].red()
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, pB)[..20].red()
     );
     println!(
         "{}{}",
         "DHPubKey: ".red(),
-        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &dB)[..20].red()
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, &b.dh_pub_key)[..20]
+            .red()
     );
     println!(
         "{}{}",
@@ -136,17 +103,7 @@ pub async fn run_demo(client: &utils::RpcClient) -> io::Result<()> {
         String::from_utf8(ct).unwrap().purple().italic()
     );
     println!("{}\n", serde_json::to_string(&txB_reveal).unwrap());
-    client.push_tx(txB_reveal).await?;
+    client.propose_block(&txB_reveal).await?;
 
     Ok(())
 }
-
-fn create_enter_tx(pub_key: &[u8]) -> Transaction {
-    let mut tx = Transaction {
-        transaction_type: 1,
-        ..Default::default()
-    };
-    tx.data.0 = SBytes64::from_bytes(pub_key);
-
-    tx
-}
@@ -1,9 +1,15 @@
-use std::io;
+use std::io::{self, Error, ErrorKind};
 
 use clap::{arg, Command};
-use zkretvm::block::transaction::{SBytes64, Transaction};
+use santazk::proofs::{EnterAuthProver, InviteEnterAuthProver};
+use zkretvm::block::transaction::Transaction;
 
-use crate::{keygen::read_key, utils::RpcClient};
+use crate::{
+    config::RpcOptions,
+    invite::parse_token,
+    keystore,
+    utils::{client_for_key, pin_checkpoint, print_proving_stage, verified_state},
+};
 
 pub const NAME: &str = "enter";
 
@@ -12,20 +18,60 @@ pub fn command() -> Command {
     Command::new(NAME)
         .about("Publish your public key")
         .arg(arg!(-k [KEY_PATH] "zkret key path"))
+        .arg(arg!(--invite <TOKEN> "Invite token to redeem, if the group is invite-only"))
 }
 
-pub async fn do_enter(key_path: &str) -> io::Result<()> {
-    let key = read_key(key_path);
-    let client = RpcClient::new(key.chain_id.as_str());
+pub async fn do_enter(
+    key_path: &str,
+    opts: &RpcOptions,
+    invite: Option<&String>,
+) -> io::Result<()> {
+    let (store, mut key) = keystore::unlock(key_path)?;
+    let client = client_for_key(&key, opts)?;
 
-    let mut tx = Transaction {
-        transaction_type: 1,
-        ..Default::default()
+    // the invite's Merkle path is built locally so that the node doesn't learn which invite it is
+    let state = if key.checkpoint.is_some() {
+        verified_state(&client, &key.chain_id, key.checkpoint.as_ref(), opts).await?
+    } else {
+        let state = pin_checkpoint(&client, &key.chain_id, opts).await?;
+        key.checkpoint = Some(state.checkpoint());
+        store.save(key_path, &key)?;
+        state
+    }
+    .protocol_state();
+    let tx = match (&state.invite_root, invite) {
+        (None, _) => {
+            println!("Generating proof...");
+            println!("Loading the proving key...");
+            let ea_prover = EnterAuthProver::new();
+            key.participant()
+                .enter_tx_with_progress(&ea_prover, &state, print_proving_stage)?
+        }
+        (Some(_), None) => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "the group is invite-only, pass the token you were given with --invite",
+            ));
+        }
+        (Some(_), Some(token)) => {
+            let (invite_secret, invite_salt) = parse_token(token)?;
+
+            println!("Generating proof...");
+            println!("Loading the proving key...");
+            let iea_prover = InviteEnterAuthProver::new();
+            key.participant().invite_enter_tx_with_progress(
+                &iea_prover,
+                &state,
+                &invite_secret,
+                &invite_salt,
+                print_proving_stage,
+            )?
+        }
     };
-    tx.data.0 = SBytes64::from_bytes(key.pub_key.as_slice());
+    let tx = Transaction::try_from(tx)?;
 
     println!("Sending ENTER transaction...");
-    client.push_tx(tx).await?;
+    client.propose_block(&tx).await?;
     println!("Done.");
 
     Ok(())
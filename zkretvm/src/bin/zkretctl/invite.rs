@@ -0,0 +1,100 @@
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+};
+
+use clap::{arg, Command};
+use santazk::{crypto::derive_invite_commitment, hash::Hash};
+use zkretvm::block::transaction::INVITE_TREE_DEPTH;
+
+pub const NAME: &str = "invite";
+pub const INVITE_REQUEST: &str = "request";
+pub const INVITE_ISSUE: &str = "issue";
+
+const INVITE_SECRET_SIZE: usize = 32;
+const INVITE_COMMITMENT_SIZE: usize = 64;
+
+#[must_use]
+pub fn request_command() -> Command {
+    Command::new(INVITE_REQUEST)
+        .about("Make an invite token and the commitment to send to the organiser (participant)")
+}
+
+#[must_use]
+pub fn issue_command() -> Command {
+    Command::new(INVITE_ISSUE)
+        .about("Put the commitments sent by participants on the allowlist (organiser)")
+        .arg(arg!(-o [INVITES_PATH] "Output file for the invite commitments to put in the genesis"))
+        .arg(arg!(<COMMITMENTS> ... "Invite commitments made by 'zkretctl invite request'"))
+        .arg_required_else_help(true)
+}
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Manage the invites of an invite-only group")
+        .subcommands(vec![request_command(), issue_command()])
+}
+
+/// Generates an invite token and prints it along with its commitment. The token
+/// is made by the participant so that the organiser, who only sees the
+/// commitment, can't link the invite nullifier spent on ENTER back to them.
+pub fn request() -> io::Result<()> {
+    let invite_secret = random_manager::secure_bytes(INVITE_SECRET_SIZE)?;
+    let invite_salt = random_manager::secure_bytes(INVITE_SECRET_SIZE)?;
+    let commitment = derive_invite_commitment(&Hash::new(), &invite_secret, &invite_salt);
+
+    println!("Send this commitment to the organiser:\n");
+    println!("{}\n", hex::encode(commitment));
+    println!("Keep this token secret and pass it to 'zkretctl enter --invite':\n");
+    println!("{}", hex::encode([invite_secret, invite_salt].concat()));
+
+    Ok(())
+}
+
+/// Writes the invite `commitments` sent by participants to `invites_path`.
+pub fn issue(invites_path: &str, commitments: &[String]) -> io::Result<()> {
+    if commitments.len() > 1 << INVITE_TREE_DEPTH {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("can issue at most {} invites", 1 << INVITE_TREE_DEPTH),
+        ));
+    }
+
+    let mut invites = String::new();
+    for (i, commitment) in commitments.iter().enumerate() {
+        let commitment = commitment.trim();
+        if hex::decode(commitment).map_or(true, |bytes| bytes.len() != INVITE_COMMITMENT_SIZE) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("'{commitment}' is not an invite commitment"),
+            ));
+        }
+        if commitments[..i].iter().any(|c| c.trim() == commitment) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invite commitment '{commitment}' is given twice"),
+            ));
+        }
+        invites.push_str(&commitment.to_lowercase());
+        invites.push('\n');
+    }
+    fs::write(invites_path, invites)?;
+
+    println!(
+        "Wrote {} invite commitments to '{invites_path}', pass it to 'zkretvm genesis --invites'.",
+        commitments.len()
+    );
+
+    Ok(())
+}
+
+/// Splits an invite token into the invite secret and salt.
+pub fn parse_token(token: &str) -> io::Result<(Vec<u8>, Vec<u8>)> {
+    let bytes = hex::decode(token.trim())
+        .ok()
+        .filter(|b| b.len() == 2 * INVITE_SECRET_SIZE)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "malformed invite token"))?;
+    let (invite_secret, invite_salt) = bytes.split_at(INVITE_SECRET_SIZE);
+    Ok((invite_secret.to_vec(), invite_salt.to_vec()))
+}
@@ -0,0 +1,119 @@
+use std::{
+    io::{self, Error, ErrorKind},
+    path::Path,
+};
+
+use clap::{arg, Command};
+use santazk::hash::Hash;
+use zkretvm::{block::transaction::SBytes64, client::ZkretClient};
+
+use crate::{
+    config::RpcOptions,
+    keygen::{save_new_key, ZkretKey},
+    keystore::{self, KeyStore, NEW_PASSPHRASE_ENV},
+    mnemonic::{derive_key_tuple, prompt_mnemonic},
+    utils::{find_choice_by_nullifier, pin_checkpoint},
+};
+
+pub const NAME: &str = "key";
+pub const KEY_CHANGE_PASSPHRASE: &str = "change-passphrase";
+pub const KEY_RECOVER: &str = "recover";
+
+#[must_use]
+pub fn change_passphrase_command() -> Command {
+    Command::new(KEY_CHANGE_PASSPHRASE)
+        .about("Re-encrypt the zkret key under a new passphrase")
+        .arg(arg!(-k [KEY_PATH] "zkret key path"))
+}
+
+#[must_use]
+pub fn recover_command() -> Command {
+    Command::new(KEY_RECOVER)
+        .about("Rebuild a zkret key file from its mnemonic phrase")
+        .arg(arg!(-k [KEY_PATH] "Output zkret key path"))
+        .arg(arg!(<CHAIN_ID> "Chain ID"))
+        .arg_required_else_help(true)
+}
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Manage the zkret key file")
+        .subcommands(vec![change_passphrase_command(), recover_command()])
+}
+
+pub fn change_passphrase(key_path: &str) -> io::Result<()> {
+    let (_, key) = keystore::unlock(key_path)?;
+
+    println!("Choose a new passphrase.");
+    let passphrase = keystore::prompt_new_passphrase(NEW_PASSPHRASE_ENV)?;
+    KeyStore::new(&passphrase)?.save(key_path, &key)?;
+    println!("Done.");
+
+    Ok(())
+}
+
+pub async fn recover(key_path: &str, chain_id: &str, opts: &RpcOptions) -> io::Result<()> {
+    if Path::new(key_path).exists() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("refusing to overwrite existing key file '{key_path}'"),
+        ));
+    }
+
+    let mnemonic = prompt_mnemonic()?;
+    let hasher = Hash::new();
+    let (secret_key, nullifier, pub_key, dh_pub_key) =
+        derive_key_tuple(&hasher, &mnemonic, chain_id);
+
+    let rpc_url = opts.rpc_url_for("");
+    let client = ZkretClient::new(&rpc_url, chain_id, opts.client_options())?;
+
+    let state = pin_checkpoint(&client, chain_id, opts).await?;
+    let block_state = state.block_state();
+    if block_state
+        .merkle_leaves
+        .contains(&SBytes64::from_bytes(&pub_key))
+    {
+        println!("Found your public key on chain.");
+    } else {
+        println!("Your public key has not been entered on this chain yet.");
+    }
+
+    let chosen_pub_key = if block_state
+        .nullifiers
+        .contains(&SBytes64::from_bytes(&nullifier))
+    {
+        println!("Scanning the chain for your CHOICE transaction...");
+        match find_choice_by_nullifier(&client, &nullifier).await? {
+            Some(choice) => {
+                println!("Found your CHOICE transaction.");
+                choice.to_vec()
+            }
+            None => {
+                return Err(Error::new(
+                    ErrorKind::NotFound,
+                    "your nullifier is spent but its CHOICE transaction was not found",
+                ))
+            }
+        }
+    } else {
+        println!("You have not made a choice yet.");
+        Vec::new()
+    };
+
+    let zkret_key = ZkretKey {
+        secret_key,
+        nullifier,
+        pub_key,
+        dh_pub_key,
+        chain_id: chain_id.to_string(),
+        rpc_url,
+        checkpoint: Some(state.checkpoint()),
+        chosen_pub_key,
+    };
+    save_new_key(key_path, &zkret_key)?;
+    println!("Done.");
+
+    Ok(())
+}
@@ -1,9 +1,16 @@
-use santazk::hash::Hash;
+use std::io;
+
+use avalanche_types::ids;
+use santazk::{hash::Hash, participant::Participant};
 
 use clap::{arg, Command};
 use serde::{Deserialize, Serialize};
 
-use crate::utils::generate_key_tuple;
+use crate::{
+    config::RpcOptions,
+    keystore::{self, KeyStore, PASSPHRASE_ENV},
+    mnemonic::{derive_key_tuple, generate_mnemonic},
+};
 
 pub const NAME: &str = "keygen";
 
@@ -12,6 +19,7 @@ pub fn command() -> Command {
     Command::new(NAME)
         .about("Generate a new zkret key")
         .arg(arg!(-k [KEY_PATH] "Output zkret key path"))
+        .arg(arg!(--mnemonic "Derive the key from a new mnemonic phrase that can be used to recover it"))
         .arg(arg!(<CHAIN_ID> "Chain ID"))
         .arg_required_else_help(true)
 }
@@ -24,27 +32,72 @@ pub struct ZkretKey {
     pub dh_pub_key: Vec<u8>,
 
     pub chain_id: String,
+    /// RPC endpoint the key was created against.
+    #[serde(default)]
+    pub rpc_url: String,
+    /// Block the chain is verified back to, pinned by `enter` or `key recover`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<ids::Id>,
 
     pub chosen_pub_key: Vec<u8>,
 }
 
-pub fn gen_key(key_path: &str, chain_id: &str) {
-    let hasher = Hash::new();
+impl ZkretKey {
+    /// Returns the keys of the participant the profile is for.
+    #[must_use]
+    pub fn participant(&self) -> Participant {
+        Participant {
+            secret_key: self.secret_key.clone(),
+            nullifier: self.nullifier.clone(),
+            pub_key: self.pub_key.clone(),
+            dh_pub_key: self.dh_pub_key.clone(),
+        }
+    }
+}
 
-    let (secret_key, nullifier, pub_key, dh_pub_key) = generate_key_tuple(&hasher);
+pub fn gen_key(
+    key_path: &str,
+    chain_id: &str,
+    with_mnemonic: bool,
+    opts: &RpcOptions,
+) -> io::Result<()> {
+    let participant = if with_mnemonic {
+        let mnemonic = generate_mnemonic()?;
+        println!("Write down this mnemonic phrase. It is the only way to recover your key:\n");
+        println!("{mnemonic}\n");
+        let (secret_key, nullifier, pub_key, dh_pub_key) =
+            derive_key_tuple(&Hash::new(), &mnemonic, chain_id);
+        Participant {
+            secret_key,
+            nullifier,
+            pub_key,
+            dh_pub_key,
+        }
+    } else {
+        Participant::random()
+    };
     let zkret_key = ZkretKey {
-        secret_key,
-        nullifier,
-        pub_key,
-        dh_pub_key,
+        secret_key: participant.secret_key,
+        nullifier: participant.nullifier,
+        pub_key: participant.pub_key,
+        dh_pub_key: participant.dh_pub_key,
         chain_id: chain_id.to_string(),
+        rpc_url: opts.rpc_url_for(""),
+        checkpoint: None,
         chosen_pub_key: Vec::new(),
     };
 
-    std::fs::write(key_path, serde_json::to_string(&zkret_key).unwrap()).unwrap();
+    save_new_key(key_path, &zkret_key)
+}
+
+/// Asks for a passphrase and writes `zkret_key` to a new key file.
+pub fn save_new_key(key_path: &str, zkret_key: &ZkretKey) -> io::Result<()> {
+    println!("Choose a passphrase to protect the key file.");
+    let passphrase = keystore::prompt_new_passphrase(PASSPHRASE_ENV)?;
+    KeyStore::new(&passphrase)?.save(key_path, zkret_key)
 }
 
-pub fn read_key(key_path: &str) -> ZkretKey {
-    let zkret_key = std::fs::read_to_string(key_path).unwrap();
-    serde_json::from_str(&zkret_key).unwrap()
+pub fn read_key(key_path: &str) -> io::Result<ZkretKey> {
+    let (_, zkret_key) = keystore::unlock(key_path)?;
+    Ok(zkret_key)
 }
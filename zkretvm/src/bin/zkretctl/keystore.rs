@@ -0,0 +1,289 @@
+//! Passphrase-protected storage for zkret key files.
+//!
+//! A key file (version 1) is laid out as
+//! `MAGIC (8) | version (1) | m_cost (4) | t_cost (4) | p_cost (4) | salt (16) | nonce (12) | ciphertext`.
+//! The file key is derived from the passphrase with Argon2id and the JSON encoded
+//! [`ZkretKey`](crate::keygen::ZkretKey) is sealed with ChaCha20-Poly1305, using the
+//! header as associated data so that the KDF parameters can't be tampered with.
+
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Error, ErrorKind, Write},
+    path::Path,
+};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+
+use crate::keygen::ZkretKey;
+
+const MAGIC: &[u8; 8] = b"ZKRETKEY";
+const FILE_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const HEADER_LEN: usize = MAGIC.len() + 1 + 3 * 4 + SALT_LEN + NONCE_LEN;
+
+/// Environment variable read instead of prompting for the passphrase.
+pub const PASSPHRASE_ENV: &str = "ZKRET_PASSPHRASE";
+/// Environment variable read instead of prompting for a new passphrase.
+pub const NEW_PASSPHRASE_ENV: &str = "ZKRET_NEW_PASSPHRASE";
+
+/// Holds the file key derived from a passphrase, used to seal a [`ZkretKey`].
+pub struct KeyStore {
+    params: Params,
+    salt: Vec<u8>,
+    file_key: Key,
+}
+
+impl KeyStore {
+    /// Derives a file key for `passphrase` with a fresh salt.
+    pub fn new(passphrase: &str) -> io::Result<Self> {
+        let params = Params::default();
+        let salt = random_manager::secure_bytes(SALT_LEN)?;
+        let file_key = derive_file_key(passphrase, &params, &salt)?;
+        Ok(Self {
+            params,
+            salt,
+            file_key,
+        })
+    }
+
+    /// Encrypts `key` into the versioned key file format.
+    pub fn seal(&self, key: &ZkretKey) -> io::Result<Vec<u8>> {
+        let nonce = random_manager::secure_bytes(NONCE_LEN)?;
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(FILE_VERSION);
+        bytes.extend_from_slice(&self.params.m_cost().to_le_bytes());
+        bytes.extend_from_slice(&self.params.t_cost().to_le_bytes());
+        bytes.extend_from_slice(&self.params.p_cost().to_le_bytes());
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&nonce);
+
+        let plaintext = serde_json::to_vec(key).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize zkret key {e}"),
+            )
+        })?;
+        let ciphertext = ChaCha20Poly1305::new(&self.file_key)
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &bytes,
+                },
+            )
+            .map_err(|_| Error::new(ErrorKind::Other, "failed to encrypt zkret key"))?;
+
+        bytes.extend_from_slice(&ciphertext);
+        Ok(bytes)
+    }
+
+    /// Decrypts a key file, returning the store so the key can be written back.
+    pub fn open(bytes: &[u8], passphrase: &str) -> io::Result<(Self, ZkretKey)> {
+        if !is_encrypted(bytes) || bytes.len() < HEADER_LEN {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "not an encrypted zkret key file",
+            ));
+        }
+
+        let version = bytes[MAGIC.len()];
+        if version != FILE_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported zkret key file version {version}"),
+            ));
+        }
+
+        let (header, ciphertext) = bytes.split_at(HEADER_LEN);
+        let mut i = MAGIC.len() + 1;
+        let mut read_u32 = || {
+            let v = u32::from_le_bytes(header[i..i + 4].try_into().unwrap());
+            i += 4;
+            v
+        };
+        let (m_cost, t_cost, p_cost) = (read_u32(), read_u32(), read_u32());
+        let params = Params::new(m_cost, t_cost, p_cost, None).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid key derivation parameters {e}"),
+            )
+        })?;
+
+        let salt = header[HEADER_LEN - NONCE_LEN - SALT_LEN..HEADER_LEN - NONCE_LEN].to_vec();
+        let nonce = &header[HEADER_LEN - NONCE_LEN..];
+
+        let file_key = derive_file_key(passphrase, &params, &salt)?;
+        let plaintext = ChaCha20Poly1305::new(&file_key)
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: header,
+                },
+            )
+            .map_err(|_| {
+                Error::new(
+                    ErrorKind::PermissionDenied,
+                    "wrong passphrase or corrupted zkret key file",
+                )
+            })?;
+
+        let key = serde_json::from_slice(&plaintext).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to deserialize zkret key {e}"),
+            )
+        })?;
+
+        Ok((
+            Self {
+                params,
+                salt,
+                file_key,
+            },
+            key,
+        ))
+    }
+
+    /// Encrypts `key` and atomically replaces the file at `key_path`.
+    pub fn save(&self, key_path: &str, key: &ZkretKey) -> io::Result<()> {
+        write_atomic(key_path, &self.seal(key)?)
+    }
+}
+
+/// Returns "true" if `bytes` start with the encrypted key file magic.
+#[must_use]
+pub fn is_encrypted(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Reads and decrypts the key file at `key_path`, prompting for its passphrase.
+/// Legacy plaintext key files are encrypted in place under a newly chosen passphrase.
+pub fn unlock(key_path: &str) -> io::Result<(KeyStore, ZkretKey)> {
+    let bytes = fs::read(key_path)?;
+
+    if !is_encrypted(&bytes) {
+        let key: ZkretKey = serde_json::from_slice(&bytes).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to read zkret key file '{key_path}' {e}"),
+            )
+        })?;
+
+        println!("Key file '{key_path}' is not encrypted. Choose a passphrase to protect it.");
+        let passphrase = prompt_new_passphrase(PASSPHRASE_ENV)?;
+        let store = KeyStore::new(&passphrase)?;
+        store.save(key_path, &key)?;
+        return Ok((store, key));
+    }
+
+    let passphrase = prompt_passphrase(&format!("Passphrase to unlock '{key_path}': "))?;
+    KeyStore::open(&bytes, &passphrase)
+}
+
+/// Reads the passphrase from [`PASSPHRASE_ENV`], or prompts for it on the terminal.
+pub fn prompt_passphrase(prompt: &str) -> io::Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+    rpassword::prompt_password(prompt)
+}
+
+/// Reads a new passphrase from `env`, or prompts for it twice on the terminal.
+pub fn prompt_new_passphrase(env: &str) -> io::Result<String> {
+    let passphrase = match std::env::var(env) {
+        Ok(passphrase) => passphrase,
+        Err(_) => {
+            let passphrase = rpassword::prompt_password("New passphrase: ")?;
+            let confirmation = rpassword::prompt_password("Repeat passphrase: ")?;
+            if passphrase != confirmation {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "passphrases do not match",
+                ));
+            }
+            passphrase
+        }
+    };
+
+    if passphrase.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "passphrase must not be empty",
+        ));
+    }
+    Ok(passphrase)
+}
+
+/// Writes `data` to a temporary file next to `path` and renames it into place,
+/// so an interrupted write never leaves a truncated key file behind.
+pub fn write_atomic(path: &str, data: &[u8]) -> io::Result<()> {
+    let tmp_path = format!("{path}.tmp");
+
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut f = options.open(&tmp_path)?;
+    f.write_all(data)?;
+    f.sync_all()?;
+    drop(f);
+
+    fs::rename(&tmp_path, Path::new(path))
+}
+
+fn derive_file_key(passphrase: &str, params: &Params, salt: &[u8]) -> io::Result<Key> {
+    let mut file_key = Key::default();
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params.clone())
+        .hash_password_into(passphrase.as_bytes(), salt, &mut file_key)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to derive file key {e}")))?;
+    Ok(file_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> ZkretKey {
+        ZkretKey {
+            secret_key: vec![1u8; 64],
+            nullifier: vec![2u8; 64],
+            pub_key: vec![3u8; 64],
+            dh_pub_key: vec![4u8; 64],
+            chain_id: String::from("chain"),
+            rpc_url: String::from("http://127.0.0.1:9650"),
+            checkpoint: None,
+            chosen_pub_key: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn seal_and_open() {
+        let store = KeyStore::new("hunter2").unwrap();
+        let bytes = store.seal(&test_key()).unwrap();
+        assert!(is_encrypted(&bytes));
+
+        let (_, key) = KeyStore::open(&bytes, "hunter2").unwrap();
+        assert_eq!(key.secret_key, test_key().secret_key);
+        assert_eq!(key.chain_id, test_key().chain_id);
+
+        let err = KeyStore::open(&bytes, "hunter3").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    fn tampered_file_is_rejected() {
+        let store = KeyStore::new("hunter2").unwrap();
+        let mut bytes = store.seal(&test_key()).unwrap();
+        bytes[HEADER_LEN - 1] ^= 1;
+        assert!(KeyStore::open(&bytes, "hunter2").is_err());
+    }
+}
@@ -1,10 +1,18 @@
 mod check_santa;
 mod check_santee;
 mod choice;
+mod config;
 mod demo;
 mod enter;
+mod invite;
+mod key;
 mod keygen;
+mod keystore;
+mod mnemonic;
+mod offline;
+mod proof;
 mod reveal;
+mod tx;
 mod utils;
 
 use std::io;
@@ -20,16 +28,22 @@ async fn main() -> io::Result<()> {
         .about("ZKretSanta Client CLI")
         .subcommands(vec![
             keygen::command(),
+            key::command(),
+            invite::command(),
             enter::command(),
             choice::command(),
             check_santa::command(),
             reveal::command(),
             check_santee::command(),
+            tx::command(),
+            proof::command(),
             demo::command(),
         ])
+        .args(config::args())
         .get_matches();
 
     let default_key_path = "key.zkret".to_string();
+    let opts = config::RpcOptions::load(&matches)?;
 
     match matches.subcommand() {
         Some((keygen::NAME, sub_matches)) => {
@@ -38,14 +52,56 @@ async fn main() -> io::Result<()> {
                 .unwrap_or(&default_key_path);
             let chain_id = sub_matches.get_one::<String>("CHAIN_ID").expect("required");
 
-            keygen::gen_key(key_path, chain_id);
+            let with_mnemonic = sub_matches.get_flag("mnemonic");
+
+            keygen::gen_key(key_path, chain_id, with_mnemonic, &opts)?;
         }
+        Some((key::NAME, sub_matches)) => match sub_matches.subcommand() {
+            Some((key::KEY_CHANGE_PASSPHRASE, sub_sub_matches)) => {
+                let key_path = sub_sub_matches
+                    .get_one::<String>("KEY_PATH")
+                    .unwrap_or(&default_key_path);
+
+                key::change_passphrase(key_path)?;
+            }
+            Some((key::KEY_RECOVER, sub_sub_matches)) => {
+                let key_path = sub_sub_matches
+                    .get_one::<String>("KEY_PATH")
+                    .unwrap_or(&default_key_path);
+                let chain_id = sub_sub_matches
+                    .get_one::<String>("CHAIN_ID")
+                    .expect("required");
+
+                key::recover(key_path, chain_id, &opts).await?;
+            }
+            _ => {}
+        },
+        Some((invite::NAME, sub_matches)) => match sub_matches.subcommand() {
+            Some((invite::INVITE_REQUEST, _)) => {
+                invite::request()?;
+            }
+            Some((invite::INVITE_ISSUE, sub_sub_matches)) => {
+                let invites_path = sub_sub_matches
+                    .get_one::<String>("INVITES_PATH")
+                    .map_or("invites.txt", String::as_str);
+                let commitments = sub_sub_matches
+                    .get_many::<String>("COMMITMENTS")
+                    .expect("required")
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                invite::issue(invites_path, &commitments)?;
+            }
+            _ => {}
+        },
         Some((enter::NAME, sub_matches)) => {
             let key_path = sub_matches
                 .get_one::<String>("KEY_PATH")
                 .unwrap_or(&default_key_path);
 
-            enter::do_enter(key_path).await?;
+            let invite = sub_matches.get_one::<String>("invite");
+
+            enter::do_enter(key_path, &opts, invite).await?;
         }
         Some((choice::NAME, sub_matches)) => match sub_matches.subcommand() {
             Some((choice::CHOICE_LIST, sub_sub_matches)) => {
@@ -53,7 +109,7 @@ async fn main() -> io::Result<()> {
                     .get_one::<String>("KEY_PATH")
                     .unwrap_or(&default_key_path);
 
-                choice::list_choices(key_path).await?;
+                choice::list_choices(key_path, &opts).await?;
             }
             Some((choice::CHOICE_MAKE, sub_sub_matches)) => {
                 let key_path = sub_sub_matches
@@ -63,7 +119,33 @@ async fn main() -> io::Result<()> {
                     .get_one::<String>("CHOICE")
                     .expect("required");
 
-                choice::do_choice_make(key_path, choice).await?;
+                choice::do_choice_make(key_path, &opts, choice).await?;
+            }
+            Some((choice::CHOICE_PREPARE, sub_sub_matches)) => {
+                let bundle_path = sub_sub_matches
+                    .get_one::<String>("BUNDLE_PATH")
+                    .map_or("choice.bundle.json", String::as_str);
+                let chain_id = sub_sub_matches
+                    .get_one::<String>("CHAIN_ID")
+                    .expect("required");
+                let choice = sub_sub_matches
+                    .get_one::<String>("CHOICE")
+                    .expect("required");
+
+                choice::do_choice_prepare(chain_id, &opts, choice, bundle_path).await?;
+            }
+            Some((choice::CHOICE_PROVE, sub_sub_matches)) => {
+                let key_path = sub_sub_matches
+                    .get_one::<String>("KEY_PATH")
+                    .unwrap_or(&default_key_path);
+                let bundle_path = sub_sub_matches
+                    .get_one::<String>("bundle")
+                    .expect("required");
+                let tx_path = sub_sub_matches
+                    .get_one::<String>("TX_PATH")
+                    .map_or("choice.tx.json", String::as_str);
+
+                choice::do_choice_prove(key_path, bundle_path, tx_path)?;
             }
             _ => {}
         },
@@ -72,28 +154,82 @@ async fn main() -> io::Result<()> {
                 .get_one::<String>("KEY_PATH")
                 .unwrap_or(&default_key_path);
 
-            check_santa::check_santa(key_path).await?;
+            check_santa::check_santa(key_path, &opts).await?;
         }
-        Some((reveal::NAME, sub_matches)) => {
-            let key_path = sub_matches
-                .get_one::<String>("KEY_PATH")
-                .unwrap_or(&default_key_path);
-            let info = sub_matches
-                .get_one::<String>("INFO")
-                .expect("required");
+        Some((reveal::NAME, sub_matches)) => match sub_matches.subcommand() {
+            Some((reveal::REVEAL_PREPARE, sub_sub_matches)) => {
+                let bundle_path = sub_sub_matches
+                    .get_one::<String>("BUNDLE_PATH")
+                    .map_or("reveal.bundle.json", String::as_str);
+                let chain_id = sub_sub_matches
+                    .get_one::<String>("CHAIN_ID")
+                    .expect("required");
 
-            reveal::do_reveal(key_path, info).await?;
-        }
+                reveal::do_reveal_prepare(chain_id, &opts, bundle_path).await?;
+            }
+            Some((reveal::REVEAL_PROVE, sub_sub_matches)) => {
+                let key_path = sub_sub_matches
+                    .get_one::<String>("KEY_PATH")
+                    .unwrap_or(&default_key_path);
+                let bundle_path = sub_sub_matches
+                    .get_one::<String>("bundle")
+                    .expect("required");
+                let tx_path = sub_sub_matches
+                    .get_one::<String>("TX_PATH")
+                    .map_or("reveal.tx.json", String::as_str);
+                let info = sub_sub_matches.get_one::<String>("INFO").expect("required");
+
+                reveal::do_reveal_prove(key_path, bundle_path, info, tx_path)?;
+            }
+            _ => {
+                let key_path = sub_matches
+                    .get_one::<String>("KEY_PATH")
+                    .unwrap_or(&default_key_path);
+                let info = sub_matches
+                    .get_one::<String>("INFO")
+                    .expect("required");
+
+                reveal::do_reveal(key_path, &opts, info).await?;
+            }
+        },
         Some((check_santee::NAME, sub_matches)) => {
             let key_path = sub_matches
                 .get_one::<String>("KEY_PATH")
                 .unwrap_or(&default_key_path);
 
-            check_santee::check_santee(key_path).await?;
+            check_santee::check_santee(key_path, &opts).await?;
+        }
+        Some((tx::NAME, sub_matches)) => {
+            if let Some((tx::TX_SUBMIT, sub_sub_matches)) = sub_matches.subcommand() {
+                let tx_path = sub_sub_matches
+                    .get_one::<String>("TX_PATH")
+                    .expect("required");
+
+                tx::submit(tx_path, &opts).await?;
+            }
+        }
+        Some((proof::NAME, sub_matches)) => {
+            if let Some((proof::PROOF_EXPORT, sub_sub_matches)) = sub_matches.subcommand() {
+                let chain_id = sub_sub_matches
+                    .get_one::<String>("CHAIN_ID")
+                    .expect("required");
+                let block_id = sub_sub_matches
+                    .get_one::<String>("BLOCK_ID")
+                    .expect("required");
+                let out_dir = sub_sub_matches
+                    .get_one::<String>("OUT_DIR")
+                    .map_or(".", String::as_str);
+
+                proof::export(chain_id, block_id, out_dir, &opts).await?;
+            }
         }
         Some((demo::NAME, sub_matches)) => {
             let chain_id = sub_matches.get_one::<String>("CHAIN_ID").expect("required");
-            let client = utils::RpcClient::new(chain_id);
+            let client = zkretvm::client::ZkretClient::new(
+                &opts.rpc_url_for(""),
+                chain_id,
+                opts.client_options(),
+            )?;
 
             demo::run_demo(&client).await?
         }
@@ -0,0 +1,81 @@
+//! Deterministic zkret key derivation from a BIP-39 mnemonic phrase.
+//!
+//! The 64-byte BIP-39 seed is expanded with HKDF-SHA512, salted with the chain id,
+//! into the secret key, nullifier and DH key, so the same phrase always rebuilds
+//! the same participation key for a given chain.
+
+use std::io::{self, Error, ErrorKind};
+
+use bip39::Mnemonic;
+use hkdf::Hkdf;
+use santazk::{crypto::derive_participation_pubkey, hash::Hash};
+use sha2::Sha512;
+
+/// Environment variable read instead of prompting for the mnemonic phrase.
+pub const MNEMONIC_ENV: &str = "ZKRET_MNEMONIC";
+
+const MNEMONIC_ENTROPY_LEN: usize = 32; // 24 words
+const DERIVED_KEY_LEN: usize = 64;
+
+/// Generates a new 24 word mnemonic phrase.
+pub fn generate_mnemonic() -> io::Result<Mnemonic> {
+    let entropy = random_manager::secure_bytes(MNEMONIC_ENTROPY_LEN)?;
+    Mnemonic::from_entropy(&entropy)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to generate mnemonic {e}")))
+}
+
+/// Reads the mnemonic phrase from [`MNEMONIC_ENV`], or prompts for it on the terminal.
+pub fn prompt_mnemonic() -> io::Result<Mnemonic> {
+    let phrase = match std::env::var(MNEMONIC_ENV) {
+        Ok(phrase) => phrase,
+        Err(_) => rpassword::prompt_password("Mnemonic phrase: ")?,
+    };
+    Mnemonic::parse(phrase.trim())
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid mnemonic {e}")))
+}
+
+/// Derives `(secret_key, nullifier, pub_key, dh_key)` for `chain_id` from `mnemonic`.
+pub fn derive_key_tuple(
+    hasher: &Hash,
+    mnemonic: &Mnemonic,
+    chain_id: &str,
+) -> (Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>) {
+    let seed = mnemonic.to_seed("");
+    let hk = Hkdf::<Sha512>::new(Some(chain_id.as_bytes()), &seed);
+
+    let expand = |info: &str| {
+        let mut okm = vec![0u8; DERIVED_KEY_LEN];
+        hk.expand(info.as_bytes(), &mut okm)
+            .expect("64 bytes is a valid HKDF-SHA512 output length");
+        okm
+    };
+
+    let secret_key = expand("zkretsanta/secret_key");
+    let nullifier = expand("zkretsanta/nullifier");
+    let dh_key = expand("zkretsanta/dh_key");
+
+    let pub_key = derive_participation_pubkey(hasher, &secret_key, &nullifier);
+
+    (secret_key, nullifier, pub_key, dh_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derivation_is_deterministic_per_chain() {
+        let hasher = Hash::new();
+        let mnemonic = Mnemonic::from_entropy(&[7u8; MNEMONIC_ENTROPY_LEN]).unwrap();
+        let reparsed = Mnemonic::parse(mnemonic.to_string()).unwrap();
+
+        let a = derive_key_tuple(&hasher, &mnemonic, "chain-a");
+        let b = derive_key_tuple(&hasher, &reparsed, "chain-a");
+        let c = derive_key_tuple(&hasher, &mnemonic, "chain-b");
+
+        assert_eq!(a, b);
+        assert_ne!(a.0, c.0);
+        assert_ne!(a.1, c.1);
+        assert_ne!(a.0, a.1);
+    }
+}
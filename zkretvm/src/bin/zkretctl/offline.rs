@@ -0,0 +1,101 @@
+//! Files that let CHOICE and REVEAL transactions be proven on a machine that
+//! never goes online: `prepare` fetches the public state a proof needs into a
+//! [`Bundle`], `prove --bundle` turns it into a [`TxFile`] without touching the
+//! network, and `tx submit` broadcasts that file from an online machine.
+
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+};
+
+use avalanche_types::ids;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use zkretvm::block::transaction::{SBytes64, Transaction};
+
+/// Public state a transaction is proven against.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Bundle {
+    Choice {
+        chain_id: ids::Id,
+        /// Public key being chosen.
+        choice: SBytes64,
+        merkle_root: SBytes64,
+        merkle_leaves: Vec<SBytes64>,
+    },
+    Reveal {
+        chain_id: ids::Id,
+    },
+}
+
+/// A proven transaction, ready to be submitted to the chain it was proven for.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TxFile {
+    pub chain_id: ids::Id,
+    pub transaction: Transaction,
+}
+
+/// Writes `value` to `path` as pretty-printed JSON.
+pub fn write_json<T: Serialize>(path: &str, value: &T) -> io::Result<()> {
+    let data = serde_json::to_vec_pretty(value).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed to encode {path} {e}"),
+        )
+    })?;
+    fs::write(path, data)
+}
+
+/// Reads a file written by [`write_json`].
+pub fn read_json<T: DeserializeOwned>(path: &str) -> io::Result<T> {
+    let data = fs::read(path)?;
+    serde_json::from_slice(&data).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed to parse {path} {e}"),
+        )
+    })
+}
+
+/// Returns the error for a bundle of another transaction type than `expected`.
+pub fn wrong_bundle(expected: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("not a {expected} bundle, make one with `zkretctl {expected} prepare`"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundle_round_trip() {
+        let bundle = Bundle::Choice {
+            chain_id: ids::Id::from_slice(&[7u8; 32]),
+            choice: SBytes64::from_bytes(&[1u8; 64]),
+            merkle_root: SBytes64::from_bytes(&[2u8; 64]),
+            merkle_leaves: vec![SBytes64::from_bytes(&[3u8; 64])],
+        };
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert!(json.contains(r#""type":"choice""#));
+
+        let Bundle::Choice {
+            chain_id,
+            merkle_leaves,
+            ..
+        } = serde_json::from_str(&json).unwrap()
+        else {
+            panic!("decoded into another bundle type");
+        };
+        assert_eq!(chain_id, ids::Id::from_slice(&[7u8; 32]));
+        assert_eq!(merkle_leaves, vec![SBytes64::from_bytes(&[3u8; 64])]);
+
+        let reveal: Bundle = serde_json::from_str(&format!(
+            r#"{{"type":"reveal","chain_id":"{}"}}"#,
+            ids::Id::empty()
+        ))
+        .unwrap();
+        assert!(matches!(reveal, Bundle::Reveal { .. }));
+    }
+}
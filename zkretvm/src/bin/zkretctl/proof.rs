@@ -0,0 +1,254 @@
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+
+use avalanche_types::ids;
+use clap::{arg, Command};
+use santazk::{
+    hash::Pedersen,
+    proofs::{
+        choice_public_inputs, reveal_public_inputs,
+        snarkjs::{
+            choice_verifying_key, export_proof, export_public_inputs, reveal_verifying_key,
+            SnarkjsProof, SnarkjsVerifyingKey,
+        },
+        ChoiceAuthVerifier, RevealAuthVerifier,
+    },
+};
+use zkretvm::{
+    block::{
+        transaction::{DecodedTransaction, SBytes64},
+        Block,
+    },
+    client::ZkretClient,
+};
+
+use crate::{config::RpcOptions, offline::write_json, utils::reported_chain_id};
+
+pub const NAME: &str = "proof";
+pub const PROOF_EXPORT: &str = "export";
+
+#[must_use]
+pub fn export_command() -> Command {
+    Command::new(PROOF_EXPORT)
+        .about("Export the proof of a CHOICE or REVEAL transaction in the snarkjs format")
+        .arg(arg!(<CHAIN_ID> "Chain Id"))
+        .arg(arg!(<BLOCK_ID> "Block the transaction was accepted in"))
+        .arg(arg!(-o [OUT_DIR] "Directory to write proof.json, public.json and verification_key.json to"))
+        .arg_required_else_help(true)
+}
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Check proofs with other tools")
+        .subcommands(vec![export_command()])
+}
+
+/// Writes the proof of the transaction accepted in the block `block_id`, its
+/// public inputs and the verifying key to check them with to `out_dir`, for
+/// `snarkjs groth16 verify`.
+pub async fn export(
+    chain_id: &str,
+    block_id: &str,
+    out_dir: &str,
+    opts: &RpcOptions,
+) -> io::Result<()> {
+    let block_id = ids::Id::from_str(block_id).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid block Id '{block_id}' {e}"),
+        )
+    })?;
+    let client = ZkretClient::new(&opts.rpc_url_for(""), chain_id, opts.client_options())?;
+    let chain_id = reported_chain_id(&client, chain_id).await?;
+
+    let block = fetch_block(&client, &block_id).await?;
+    let parent = fetch_block(&client, &block.parent_id()).await?;
+    // a CHOICE is proven against the Merkle root the VM verified it with, which
+    // is the one of the state before its block
+    let merkle_root = &parent.block_state().merkle_root;
+    let tx = block.transaction().decode()?;
+    let (proof, public_inputs, verifying_key) = statement(&tx, &chain_id.to_vec(), merkle_root)?;
+
+    fs::create_dir_all(out_dir)?;
+    let out = |name: &str| Path::new(out_dir).join(name).to_string_lossy().into_owned();
+    write_json(&out("proof.json"), &proof)?;
+    write_json(&out("public.json"), &public_inputs)?;
+    write_json(&out("verification_key.json"), &verifying_key)?;
+    println!(
+        "Wrote proof.json, public.json and verification_key.json to {out_dir}, check them with"
+    );
+    println!("snarkjs groth16 verify verification_key.json public.json proof.json");
+
+    Ok(())
+}
+
+/// Fetches the block `id`, checking that the node served the block with that Id.
+async fn fetch_block(client: &ZkretClient, id: &ids::Id) -> io::Result<Block> {
+    let block = client.get_block(id).await?;
+    if block.id() != *id {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("the node served block {} for {id}", block.id()),
+        ));
+    }
+    Ok(block)
+}
+
+/// Returns the proof of `tx` with the public inputs and verifying key it verifies
+/// against.
+fn statement(
+    tx: &DecodedTransaction,
+    chain_id: &[u8],
+    merkle_root: &SBytes64,
+) -> io::Result<(SnarkjsProof, Vec<String>, SnarkjsVerifyingKey)> {
+    let (dh_pub_key, signature) = (tx.data.2.to_u8_64(), tx.data.3.to_u8_64());
+    let (proof, public_inputs, verifying_key, verified) = match tx.transaction_type {
+        2 => {
+            let root = merkle_root.to_u8_64();
+            let (nullifier, choice) = (tx.data.1.to_u8_64(), tx.data.0.to_u8_64());
+            let verified = ChoiceAuthVerifier::new().verify(
+                &tx.data.4,
+                &nullifier,
+                &root,
+                &choice,
+                chain_id,
+                &dh_pub_key,
+                &signature,
+            );
+            let public_inputs = choice_public_inputs::<Pedersen>(
+                &nullifier,
+                &root,
+                &choice,
+                chain_id,
+                &dh_pub_key,
+                &signature,
+            )
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    "the Merkle root of the state before the block isn't a point",
+                )
+            })?;
+            (&tx.data.4, public_inputs, choice_verifying_key(), verified)
+        }
+        3 => {
+            let (pub_key, ct_hash) = (tx.data.0.to_u8_64(), tx.data.1.to_u8_64());
+            let verified = RevealAuthVerifier::new().verify(
+                &tx.data.5,
+                &pub_key,
+                &ct_hash,
+                chain_id,
+                &dh_pub_key,
+                &signature,
+            );
+            let public_inputs =
+                reveal_public_inputs(&pub_key, &ct_hash, chain_id, &dh_pub_key, &signature);
+            (&tx.data.5, public_inputs, reveal_verifying_key(), verified)
+        }
+        _ => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "only CHOICE and REVEAL proofs can be exported",
+            ))
+        }
+    };
+
+    let proof = export_proof(proof).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "not a Groth16 proof, only those can be exported",
+        )
+    })?;
+    if !verified {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "the proof doesn't verify against the state before its block",
+        ));
+    }
+    Ok((proof, export_public_inputs(&public_inputs), verifying_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use santazk::{
+        crypto::{derive_participation_pubkey, sign_reveal_tx},
+        hash::Hash,
+        proofs::{
+            snarkjs::{import_proof, import_public_inputs},
+            RevealAuthProver,
+        },
+    };
+    use zkretvm::block::transaction::Transaction;
+
+    use super::*;
+
+    /// A REVEAL transaction for the chain `[4; 32]`, with the proof `prove` makes.
+    fn reveal_tx(
+        prove: impl FnOnce(&[u8], &[u8], &[u8], &[u8], &[u8]) -> Vec<u8>,
+    ) -> DecodedTransaction {
+        let hasher = Hash::new();
+        let (secret_key, nullifier) = ([1u8; 64], [2u8; 64]);
+        let (chain_id, dh_pub_key) = ([4u8; 32], [5u8; 64]);
+        let pub_key = derive_participation_pubkey(&hasher, &secret_key, &nullifier);
+        let ct = b"ciphertext".to_vec();
+        let ct_hash = hasher.h1(&ct);
+        let signature = sign_reveal_tx(
+            &hasher,
+            &secret_key,
+            &nullifier,
+            &chain_id,
+            &ct_hash,
+            &dh_pub_key,
+        );
+        let proof = prove(&secret_key, &nullifier, &pub_key, &ct_hash, &signature);
+        Transaction::reveal(&pub_key, &ct_hash, &dh_pub_key, &signature, ct, proof)
+            .unwrap()
+            .decode()
+            .unwrap()
+    }
+
+    #[test]
+    fn only_groth16_choice_and_reveal_proofs_are_exported() {
+        let enter = DecodedTransaction {
+            transaction_type: 1,
+            ..Default::default()
+        };
+        assert!(statement(&enter, &[4u8; 32], &SBytes64::default()).is_err());
+
+        // tagged as a Marlin proof
+        let reveal = reveal_tx(|_, _, _, _, _| vec![2u8; 100]);
+        let err = statement(&reveal, &[4u8; 32], &SBytes64::default()).unwrap_err();
+        assert!(err.to_string().contains("not a Groth16 proof"));
+    }
+
+    #[test]
+    #[ignore = "loads the REVEAL proving key, run with `cargo test --release -- --ignored`"]
+    fn reveal_proofs_are_exported() {
+        let chain_id = [4u8; 32];
+        let reveal = reveal_tx(|secret_key, nullifier, pub_key, ct_hash, signature| {
+            RevealAuthProver::new().prove(
+                secret_key, nullifier, pub_key, ct_hash, &chain_id, &[5u8; 64], signature,
+            )
+        });
+
+        let (proof, public_inputs, verifying_key) =
+            statement(&reveal, &chain_id, &SBytes64::default()).unwrap();
+        assert_eq!(import_proof(&proof).unwrap(), reveal.data.5);
+        assert_eq!(verifying_key.n_public, public_inputs.len());
+        assert_eq!(
+            import_public_inputs(&public_inputs).unwrap(),
+            reveal_public_inputs(
+                &reveal.data.0.to_u8_64(),
+                &reveal.data.1.to_u8_64(),
+                &chain_id,
+                &reveal.data.2.to_u8_64(),
+                &reveal.data.3.to_u8_64(),
+            )
+        );
+    }
+}
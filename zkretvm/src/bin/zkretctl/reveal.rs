@@ -1,15 +1,38 @@
 use std::io;
 
+use avalanche_types::ids;
 use clap::{arg, Command};
-use santazk::{crypto::sign_reveal_tx, hash::Hash, proofs::RevealAuthProver};
-use zkretvm::block::transaction::{SBytes64, Transaction, TransactionData};
+use santazk::{participant::ProtocolState, proofs::RevealAuthProver};
+use zkretvm::{block::transaction::Transaction, client::ZkretClient};
 
 use crate::{
-    keygen::read_key,
-    utils::{RpcClient},
+    config::RpcOptions,
+    keygen::{read_key, ZkretKey},
+    offline::{read_json, write_json, wrong_bundle, Bundle, TxFile},
+    utils::{client_for_key, print_proving_stage, verified_state},
 };
 
 pub const NAME: &str = "reveal";
+pub const REVEAL_PREPARE: &str = "prepare";
+pub const REVEAL_PROVE: &str = "prove";
+
+#[must_use]
+pub fn prepare_command() -> Command {
+    Command::new(REVEAL_PREPARE)
+        .about("Fetch what a reveal is proven against, to prove it on an offline machine")
+        .arg(arg!(-o [BUNDLE_PATH] "Output bundle path"))
+        .arg(arg!(<CHAIN_ID> "Chain ID"))
+}
+
+#[must_use]
+pub fn prove_command() -> Command {
+    Command::new(REVEAL_PROVE)
+        .about("Reveal your information from a bundle without going online")
+        .arg(arg!(-k [KEY_PATH] "zkret key path"))
+        .arg(arg!(--bundle <BUNDLE_PATH> "Bundle made by `reveal prepare`"))
+        .arg(arg!(-o [TX_PATH] "Output transaction path"))
+        .arg(arg!(<INFO> "Your information to reveal"))
+}
 
 #[must_use]
 pub fn command() -> Command {
@@ -17,50 +40,93 @@ pub fn command() -> Command {
         .about("Reveal your information to your santa")
         .arg(arg!(-k [KEY_PATH] "zkret key path"))
         .arg(arg!(<INFO> "Your information to reveal"))
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
+        .subcommands(vec![prepare_command(), prove_command()])
 }
 
-pub async fn do_reveal(key_path: &str, info: &str) -> io::Result<()> {
-    let key = read_key(key_path);
-    let client = RpcClient::new(key.chain_id.as_str());
+pub async fn do_reveal(key_path: &str, opts: &RpcOptions, info: &str) -> io::Result<()> {
+    let key = read_key(key_path)?;
+    let client = client_for_key(&key, opts)?;
+    let bundle = fetch_bundle(&client, &key.chain_id, key.checkpoint.as_ref(), opts).await?;
+    let tx = prove(&key, &bundle, info)?;
 
-    let hasher = Hash::new();
+    println!("Sending REVEAL transaction...");
+    client.propose_block(&tx).await?;
+    println!("Done.");
 
-    let ct = info.as_bytes();
-    let ct_hash = hasher.h1(ct);
-    let signature = sign_reveal_tx(
-        &hasher,
-        &key.secret_key,
-        &key.nullifier,
-        &ct_hash,
-        &key.dh_pub_key,
-    );
+    Ok(())
+}
 
-    println!("Generating ZK proof...");
-    let ra_prover = RevealAuthProver::new();
-    let proof = ra_prover.prove(
-        &key.secret_key,
-        &key.nullifier,
-        &key.pub_key,
-        &ct_hash,
-        &key.dh_pub_key,
-        &signature,
-    );
-
-    let tx = Transaction {
-        transaction_type: 3,
-        data: TransactionData (
-            SBytes64::from_bytes(&key.pub_key),
-            SBytes64::from_bytes(&ct_hash),
-            SBytes64::from_bytes(&key.dh_pub_key),
-            SBytes64::from_bytes(&signature),
-            ct.to_vec(),
-            proof,
-        ),
+/// Writes the public state a REVEAL is proven against to `bundle_path`, for
+/// [`do_reveal_prove`] to use offline.
+pub async fn do_reveal_prepare(
+    chain_id: &str,
+    opts: &RpcOptions,
+    bundle_path: &str,
+) -> io::Result<()> {
+    let client = ZkretClient::new(&opts.rpc_url_for(""), chain_id, opts.client_options())?;
+    let bundle = fetch_bundle(&client, chain_id, None, opts).await?;
+    write_json(bundle_path, &bundle)?;
+    println!("Wrote {bundle_path}, prove it offline with `zkretctl reveal prove --bundle`.");
+
+    Ok(())
+}
+
+/// Proves the REVEAL of `info` against the bundle at `bundle_path` without going
+/// online, and writes the transaction to `tx_path`.
+pub fn do_reveal_prove(
+    key_path: &str,
+    bundle_path: &str,
+    info: &str,
+    tx_path: &str,
+) -> io::Result<()> {
+    let key = read_key(key_path)?;
+    let bundle = read_json(bundle_path)?;
+    let Bundle::Reveal { chain_id } = &bundle else {
+        return Err(wrong_bundle(NAME));
     };
 
-    println!("Sending REVEAL transaction...");
-    client.push_tx(tx).await?;
-    println!("Done.");
+    let transaction = prove(&key, &bundle, info)?;
+    write_json(
+        tx_path,
+        &TxFile {
+            chain_id: *chain_id,
+            transaction,
+        },
+    )?;
+    println!("Wrote {tx_path}, send it with `zkretctl tx submit`.");
 
     Ok(())
 }
+
+/// A REVEAL is only bound to the chain, the rest of what it proves is in the key.
+async fn fetch_bundle(
+    client: &ZkretClient,
+    chain_id: &str,
+    pinned: Option<&ids::Id>,
+    opts: &RpcOptions,
+) -> io::Result<Bundle> {
+    let state = verified_state(client, chain_id, pinned, opts).await?;
+    Ok(Bundle::Reveal {
+        chain_id: state.chain_id(),
+    })
+}
+
+/// Makes the REVEAL transaction of `info` by `key` against the state in `bundle`.
+fn prove(key: &ZkretKey, bundle: &Bundle, info: &str) -> io::Result<Transaction> {
+    let Bundle::Reveal { chain_id } = bundle else {
+        return Err(wrong_bundle(NAME));
+    };
+    let state = ProtocolState {
+        chain_id: chain_id.to_vec(),
+        ..Default::default()
+    };
+
+    println!("Generating ZK proof...");
+    println!("Loading the proving key...");
+    let ra_prover = RevealAuthProver::new();
+    key.participant()
+        .reveal_tx_with_progress(&ra_prover, &state, info.as_bytes(), print_proving_stage)?
+        .try_into()
+}
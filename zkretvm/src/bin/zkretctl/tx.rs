@@ -0,0 +1,50 @@
+use std::io::{self, Error, ErrorKind};
+
+use clap::{arg, Command};
+use zkretvm::client::ZkretClient;
+
+use crate::{
+    config::RpcOptions,
+    offline::{read_json, TxFile},
+};
+
+pub const NAME: &str = "tx";
+pub const TX_SUBMIT: &str = "submit";
+
+#[must_use]
+pub fn submit_command() -> Command {
+    Command::new(TX_SUBMIT)
+        .about("Send a transaction proven offline")
+        .arg(arg!(<TX_PATH> "Transaction file made by `choice prove` or `reveal prove`"))
+}
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Handle transactions made offline")
+        .subcommands(vec![submit_command()])
+}
+
+pub async fn submit(tx_path: &str, opts: &RpcOptions) -> io::Result<()> {
+    let tx_file: TxFile = read_json(tx_path)?;
+    let chain_id = tx_file.chain_id;
+
+    let client = ZkretClient::new(
+        &opts.rpc_url_for(""),
+        &chain_id.to_string(),
+        opts.client_options(),
+    )?;
+    // the proof only verifies on the chain it was made for
+    if client.chain_id().await? != chain_id {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("the node at {} isn't on chain {chain_id}", client.url()),
+        ));
+    }
+
+    println!("Sending transaction...");
+    client.propose_block(&tx_file.transaction).await?;
+    println!("Done.");
+
+    Ok(())
+}
@@ -1,141 +1,148 @@
-use std::io;
-
-use santazk::{crypto::derive_participation_pubkey, hash::Hash};
-use zkretvm::block::transaction::{SBytes64, Transaction};
-
-const HTTP_RPC: &str = "http://127.0.0.1:9650";
-pub struct RpcClient {
-    pub url_path: String,
+use std::{
+    io::{self, Error, ErrorKind},
+    str::FromStr,
+};
+
+use avalanche_types::ids;
+use santazk::{
+    proofs::ProvingStage,
+    serialization::{compress_point, decompress_point, try_deserialize_jub_jub_affine_point},
+};
+use zkretvm::{
+    block::transaction::SBytes64,
+    client::{light::VerifiedState, ZkretClient},
+};
+
+use crate::{config::RpcOptions, keygen::ZkretKey};
+
+/// Connects to the chain and endpoint recorded in the key profile,
+/// unless the options override the endpoint.
+pub fn client_for_key(key: &ZkretKey, opts: &RpcOptions) -> io::Result<ZkretClient> {
+    ZkretClient::new(
+        &opts.rpc_url_for(&key.rpc_url),
+        &key.chain_id,
+        opts.client_options(),
+    )
 }
 
-impl RpcClient {
-    pub fn new(chain_id: &str) -> Self {
-        Self {
-            url_path: format!("/ext/bc/{}/rpc", chain_id),
-        }
-    }
-
-    pub async fn push_tx(&self, tx: Transaction) -> io::Result<()> {
-        let s = serde_json::to_string(&tx).unwrap();
-        let params_str = format!(r#"[{{"transaction": {}}}]"#, s);
-        let _ = self.make_request("proposeBlock", &params_str).await?;
-        Ok(())
-    }
-
-    pub async fn get_current_block_state(&self) -> io::Result<String> {
-        let params_str = r#"[]"#;
-        let resp = self.make_request("lastAccepted", params_str).await?;
-        let id = serde_json::from_str::<serde_json::Value>(&resp)
-            .unwrap()
-            .get("result")
-            .unwrap()
-            .get("id")
-            .unwrap()
-            .to_string();
-
-        let params_str = format!(r#"[{{"id": {}}}]"#, id);
-        let resp = self.make_request("getBlock", &params_str).await?;
-        let resp = serde_json::from_str::<serde_json::Value>(&resp)
-            .unwrap()
-            .get("result")
-            .unwrap()
-            .get("block")
-            .unwrap()
-            .get("block_state")
-            .unwrap()
-            .to_string();
-        Ok(resp)
-    }
-
-    pub async fn get_current_merkle_leaves(&self) -> io::Result<Vec<SBytes64>> {
-        let state = self.get_current_block_state().await?;
-        let res = serde_json::from_str::<serde_json::Value>(&state)
-            .unwrap()
-            .get("merkle_leaves")
-            .unwrap()
-            .to_string();
-
-        let res = serde_json::from_str::<Vec<SBytes64>>(&res).unwrap();
-        Ok(res)
-    }
-
-    pub async fn get_current_unclaimed_pub_keys(&self) -> io::Result<Vec<SBytes64>> {
-        let state = self.get_current_block_state().await?;
-        let res = serde_json::from_str::<serde_json::Value>(&state)
-            .unwrap()
-            .get("unclaimed_pub_keys")
-            .unwrap()
-            .to_string();
-
-        let res = serde_json::from_str::<Vec<SBytes64>>(&res).unwrap();
-        Ok(res)
-    }
-
-    pub async fn get_current_revealed_pub_keys(&self) -> io::Result<Vec<SBytes64>> {
-        let state = self.get_current_block_state().await?;
-        let res = serde_json::from_str::<serde_json::Value>(&state)
-            .unwrap()
-            .get("revealed_pub_keys")
-            .unwrap()
-            .to_string();
+/// Fetches the state of the last accepted block of `chain_id`, verified back to
+/// the checkpoint of the options or to `pinned`, the one pinned in the key file.
+/// Transactions are only proven against such state, so that the node can't make
+/// up who is in the group.
+pub async fn verified_state(
+    client: &ZkretClient,
+    chain_id: &str,
+    pinned: Option<&ids::Id>,
+    opts: &RpcOptions,
+) -> io::Result<VerifiedState> {
+    let reported = reported_chain_id(client, chain_id).await?;
+    let checkpoint = opts
+        .checkpoint_for(&reported)?
+        .or(pinned.copied())
+        .ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!(
+                    "no checkpoint to verify chain {reported} back to, pass the genesis block Id the organiser published with --checkpoint"
+                ),
+            )
+        })?;
+
+    println!("Verifying the chain...");
+    client.verified_state(&reported, &checkpoint).await
+}
 
-        let res = serde_json::from_str::<Vec<SBytes64>>(&res).unwrap();
-        Ok(res)
+/// Like [`verified_state`] for a key that has no checkpoint pinned yet: without
+/// one in the options, the chain is verified back to the genesis block the node
+/// serves, whose Id is printed for the user to check. The caller pins
+/// [`VerifiedState::checkpoint`] in the key file.
+pub async fn pin_checkpoint(
+    client: &ZkretClient,
+    chain_id: &str,
+    opts: &RpcOptions,
+) -> io::Result<VerifiedState> {
+    let reported = reported_chain_id(client, chain_id).await?;
+    if let Some(checkpoint) = opts.checkpoint_for(&reported)? {
+        println!("Verifying the chain...");
+        return client.verified_state(&reported, &checkpoint).await;
     }
 
-    pub async fn get_current_revealed_cts(&self) -> io::Result<Vec<Vec<u8>>> {
-        let state = self.get_current_block_state().await?;
-        let res = serde_json::from_str::<serde_json::Value>(&state)
-            .unwrap()
-            .get("revealed_cts")
-            .unwrap()
-            .to_string();
+    println!("Verifying the chain back to the genesis block the node serves...");
+    let state = client.genesis_state(&reported).await?;
+    println!(
+        "Pinned genesis block {} in the key file, check that it is the one the organiser published.",
+        state.checkpoint()
+    );
+    Ok(state)
+}
 
-        let res = serde_json::from_str::<Vec<Vec<u8>>>(&res).unwrap();
-        Ok(res)
+/// Returns the Id of the chain the node is on, if `chain_id` names it.
+pub async fn reported_chain_id(client: &ZkretClient, chain_id: &str) -> io::Result<ids::Id> {
+    let reported = client.chain_id().await?;
+    // an alias can only be resolved by the node
+    if let Ok(chain_id) = ids::Id::from_str(chain_id) {
+        if reported != chain_id {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("the node at {} isn't on chain {chain_id}", client.url()),
+            ));
+        }
     }
+    Ok(reported)
+}
 
-    pub async fn make_request(&self, method: &str, params: &str) -> io::Result<String> {
-        let req_str = format!(
-            r#"{{"jsonrpc":"2.0","id":1,"method":"zkretvm.{}","params":{}}}"#,
-            method, params
-        );
-        let res = http_manager::post_non_tls(HTTP_RPC, &self.url_path, &req_str).await?;
-        Ok(String::from_utf8(res).unwrap())
+/// Walks the chain back from the last accepted block looking for the CHOICE
+/// transaction that spent `nullifier`, and returns the public key it chose.
+pub async fn find_choice_by_nullifier(
+    client: &ZkretClient,
+    nullifier: &[u8],
+) -> io::Result<Option<SBytes64>> {
+    let nullifier = SBytes64::from_bytes(nullifier);
+
+    let mut block = client.last_accepted_block().await?;
+    loop {
+        // compare in the format the nullifier was given in
+        let tx = block.transaction().decode()?;
+        if tx.transaction_type == 2 && tx.data.1 == nullifier {
+            return Ok(Some(tx.data.0));
+        }
+        if block.height() == 0 {
+            return Ok(None);
+        }
+        block = client.get_block(&block.parent_id()).await?;
     }
 }
 
-pub fn generate_key_tuple(hasher: &Hash) -> (Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>) {
-    let secret_key = random_manager::secure_bytes(64).unwrap();
-    let nullifier = random_manager::secure_bytes(64).unwrap();
-    let dh_key = random_manager::secure_bytes(64).unwrap();
-
-    let pub_key = derive_participation_pubkey(hasher, &secret_key, &nullifier);
-
-    (secret_key, nullifier, pub_key, dh_key)
+/// Reports the stages of making a proof, as the `progress` callback of a prover.
+pub fn print_proving_stage(stage: ProvingStage) {
+    match stage {
+        ProvingStage::Witness => println!("Computing the witness..."),
+        ProvingStage::Proof => println!("Computing the proof..."),
+    }
 }
 
+/// Prints a public key as the hex of its compressed encoding.
 pub fn pub_key_to_printable(pub_key: &SBytes64) -> String {
-    let mut s = String::new();
-    for i in 0..pub_key.0.len() {
-        s.push_str(&format!("{:02x}", pub_key.0[i]));
-    }
-    for i in 0..pub_key.1.len() {
-        s.push_str(&format!("{:02x}", pub_key.1[i]));
+    match compress_point(&pub_key.to_vec()) {
+        Ok(compressed) => hex::encode(compressed),
+        Err(_) => hex::encode(pub_key.to_vec()),
     }
-    s
 }
 
-pub fn printable_to_pub_key(s: &str) -> SBytes64 {
-    let mut pub_key = SBytes64::default();
-    let mut i = 0;
-    while i < 32 {
-        pub_key.0[i] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
-        i += 1;
-    }
-    while i < 64 {
-        pub_key.1[i - 32] = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
-        i += 1;
-    }
-    pub_key
+/// Parses a public key printed by [`pub_key_to_printable`], or the hex of its
+/// uncompressed encoding printed by earlier versions.
+pub fn printable_to_pub_key(s: &str) -> io::Result<SBytes64> {
+    let invalid = || Error::new(ErrorKind::InvalidInput, format!("invalid public key {s}"));
+    let bytes = hex::decode(s.trim()).map_err(|_| invalid())?;
+    let pub_key = match bytes.len() {
+        32 => decompress_point(&bytes).map_err(|_| invalid())?,
+        64 => {
+            if try_deserialize_jub_jub_affine_point(&bytes).is_err() {
+                return Err(invalid());
+            }
+            bytes
+        }
+        _ => return Err(invalid()),
+    };
+    Ok(SBytes64::from_bytes(&pub_key))
 }
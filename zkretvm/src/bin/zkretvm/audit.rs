@@ -0,0 +1,86 @@
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::PathBuf,
+};
+
+use avalanche_types::key::secp256k1::private_key;
+use clap::{arg, ArgMatches, Command};
+use zkretvm::audit::{audit, ChainExport};
+
+pub const NAME: &str = "audit";
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Replay a chain export, re-verifying every proof, and print a signed report of the round")
+        .arg(
+            arg!(<FILE> "Chain export written by 'zkretvm export'")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--"signing-key" <FILE> "File holding the secp256k1 key to sign the report with, as PrivateKey-<CB58> or 0x<HEX>")
+                .required(true)
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--out <FILE> "Write the signed report to FILE instead of printing it")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg_required_else_help(true)
+}
+
+/// Audits the export and fails after writing the report if it found anomalies.
+pub fn run(matches: &ArgMatches) -> io::Result<()> {
+    let export = ChainExport::from_slice(fs::read(
+        matches.get_one::<PathBuf>("FILE").expect("required"),
+    )?)?;
+    let key = read_signing_key(matches.get_one::<PathBuf>("signing-key").expect("required"))?;
+
+    let report = audit(&export)?;
+    eprintln!(
+        "audited {} blocks: {} participants, {} choices, {} reveals, {} anomalies",
+        report.blocks,
+        report.participants,
+        report.choices,
+        report.reveals,
+        report.anomalies.len()
+    );
+    for anomaly in &report.anomalies {
+        eprintln!(
+            "  block {} at height {}: {}",
+            anomaly.block_id, anomaly.height, anomaly.message
+        );
+    }
+
+    let anomalies = report.anomalies.len();
+    let signed = serde_json::to_string_pretty(&report.sign(&key)?)?;
+    match matches.get_one::<PathBuf>("out") {
+        Some(out) => fs::write(out, signed)?,
+        None => println!("{signed}"),
+    }
+
+    if anomalies > 0 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("the chain has {anomalies} anomalies"),
+        ));
+    }
+    Ok(())
+}
+
+fn read_signing_key(path: &PathBuf) -> io::Result<private_key::Key> {
+    let encoded = fs::read_to_string(path)?;
+    let encoded = encoded.trim();
+    let key = if encoded.starts_with("0x") {
+        private_key::Key::from_hex(encoded)
+    } else {
+        private_key::Key::from_cb58(encoded)
+    };
+    key.map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("'{}' doesn't hold a private key: {e}", path.display()),
+        )
+    })
+}
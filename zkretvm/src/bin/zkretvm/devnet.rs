@@ -0,0 +1,49 @@
+use std::{io, net::SocketAddr};
+
+use clap::{arg, Command};
+use zkretvm::{
+    devnet::{Devnet, DEFAULT_CHAIN_ID},
+    genesis::Genesis,
+};
+
+use crate::genesis::{invites_arg, with_invites};
+
+pub const NAME: &str = "devnet";
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Run a single-node development network in-process, without avalanchego")
+        .arg(
+            arg!(--listen <ADDR> "Address to serve the RPC handlers at")
+                .default_value("127.0.0.1:9650")
+                .value_parser(clap::value_parser!(SocketAddr)),
+        )
+        .arg(
+            arg!(--"chain-id" <CHAIN_ID> "Chain ID to serve the RPC handlers under")
+                .default_value(DEFAULT_CHAIN_ID),
+        )
+        .arg(arg!(--"genesis-data" <DATA> "Genesis message data"))
+        .arg(invites_arg())
+}
+
+pub async fn run(
+    listen: SocketAddr,
+    chain_id: &str,
+    genesis_data: Option<&String>,
+    invites: Option<&String>,
+) -> io::Result<()> {
+    let genesis = with_invites(
+        genesis_data.map_or_else(Genesis::default, |data| Genesis::new(data)),
+        invites,
+    )?;
+    let devnet = Devnet::start(&genesis, chain_id, listen).await?;
+
+    println!("devnet is running, stop it with Ctrl-C");
+    println!("  rpc url:  {}", devnet.http_rpc());
+    println!("  chain id: {}", devnet.chain_id());
+    println!("  genesis:  {}", devnet.vm().genesis_block_id().await?);
+
+    tokio::signal::ctrl_c().await?;
+    devnet.shutdown().await
+}
@@ -0,0 +1,67 @@
+use std::{fs, io, path::PathBuf};
+
+use clap::{arg, ArgGroup, ArgMatches, Command};
+use zkretvm::{
+    audit::ChainExport,
+    client::{ClientOptions, ZkretClient},
+    node::chain_id_for_alias,
+    standalone::DEFAULT_CHAIN_ID,
+    state::{embedded::EmbeddedDatabase, storage::Storage, State},
+};
+
+pub const NAME: &str = "export";
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about(
+            "Export every accepted block of a chain, for 'zkretvm audit', from a running node \
+            over RPC or from a stopped standalone node's data directory",
+        )
+        .arg(arg!(--"rpc-url" <URL> "Base URL of a running node, e.g. http://127.0.0.1:9650"))
+        .arg(
+            arg!(--"data-dir" <DIR> "Data directory of a stopped standalone node")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .group(
+            ArgGroup::new("source")
+                .args(["rpc-url", "data-dir"])
+                .required(true),
+        )
+        .arg(
+            arg!(--"chain-id" <CHAIN_ID> "Chain ID the node serves its handlers under")
+                .default_value(DEFAULT_CHAIN_ID),
+        )
+        .arg(
+            arg!(--out <FILE> "File to write the chain export to")
+                .required(true)
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+}
+
+pub async fn run(matches: &ArgMatches) -> io::Result<()> {
+    let chain_id = matches.get_one::<String>("chain-id").expect("defaulted");
+    let out = matches.get_one::<PathBuf>("out").expect("required");
+
+    let export = if let Some(rpc_url) = matches.get_one::<String>("rpc-url") {
+        let client = ZkretClient::new(rpc_url, chain_id, ClientOptions::default())?;
+        ChainExport::from_client(&client).await?
+    } else {
+        let data_dir = matches.get_one::<PathBuf>("data-dir").expect("grouped");
+        let db = EmbeddedDatabase::open(data_dir)?;
+        let mut state = State::open(db.clone().boxed()).await?;
+        state.chain_id = chain_id_for_alias(chain_id);
+        let export = ChainExport::from_state(&state).await;
+        db.close().await?;
+        export?
+    };
+
+    fs::write(out, export.to_vec()?)?;
+    println!(
+        "exported {} blocks of chain {} to '{}'",
+        export.blocks.len(),
+        export.chain_id,
+        out.display()
+    );
+    Ok(())
+}
@@ -1,4 +1,10 @@
-use clap::{arg, Command};
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+};
+
+use clap::{arg, Arg, Command};
+use zkretvm::genesis::Genesis;
 
 pub const NAME: &str = "genesis";
 
@@ -7,5 +13,42 @@ pub fn command() -> Command {
     Command::new(NAME)
         .about("Write a genesis file")
         .arg(arg!(<DATA> "Genesis message data"))
+        .arg(invites_arg())
         .arg_required_else_help(true)
 }
+
+/// Returns the `--invites` argument, shared by the commands that create a chain.
+#[must_use]
+pub fn invites_arg() -> Arg {
+    arg!(--invites <FILE> "Make the group invite-only, with the invite commitments written by 'zkretctl invite issue'")
+}
+
+/// Adds the invite commitments read from `invites_path` to `genesis`, if there is one.
+pub fn with_invites(mut genesis: Genesis, invites_path: Option<&String>) -> io::Result<Genesis> {
+    if let Some(path) = invites_path {
+        genesis.invites = read_invites(path)?;
+    }
+    Ok(genesis)
+}
+
+/// Reads one hex-encoded invite commitment per line.
+fn read_invites(path: &str) -> io::Result<Vec<[u8; 64]>> {
+    let invalid = |line: &str| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("'{line}' in '{path}' is not an invite commitment"),
+        )
+    };
+
+    fs::read_to_string(path)?
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            hex::decode(line)
+                .ok()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(|| invalid(line))
+        })
+        .collect()
+}
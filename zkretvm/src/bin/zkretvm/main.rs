@@ -1,12 +1,16 @@
+pub mod audit;
+pub mod devnet;
+pub mod export;
 pub mod genesis;
+pub mod standalone;
 pub mod vm_id;
 
-use std::io;
+use std::{io, net::SocketAddr};
 
 use avalanche_types::subnet;
 use clap::{crate_version, Command};
-use zkretvm::vm;
 use tokio::sync::broadcast::{self, Receiver, Sender};
+use zkretvm::vm;
 
 pub const APP_NAME: &str = "zkretvm";
 
@@ -15,7 +19,14 @@ async fn main() -> io::Result<()> {
     let matches = Command::new(APP_NAME)
         .version(crate_version!())
         .about("zkretvm")
-        .subcommands(vec![genesis::command(), vm_id::command()])
+        .subcommands(vec![
+            audit::command(),
+            devnet::command(),
+            export::command(),
+            genesis::command(),
+            standalone::command(),
+            vm_id::command(),
+        ])
         .get_matches();
 
     // ref. https://github.com/env-logger-rs/env_logger/issues/47
@@ -24,14 +35,39 @@ async fn main() -> io::Result<()> {
     );
 
     match matches.subcommand() {
+        Some((audit::NAME, sub_matches)) => audit::run(sub_matches),
+
+        Some((devnet::NAME, sub_matches)) => {
+            let listen = sub_matches
+                .get_one::<SocketAddr>("listen")
+                .expect("defaulted");
+            let chain_id = sub_matches
+                .get_one::<String>("chain-id")
+                .expect("defaulted");
+            devnet::run(
+                *listen,
+                chain_id,
+                sub_matches.get_one::<String>("genesis-data"),
+                sub_matches.get_one::<String>("invites"),
+            )
+            .await
+        }
+
+        Some((export::NAME, sub_matches)) => export::run(sub_matches).await,
+
         Some((genesis::NAME, sub_matches)) => {
             let data = sub_matches.get_one::<String>("DATA").expect("required");
-            let genesis = zkretvm::genesis::Genesis { data: data.clone() };
+            let genesis = genesis::with_invites(
+                zkretvm::genesis::Genesis::new(data),
+                sub_matches.get_one::<String>("invites"),
+            )?;
             println!("{genesis}");
 
             Ok(())
         }
 
+        Some((standalone::NAME, sub_matches)) => standalone::run(sub_matches).await,
+
         Some((vm_id::NAME, sub_matches)) => {
             let vm_name = sub_matches.get_one::<String>("VM_NAME").expect("required");
             let id = subnet::vm_name_to_id(vm_name)?;
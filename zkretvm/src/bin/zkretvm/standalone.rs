@@ -0,0 +1,68 @@
+use std::{io, net::SocketAddr, path::PathBuf, time::Duration};
+
+use clap::{arg, ArgMatches, Command};
+use zkretvm::{
+    genesis::Genesis,
+    standalone::{Config, Standalone, DEFAULT_BLOCK_INTERVAL, DEFAULT_CHAIN_ID},
+};
+
+use crate::genesis::{invites_arg, with_invites};
+
+pub const NAME: &str = "standalone";
+
+#[must_use]
+pub fn command() -> Command {
+    Command::new(NAME)
+        .about("Run a single-node chain with its own HTTP server and persistent store, without an Avalanche subnet")
+        .arg(
+            arg!(--"data-dir" <DIR> "Directory holding the chain state")
+                .required(true)
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            arg!(--listen <ADDR> "Address to serve the RPC handlers at")
+                .default_value("127.0.0.1:9650")
+                .value_parser(clap::value_parser!(SocketAddr)),
+        )
+        .arg(arg!(--"chain-id" <CHAIN_ID> "Chain ID to serve the RPC handlers under").default_value(DEFAULT_CHAIN_ID))
+        .arg(
+            arg!(--"block-interval-ms" <MS> "Interval between block builds in milliseconds")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(arg!(--"genesis-data" <DATA> "Genesis message data, used when creating the chain"))
+        .arg(invites_arg())
+}
+
+pub async fn run(matches: &ArgMatches) -> io::Result<()> {
+    let config = Config {
+        data_dir: matches
+            .get_one::<PathBuf>("data-dir")
+            .expect("required")
+            .clone(),
+        chain_id: matches
+            .get_one::<String>("chain-id")
+            .expect("defaulted")
+            .clone(),
+        listen: *matches.get_one::<SocketAddr>("listen").expect("defaulted"),
+        block_interval: matches
+            .get_one::<u64>("block-interval-ms")
+            .map_or(DEFAULT_BLOCK_INTERVAL, |ms| Duration::from_millis(*ms)),
+    };
+    let genesis = with_invites(
+        matches
+            .get_one::<String>("genesis-data")
+            .map_or_else(Genesis::default, |data| Genesis::new(data)),
+        matches.get_one::<String>("invites"),
+    )?;
+
+    let node = Standalone::start(&config, &genesis).await?;
+
+    println!("standalone node is running, stop it with Ctrl-C");
+    println!("  rpc url:  {}", node.http_rpc());
+    println!("  chain id: {}", node.chain_id());
+    println!("  genesis:  {}", node.vm().genesis_block_id().await?);
+    println!("  data dir: {}", config.data_dir.display());
+
+    tokio::signal::ctrl_c().await?;
+    node.shutdown().await
+}
@@ -197,66 +197,108 @@ impl Block {
 
         let prnt_blk = self.state.get_block(&self.parent_id).await?;
 
-        // ensure the height of the block is immediately following its parent
-        if prnt_blk.height != self.height - 1 {
+        let one_hour_from_now = Utc::now() + Duration::hours(1);
+        let one_hour_from_now = one_hour_from_now
+            .timestamp()
+            .try_into()
+            .expect("failed to convert timestamp from i64 to u64");
+
+        // ensure block timestamp is no more than an hour ahead of this nodes time
+        if self.timestamp >= one_hour_from_now {
             return Err(Error::new(
                 ErrorKind::InvalidData,
                 format!(
-                    "parent block height {} != current block height {} - 1",
-                    prnt_blk.height, self.height
+                    "block timestamp {} is more than 1 hour ahead of local time",
+                    self.timestamp
                 ),
             ));
         }
 
-        // ensure block timestamp is after its parent
-        if prnt_blk.timestamp > self.timestamp {
+        self.verify_against_parent(&prnt_blk, &self.state.chain_id)?;
+
+        // add newly verified block to memory
+        self.state.add_verified(&self.clone()).await;
+        Ok(())
+    }
+
+    /// Verifies that the block follows `parent`, and that its transaction is valid
+    /// against the parent's state on the chain `chain_id` and leads to its state.
+    /// Unlike [`Block::verify`], this needs nothing but the two blocks, so light
+    /// clients can check the blocks a node serves them.
+    /// # Errors
+    /// Fails if any of the checks fails.
+    pub fn verify_against_parent(&self, parent: &Block, chain_id: &ids::Id) -> io::Result<()> {
+        if self.parent_id != parent.id {
             return Err(Error::new(
                 ErrorKind::InvalidData,
                 format!(
-                    "parent block timestamp {} > current block timestamp {}",
-                    prnt_blk.timestamp, self.timestamp
+                    "block {} has parent {}, not {}",
+                    self.id, self.parent_id, parent.id
                 ),
             ));
         }
 
-        let one_hour_from_now = Utc::now() + Duration::hours(1);
-        let one_hour_from_now = one_hour_from_now
-            .timestamp()
-            .try_into()
-            .expect("failed to convert timestamp from i64 to u64");
+        // ensure the height of the block is immediately following its parent
+        if self.height.checked_sub(1) != Some(parent.height) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "parent block height {} != current block height {} - 1",
+                    parent.height, self.height
+                ),
+            ));
+        }
 
-        // ensure block timestamp is no more than an hour ahead of this nodes time
-        if self.timestamp >= one_hour_from_now {
+        // ensure block timestamp is after its parent
+        if parent.timestamp > self.timestamp {
             return Err(Error::new(
                 ErrorKind::InvalidData,
                 format!(
-                    "block timestamp {} is more than 1 hour ahead of local time",
-                    self.timestamp
+                    "parent block timestamp {} > current block timestamp {}",
+                    parent.timestamp, self.timestamp
                 ),
             ));
         }
 
-        if !self.transaction.verify(&prnt_blk.block_state) {
+        if !self.transaction.verify(&parent.block_state, chain_id) {
             return Err(Error::new(
                 ErrorKind::InvalidData,
                 format!("block {} transaction is invalid", self.id),
             ));
         }
 
-        let mut updated_state = prnt_blk.block_state.clone();
+        let mut updated_state = parent.block_state.clone();
         self.transaction.update_state(&mut updated_state);
         if self.block_state != updated_state {
             return Err(Error::new(
                 ErrorKind::InvalidData,
-                format!(
-                    "block {} state is not equal to updated state",
-                    self.id
-                ),
+                format!("block {} state is not equal to updated state", self.id),
             ));
         }
 
-        // add newly verified block to memory
-        self.state.add_verified(&self.clone()).await;
+        Ok(())
+    }
+
+    /// Verifies that this is a genesis block, which starts a chain nobody has entered.
+    /// # Errors
+    /// Fails if the block isn't at height 0 without a parent, if its transaction
+    /// isn't a genesis transaction or if its state holds more than the allowlist.
+    pub fn verify_genesis(&self) -> io::Result<()> {
+        let is_genesis = self.height == 0
+            && self.parent_id == ids::Id::empty()
+            && self.transaction.transaction_type == 0
+            && self.block_state
+                == BlockState {
+                    invite_root: self.block_state.invite_root,
+                    invites: self.block_state.invites.clone(),
+                    ..Default::default()
+                };
+        if !is_genesis {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("block {} is not a genesis block", self.id),
+            ));
+        }
         Ok(())
     }
 
@@ -266,9 +308,8 @@ impl Block {
     pub async fn accept(&mut self) -> io::Result<()> {
         self.set_status(choices::status::Status::Accepted);
 
-        // only decided blocks are persistent -- no reorg
-        self.state.write_block(&self.clone()).await?;
-        self.state.set_last_accepted_block(&self.id()).await?;
+        // only accepted blocks are persistent -- no reorg
+        self.state.write_accepted_block(&self.clone()).await?;
 
         self.state.remove_verified(&self.id()).await;
         Ok(())
@@ -280,9 +321,7 @@ impl Block {
     pub async fn reject(&mut self) -> io::Result<()> {
         self.set_status(choices::status::Status::Rejected);
 
-        // only decided blocks are persistent -- no reorg
-        self.state.write_block(&self.clone()).await?;
-
+        // rejected blocks are dropped, they never make it to the accepted chain
         self.state.remove_verified(&self.id()).await;
         Ok(())
     }
@@ -1,42 +1,87 @@
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    io::{self, Error, ErrorKind},
+};
 
+use avalanche_types::ids;
 use derivative::{self, Derivative};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
+#[cfg(feature = "marlin")]
+use santazk::proofs::Marlin;
 use santazk::{
     hash::Hash,
     merkle::MerkleTree,
-    proofs::{ChoiceAuthVerifier, RevealAuthVerifier},
+    participant::{ChoiceTx, EnterTx, ProtocolState, RevealTx},
+    proofs::{
+        proof_system_id, ChoiceAuthVerifier, EnterAuthVerifier, Groth16, InviteEnterAuthVerifier,
+        ProofSystem, RevealAuthVerifier,
+    },
+    serialization::{compress_point, decompress_point, try_deserialize_jub_jub_affine_point},
 };
 
-const MERKLE_TREE_DEPTH: usize = 7;
+use crate::genesis::Genesis;
+
+pub use santazk::participant::INVITE_TREE_DEPTH;
+pub(crate) use santazk::participant::MERKLE_TREE_DEPTH;
 
 pub(crate) type Bytes64 = [u8; 64];
 
+/// Transaction format whose point fields hold the 64-byte x‖y encoding.
+pub const TX_VERSION_UNCOMPRESSED: u8 = 0;
+/// Transaction format whose point fields hold the 32-byte compressed encoding.
+pub const TX_VERSION_COMPRESSED: u8 = 1;
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Copy, Derivative, Default)]
 #[derivative(Debug, PartialEq, Eq)]
 pub struct SBytes64(pub [u8; 32], pub [u8; 32]); // serde-serializable Bytes64
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SBytes32(pub [u8; 32]); // serde-serializable compressed point
+
+/// A fixed-size field of a transaction on the wire.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TxField {
+    /// 64 bytes, the x‖y encoding of the points of [`TX_VERSION_UNCOMPRESSED`].
+    Bytes64(SBytes64),
+    /// The compressed encoding of a point of [`TX_VERSION_COMPRESSED`].
+    Bytes32(SBytes32),
+}
+
+impl Default for TxField {
+    fn default() -> Self {
+        TxField::Bytes64(SBytes64::default())
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Derivative, Default)]
 #[derivative(Debug, PartialEq, Eq)]
-pub struct TransactionData(
-    pub SBytes64,
-    pub SBytes64,
-    pub SBytes64,
-    pub SBytes64,
-    pub Vec<u8>,
-    pub Vec<u8>,
-);
+pub struct TransactionData<F = TxField>(pub F, pub F, pub F, pub F, pub Vec<u8>, pub Vec<u8>);
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Derivative, Default)]
 #[derivative(Debug, PartialEq, Eq)]
-pub struct Transaction {
+pub struct Transaction<F = TxField> {
     pub transaction_type: u8,
-    pub data: TransactionData,
+    /// Encoding of the point fields, left out for [`TX_VERSION_UNCOMPRESSED`]
+    /// so that the blocks of that format keep their Ids.
+    #[serde(default, skip_serializing_if = "is_uncompressed")]
+    pub version: u8,
+    pub data: TransactionData<F>,
+}
+
+/// A transaction with its points in the 64-byte form the state is kept in, as
+/// returned by [`Transaction::decode`].
+#[allow(clippy::module_name_repetitions)]
+pub type DecodedTransaction = Transaction<SBytes64>;
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_uncompressed(version: &u8) -> bool {
+    *version == TX_VERSION_UNCOMPRESSED
 }
 
 #[serde_as]
@@ -49,6 +94,110 @@ pub struct BlockState {
     pub unclaimed_pub_keys: Vec<SBytes64>,
     pub revealed_pub_keys: Vec<SBytes64>,
     pub revealed_cts: Vec<Vec<u8>>,
+
+    /// Root of the allowlist Merkle tree, set if the group is invite-only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invite_root: Option<SBytes64>,
+    /// Invite commitments the allowlist Merkle tree is built from.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invites: Vec<SBytes64>,
+    /// Nullifiers of the invites redeemed by ENTER transactions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invite_nullifiers: Vec<SBytes64>,
+}
+
+impl BlockState {
+    /// Returns the state before the first transaction of the chain created from `genesis`.
+    /// # Errors
+    /// Fails if the genesis has more invites than the allowlist Merkle tree can hold.
+    pub fn from_genesis(genesis: &Genesis) -> io::Result<Self> {
+        if genesis.invites.is_empty() {
+            return Ok(Self::default());
+        }
+        if genesis.invites.len() > 1 << INVITE_TREE_DEPTH {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "genesis has {} invites, but at most {} are supported",
+                    genesis.invites.len(),
+                    1 << INVITE_TREE_DEPTH
+                ),
+            ));
+        }
+
+        let invites = genesis
+            .invites
+            .iter()
+            .map(|invite| SBytes64::from_bytes(invite))
+            .collect::<Vec<_>>();
+        let invite_tree = MerkleTree::new(
+            INVITE_TREE_DEPTH,
+            &invites.iter().map(SBytes64::to_vec).collect::<Vec<_>>(),
+        );
+
+        Ok(Self {
+            invite_root: Some(SBytes64::from_bytes(&invite_tree.root())),
+            invites,
+            ..Default::default()
+        })
+    }
+
+    /// Checks that the Merkle root and the allowlist root are those of the Merkle
+    /// leaves and the invites, which the RPCs serve separately.
+    /// # Errors
+    /// Fails if a root doesn't match what it is computed from.
+    pub fn verify_roots(&self) -> io::Result<()> {
+        let to_vecs = |points: &[SBytes64]| points.iter().map(SBytes64::to_vec).collect::<Vec<_>>();
+
+        // the root of a group nobody entered is left unset
+        let merkle_root = if self.merkle_leaves.is_empty() {
+            SBytes64::default()
+        } else {
+            SBytes64::from_bytes(
+                &MerkleTree::new(MERKLE_TREE_DEPTH, &to_vecs(&self.merkle_leaves)).root(),
+            )
+        };
+        if self.merkle_root != merkle_root {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Merkle leaves don't match the Merkle root",
+            ));
+        }
+
+        let invite_root = (!self.invites.is_empty()).then(|| {
+            SBytes64::from_bytes(
+                &MerkleTree::new(INVITE_TREE_DEPTH, &to_vecs(&self.invites)).root(),
+            )
+        });
+        if self.invite_root != invite_root {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "invites don't match the allowlist root",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the state as a participant of the chain `chain_id` sees it.
+    #[must_use]
+    pub fn protocol_state(&self, chain_id: &ids::Id) -> ProtocolState {
+        let to_vecs = |points: &[SBytes64]| points.iter().map(SBytes64::to_vec).collect();
+        ProtocolState {
+            chain_id: chain_id.to_vec(),
+            merkle_root: self.merkle_root.to_vec(),
+            merkle_leaves: to_vecs(&self.merkle_leaves),
+            invite_root: self.invite_root.as_ref().map(SBytes64::to_vec),
+            invites: to_vecs(&self.invites),
+            invite_nullifiers: to_vecs(&self.invite_nullifiers),
+            reveals: self
+                .revealed_pub_keys
+                .iter()
+                .map(SBytes64::to_vec)
+                .zip(self.revealed_cts.iter().cloned())
+                .collect(),
+        }
+    }
 }
 
 impl Transaction {
@@ -61,16 +210,186 @@ impl Transaction {
         transaction
     }
 
-    pub(crate) fn enter(pub_key: &[u8]) -> Self {
-        let mut transaction = Transaction {
+    /// Creates an ENTER transaction of an open group.
+    /// # Errors
+    /// Fails if `pub_key` isn't a valid point.
+    pub fn enter(pub_key: &[u8], proof: Vec<u8>) -> io::Result<Self> {
+        let mut transaction = DecodedTransaction {
             transaction_type: 1,
             ..Default::default()
         };
         transaction.data.0 = SBytes64::from_bytes(pub_key);
-        transaction
+        transaction.data.4 = proof;
+        transaction.compressed()
     }
 
-    pub(crate) fn verify(&self, bs: &BlockState) -> bool {
+    /// Creates an ENTER transaction of an invite-only group, which spends `invite_nullifier`.
+    /// # Errors
+    /// Fails if `pub_key` or `invite_nullifier` isn't a valid point.
+    pub fn enter_with_invite(
+        pub_key: &[u8],
+        invite_nullifier: &[u8],
+        proof: Vec<u8>,
+    ) -> io::Result<Self> {
+        let mut transaction = DecodedTransaction {
+            transaction_type: 1,
+            ..Default::default()
+        };
+        transaction.data.0 = SBytes64::from_bytes(pub_key);
+        transaction.data.1 = SBytes64::from_bytes(invite_nullifier);
+        transaction.data.4 = proof;
+        transaction.compressed()
+    }
+
+    /// Creates a CHOICE transaction for the public key `choice`.
+    /// # Errors
+    /// Fails if `choice` or `signature` isn't a valid point.
+    pub fn choice(
+        choice: &[u8],
+        nullifier: &[u8],
+        dh_pub_key: &[u8],
+        signature: &[u8],
+        proof: Vec<u8>,
+    ) -> io::Result<Self> {
+        DecodedTransaction {
+            transaction_type: 2,
+            version: TX_VERSION_UNCOMPRESSED,
+            data: TransactionData(
+                SBytes64::from_bytes(choice),
+                SBytes64::from_bytes(nullifier),
+                SBytes64::from_bytes(dh_pub_key),
+                SBytes64::from_bytes(signature),
+                proof,
+                Vec::new(),
+            ),
+        }
+        .compressed()
+    }
+
+    /// Creates a REVEAL transaction of the ciphertext `ct`.
+    /// # Errors
+    /// Fails if `pub_key`, `ct_hash` or `signature` isn't a valid point.
+    pub fn reveal(
+        pub_key: &[u8],
+        ct_hash: &[u8],
+        dh_pub_key: &[u8],
+        signature: &[u8],
+        ct: Vec<u8>,
+        proof: Vec<u8>,
+    ) -> io::Result<Self> {
+        DecodedTransaction {
+            transaction_type: 3,
+            version: TX_VERSION_UNCOMPRESSED,
+            data: TransactionData(
+                SBytes64::from_bytes(pub_key),
+                SBytes64::from_bytes(ct_hash),
+                SBytes64::from_bytes(dh_pub_key),
+                SBytes64::from_bytes(signature),
+                ct,
+                proof,
+            ),
+        }
+        .compressed()
+    }
+
+    /// Returns the transaction with its point fields decoded into the 64-byte
+    /// form the state is kept in, after checking that they hold canonical
+    /// encodings of points in the prime-order subgroup.
+    /// # Errors
+    /// Fails if the version is unknown or if a field is invalid.
+    pub fn decode(&self) -> io::Result<DecodedTransaction> {
+        if ![TX_VERSION_UNCOMPRESSED, TX_VERSION_COMPRESSED].contains(&self.version) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported transaction version {}", self.version),
+            ));
+        }
+
+        let points = point_fields(self.transaction_type);
+        let mut decoded = [SBytes64::default(); 4];
+        for (index, decoded) in decoded.iter_mut().enumerate() {
+            let field = *self.data.field(index);
+            let Some(&(_, name, optional)) = points.iter().find(|point| point.0 == index) else {
+                // the fields that aren't points are 64 bytes in both formats
+                match field {
+                    TxField::Bytes64(bytes) => *decoded = bytes,
+                    TxField::Bytes32(_) => {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!("field {index} isn't 64 bytes"),
+                        ))
+                    }
+                }
+                continue;
+            };
+
+            let invalid = || Error::new(ErrorKind::InvalidData, format!("invalid {name} point"));
+            *decoded = match field {
+                _ if optional && field == TxField::default() => SBytes64::default(),
+                TxField::Bytes64(bytes) if self.version == TX_VERSION_UNCOMPRESSED => {
+                    try_deserialize_jub_jub_affine_point(&bytes.to_vec())
+                        .map(|_| bytes)
+                        .map_err(|_| invalid())?
+                }
+                TxField::Bytes32(bytes) if self.version == TX_VERSION_COMPRESSED => {
+                    SBytes64::from_bytes(&decompress_point(&bytes.0).map_err(|_| invalid())?)
+                }
+                _ => return Err(invalid()),
+            };
+        }
+
+        let [d0, d1, d2, d3] = decoded;
+        Ok(DecodedTransaction {
+            transaction_type: self.transaction_type,
+            version: TX_VERSION_UNCOMPRESSED,
+            data: TransactionData(d0, d1, d2, d3, self.data.4.clone(), self.data.5.clone()),
+        })
+    }
+
+    /// Verifies the transaction against the state `bs`, in either format.
+    pub(crate) fn verify(&self, bs: &BlockState, chain_id: &ids::Id) -> bool {
+        match self.decode() {
+            Ok(tx) => tx.verify_decoded(bs, chain_id),
+            Err(e) => {
+                log::info!("invalid transaction: {e}");
+                false
+            }
+        }
+    }
+
+    /// Applies a verified transaction to the state `bs`.
+    pub(crate) fn update_state(&self, bs: &mut BlockState) {
+        match self.decode() {
+            Ok(tx) => tx.update_decoded_state(bs),
+            Err(e) => log::warn!("not applying invalid transaction: {e}"),
+        }
+    }
+}
+
+impl DecodedTransaction {
+    /// Converts the transaction to the [`TX_VERSION_COMPRESSED`] format.
+    fn compressed(self) -> io::Result<Transaction> {
+        let points = point_fields(self.transaction_type);
+        let mut compressed = Transaction::from(self);
+        for &(index, name, optional) in points {
+            let field = compressed.data.field_mut(index);
+            let TxField::Bytes64(bytes) = *field else {
+                continue;
+            };
+            if optional && bytes == SBytes64::default() {
+                continue;
+            }
+
+            let point = compress_point(&bytes.to_vec()).map_err(|_| {
+                Error::new(ErrorKind::InvalidInput, format!("invalid {name} point"))
+            })?;
+            *field = TxField::Bytes32(SBytes32(point.try_into().unwrap()));
+        }
+        compressed.version = TX_VERSION_COMPRESSED;
+        Ok(compressed)
+    }
+
+    fn verify_decoded(&self, bs: &BlockState, chain_id: &ids::Id) -> bool {
         let entered_pub_keys_set = bs
             .merkle_leaves
             .iter()
@@ -99,7 +418,29 @@ impl Transaction {
                 if entered_pub_keys_set.contains(&pub_key) {
                     return false;
                 }
-                true
+
+                if let Some(invite_root) = &bs.invite_root {
+                    let invite_nullifier = self.data.1;
+                    if bs.invite_nullifiers.contains(&invite_nullifier) {
+                        return false;
+                    }
+
+                    let iea_verifier = InviteEnterAuthVerifier::new();
+                    return iea_verifier.verify(
+                        &self.data.4, // proof
+                        &pub_key,
+                        &chain_id.to_vec(),
+                        &invite_root.to_u8_64(),
+                        &invite_nullifier.to_u8_64(),
+                    );
+                }
+
+                let ea_verifier = EnterAuthVerifier::new();
+                ea_verifier.verify(
+                    &self.data.4, // proof
+                    &pub_key,
+                    &chain_id.to_vec(),
+                )
             }
             2 => {
                 let nullifier = self.data.1.to_u8_64();
@@ -117,12 +458,12 @@ impl Transaction {
 
                 let root = bs.merkle_root.to_u8_64();
 
-                let ca_verifier = ChoiceAuthVerifier::new();
-                ca_verifier.verify(
+                verify_choice_proof(
                     &self.data.4, // proof
                     &nullifier,
                     &root,
                     &choice,
+                    &chain_id.to_vec(),
                     &dh_pub_key,
                     &signature,
                 )
@@ -148,11 +489,11 @@ impl Transaction {
                 let dh_pub_key = self.data.2.to_u8_64();
                 let signature = self.data.3.to_u8_64();
 
-                let ra_verifier = RevealAuthVerifier::new();
-                ra_verifier.verify(
+                verify_reveal_proof(
                     &self.data.5, // proof
                     &pk,
                     &ct_hash,
+                    &chain_id.to_vec(),
                     &dh_pub_key,
                     &signature,
                 )
@@ -161,12 +502,15 @@ impl Transaction {
         }
     }
 
-    pub(crate) fn update_state(&self, bs: &mut BlockState) {
+    fn update_decoded_state(&self, bs: &mut BlockState) {
         match self.transaction_type {
             1 => {
                 let pub_key = self.data.0;
                 bs.merkle_leaves.push(pub_key);
                 bs.unclaimed_pub_keys.push(pub_key);
+                if bs.invite_root.is_some() {
+                    bs.invite_nullifiers.push(self.data.1);
+                }
                 bs.merkle_root = SBytes64::from_bytes(
                     &MerkleTree::new(
                         MERKLE_TREE_DEPTH,
@@ -197,6 +541,144 @@ impl Transaction {
     }
 }
 
+/// Returns the fields of a transaction of type `transaction_type` that hold
+/// points, with their names and whether they may be left zero.
+fn point_fields(transaction_type: u8) -> &'static [(usize, &'static str, bool)] {
+    match transaction_type {
+        // the invite nullifier is only set in invite-only groups
+        1 => &[(0, "public key", false), (1, "invite nullifier", true)],
+        2 => &[(0, "choice", false), (3, "signature", false)],
+        3 => &[
+            (0, "public key", false),
+            (1, "ciphertext hash", false),
+            (3, "signature", false),
+        ],
+        _ => &[],
+    }
+}
+
+impl<F> TransactionData<F> {
+    fn field(&self, index: usize) -> &F {
+        match index {
+            0 => &self.0,
+            1 => &self.1,
+            2 => &self.2,
+            _ => &self.3,
+        }
+    }
+
+    fn field_mut(&mut self, index: usize) -> &mut F {
+        match index {
+            0 => &mut self.0,
+            1 => &mut self.1,
+            2 => &mut self.2,
+            _ => &mut self.3,
+        }
+    }
+}
+
+impl From<DecodedTransaction> for Transaction {
+    /// Returns the transaction in the [`TX_VERSION_UNCOMPRESSED`] format.
+    fn from(tx: DecodedTransaction) -> Self {
+        let TransactionData(d0, d1, d2, d3, d4, d5) = tx.data;
+        Transaction {
+            transaction_type: tx.transaction_type,
+            version: TX_VERSION_UNCOMPRESSED,
+            data: TransactionData(
+                TxField::Bytes64(d0),
+                TxField::Bytes64(d1),
+                TxField::Bytes64(d2),
+                TxField::Bytes64(d3),
+                d4,
+                d5,
+            ),
+        }
+    }
+}
+
+impl TryFrom<EnterTx> for Transaction {
+    type Error = Error;
+
+    fn try_from(tx: EnterTx) -> io::Result<Self> {
+        match tx.invite_nullifier {
+            Some(invite_nullifier) => {
+                Transaction::enter_with_invite(&tx.pub_key, &invite_nullifier, tx.proof)
+            }
+            None => Transaction::enter(&tx.pub_key, tx.proof),
+        }
+    }
+}
+
+impl TryFrom<ChoiceTx> for Transaction {
+    type Error = Error;
+
+    fn try_from(tx: ChoiceTx) -> io::Result<Self> {
+        Transaction::choice(
+            &tx.choice,
+            &tx.nullifier,
+            &tx.dh_pub_key,
+            &tx.signature,
+            tx.proof,
+        )
+    }
+}
+
+impl TryFrom<RevealTx> for Transaction {
+    type Error = Error;
+
+    fn try_from(tx: RevealTx) -> io::Result<Self> {
+        Transaction::reveal(
+            &tx.pub_key,
+            &tx.ciphertext_hash,
+            &tx.dh_pub_key,
+            &tx.signature,
+            tx.ciphertext,
+            tx.proof,
+        )
+    }
+}
+
+/// Verifies a CHOICE proof with the proof system it is tagged with.
+fn verify_choice_proof(
+    proof: &[u8],
+    nullifier: &[u8],
+    root: &[u8],
+    choice: &[u8],
+    chain_id: &[u8],
+    dh_pub_key: &[u8],
+    signature: &[u8],
+) -> bool {
+    match proof_system_id(proof) {
+        Some(Groth16::ID) => ChoiceAuthVerifier::new().verify(
+            proof, nullifier, root, choice, chain_id, dh_pub_key, signature,
+        ),
+        #[cfg(feature = "marlin")]
+        Some(Marlin::ID) => ChoiceAuthVerifier::<Marlin>::embedded().verify(
+            proof, nullifier, root, choice, chain_id, dh_pub_key, signature,
+        ),
+        _ => false,
+    }
+}
+
+/// Verifies a REVEAL proof with the proof system it is tagged with.
+fn verify_reveal_proof(
+    proof: &[u8],
+    pub_key: &[u8],
+    ct_hash: &[u8],
+    chain_id: &[u8],
+    dh_pub_key: &[u8],
+    signature: &[u8],
+) -> bool {
+    match proof_system_id(proof) {
+        Some(Groth16::ID) => RevealAuthVerifier::new()
+            .verify(proof, pub_key, ct_hash, chain_id, dh_pub_key, signature),
+        #[cfg(feature = "marlin")]
+        Some(Marlin::ID) => RevealAuthVerifier::<Marlin>::embedded()
+            .verify(proof, pub_key, ct_hash, chain_id, dh_pub_key, signature),
+        _ => false,
+    }
+}
+
 impl SBytes64 {
     #[must_use]
     pub fn from_bytes(bytes: &[u8]) -> Self {
@@ -0,0 +1,245 @@
+//! Light-client verification of the blocks an RPC node serves.
+//!
+//! A node that lies about the Merkle leaves can tell who is choosing from the
+//! root their CHOICE is proven against, so clients only prove against a
+//! [`VerifiedState`]: the state of the last accepted block, once every block back
+//! to a checkpoint has been checked against its parent and the checkpoint's state
+//! against its Merkle roots.
+//!
+//! The checkpoint is a block Id the client trusts, e.g. the genesis block Id the
+//! organiser of the group published. There is no default: a node could otherwise
+//! serve a chain of its own from a genesis block it made, with only ENTERs of keys
+//! it controls. [`ZkretClient::genesis_state`] trusts the genesis block the node
+//! serves, for clients to pin a checkpoint on first use. Without consensus, a
+//! node can still serve a fork of valid blocks after the checkpoint, so later
+//! checkpoints narrow what it can fake.
+
+use std::io::{self, Error, ErrorKind};
+
+use avalanche_types::ids;
+use santazk::participant::ProtocolState;
+
+use super::ZkretClient;
+use crate::block::{transaction::BlockState, Block};
+
+/// The state of a block that was verified back to a checkpoint.
+#[derive(Debug, Clone)]
+pub struct VerifiedState {
+    chain_id: ids::Id,
+    checkpoint: ids::Id,
+    block: Block,
+}
+
+impl VerifiedState {
+    /// Returns the Id of the chain the blocks were verified on.
+    #[must_use]
+    pub fn chain_id(&self) -> ids::Id {
+        self.chain_id
+    }
+
+    /// Returns the Id of the block the chain was verified back to.
+    #[must_use]
+    pub fn checkpoint(&self) -> ids::Id {
+        self.checkpoint
+    }
+
+    /// Returns the last verified block.
+    #[must_use]
+    pub fn block(&self) -> &Block {
+        &self.block
+    }
+
+    /// Returns the state of the last verified block.
+    #[must_use]
+    pub fn block_state(&self) -> &BlockState {
+        self.block.block_state()
+    }
+
+    /// Returns the state as a participant sees it, to prove transactions against.
+    #[must_use]
+    pub fn protocol_state(&self) -> ProtocolState {
+        self.block_state().protocol_state(&self.chain_id)
+    }
+}
+
+/// Verifies `blocks`, oldest first, on the chain `chain_id`: the first block must
+/// be `checkpoint` and its state must match its Merkle roots, and every other
+/// block must be valid on top of the one before.
+/// # Errors
+/// Fails if `blocks` is empty or if any of the checks fails.
+pub fn verify_blocks(
+    chain_id: &ids::Id,
+    checkpoint: &ids::Id,
+    blocks: &[Block],
+) -> io::Result<VerifiedState> {
+    let (Some(first), Some(last)) = (blocks.first(), blocks.last()) else {
+        return Err(Error::new(ErrorKind::InvalidInput, "no blocks to verify"));
+    };
+    if first.id() != *checkpoint {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("block {} is not the checkpoint {checkpoint}", first.id()),
+        ));
+    }
+    first.block_state().verify_roots()?;
+
+    for pair in blocks.windows(2) {
+        pair[1].verify_against_parent(&pair[0], chain_id)?;
+    }
+
+    last.block_state().verify_roots()?;
+    Ok(VerifiedState {
+        chain_id: *chain_id,
+        checkpoint: *checkpoint,
+        block: last.clone(),
+    })
+}
+
+impl ZkretClient {
+    /// Fetches the last accepted block and the blocks before it back to
+    /// `checkpoint`, and verifies them with [`verify_blocks`] on the chain `chain_id`.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error, if the
+    /// checkpoint isn't an ancestor of the last accepted block, or if the blocks
+    /// don't verify.
+    pub async fn verified_state(
+        &self,
+        chain_id: &ids::Id,
+        checkpoint: &ids::Id,
+    ) -> io::Result<VerifiedState> {
+        let blocks = self.blocks_back_to(Some(checkpoint)).await?;
+        verify_blocks(chain_id, checkpoint, &blocks)
+    }
+
+    /// Like [`Self::verified_state`], with the genesis block the node serves as
+    /// the checkpoint. The node picks that block, so this is only for pinning a
+    /// checkpoint on first use, and [`VerifiedState::checkpoint`] is to be shown
+    /// to the user to compare with the one the organiser published.
+    /// # Errors
+    /// Errors like [`Self::verified_state`], or if the chain doesn't start with a
+    /// genesis block.
+    pub async fn genesis_state(&self, chain_id: &ids::Id) -> io::Result<VerifiedState> {
+        let blocks = self.blocks_back_to(None).await?;
+        let Some(genesis) = blocks.first() else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "the node served no blocks",
+            ));
+        };
+        genesis.verify_genesis()?;
+        verify_blocks(chain_id, &genesis.id(), &blocks)
+    }
+
+    /// Fetches every accepted block, from the genesis block to the last
+    /// accepted block. Only their Ids are checked, the blocks are not verified.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error, or
+    /// if a block served doesn't match its Id.
+    pub async fn accepted_blocks(&self) -> io::Result<Vec<Block>> {
+        self.blocks_back_to(None).await
+    }
+
+    /// Fetches the last accepted block and the blocks before it back to
+    /// `checkpoint`, or to the genesis block if there is none, oldest first.
+    async fn blocks_back_to(&self, checkpoint: Option<&ids::Id>) -> io::Result<Vec<Block>> {
+        let mut blocks = Vec::new();
+
+        let mut id = self.last_accepted().await?;
+        loop {
+            let block = self.get_block(&id).await?;
+            // the Id is derived from the bytes that were served
+            if block.id() != id {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("the node served block {} for {id}", block.id()),
+                ));
+            }
+
+            let done = match checkpoint {
+                Some(checkpoint) if id == *checkpoint => true,
+                Some(checkpoint) if block.height() == 0 => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "checkpoint {checkpoint} is not an ancestor of the last accepted block"
+                        ),
+                    ));
+                }
+                Some(_) => false,
+                None => block.height() == 0,
+            };
+
+            id = block.parent_id();
+            blocks.push(block);
+            if done {
+                break;
+            }
+        }
+
+        blocks.reverse();
+        Ok(blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        block::transaction::SBytes64,
+        testing::{self, child_block, child_block_with_state, genesis_block},
+    };
+
+    #[test]
+    fn blocks_are_verified_back_to_the_checkpoint() {
+        let chain_id = ids::Id::from_slice(&[7u8; 32]);
+        let genesis = genesis_block(b"test");
+        let entered = child_block(
+            &genesis,
+            testing::enter_tx(&testing::participant(1), &chain_id),
+        );
+
+        let checkpoint = genesis.id();
+        let state =
+            verify_blocks(&chain_id, &checkpoint, &[genesis.clone(), entered.clone()]).unwrap();
+        assert_eq!(state.block().id(), entered.id());
+        assert_eq!(state.checkpoint(), checkpoint);
+        assert_eq!(state.protocol_state().merkle_leaves.len(), 1);
+
+        // the checkpoint is trusted as it is
+        let later = entered.id();
+        assert!(verify_blocks(&chain_id, &later, std::slice::from_ref(&entered)).is_ok());
+        assert!(verify_blocks(&chain_id, &later, std::slice::from_ref(&genesis)).is_err());
+        assert!(verify_blocks(&chain_id, &checkpoint, std::slice::from_ref(&entered)).is_err());
+
+        // a chain the node made up from a genesis block of its own
+        let made_up = genesis_block(b"made up");
+        let made_up_entered = child_block(
+            &made_up,
+            testing::enter_tx(&testing::participant(2), &chain_id),
+        );
+        assert!(made_up.verify_genesis().is_ok());
+        assert!(verify_blocks(&chain_id, &checkpoint, &[made_up, made_up_entered]).is_err());
+
+        // the ENTER is bound to another chain
+        let other_chain_id = ids::Id::from_slice(&[8u8; 32]);
+        assert!(verify_blocks(&other_chain_id, &checkpoint, &[genesis.clone(), entered]).is_err());
+    }
+
+    #[test]
+    fn states_that_dont_follow_from_the_blocks_are_refused() {
+        let chain_id = ids::Id::from_slice(&[7u8; 32]);
+        let genesis = genesis_block(b"test");
+        let tx = testing::enter_tx(&testing::participant(1), &chain_id);
+
+        // a leaf the node added without an ENTER, to learn who proves against it
+        let mut block_state = genesis.block_state().clone();
+        tx.update_state(&mut block_state);
+        block_state
+            .merkle_leaves
+            .push(SBytes64::from_bytes(&[3u8; 64]));
+        let forged = child_block_with_state(&genesis, tx, block_state);
+        assert!(verify_blocks(&chain_id, &genesis.id(), &[genesis, forged.clone()]).is_err());
+        let err = verify_blocks(&chain_id, &forged.id(), &[forged]).unwrap_err();
+        assert_eq!(err.to_string(), "Merkle leaves don't match the Merkle root");
+    }
+}
@@ -1,162 +1,522 @@
-#![allow(clippy::missing_panics_doc)]
+//! Typed JSON-RPC client for the chain-specific handlers of zkretvm.
 
 use std::{
-    collections::HashMap,
+    fmt,
     io::{self, Error, ErrorKind},
+    mem,
+    time::Duration,
 };
 
-use avalanche_types::{ids, jsonrpc};
-use serde::{Deserialize, Serialize};
-
-use crate::block::transaction::Transaction;
-
-/// Represents the RPC response for API `ping`.
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct PingResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<crate::api::PingResponse>,
+use avalanche_types::ids;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use tokio::{sync::mpsc, time::sleep};
+
+use crate::{
+    api::{
+        chain_handlers::{
+            GetBlockResponse, GetChainIdResponse, GetRevealForResponse, InvitesResponse,
+            IsNullifierSpentResponse, LastAcceptedResponse, MerkleLeavesResponse,
+            MerklePathResponse, PageArgs, ProposeBlockResponse, PubKeysResponse,
+        },
+        PingResponse,
+    },
+    block::{
+        transaction::{BlockState, SBytes64, Transaction},
+        Block,
+    },
+};
 
-    /// Returns non-empty if any.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<APIError>,
+pub mod light;
+
+/// Number of times fetching all the pages of a list starts over because a
+/// block was accepted in between, before it fails.
+pub const MAX_PAGING_RESTARTS: usize = 8;
+
+/// Connection settings for [`ZkretClient`](ZkretClient).
+#[derive(Debug, Clone)]
+pub struct ClientOptions {
+    /// Per-request timeout.
+    pub timeout: Duration,
+    /// Number of times a read-only request that failed in transport is retried.
+    /// Transactions are submitted once, since resending one that reached the node
+    /// would have it rejected as a duplicate.
+    pub retries: u32,
+    /// Delay before the first retry, growing linearly with each attempt.
+    pub retry_backoff: Duration,
 }
 
-/// Ping the VM.
-/// # Errors
-/// Errors on an http failure or a failed deserialization.
-pub async fn ping(http_rpc: &str, url_path: &str) -> io::Result<PingResponse> {
-    log::info!("ping {http_rpc} with {url_path}");
-
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("zkretvm.ping");
-
-    let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, url_path, &d).await?;
-
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed ping '{e}'")))
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(15),
+            retries: 3,
+            retry_backoff: Duration::from_millis(500),
+        }
+    }
 }
 
-/// Represents the RPC response for API `last_accepted`.
+/// Represents the JSON-RPC response envelope of every API.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct LastAcceptedResponse {
+pub struct Response<T> {
     pub jsonrpc: String,
     pub id: u32,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<crate::api::chain_handlers::LastAcceptedResponse>,
+    pub result: Option<T>,
 
     /// Returns non-empty if any.
+    /// e.g., "error":{"code":-32603,"message":"data 1048586-byte exceeds the limit 1048576-byte"}
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<APIError>,
 }
 
-/// Requests for the last accepted block Id.
-/// # Errors
-/// Errors on failed (de)serialization or an http failure.
-pub async fn last_accepted(http_rpc: &str, url_path: &str) -> io::Result<LastAcceptedResponse> {
-    log::info!("last_accepted {http_rpc} with {url_path}");
-
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("zkretvm.lastAccepted");
-
-    let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, url_path, &d).await?;
-
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed last_accepted '{e}'")))
+impl<T> Response<T> {
+    /// Returns the result, or the API error mapped to an [`io::Error`](std::io::Error).
+    /// # Errors
+    /// Fails if the response carries an error or no result.
+    pub fn into_result(self) -> io::Result<T> {
+        match (self.result, self.error) {
+            (_, Some(e)) => Err(e.into()),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(Error::new(
+                ErrorKind::InvalidData,
+                "response has neither a result nor an error",
+            )),
+        }
+    }
 }
 
-/// Represents the RPC response for API `get_block`.
+/// Represents the error (if any) for APIs.
 #[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct GetBlockResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<crate::api::chain_handlers::GetBlockResponse>,
-
-    /// Returns non-empty if any.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<APIError>,
+pub struct APIError {
+    pub code: i32,
+    pub message: String,
 }
 
-/// Fetches the block for the corresponding block Id (if any).
-/// # Errors
-/// Errors on failed (de)serialization or an http failure.
-pub async fn get_block(
-    http_rpc: &str,
-    url_path: &str,
-    id: &ids::Id,
-) -> io::Result<GetBlockResponse> {
-    log::info!("get_block {http_rpc} with {url_path}");
-
-    let mut data = jsonrpc::RequestWithParamsHashMapArray::default();
-    data.method = String::from("zkretvm.getBlock");
-
-    let mut m = HashMap::new();
-    m.insert("id".to_string(), id.to_string());
-
-    let params = vec![m];
-    data.params = Some(params);
-
-    let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, url_path, &d).await?;
-
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_block '{e}'")))
+impl fmt::Display for APIError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API error {}: {}", self.code, self.message)
+    }
 }
 
-/// Represents the RPC response for API `propose_block`.
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct ProposeBlockResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<crate::api::chain_handlers::ProposeBlockResponse>,
+impl From<APIError> for Error {
+    fn from(e: APIError) -> Self {
+        // ref. https://www.jsonrpc.org/specification#error_object
+        let kind = match e.code {
+            -32700 | -32602 => ErrorKind::InvalidInput,
+            -32601 => ErrorKind::Unsupported,
+            _ => ErrorKind::Other,
+        };
+        Error::new(kind, e.to_string())
+    }
+}
 
-    /// Returns non-empty if any.
-    /// e.g., "error":{"code":-32603,"message":"data 1048586-byte exceeds the limit 1048576-byte"}
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<APIError>,
+/// Client for the zkretvm RPCs served at `[HOST]/ext/bc/[CHAIN ID]/rpc`.
+#[derive(Debug, Clone)]
+pub struct ZkretClient {
+    url: String,
+    http: reqwest::Client,
+    opts: ClientOptions,
 }
 
-/// Proposes arbitrary data.
-/// # Errors
-/// Errors on failed (de)serialization or an http failure.
-pub async fn propose_block(
-    http_rpc: &str,
-    url_path: &str,
-    tx: Transaction,
-) -> io::Result<ProposeBlockResponse> {
-    log::info!("propose_block {http_rpc} with {url_path}");
-
-    let mut data = jsonrpc::RequestWithParamsHashMapArray::default();
-    data.method = String::from("zkretvm.proposeBlock");
-
-    let mut m = HashMap::new();
-    m.insert(
-        "transaction".to_string(),
-        serde_json::to_string(&tx).unwrap(),
-    );
-
-    let params = vec![m];
-    data.params = Some(params);
-
-    let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, url_path, &d).await?;
-
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed propose_block '{e}'")))
+impl ZkretClient {
+    /// Creates a client for the chain `chain_id` served by the node at `http_rpc`
+    /// (e.g., `https://node.example.com:9650`).
+    /// # Errors
+    /// Fails if the underlying HTTP client can't be built.
+    pub fn new(http_rpc: &str, chain_id: &str, opts: ClientOptions) -> io::Result<Self> {
+        let url = format!("{}/ext/bc/{chain_id}/rpc", http_rpc.trim_end_matches('/'));
+        Self::with_url(&url, opts)
+    }
+
+    /// Creates a client for the RPC handler served at `url`.
+    /// # Errors
+    /// Fails if the underlying HTTP client can't be built.
+    pub fn with_url(url: &str, opts: ClientOptions) -> io::Result<Self> {
+        let http = reqwest::Client::builder()
+            .user_agent(env!("CARGO_PKG_NAME"))
+            .timeout(opts.timeout)
+            .build()
+            .map_err(|e| {
+                Error::new(ErrorKind::Other, format!("failed to build http client {e}"))
+            })?;
+
+        Ok(Self {
+            url: url.to_string(),
+            http,
+            opts,
+        })
+    }
+
+    /// Returns the URL of the RPC handler.
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Pings the VM.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn ping(&self) -> io::Result<PingResponse> {
+        self.call("ping", json!([])).await
+    }
+
+    /// Requests for the Id of the chain, which ENTER proofs must be bound to.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn chain_id(&self) -> io::Result<ids::Id> {
+        let resp: GetChainIdResponse = self.call("getChainId", json!([])).await?;
+        Ok(resp.chain_id)
+    }
+
+    /// Requests for the last accepted block Id.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn last_accepted(&self) -> io::Result<ids::Id> {
+        let resp: LastAcceptedResponse = self.call("lastAccepted", json!([])).await?;
+        Ok(resp.id)
+    }
+
+    /// Fetches the block for the corresponding block Id.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn get_block(&self, id: &ids::Id) -> io::Result<Block> {
+        let resp: GetBlockResponse = self
+            .call("getBlock", json!([{ "id": id.to_string() }]))
+            .await?;
+
+        // re-encode so that the block Id is derived from its bytes
+        Block::from_slice(resp.block.to_vec()?)
+    }
+
+    /// Fetches the last accepted block.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn last_accepted_block(&self) -> io::Result<Block> {
+        let id = self.last_accepted().await?;
+        self.get_block(&id).await
+    }
+
+    /// Fetches the protocol state as of the last accepted block.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn block_state(&self) -> io::Result<BlockState> {
+        let block = self.last_accepted_block().await?;
+        Ok(block.block_state().clone())
+    }
+
+    /// Fetches a page of the unclaimed public keys.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn get_unclaimed_pub_keys(&self, page: &PageArgs) -> io::Result<PubKeysResponse> {
+        self.call("getUnclaimedPubKeys", json!([page])).await
+    }
+
+    /// Fetches every unclaimed public key, paging through a single accepted block.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error, or
+    /// if blocks kept being accepted while paging.
+    pub async fn unclaimed_pub_keys(&self) -> io::Result<Vec<SBytes64>> {
+        let (pub_keys, _) = self
+            .paginate("getUnclaimedPubKeys", |resp: &mut PubKeysResponse| {
+                (
+                    resp.block_id,
+                    resp.next_offset,
+                    mem::take(&mut resp.pub_keys),
+                )
+            })
+            .await?;
+        Ok(pub_keys)
+    }
+
+    /// Fetches a page of the Merkle leaves.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn get_merkle_leaves(&self, page: &PageArgs) -> io::Result<MerkleLeavesResponse> {
+        self.call("getMerkleLeaves", json!([page])).await
+    }
+
+    /// Fetches every Merkle leaf and the Merkle root, paging through a single
+    /// accepted block.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error, or
+    /// if blocks kept being accepted while paging.
+    pub async fn merkle_leaves(&self) -> io::Result<(SBytes64, Vec<SBytes64>)> {
+        let (leaves, last) = self
+            .paginate("getMerkleLeaves", |resp: &mut MerkleLeavesResponse| {
+                (resp.block_id, resp.next_offset, mem::take(&mut resp.leaves))
+            })
+            .await?;
+        Ok((last.merkle_root, leaves))
+    }
+
+    /// Fetches the Merkle path of an entered public key.
+    /// Note that this reveals the key to the node.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error,
+    /// e.g., if the key has not entered.
+    pub async fn get_merkle_path(&self, pub_key: &SBytes64) -> io::Result<MerklePathResponse> {
+        self.call("getMerklePath", json!([{ "pub_key": pub_key }]))
+            .await
+    }
+
+    /// Fetches a page of the invite commitments.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn get_invites(&self, page: &PageArgs) -> io::Result<InvitesResponse> {
+        self.call("getInvites", json!([page])).await
+    }
+
+    /// Fetches every invite commitment and the allowlist Merkle root, paging through
+    /// a single accepted block. The root is none if anyone can ENTER.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error, or
+    /// if blocks kept being accepted while paging.
+    pub async fn invites(&self) -> io::Result<(Option<SBytes64>, Vec<SBytes64>)> {
+        let (invites, last) = self
+            .paginate("getInvites", |resp: &mut InvitesResponse| {
+                (
+                    resp.block_id,
+                    resp.next_offset,
+                    mem::take(&mut resp.invites),
+                )
+            })
+            .await?;
+        Ok((last.invite_root, invites))
+    }
+
+    /// Checks whether an invite nullifier has been used by an ENTER transaction.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn is_invite_spent(&self, invite_nullifier: &SBytes64) -> io::Result<bool> {
+        let resp: IsNullifierSpentResponse = self
+            .call("isInviteSpent", json!([{ "nullifier": invite_nullifier }]))
+            .await?;
+        Ok(resp.spent)
+    }
+
+    /// Checks whether a nullifier has been used by a CHOICE transaction.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn is_nullifier_spent(&self, nullifier: &SBytes64) -> io::Result<bool> {
+        let resp: IsNullifierSpentResponse = self
+            .call("isNullifierSpent", json!([{ "nullifier": nullifier }]))
+            .await?;
+        Ok(resp.spent)
+    }
+
+    /// Fetches the ciphertext revealed by a public key, if any.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error.
+    pub async fn get_reveal_for(&self, pub_key: &SBytes64) -> io::Result<Option<Vec<u8>>> {
+        let resp: GetRevealForResponse = self
+            .call("getRevealFor", json!([{ "pub_key": pub_key }]))
+            .await?;
+        Ok(resp.ct)
+    }
+
+    /// Submits a transaction to the mempool of the node.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error,
+    /// e.g., if the transaction exceeds the proposal size limit.
+    /// The request isn't retried, see [`ClientOptions::retries`].
+    pub async fn propose_block(&self, tx: &Transaction) -> io::Result<ProposeBlockResponse> {
+        self.call_with_retries("proposeBlock", json!([{ "transaction": tx }]), 0)
+            .await
+    }
+
+    /// Polls the node every `poll_interval` and sends each newly accepted block,
+    /// oldest first, on the returned channel. Polling stops once the receiver is
+    /// dropped or after the first error, which is sent on the channel.
+    #[must_use]
+    pub fn subscribe_accepted_blocks(
+        &self,
+        poll_interval: Duration,
+    ) -> mpsc::Receiver<io::Result<Block>> {
+        let (tx, rx) = mpsc::channel(16);
+        let client = self.clone();
+
+        tokio::spawn(async move {
+            let mut last = match client.last_accepted().await {
+                Ok(id) => id,
+                Err(e) => {
+                    let _ = tx.send(Err(e)).await;
+                    return;
+                }
+            };
+
+            loop {
+                sleep(poll_interval).await;
+
+                let new_blocks = match client.blocks_since(&last).await {
+                    Ok(blocks) => blocks,
+                    Err(e) => {
+                        let _ = tx.send(Err(e)).await;
+                        return;
+                    }
+                };
+
+                for block in new_blocks {
+                    last = block.id();
+                    if tx.send(Ok(block)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        rx
+    }
+
+    /// Returns the blocks accepted after `since`, oldest first.
+    async fn blocks_since(&self, since: &ids::Id) -> io::Result<Vec<Block>> {
+        let mut blocks = Vec::new();
+
+        let mut id = self.last_accepted().await?;
+        while id != *since {
+            let block = self.get_block(&id).await?;
+            if block.height() == 0 {
+                break;
+            }
+            id = block.parent_id();
+            blocks.push(block);
+        }
+
+        blocks.reverse();
+        Ok(blocks)
+    }
+
+    /// Fetches every page of the paged `method`, restarting from the first page
+    /// when a block is accepted in between so that all of them are read from a
+    /// single accepted block. `extract` takes the Id of that block, the offset
+    /// of the next page and the items out of a page. Returns the items with the
+    /// last page.
+    /// # Errors
+    /// Errors on an http failure, a failed deserialization or an API error, or
+    /// if blocks kept being accepted for more than [`MAX_PAGING_RESTARTS`]
+    /// restarts.
+    async fn paginate<R: DeserializeOwned, T>(
+        &self,
+        method: &str,
+        extract: impl Fn(&mut R) -> (ids::Id, Option<usize>, Vec<T>),
+    ) -> io::Result<(Vec<T>, R)> {
+        let mut page = PageArgs::default();
+        let mut items = Vec::new();
+        let mut block_id = None;
+        let mut restarts = 0;
+
+        loop {
+            let mut resp = self.call(method, json!([page])).await?;
+            let (resp_block_id, next_offset, page_items) = extract(&mut resp);
+            if *block_id.get_or_insert(resp_block_id) != resp_block_id {
+                // a block was accepted in between, so start over
+                if restarts == MAX_PAGING_RESTARTS {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        format!("gave up paging {method} after {restarts} restarts"),
+                    ));
+                }
+                restarts += 1;
+                block_id = Some(resp_block_id);
+                page.offset = 0;
+                items.clear();
+                continue;
+            }
+
+            items.extend(page_items);
+            match next_offset {
+                Some(offset) => page.offset = offset,
+                None => return Ok((items, resp)),
+            }
+        }
+    }
+
+    /// Calls the read-only `zkretvm.[method]` and decodes its result, retrying on
+    /// transport failures.
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> io::Result<T> {
+        self.call_with_retries(method, params, self.opts.retries)
+            .await
+    }
+
+    /// Calls `zkretvm.[method]` and decodes its result, retrying up to `retries`
+    /// times on transport failures.
+    async fn call_with_retries<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        retries: u32,
+    ) -> io::Result<T> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": format!("zkretvm.{method}"),
+            "params": params,
+        })
+        .to_string();
+
+        let mut attempt = 0;
+        let rb = loop {
+            match self.post(&body).await {
+                Ok(rb) => break rb,
+                Err(e) if attempt < retries => {
+                    attempt += 1;
+                    log::warn!(
+                        "{method} to {} failed ({e}), retry {attempt}/{retries}",
+                        self.url
+                    );
+                    sleep(self.opts.retry_backoff * attempt).await;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        let resp: Response<T> = serde_json::from_slice(&rb)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed {method} '{e}'")))?;
+        resp.into_result()
+    }
+
+    async fn post(&self, body: &str) -> io::Result<Vec<u8>> {
+        log::debug!("posting {}-byte request to {}", body.len(), self.url);
+
+        let resp = self
+            .http
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to send request {e}")))?;
+
+        let rb = resp
+            .bytes()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to read response {e}")))?;
+        Ok(rb.to_vec())
+    }
 }
 
-/// Represents the error (if any) for APIs.
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct APIError {
-    pub code: i32,
-    pub message: String,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn api_error_is_mapped() {
+        let resp: Response<ProposeBlockResponse> = serde_json::from_str(
+            r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32603,"message":"block is invalid"}}"#,
+        )
+        .unwrap();
+        let err = resp.into_result().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert!(err.to_string().contains("block is invalid"));
+
+        let resp: Response<ProposeBlockResponse> =
+            serde_json::from_str(r#"{"jsonrpc":"2.0","id":1,"result":{"success":true}}"#).unwrap();
+        assert!(resp.into_result().unwrap().success);
+    }
 }
@@ -0,0 +1,309 @@
+//! In-process development network that drives a single [`Vm`](crate::vm::Vm)
+//! without avalanchego.
+//!
+//! The Vm runs on top of an in-memory database and a mock consensus engine that
+//! builds, verifies and accepts a block as soon as the Vm reports pending
+//! transactions. Its chain handlers are served at the avalanchego RPC path, so
+//! `zkretctl` and [`ZkretClient`](crate::client::ZkretClient) can be pointed at it.
+
+use avalanche_types::subnet::rpc::{database::memdb, snow::engine::common::message::Message};
+use std::{
+    io::{self, Error, ErrorKind},
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
+use tokio::{
+    sync::{mpsc, oneshot, Mutex},
+    task::JoinHandle,
+    time::{sleep, Instant},
+};
+
+use crate::{
+    client::{ClientOptions, ZkretClient},
+    genesis::Genesis,
+    node::{build_and_accept, init_vm, serve_vm, NoopAppSender},
+    state::storage::RpcStorage,
+    vm::Vm,
+};
+
+/// Chain Id the devnet serves its handlers under, unless told otherwise.
+pub const DEFAULT_CHAIN_ID: &str = "devnet";
+
+/// A running single-node network.
+pub struct Devnet {
+    vm: Vm<NoopAppSender>,
+    chain_id: String,
+    addr: SocketAddr,
+
+    /// Held by the consensus loop while it builds and accepts blocks.
+    building: Arc<Mutex<()>>,
+
+    stop: Option<oneshot::Sender<()>>,
+    consensus: JoinHandle<()>,
+    server: JoinHandle<io::Result<()>>,
+}
+
+impl Devnet {
+    /// Initializes a Vm from `genesis` and serves its chain handlers at `addr`
+    /// (port 0 picks a free port).
+    /// # Errors
+    /// Fails if the Vm can't be initialized or if `addr` can't be bound.
+    pub async fn start(genesis: &Genesis, chain_id: &str, addr: SocketAddr) -> io::Result<Self> {
+        let storage = RpcStorage::new(memdb::Database::new()).boxed();
+        let (mut vm, from_vm) = init_vm(chain_id, storage, genesis).await?;
+
+        let building = Arc::new(Mutex::new(()));
+        let consensus = tokio::spawn(run_consensus(vm.clone(), from_vm, Arc::clone(&building)));
+
+        let (stop, stopped) = oneshot::channel();
+        let (addr, server) = serve_vm(&mut vm, chain_id, addr, stopped).await?;
+
+        log::info!("devnet started at http://{addr} with chain Id '{chain_id}'");
+        Ok(Self {
+            vm,
+            chain_id: chain_id.to_string(),
+            addr,
+            building,
+            stop: Some(stop),
+            consensus,
+            server,
+        })
+    }
+
+    #[must_use]
+    pub fn vm(&self) -> &Vm<NoopAppSender> {
+        &self.vm
+    }
+
+    #[must_use]
+    pub fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+
+    /// Returns the base URL to pass as `--rpc-url` to `zkretctl`.
+    #[must_use]
+    pub fn http_rpc(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Returns a client for the devnet chain.
+    /// # Errors
+    /// Fails if the underlying HTTP client can't be built.
+    pub fn client(&self) -> io::Result<ZkretClient> {
+        ZkretClient::new(&self.http_rpc(), &self.chain_id, ClientOptions::default())
+    }
+
+    /// Waits until every proposed transaction has been built into an accepted
+    /// block or dropped as invalid.
+    /// # Errors
+    /// Fails if the mempool is not drained within `timeout`.
+    pub async fn wait_idle(&self, timeout: Duration) -> io::Result<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            {
+                let _building = self.building.lock().await;
+                if self.vm.mempool.read().await.is_empty() {
+                    return Ok(());
+                }
+            }
+
+            if Instant::now() >= deadline {
+                return Err(Error::new(
+                    ErrorKind::TimedOut,
+                    "timed out waiting for the mempool to drain",
+                ));
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    }
+
+    /// Stops serving and tears the devnet down.
+    /// # Errors
+    /// Fails if the HTTP server failed.
+    pub async fn shutdown(mut self) -> io::Result<()> {
+        if let Some(stop) = self.stop.take() {
+            let _ = stop.send(());
+        }
+        self.consensus.abort();
+
+        self.server
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("http server panicked {e}")))?
+    }
+}
+
+/// Mock consensus engine: on every `PendingTxs`, builds, prefers and accepts
+/// blocks until the mempool is empty.
+async fn run_consensus(
+    vm: Vm<NoopAppSender>,
+    mut from_vm: mpsc::Receiver<Message>,
+    building: Arc<Mutex<()>>,
+) {
+    while let Some(msg) = from_vm.recv().await {
+        if !matches!(msg, Message::PendingTxs) {
+            continue;
+        }
+
+        let _building = building.lock().await;
+        loop {
+            // "build_block" notifies again for each block, so keep the channel drained
+            while from_vm.try_recv().is_ok() {}
+
+            if vm.mempool.read().await.is_empty() {
+                break;
+            }
+            match build_and_accept(&vm).await {
+                Ok(blk_id) => log::info!("devnet accepted block {blk_id}"),
+                Err(e) => log::warn!("devnet failed to build block: {e}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use avalanche_types::ids;
+    use santazk::merkle::MerkleTree;
+
+    use super::*;
+    use crate::{
+        audit::{audit, ChainExport},
+        block::transaction::SBytes64,
+        node::chain_id_for_alias,
+        testing::{choice_tx, enter_tx, participant, reveal_tx},
+    };
+
+    const N: usize = 3;
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+    async fn start_devnet() -> Devnet {
+        Devnet::start(
+            &Genesis::default(),
+            DEFAULT_CHAIN_ID,
+            "127.0.0.1:0".parse().unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn participants_enter_over_rpc() {
+        let devnet = start_devnet().await;
+        let client = devnet.client().unwrap();
+        assert_eq!(
+            client.chain_id().await.unwrap(),
+            chain_id_for_alias(DEFAULT_CHAIN_ID)
+        );
+
+        let chain_id = chain_id_for_alias(DEFAULT_CHAIN_ID);
+        for i in 0..N {
+            let p = participant(u8::try_from(i).unwrap());
+            client
+                .propose_block(&enter_tx(&p, &chain_id))
+                .await
+                .unwrap();
+        }
+        devnet.wait_idle(IDLE_TIMEOUT).await.unwrap();
+
+        assert_eq!(client.unclaimed_pub_keys().await.unwrap().len(), N);
+        assert_eq!(
+            client.last_accepted_block().await.unwrap().height(),
+            N as u64
+        );
+
+        // the blocks served verify back to the genesis block, or to a later checkpoint
+        let state = client.genesis_state(&chain_id).await.unwrap();
+        assert_eq!(state.block_state().merkle_leaves.len(), N);
+        assert_eq!(
+            state.checkpoint(),
+            devnet.vm().genesis_block_id().await.unwrap()
+        );
+        assert!(client
+            .verified_state(&chain_id, &state.checkpoint())
+            .await
+            .is_ok());
+        let checkpoint = state.block().parent_id();
+        assert!(client.verified_state(&chain_id, &checkpoint).await.is_ok());
+        let unknown = ids::Id::from_slice(&[9u8; 32]);
+        assert!(client.verified_state(&chain_id, &unknown).await.is_err());
+
+        // the chain exported over RPC audits cleanly
+        let export = ChainExport::from_client(&client).await.unwrap();
+        assert_eq!(export.chain_id, chain_id);
+        assert_eq!(export.blocks.len(), N + 1);
+        let report = audit(&export).unwrap();
+        assert_eq!(report.participants, N);
+        assert!(report.anomalies.is_empty());
+
+        // entering twice is rejected before it reaches the mempool
+        assert!(client
+            .propose_block(&enter_tx(&participant(0), &chain_id))
+            .await
+            .is_err());
+
+        devnet.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore = "proves 3N Groth16 statements, run with `cargo test --release -- --ignored`"]
+    async fn protocol_round_over_rpc() {
+        let devnet = start_devnet().await;
+        let client = devnet.client().unwrap();
+        client.ping().await.unwrap();
+
+        let chain_id = chain_id_for_alias(DEFAULT_CHAIN_ID);
+        let participants: Vec<_> = (0..N)
+            .map(|i| participant(u8::try_from(i).unwrap()))
+            .collect();
+
+        // ENTER
+        for p in &participants {
+            client.propose_block(&enter_tx(p, &chain_id)).await.unwrap();
+        }
+        devnet.wait_idle(IDLE_TIMEOUT).await.unwrap();
+        assert_eq!(client.unclaimed_pub_keys().await.unwrap().len(), N);
+
+        // CHOICE: everyone chooses the next participant
+        let (root, leaves) = client.merkle_leaves().await.unwrap();
+        let leaves: Vec<_> = leaves.iter().map(SBytes64::to_vec).collect();
+        let mt = MerkleTree::new(7, &leaves);
+        assert_eq!(mt.root(), root.to_vec());
+
+        for (i, p) in participants.iter().enumerate() {
+            let choice = &participants[(i + 1) % N].pub_key;
+            client
+                .propose_block(&choice_tx(p, &leaves, choice, &chain_id))
+                .await
+                .unwrap();
+        }
+        devnet.wait_idle(IDLE_TIMEOUT).await.unwrap();
+        assert!(client.unclaimed_pub_keys().await.unwrap().is_empty());
+        for p in &participants {
+            let nullifier = SBytes64::from_bytes(&p.nullifier);
+            assert!(client.is_nullifier_spent(&nullifier).await.unwrap());
+        }
+
+        // REVEAL
+        for (i, p) in participants.iter().enumerate() {
+            let ct = format!("participant {i}").into_bytes();
+            client
+                .propose_block(&reveal_tx(p, &ct, &chain_id))
+                .await
+                .unwrap();
+        }
+        devnet.wait_idle(IDLE_TIMEOUT).await.unwrap();
+        for (i, p) in participants.iter().enumerate() {
+            let ct = client
+                .get_reveal_for(&SBytes64::from_bytes(&p.pub_key))
+                .await
+                .unwrap();
+            assert_eq!(ct, Some(format!("participant {i}").into_bytes()));
+        }
+
+        let last = client.last_accepted_block().await.unwrap();
+        assert_eq!(last.height(), 3 * N as u64);
+
+        devnet.shutdown().await.unwrap();
+    }
+}
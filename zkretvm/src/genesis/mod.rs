@@ -8,22 +8,37 @@ use std::{
 };
 
 use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
 
 /// Represents the genesis data specific to the VM.
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Genesis {
     pub data: String,
+
+    /// Commitments to the invite tokens made by the invitees, hex-encoded.
+    /// If there are any, only holders of an unused invite can ENTER.
+    #[serde_as(as = "Vec<Hex>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invites: Vec<[u8; 64]>,
 }
 
 impl Default for Genesis {
     fn default() -> Self {
-        Self {
-            data: String::from("Hello from Rust VM!"),
-        }
+        Self::new("Hello from Rust VM!")
     }
 }
 
 impl Genesis {
+    /// Creates the genesis of an open group, which anyone can ENTER.
+    #[must_use]
+    pub fn new(data: &str) -> Self {
+        Self {
+            data: data.to_string(),
+            invites: Vec::new(),
+        }
+    }
+
     /// Encodes the genesis to JSON bytes.
     /// # Errors
     /// Fails if `Self` can't be serialized
@@ -1,8 +1,15 @@
 #![deny(clippy::pedantic)]
 
 pub mod api;
+pub mod audit;
 pub mod block;
 pub mod client;
+pub mod devnet;
 pub mod genesis;
+pub mod node;
+pub mod standalone;
 pub mod state;
 pub mod vm;
+
+#[cfg(test)]
+mod testing;
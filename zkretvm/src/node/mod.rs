@@ -0,0 +1,154 @@
+//! Bootstrap shared by the nodes that run a [`Vm`](crate::vm::Vm) without
+//! avalanchego: the [devnet](crate::devnet) and the
+//! [standalone](crate::standalone) node.
+//!
+//! Both initialize a single-node Vm, serve its chain handlers at the
+//! avalanchego RPC path and build blocks themselves, each with their own
+//! storage and block building schedule.
+
+use std::{
+    io::{self, Error, ErrorKind},
+    net::SocketAddr,
+};
+
+use avalanche_types::{
+    ids,
+    subnet::rpc::{
+        database::manager::DatabaseManager,
+        snow::{
+            self,
+            engine::common::{appsender::AppSender, message::Message, vm::CommonVm},
+        },
+        snowman::block::ChainVm,
+    },
+};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+
+use crate::{
+    api::{chain_handlers::ChainHandler, server},
+    genesis::Genesis,
+    state::storage::BoxedStorage,
+    vm::Vm,
+};
+
+/// [`AppSender`](AppSender) of a network with a single node, where messages go nowhere.
+#[derive(Clone, Default)]
+pub struct NoopAppSender;
+
+#[tonic::async_trait]
+impl AppSender for NoopAppSender {
+    async fn send_app_request(
+        &self,
+        _node_ids: ids::node::Set,
+        _request_id: u32,
+        _request: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn send_app_response(
+        &self,
+        _node_id: ids::node::Id,
+        _request_id: u32,
+        _response: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn send_app_gossip(&self, _msg: Vec<u8>) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn send_app_gossip_specific(
+        &self,
+        _node_ids: ids::node::Set,
+        _msg: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn send_cross_chain_app_request(
+        &self,
+        _chain_id: ids::Id,
+        _request_id: u32,
+        _app_request_bytes: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn send_cross_chain_app_response(
+        &self,
+        _chain_id: ids::Id,
+        _request_id: u32,
+        _app_response_bytes: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the Id of the chain served under `alias`: the alias itself if it
+/// is an Id, or else its SHA-256 digest.
+#[must_use]
+pub fn chain_id_for_alias(alias: &str) -> ids::Id {
+    alias.parse().unwrap_or_else(|_| ids::Id::sha256(alias))
+}
+
+/// Initializes a single-node Vm on top of `storage` for the chain served under
+/// `chain_alias`, returning it with the receiving end of its channel to the
+/// consensus engine.
+pub(crate) async fn init_vm(
+    chain_alias: &str,
+    storage: BoxedStorage,
+    genesis: &Genesis,
+) -> io::Result<(Vm<NoopAppSender>, mpsc::Receiver<Message>)> {
+    let (to_engine, from_vm) = mpsc::channel(100);
+
+    let mut vm = Vm::without_avalanchego(chain_id_for_alias(chain_alias), storage);
+    vm.initialize(
+        None,
+        DatabaseManager::from_databases(Vec::new()),
+        &genesis.to_vec()?,
+        &[],
+        &[],
+        to_engine,
+        &[],
+        NoopAppSender,
+    )
+    .await?;
+    vm.set_state(snow::State::NormalOp).await?;
+
+    Ok((vm, from_vm))
+}
+
+/// Serves the chain handlers of `vm` at `addr` until `stopped` fires.
+/// Returns the bound address and the server task.
+pub(crate) async fn serve_vm(
+    vm: &mut Vm<NoopAppSender>,
+    chain_id: &str,
+    addr: SocketAddr,
+    stopped: oneshot::Receiver<()>,
+) -> io::Result<(SocketAddr, JoinHandle<io::Result<()>>)> {
+    let handler = vm
+        .create_handlers()
+        .await?
+        .remove("/rpc")
+        .map(|h| h.handler)
+        .ok_or_else(|| Error::new(ErrorKind::NotFound, "no /rpc handler"))?;
+    let (addr, server) = server::serve::<ChainHandler<_>>(addr, chain_id, handler, async {
+        let _ = stopped.await;
+    })?;
+
+    Ok((addr, tokio::spawn(server)))
+}
+
+/// Builds a block from the mempool, prefers and accepts it.
+pub(crate) async fn build_and_accept(vm: &Vm<NoopAppSender>) -> io::Result<ids::Id> {
+    // "build_block" verifies the block before returning it
+    let mut block = vm.build_block().await?;
+    vm.set_preference(block.id()).await?;
+    block.accept().await?;
+    Ok(block.id())
+}
@@ -0,0 +1,222 @@
+//! Single-node mode that hosts a chain without an Avalanche subnet.
+//!
+//! The Vm runs with the same block and transaction verification, persists to an
+//! [`EmbeddedDatabase`](crate::state::embedded::EmbeddedDatabase) in a data
+//! directory, builds blocks from its mempool on a fixed interval and serves its
+//! chain handlers over plain HTTP at the avalanchego RPC path.
+
+use std::{io, net::SocketAddr, path::PathBuf, time::Duration};
+
+use avalanche_types::subnet::rpc::snow::engine::common::message::Message;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+    time::{interval, MissedTickBehavior},
+};
+
+use crate::{
+    genesis::Genesis,
+    node::{build_and_accept, init_vm, serve_vm, NoopAppSender},
+    state::{embedded::EmbeddedDatabase, storage::Storage},
+    vm::Vm,
+};
+
+/// Chain Id the node serves its handlers under, unless told otherwise.
+pub const DEFAULT_CHAIN_ID: &str = "standalone";
+/// Interval between two block builds, unless told otherwise.
+pub const DEFAULT_BLOCK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Settings of a standalone node.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Directory holding the persistent chain state.
+    pub data_dir: PathBuf,
+    pub chain_id: String,
+    /// Address to serve the chain handlers at.
+    pub listen: SocketAddr,
+    pub block_interval: Duration,
+}
+
+/// A running standalone node.
+pub struct Standalone {
+    vm: Vm<NoopAppSender>,
+    chain_id: String,
+    addr: SocketAddr,
+    db: EmbeddedDatabase,
+
+    stop_server: Option<oneshot::Sender<()>>,
+    stop_sequencer: Option<oneshot::Sender<()>>,
+    server: JoinHandle<io::Result<()>>,
+    sequencer: JoinHandle<()>,
+}
+
+impl Standalone {
+    /// Opens the chain in `config.data_dir` and starts serving it. `genesis`
+    /// is only used if the data directory doesn't hold a chain yet.
+    /// # Errors
+    /// Fails if the data directory can't be opened, if the Vm can't be
+    /// initialized or if the listen address can't be bound.
+    pub async fn start(config: &Config, genesis: &Genesis) -> io::Result<Self> {
+        let db = EmbeddedDatabase::open(&config.data_dir)?;
+        let (mut vm, from_vm) = init_vm(&config.chain_id, db.clone().boxed(), genesis).await?;
+
+        let (stop_sequencer, sequencer_stopped) = oneshot::channel();
+        let sequencer = tokio::spawn(run_sequencer(
+            vm.clone(),
+            from_vm,
+            config.block_interval,
+            sequencer_stopped,
+        ));
+
+        let (stop_server, server_stopped) = oneshot::channel();
+        let (addr, server) =
+            serve_vm(&mut vm, &config.chain_id, config.listen, server_stopped).await?;
+
+        log::info!(
+            "standalone node started at http://{addr} with chain Id '{}' in '{}'",
+            config.chain_id,
+            config.data_dir.display()
+        );
+        Ok(Self {
+            vm,
+            chain_id: config.chain_id.clone(),
+            addr,
+            db,
+            stop_server: Some(stop_server),
+            stop_sequencer: Some(stop_sequencer),
+            server,
+            sequencer,
+        })
+    }
+
+    #[must_use]
+    pub fn vm(&self) -> &Vm<NoopAppSender> {
+        &self.vm
+    }
+
+    #[must_use]
+    pub fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+
+    /// Returns the base URL to pass as `--rpc-url` to `zkretctl`.
+    #[must_use]
+    pub fn http_rpc(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Stops serving, lets an in-progress block build finish and closes the database.
+    /// Transactions still in the mempool are dropped.
+    /// # Errors
+    /// Fails if the HTTP server failed or if the database can't be flushed.
+    pub async fn shutdown(mut self) -> io::Result<()> {
+        if let Some(stop) = self.stop_server.take() {
+            let _ = stop.send(());
+        }
+        if let Some(stop) = self.stop_sequencer.take() {
+            let _ = stop.send(());
+        }
+
+        let _ = self.sequencer.await;
+        let served = self
+            .server
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, format!("http server panicked {e}")));
+
+        self.db.close().await?;
+        served?
+    }
+}
+
+/// Builds, prefers and accepts blocks from the mempool every `block_interval`,
+/// until `stopped` fires.
+async fn run_sequencer(
+    vm: Vm<NoopAppSender>,
+    mut from_vm: mpsc::Receiver<Message>,
+    block_interval: Duration,
+    mut stopped: oneshot::Receiver<()>,
+) {
+    let mut ticker = interval(block_interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            _ = &mut stopped => return,
+
+            // blocks are built on the timer, so notifications are only drained
+            msg = from_vm.recv() => if msg.is_none() {
+                return;
+            },
+
+            _ = ticker.tick() => {
+                while !vm.mempool.read().await.is_empty() {
+                    match build_and_accept(&vm).await {
+                        Ok(blk_id) => log::info!("accepted block {blk_id}"),
+                        Err(e) => log::warn!("failed to build block: {e}"),
+                    }
+                    while from_vm.try_recv().is_ok() {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::sleep;
+
+    use super::*;
+    use crate::{
+        client::{ClientOptions, ZkretClient},
+        node::chain_id_for_alias,
+        testing::{enter_tx, participant},
+    };
+
+    #[tokio::test]
+    async fn chain_survives_restart() {
+        let config = Config {
+            data_dir: std::env::temp_dir().join(format!(
+                "zkretvm-standalone-{}",
+                random_manager::secure_string(10)
+            )),
+            chain_id: DEFAULT_CHAIN_ID.to_string(),
+            listen: "127.0.0.1:0".parse().unwrap(),
+            block_interval: Duration::from_millis(100),
+        };
+        let genesis = Genesis::default();
+
+        let node = Standalone::start(&config, &genesis).await.unwrap();
+        let client =
+            ZkretClient::new(&node.http_rpc(), DEFAULT_CHAIN_ID, ClientOptions::default()).unwrap();
+
+        let tx = enter_tx(&participant(1), &chain_id_for_alias(DEFAULT_CHAIN_ID));
+        client.propose_block(&tx).await.unwrap();
+
+        let mut height = 0;
+        for _ in 0..50 {
+            height = client.last_accepted_block().await.unwrap().height();
+            if height == 1 {
+                break;
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(height, 1);
+        let last_accepted = client.last_accepted().await.unwrap();
+        node.shutdown().await.unwrap();
+
+        let node = Standalone::start(&config, &genesis).await.unwrap();
+        let client =
+            ZkretClient::new(&node.http_rpc(), DEFAULT_CHAIN_ID, ClientOptions::default()).unwrap();
+        assert_eq!(client.last_accepted().await.unwrap(), last_accepted);
+        assert_eq!(
+            client.unclaimed_pub_keys().await.unwrap(),
+            vec![tx.decode().unwrap().data.0]
+        );
+
+        // the ENTER is now rejected against the persisted state
+        assert!(client.propose_block(&tx).await.is_err());
+        node.shutdown().await.unwrap();
+
+        std::fs::remove_dir_all(&config.data_dir).unwrap();
+    }
+}
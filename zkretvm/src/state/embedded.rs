@@ -0,0 +1,162 @@
+//! Implements a persistent, embedded [`Storage`](super::storage::Storage) engine on
+//! top of [`sled`](https://docs.rs/sled), for running zkretvm outside of avalanchego.
+
+use std::{
+    io::{self, Error, ErrorKind},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use avalanche_types::subnet::rpc::errors;
+
+use super::storage::{BatchOp, BoxedStorage, Storage};
+
+/// Database stored in a directory on disk.
+/// Every write is flushed before it returns.
+#[derive(Clone)]
+pub struct EmbeddedDatabase {
+    db: sled::Db,
+    closed: Arc<AtomicBool>,
+}
+
+impl EmbeddedDatabase {
+    /// Opens the database in `dir`, creating it if it doesn't exist.
+    /// # Errors
+    /// Fails if the directory can't be opened or is locked by another process.
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let db = sled::open(dir.as_ref()).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!(
+                    "failed to open database at '{}': {e}",
+                    dir.as_ref().display()
+                ),
+            )
+        })?;
+
+        Ok(Self {
+            db,
+            closed: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    /// Returns the database boxed for use by [`State`](crate::state::State).
+    #[must_use]
+    pub fn boxed(self) -> BoxedStorage {
+        Box::new(self)
+    }
+
+    fn check_open(&self) -> io::Result<()> {
+        if self.closed.load(Ordering::Relaxed) {
+            return Err(errors::Error::DatabaseClosed.to_err());
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> io::Result<()> {
+        self.db.flush_async().await.map(|_| ()).map_err(to_io_error)
+    }
+}
+
+fn to_io_error(e: sled::Error) -> Error {
+    match e {
+        sled::Error::Io(e) => e,
+        e => Error::new(ErrorKind::Other, format!("database failure: {e}")),
+    }
+}
+
+#[tonic::async_trait]
+impl Storage for EmbeddedDatabase {
+    async fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        self.check_open()?;
+        let v = self.db.get(key).map_err(to_io_error)?;
+        Ok(v.map(|v| v.to_vec()))
+    }
+
+    async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
+        self.check_open()?;
+        self.db.insert(key, value).map_err(to_io_error)?;
+        self.flush().await
+    }
+
+    async fn delete(&mut self, key: &[u8]) -> io::Result<()> {
+        self.check_open()?;
+        self.db.remove(key).map_err(to_io_error)?;
+        self.flush().await
+    }
+
+    async fn keys_with_prefix(&self, prefix: &[u8]) -> io::Result<Vec<Vec<u8>>> {
+        self.check_open()?;
+        self.db
+            .scan_prefix(prefix)
+            .keys()
+            .map(|k| k.map(|k| k.to_vec()).map_err(to_io_error))
+            .collect()
+    }
+
+    async fn write_batch(&mut self, ops: Vec<BatchOp>) -> io::Result<()> {
+        self.check_open()?;
+
+        let mut batch = sled::Batch::default();
+        for op in ops {
+            match op {
+                BatchOp::Put(k, v) => batch.insert(k, v),
+                BatchOp::Delete(k) => batch.remove(k),
+            }
+        }
+        self.db.apply_batch(batch).map_err(to_io_error)?;
+        self.flush().await
+    }
+
+    async fn close(&self) -> io::Result<()> {
+        self.check_open()?;
+        self.flush().await?;
+        self.closed.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn persists_across_reopen() {
+        let dir = std::env::temp_dir().join(format!(
+            "zkretvm-embedded-{}",
+            random_manager::secure_string(10)
+        ));
+
+        {
+            let mut db = EmbeddedDatabase::open(&dir).unwrap();
+            db.put(b"0/a", b"1").await.unwrap();
+            db.put(b"0/b", b"2").await.unwrap();
+            db.put(b"1/c", b"3").await.unwrap();
+            db.delete(b"0/b").await.unwrap();
+
+            db.write_batch(vec![
+                BatchOp::Put(b"0/d".to_vec(), b"4".to_vec()),
+                BatchOp::Delete(b"1/c".to_vec()),
+            ])
+            .await
+            .unwrap();
+            db.close().await.unwrap();
+            assert!(db.get(b"0/a").await.is_err());
+        }
+
+        let db = EmbeddedDatabase::open(&dir).unwrap();
+        assert_eq!(db.get(b"0/a").await.unwrap(), Some(b"1".to_vec()));
+        assert_eq!(db.get(b"0/b").await.unwrap(), None);
+        assert_eq!(db.get(b"1/c").await.unwrap(), None);
+        assert_eq!(
+            db.keys_with_prefix(b"0/").await.unwrap(),
+            vec![b"0/a".to_vec(), b"0/d".to_vec()]
+        );
+
+        drop(db);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
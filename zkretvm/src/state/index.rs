@@ -0,0 +1,166 @@
+//! Indexes the protocol state of the last accepted block for the state query APIs.
+
+use std::collections::{HashMap, HashSet};
+
+use avalanche_types::ids;
+use santazk::merkle::MerkleTree;
+
+use crate::block::transaction::{BlockState, SBytes64, MERKLE_TREE_DEPTH};
+
+/// Default number of items returned by a paginated query.
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+/// Maximum number of items returned by a paginated query.
+pub const MAX_PAGE_LIMIT: usize = 1000;
+
+/// Lookup tables over the [`BlockState`](BlockState) of one block.
+pub struct StateIndex {
+    block_id: ids::Id,
+    block_state: BlockState,
+
+    /// Maps each entered public key to its Merkle leaf index.
+    leaf_positions: HashMap<[u8; 64], usize>,
+    nullifiers: HashSet<[u8; 64]>,
+    invite_nullifiers: HashSet<[u8; 64]>,
+    /// Maps each revealed public key to its position in `revealed_cts`.
+    reveals: HashMap<[u8; 64], usize>,
+
+    merkle_tree: MerkleTree,
+}
+
+impl StateIndex {
+    #[must_use]
+    pub fn new(block_id: ids::Id, block_state: BlockState) -> Self {
+        let leaf_positions = block_state
+            .merkle_leaves
+            .iter()
+            .enumerate()
+            .map(|(i, leaf)| (leaf.to_u8_64(), i))
+            .collect();
+        let nullifiers = block_state
+            .nullifiers
+            .iter()
+            .map(SBytes64::to_u8_64)
+            .collect();
+        let invite_nullifiers = block_state
+            .invite_nullifiers
+            .iter()
+            .map(SBytes64::to_u8_64)
+            .collect();
+        let reveals = block_state
+            .revealed_pub_keys
+            .iter()
+            .enumerate()
+            .map(|(i, pk)| (pk.to_u8_64(), i))
+            .collect();
+        let merkle_tree = MerkleTree::new(
+            MERKLE_TREE_DEPTH,
+            &block_state
+                .merkle_leaves
+                .iter()
+                .map(SBytes64::to_vec)
+                .collect::<Vec<_>>(),
+        );
+
+        Self {
+            block_id,
+            block_state,
+            leaf_positions,
+            nullifiers,
+            invite_nullifiers,
+            reveals,
+            merkle_tree,
+        }
+    }
+
+    /// Returns the Id of the block this index was built from.
+    #[must_use]
+    pub fn block_id(&self) -> ids::Id {
+        self.block_id
+    }
+
+    #[must_use]
+    pub fn block_state(&self) -> &BlockState {
+        &self.block_state
+    }
+
+    /// Returns the leaf index and serialized Merkle path of an entered public key.
+    #[must_use]
+    pub fn merkle_path(&self, pub_key: &SBytes64) -> Option<(usize, Vec<u8>)> {
+        let leaf_index = *self.leaf_positions.get(&pub_key.to_u8_64())?;
+        let path = self.merkle_tree.generate_proof(leaf_index)?;
+        Some((leaf_index, path))
+    }
+
+    #[must_use]
+    pub fn is_nullifier_spent(&self, nullifier: &SBytes64) -> bool {
+        self.nullifiers.contains(&nullifier.to_u8_64())
+    }
+
+    #[must_use]
+    pub fn is_invite_spent(&self, invite_nullifier: &SBytes64) -> bool {
+        self.invite_nullifiers
+            .contains(&invite_nullifier.to_u8_64())
+    }
+
+    /// Returns the ciphertext revealed for a public key, if any.
+    #[must_use]
+    pub fn reveal_for(&self, pub_key: &SBytes64) -> Option<&[u8]> {
+        let i = *self.reveals.get(&pub_key.to_u8_64())?;
+        self.block_state.revealed_cts.get(i).map(Vec::as_slice)
+    }
+}
+
+/// Returns the page of `items` starting at `offset` with at most `limit` items
+/// (clamped to `1..=MAX_PAGE_LIMIT`), and the offset of the next page if there is one.
+/// A page holds at least one item, so following the next offsets always ends.
+#[must_use]
+pub fn paginate<T: Clone>(items: &[T], offset: usize, limit: usize) -> (Vec<T>, Option<usize>) {
+    let limit = limit.clamp(1, MAX_PAGE_LIMIT);
+    let start = offset.min(items.len());
+    let end = start.saturating_add(limit).min(items.len());
+
+    let next_offset = if end < items.len() { Some(end) } else { None };
+    (items[start..end].to_vec(), next_offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paginate_pages() {
+        let items: Vec<u32> = (0..5).collect();
+        assert_eq!(paginate(&items, 0, 2), (vec![0, 1], Some(2)));
+        assert_eq!(paginate(&items, 4, 2), (vec![4], None));
+        assert_eq!(paginate(&items, 7, 2), (vec![], None));
+        assert_eq!(paginate(&items, 0, 0), (vec![0], Some(1)));
+        assert_eq!(paginate(&items, 4, 0), (vec![4], None));
+    }
+
+    #[test]
+    fn index_lookups() {
+        let pk1 = SBytes64([1u8; 32], [1u8; 32]);
+        let pk2 = SBytes64([2u8; 32], [2u8; 32]);
+        let nullifier = SBytes64([3u8; 32], [3u8; 32]);
+
+        let block_state = BlockState {
+            merkle_leaves: vec![pk1, pk2],
+            nullifiers: vec![nullifier],
+            unclaimed_pub_keys: vec![pk1],
+            revealed_pub_keys: vec![pk2],
+            revealed_cts: vec![b"hello".to_vec()],
+            invite_nullifiers: vec![pk1],
+            ..Default::default()
+        };
+        let index = StateIndex::new(ids::Id::empty(), block_state);
+
+        assert_eq!(index.merkle_path(&pk2).unwrap().0, 1);
+        assert!(index.merkle_path(&nullifier).is_none());
+        assert!(index.is_nullifier_spent(&nullifier));
+        assert!(!index.is_nullifier_spent(&pk1));
+        assert!(index.is_invite_spent(&pk1));
+        assert!(!index.is_invite_spent(&nullifier));
+        assert_eq!(index.reveal_for(&pk2), Some(&b"hello"[..]));
+        assert_eq!(index.reveal_for(&pk1), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Upgrades a persisted [`State`](super::State) to the current schema version.
+//!
+//! - Version 0 stored accepted and rejected blocks under their Id, and the last
+//!   accepted block Id.
+//! - Version 1 indexes the Id of each accepted block by its height, and no
+//!   longer stores rejected blocks.
+
+use std::io::{self, Error, ErrorKind};
+
+use avalanche_types::ids;
+
+use super::{
+    block_id_at_height_key, storage::BatchOp, State, LAST_ACCEPTED_BLOCK_KEY, SCHEMA_VERSION_KEY,
+};
+
+/// Version of the layout [`State`](super::State) persists blocks with.
+pub const SCHEMA_VERSION: u32 = 1;
+
+impl State {
+    /// Returns the schema version of the persisted state, or 0 if it predates versioning.
+    /// # Errors
+    /// Fails if the db can't be read or holds a malformed version
+    pub async fn schema_version(&self) -> io::Result<u32> {
+        let db = self.db.read().await;
+        match db.get(SCHEMA_VERSION_KEY).await? {
+            Some(d) => {
+                let d = d
+                    .try_into()
+                    .map_err(|_| Error::new(ErrorKind::InvalidData, "malformed schema version"))?;
+                Ok(u32::from_be_bytes(d))
+            }
+            None => Ok(0),
+        }
+    }
+
+    /// Migrates the persisted state to [`SCHEMA_VERSION`](SCHEMA_VERSION), one version at
+    /// a time. Each migration is written at once together with its version, so an
+    /// interrupted migration is redone on the next start.
+    pub(super) async fn migrate(&self) -> io::Result<()> {
+        let mut version = self.schema_version().await?;
+        if version > SCHEMA_VERSION {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "state has schema version {version}, but this zkretvm only supports up to {SCHEMA_VERSION}"
+                ),
+            ));
+        }
+
+        // a new store starts out at the current version
+        if version == 0 && !self.has_last_accepted_block().await? {
+            return self.write_schema_version(Vec::new(), SCHEMA_VERSION).await;
+        }
+
+        while version < SCHEMA_VERSION {
+            let ops = match version {
+                0 => self.index_heights().await?,
+                _ => unreachable!("no migration from schema version {version}"),
+            };
+            version += 1;
+            self.write_schema_version(ops, version).await?;
+            log::info!("migrated state to schema version {version}");
+        }
+        Ok(())
+    }
+
+    async fn write_schema_version(&self, mut ops: Vec<BatchOp>, version: u32) -> io::Result<()> {
+        ops.push(BatchOp::Put(
+            SCHEMA_VERSION_KEY.to_vec(),
+            version.to_be_bytes().to_vec(),
+        ));
+        let mut db = self.db.write().await;
+        db.write_batch(ops).await
+    }
+
+    /// Version 0 to 1: indexes the accepted chain by height, walking back from the
+    /// last accepted block to genesis. Rejected blocks are left to garbage collection.
+    async fn index_heights(&self) -> io::Result<Vec<BatchOp>> {
+        let db = self.db.read().await;
+        let Some(last_accepted) = db.get(LAST_ACCEPTED_BLOCK_KEY).await? else {
+            return Ok(Vec::new());
+        };
+        drop(db);
+
+        let mut ops = Vec::new();
+        let mut blk_id = ids::Id::from_slice(&last_accepted);
+        while blk_id != ids::Id::empty() {
+            let blk = self.get_block(&blk_id).await?;
+            ops.push(BatchOp::Put(
+                block_id_at_height_key(blk.height()),
+                blk_id.to_vec(),
+            ));
+            blk_id = blk.parent_id();
+        }
+        Ok(ops)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use avalanche_types::{choices::status::Status, subnet::rpc::database::memdb};
+
+    use super::*;
+    use crate::{
+        block::{
+            transaction::{BlockState, Transaction},
+            Block,
+        },
+        state::storage::RpcStorage,
+    };
+
+    fn block(parent_id: ids::Id, height: u64, timestamp: u64, status: Status) -> Block {
+        Block::try_new(
+            parent_id,
+            height,
+            timestamp,
+            Transaction::default(),
+            BlockState::default(),
+            status,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn migrates_v0_state() {
+        let mut state = State::new(RpcStorage::new(memdb::Database::new()).boxed());
+
+        // layout written by zkretvm before schema versioning
+        let genesis = block(ids::Id::empty(), 0, 0, Status::Accepted);
+        let accepted = block(genesis.id(), 1, 1, Status::Accepted);
+        let rejected = block(genesis.id(), 1, 2, Status::Rejected);
+        for blk in [&genesis, &accepted, &rejected] {
+            state.write_block(blk).await.unwrap();
+        }
+        state.set_last_accepted_block(&accepted.id()).await.unwrap();
+        assert_eq!(state.schema_version().await.unwrap(), 0);
+
+        state.migrate().await.unwrap();
+        assert_eq!(state.schema_version().await.unwrap(), SCHEMA_VERSION);
+        assert_eq!(state.get_block_id_at_height(0).await.unwrap(), genesis.id());
+        assert_eq!(
+            state.get_block_id_at_height(1).await.unwrap(),
+            accepted.id()
+        );
+
+        assert_eq!(state.collect_garbage().await.unwrap(), 1);
+        assert!(state.get_block(&rejected.id()).await.is_err());
+        assert_eq!(state.get_block(&accepted.id()).await.unwrap(), accepted);
+
+        // already migrated
+        state.migrate().await.unwrap();
+        assert_eq!(state.collect_garbage().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn refuses_newer_schema() {
+        let state = State::new(RpcStorage::new(memdb::Database::new()).boxed());
+        state
+            .write_schema_version(Vec::new(), SCHEMA_VERSION + 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            state.migrate().await.unwrap_err().kind(),
+            ErrorKind::Unsupported
+        );
+    }
+}
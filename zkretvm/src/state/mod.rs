@@ -1,7 +1,12 @@
 //! Manages the virtual machine states.
 
+pub mod embedded;
+pub mod index;
+mod migrations;
+pub mod storage;
+
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     io::{self, Error, ErrorKind},
     sync::Arc,
 };
@@ -10,29 +15,45 @@ use crate::block::Block;
 use avalanche_types::{choices, ids, subnet};
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
+
+use self::{
+    index::StateIndex,
+    storage::{BatchOp, BoxedStorage, RpcStorage},
+};
+
+pub use self::migrations::SCHEMA_VERSION;
+
 /// Manages block and chain states for this Vm, both in-memory and persistent.
 #[derive(Clone)]
 pub struct State {
-    pub db: Arc<RwLock<Box<dyn subnet::rpc::database::Database + Send + Sync>>>,
+    pub db: Arc<RwLock<BoxedStorage>>,
 
     /// Maps block Id to Block.
     /// Each element is verified but not yet accepted/rejected (e.g., preferred).
     pub verified_blocks: Arc<RwLock<HashMap<ids::Id, Block>>>,
+
+    /// Index over the state of the last accepted block, built on first query.
+    pub index: Arc<RwLock<Option<Arc<StateIndex>>>>,
+
+    /// Id of the chain that transaction proofs are bound to.
+    pub chain_id: ids::Id,
 }
 
 impl Default for State {
     fn default() -> State {
-        Self {
-            db: Arc::new(RwLock::new(subnet::rpc::database::memdb::Database::new())),
-            verified_blocks: Arc::new(RwLock::new(HashMap::new())),
-        }
+        Self::new(RpcStorage::new(subnet::rpc::database::memdb::Database::new()).boxed())
     }
 }
 
 const LAST_ACCEPTED_BLOCK_KEY: &[u8] = b"last_accepted_block";
 
+const SCHEMA_VERSION_KEY: &[u8] = b"schema_version";
+
 const STATUS_PREFIX: u8 = 0x0;
 
+/// Prefix of the keys mapping the height of each accepted block to its Id.
+const HEIGHT_PREFIX: u8 = 0x1;
+
 const DELIMITER: u8 = b'/';
 
 /// Returns a vec of bytes used as a key for identifying blocks in state.
@@ -45,6 +66,16 @@ fn block_with_status_key(blk_id: &ids::Id) -> Vec<u8> {
     k
 }
 
+/// Returns a vec of bytes used as a key for identifying the accepted block at a height.
+/// '`HEIGHT_PREFIX`' + '`BYTE_DELIMITER`' + [`height`] in big endian
+fn block_id_at_height_key(height: u64) -> Vec<u8> {
+    let mut k: Vec<u8> = Vec::with_capacity(8 + 2);
+    k.push(HEIGHT_PREFIX);
+    k.push(DELIMITER);
+    k.extend_from_slice(&height.to_be_bytes());
+    k
+}
+
 /// Wraps a [`Block`](crate::block::Block) and its status.
 /// This is the data format that [`State`](State) uses to persist blocks.
 #[derive(Serialize, Deserialize, Clone)]
@@ -75,6 +106,32 @@ impl BlockWithStatus {
 }
 
 impl State {
+    /// Creates a state on top of `db` as is, without migrating it.
+    #[must_use]
+    pub fn new(db: BoxedStorage) -> Self {
+        Self {
+            db: Arc::new(RwLock::new(db)),
+            verified_blocks: Arc::new(RwLock::new(HashMap::new())),
+            index: Arc::new(RwLock::new(None)),
+            chain_id: ids::Id::empty(),
+        }
+    }
+
+    /// Creates a state on top of `db`, migrating it to [`SCHEMA_VERSION`](SCHEMA_VERSION)
+    /// and deleting the blocks that aren't on the accepted chain.
+    /// # Errors
+    /// Fails if the db can't be read or updated, or was written by a newer version
+    pub async fn open(db: BoxedStorage) -> io::Result<Self> {
+        let state = Self::new(db);
+        state.migrate().await?;
+
+        let collected = state.collect_garbage().await?;
+        if collected > 0 {
+            log::info!("deleted {collected} blocks that aren't on the accepted chain");
+        }
+        Ok(state)
+    }
+
     /// Persists the last accepted block Id to state.
     /// # Errors
     /// Fails if the db can't be updated
@@ -95,8 +152,8 @@ impl State {
     /// Fails if the db can't be read
     pub async fn has_last_accepted_block(&self) -> io::Result<bool> {
         let db = self.db.read().await;
-        match db.has(LAST_ACCEPTED_BLOCK_KEY).await {
-            Ok(found) => Ok(found),
+        match db.get(LAST_ACCEPTED_BLOCK_KEY).await {
+            Ok(found) => Ok(found.is_some()),
             Err(e) => Err(Error::new(
                 ErrorKind::Other,
                 format!("failed to load last accepted block: {e}"),
@@ -109,14 +166,18 @@ impl State {
     /// Can fail if the db can't be read
     pub async fn get_last_accepted_block_id(&self) -> io::Result<ids::Id> {
         let db = self.db.read().await;
-        match db.get(LAST_ACCEPTED_BLOCK_KEY).await {
-            Ok(d) => Ok(ids::Id::from_slice(&d)),
-            Err(e) => {
-                if subnet::rpc::errors::is_not_found(&e) {
-                    return Ok(ids::Id::empty());
-                }
-                Err(e)
-            }
+        let d = db.get(LAST_ACCEPTED_BLOCK_KEY).await?;
+        Ok(d.map_or_else(ids::Id::empty, |d| ids::Id::from_slice(&d)))
+    }
+
+    /// Returns the Id of the accepted block at `height`.
+    /// # Errors
+    /// Fails if no block has been accepted at `height`, or if the db can't be read
+    pub async fn get_block_id_at_height(&self, height: u64) -> io::Result<ids::Id> {
+        let db = self.db.read().await;
+        match db.get(&block_id_at_height_key(height)).await? {
+            Some(d) => Ok(ids::Id::from_slice(&d)),
+            None => Err(subnet::rpc::errors::Error::NotFound.to_err()),
         }
     }
 
@@ -167,6 +228,62 @@ impl State {
             .map_err(|e| Error::new(ErrorKind::Other, format!("failed to put block: {e:?}")))
     }
 
+    /// Writes an accepted block to the state storage, indexes it by height
+    /// and persists it as the last accepted block, all at once.
+    /// # Errors
+    /// Can fail if the block fails to serialize or if the db can't be updated
+    pub async fn write_accepted_block(&mut self, block: &Block) -> io::Result<()> {
+        let blk_id = block.id();
+        let blk_status = BlockWithStatus {
+            block_bytes: block.to_vec()?,
+            status: block.status(),
+        };
+
+        let mut db = self.db.write().await;
+        db.write_batch(vec![
+            BatchOp::Put(block_with_status_key(&blk_id), blk_status.encode()?),
+            BatchOp::Put(block_id_at_height_key(block.height()), blk_id.to_vec()),
+            BatchOp::Put(LAST_ACCEPTED_BLOCK_KEY.to_vec(), blk_id.to_vec()),
+        ])
+        .await
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to put accepted block: {e:?}"),
+            )
+        })
+    }
+
+    /// Deletes the stored blocks that aren't indexed by height as accepted, i.e. the
+    /// rejected blocks that schema version 0 persisted and the blocks orphaned by an
+    /// interrupted write. Returns the number of blocks deleted.
+    /// # Errors
+    /// Fails if the db can't be read or updated
+    pub async fn collect_garbage(&self) -> io::Result<usize> {
+        let mut db = self.db.write().await;
+
+        let mut accepted = HashSet::new();
+        for k in db.keys_with_prefix(&[HEIGHT_PREFIX, DELIMITER]).await? {
+            if let Some(blk_id) = db.get(&k).await? {
+                accepted.insert(blk_id);
+            }
+        }
+
+        let garbage: Vec<_> = db
+            .keys_with_prefix(&[STATUS_PREFIX, DELIMITER])
+            .await?
+            .into_iter()
+            .filter(|k| !accepted.contains(&k[2..]))
+            .map(BatchOp::Delete)
+            .collect();
+
+        let collected = garbage.len();
+        if collected > 0 {
+            db.write_batch(garbage).await?;
+        }
+        Ok(collected)
+    }
+
     /// Reads a block from the state storage using the `block_with_status_key`.
     /// # Errors
     /// Can fail if the block is not found in the state storage, or if the block fails to deserialize
@@ -179,7 +296,10 @@ impl State {
 
         let db = self.db.read().await;
 
-        let blk_status_bytes = db.get(&block_with_status_key(blk_id)).await?;
+        let blk_status_bytes = db
+            .get(&block_with_status_key(blk_id))
+            .await?
+            .ok_or_else(|| subnet::rpc::errors::Error::NotFound.to_err())?;
         let blk_status = BlockWithStatus::from_slice(blk_status_bytes)?;
 
         let mut blk = Block::from_slice(&blk_status.block_bytes)?;
@@ -187,4 +307,24 @@ impl State {
 
         Ok(blk)
     }
-}
\ No newline at end of file
+
+    /// Returns the index over the state of the last accepted block,
+    /// rebuilding it if another block has been accepted since it was built.
+    /// # Errors
+    /// Can fail if the last accepted block can't be read
+    pub async fn index(&self) -> io::Result<Arc<StateIndex>> {
+        let last_accepted = self.get_last_accepted_block_id().await?;
+        if let Some(index) = self.index.read().await.as_ref() {
+            if index.block_id() == last_accepted {
+                return Ok(Arc::clone(index));
+            }
+        }
+
+        let block = self.get_block(&last_accepted).await?;
+        let index = Arc::new(StateIndex::new(last_accepted, block.block_state().clone()));
+        log::info!("indexed state of block {last_accepted}");
+
+        *self.index.write().await = Some(Arc::clone(&index));
+        Ok(index)
+    }
+}
@@ -0,0 +1,128 @@
+//! Key-value storage backends that [`State`](crate::state::State) persists to.
+
+use std::io;
+
+use avalanche_types::subnet::rpc::{database::BoxedDatabase, errors};
+
+/// A single write in an atomic batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOp {
+    Put(Vec<u8>, Vec<u8>),
+    Delete(Vec<u8>),
+}
+
+/// Key-value store that [`State`](crate::state::State) persists blocks and metadata to.
+#[tonic::async_trait]
+pub trait Storage: Send + Sync {
+    /// Returns the value of `key`, or none if it isn't set.
+    async fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>>;
+
+    async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()>;
+
+    /// Removes `key`. Removing a key that isn't set is not an error.
+    async fn delete(&mut self, key: &[u8]) -> io::Result<()>;
+
+    /// Returns all keys starting with `prefix`, in ascending order.
+    async fn keys_with_prefix(&self, prefix: &[u8]) -> io::Result<Vec<Vec<u8>>>;
+
+    /// Applies all of `ops` at once, or none of them.
+    async fn write_batch(&mut self, ops: Vec<BatchOp>) -> io::Result<()>;
+
+    /// Flushes pending writes and closes the store.
+    async fn close(&self) -> io::Result<()>;
+}
+
+pub type BoxedStorage = Box<dyn Storage>;
+
+/// Storage backed by an avalanche [`Database`](avalanche_types::subnet::rpc::database::Database),
+/// i.e. the rpcdb served by avalanchego or an in-memory memdb.
+pub struct RpcStorage {
+    db: BoxedDatabase,
+}
+
+impl RpcStorage {
+    #[must_use]
+    pub fn new(db: BoxedDatabase) -> Self {
+        Self { db }
+    }
+
+    /// Returns the storage boxed for use by [`State`](crate::state::State).
+    #[must_use]
+    pub fn boxed(self) -> BoxedStorage {
+        Box::new(self)
+    }
+}
+
+#[tonic::async_trait]
+impl Storage for RpcStorage {
+    async fn get(&self, key: &[u8]) -> io::Result<Option<Vec<u8>>> {
+        match self.db.get(key).await {
+            Ok(v) => Ok(Some(v)),
+            Err(e) if errors::is_not_found(&e) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
+        self.db.put(key, value).await
+    }
+
+    async fn delete(&mut self, key: &[u8]) -> io::Result<()> {
+        self.db.delete(key).await
+    }
+
+    async fn keys_with_prefix(&self, prefix: &[u8]) -> io::Result<Vec<Vec<u8>>> {
+        let mut it = self.db.new_iterator_with_prefix(prefix).await?;
+        let mut keys = Vec::new();
+        while it.next().await? {
+            keys.push(it.key().await?.to_vec());
+        }
+        it.release().await;
+        Ok(keys)
+    }
+
+    async fn write_batch(&mut self, ops: Vec<BatchOp>) -> io::Result<()> {
+        let mut batch = self.db.new_batch().await?;
+        for op in ops {
+            match op {
+                BatchOp::Put(k, v) => batch.put(&k, &v).await?,
+                BatchOp::Delete(k) => batch.delete(&k).await?,
+            }
+        }
+        batch.write().await
+    }
+
+    async fn close(&self) -> io::Result<()> {
+        self.db.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use avalanche_types::subnet::rpc::database::memdb;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn rpc_storage_round_trip() {
+        let mut storage = RpcStorage::new(memdb::Database::new());
+        assert_eq!(storage.get(b"a").await.unwrap(), None);
+
+        storage
+            .write_batch(vec![
+                BatchOp::Put(b"0/a".to_vec(), b"1".to_vec()),
+                BatchOp::Put(b"0/b".to_vec(), b"2".to_vec()),
+                BatchOp::Put(b"1/c".to_vec(), b"3".to_vec()),
+                BatchOp::Delete(b"0/a".to_vec()),
+            ])
+            .await
+            .unwrap();
+        storage.delete(b"missing").await.unwrap();
+
+        assert_eq!(storage.get(b"0/b").await.unwrap(), Some(b"2".to_vec()));
+        assert_eq!(
+            storage.keys_with_prefix(b"0/").await.unwrap(),
+            vec![b"0/b".to_vec()]
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Helpers shared by the unit tests.
+
+use std::sync::OnceLock;
+
+use avalanche_types::{choices::status::Status, ids};
+use santazk::{
+    crypto::derive_invite_commitment,
+    hash::Hash,
+    merkle::MerkleTree,
+    participant::{Participant, ProtocolState},
+    proofs::{
+        ChoiceAuthProver, EnterAuthProver, InviteEnterAuthProver, ProofSystem, RevealAuthProver,
+    },
+};
+
+use crate::block::{
+    transaction::{BlockState, Transaction, INVITE_TREE_DEPTH},
+    Block,
+};
+
+/// Returns the accepted genesis block of an open group with `data`.
+pub(crate) fn genesis_block(data: &[u8]) -> Block {
+    Block::try_new(
+        ids::Id::empty(),
+        0,
+        0,
+        Transaction::genesis(data.to_vec()),
+        BlockState::default(),
+        Status::Accepted,
+    )
+    .unwrap()
+}
+
+/// Returns the accepted block that applies `tx` on top of `parent`.
+pub(crate) fn child_block(parent: &Block, tx: Transaction) -> Block {
+    let mut block_state = parent.block_state().clone();
+    tx.update_state(&mut block_state);
+    child_block_with_state(parent, tx, block_state)
+}
+
+/// Returns the accepted block of `tx` on top of `parent` that claims the state
+/// `block_state`, whether it follows from them or not.
+pub(crate) fn child_block_with_state(
+    parent: &Block,
+    tx: Transaction,
+    block_state: BlockState,
+) -> Block {
+    Block::try_new(
+        parent.id(),
+        parent.height() + 1,
+        parent.timestamp() + 1,
+        tx,
+        block_state,
+        Status::Accepted,
+    )
+    .unwrap()
+}
+
+/// Returns the ENTER prover, loaded once since loading the proving key is slow.
+pub(crate) fn enter_auth_prover() -> &'static EnterAuthProver {
+    static PROVER: OnceLock<EnterAuthProver> = OnceLock::new();
+    PROVER.get_or_init(EnterAuthProver::new)
+}
+
+/// Returns the invite ENTER prover, loaded once since loading the proving key is slow.
+pub(crate) fn invite_enter_auth_prover() -> &'static InviteEnterAuthProver {
+    static PROVER: OnceLock<InviteEnterAuthProver> = OnceLock::new();
+    PROVER.get_or_init(InviteEnterAuthProver::new)
+}
+
+/// Returns the participant whose secret key, nullifier and Diffie-Hellman
+/// public key are filled with `i`, `i + 100` and `i + 200`.
+pub(crate) fn participant(i: u8) -> Participant {
+    Participant::new(vec![i; 64], vec![i + 100; 64], vec![i + 200; 64])
+}
+
+/// Returns the ENTER transaction of `participant`, with a proof bound to `chain_id`.
+pub(crate) fn enter_tx(participant: &Participant, chain_id: &ids::Id) -> Transaction {
+    let state = ProtocolState::new(&chain_id.to_vec(), Vec::new());
+    let tx = participant.enter_tx(enter_auth_prover(), &state).unwrap();
+    Transaction::try_from(tx).unwrap()
+}
+
+/// Returns the commitment of the invite token (`invite_secret`, `invite_salt`).
+pub(crate) fn invite_commitment(invite_secret: &[u8], invite_salt: &[u8]) -> [u8; 64] {
+    derive_invite_commitment(&Hash::new(), invite_secret, invite_salt)
+        .try_into()
+        .unwrap()
+}
+
+/// Returns an ENTER transaction like [`enter_tx`] that redeems the invite token
+/// (`invite_secret`, `invite_salt`), proven against the allowlist of `invites`.
+pub(crate) fn invite_enter_tx(
+    participant: &Participant,
+    chain_id: &ids::Id,
+    (invite_secret, invite_salt): (&[u8], &[u8]),
+    invites: &[[u8; 64]],
+) -> Transaction {
+    let invites: Vec<_> = invites.iter().map(|i| i.to_vec()).collect();
+    let state = ProtocolState {
+        invite_root: Some(MerkleTree::new(INVITE_TREE_DEPTH, &invites).root()),
+        invites,
+        ..ProtocolState::new(&chain_id.to_vec(), Vec::new())
+    };
+    let tx = participant
+        .invite_enter_tx(
+            invite_enter_auth_prover(),
+            &state,
+            invite_secret,
+            invite_salt,
+        )
+        .unwrap();
+    Transaction::try_from(tx).unwrap()
+}
+
+/// Returns the CHOICE transaction of `participant` for `choice`, proven against
+/// the Merkle tree of the entered `pub_keys`.
+pub(crate) fn choice_tx(
+    participant: &Participant,
+    pub_keys: &[Vec<u8>],
+    choice: &[u8],
+    chain_id: &ids::Id,
+) -> Transaction {
+    static PROVER: OnceLock<ChoiceAuthProver> = OnceLock::new();
+
+    choice_tx_with(
+        PROVER.get_or_init(ChoiceAuthProver::new),
+        participant,
+        pub_keys,
+        choice,
+        chain_id,
+    )
+}
+
+/// Like [`choice_tx`], proven by `prover`.
+pub(crate) fn choice_tx_with<S: ProofSystem>(
+    prover: &ChoiceAuthProver<S>,
+    participant: &Participant,
+    pub_keys: &[Vec<u8>],
+    choice: &[u8],
+    chain_id: &ids::Id,
+) -> Transaction {
+    let state = ProtocolState::new(&chain_id.to_vec(), pub_keys.to_vec());
+    let tx = participant.choice_tx(prover, &state, choice).unwrap();
+    Transaction::try_from(tx).unwrap()
+}
+
+/// Returns the REVEAL transaction of the ciphertext `ct` by `participant`.
+pub(crate) fn reveal_tx(participant: &Participant, ct: &[u8], chain_id: &ids::Id) -> Transaction {
+    static PROVER: OnceLock<RevealAuthProver> = OnceLock::new();
+
+    reveal_tx_with(
+        PROVER.get_or_init(RevealAuthProver::new),
+        participant,
+        ct,
+        chain_id,
+    )
+}
+
+/// Like [`reveal_tx`], proven by `prover`.
+pub(crate) fn reveal_tx_with<S: ProofSystem>(
+    prover: &RevealAuthProver<S>,
+    participant: &Participant,
+    ct: &[u8],
+    chain_id: &ids::Id,
+) -> Transaction {
+    let state = ProtocolState::new(&chain_id.to_vec(), Vec::new());
+    let tx = participant.reveal_tx(prover, &state, ct).unwrap();
+    Transaction::try_from(tx).unwrap()
+}
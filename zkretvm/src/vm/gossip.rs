@@ -0,0 +1,43 @@
+//! Messages exchanged between validators through app gossip.
+
+use std::io::{self, Error, ErrorKind};
+
+use derivative::{self, Derivative};
+use serde::{Deserialize, Serialize};
+
+use crate::block::transaction::Transaction;
+
+/// Represents an app gossip message.
+#[derive(Serialize, Deserialize, Clone, Derivative)]
+#[derivative(Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GossipMessage {
+    /// A verified transaction to be added to the mempool of the receiver.
+    Transaction(Transaction),
+}
+
+impl GossipMessage {
+    /// Encodes the [`GossipMessage`](GossipMessage) to JSON in bytes.
+    /// # Errors
+    /// Errors if the message can't be serialized to JSON.
+    pub fn to_vec(&self) -> io::Result<Vec<u8>> {
+        serde_json::to_vec(&self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize GossipMessage to JSON bytes {e}"),
+            )
+        })
+    }
+
+    /// Loads [`GossipMessage`](GossipMessage) from JSON bytes.
+    /// # Errors
+    /// Will fail if the message can't be deserialized from JSON.
+    pub fn from_slice(d: impl AsRef<[u8]>) -> io::Result<Self> {
+        serde_json::from_slice(d.as_ref()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to deserialize GossipMessage from JSON {e}"),
+            )
+        })
+    }
+}
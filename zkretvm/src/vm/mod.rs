@@ -1,5 +1,7 @@
 //! Implementation of [`snowman.block.ChainVM`](https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/snowman/block#ChainVM) interface for zkretvm.
 
+pub mod gossip;
+
 use std::{
     collections::{HashMap, VecDeque},
     io::{self, Error, ErrorKind},
@@ -12,9 +14,15 @@ use crate::{
         chain_handlers::{ChainHandler, ChainService},
         static_handlers::{StaticHandler, StaticService},
     },
-    block::{transaction::{Transaction, BlockState}, Block},
+    block::{
+        transaction::{BlockState, Transaction},
+        Block,
+    },
     genesis::Genesis,
-    state,
+    state::{
+        self,
+        storage::{BoxedStorage, RpcStorage},
+    },
 };
 use avalanche_types::{
     choices, ids,
@@ -43,21 +51,34 @@ use chrono::{DateTime, Utc};
 use semver::Version;
 use tokio::sync::{mpsc::Sender, RwLock};
 
+use self::gossip::GossipMessage;
+
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Limits how much data a user can propose.
 pub const PROPOSE_LIMIT_BYTES: usize = 1024 * 1024;
 
+/// Returns the number of bytes a transaction accounts for against `PROPOSE_LIMIT_BYTES`.
+fn transaction_size(tx: &Transaction) -> usize {
+    1 + 4 * 64 + tx.data.4.len() + tx.data.5.len()
+}
+
 /// Represents VM-specific states.
 /// Defined in a separate struct, for interior mutability in [`Vm`](Vm).
 /// To be protected with `Arc` and `RwLock`.
 pub struct State {
     pub ctx: Option<Context<ValidatorStateClient>>,
+    /// Id of this chain, from the context or set by
+    /// [`Vm::without_avalanchego`](Vm::without_avalanchego).
+    pub chain_id: ids::Id,
     pub version: Version,
     pub genesis: Genesis,
 
     /// Represents persistent Vm state.
     pub state: Option<state::State>,
+    /// Storage to persist to instead of the database from avalanchego,
+    /// set by [`Vm::without_avalanchego`](Vm::without_avalanchego).
+    pub storage: Option<BoxedStorage>,
     /// Currently preferred block Id.
     pub preferred: ids::Id,
     /// Channel to send messages to the snowman consensus engine.
@@ -71,10 +92,12 @@ impl Default for State {
     fn default() -> Self {
         Self {
             ctx: None,
+            chain_id: ids::Id::empty(),
             version: Version::new(0, 0, 0),
             genesis: Genesis::default(),
 
             state: None,
+            storage: None,
             preferred: ids::Id::empty(),
             to_engine: None,
             bootstrapped: false,
@@ -116,6 +139,25 @@ where
         }
     }
 
+    /// Creates a Vm for the chain `chain_id` that persists to `storage` once
+    /// initialized, for running without a context or database manager from avalanchego.
+    #[must_use]
+    pub fn without_avalanchego(chain_id: ids::Id, storage: BoxedStorage) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(State {
+                chain_id,
+                storage: Some(storage),
+                ..State::default()
+            })),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the Id of this chain, which ENTER proofs must be bound to.
+    pub async fn chain_id(&self) -> ids::Id {
+        self.state.read().await.chain_id
+    }
+
     pub async fn is_bootstrapped(&self) -> bool {
         let vm_state = self.state.read().await;
         vm_state.bootstrapped
@@ -136,14 +178,14 @@ where
         }
     }
 
-    /// Proposes arbitrary data to mempool and notifies that a block is ready for builds.
-    /// Other VMs may optimize mempool with more complicated batching mechanisms.
+    /// Verifies a transaction against the state of the preferred block and
+    /// appends it to the mempool, unless it is already pending.
+    /// Returns "true" if the transaction was added.
     /// # Errors
-    /// Can fail if the data size exceeds `PROPOSE_LIMIT_BYTES`.
-    pub async fn propose_block(&self, tx: Transaction) -> io::Result<()> {
-        let size = 1 + 4 * 64 + tx.data.4.len() + tx.data.5.len();
-        log::info!("received propose_block of {size} bytes");
-
+    /// Can fail if the data size exceeds `PROPOSE_LIMIT_BYTES`, if the transaction
+    /// holds an invalid point, if it is invalid or if there's no state.
+    pub async fn add_to_mempool(&self, tx: Transaction) -> io::Result<bool> {
+        let size = transaction_size(&tx);
         if size > PROPOSE_LIMIT_BYTES {
             log::info!("limit exceeded... returning an error...");
             return Err(Error::new(
@@ -152,12 +194,36 @@ where
             ));
         }
 
+        if self.mempool.read().await.contains(&tx) {
+            return Ok(false);
+        }
+
+        let (preferred, chain_id) = {
+            let vm_state = self.state.read().await;
+            match &vm_state.state {
+                Some(state) => (state.get_block(&vm_state.preferred).await?, state.chain_id),
+                None => return Err(Error::new(ErrorKind::NotFound, "state manager not found")),
+            }
+        };
+        tx.decode()?;
+        if !tx.verify(preferred.block_state(), &chain_id) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "transaction is invalid against preferred block {}",
+                    preferred.id()
+                ),
+            ));
+        }
+
+        // re-check, since another copy may have been added during verification
         let mut mempool = self.mempool.write().await;
+        if mempool.contains(&tx) {
+            return Ok(false);
+        }
         mempool.push_back(tx);
-        log::info!("proposed {size} bytes of data for a block");
 
-        self.notify_block_ready().await;
-        Ok(())
+        Ok(true)
     }
 
     /// Sets the state of the Vm.
@@ -205,6 +271,62 @@ where
             None => Err(Error::new(ErrorKind::NotFound, "state manager not found")),
         }
     }
+
+    /// Returns the Id of the genesis block, for organisers to publish as the
+    /// checkpoint clients verify the chain back to.
+    /// # Errors
+    /// Will fail if there's no state or if the db can't be accessed
+    pub async fn genesis_block_id(&self) -> io::Result<ids::Id> {
+        let vm_state = self.state.read().await;
+
+        match &vm_state.state {
+            Some(state) => state.get_block_id_at_height(0).await,
+            None => Err(Error::new(ErrorKind::NotFound, "state manager not found")),
+        }
+    }
+}
+
+impl<A> Vm<A>
+where
+    A: AppSender + Send + Sync + Clone + 'static,
+{
+    /// Proposes a transaction to the mempool, gossips it to the other validators
+    /// and notifies that a block is ready for builds.
+    /// Other VMs may optimize mempool with more complicated batching mechanisms.
+    /// # Errors
+    /// Can fail if the data size exceeds `PROPOSE_LIMIT_BYTES` or if the
+    /// transaction is invalid against the state of the preferred block.
+    pub async fn propose_block(&self, tx: Transaction) -> io::Result<()> {
+        let size = transaction_size(&tx);
+        log::info!("received propose_block of {size} bytes");
+
+        if !self.add_to_mempool(tx.clone()).await? {
+            log::info!("transaction is already pending");
+            return Ok(());
+        }
+        log::info!("proposed {size} bytes of data for a block");
+
+        self.gossip_transaction(tx).await;
+        self.notify_block_ready().await;
+        Ok(())
+    }
+
+    /// Sends a transaction to the other validators.
+    /// Failures are only logged, as the transaction is already in the local mempool.
+    async fn gossip_transaction(&self, tx: Transaction) {
+        let Some(app_sender) = &self.app_sender else {
+            log::warn!("app sender not initialized, not gossiping transaction");
+            return;
+        };
+
+        match GossipMessage::Transaction(tx).to_vec() {
+            Ok(msg) => app_sender
+                .send_app_gossip(msg)
+                .await
+                .unwrap_or_else(|e| log::warn!("failed to gossip transaction: {e}")),
+            Err(e) => log::warn!("failed to encode gossip message: {e}"),
+        }
+    }
 }
 
 #[tonic::async_trait]
@@ -232,6 +354,9 @@ where
         log::info!("initializing Vm");
         let mut vm_state = self.state.write().await;
 
+        if let Some(ctx) = &ctx {
+            vm_state.chain_id = ctx.chain_id;
+        }
         vm_state.ctx = ctx;
 
         let version =
@@ -241,11 +366,12 @@ where
         let genesis = Genesis::from_slice(genesis_bytes)?;
         vm_state.genesis = genesis;
 
-        let current = db_manager.current().await?;
-        let state = state::State {
-            db: Arc::new(RwLock::new(current.db)),
-            verified_blocks: Arc::new(RwLock::new(HashMap::new())),
+        let storage = match vm_state.storage.take() {
+            Some(storage) => storage,
+            None => RpcStorage::new(db_manager.current().await?.db).boxed(),
         };
+        let mut state = state::State::open(storage).await?;
+        state.chain_id = vm_state.chain_id;
         vm_state.state = Some(state.clone());
 
         vm_state.to_engine = Some(to_engine);
@@ -263,7 +389,7 @@ where
                 0,
                 0,
                 Transaction::genesis(vm_state.genesis.data.as_bytes().to_vec()),
-                BlockState::default(),
+                BlockState::from_genesis(&vm_state.genesis)?,
                 choices::status::Status::default(),
             )?;
             genesis_block.set_state(state.clone());
@@ -361,9 +487,16 @@ where
                 .try_into()
                 .expect("timestamp to convert from i64 to u64");
 
-            let first = mempool.pop_front().unwrap();
-
+            // skip transactions that are no longer valid, e.g., because a block
+            // built by another validator included them after they were gossiped
             let mut block_state = prnt_blk.block_state().clone();
+            let first = loop {
+                match mempool.pop_front() {
+                    Some(tx) if tx.verify(&block_state, &state.chain_id) => break tx,
+                    Some(_) => log::info!("dropping invalid transaction from mempool"),
+                    None => return Err(Error::new(ErrorKind::Other, "no pending block")),
+                }
+            };
             first.update_state(&mut block_state);
 
             let mut block = Block::try_new(
@@ -408,9 +541,13 @@ where
         Ok(())
     }
 
-    // Returns an error as a no-op for now.
-    async fn get_block_id_at_height(&self, _height: u64) -> io::Result<ids::Id> {
-        Err(Error::new(ErrorKind::NotFound, "block id not found"))
+    async fn get_block_id_at_height(&self, height: u64) -> io::Result<ids::Id> {
+        let vm_state = self.state.read().await;
+        if let Some(state) = &vm_state.state {
+            return state.get_block_id_at_height(height).await;
+        }
+
+        Err(Error::new(ErrorKind::NotFound, "state manager not found"))
     }
 
     async fn state_sync_enabled(&self) -> io::Result<bool> {
@@ -480,8 +617,26 @@ where
         Ok(())
     }
 
-    /// Currently, no app-specific messages, so returning Ok.
-    async fn app_gossip(&self, _node_id: &ids::node::Id, _msg: &[u8]) -> io::Result<()> {
+    /// Adds transactions gossiped by other validators to the mempool.
+    /// Received transactions are not gossiped again, since the sender
+    /// already reached every validator.
+    async fn app_gossip(&self, node_id: &ids::node::Id, msg: &[u8]) -> io::Result<()> {
+        let tx = match GossipMessage::from_slice(msg) {
+            Ok(GossipMessage::Transaction(tx)) => tx,
+            Err(e) => {
+                log::warn!("dropping gossip from {node_id}: {e}");
+                return Ok(());
+            }
+        };
+
+        match self.add_to_mempool(tx).await {
+            Ok(true) => {
+                log::info!("added transaction gossiped by {node_id}");
+                self.notify_block_ready().await;
+            }
+            Ok(false) => log::debug!("transaction gossiped by {node_id} is already pending"),
+            Err(e) => log::warn!("dropping transaction gossiped by {node_id}: {e}"),
+        }
         Ok(())
     }
 }
@@ -595,3 +750,380 @@ where
         Err(Error::new(ErrorKind::NotFound, "state manager not found"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use avalanche_types::subnet::rpc::snow::engine::common::message::Message;
+    use tokio::sync::mpsc;
+
+    use santazk::hash::Hash;
+
+    use super::*;
+    use crate::{
+        block::transaction::{
+            SBytes32, SBytes64, TxField, TX_VERSION_COMPRESSED, TX_VERSION_UNCOMPRESSED,
+        },
+        testing::{
+            choice_tx, enter_tx, invite_commitment, invite_enter_tx, participant, reveal_tx,
+        },
+    };
+
+    /// Records the gossip sent by one node, to be delivered by the test.
+    #[derive(Clone, Default)]
+    struct MockAppSender {
+        gossip: Arc<Mutex<Vec<Vec<u8>>>>,
+    }
+
+    #[tonic::async_trait]
+    impl AppSender for MockAppSender {
+        async fn send_app_request(&self, _: ids::node::Set, _: u32, _: Vec<u8>) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_app_response(&self, _: ids::node::Id, _: u32, _: Vec<u8>) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_app_gossip(&self, msg: Vec<u8>) -> io::Result<()> {
+            self.gossip.lock().unwrap().push(msg);
+            Ok(())
+        }
+        async fn send_app_gossip_specific(&self, _: ids::node::Set, _: Vec<u8>) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_cross_chain_app_request(
+            &self,
+            _: ids::Id,
+            _: u32,
+            _: Vec<u8>,
+        ) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_cross_chain_app_response(
+            &self,
+            _: ids::Id,
+            _: u32,
+            _: Vec<u8>,
+        ) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct Node {
+        id: ids::node::Id,
+        vm: Vm<MockAppSender>,
+        sender: MockAppSender,
+        _to_engine: mpsc::Receiver<Message>,
+    }
+
+    /// Sets up a Vm on top of an in-memory state holding the genesis block.
+    async fn new_node(i: u8) -> Node {
+        new_node_with_genesis(i, &Genesis::default()).await
+    }
+
+    async fn new_node_with_genesis(i: u8, genesis: &Genesis) -> Node {
+        let sender = MockAppSender::default();
+        let (to_engine, rx) = mpsc::channel(100);
+
+        let mut vm = Vm::new();
+        vm.app_sender = Some(sender.clone());
+
+        let state = state::State::default();
+        let mut genesis_block = Block::try_new(
+            ids::Id::empty(),
+            0,
+            0,
+            Transaction::genesis(b"test".to_vec()),
+            BlockState::from_genesis(genesis).unwrap(),
+            choices::status::Status::default(),
+        )
+        .unwrap();
+        genesis_block.set_state(state.clone());
+        genesis_block.accept().await.unwrap();
+
+        let mut vm_state = vm.state.write().await;
+        vm_state.state = Some(state);
+        vm_state.preferred = genesis_block.id();
+        vm_state.to_engine = Some(to_engine);
+        drop(vm_state);
+
+        Node {
+            id: ids::node::Id::from_slice(&[i; 20]),
+            vm,
+            sender,
+            _to_engine: rx,
+        }
+    }
+
+    /// Delivers the gossip sent by each node to every other node.
+    async fn deliver_gossip(nodes: &[Node]) {
+        for from in nodes {
+            let msgs: Vec<_> = from.sender.gossip.lock().unwrap().drain(..).collect();
+            for msg in msgs {
+                for to in nodes.iter().filter(|n| n.id != from.id) {
+                    to.vm.app_gossip(&from.id, &msg).await.unwrap();
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn proposed_transactions_are_gossiped() {
+        let mut nodes = Vec::new();
+        for i in 0..3 {
+            nodes.push(new_node(i).await);
+        }
+
+        let tx = enter_tx(&participant(1), &ids::Id::empty());
+        nodes[0].vm.propose_block(tx.clone()).await.unwrap();
+        deliver_gossip(&nodes).await;
+
+        for node in &nodes {
+            let mempool = node.vm.mempool.read().await;
+            assert_eq!(mempool.len(), 1);
+            assert_eq!(mempool[0], tx);
+        }
+
+        // proposing the same transaction elsewhere adds and gossips nothing new
+        nodes[1].vm.propose_block(tx.clone()).await.unwrap();
+        assert!(nodes[1].sender.gossip.lock().unwrap().is_empty());
+
+        // duplicate gossip is ignored
+        let msg = GossipMessage::Transaction(tx).to_vec().unwrap();
+        nodes[2].vm.app_gossip(&nodes[0].id, &msg).await.unwrap();
+        for node in &nodes {
+            assert_eq!(node.vm.mempool.read().await.len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_gossip_is_dropped() {
+        let node = new_node(0).await;
+        let peer = ids::node::Id::from_slice(&[1; 20]);
+
+        let invalid = Transaction {
+            transaction_type: 9,
+            ..Default::default()
+        };
+        let msg = GossipMessage::Transaction(invalid.clone())
+            .to_vec()
+            .unwrap();
+        node.vm.app_gossip(&peer, &msg).await.unwrap();
+        node.vm.app_gossip(&peer, b"not a message").await.unwrap();
+        assert!(node.vm.mempool.read().await.is_empty());
+
+        assert!(node.vm.propose_block(invalid).await.is_err());
+        assert!(node.sender.gossip.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn enter_requires_a_proof_for_this_chain() {
+        let node = new_node(0).await;
+
+        // a key nobody knows the secret key of
+        let garbage = Transaction::enter(&Hash::new().h1(&[9u8; 32]), Vec::new()).unwrap();
+        assert!(node.vm.propose_block(garbage).await.is_err());
+
+        // a proof for another chain can't be replayed here
+        let other_chain = ids::Id::sha256("other");
+        let replayed = enter_tx(&participant(1), &other_chain);
+        assert!(node.vm.propose_block(replayed).await.is_err());
+
+        // nor can a proof be reused for another key
+        let tx = enter_tx(&participant(1), &ids::Id::empty());
+        let stolen = Transaction::enter(&participant(2).pub_key, tx.data.4.clone()).unwrap();
+        assert!(node.vm.propose_block(stolen).await.is_err());
+
+        node.vm.propose_block(tx).await.unwrap();
+        assert_eq!(node.vm.mempool.read().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn points_are_validated_in_both_formats() {
+        let node = new_node(0).await;
+        let tx = enter_tx(&participant(1), &ids::Id::empty());
+        assert_eq!(tx.version, TX_VERSION_COMPRESSED);
+        let decoded = tx.decode().unwrap();
+        let legacy = Transaction::from(decoded.clone());
+        assert_eq!(legacy.version, TX_VERSION_UNCOMPRESSED);
+
+        let mut off_curve = decoded.clone();
+        off_curve.data.0 .0[0] ^= 1;
+        let TxField::Bytes32(compressed) = tx.data.0 else {
+            panic!("the public key isn't compressed");
+        };
+        // the compressed point padded to 64 bytes
+        let mut padded = tx.clone();
+        padded.data.0 = TxField::Bytes64(SBytes64(compressed.0, [0u8; 32]));
+        let mut not_a_point = tx.clone();
+        not_a_point.data.0 = TxField::Bytes32(SBytes32([0xff; 32]));
+        let mut mixed_formats = legacy.clone();
+        mixed_formats.data.0 = tx.data.0;
+        let mut unknown_version = tx.clone();
+        unknown_version.version = 2;
+        for invalid in [
+            Transaction::from(off_curve),
+            padded,
+            not_a_point,
+            mixed_formats,
+            unknown_version,
+        ] {
+            let err = node.vm.propose_block(invalid).await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::InvalidData);
+        }
+
+        // transactions of the first format are still accepted, into the same state
+        node.vm.propose_block(legacy).await.unwrap();
+        let mut block = node.vm.build_block().await.unwrap();
+        block.accept().await.unwrap();
+        assert_eq!(block.block_state().merkle_leaves, vec![decoded.data.0]);
+    }
+
+    #[tokio::test]
+    async fn enter_requires_an_unused_invite() {
+        let invite_a = (&[1u8; 32][..], &[2u8; 32][..]);
+        let invite_b = (&[3u8; 32][..], &[4u8; 32][..]);
+        let invites = vec![
+            invite_commitment(invite_a.0, invite_a.1),
+            invite_commitment(invite_b.0, invite_b.1),
+        ];
+        let genesis = Genesis {
+            invites: invites.clone(),
+            ..Default::default()
+        };
+        let node = new_node_with_genesis(0, &genesis).await;
+        let chain_id = ids::Id::empty();
+
+        // the group is invite-only
+        let uninvited = enter_tx(&participant(1), &chain_id);
+        assert!(node.vm.propose_block(uninvited).await.is_err());
+
+        // an invite the organiser didn't issue
+        let forged = (&[5u8; 32][..], &[6u8; 32][..]);
+        let forged_tx = invite_enter_tx(
+            &participant(1),
+            &chain_id,
+            forged,
+            &[invite_commitment(forged.0, forged.1)],
+        );
+        assert!(node.vm.propose_block(forged_tx).await.is_err());
+
+        let tx = invite_enter_tx(&participant(1), &chain_id, invite_a, &invites);
+        node.vm.propose_block(tx).await.unwrap();
+        let mut block = node.vm.build_block().await.unwrap();
+        block.accept().await.unwrap();
+        node.vm.set_preference(block.id()).await.unwrap();
+        assert_eq!(block.block_state().invite_nullifiers.len(), 1);
+
+        // the invite can't be redeemed twice, not even for another key
+        let reused = invite_enter_tx(&participant(2), &chain_id, invite_a, &invites);
+        assert!(node.vm.propose_block(reused).await.is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "loads the CHOICE and REVEAL proving keys, run with `cargo test --release -- --ignored`"]
+    async fn choice_and_reveal_are_bound_to_the_chain() {
+        let node = new_node(0).await;
+        let chain_id = ids::Id::empty();
+        let other_chain_id = ids::Id::from_slice(&[1u8; 32]);
+
+        let mut leaves = Vec::new();
+        for i in [1, 2] {
+            node.vm
+                .propose_block(enter_tx(&participant(i), &chain_id))
+                .await
+                .unwrap();
+            let mut block = node.vm.build_block().await.unwrap();
+            block.accept().await.unwrap();
+            node.vm.set_preference(block.id()).await.unwrap();
+            leaves = block.block_state().merkle_leaves.clone();
+        }
+        let pub_keys: Vec<_> = leaves.iter().map(SBytes64::to_vec).collect();
+
+        // proofs made for another chain don't verify here
+        let replayed = choice_tx(&participant(1), &pub_keys, &pub_keys[1], &other_chain_id);
+        assert!(node.vm.propose_block(replayed).await.is_err());
+        let replayed = reveal_tx(&participant(1), b"ct", &other_chain_id);
+        assert!(node.vm.propose_block(replayed).await.is_err());
+
+        let tx = choice_tx(&participant(1), &pub_keys, &pub_keys[1], &chain_id);
+        node.vm.propose_block(tx).await.unwrap();
+        let tx = reveal_tx(&participant(1), b"ct", &chain_id);
+        node.vm.propose_block(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "marlin")]
+    #[ignore = "needs the Marlin SRS pinned by MARLIN_SRS_SHA256, run with `cargo test --release --features marlin -- --ignored`"]
+    async fn marlin_and_groth16_proofs_are_both_accepted() {
+        use santazk::proofs::{
+            marlin::MARLIN_SRS_PATH, ChoiceAuthProver, Groth16, Marlin, ProofSystem,
+            RevealAuthProver,
+        };
+
+        use crate::{
+            block::transaction::MERKLE_TREE_DEPTH,
+            testing::{choice_tx_with, reveal_tx_with},
+        };
+
+        let node = new_node(0).await;
+        let chain_id = ids::Id::empty();
+
+        let mut leaves = Vec::new();
+        for i in [1, 2] {
+            node.vm
+                .propose_block(enter_tx(&participant(i), &chain_id))
+                .await
+                .unwrap();
+            let mut block = node.vm.build_block().await.unwrap();
+            block.accept().await.unwrap();
+            node.vm.set_preference(block.id()).await.unwrap();
+            leaves = block.block_state().merkle_leaves.clone();
+        }
+        let pub_keys: Vec<_> = leaves.iter().map(SBytes64::to_vec).collect();
+
+        let srs = Marlin::load_srs(MARLIN_SRS_PATH).unwrap();
+        let (choice_prover, _) =
+            ChoiceAuthProver::<Marlin>::index(&srs, MERKLE_TREE_DEPTH).unwrap();
+        let (reveal_prover, _) = RevealAuthProver::<Marlin>::index(&srs).unwrap();
+        drop(srs);
+
+        let tx = choice_tx_with(
+            &choice_prover,
+            &participant(1),
+            &pub_keys,
+            &pub_keys[1],
+            &chain_id,
+        );
+        assert_eq!(tx.data.4.first(), Some(&Marlin::ID));
+        node.vm.propose_block(tx).await.unwrap();
+        let tx = reveal_tx_with(&reveal_prover, &participant(1), b"ct", &chain_id);
+        node.vm.propose_block(tx).await.unwrap();
+
+        // the other participant proves with Groth16
+        let tx = choice_tx(&participant(2), &pub_keys, &pub_keys[0], &chain_id);
+        assert_eq!(tx.data.4.first(), Some(&Groth16::ID));
+        // without its tag the proof isn't taken for a Groth16 one
+        let mut untagged = tx.clone();
+        untagged.data.4.remove(0);
+        assert!(node.vm.propose_block(untagged).await.is_err());
+        node.vm.propose_block(tx).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn stale_transactions_are_skipped_when_building() {
+        let node = new_node(0).await;
+
+        let tx = enter_tx(&participant(1), &ids::Id::empty());
+        node.vm.propose_block(tx.clone()).await.unwrap();
+
+        // the same ENTER arrives again after its first copy was built into a block
+        let mut block = node.vm.build_block().await.unwrap();
+        block.accept().await.unwrap();
+        node.vm.set_preference(block.id()).await.unwrap();
+        node.vm.mempool.write().await.push_back(tx);
+
+        assert!(node.vm.build_block().await.is_err());
+        assert!(node.vm.mempool.read().await.is_empty());
+    }
+}